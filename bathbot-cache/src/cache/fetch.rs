@@ -58,6 +58,16 @@ impl Cache {
         Ok(Ok(bytes))
     }
 
+    /// Fetch the value of a counter, `0` if it does not exist.
+    pub async fn fetch_counter<K>(&self, key: &K) -> Result<u64, FetchError>
+    where
+        K: ToCacheKey + ?Sized,
+    {
+        let count: Option<u64> = self.connection().await?.get(RedisKey::from(key)).await?;
+
+        Ok(count.unwrap_or(0))
+    }
+
     async fn fetch_discord_type<T>(&self, key: RedisKey<'_>) -> FetchResult<T>
     where
         T: Portable + Portable + for<'a> CheckBytes<ValidatorStrategy<'a>>,
@@ -96,6 +106,26 @@ impl Cache {
             .wrap_err("Failed to get member ids")
     }
 
+    pub async fn channel_ids(&self) -> Result<Vec<u64>, Report> {
+        self.connection()
+            .await
+            .map_err(FetchError::Connection)
+            .map_err(Report::new)?
+            .smembers(RedisKey::channels())
+            .await
+            .wrap_err("Failed to get channel ids")
+    }
+
+    pub async fn guild_ids(&self) -> Result<Vec<u64>, Report> {
+        self.connection()
+            .await
+            .map_err(FetchError::Connection)
+            .map_err(Report::new)?
+            .smembers(RedisKey::guilds())
+            .await
+            .wrap_err("Failed to get guild ids")
+    }
+
     pub async fn member(
         &self,
         guild: Id<GuildMarker>,
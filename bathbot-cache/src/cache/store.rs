@@ -86,6 +86,28 @@ impl Cache {
         Ok(count == 1)
     }
 
+    /// Increment a counter and (re)set its expiration.
+    ///
+    /// Returns the new value of the counter.
+    pub async fn increment_counter<K>(&self, key: &K, expire_seconds: i64) -> Result<u64>
+    where
+        K: ToCacheKey + ?Sized,
+    {
+        let mut conn = self.connection().await?;
+        let key = RedisKey::from(key);
+
+        let count: u64 = conn
+            .incr(&key, 1)
+            .await
+            .wrap_err("Failed to increment counter")?;
+
+        conn.expire::<_, ()>(key, expire_seconds)
+            .await
+            .wrap_err("Failed to set counter expiration")?;
+
+        Ok(count)
+    }
+
     pub(crate) async fn cache_channel(&self, channel: &Channel) -> Result<CacheChange> {
         let bytes = rkyv::util::with_arena(|arena| {
             let mut serializer = Serializer::new(AlignedVec::<8>::new(), arena.acquire(), ());
@@ -0,0 +1,90 @@
+use std::{fs, path::Path};
+
+use skia_safe::{Canvas, ClipOp, Data, Image, Paint, Rect};
+
+use crate::{error::BackgroundError, skills::CardTitle};
+
+/// Image that is drawn behind the content of a card.
+#[derive(Copy, Clone, Debug, Default)]
+pub enum CardBackground<'a> {
+    /// The background that belongs to the card's title prefix
+    #[default]
+    Title,
+    /// The background of a title prefix, regardless of the card's title
+    Preset(BackgroundPreset),
+    /// Encoded image bytes e.g. of the user's profile cover
+    Image(&'a [u8]),
+}
+
+#[derive(Copy, Clone, Debug)]
+pub enum BackgroundPreset {
+    Newbie,
+    Novice,
+    Rookie,
+    Apprentice,
+    Advanced,
+    Outstanding,
+    Seasoned,
+    Professional,
+    Expert,
+    Master,
+    Legendary,
+    God,
+}
+
+impl BackgroundPreset {
+    fn filename(self) -> &'static str {
+        match self {
+            Self::Newbie => "newbie.png",
+            Self::Novice => "novice.png",
+            Self::Rookie => "rookie.png",
+            Self::Apprentice => "apprentice.png",
+            Self::Advanced => "advanced.png",
+            Self::Outstanding => "outstanding.png",
+            Self::Seasoned => "seasoned.png",
+            Self::Professional => "professional.png",
+            Self::Expert => "expert.png",
+            Self::Master => "master.png",
+            Self::Legendary => "legendary.png",
+            Self::God => "god.png",
+        }
+    }
+}
+
+impl CardBackground<'_> {
+    pub(crate) fn image(self, title: &CardTitle, assets: &Path) -> Result<Image, BackgroundError> {
+        let preset = match self {
+            Self::Title => title.prefix.background(),
+            Self::Preset(preset) => preset,
+            Self::Image(bytes) => return decode(bytes),
+        };
+
+        let path = assets.join("backgrounds").join(preset.filename());
+        let bytes = fs::read(path).map_err(BackgroundError::File)?;
+
+        decode(&bytes)
+    }
+}
+
+fn decode(bytes: &[u8]) -> Result<Image, BackgroundError> {
+    Image::from_encoded_with_alpha_type(Data::new_copy(bytes), None).ok_or(BackgroundError::Image)
+}
+
+/// Draw the image such that it fills the whole area while keeping its aspect
+/// ratio.
+pub(crate) fn draw_filled(canvas: &Canvas, img: &Image, area: Rect) {
+    let scale = f32::max(
+        area.width() / img.width() as f32,
+        area.height() / img.height() as f32,
+    );
+    let img_w = img.width() as f32 * scale;
+    let img_h = img.height() as f32 * scale;
+    let img_left = area.left + (area.width() - img_w) / 2.0;
+    let img_top = area.top + (area.height() - img_h) / 2.0;
+    let dst = Rect::new(img_left, img_top, img_left + img_w, img_top + img_h);
+
+    canvas.save();
+    canvas.clip_rect(area, Some(ClipOp::Intersect), Some(true));
+    canvas.draw_image_rect(img, None, dst, &Paint::default());
+    canvas.restore();
+}
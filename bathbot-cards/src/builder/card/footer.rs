@@ -26,16 +26,20 @@ impl CardBuilder<'_> {
         card: &CardInner<'_>,
         font_data: &FontData,
     ) -> Result<&mut Self, FooterError> {
-        draw_background(self)?;
+        draw_background(self, card.accent)?;
         draw_logo(self, card.assets.clone())?;
         draw_name(self, card.assets.clone())?;
         draw_date(self, card.date, font_data)?;
 
+        if let Some(label) = card.label {
+            draw_label(self, label, font_data)?;
+        }
+
         Ok(self)
     }
 }
 
-fn draw_background(card: &mut CardBuilder<'_>) -> Result<(), FooterError> {
+fn draw_background(card: &mut CardBuilder<'_>, accent: (u8, u8, u8)) -> Result<(), FooterError> {
     let rect = Rect::new(0.0, 0.0, W as f32, FOOTER_H as f32);
     let radii = [
         Vector::from((0.0, 0.0)),
@@ -45,13 +49,15 @@ fn draw_background(card: &mut CardBuilder<'_>) -> Result<(), FooterError> {
     ];
     let rrect = RRect::new_rect_radii(rect, &radii);
 
+    let (r, g, b) = accent;
+
     let start = Gradient {
         pos: ((W / 2) as f32, 0.0),
-        argb: (153, 0, 0, 0),
+        argb: (153, r, g, b),
     };
     let end = Gradient {
         pos: ((W / 2) as f32, FOOTER_H as f32),
-        argb: (76, 0, 0, 0),
+        argb: (76, r, g, b),
     };
 
     let paint = PaintBuilder::gradient(start, end)?.build();
@@ -125,3 +131,24 @@ fn draw_date(
 
     Ok(())
 }
+
+fn draw_label(
+    card: &mut CardBuilder<'_>,
+    label: &str,
+    font_data: &FontData,
+) -> Result<(), FooterError> {
+    let font = FontBuilder::build(300, Slant::Italic, font_data, 28.0)?;
+    let paint = PaintBuilder::rgb(255, 255, 255).alpha(204).build();
+    let pos_x = W - FOOTER_DATE_MARGIN_RIGHT;
+    let pos_y = H - FOOTER_H + 63 + 45 + 40;
+
+    card.canvas.draw_str_align(
+        label,
+        (pos_x as f32, pos_y as f32),
+        &font,
+        &paint,
+        Align::Right,
+    );
+
+    Ok(())
+}
@@ -33,7 +33,7 @@ impl CardBuilder<'_> {
         title: &CardTitle,
         font_data: &FontData,
     ) -> Result<&mut Self, HeaderError> {
-        draw_background(self)?;
+        draw_background(self, data.accent)?;
         let title = draw_title(self, title, data.username, font_data)?;
         draw_flag(self, data.flag, title)?;
         draw_mode_background(self, mode)?;
@@ -43,7 +43,7 @@ impl CardBuilder<'_> {
     }
 }
 
-fn draw_background(card: &mut CardBuilder<'_>) -> Result<(), HeaderError> {
+fn draw_background(card: &mut CardBuilder<'_>, accent: (u8, u8, u8)) -> Result<(), HeaderError> {
     let rect = Rect::new(0.0, 0.0, W as f32, HEADER_H as f32);
     let radii = [
         Vector::from((0.0, 0.0)),
@@ -53,13 +53,15 @@ fn draw_background(card: &mut CardBuilder<'_>) -> Result<(), HeaderError> {
     ];
     let rrect = RRect::new_rect_radii(rect, &radii);
 
+    let (r, g, b) = accent;
+
     let start = Gradient {
         pos: ((W / 2) as f32, 0.0),
-        argb: (171, 0, 0, 0),
+        argb: (171, r, g, b),
     };
     let end = Gradient {
         pos: ((W / 2) as f32, HEADER_H as f32),
-        argb: (204, 0, 0, 0),
+        argb: (204, r, g, b),
     };
 
     let paint = PaintBuilder::gradient(start, end)?.build();
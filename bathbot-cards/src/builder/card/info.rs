@@ -36,6 +36,7 @@ impl CardBuilder<'_> {
         card: &CardInner<'_>,
         skills: &Skills,
         font_data: &FontData,
+        progress: f32,
     ) -> Result<&mut Self, InfoError> {
         draw_upper_left_background(self)?;
         draw_upper_right_background(self)?;
@@ -43,9 +44,9 @@ impl CardBuilder<'_> {
         draw_pfp(self, card.pfp)?;
         draw_global_rank(self, card.rank_global, font_data)?;
         draw_country_rank(self, card.rank_country, font_data)?;
-        draw_skills(self, skills, font_data)?;
-        draw_level(self, card.level, font_data)?;
-        draw_medals(self, card.medals, card.total_medals, font_data)?;
+        draw_skills(self, skills, font_data, progress)?;
+        draw_level(self, card.level, font_data, progress)?;
+        draw_medals(self, card.medals, card.total_medals, font_data, progress)?;
 
         Ok(self)
     }
@@ -213,45 +214,9 @@ fn draw_skills(
     card: &mut CardBuilder<'_>,
     skills: &Skills,
     font_data: &FontData,
+    progress: f32,
 ) -> Result<(), InfoError> {
-    struct DrawableSkill {
-        name: &'static str,
-        value: f64,
-    }
-
-    impl DrawableSkill {
-        fn new(name: &'static str, value: f64) -> Self {
-            Self { name, value }
-        }
-    }
-
-    let drawables = match skills {
-        Skills::Osu { acc, aim, speed } => {
-            let acc = DrawableSkill::new("ACCURACY", *acc);
-            let aim = DrawableSkill::new("AIM", *aim);
-            let speed = DrawableSkill::new("SPEED", *speed);
-
-            vec![acc, aim, speed]
-        }
-        Skills::Taiko { acc, strain } => {
-            let acc = DrawableSkill::new("ACCURACY", *acc);
-            let strain = DrawableSkill::new("STRAIN", *strain);
-
-            vec![acc, strain]
-        }
-        Skills::Catch { acc, movement } => {
-            let acc = DrawableSkill::new("ACCURACY", *acc);
-            let movement = DrawableSkill::new("MOVEMENT", *movement);
-
-            vec![acc, movement]
-        }
-        Skills::Mania { acc, strain } => {
-            let acc = DrawableSkill::new("ACCURACY", *acc);
-            let strain = DrawableSkill::new("STRAIN", *strain);
-
-            vec![acc, strain]
-        }
-    };
+    let drawables = skills.values();
 
     // `init_y`: y-pos of skill's rect
     // `margin`: pixels inbetween two rects' y-pos
@@ -273,9 +238,7 @@ fn draw_skills(
 
     let fract_font = FontBuilder::build(400, Slant::Upright, font_data, 67.0)?;
 
-    for (skill, i) in drawables.into_iter().zip(0..) {
-        let DrawableSkill { name, value } = skill;
-
+    for ((name, value), i) in drawables.into_iter().zip(0..) {
         // Rectangle
         let rect = Rect::new(0.0, 0.0, 4.0, 43.0);
         let rect_y = init_y + i * margin;
@@ -291,6 +254,7 @@ fn draw_skills(
             .draw_str(name, (name_x as f32, name_y as f32), &name_font, &paint);
 
         // Value
+        let value = value * f64::from(progress);
         let mut builder = TextBlobBuilder::new();
         let trunc = format!("{}.", value.trunc() as i32);
         let fract = format!("{:0>2}", (value.fract() * 100.0) as i32);
@@ -314,6 +278,7 @@ fn draw_level(
     card: &mut CardBuilder<'_>,
     level: f32,
     font_data: &FontData,
+    progress: f32,
 ) -> Result<(), InfoError> {
     // Text
     let level_value = card.int_buf.format(level.trunc() as u32);
@@ -350,7 +315,7 @@ fn draw_level(
         .draw_round_rect(rect, 3.0, 3.0, &paint)
         .translate((-translate_x, -translate_y as f32));
 
-    let rect = Rect::new(0.0, 0.0, rect_w * percent, 9.0);
+    let rect = Rect::new(0.0, 0.0, rect_w * percent * progress, 9.0);
     let paint = PaintBuilder::rgb(255, 255, 255).build();
     let translate_x = (INFO_PAD + 54) as f32 + level_w + value_w;
     let translate_y =
@@ -369,6 +334,7 @@ fn draw_medals(
     curr_medals: u32,
     total_medals: u32,
     font_data: &FontData,
+    progress: f32,
 ) -> Result<(), InfoError> {
     enum MedalClub {
         C95,
@@ -545,7 +511,7 @@ fn draw_medals(
 
     // Shadow
     let shadow_w = 6.0;
-    let rect = Rect::new(0.0, 0.0, rect_w * percent + 2.0 * shadow_w, 20.0);
+    let rect = Rect::new(0.0, 0.0, rect_w * percent * progress + 2.0 * shadow_w, 20.0);
     let paint = PaintBuilder::rgb(r, g, b)
         .alpha(128)
         .mask_filter(BlurStyle::Normal, shadow_w)?
@@ -560,7 +526,7 @@ fn draw_medals(
         .translate((-translate_x, -translate_y as f32));
 
     // Thick bar
-    let rect = Rect::new(0.0, 0.0, rect_w * percent, 9.0);
+    let rect = Rect::new(0.0, 0.0, rect_w * percent * progress, 9.0);
     let paint = PaintBuilder::rgb(r, g, b).build();
     let translate_x = (INFO_PAD + 53) as f32 + medal_w + percent_w;
     let translate_y =
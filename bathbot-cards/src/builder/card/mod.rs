@@ -2,12 +2,16 @@ mod footer;
 mod header;
 mod info;
 
-use std::{fs, path::PathBuf};
+use std::path::Path;
 
 use itoa::Buffer;
-use skia_safe::{Canvas, Data, Image};
+use skia_safe::{Canvas, Rect};
 
-use crate::{error::BackgroundError, skills::CardTitle};
+use crate::{
+    background::{CardBackground, draw_filled},
+    error::BackgroundError,
+    skills::CardTitle,
+};
 
 pub(crate) const H: i32 = 1260;
 pub(crate) const W: i32 = 980;
@@ -27,18 +31,13 @@ impl<'a> CardBuilder<'a> {
 
     pub(crate) fn draw_background(
         &mut self,
+        background: CardBackground<'_>,
         title: &CardTitle,
-        mut assets: PathBuf,
+        assets: &Path,
     ) -> Result<&mut Self, BackgroundError> {
-        assets.push("backgrounds");
-        assets.push(title.prefix.filename());
-        let bytes = fs::read(assets).map_err(BackgroundError::File)?;
-
-        // SAFETY: `bytes` and `Data` share the same lifetime
-        let data = unsafe { Data::new_bytes(&bytes) };
-
-        let img = Image::from_encoded_with_alpha_type(data, None).ok_or(BackgroundError::Image)?;
-        self.canvas.draw_image(&img, (0, 0), None);
+        let img = background.image(title, assets)?;
+        let area = Rect::new(0.0, 0.0, W as f32, H as f32);
+        draw_filled(self.canvas, &img, area);
 
         Ok(self)
     }
@@ -2,13 +2,15 @@ use std::{collections::HashMap, hash::BuildHasher, marker::PhantomData, mem, pat
 
 use rosu_pp::any::DifficultyAttributes;
 use rosu_v2::model::{GameMode, score::Score};
-use skia_safe::{EncodedImageFormat, surfaces};
+use skia_safe::{AlphaType, ColorType, EncodedImageFormat, ImageInfo, Surface, surfaces};
 
 use crate::{
+    background::CardBackground,
     builder::card::{CardBuilder, H, W},
     error::CardError,
     font::FontData,
     skills::{CardTitle, Skills},
+    versus,
 };
 
 pub struct UserNext;
@@ -21,6 +23,14 @@ pub struct ReadyToDraw;
 
 pub(crate) type Maps<S> = HashMap<u32, RequiredAttributes, S>;
 
+/// Raw pixels of a single frame of an animated card.
+pub struct CardFrame {
+    pub width: u32,
+    pub height: u32,
+    /// Unpremultiplied RGBA pixels, row by row
+    pub rgba: Vec<u8>,
+}
+
 pub struct RequiredAttributes {
     pub difficulty: DifficultyAttributes,
     pub od: f32,
@@ -45,6 +55,9 @@ pub(crate) struct CardInner<'a> {
     pub(crate) flag: &'a [u8],
     pub(crate) date: &'a str,
     pub(crate) assets: PathBuf,
+    pub(crate) background: CardBackground<'a>,
+    pub(crate) accent: (u8, u8, u8),
+    pub(crate) label: Option<&'a str>,
 }
 
 impl<'a, Status> BathbotCard<'a, Status> {
@@ -130,16 +143,33 @@ impl<'a> BathbotCard<'a, AssetsPathNext> {
     }
 }
 
-impl BathbotCard<'_, ReadyToDraw> {
+impl<'a> BathbotCard<'a, ReadyToDraw> {
+    /// Draw a different background than the one of the card's title.
+    pub fn background(&mut self, background: CardBackground<'a>) -> &mut Self {
+        self.inner.background = background;
+
+        self
+    }
+
+    /// Tint the header and footer of the card with the given color instead of
+    /// black.
+    pub fn accent_color(&mut self, rgb: (u8, u8, u8)) -> &mut Self {
+        self.inner.accent = rgb;
+
+        self
+    }
+
+    /// Draw a small label below the date, e.g. to point out that only some
+    /// scores were considered.
+    pub fn label(&mut self, label: &'a str) -> &mut Self {
+        self.inner.label = Some(label);
+
+        self
+    }
+
     pub fn draw(&self) -> Result<Vec<u8>, CardError> {
         let fonts = FontData::new(self.inner.assets.clone())?;
-        let mut surface = surfaces::raster_n32_premul((W, H)).ok_or(CardError::CreateSurface)?;
-
-        CardBuilder::new(surface.canvas())
-            .draw_background(&self.title, self.inner.assets.clone())?
-            .draw_header(self.skills.mode(), &self.inner, &self.title, &fonts)?
-            .draw_info(&self.inner, &self.skills, &fonts)?
-            .draw_footer(&self.inner, &fonts)?;
+        let mut surface = self.render(&fonts, 1.0)?;
 
         surface
             .image_snapshot()
@@ -147,4 +177,52 @@ impl BathbotCard<'_, ReadyToDraw> {
             .map(|png_data| png_data.as_bytes().to_vec())
             .ok_or(CardError::EncodeAsPng)
     }
+
+    /// Draw `count` frames in which the skill values count up and the bars
+    /// fill up until the last frame shows the complete card.
+    pub fn draw_frames(&self, count: usize) -> Result<Vec<CardFrame>, CardError> {
+        let fonts = FontData::new(self.inner.assets.clone())?;
+        let info = ImageInfo::new((W, H), ColorType::RGBA8888, AlphaType::Unpremul, None);
+        let row_bytes = info.min_row_bytes();
+        let last = count.saturating_sub(1).max(1) as f32;
+
+        (0..count)
+            .map(|i| {
+                // Ease out so the values slow down towards the end
+                let progress = 1.0 - (1.0 - i as f32 / last).powi(3);
+                let mut surface = self.render(&fonts, progress)?;
+                let mut rgba = vec![0; row_bytes * H as usize];
+
+                if !surface.read_pixels(&info, &mut rgba, row_bytes, (0, 0)) {
+                    return Err(CardError::ReadPixels);
+                }
+
+                Ok(CardFrame {
+                    width: W as u32,
+                    height: H as u32,
+                    rgba,
+                })
+            })
+            .collect()
+    }
+
+    fn render(&self, fonts: &FontData, progress: f32) -> Result<Surface, CardError> {
+        let mut surface = surfaces::raster_n32_premul((W, H)).ok_or(CardError::CreateSurface)?;
+
+        CardBuilder::new(surface.canvas())
+            .draw_background(self.inner.background, &self.title, &self.inner.assets)?
+            .draw_header(self.skills.mode(), &self.inner, &self.title, fonts)?
+            .draw_info(&self.inner, &self.skills, fonts, progress)?
+            .draw_footer(&self.inner, fonts)?;
+
+        Ok(surface)
+    }
+
+    /// Draw a card that compares this card's user with the user of another
+    /// card.
+    ///
+    /// Both cards should have been created for the same mode.
+    pub fn draw_versus(&self, other: &BathbotCard<'_, ReadyToDraw>) -> Result<Vec<u8>, CardError> {
+        versus::draw(self, other)
+    }
 }
@@ -20,6 +20,46 @@ pub enum CardError {
     Info(#[from] InfoError),
     #[error("Failed to draw footer")]
     Footer(#[from] FooterError),
+    #[error("Failed to draw versus card")]
+    Versus(#[from] VersusError),
+    #[error("Failed to encode as PNG")]
+    EncodeAsPng,
+    #[error("Failed to read pixels of frame")]
+    ReadPixels,
+}
+
+#[derive(Debug, ThisError)]
+pub enum ScoreCardError {
+    #[error("Failed to load fonts")]
+    LoadFonts(#[source] CardError),
+    #[error("Failed to create surface")]
+    CreateSurface,
+    #[error("Failed to create background image")]
+    Background,
+    #[error("Font error")]
+    Font(#[from] FontError),
+    #[error("Paint error")]
+    Paint(#[from] PaintError),
+    #[error("Failed to read logo file")]
+    LogoFile(#[source] IoError),
+    #[error("Failed to create logo image")]
+    Logo,
+    #[error("Failed to encode as PNG")]
+    EncodeAsPng,
+}
+
+#[derive(Debug, ThisError)]
+pub enum MapIntroError {
+    #[error("Failed to load fonts")]
+    LoadFonts(#[source] CardError),
+    #[error("Failed to create surface")]
+    CreateSurface,
+    #[error("Failed to create background image")]
+    Background,
+    #[error("Font error")]
+    Font(#[from] FontError),
+    #[error("Paint error")]
+    Paint(#[from] PaintError),
     #[error("Failed to encode as PNG")]
     EncodeAsPng,
 }
@@ -90,6 +130,22 @@ pub enum FooterError {
     BrandingSvg(#[source] SvgError),
 }
 
+#[derive(Debug, ThisError)]
+pub enum VersusError {
+    #[error("Failed to draw background")]
+    Background(#[from] BackgroundError),
+    #[error("Failed to create avatar image")]
+    Avatar,
+    #[error("Font error")]
+    Font(#[from] FontError),
+    #[error("Paint error")]
+    Paint(#[from] PaintError),
+    #[error("Failed to read logo file")]
+    LogoFile(#[source] IoError),
+    #[error("Failed to create logo image")]
+    Logo,
+}
+
 #[derive(Debug, ThisError)]
 pub enum SvgError {
     #[error("Failed to create path")]
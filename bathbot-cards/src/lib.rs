@@ -1,8 +1,17 @@
+mod background;
 mod builder;
 mod card;
 mod error;
 mod font;
+mod map_intro;
+mod score;
 mod skills;
 mod svg;
+mod versus;
 
-pub use self::card::{BathbotCard, RequiredAttributes};
+pub use self::{
+    background::{BackgroundPreset, CardBackground},
+    card::{BathbotCard, CardFrame, ReadyToDraw, RequiredAttributes, UserNext},
+    map_intro::MapIntroCard,
+    score::ScoreCard,
+};
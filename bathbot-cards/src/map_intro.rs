@@ -0,0 +1,241 @@
+use std::path::PathBuf;
+
+use skia_safe::{
+    Canvas, Data, EncodedImageFormat, Image, Rect, font_style::Slant, surfaces,
+    utils::text_utils::Align,
+};
+
+use crate::{
+    background::draw_filled,
+    builder::{
+        font::FontBuilder,
+        paint::{Gradient, PaintBuilder},
+    },
+    error::MapIntroError,
+    font::FontData,
+    score::fit_str,
+};
+
+const W: i32 = 1920;
+const H: i32 = 480;
+
+const PAD: i32 = 60;
+const ACCENT_W: i32 = 16;
+const SLOT_W: i32 = 380;
+const STATS_H: i32 = 130;
+
+/// A wide image introducing a map of a mappool, meant to be shown on
+/// tournament streams.
+pub struct MapIntroCard<'a> {
+    /// Encoded mapset cover; a plain background is drawn if unavailable.
+    pub cover: Option<&'a [u8]>,
+    pub artist: &'a str,
+    pub title: &'a str,
+    pub version: &'a str,
+    pub creator: &'a str,
+    /// Slot of the map within the pool e.g. `NM1`
+    pub slot: &'a str,
+    /// Mods of the slot; shown below the slot if non-empty
+    pub mods: &'a str,
+    pub stars: f32,
+    pub bpm: f32,
+    /// Drain time in seconds
+    pub seconds: u32,
+    pub cs: f32,
+    pub ar: f32,
+    pub od: f32,
+    pub hp: f32,
+    /// Color of the slot label and decorations
+    pub accent: (u8, u8, u8),
+    /// Text above the slot, usually the tournament name
+    pub label: Option<&'a str>,
+    pub assets: PathBuf,
+}
+
+impl MapIntroCard<'_> {
+    pub fn draw(&self) -> Result<Vec<u8>, MapIntroError> {
+        let fonts = FontData::new(self.assets.clone()).map_err(MapIntroError::LoadFonts)?;
+        let mut surface =
+            surfaces::raster_n32_premul((W, H)).ok_or(MapIntroError::CreateSurface)?;
+        let canvas = surface.canvas();
+
+        draw_background(canvas, self)?;
+        draw_slot(canvas, self, &fonts)?;
+        draw_map(canvas, self, &fonts)?;
+        draw_stats(canvas, self, &fonts)?;
+
+        surface
+            .image_snapshot()
+            .encode(None, EncodedImageFormat::PNG, None)
+            .map(|png_data| png_data.as_bytes().to_vec())
+            .ok_or(MapIntroError::EncodeAsPng)
+    }
+}
+
+fn draw_background(canvas: &Canvas, card: &MapIntroCard<'_>) -> Result<(), MapIntroError> {
+    let full = Rect::new(0.0, 0.0, W as f32, H as f32);
+
+    match card.cover {
+        Some(cover) => {
+            let img = Image::from_encoded_with_alpha_type(Data::new_copy(cover), None)
+                .ok_or(MapIntroError::Background)?;
+
+            draw_filled(canvas, &img, full);
+        }
+        None => {
+            let paint = PaintBuilder::rgb(42, 34, 38).build();
+            canvas.draw_rect(full, &paint);
+        }
+    }
+
+    // Darken the left side more so that the text remains readable
+    let start = Gradient {
+        pos: (0.0, (H / 2) as f32),
+        argb: (235, 0, 0, 0),
+    };
+    let end = Gradient {
+        pos: (W as f32, (H / 2) as f32),
+        argb: (140, 0, 0, 0),
+    };
+
+    let paint = PaintBuilder::gradient(start, end)?.build();
+    canvas.draw_rect(full, &paint);
+
+    let (r, g, b) = card.accent;
+    let accent_rect = Rect::new(0.0, 0.0, ACCENT_W as f32, H as f32);
+    canvas.draw_rect(accent_rect, &PaintBuilder::rgb(r, g, b).build());
+
+    let stats_rect = Rect::new(0.0, (H - STATS_H) as f32, W as f32, H as f32);
+    let stats_paint = PaintBuilder::rgb(0, 0, 0).alpha(128).build();
+    canvas.draw_rect(stats_rect, &stats_paint);
+
+    Ok(())
+}
+
+fn draw_slot(
+    canvas: &Canvas,
+    card: &MapIntroCard<'_>,
+    font_data: &FontData,
+) -> Result<(), MapIntroError> {
+    let (r, g, b) = card.accent;
+    let left = (ACCENT_W + PAD) as f32;
+    let max_w = (SLOT_W - PAD) as f32;
+
+    if let Some(label) = card.label {
+        let label_font = FontBuilder::build(500, Slant::Upright, font_data, 30.0)?;
+        let label_paint = PaintBuilder::rgb(255, 255, 255)
+            .alpha(204)
+            .anti_alias()
+            .build();
+        let label = fit_str(label, (W - 2 * PAD) as f32, &label_font);
+
+        canvas.draw_str(label, (left, (PAD + 20) as f32), &label_font, &label_paint);
+    }
+
+    let slot_font = FontBuilder::build(900, Slant::Upright, font_data, 150.0)?;
+    let slot_paint = PaintBuilder::rgb(r, g, b).anti_alias().build();
+    let slot = fit_str(card.slot, max_w, &slot_font);
+
+    canvas.draw_str(slot, (left, 230.0), &slot_font, &slot_paint);
+
+    if !card.mods.is_empty() {
+        let mods_font = FontBuilder::build(700, Slant::Upright, font_data, 40.0)?;
+        let mods_paint = PaintBuilder::rgb(255, 255, 255).anti_alias().build();
+        let mods = fit_str(card.mods, max_w, &mods_font);
+
+        canvas.draw_str(mods, (left, 295.0), &mods_font, &mods_paint);
+    }
+
+    Ok(())
+}
+
+fn draw_map(
+    canvas: &Canvas,
+    card: &MapIntroCard<'_>,
+    font_data: &FontData,
+) -> Result<(), MapIntroError> {
+    let left = (ACCENT_W + PAD + SLOT_W) as f32;
+    let max_w = W as f32 - left - PAD as f32;
+
+    let title_font = FontBuilder::build(700, Slant::Upright, font_data, 64.0)?;
+    let title_paint = PaintBuilder::rgb(255, 255, 255).anti_alias().build();
+    let title = format!("{} - {}", card.artist, card.title);
+    let title = fit_str(&title, max_w, &title_font);
+
+    canvas.draw_str(title, (left, 170.0), &title_font, &title_paint);
+
+    let version_font = FontBuilder::build(400, Slant::Italic, font_data, 44.0)?;
+    let version_paint = PaintBuilder::rgb(255, 255, 255)
+        .alpha(230)
+        .anti_alias()
+        .build();
+    let version = format!("[{}]", card.version);
+    let version = fit_str(&version, max_w, &version_font);
+
+    canvas.draw_str(version, (left, 235.0), &version_font, &version_paint);
+
+    let creator_font = FontBuilder::build(300, Slant::Upright, font_data, 36.0)?;
+    let creator_paint = PaintBuilder::rgb(255, 255, 255)
+        .alpha(204)
+        .anti_alias()
+        .build();
+    let creator = format!("mapped by {}", card.creator);
+    let creator = fit_str(&creator, max_w, &creator_font);
+
+    canvas.draw_str(creator, (left, 295.0), &creator_font, &creator_paint);
+
+    Ok(())
+}
+
+fn draw_stats(
+    canvas: &Canvas,
+    card: &MapIntroCard<'_>,
+    font_data: &FontData,
+) -> Result<(), MapIntroError> {
+    let length = format!("{}:{:02}", card.seconds / 60, card.seconds % 60);
+
+    let stats: [(&str, String); 7] = [
+        ("Stars", format!("{:.2}", card.stars)),
+        ("BPM", format!("{}", card.bpm.round())),
+        ("Length", length),
+        ("CS", format!("{:.1}", card.cs)),
+        ("AR", format!("{:.1}", card.ar)),
+        ("OD", format!("{:.1}", card.od)),
+        ("HP", format!("{:.1}", card.hp)),
+    ];
+
+    let (r, g, b) = card.accent;
+
+    let label_font = FontBuilder::build(500, Slant::Upright, font_data, 26.0)?;
+    let label_paint = PaintBuilder::rgb(r, g, b).anti_alias().build();
+
+    let value_font = FontBuilder::build(700, Slant::Upright, font_data, 48.0)?;
+    let value_paint = PaintBuilder::rgb(255, 255, 255).anti_alias().build();
+
+    let left = ACCENT_W + PAD;
+    let column_w = (W - left - PAD) / stats.len() as i32;
+    let label_y = (H - STATS_H + 45) as f32;
+    let value_y = label_y + 55.0;
+
+    for (i, (label, value)) in stats.iter().enumerate() {
+        let center_x = (left + i as i32 * column_w + column_w / 2) as f32;
+
+        canvas.draw_str_align(
+            label,
+            (center_x, label_y),
+            &label_font,
+            &label_paint,
+            Align::Center,
+        );
+
+        canvas.draw_str_align(
+            value,
+            (center_x, value_y),
+            &value_font,
+            &value_paint,
+            Align::Center,
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,327 @@
+use std::{borrow::Cow, fs, path::PathBuf};
+
+use rosu_v2::model::{GameMode, Grade};
+use skia_safe::{
+    Canvas, Data, EncodedImageFormat, Font, Image, Paint, Rect, font_style::Slant, surfaces,
+    utils::text_utils::Align,
+};
+
+use crate::{
+    builder::{
+        font::FontBuilder,
+        paint::{Gradient, PaintBuilder},
+    },
+    error::ScoreCardError,
+    font::FontData,
+};
+
+const W: i32 = 1200;
+const H: i32 = 630;
+
+const PAD: i32 = 50;
+const HEADER_H: i32 = 150;
+const FOOTER_H: i32 = 90;
+const GRADE_W: i32 = 330;
+const STAT_COLUMNS: usize = 3;
+const STAT_ROW_H: i32 = 150;
+const LOGO_H: i32 = 50;
+
+/// An image of a single score, meant to be shared outside of discord.
+pub struct ScoreCard<'a> {
+    pub mode: GameMode,
+    /// Encoded mapset cover; a plain background is drawn if unavailable.
+    pub cover: Option<&'a [u8]>,
+    pub artist: &'a str,
+    pub title: &'a str,
+    pub version: &'a str,
+    pub creator: &'a str,
+    pub username: &'a str,
+    pub grade: Grade,
+    pub accuracy: f32,
+    pub pp: f32,
+    pub max_pp: f32,
+    pub combo: u32,
+    pub max_combo: u32,
+    pub misses: u32,
+    pub stars: f32,
+    pub mods: &'a str,
+    pub date: &'a str,
+    pub assets: PathBuf,
+}
+
+impl ScoreCard<'_> {
+    pub fn draw(&self) -> Result<Vec<u8>, ScoreCardError> {
+        let fonts = FontData::new(self.assets.clone()).map_err(ScoreCardError::LoadFonts)?;
+        let mut surface =
+            surfaces::raster_n32_premul((W, H)).ok_or(ScoreCardError::CreateSurface)?;
+        let canvas = surface.canvas();
+
+        draw_background(canvas, self.cover)?;
+        draw_header(canvas, self, &fonts)?;
+        draw_grade(canvas, self.grade, &fonts)?;
+        draw_stats(canvas, self, &fonts)?;
+        draw_footer(canvas, self, &fonts)?;
+
+        surface
+            .image_snapshot()
+            .encode(None, EncodedImageFormat::PNG, None)
+            .map(|png_data| png_data.as_bytes().to_vec())
+            .ok_or(ScoreCardError::EncodeAsPng)
+    }
+}
+
+fn draw_background(canvas: &Canvas, cover: Option<&[u8]>) -> Result<(), ScoreCardError> {
+    let full = Rect::new(0.0, 0.0, W as f32, H as f32);
+
+    match cover {
+        Some(cover) => {
+            // SAFETY: `cover` has a longer lifetime than `Data`
+            let data = unsafe { Data::new_bytes(cover) };
+            let img = Image::from_encoded_with_alpha_type(data, None)
+                .ok_or(ScoreCardError::Background)?;
+
+            // Scale the cover so that it fills the whole card while keeping
+            // its aspect ratio
+            let scale = f32::max(
+                W as f32 / img.width() as f32,
+                H as f32 / img.height() as f32,
+            );
+            let img_w = img.width() as f32 * scale;
+            let img_h = img.height() as f32 * scale;
+            let left = (W as f32 - img_w) / 2.0;
+            let top = (H as f32 - img_h) / 2.0;
+            let dst = Rect::new(left, top, left + img_w, top + img_h);
+
+            canvas.draw_image_rect(&img, None, dst, &Paint::default());
+        }
+        None => {
+            let paint = PaintBuilder::rgb(42, 34, 38).build();
+            canvas.draw_rect(full, &paint);
+        }
+    }
+
+    let start = Gradient {
+        pos: ((W / 2) as f32, 0.0),
+        argb: (153, 0, 0, 0),
+    };
+    let end = Gradient {
+        pos: ((W / 2) as f32, H as f32),
+        argb: (230, 0, 0, 0),
+    };
+
+    let paint = PaintBuilder::gradient(start, end)?.build();
+    canvas.draw_rect(full, &paint);
+
+    Ok(())
+}
+
+fn draw_header(
+    canvas: &Canvas,
+    card: &ScoreCard<'_>,
+    font_data: &FontData,
+) -> Result<(), ScoreCardError> {
+    let max_w = (W - 2 * PAD) as f32;
+
+    let title_font = FontBuilder::build(700, Slant::Upright, font_data, 46.0)?;
+    let title_paint = PaintBuilder::rgb(255, 255, 255).anti_alias().build();
+    let title = format!("{} - {}", card.artist, card.title);
+    let title = fit_str(&title, max_w, &title_font);
+    let title_y = (PAD + 40) as f32;
+
+    canvas.draw_str(title, (PAD as f32, title_y), &title_font, &title_paint);
+
+    let version_font = FontBuilder::build(400, Slant::Italic, font_data, 32.0)?;
+    let version_paint = PaintBuilder::rgb(255, 255, 255)
+        .alpha(204)
+        .anti_alias()
+        .build();
+    let version = format!("[{}] mapped by {}", card.version, card.creator);
+    let version = fit_str(&version, max_w, &version_font);
+    let version_y = title_y + 48.0;
+
+    canvas.draw_str(
+        version,
+        (PAD as f32, version_y),
+        &version_font,
+        &version_paint,
+    );
+
+    Ok(())
+}
+
+fn draw_grade(canvas: &Canvas, grade: Grade, font_data: &FontData) -> Result<(), ScoreCardError> {
+    let rect = Rect::new(
+        0.0,
+        0.0,
+        GRADE_W as f32,
+        (H - HEADER_H - FOOTER_H - PAD) as f32,
+    );
+    let paint = PaintBuilder::rgb(0, 0, 0).alpha(77).build();
+
+    canvas
+        .translate((PAD, HEADER_H))
+        .draw_round_rect(rect, 16.0, 16.0, &paint)
+        .translate((-PAD, -HEADER_H));
+
+    let (text, (r, g, b)) = match grade {
+        Grade::XH => ("SS", (222, 222, 232)),
+        Grade::X => ("SS", (255, 204, 34)),
+        Grade::SH => ("S", (222, 222, 232)),
+        Grade::S => ("S", (255, 204, 34)),
+        Grade::A => ("A", (136, 218, 32)),
+        Grade::B => ("B", (46, 174, 240)),
+        Grade::C => ("C", (162, 105, 213)),
+        Grade::D => ("D", (229, 89, 89)),
+        Grade::F => ("F", (128, 128, 128)),
+    };
+
+    let font = FontBuilder::build(900, Slant::Upright, font_data, 200.0)?;
+    let paint = PaintBuilder::rgb(r, g, b).anti_alias().build();
+
+    let pos_x = (PAD + GRADE_W / 2) as f32;
+    let pos_y = HEADER_H as f32 + (rect.height() + font.size() * 0.7) / 2.0;
+
+    canvas.draw_str_align(text, (pos_x, pos_y), &font, &paint, Align::Center);
+
+    Ok(())
+}
+
+fn draw_stats(
+    canvas: &Canvas,
+    card: &ScoreCard<'_>,
+    font_data: &FontData,
+) -> Result<(), ScoreCardError> {
+    let mods = if card.mods.is_empty() {
+        "NM"
+    } else {
+        card.mods
+    };
+
+    let stats: [(&str, Cow<'_, str>); 6] = [
+        ("Accuracy", format!("{:.2}%", card.accuracy).into()),
+        ("PP", format!("{:.2}/{:.2}", card.pp, card.max_pp).into()),
+        (
+            "Combo",
+            format!("{}x/{}x", card.combo, card.max_combo).into(),
+        ),
+        ("Stars", format!("{:.2}", card.stars).into()),
+        ("Misses", card.misses.to_string().into()),
+        ("Mods", mods.into()),
+    ];
+
+    let label_font = FontBuilder::build(500, Slant::Upright, font_data, 26.0)?;
+    let label_paint = PaintBuilder::rgb(255, 255, 255)
+        .alpha(153)
+        .anti_alias()
+        .build();
+
+    let value_font = FontBuilder::build(700, Slant::Upright, font_data, 44.0)?;
+    let value_paint = PaintBuilder::rgb(255, 255, 255).anti_alias().build();
+
+    let left = PAD + GRADE_W + PAD;
+    let column_w = (W - left - PAD) / STAT_COLUMNS as i32;
+
+    for (i, (label, value)) in stats.iter().enumerate() {
+        let column = (i % STAT_COLUMNS) as i32;
+        let row = (i / STAT_COLUMNS) as i32;
+
+        let pos_x = (left + column * column_w) as f32;
+        let pos_y = (HEADER_H + 30 + row * STAT_ROW_H) as f32;
+
+        canvas.draw_str(label, (pos_x, pos_y), &label_font, &label_paint);
+
+        let value = fit_str(value, (column_w - 10) as f32, &value_font);
+        let value_y = pos_y + 55.0;
+
+        canvas.draw_str(value, (pos_x, value_y), &value_font, &value_paint);
+    }
+
+    Ok(())
+}
+
+fn draw_footer(
+    canvas: &Canvas,
+    card: &ScoreCard<'_>,
+    font_data: &FontData,
+) -> Result<(), ScoreCardError> {
+    let rect = Rect::new(0.0, 0.0, W as f32, FOOTER_H as f32);
+
+    let (r, g, b) = match card.mode {
+        GameMode::Osu => (255, 102, 170),
+        GameMode::Taiko => (94, 203, 162),
+        GameMode::Catch => (102, 204, 255),
+        GameMode::Mania => (197, 102, 255),
+    };
+
+    let paint = PaintBuilder::rgb(r, g, b).alpha(64).build();
+    let translate_y = H - FOOTER_H;
+
+    canvas
+        .translate((0, translate_y))
+        .draw_rect(rect, &paint)
+        .translate((-0, -translate_y));
+
+    let mut logo_path = card.assets.clone();
+    logo_path.push("branding/icon.png");
+    let bytes = fs::read(logo_path).map_err(ScoreCardError::LogoFile)?;
+
+    // SAFETY: `bytes` and `data` share the same lifetime
+    let data = unsafe { Data::new_bytes(&bytes) };
+    let img = Image::from_encoded_with_alpha_type(data, None).ok_or(ScoreCardError::Logo)?;
+
+    let logo_w = LOGO_H as f32 * img.width() as f32 / img.height() as f32;
+    let logo_top = (H - FOOTER_H + (FOOTER_H - LOGO_H) / 2) as f32;
+    let logo_rect = Rect::new(
+        PAD as f32,
+        logo_top,
+        PAD as f32 + logo_w,
+        logo_top + LOGO_H as f32,
+    );
+
+    let logo_paint = PaintBuilder::rgb(255, 255, 255).anti_alias().build();
+    canvas.draw_image_rect(&img, None, logo_rect, &logo_paint);
+
+    let text_y = (H - FOOTER_H / 2 + 12) as f32;
+
+    let name_font = FontBuilder::build(700, Slant::Upright, font_data, 36.0)?;
+    let name_paint = PaintBuilder::rgb(255, 255, 255).anti_alias().build();
+    let name_x = PAD as f32 + logo_w + 20.0;
+
+    canvas.draw_str(card.username, (name_x, text_y), &name_font, &name_paint);
+
+    let date_font = FontBuilder::build(300, Slant::Italic, font_data, 32.0)?;
+    let date_paint = PaintBuilder::rgb(255, 255, 255)
+        .alpha(204)
+        .anti_alias()
+        .build();
+
+    canvas.draw_str_align(
+        card.date,
+        ((W - PAD) as f32, text_y),
+        &date_font,
+        &date_paint,
+        Align::Right,
+    );
+
+    Ok(())
+}
+
+/// Shortens the text with an ellipsis until it fits within the given width.
+pub(crate) fn fit_str<'s>(text: &'s str, max_w: f32, font: &Font) -> Cow<'s, str> {
+    let (text_w, _) = font.measure_str(text, None);
+
+    if text_w <= max_w {
+        return Cow::Borrowed(text);
+    }
+
+    for (end, _) in text.char_indices().rev() {
+        let candidate = format!("{}...", text[..end].trim_end());
+        let (candidate_w, _) = font.measure_str(&candidate, None);
+
+        if candidate_w <= max_w {
+            return Cow::Owned(candidate);
+        }
+    }
+
+    Cow::Borrowed("...")
+}
@@ -251,6 +251,18 @@ impl Skills {
         }
     }
 
+    /// Display names and values of all skills of the mode.
+    pub(crate) fn values(&self) -> Vec<(&'static str, f64)> {
+        match self {
+            Skills::Osu { acc, aim, speed } => {
+                vec![("ACCURACY", *acc), ("AIM", *aim), ("SPEED", *speed)]
+            }
+            Skills::Taiko { acc, strain } => vec![("ACCURACY", *acc), ("STRAIN", *strain)],
+            Skills::Catch { acc, movement } => vec![("ACCURACY", *acc), ("MOVEMENT", *movement)],
+            Skills::Mania { acc, strain } => vec![("ACCURACY", *acc), ("STRAIN", *strain)],
+        }
+    }
+
     pub(crate) fn mode(&self) -> GameMode {
         match self {
             Skills::Osu { .. } => GameMode::Osu,
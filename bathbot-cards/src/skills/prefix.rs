@@ -1,5 +1,7 @@
 use std::fmt::{Debug, Display, Formatter, Result as FmtResult};
 
+use crate::background::BackgroundPreset;
+
 #[derive(Debug)]
 pub(crate) enum TitlePrefix {
     Newbie,
@@ -34,20 +36,20 @@ impl TitlePrefix {
         }
     }
 
-    pub(crate) fn filename(&self) -> &'static str {
+    pub(crate) fn background(&self) -> BackgroundPreset {
         match self {
-            Self::Newbie => "newbie.png",
-            Self::Novice => "novice.png",
-            Self::Rookie => "rookie.png",
-            Self::Apprentice => "apprentice.png",
-            Self::Advanced => "advanced.png",
-            Self::Outstanding => "outstanding.png",
-            Self::Seasoned => "seasoned.png",
-            Self::Professional => "professional.png",
-            Self::Expert => "expert.png",
-            Self::Master => "master.png",
-            Self::Legendary => "legendary.png",
-            Self::God => "god.png",
+            Self::Newbie => BackgroundPreset::Newbie,
+            Self::Novice => BackgroundPreset::Novice,
+            Self::Rookie => BackgroundPreset::Rookie,
+            Self::Apprentice => BackgroundPreset::Apprentice,
+            Self::Advanced => BackgroundPreset::Advanced,
+            Self::Outstanding => BackgroundPreset::Outstanding,
+            Self::Seasoned => BackgroundPreset::Seasoned,
+            Self::Professional => BackgroundPreset::Professional,
+            Self::Expert => BackgroundPreset::Expert,
+            Self::Master => BackgroundPreset::Master,
+            Self::Legendary => BackgroundPreset::Legendary,
+            Self::God => BackgroundPreset::God,
         }
     }
 }
@@ -0,0 +1,373 @@
+use std::{borrow::Cow, cmp::Ordering, fs};
+
+use rosu_v2::model::GameMode;
+use skia_safe::{
+    Canvas, ClipOp, Data, EncodedImageFormat, Image, Paint, RRect, Rect, font_style::Slant,
+    surfaces, utils::text_utils::Align,
+};
+
+use crate::{
+    background::draw_filled,
+    builder::{
+        font::FontBuilder,
+        paint::{Gradient, PaintBuilder},
+    },
+    card::{BathbotCard, ReadyToDraw},
+    error::{CardError, VersusError},
+    font::FontData,
+    score::fit_str,
+    skills::Skills,
+};
+
+const W: i32 = 1400;
+const H: i32 = 860;
+const HALF_W: i32 = W / 2;
+
+const PAD: i32 = 50;
+const AVATAR_W: i32 = 160;
+const SKILLS_TOP: i32 = 400;
+const SKILL_ROW_H: i32 = 120;
+/// Distance between the center and the start of a skill bar
+const BAR_GAP: i32 = 110;
+const BAR_H: i32 = 18;
+const FOOTER_H: i32 = 90;
+const LOGO_H: i32 = 50;
+
+/// Color of the user that has the higher value of a skill
+const WINNER_RGB: (u8, u8, u8) = (255, 204, 34);
+
+type Card<'c, 'a> = &'c BathbotCard<'a, ReadyToDraw>;
+
+#[derive(Copy, Clone)]
+enum Side {
+    Left,
+    Right,
+}
+
+impl Side {
+    fn offset(self) -> i32 {
+        match self {
+            Side::Left => 0,
+            Side::Right => HALF_W,
+        }
+    }
+}
+
+/// Draws two cards of the same mode next to each other and highlights which
+/// user wins each skill.
+pub(crate) fn draw(left: Card<'_, '_>, right: Card<'_, '_>) -> Result<Vec<u8>, CardError> {
+    let fonts = FontData::new(left.inner.assets.clone())?;
+    let mut surface = surfaces::raster_n32_premul((W, H)).ok_or(CardError::CreateSurface)?;
+    let canvas = surface.canvas();
+
+    draw_background(canvas, left, Side::Left)?;
+    draw_background(canvas, right, Side::Right)?;
+    draw_overlay(canvas)?;
+    draw_user(canvas, left, Side::Left, &fonts)?;
+    draw_user(canvas, right, Side::Right, &fonts)?;
+    let wins = draw_skills(canvas, &left.skills, &right.skills, &fonts)?;
+    draw_footer(canvas, left, wins, &fonts)?;
+
+    surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .map(|png_data| png_data.as_bytes().to_vec())
+        .ok_or(CardError::EncodeAsPng)
+}
+
+fn draw_background(canvas: &Canvas, card: Card<'_, '_>, side: Side) -> Result<(), VersusError> {
+    let img = card
+        .inner
+        .background
+        .image(&card.title, &card.inner.assets)?;
+
+    let left = side.offset() as f32;
+    let area = Rect::new(left, 0.0, left + HALF_W as f32, H as f32);
+    draw_filled(canvas, &img, area);
+
+    Ok(())
+}
+
+fn draw_overlay(canvas: &Canvas) -> Result<(), VersusError> {
+    let start = Gradient {
+        pos: (HALF_W as f32, 0.0),
+        argb: (153, 0, 0, 0),
+    };
+    let end = Gradient {
+        pos: (HALF_W as f32, H as f32),
+        argb: (204, 0, 0, 0),
+    };
+
+    let paint = PaintBuilder::gradient(start, end)?.build();
+    canvas.draw_rect(Rect::new(0.0, 0.0, W as f32, H as f32), &paint);
+
+    // Divider between the two users above the skills
+    let rect = Rect::new(0.0, 0.0, 3.0, (SKILLS_TOP - 2 * PAD) as f32);
+    let paint = PaintBuilder::rgb(255, 255, 255).alpha(51).build();
+
+    canvas
+        .translate((HALF_W - 1, PAD))
+        .draw_rect(rect, &paint)
+        .translate((-(HALF_W - 1), -PAD));
+
+    Ok(())
+}
+
+fn draw_user(
+    canvas: &Canvas,
+    card: Card<'_, '_>,
+    side: Side,
+    font_data: &FontData,
+) -> Result<(), VersusError> {
+    let center_x = (side.offset() + HALF_W / 2) as f32;
+    let max_w = (HALF_W - 2 * PAD) as f32;
+
+    // Avatar
+    // SAFETY: `pfp` has a longer lifetime than `Data`
+    let data = unsafe { Data::new_bytes(card.inner.pfp) };
+    let img = Image::from_encoded_with_alpha_type(data, None).ok_or(VersusError::Avatar)?;
+
+    let half_avatar = (AVATAR_W / 2) as f32;
+    let rect = Rect::new(
+        center_x - half_avatar,
+        PAD as f32,
+        center_x + half_avatar,
+        (PAD + AVATAR_W) as f32,
+    );
+    let rrect = RRect::new_rect_xy(rect, 16.0, 16.0);
+
+    canvas.save();
+    canvas.clip_rrect(rrect, Some(ClipOp::Intersect), Some(true));
+    canvas.draw_image_rect(&img, None, rect, &Paint::default());
+    canvas.restore();
+
+    // Name
+    let name_font = FontBuilder::build(900, Slant::Upright, font_data, 56.0)?;
+    let name_paint = PaintBuilder::rgb(255, 255, 255).anti_alias().build();
+    let name = fit_str(card.inner.username, max_w, &name_font);
+    let name_y = (PAD + AVATAR_W + 70) as f32;
+
+    canvas.draw_str_align(
+        name,
+        (center_x, name_y),
+        &name_font,
+        &name_paint,
+        Align::Center,
+    );
+
+    // Title
+    let title_font = FontBuilder::build(400, Slant::Italic, font_data, 30.0)?;
+    let title_paint = PaintBuilder::rgb(255, 255, 255)
+        .alpha(204)
+        .anti_alias()
+        .build();
+    let title = card.title.to_string();
+    let title = fit_str(&title, max_w, &title_font);
+    let title_y = name_y + 50.0;
+
+    canvas.draw_str_align(
+        title,
+        (center_x, title_y),
+        &title_font,
+        &title_paint,
+        Align::Center,
+    );
+
+    // Global rank
+    let rank_font = FontBuilder::build(500, Slant::Upright, font_data, 32.0)?;
+    let rank_paint = PaintBuilder::rgb(255, 255, 255).anti_alias().build();
+    let rank = format!("#{}", card.inner.rank_global);
+    let rank_y = title_y + 50.0;
+
+    canvas.draw_str_align(
+        &rank,
+        (center_x, rank_y),
+        &rank_font,
+        &rank_paint,
+        Align::Center,
+    );
+
+    Ok(())
+}
+
+/// Returns how many skills each user won.
+fn draw_skills(
+    canvas: &Canvas,
+    left: &Skills,
+    right: &Skills,
+    font_data: &FontData,
+) -> Result<(usize, usize), VersusError> {
+    let name_font = FontBuilder::build(300, Slant::Italic, font_data, 34.0)?;
+    let name_paint = PaintBuilder::rgb(255, 255, 255).anti_alias().build();
+
+    let winner_font = FontBuilder::build(900, Slant::Upright, font_data, 40.0)?;
+    let loser_font = FontBuilder::build(400, Slant::Upright, font_data, 40.0)?;
+
+    let (r, g, b) = WINNER_RGB;
+    let center = HALF_W as f32;
+    let max_bar_w = (HALF_W - BAR_GAP - PAD) as f32;
+
+    let mut wins = (0, 0);
+
+    let skills = left.values().into_iter().zip(right.values());
+
+    for (row, ((name, left_value), (_, right_value))) in skills.enumerate() {
+        let ordering = left_value.total_cmp(&right_value);
+
+        match ordering {
+            Ordering::Greater => wins.0 += 1,
+            Ordering::Less => wins.1 += 1,
+            Ordering::Equal => {}
+        }
+
+        let row_y = (SKILLS_TOP + row as i32 * SKILL_ROW_H) as f32;
+
+        canvas.draw_str_align(
+            name,
+            (center, row_y + 40.0),
+            &name_font,
+            &name_paint,
+            Align::Center,
+        );
+
+        let bar_y = row_y + 62.0;
+
+        let sides = [
+            (Side::Left, left_value, Ordering::Greater),
+            (Side::Right, right_value, Ordering::Less),
+        ];
+
+        for (side, value, win_ordering) in sides {
+            let is_winner = ordering == win_ordering;
+
+            // Track
+            let track_paint = PaintBuilder::rgb(255, 255, 255).alpha(38).build();
+            let track_x = match side {
+                Side::Left => center - BAR_GAP as f32 - max_bar_w,
+                Side::Right => center + BAR_GAP as f32,
+            };
+            let track = Rect::from_xywh(track_x, bar_y, max_bar_w, BAR_H as f32);
+            canvas.draw_round_rect(track, 9.0, 9.0, &track_paint);
+
+            // Bar
+            let bar_paint = if is_winner {
+                PaintBuilder::rgb(r, g, b).anti_alias().build()
+            } else {
+                PaintBuilder::rgb(255, 255, 255)
+                    .alpha(128)
+                    .anti_alias()
+                    .build()
+            };
+
+            let bar_w = max_bar_w * (value / 100.0).clamp(0.0, 1.0) as f32;
+            let bar_x = match side {
+                Side::Left => center - BAR_GAP as f32 - bar_w,
+                Side::Right => center + BAR_GAP as f32,
+            };
+            let bar = Rect::from_xywh(bar_x, bar_y, bar_w, BAR_H as f32);
+            canvas.draw_round_rect(bar, 9.0, 9.0, &bar_paint);
+
+            // Value
+            let (font, paint) = if is_winner {
+                (
+                    &winner_font,
+                    PaintBuilder::rgb(r, g, b).anti_alias().build(),
+                )
+            } else {
+                (
+                    &loser_font,
+                    PaintBuilder::rgb(255, 255, 255).anti_alias().build(),
+                )
+            };
+
+            let value = format!("{value:.2}");
+
+            let (value_x, align) = match side {
+                Side::Left => (center - BAR_GAP as f32, Align::Right),
+                Side::Right => (center + BAR_GAP as f32, Align::Left),
+            };
+
+            canvas.draw_str_align(&value, (value_x, row_y + 40.0), font, &paint, align);
+        }
+    }
+
+    Ok(wins)
+}
+
+fn draw_footer(
+    canvas: &Canvas,
+    card: Card<'_, '_>,
+    (left_wins, right_wins): (usize, usize),
+    font_data: &FontData,
+) -> Result<(), VersusError> {
+    let rect = Rect::new(0.0, 0.0, W as f32, FOOTER_H as f32);
+
+    let (r, g, b) = match card.skills.mode() {
+        GameMode::Osu => (255, 102, 170),
+        GameMode::Taiko => (94, 203, 162),
+        GameMode::Catch => (102, 204, 255),
+        GameMode::Mania => (197, 102, 255),
+    };
+
+    let paint = PaintBuilder::rgb(r, g, b).alpha(64).build();
+    let translate_y = H - FOOTER_H;
+
+    canvas
+        .translate((0, translate_y))
+        .draw_rect(rect, &paint)
+        .translate((-0, -translate_y));
+
+    let logo_path = card.inner.assets.join("branding/icon.png");
+    let bytes = fs::read(logo_path).map_err(VersusError::LogoFile)?;
+
+    // SAFETY: `bytes` and `data` share the same lifetime
+    let data = unsafe { Data::new_bytes(&bytes) };
+    let img = Image::from_encoded_with_alpha_type(data, None).ok_or(VersusError::Logo)?;
+
+    let logo_w = LOGO_H as f32 * img.width() as f32 / img.height() as f32;
+    let logo_top = (H - FOOTER_H + (FOOTER_H - LOGO_H) / 2) as f32;
+    let logo_rect = Rect::new(
+        PAD as f32,
+        logo_top,
+        PAD as f32 + logo_w,
+        logo_top + LOGO_H as f32,
+    );
+
+    let logo_paint = PaintBuilder::rgb(255, 255, 255).anti_alias().build();
+    canvas.draw_image_rect(&img, None, logo_rect, &logo_paint);
+
+    let text_y = (H - FOOTER_H / 2 + 12) as f32;
+
+    let score_font = FontBuilder::build(900, Slant::Upright, font_data, 40.0)?;
+    let score_paint = PaintBuilder::rgb(255, 255, 255).anti_alias().build();
+    let score = format!("{left_wins} - {right_wins}");
+
+    canvas.draw_str_align(
+        &score,
+        (HALF_W as f32, text_y),
+        &score_font,
+        &score_paint,
+        Align::Center,
+    );
+
+    let date_font = FontBuilder::build(300, Slant::Italic, font_data, 32.0)?;
+    let date_paint = PaintBuilder::rgb(255, 255, 255)
+        .alpha(204)
+        .anti_alias()
+        .build();
+
+    let date = match card.inner.label {
+        Some(label) => Cow::Owned(format!("{label} • {}", card.inner.date)),
+        None => Cow::Borrowed(card.inner.date),
+    };
+
+    canvas.draw_str_align(
+        &*date,
+        ((W - PAD) as f32, text_y),
+        &date_font,
+        &date_paint,
+        Align::Right,
+    );
+
+    Ok(())
+}
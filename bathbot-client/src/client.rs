@@ -4,7 +4,7 @@ use bytes::Bytes;
 use eyre::{Result, WrapErr};
 use http_body_util::{BodyExt, Collected, Full};
 use hyper::{
-    Method, Request, Response,
+    Method, Request, Response, StatusCode,
     body::Incoming,
     header::{AUTHORIZATION, CONTENT_LENGTH, CONTENT_TYPE, USER_AGENT},
 };
@@ -15,7 +15,11 @@ use hyper_util::{
 };
 
 use crate::{
-    ClientError, MY_USER_AGENT, Ratelimiters, Site, metrics::ClientMetrics, multipart::Multipart,
+    ClientError, MY_USER_AGENT, Ratelimiters, Site,
+    conditional::{ConditionalCache, Validators},
+    metrics::ClientMetrics,
+    multipart::Multipart,
+    osu_api::{OSU_API_VERSION, OsuApiAuth, OsuServer},
 };
 
 pub(crate) type InnerClient = HyperClient<HttpsConnector<HttpConnector>, Body>;
@@ -26,13 +30,18 @@ pub struct Client {
     #[cfg(feature = "twitch")]
     twitch: bathbot_model::TwitchData,
     github_auth: Box<str>,
+    pub(crate) osu_auth: OsuApiAuth,
+    pub(crate) osu_servers: Box<[(Box<str>, OsuApiAuth)]>,
     ratelimiters: Ratelimiters,
+    conditional: ConditionalCache,
 }
 
 impl Client {
     pub async fn new(
         #[cfg(feature = "twitch")] (twitch_client_id, twitch_token): (&str, &str),
         github_token: &str,
+        (osu_client_id, osu_client_secret): (u64, &str),
+        osu_servers: &[OsuServer],
     ) -> Result<Self> {
         ClientMetrics::init();
 
@@ -56,9 +65,15 @@ impl Client {
         Ok(Self {
             client,
             ratelimiters: Ratelimiters::new(),
+            conditional: ConditionalCache::new(),
             #[cfg(feature = "twitch")]
             twitch,
             github_auth: format!("Bearer {github_token}").into_boxed_str(),
+            osu_auth: OsuApiAuth::bancho(osu_client_id, osu_client_secret),
+            osu_servers: osu_servers
+                .iter()
+                .map(|server| (server.name.clone(), OsuApiAuth::server(server)))
+                .collect(),
         })
     }
 
@@ -93,9 +108,25 @@ impl Client {
                     http::header::AUTHORIZATION,
                     format!("Bearer {}", self.twitch.oauth_token),
                 ),
+            Site::OsuApi | Site::OsuPrivateServer => {
+                let auth = self.osu_api_auth(url);
+
+                req.header(AUTHORIZATION, self.osu_api_authorization(auth).await?.as_ref())
+                    .header("x-api-version", OSU_API_VERSION)
+            }
             _ => req,
         };
 
+        let cached = site
+            .is_conditional()
+            .then(|| self.conditional.validators(url))
+            .flatten();
+
+        let req = match cached {
+            Some(ref validators) => validators.apply(req),
+            None => req,
+        };
+
         let req = req
             .body(Body::default())
             .wrap_err("failed to build GET request")?;
@@ -106,11 +137,30 @@ impl Client {
             .wrap_err("failed to receive GET response")?;
 
         let status = response.status();
+
+        let not_modified = (status == StatusCode::NOT_MODIFIED)
+            .then(|| self.conditional.not_modified(url))
+            .flatten();
+
+        if let Some(bytes) = not_modified {
+            ClientMetrics::observe(site, status, start.elapsed());
+
+            return Ok(bytes);
+        }
+
+        let validators = site
+            .is_conditional()
+            .then(|| Validators::from_headers(response.headers()));
+
         let bytes_res = Self::error_for_status(response, url).await;
 
         let latency = start.elapsed();
         ClientMetrics::observe(site, status, latency);
 
+        if let (Some(validators), Ok(bytes)) = (validators, &bytes_res) {
+            self.conditional.insert(url, validators, bytes);
+        }
+
         bytes_res
     }
 
@@ -208,7 +258,10 @@ impl Client {
             400 => Err(ClientError::BadRequest),
             404 => Err(ClientError::NotFound),
             429 => Err(ClientError::Ratelimited),
-            _ => Err(eyre!("Failed with status code {status} when requesting url {url}").into()),
+            _ => Err(ClientError::Status {
+                status,
+                url: Box::from(url),
+            }),
         }
     }
 
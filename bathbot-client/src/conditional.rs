@@ -0,0 +1,127 @@
+use std::{collections::HashMap, sync::Mutex, time::Instant};
+
+use bytes::Bytes;
+use http::{
+    HeaderMap,
+    header::{ETAG, HeaderValue, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED},
+    request::Builder as RequestBuilder,
+};
+
+/// Upper limit for the total size of all cached response bodies.
+const MAX_BYTES: usize = 32 * 1024 * 1024;
+
+/// Response bodies that are too large to be worth keeping around.
+const MAX_ENTRY_BYTES: usize = 4 * 1024 * 1024;
+
+/// Remembers validators of responses so that repeated requests to the same url
+/// can be made conditional.
+///
+/// If the server responds with `304 Not Modified`, the cached body is used
+/// instead. Least recently used entries are evicted once the total size
+/// exceeds [`MAX_BYTES`].
+pub(crate) struct ConditionalCache {
+    inner: Mutex<Entries>,
+}
+
+#[derive(Default)]
+struct Entries {
+    map: HashMap<Box<str>, CachedResponse>,
+    total_bytes: usize,
+}
+
+struct CachedResponse {
+    validators: Validators,
+    bytes: Bytes,
+    last_used: Instant,
+}
+
+#[derive(Clone, Default)]
+pub(crate) struct Validators {
+    etag: Option<HeaderValue>,
+    last_modified: Option<HeaderValue>,
+}
+
+impl Validators {
+    pub(crate) fn from_headers(headers: &HeaderMap) -> Self {
+        Self {
+            etag: headers.get(ETAG).cloned(),
+            last_modified: headers.get(LAST_MODIFIED).cloned(),
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.etag.is_none() && self.last_modified.is_none()
+    }
+
+    /// Add the conditional headers to a request.
+    pub(crate) fn apply(&self, mut req: RequestBuilder) -> RequestBuilder {
+        if let Some(ref etag) = self.etag {
+            req = req.header(IF_NONE_MATCH, etag.clone());
+        }
+
+        if let Some(ref last_modified) = self.last_modified {
+            req = req.header(IF_MODIFIED_SINCE, last_modified.clone());
+        }
+
+        req
+    }
+}
+
+impl ConditionalCache {
+    pub(crate) fn new() -> Self {
+        Self {
+            inner: Mutex::new(Entries::default()),
+        }
+    }
+
+    /// Validators of the cached response for the url, if any.
+    pub(crate) fn validators(&self, url: &str) -> Option<Validators> {
+        let entries = self.inner.lock().unwrap();
+
+        entries.map.get(url).map(|entry| entry.validators.clone())
+    }
+
+    /// The cached body after the server responded with `304 Not Modified`.
+    pub(crate) fn not_modified(&self, url: &str) -> Option<Bytes> {
+        let mut entries = self.inner.lock().unwrap();
+        let entry = entries.map.get_mut(url)?;
+        entry.last_used = Instant::now();
+
+        Some(entry.bytes.clone())
+    }
+
+    /// Store a successful response if the server provided validators for it.
+    pub(crate) fn insert(&self, url: &str, validators: Validators, bytes: &Bytes) {
+        if validators.is_empty() || bytes.len() > MAX_ENTRY_BYTES {
+            return;
+        }
+
+        let mut entries = self.inner.lock().unwrap();
+
+        let entry = CachedResponse {
+            validators,
+            bytes: bytes.clone(),
+            last_used: Instant::now(),
+        };
+
+        entries.total_bytes += bytes.len();
+
+        if let Some(prev) = entries.map.insert(Box::from(url), entry) {
+            entries.total_bytes -= prev.bytes.len();
+        }
+
+        while entries.total_bytes > MAX_BYTES {
+            let oldest = entries
+                .map
+                .iter()
+                .min_by_key(|(_, entry)| entry.last_used)
+                .map(|(url, _)| url.clone());
+
+            let Some(removed) = oldest.and_then(|url| entries.map.remove(&url)) else {
+                break;
+            };
+
+            entries.total_bytes -= removed.bytes.len();
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use eyre::Report;
+use hyper::StatusCode;
 use thiserror::Error;
 
 #[derive(Debug, Error)]
@@ -9,6 +10,8 @@ pub enum ClientError {
     NotFound,
     #[error("status code 429 - ratelimited")]
     Ratelimited,
+    #[error("status code {status} when requesting url {url}")]
+    Status { status: StatusCode, url: Box<str> },
     #[error(transparent)]
     Report(#[from] Report),
 }
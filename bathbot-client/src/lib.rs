@@ -5,6 +5,7 @@ extern crate eyre;
 extern crate tracing;
 
 mod client;
+mod conditional;
 mod discord;
 mod error;
 mod github;
@@ -13,6 +14,7 @@ mod miss_analyzer;
 mod multipart;
 mod osekai;
 mod osu;
+mod osu_api;
 mod osustats;
 mod osutrack;
 mod relax;
@@ -22,6 +24,10 @@ mod snipe;
 mod twitch;
 
 use self::site::{Ratelimiters, Site};
-pub use self::{client::Client, error::ClientError};
+pub use self::{
+    client::Client,
+    error::ClientError,
+    osu_api::{OsuServer, OsuServerScores},
+};
 
 static MY_USER_AGENT: &str = env!("CARGO_PKG_NAME");
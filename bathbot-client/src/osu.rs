@@ -90,6 +90,19 @@ impl Client {
             .map_err(Report::new)
     }
 
+    pub async fn get_user_cover(&self, url: &str) -> Result<Bytes> {
+        self.make_get_request(url, Site::OsuUserCover)
+            .await
+            .map_err(Report::new)
+    }
+
+    /// Audio preview of a mapset as mp3
+    pub async fn get_map_preview(&self, mapset_id: u32) -> Result<Bytes, ClientError> {
+        let url = format!("https://b.ppy.sh/preview/{mapset_id}.mp3");
+
+        self.make_get_request(&url, Site::OsuMapPreview).await
+    }
+
     pub async fn get_map_file(&self, map_id: u32) -> Result<Bytes, ClientError> {
         let url = format!("{OSU_BASE}osu/{map_id}");
 
@@ -0,0 +1,354 @@
+use std::{
+    fmt::Write,
+    time::{Duration, Instant},
+};
+
+use bathbot_util::constants::OSU_BASE;
+use eyre::WrapErr;
+use rosu_v2::{
+    prelude::{BeatmapsetExtended, GameMode, Score, UserExtended},
+    request::UserId,
+};
+use serde::{Deserialize, Serialize, de::DeserializeOwned};
+use tokio::sync::Mutex;
+
+use crate::{Client, ClientError, Site};
+
+/// Must match the version that rosu-v2 requests so that responses deserialize
+/// into its models.
+pub(crate) const OSU_API_VERSION: &str = "20220705";
+
+/// Tokens are renewed this long before they expire.
+const TOKEN_EXPIRE_BUFFER: Duration = Duration::from_secs(60);
+
+/// A private osu! server that implements the osu!api v2.
+#[derive(Debug)]
+pub struct OsuServer {
+    /// Name through which guilds refer to the server
+    pub name: Box<str>,
+    /// Url that API paths such as `api/v2/users` are appended to
+    pub base_url: Box<str>,
+    pub client_id: u64,
+    pub client_secret: Box<str>,
+}
+
+/// Which scores of a user to request from a private server.
+#[derive(Copy, Clone, Debug)]
+pub enum OsuServerScores {
+    Best,
+    Recent { include_fails: bool },
+    Pinned,
+}
+
+/// Client credentials for osu!api requests that don't go through rosu-v2.
+///
+/// rosu-v2 can't attach custom headers nor target other servers so lookups
+/// that benefit from conditional requests as well as requests to private
+/// servers are sent through here.
+pub(crate) struct OsuApiAuth {
+    base_url: Box<str>,
+    site: Site,
+    client_id: u64,
+    client_secret: Box<str>,
+    token: Mutex<Option<OsuToken>>,
+}
+
+struct OsuToken {
+    /// Value of the authorization header
+    authorization: Box<str>,
+    expires_at: Instant,
+}
+
+impl OsuApiAuth {
+    pub(crate) fn bancho(client_id: u64, client_secret: &str) -> Self {
+        Self::new(Box::from(OSU_BASE), Site::OsuApi, client_id, client_secret)
+    }
+
+    pub(crate) fn server(server: &OsuServer) -> Self {
+        let mut base_url = String::from(server.base_url.as_ref());
+
+        if !base_url.ends_with('/') {
+            base_url.push('/');
+        }
+
+        Self::new(
+            base_url.into_boxed_str(),
+            Site::OsuPrivateServer,
+            server.client_id,
+            &server.client_secret,
+        )
+    }
+
+    fn new(base_url: Box<str>, site: Site, client_id: u64, client_secret: &str) -> Self {
+        Self {
+            base_url,
+            site,
+            client_id,
+            client_secret: Box::from(client_secret),
+            token: Mutex::new(None),
+        }
+    }
+}
+
+impl Client {
+    /// Whether a private server with the given name is configured.
+    pub fn has_osu_server(&self, server: &str) -> bool {
+        self.osu_server(server).is_ok()
+    }
+
+    /// Names of all configured private servers.
+    pub fn osu_servers(&self) -> impl Iterator<Item = &str> {
+        self.osu_servers.iter().map(|(name, _)| name.as_ref())
+    }
+
+    fn osu_server(&self, server: &str) -> Result<&OsuApiAuth, ClientError> {
+        self.osu_servers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(server))
+            .map(|(_, auth)| auth)
+            .ok_or_else(|| ClientError::Report(eyre!("Unknown osu server `{server}`")))
+    }
+
+    /// The credentials of the server that the url targets.
+    pub(crate) fn osu_api_auth(&self, url: &str) -> &OsuApiAuth {
+        self.osu_servers
+            .iter()
+            .map(|(_, auth)| auth)
+            .find(|auth| url.starts_with(auth.base_url.as_ref()))
+            .unwrap_or(&self.osu_auth)
+    }
+
+    /// Wait until the ratelimit of the primary osu!api credentials allows
+    /// another request.
+    ///
+    /// Requests of rosu-v2's client with the same credentials must go through
+    /// here as well so that both stay within a single budget.
+    pub async fn osu_api_ratelimit(&self) {
+        self.ratelimit(Site::OsuApi).await
+    }
+
+    /// Authorization header value for osu!api requests.
+    ///
+    /// The token is requested lazily and renewed shortly before it expires.
+    pub(crate) async fn osu_api_authorization(
+        &self,
+        auth: &OsuApiAuth,
+    ) -> Result<Box<str>, ClientError> {
+        let mut token = auth.token.lock().await;
+
+        match *token {
+            Some(ref token) if token.expires_at > Instant::now() => {
+                return Ok(token.authorization.clone());
+            }
+            _ => {}
+        }
+
+        #[derive(Serialize)]
+        struct Body<'s> {
+            client_id: u64,
+            client_secret: &'s str,
+            grant_type: &'static str,
+            scope: &'static str,
+        }
+
+        #[derive(Deserialize)]
+        struct TokenResponse {
+            access_token: Box<str>,
+            expires_in: u64,
+        }
+
+        let body = Body {
+            client_id: auth.client_id,
+            client_secret: &auth.client_secret,
+            grant_type: "client_credentials",
+            scope: "public",
+        };
+
+        let url = format!("{}oauth/token", auth.base_url);
+        let json = serde_json::to_vec(&body).unwrap();
+        let bytes = self.make_json_post_request(url, auth.site, json).await?;
+
+        let response: TokenResponse = serde_json::from_slice(&bytes)
+            .wrap_err("Failed to deserialize osu!api token response")?;
+
+        let expires_in = Duration::from_secs(response.expires_in);
+        let authorization = format!("Bearer {}", response.access_token).into_boxed_str();
+
+        *token = Some(OsuToken {
+            authorization: authorization.clone(),
+            expires_at: Instant::now() + expires_in.saturating_sub(TOKEN_EXPIRE_BUFFER),
+        });
+
+        Ok(authorization)
+    }
+
+    /// Request a user, revalidating a previous response if possible.
+    pub async fn get_osu_user(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+    ) -> Result<UserExtended, ClientError> {
+        self.get_osu_user_inner(&self.osu_auth, &UserId::Id(user_id), mode)
+            .await
+    }
+
+    /// Request a user from a private server.
+    pub async fn get_server_osu_user(
+        &self,
+        server: &str,
+        user_id: &UserId,
+        mode: GameMode,
+    ) -> Result<UserExtended, ClientError> {
+        let auth = self.osu_server(server)?;
+
+        self.get_osu_user_inner(auth, user_id, mode).await
+    }
+
+    async fn get_osu_user_inner(
+        &self,
+        auth: &OsuApiAuth,
+        user_id: &UserId,
+        mode: GameMode,
+    ) -> Result<UserExtended, ClientError> {
+        let mut url = format!("{}api/v2/users/", auth.base_url);
+
+        match user_id {
+            UserId::Id(user_id) => {
+                let _ = write!(url, "{user_id}/{mode}?key=id", mode = mode_str(mode));
+            }
+            UserId::Name(name) => {
+                encode_path_segment(&mut url, name.as_str());
+                let _ = write!(url, "/{mode}?key=username", mode = mode_str(mode));
+            }
+        }
+
+        let bytes = self.make_get_request(url, auth.site).await?;
+
+        deserialize(&bytes, "osu user")
+    }
+
+    /// Request scores of a user from a private server.
+    pub async fn get_server_user_scores(
+        &self,
+        server: &str,
+        user_id: u32,
+        scores: OsuServerScores,
+        mode: GameMode,
+        limit: usize,
+        offset: usize,
+    ) -> Result<Vec<Score>, ClientError> {
+        let auth = self.osu_server(server)?;
+
+        let kind = match scores {
+            OsuServerScores::Best => "best",
+            OsuServerScores::Recent { .. } => "recent",
+            OsuServerScores::Pinned => "pinned",
+        };
+
+        let mut url = format!(
+            "{base}api/v2/users/{user_id}/scores/{kind}?mode={mode}&limit={limit}&offset={offset}",
+            base = auth.base_url,
+            mode = mode_str(mode),
+        );
+
+        if let OsuServerScores::Recent { include_fails } = scores {
+            let _ = write!(url, "&include_fails={}", u8::from(include_fails));
+        }
+
+        let bytes = self.make_get_request(url, auth.site).await?;
+
+        deserialize(&bytes, "osu scores")
+    }
+
+    /// Request all scores of a user on a map from a private server.
+    pub async fn get_server_user_map_scores(
+        &self,
+        server: &str,
+        map_id: u32,
+        user_id: u32,
+        mode: GameMode,
+    ) -> Result<Vec<Score>, ClientError> {
+        #[derive(Deserialize)]
+        struct Scores {
+            scores: Vec<Score>,
+        }
+
+        let auth = self.osu_server(server)?;
+
+        let url = format!(
+            "{base}api/v2/beatmaps/{map_id}/scores/users/{user_id}/all?mode={mode}",
+            base = auth.base_url,
+            mode = mode_str(mode),
+        );
+
+        let bytes = self.make_get_request(url, auth.site).await?;
+
+        deserialize::<Scores>(&bytes, "osu map scores").map(|scores| scores.scores)
+    }
+
+    /// Request the mapset of a map, revalidating a previous response if
+    /// possible.
+    pub async fn get_osu_mapset_from_map_id(
+        &self,
+        map_id: u32,
+    ) -> Result<BeatmapsetExtended, ClientError> {
+        let url = format!("{OSU_BASE}api/v2/beatmapsets/lookup?beatmap_id={map_id}");
+        let bytes = self.make_get_request(url, Site::OsuApi).await?;
+
+        deserialize(&bytes, "osu mapset")
+    }
+
+    /// Request a mapset, revalidating a previous response if possible.
+    pub async fn get_osu_mapset(&self, mapset_id: u32) -> Result<BeatmapsetExtended, ClientError> {
+        let url = format!("{OSU_BASE}api/v2/beatmapsets/{mapset_id}");
+        let bytes = self.make_get_request(url, Site::OsuApi).await?;
+
+        deserialize(&bytes, "osu mapset")
+    }
+}
+
+fn deserialize<T: DeserializeOwned>(bytes: &[u8], kind: &str) -> Result<T, ClientError> {
+    serde_json::from_slice(bytes)
+        .wrap_err_with(|| {
+            let body = String::from_utf8_lossy(bytes);
+
+            format!("Failed to deserialize {kind}: {body}")
+        })
+        .map_err(ClientError::Report)
+}
+
+fn mode_str(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Osu => "osu",
+        GameMode::Taiko => "taiko",
+        GameMode::Catch => "fruits",
+        GameMode::Mania => "mania",
+    }
+}
+
+/// Percent-encode everything but unreserved characters.
+fn encode_path_segment(url: &mut String, segment: &str) {
+    for byte in segment.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'_' | b'.' | b'~') {
+            url.push(byte as char);
+        } else {
+            let _ = write!(url, "%{byte:02X}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encodes_usernames() {
+        let mut url = String::new();
+        encode_path_segment(&mut url, "freddie benson");
+        assert_eq!(url, "freddie%20benson");
+
+        let mut url = String::new();
+        encode_path_segment(&mut url, "[-Mr_X-]");
+        assert_eq!(url, "%5B-Mr_X-%5D");
+    }
+}
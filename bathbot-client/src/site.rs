@@ -18,6 +18,26 @@ impl Ratelimiters {
     }
 }
 
+impl Site {
+    /// Whether responses are cached and revalidated through conditional
+    /// requests.
+    ///
+    /// Only resources that are requested repeatedly and rarely change
+    /// qualify, i.e. assets and osu!api user and mapset lookups.
+    pub fn is_conditional(self) -> bool {
+        matches!(
+            self,
+            Self::Flags
+                | Self::OsuApi
+                | Self::OsuAvatar
+                | Self::OsuBadge
+                | Self::OsuMapsetCover
+                | Self::OsuMedalIcon
+                | Self::OsuUserCover
+        )
+    }
+}
+
 /// List of `{variant name} -> {allowed requests per second}`
 macro_rules! sites {
     ( $( $variant:ident -> $per_second:literal, )+ ) => {
@@ -58,14 +78,19 @@ sites! {
     KittenRoleplay -> 5,
     MissAnalyzer -> 5,
     Osekai -> 2,
+    // Shared with rosu-v2's client of the same credentials
+    OsuApi -> 15,
     OsuAvatar -> 10,
     OsuBadge -> 10,
     OsuMapFile -> 2,
+    OsuMapPreview -> 5,
     OsuMapsetCover -> 10,
     OsuMedalIcon -> 25,
+    OsuPrivateServer -> 5,
     OsuProfile -> 1,
     OsuStats -> 2,
     OsuTrack -> 2,
+    OsuUserCover -> 10,
     Relax -> 2,
     Respektive -> 1,
     Twitch -> 5,
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+
+use crate::OsuStatsBestScore;
+
+/// Mapsets on which multiple users set one of the best scores of a timeframe.
+///
+/// Those are the maps that currently hand out the most pp so they're
+/// commonly known as farm sets.
+pub struct FarmSets {
+    /// Sorted by mapset id
+    sets: Vec<FarmSet>,
+}
+
+#[derive(Clone, Debug)]
+pub struct FarmSet {
+    pub mapset_id: u32,
+    pub name: Box<str>,
+    /// Amount of distinct users with a best score on the mapset
+    pub users: usize,
+}
+
+/// A mapset needs best scores of at least this many distinct users to be
+/// considered a farm set.
+const MIN_USERS: usize = 2;
+
+impl FarmSets {
+    /// Derive the farm sets from osu!stats' best scores of a timeframe.
+    pub fn new(scores: &[OsuStatsBestScore]) -> Self {
+        let plays = scores.iter().map(|score| FarmPlay {
+            mapset_id: score.map.mapset_id,
+            user_id: score.user.user_id,
+            artist: &score.map.artist,
+            title: &score.map.title,
+        });
+
+        Self::from_plays(plays)
+    }
+
+    fn from_plays<'a>(plays: impl Iterator<Item = FarmPlay<'a>>) -> Self {
+        let mut mapsets = HashMap::<u32, (FarmPlay<'a>, Vec<u32>)>::new();
+
+        for play in plays {
+            let (_, users) = mapsets.entry(play.mapset_id).or_insert_with(|| (play, Vec::new()));
+
+            if !users.contains(&play.user_id) {
+                users.push(play.user_id);
+            }
+        }
+
+        mapsets
+            .into_values()
+            .filter(|(_, users)| users.len() >= MIN_USERS)
+            .map(|(play, users)| FarmSet {
+                mapset_id: play.mapset_id,
+                name: format!("{} - {}", play.artist, play.title).into_boxed_str(),
+                users: users.len(),
+            })
+            .collect()
+    }
+
+    /// Returns the farm set with the given mapset id, if it is one.
+    pub fn get(&self, mapset_id: u32) -> Option<&FarmSet> {
+        self.sets
+            .binary_search_by_key(&mapset_id, |set| set.mapset_id)
+            .ok()
+            .map(|idx| &self.sets[idx])
+    }
+}
+
+impl FromIterator<FarmSet> for FarmSets {
+    fn from_iter<I: IntoIterator<Item = FarmSet>>(iter: I) -> Self {
+        let mut sets: Vec<_> = iter.into_iter().collect();
+        sets.sort_unstable_by_key(|set| set.mapset_id);
+
+        Self { sets }
+    }
+}
+
+#[derive(Copy, Clone)]
+struct FarmPlay<'a> {
+    mapset_id: u32,
+    user_id: u32,
+    artist: &'a str,
+    title: &'a str,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn play(mapset_id: u32, user_id: u32) -> FarmPlay<'static> {
+        FarmPlay {
+            mapset_id,
+            user_id,
+            artist: "xi",
+            title: "FREEDOM DiVE",
+        }
+    }
+
+    #[test]
+    fn requires_distinct_users() {
+        let plays = [
+            play(39804, 1),
+            play(39804, 2),
+            play(292301, 1),
+            play(292301, 1),
+            play(41823, 3),
+        ];
+
+        let sets = FarmSets::from_plays(plays.into_iter());
+
+        assert_eq!(
+            sets.get(39804).map(|set| set.name.as_ref()),
+            Some("xi - FREEDOM DiVE")
+        );
+        assert_eq!(sets.get(39804).map(|set| set.users), Some(2));
+        assert!(sets.get(292301).is_none());
+        assert!(sets.get(41823).is_none());
+    }
+
+    #[test]
+    fn sorted_by_mapset_id() {
+        let plays = [3, 1, 2].into_iter().flat_map(|id| [play(id, 1), play(id, 2)]);
+        let sets = FarmSets::from_plays(plays);
+
+        assert!(
+            sets.sets
+                .windows(2)
+                .all(|pair| pair[0].mapset_id < pair[1].mapset_id)
+        );
+    }
+}
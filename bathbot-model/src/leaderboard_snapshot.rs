@@ -0,0 +1,6 @@
+/// Entry of a map leaderboard that was stored to compare against later.
+#[derive(rkyv::Archive, rkyv::Serialize)]
+pub struct LeaderboardSnapshotEntry {
+    pub user_id: u32,
+    pub score_id: u64,
+}
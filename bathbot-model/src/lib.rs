@@ -1,10 +1,12 @@
 mod country_code;
 mod deser;
 mod either;
+mod farm_sets;
 mod games;
 mod github;
 mod huismetbenen;
 mod kittenroleplay;
+mod leaderboard_snapshot;
 mod osekai;
 mod osu;
 mod osu_stats;
@@ -25,8 +27,8 @@ pub mod twilight;
 pub mod rkyv_util;
 
 pub use self::{
-    country_code::*, deser::ModeAsSeed, either::Either, games::*, github::*, huismetbenen::*,
-    kittenroleplay::*, osekai::*, osu::*, osu_stats::*, osutrack::*,
-    personal_best::PersonalBestIndex, ranking_entries::*, relax::*, respektive::*, score_slim::*,
-    twitch::*, user_stats::*,
+    country_code::*, deser::ModeAsSeed, either::Either, farm_sets::*, games::*, github::*,
+    huismetbenen::*, kittenroleplay::*, leaderboard_snapshot::*, osekai::*, osu::*, osu_stats::*,
+    osutrack::*, personal_best::PersonalBestIndex, ranking_entries::*, relax::*, respektive::*,
+    score_slim::*, twitch::*, user_stats::*,
 };
@@ -10,6 +10,8 @@ use twilight_model::{
     user::UserFlags,
 };
 
+use crate::rosu_v2::user::Playstyles;
+
 /// Used to archive flag type such as [`Permissions`] or [`MemberFlags`].
 ///
 /// # Example
@@ -51,9 +53,9 @@ macro_rules! impl_bitflags {
             }
         }
 
-        impl<D: Fallible + ?Sized> DeserializeWith<Archived<u64>, $ty, D> for BitflagsRkyv {
+        impl<D: Fallible + ?Sized> DeserializeWith<Archived<$ar>, $ty, D> for BitflagsRkyv {
             fn deserialize_with(
-                archived: &Archived<u64>,
+                archived: &Archived<$ar>,
                 _: &mut D,
             ) -> Result<$ty, <D as Fallible>::Error> {
                 Ok(<$ty>::from_bits_truncate((*archived).into()))
@@ -67,5 +69,6 @@ impl_bitflags!(ChannelFlags: u64);
 impl_bitflags!(MemberFlags: u64);
 impl_bitflags!(MessageFlags: u64);
 impl_bitflags!(Permissions: u64);
+impl_bitflags!(Playstyles: u8);
 impl_bitflags!(SystemChannelFlags: u64);
 impl_bitflags!(UserFlags: u64);
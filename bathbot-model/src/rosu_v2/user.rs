@@ -1,5 +1,5 @@
 use ::rosu_v2::model::user::{
-    Badge, GradeCounts, MedalCompact, MonthlyCount, Team, UserExtended, UserHighestRank,
+    Badge, GradeCounts, MedalCompact, MonthlyCount, Playstyle, Team, UserExtended, UserHighestRank,
     UserKudosu, UserLevel, UserStatistics,
 };
 use bathbot_util::osu::UserStats;
@@ -19,7 +19,7 @@ use time::{Date, OffsetDateTime};
 use crate::{
     Either,
     rkyv_util::{
-        DerefAsString, MapUnwrapOrDefault, UnwrapOrDefault,
+        BitflagsRkyv, DerefAsString, MapUnwrapOrDefault, UnwrapOrDefault,
         time::{DateRkyv, DateTimeRkyv},
     },
 };
@@ -129,11 +129,40 @@ impl Niching<ArchivedTeam> for TeamRkyv {
     }
 }
 
+bitflags::bitflags! {
+    #[derive(Copy, Clone, Default)]
+    pub struct Playstyles: u8 {
+        const MOUSE =    1 << 0;
+        const KEYBOARD = 1 << 1;
+        const TABLET =   1 << 2;
+        const TOUCH =    1 << 3;
+    }
+}
+
+impl Playstyles {
+    pub fn new(playstyles: Option<&[Playstyle]>) -> Self {
+        playstyles
+            .unwrap_or_default()
+            .iter()
+            .fold(Self::empty(), |flags, playstyle| {
+                flags
+                    | match playstyle {
+                        Playstyle::Mouse => Self::MOUSE,
+                        Playstyle::Keyboard => Self::KEYBOARD,
+                        Playstyle::Tablet => Self::TABLET,
+                        Playstyle::Touch => Self::TOUCH,
+                    }
+            })
+    }
+}
+
 #[derive(Clone, Archive, Serialize)]
 pub struct User {
     #[rkyv(with = DerefAsString)]
     pub avatar_url: Box<str>,
     #[rkyv(with = DerefAsString)]
+    pub cover_url: Box<str>,
+    #[rkyv(with = DerefAsString)]
     pub country_code: CountryCode,
     #[rkyv(with = DateTimeRkyv)]
     pub join_date: OffsetDateTime,
@@ -171,6 +200,9 @@ pub struct User {
     pub daily_challenge: DailyChallengeUserStatistics,
     #[rkyv(with = MapNiche<TeamRkyv, TeamRkyv>)]
     pub team: Option<Team>,
+    pub support_level: u8,
+    #[rkyv(with = BitflagsRkyv)]
+    pub playstyles: Playstyles,
 }
 
 impl ArchiveWith<UserExtended> for User {
@@ -181,6 +213,7 @@ impl ArchiveWith<UserExtended> for User {
     fn resolve_with(user: &UserExtended, resolver: Self::Resolver, out: Place<Self::Archived>) {
         munge!(let ArchivedUser {
             avatar_url,
+            cover_url,
             country_code,
             join_date,
             kudosu,
@@ -205,9 +238,12 @@ impl ArchiveWith<UserExtended> for User {
             medals,
             daily_challenge,
             team,
+            support_level,
+            playstyles,
         } = out);
 
         ArchivedString::resolve_from_str(&user.avatar_url, resolver.avatar_url, avatar_url);
+        ArchivedString::resolve_from_str(&user.cover.url, resolver.cover_url, cover_url);
         DerefAsString::resolve_with(&user.country_code, resolver.country_code, country_code);
         DateTimeRkyv::resolve_with(&user.join_date, resolver.join_date, join_date);
         UserKudosuRkyv::resolve_with(&user.kudosu, resolver.kudosu, kudosu);
@@ -292,6 +328,12 @@ impl ArchiveWith<UserExtended> for User {
             daily_challenge,
         );
         MapNiche::<TeamRkyv, TeamRkyv>::resolve_with(&user.team, resolver.team, team);
+        UnwrapOrDefault::resolve_with(&user.support_level, resolver.support_level, support_level);
+        BitflagsRkyv::resolve_with(
+            &Playstyles::new(user.playstyle.as_deref()),
+            resolver.playstyles,
+            playstyles,
+        );
     }
 }
 
@@ -301,6 +343,7 @@ impl<S: Fallible<Error: Source> + Writer + Allocator + ?Sized> SerializeWith<Use
     fn serialize_with(user: &UserExtended, serializer: &mut S) -> Result<Self::Resolver, S::Error> {
         Ok(UserResolver {
             avatar_url: ArchivedString::serialize_from_str(&user.avatar_url, serializer)?,
+            cover_url: ArchivedString::serialize_from_str(&user.cover.url, serializer)?,
             country_code: DerefAsString::serialize_with(&user.country_code, serializer)?,
             join_date: DateTimeRkyv::serialize_with(&user.join_date, serializer)?,
             kudosu: UserKudosuRkyv::serialize_with(&user.kudosu, serializer)?,
@@ -370,6 +413,11 @@ impl<S: Fallible<Error: Source> + Writer + Allocator + ?Sized> SerializeWith<Use
                 serializer,
             )?,
             team: MapNiche::<TeamRkyv, TeamRkyv>::serialize_with(&user.team, serializer)?,
+            support_level: UnwrapOrDefault::serialize_with(&user.support_level, serializer)?,
+            playstyles: BitflagsRkyv::serialize_with(
+                &Playstyles::new(user.playstyle.as_deref()),
+                serializer,
+            )?,
         })
     }
 }
@@ -378,6 +426,7 @@ impl From<UserExtended> for User {
     fn from(user: UserExtended) -> Self {
         Self {
             avatar_url: user.avatar_url.into_boxed_str(),
+            cover_url: user.cover.url.into_boxed_str(),
             country_code: user.country_code,
             join_date: user.join_date,
             kudosu: user.kudosu,
@@ -404,6 +453,8 @@ impl From<UserExtended> for User {
             medals: user.medals.unwrap_or_default(),
             daily_challenge: user.daily_challenge_stats,
             team: user.team,
+            support_level: user.support_level.unwrap_or_default(),
+            playstyles: Playstyles::new(user.playstyle.as_deref()),
         }
     }
 }
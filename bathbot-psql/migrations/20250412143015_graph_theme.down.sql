@@ -0,0 +1 @@
+ALTER TABLE user_configs DROP COLUMN graph_theme;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE user_configs DROP COLUMN accessibility;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE user_configs ADD COLUMN accessibility BOOLEAN;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE user_configs DROP COLUMN data_saver;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE user_configs ADD COLUMN data_saver BOOLEAN;
\ No newline at end of file
@@ -0,0 +1,8 @@
+-- Unverified links that a guild imported for its members.
+-- They only apply within that guild until the member links themselves.
+CREATE TABLE IF NOT EXISTS imported_osu_links (
+    guild_id   INT8 NOT NULL,
+    discord_id INT8 NOT NULL,
+    osu_id     INT4 NOT NULL,
+    PRIMARY KEY (guild_id, discord_id)
+);
@@ -0,0 +1 @@
+ALTER TABLE user_configs DROP COLUMN profile_badges;
\ No newline at end of file
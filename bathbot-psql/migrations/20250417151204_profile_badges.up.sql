@@ -0,0 +1 @@
+ALTER TABLE user_configs ADD COLUMN profile_badges BOOLEAN;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN osu_server TEXT;
@@ -0,0 +1 @@
+DROP TABLE osu_rank_snapshots;
\ No newline at end of file
@@ -0,0 +1,7 @@
+CREATE TABLE IF NOT EXISTS osu_rank_snapshots (
+    user_id     INT4 NOT NULL,
+    gamemode    INT2 NOT NULL,
+    date        DATE NOT NULL,
+    global_rank INT4 NOT NULL,
+    PRIMARY KEY (user_id, gamemode, date)
+);
\ No newline at end of file
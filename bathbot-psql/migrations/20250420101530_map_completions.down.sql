@@ -0,0 +1 @@
+DROP TABLE osu_map_completions;
\ No newline at end of file
@@ -0,0 +1,10 @@
+CREATE TABLE IF NOT EXISTS osu_map_completions (
+    score_id   INT8 NOT NULL,
+    map_id     INT4 NOT NULL,
+    gamemode   INT2 NOT NULL,
+    passed     BOOLEAN NOT NULL,
+    completion INT2 NOT NULL,
+    PRIMARY KEY (score_id)
+);
+
+CREATE INDEX IF NOT EXISTS osu_map_completions_map_idx ON osu_map_completions (map_id, gamemode);
@@ -0,0 +1 @@
+DROP TABLE user_simulate_presets;
\ No newline at end of file
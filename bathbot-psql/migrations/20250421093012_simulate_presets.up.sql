@@ -0,0 +1,14 @@
+CREATE TABLE IF NOT EXISTS user_simulate_presets (
+    discord_id   INT8 NOT NULL,
+    name         VARCHAR(32) NOT NULL,
+    mods         VARCHAR(64),
+    acc          FLOAT4,
+    misses       INT4,
+    clock_rate   FLOAT8,
+    ar           FLOAT4,
+    cs           FLOAT4,
+    hp           FLOAT4,
+    od           FLOAT4,
+    set_on_lazer BOOLEAN NOT NULL,
+    PRIMARY KEY (discord_id, name)
+);
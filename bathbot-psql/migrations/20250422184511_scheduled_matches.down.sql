@@ -0,0 +1 @@
+DROP TABLE scheduled_matches;
\ No newline at end of file
@@ -0,0 +1,11 @@
+CREATE TABLE IF NOT EXISTS scheduled_matches (
+    match_id   SERIAL PRIMARY KEY,
+    guild_id   INT8 NOT NULL,
+    channel_id INT8 NOT NULL,
+    player1    INT8 NOT NULL,
+    player2    INT8 NOT NULL,
+    start_at   TIMESTAMPTZ NOT NULL,
+    reminded   BOOLEAN NOT NULL DEFAULT FALSE
+);
+
+CREATE INDEX IF NOT EXISTS scheduled_matches_guild_start_idx ON scheduled_matches (guild_id, start_at);
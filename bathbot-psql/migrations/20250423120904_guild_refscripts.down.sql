@@ -0,0 +1 @@
+DROP TABLE guild_refscripts;
\ No newline at end of file
@@ -0,0 +1,5 @@
+CREATE TABLE IF NOT EXISTS guild_refscripts (
+    guild_id INT8 NOT NULL PRIMARY KEY,
+    mappool  TEXT NOT NULL DEFAULT '',
+    template TEXT
+);
@@ -0,0 +1,3 @@
+ALTER TABLE osu_mapsets DROP COLUMN artist_unicode, DROP COLUMN title_unicode;
+ALTER TABLE user_configs DROP COLUMN unicode_titles;
+ALTER TABLE guild_configs DROP COLUMN unicode_titles;
\ No newline at end of file
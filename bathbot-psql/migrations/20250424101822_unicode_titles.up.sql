@@ -0,0 +1,3 @@
+ALTER TABLE osu_mapsets ADD COLUMN artist_unicode VARCHAR(80), ADD COLUMN title_unicode VARCHAR(80);
+ALTER TABLE user_configs ADD COLUMN unicode_titles BOOLEAN;
+ALTER TABLE guild_configs ADD COLUMN unicode_titles BOOLEAN;
\ No newline at end of file
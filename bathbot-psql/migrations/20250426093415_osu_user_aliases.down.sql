@@ -0,0 +1 @@
+DROP TABLE osu_user_aliases;
\ No newline at end of file
@@ -0,0 +1,8 @@
+CREATE TABLE IF NOT EXISTS osu_user_aliases (
+    user_id     INT4 NOT NULL,
+    username    VARCHAR(32) NOT NULL,
+    observed_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, username)
+);
+
+CREATE INDEX osu_user_aliases_name_index ON osu_user_aliases (username);
@@ -0,0 +1 @@
+ALTER TABLE user_configs DROP COLUMN last_seen;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE user_configs ADD COLUMN last_seen TIMESTAMPTZ NOT NULL DEFAULT NOW();
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE user_configs DROP COLUMN card_background, DROP COLUMN card_accent_color;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE user_configs ADD COLUMN card_background INT2, ADD COLUMN card_accent_color INT4;
\ No newline at end of file
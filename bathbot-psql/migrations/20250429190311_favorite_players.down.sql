@@ -0,0 +1 @@
+DROP TABLE user_favorite_players;
\ No newline at end of file
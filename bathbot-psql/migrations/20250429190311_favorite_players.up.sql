@@ -0,0 +1,6 @@
+CREATE TABLE IF NOT EXISTS user_favorite_players (
+    discord_id  INT8 NOT NULL,
+    user_id     INT4 NOT NULL,
+    insert_date TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (discord_id, user_id)
+);
\ No newline at end of file
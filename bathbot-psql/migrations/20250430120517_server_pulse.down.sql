@@ -0,0 +1 @@
+ALTER TABLE guild_configs DROP COLUMN pulse_channel;
\ No newline at end of file
@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN pulse_channel INT8;
\ No newline at end of file
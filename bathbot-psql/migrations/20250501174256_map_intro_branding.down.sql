@@ -0,0 +1 @@
+ALTER TABLE guild_configs DROP COLUMN intro_accent, DROP COLUMN intro_label;
\ No newline at end of file
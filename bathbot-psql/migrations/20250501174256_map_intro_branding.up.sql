@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN intro_accent INT4, ADD COLUMN intro_label TEXT;
\ No newline at end of file
@@ -0,0 +1,3 @@
+DROP TABLE badge_seen;
+DROP TABLE badge_notify_users;
+DROP TABLE badge_notify_channels;
\ No newline at end of file
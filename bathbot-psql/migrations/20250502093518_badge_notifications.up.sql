@@ -0,0 +1,16 @@
+-- Channels that announce new badges of the guild's linked members
+CREATE TABLE IF NOT EXISTS badge_notify_channels (
+    guild_id   INT8 NOT NULL PRIMARY KEY,
+    channel_id INT8 NOT NULL
+);
+
+-- Users that are notified via DM when their linked osu! user gets a new badge
+CREATE TABLE IF NOT EXISTS badge_notify_users (
+    discord_id INT8 NOT NULL PRIMARY KEY
+);
+
+-- Last seen badge ids of each osu! user that is relevant for notifications
+CREATE TABLE IF NOT EXISTS badge_seen (
+    osu_id    INT4 NOT NULL PRIMARY KEY,
+    badge_ids INT4[] NOT NULL
+);
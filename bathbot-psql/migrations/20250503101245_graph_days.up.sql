@@ -0,0 +1 @@
+ALTER TABLE user_configs ADD COLUMN graph_days INT2;
\ No newline at end of file
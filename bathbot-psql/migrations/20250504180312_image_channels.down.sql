@@ -0,0 +1 @@
+ALTER TABLE guild_configs DROP COLUMN image_channels;
\ No newline at end of file
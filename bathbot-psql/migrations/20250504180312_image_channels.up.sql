@@ -0,0 +1 @@
+ALTER TABLE guild_configs ADD COLUMN image_channels INT8[] NOT NULL DEFAULT '{}';
\ No newline at end of file
@@ -0,0 +1,2 @@
+DROP TABLE milestone_seen;
+DROP TABLE milestone_channels;
\ No newline at end of file
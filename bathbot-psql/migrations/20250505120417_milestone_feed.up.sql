@@ -0,0 +1,16 @@
+-- Channels that announce milestones of the guild's linked members
+CREATE TABLE IF NOT EXISTS milestone_channels (
+    guild_id   INT8 NOT NULL PRIMARY KEY,
+    channel_id INT8 NOT NULL
+);
+
+-- Highest milestones that were reached by each osu! user per mode.
+-- A rank of 0 means that no rank milestone was reached yet.
+CREATE TABLE IF NOT EXISTS milestone_seen (
+    osu_id    INT4 NOT NULL,
+    gamemode  INT2 NOT NULL,
+    pp        INT4 NOT NULL,
+    playcount INT4 NOT NULL,
+    rank      INT4 NOT NULL,
+    PRIMARY KEY (osu_id, gamemode)
+);
@@ -0,0 +1,161 @@
+use eyre::{Result, WrapErr};
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, GuildMarker, UserMarker},
+};
+
+use crate::{
+    Database,
+    model::badge_notify::{DbBadgeNotifyChannel, DbSeenBadges},
+};
+
+impl Database {
+    pub async fn select_badge_notify_channels(&self) -> Result<Vec<DbBadgeNotifyChannel>> {
+        let query = sqlx::query_as!(
+            DbBadgeNotifyChannel,
+            r#"
+SELECT 
+  guild_id, 
+  channel_id 
+FROM 
+  badge_notify_channels"#
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    pub async fn upsert_badge_notify_channel(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO badge_notify_channels (guild_id, channel_id) 
+VALUES 
+  ($1, $2) ON CONFLICT (guild_id) DO 
+UPDATE 
+SET 
+  channel_id = $2"#,
+            guild_id.get() as i64,
+            channel_id.get() as i64,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns whether the guild had a notification channel.
+    pub async fn delete_badge_notify_channel(&self, guild_id: Id<GuildMarker>) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  badge_notify_channels 
+WHERE 
+  guild_id = $1"#,
+            guild_id.get() as i64,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    pub async fn select_badge_notify_users(&self) -> Result<Vec<i64>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  discord_id 
+FROM 
+  badge_notify_users"#
+        );
+
+        let rows = query
+            .fetch_all(self)
+            .await
+            .wrap_err("failed to fetch all")?;
+
+        Ok(rows.into_iter().map(|row| row.discord_id).collect())
+    }
+
+    pub async fn insert_badge_notify_user(&self, user_id: Id<UserMarker>) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO badge_notify_users (discord_id) 
+VALUES 
+  ($1) ON CONFLICT (discord_id) DO NOTHING"#,
+            user_id.get() as i64,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns whether the user was subscribed.
+    pub async fn delete_badge_notify_user(&self, user_id: Id<UserMarker>) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  badge_notify_users 
+WHERE 
+  discord_id = $1"#,
+            user_id.get() as i64,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    pub async fn select_seen_badges(&self, osu_ids: &[i32]) -> Result<Vec<DbSeenBadges>> {
+        let query = sqlx::query_as!(
+            DbSeenBadges,
+            r#"
+SELECT 
+  osu_id, 
+  badge_ids 
+FROM 
+  badge_seen 
+WHERE 
+  osu_id = ANY($1)"#,
+            osu_ids,
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    pub async fn upsert_seen_badges(&self, osu_id: u32, badge_ids: &[i32]) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO badge_seen (osu_id, badge_ids) 
+VALUES 
+  ($1, $2) ON CONFLICT (osu_id) DO 
+UPDATE 
+SET 
+  badge_ids = $2"#,
+            osu_id as i32,
+            badge_ids,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+}
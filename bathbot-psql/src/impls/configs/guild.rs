@@ -31,7 +31,13 @@ SELECT
   render_button, 
   allow_custom_skins, 
   hide_medal_solution, 
-  score_data 
+  score_data, 
+  unicode_titles, 
+  pulse_channel, 
+  intro_accent, 
+  intro_label, 
+  image_channels, 
+  osu_server 
 FROM 
   guild_configs"#
         );
@@ -63,6 +69,12 @@ FROM
             allow_custom_skins,
             hide_medal_solution,
             score_data,
+            unicode_titles,
+            pulse_channel,
+            intro_accent,
+            intro_label,
+            image_channels,
+            osu_server,
         } = config;
 
         let authorities = rkyv::util::with_arena(|arena| {
@@ -74,16 +86,26 @@ FROM
             Ok::<_, Report>(writer)
         })?;
 
+        let image_channels: Vec<_> = image_channels
+            .iter()
+            .map(|channel_id| channel_id.get() as i64)
+            .collect();
+
         let query = sqlx::query!(
             r#"
 INSERT INTO guild_configs (
   guild_id, authorities, prefixes, allow_songs, 
   retries, list_size, 
   render_button, allow_custom_skins, 
-  hide_medal_solution, score_data
+  hide_medal_solution, score_data, unicode_titles, 
+  pulse_channel, intro_accent, intro_label, 
+  image_channels, osu_server
 ) 
 VALUES 
-  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+  (
+    $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, 
+    $12, $13, $14, $15, $16
+  )
 ON CONFLICT
   (guild_id)
 DO 
@@ -97,7 +119,13 @@ SET
   render_button = $7, 
   allow_custom_skins = $8, 
   hide_medal_solution = $9, 
-  score_data = $10"#,
+  score_data = $10, 
+  unicode_titles = $11, 
+  pulse_channel = $12, 
+  intro_accent = $13, 
+  intro_label = $14, 
+  image_channels = $15, 
+  osu_server = $16"#,
             guild_id.get() as i64,
             &authorities as &[u8],
             Json(prefixes) as _,
@@ -108,6 +136,12 @@ SET
             *allow_custom_skins,
             hide_medal_solution.map(i16::from),
             score_data.map(i16::from),
+            *unicode_titles,
+            pulse_channel.map(|id| id.get() as i64),
+            intro_accent.map(|color| color as i32),
+            intro_label.as_deref(),
+            &image_channels,
+            osu_server.as_deref(),
         );
 
         query
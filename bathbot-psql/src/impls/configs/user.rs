@@ -3,8 +3,11 @@ use eyre::{Result, WrapErr};
 use futures::StreamExt;
 use rosu_v2::prelude::GameMode;
 use sqlx::types::Json;
-use time::UtcOffset;
-use twilight_model::id::{Id, marker::UserMarker};
+use time::{OffsetDateTime, UtcOffset};
+use twilight_model::id::{
+    Id,
+    marker::{GuildMarker, UserMarker},
+};
 
 use crate::{
     Database,
@@ -28,7 +31,15 @@ SELECT
   twitch_id, 
   timezone_seconds, 
   render_button, 
-  score_data 
+  score_data, 
+  graph_theme, 
+  accessibility, 
+  data_saver, 
+  profile_badges, 
+  unicode_titles, 
+  card_background, 
+  card_accent_color, 
+  graph_days 
 FROM 
   user_configs 
 WHERE 
@@ -262,6 +273,14 @@ FROM
             timezone,
             render_button,
             score_data,
+            graph_theme,
+            accessibility,
+            data_saver,
+            profile_badges,
+            unicode_titles,
+            card_background,
+            card_accent_color,
+            graph_days,
         } = config;
 
         let query = sqlx::query!(
@@ -269,10 +288,13 @@ FROM
 INSERT INTO user_configs (
   discord_id, osu_id, gamemode, twitch_id, 
   retries, score_embed, list_size, 
-  timezone_seconds, render_button, score_data
+  timezone_seconds, render_button, score_data, 
+  graph_theme, accessibility, data_saver, 
+  profile_badges, unicode_titles, card_background, 
+  card_accent_color, graph_days
 ) 
 VALUES 
-  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10) ON CONFLICT (discord_id) DO 
+  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17, $18) ON CONFLICT (discord_id) DO 
 UPDATE 
 SET 
   osu_id = $2, 
@@ -283,7 +305,15 @@ SET
   list_size = $7, 
   timezone_seconds = $8, 
   render_button = $9, 
-  score_data = $10"#,
+  score_data = $10, 
+  graph_theme = $11, 
+  accessibility = $12, 
+  data_saver = $13, 
+  profile_badges = $14, 
+  unicode_titles = $15, 
+  card_background = $16, 
+  card_accent_color = $17, 
+  graph_days = $18"#,
             user_id.get() as i64,
             osu.map(|id| id as i32),
             mode.map(|mode| mode as i16) as Option<i16>,
@@ -294,6 +324,14 @@ SET
             timezone.map(UtcOffset::whole_seconds),
             *render_button,
             score_data.map(i16::from),
+            graph_theme.map(i16::from),
+            *accessibility,
+            *data_saver,
+            *profile_badges,
+            *unicode_titles,
+            card_background.map(i16::from),
+            card_accent_color.map(|color| color as i32),
+            graph_days.map(|days| days as i16),
         );
 
         query
@@ -306,6 +344,228 @@ SET
         Ok(())
     }
 
+    /// Store osu! links that the guild imported for the given discord ids.
+    ///
+    /// Returns how many links were inserted or updated.
+    pub async fn insert_imported_osu_links(
+        &self,
+        guild_id: Id<GuildMarker>,
+        discord_ids: &[i64],
+        osu_ids: &[i32],
+    ) -> Result<u64> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO imported_osu_links (guild_id, discord_id, osu_id) 
+SELECT 
+  $1, 
+  discord_id, 
+  osu_id 
+FROM 
+  UNNEST($2::INT8[], $3::INT4[]) AS links (discord_id, osu_id) ON CONFLICT (guild_id, discord_id) DO 
+UPDATE 
+SET 
+  osu_id = excluded.osu_id"#,
+            guild_id.get() as i64,
+            discord_ids,
+            osu_ids,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected())
+    }
+
+    pub async fn select_imported_osu_id(
+        &self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<Option<u32>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  osu_id 
+FROM 
+  imported_osu_links 
+WHERE 
+  guild_id = $1 
+  AND discord_id = $2"#,
+            guild_id.get() as i64,
+            user_id.get() as i64,
+        );
+
+        let osu_id = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?
+            .map(|row| row.osu_id as u32);
+
+        Ok(osu_id)
+    }
+
+    pub async fn delete_imported_osu_links(&self, user_id: Id<UserMarker>) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  imported_osu_links 
+WHERE 
+  discord_id = $1"#,
+            user_id.get() as i64
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn select_linked_discord_ids(&self, discord_ids: &[i64]) -> Result<Vec<i64>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  discord_id 
+FROM 
+  user_configs 
+WHERE 
+  discord_id = ANY($1) 
+  AND osu_id IS NOT NULL"#,
+            discord_ids,
+        );
+
+        let rows = query
+            .fetch_all(self)
+            .await
+            .wrap_err("failed to fetch all")?;
+
+        Ok(rows.into_iter().map(|row| row.discord_id).collect())
+    }
+
+    pub async fn select_linked_user_count(&self) -> Result<u64> {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  COUNT(*) AS "count!"
+FROM
+  user_configs
+WHERE
+  osu_id IS NOT NULL"#
+        );
+
+        let row = query
+            .fetch_one(self)
+            .await
+            .wrap_err("failed to fetch one")?;
+
+        Ok(row.count as u64)
+    }
+
+    pub async fn select_linked_osu_ids(&self, discord_ids: &[i64]) -> Result<Vec<i32>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  DISTINCT osu_id AS "osu_id!" 
+FROM 
+  user_configs 
+WHERE 
+  discord_id = ANY($1) 
+  AND osu_id IS NOT NULL"#,
+            discord_ids,
+        );
+
+        let rows = query
+            .fetch_all(self)
+            .await
+            .wrap_err("failed to fetch all")?;
+
+        Ok(rows.into_iter().map(|row| row.osu_id).collect())
+    }
+
+    /// Mark the given discord users as currently sharing a guild with the bot.
+    pub async fn update_user_configs_last_seen(&self, discord_ids: &[i64]) -> Result<u64> {
+        let query = sqlx::query!(
+            r#"
+UPDATE 
+  user_configs 
+SET 
+  last_seen = NOW() 
+WHERE 
+  discord_id = ANY($1)"#,
+            discord_ids,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected())
+    }
+
+    /// Count configs of users that are not linked to an osu! profile, were
+    /// last seen before the given datetime, and are not among the given
+    /// discord ids.
+    pub async fn select_stale_user_config_count(
+        &self,
+        present_ids: &[i64],
+        last_seen_before: OffsetDateTime,
+    ) -> Result<u64> {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  COUNT(*) AS "count!"
+FROM
+  user_configs
+WHERE
+  last_seen < $2
+  AND osu_id IS NULL
+  AND NOT discord_id = ANY($1)"#,
+            present_ids,
+            last_seen_before,
+        );
+
+        let row = query
+            .fetch_one(self)
+            .await
+            .wrap_err("failed to fetch one")?;
+
+        Ok(row.count as u64)
+    }
+
+    /// Delete configs of users that are not linked to an osu! profile, were
+    /// last seen before the given datetime, and are not among the given
+    /// discord ids.
+    ///
+    /// Linked configs are kept because users may invoke commands only in DMs
+    /// or through the user-installed app and would lose their link otherwise.
+    pub async fn delete_stale_user_configs(
+        &self,
+        present_ids: &[i64],
+        last_seen_before: OffsetDateTime,
+    ) -> Result<u64> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  user_configs 
+WHERE 
+  last_seen < $2 
+  AND osu_id IS NULL 
+  AND NOT discord_id = ANY($1)"#,
+            present_ids,
+            last_seen_before,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected())
+    }
+
     pub async fn update_score_embed_settings(
         &self,
         user_id: Id<UserMarker>,
@@ -402,4 +662,67 @@ WHERE
 
         Ok(row_opt.and_then(|row| row.gamemode.map(|mode| GameMode::from(mode as u8))))
     }
+
+    pub async fn select_user_accessibility(&self, user_id: Id<UserMarker>) -> Result<Option<bool>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  accessibility 
+FROM 
+  user_configs 
+WHERE 
+  discord_id = $1"#,
+            user_id.get() as i64
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt.and_then(|row| row.accessibility))
+    }
+
+    pub async fn select_user_data_saver(&self, user_id: Id<UserMarker>) -> Result<Option<bool>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  data_saver 
+FROM 
+  user_configs 
+WHERE 
+  discord_id = $1"#,
+            user_id.get() as i64
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt.and_then(|row| row.data_saver))
+    }
+
+    pub async fn select_user_unicode_titles(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> Result<Option<bool>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  unicode_titles 
+FROM 
+  user_configs 
+WHERE 
+  discord_id = $1"#,
+            user_id.get() as i64
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt.and_then(|row| row.unicode_titles))
+    }
 }
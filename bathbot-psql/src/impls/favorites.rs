@@ -0,0 +1,76 @@
+use eyre::{Result, WrapErr};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use crate::Database;
+
+impl Database {
+    pub async fn select_favorite_players(&self, user_id: Id<UserMarker>) -> Result<Vec<u32>> {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  user_id 
+FROM 
+  user_favorite_players 
+WHERE 
+  discord_id = $1 
+ORDER BY 
+  insert_date"#,
+            user_id.get() as i64
+        );
+
+        let rows = query
+            .fetch_all(self)
+            .await
+            .wrap_err("Failed to fetch all")?;
+
+        Ok(rows.into_iter().map(|row| row.user_id as u32).collect())
+    }
+
+    /// Returns whether the player was not yet a favorite.
+    pub async fn insert_favorite_player(
+        &self,
+        user_id: Id<UserMarker>,
+        osu_id: u32,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO user_favorite_players (discord_id, user_id) 
+VALUES 
+  ($1, $2) ON CONFLICT (discord_id, user_id) DO NOTHING"#,
+            user_id.get() as i64,
+            osu_id as i32
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Returns whether the player was a favorite.
+    pub async fn delete_favorite_player(
+        &self,
+        user_id: Id<UserMarker>,
+        osu_id: u32,
+    ) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  user_favorite_players 
+WHERE 
+  discord_id = $1 
+  AND user_id = $2"#,
+            user_id.get() as i64,
+            osu_id as i32
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+}
@@ -0,0 +1,147 @@
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, GuildMarker},
+};
+
+use crate::{
+    Database,
+    model::milestones::{DbMilestoneChannel, DbMilestoneSeen, DbMilestoneStats},
+};
+
+impl Database {
+    pub async fn select_milestone_channels(&self) -> Result<Vec<DbMilestoneChannel>> {
+        let query = sqlx::query_as!(
+            DbMilestoneChannel,
+            r#"
+SELECT 
+  guild_id, 
+  channel_id 
+FROM 
+  milestone_channels"#
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    pub async fn upsert_milestone_channel(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO milestone_channels (guild_id, channel_id) 
+VALUES 
+  ($1, $2) ON CONFLICT (guild_id) DO 
+UPDATE 
+SET 
+  channel_id = $2"#,
+            guild_id.get() as i64,
+            channel_id.get() as i64,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns whether the guild had a milestone channel.
+    pub async fn delete_milestone_channel(&self, guild_id: Id<GuildMarker>) -> Result<bool> {
+        let query = sqlx::query!(
+            r#"
+DELETE FROM 
+  milestone_channels 
+WHERE 
+  guild_id = $1"#,
+            guild_id.get() as i64,
+        );
+
+        let res = query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(res.rows_affected() > 0)
+    }
+
+    /// Latest stored stats of the given osu! users for all modes.
+    pub async fn select_milestone_stats(&self, osu_ids: &[i32]) -> Result<Vec<DbMilestoneStats>> {
+        let query = sqlx::query_as!(
+            DbMilestoneStats,
+            r#"
+SELECT 
+  user_id, 
+  gamemode, 
+  pp, 
+  playcount, 
+  global_rank 
+FROM 
+  osu_user_mode_stats 
+WHERE 
+  user_id = ANY($1)"#,
+            osu_ids,
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    pub async fn select_milestone_seen(&self, osu_ids: &[i32]) -> Result<Vec<DbMilestoneSeen>> {
+        let query = sqlx::query_as!(
+            DbMilestoneSeen,
+            r#"
+SELECT 
+  osu_id, 
+  gamemode, 
+  pp, 
+  playcount, 
+  rank 
+FROM 
+  milestone_seen 
+WHERE 
+  osu_id = ANY($1)"#,
+            osu_ids,
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    pub async fn upsert_milestone_seen(
+        &self,
+        osu_id: u32,
+        mode: GameMode,
+        pp: u32,
+        playcount: u32,
+        rank: u32,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO milestone_seen (
+  osu_id, gamemode, pp, playcount, rank
+) 
+VALUES 
+  ($1, $2, $3, $4, $5) ON CONFLICT (osu_id, gamemode) DO 
+UPDATE 
+SET 
+  pp = $3, 
+  playcount = $4, 
+  rank = $5"#,
+            osu_id as i32,
+            mode as i16,
+            pp as i32,
+            playcount as i32,
+            rank as i32,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+}
@@ -1,5 +1,11 @@
+mod badge_notify;
 mod bookmarks;
 mod configs;
+mod favorites;
 mod games;
+mod milestones;
 mod osu;
+mod refscripts;
+mod scheduled_matches;
+mod simulate_presets;
 mod tracked_streams;
@@ -31,6 +31,8 @@ SELECT
   map.bpm, 
   mapset.artist, 
   mapset.title, 
+  mapset.artist_unicode, 
+  mapset.title_unicode, 
   mapset.creator, 
   mapset.rank_status, 
   mapset.ranked_date, 
@@ -58,6 +60,8 @@ FROM
       mapset_id, 
       artist, 
       title, 
+      artist_unicode, 
+      title_unicode, 
       creator, 
       rank_status, 
       ranked_date, 
@@ -93,6 +97,8 @@ FROM
             user_id: row.user_id,
             artist: row.artist,
             title: row.title,
+            artist_unicode: row.artist_unicode,
+            title_unicode: row.title_unicode,
             creator: row.creator,
             rank_status: row.rank_status,
             ranked_date: row.ranked_date,
@@ -136,6 +142,8 @@ SELECT
   map.bpm, 
   mapset.artist, 
   mapset.title, 
+  mapset.artist_unicode, 
+  mapset.title_unicode, 
   mapset.creator, 
   mapset.rank_status, 
   mapset.ranked_date, 
@@ -156,6 +164,8 @@ FROM
       mapset_id, 
       artist, 
       title, 
+      artist_unicode, 
+      title_unicode, 
       creator, 
       rank_status, 
       ranked_date, 
@@ -197,6 +207,8 @@ FROM
                 user_id: row.user_id,
                 artist: row.artist,
                 title: row.title,
+                artist_unicode: row.artist_unicode,
+                title_unicode: row.title_unicode,
                 creator: row.creator,
                 rank_status: row.rank_status,
                 ranked_date: row.ranked_date,
@@ -240,6 +252,23 @@ FROM
             .map(|row_opt| row_opt.map(|row| row.content))
     }
 
+    pub async fn select_osu_map_count(&self) -> Result<u64> {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  COUNT(*) AS "count!"
+FROM
+  osu_maps"#
+        );
+
+        let row = query
+            .fetch_one(self)
+            .await
+            .wrap_err("failed to fetch one")?;
+
+        Ok(row.count as u64)
+    }
+
     pub async fn select_map_versions_by_map_id(&self, map_id: u32) -> Result<Vec<MapVersion>> {
         let query = sqlx::query_as!(
             MapVersion,
@@ -0,0 +1,103 @@
+use bathbot_util::ScoreExt;
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::{GameMode, Score};
+
+use crate::{Database, model::osu::DbMapCompletion};
+
+impl Database {
+    /// Store how far into the map each score got.
+    ///
+    /// Catch scores are skipped because their completion can't be determined
+    /// reliably due to juicestreams.
+    pub async fn insert_map_completions(&self, scores: &[Score]) -> Result<()> {
+        let capacity = scores.len();
+        let mut score_ids = Vec::with_capacity(capacity);
+        let mut map_ids = Vec::with_capacity(capacity);
+        let mut modes = Vec::with_capacity(capacity);
+        let mut passed = Vec::with_capacity(capacity);
+        let mut completions = Vec::with_capacity(capacity);
+
+        for score in scores {
+            if score.mode == GameMode::Catch {
+                continue;
+            }
+
+            let Some(ref map) = score.map else { continue };
+
+            let n_objects = map.count_circles + map.count_sliders + map.count_spinners;
+
+            if n_objects == 0 {
+                continue;
+            }
+
+            let completion = if score.passed {
+                100
+            } else {
+                (100 * score.total_hits(score.mode as u8) / n_objects).min(100)
+            };
+
+            score_ids.push(score.id as i64);
+            map_ids.push(score.map_id as i32);
+            modes.push(score.mode as i16);
+            passed.push(score.passed);
+            completions.push(completion as i16);
+        }
+
+        if score_ids.is_empty() {
+            return Ok(());
+        }
+
+        let query = sqlx::query!(
+            r#"
+INSERT INTO osu_map_completions (
+  score_id, map_id, gamemode, passed, completion
+) 
+SELECT 
+  * 
+FROM 
+  UNNEST(
+    $1::INT8[], $2::INT4[], $3::INT2[], $4::BOOLEAN[], $5::INT2[]
+  ) ON CONFLICT (score_id) DO NOTHING"#,
+            &score_ids,
+            &map_ids,
+            &modes,
+            &passed,
+            &completions,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns the amount of stored scores for each completion.
+    pub async fn select_map_completions(
+        &self,
+        map_id: u32,
+        mode: GameMode,
+    ) -> Result<Vec<DbMapCompletion>> {
+        let query = sqlx::query_as!(
+            DbMapCompletion,
+            r#"
+SELECT
+  passed,
+  completion,
+  COUNT(*) AS "count!"
+FROM
+  osu_map_completions
+WHERE
+  map_id = $1
+  AND gamemode = $2
+GROUP BY
+  passed,
+  completion"#,
+            map_id as i32,
+            mode as i16,
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+}
@@ -19,6 +19,8 @@ SELECT
   user_id, 
   artist, 
   title, 
+  artist_unicode, 
+  title_unicode, 
   creator, 
   rank_status, 
   ranked_date, 
@@ -66,12 +68,13 @@ INSERT INTO osu_mapsets (
   mapset_id, user_id, artist, title, 
   creator, source, tags, video, storyboard, 
   bpm, rank_status, ranked_date, genre_id, 
-  language_id, thumbnail, cover
+  language_id, thumbnail, cover, artist_unicode, 
+  title_unicode
 ) 
 VALUES 
   (
     $1, $2, $3, $4, $5, $6, $7, $8, $9, $10, 
-    $11, $12, $13, $14, $15, $16
+    $11, $12, $13, $14, $15, $16, $17, $18
   ) ON CONFLICT (mapset_id) DO 
 UPDATE 
 SET 
@@ -90,6 +93,8 @@ SET
   language_id = $14, 
   thumbnail = $15, 
   cover = $16, 
+  artist_unicode = $17, 
+  title_unicode = $18, 
   last_update = NOW()"#,
             mapset.mapset_id as i32,
             mapset.creator_id as i32,
@@ -107,6 +112,8 @@ SET
             mapset.language.map(|language| language as i16),
             mapset.covers.list,
             mapset.covers.cover,
+            mapset.artist_unicode,
+            mapset.title_unicode,
         );
 
         query
@@ -1,7 +1,9 @@
 pub mod map;
+pub mod map_completions;
 pub mod mapset;
 pub mod name;
 pub mod rank_pp;
+pub mod rank_snapshots;
 pub mod render;
 pub mod score;
 pub mod tracked_users;
@@ -52,6 +52,44 @@ WHERE
         Ok(row_opt.map(|row| row.user_id as u32))
     }
 
+    /// Returns the user id and current username of the user that previously
+    /// went by the given name.
+    ///
+    /// If multiple users went by the name, the one that was last observed with
+    /// it is picked.
+    ///
+    /// Be sure wildcards (_, %) are escaped as required!
+    pub async fn select_osu_user_by_alias(
+        &self,
+        username: &str,
+        alt_username: Option<&str>,
+    ) -> Result<Option<(u32, Username)>> {
+        let query = sqlx::query!(
+            r#"
+SELECT
+  names.user_id,
+  names.username
+FROM
+  osu_user_aliases AS aliases
+  JOIN osu_user_names AS names ON aliases.user_id = names.user_id
+WHERE
+  aliases.username ILIKE $1 OR aliases.username ILIKE $2
+ORDER BY
+  aliases.observed_at DESC
+LIMIT
+  1"#,
+            username,
+            alt_username,
+        );
+
+        let row_opt = query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")?;
+
+        Ok(row_opt.map(|row| (row.user_id as u32, row.username.into())))
+    }
+
     pub async fn select_osu_name_by_discord_id(
         &self,
         user_id: Id<UserMarker>,
@@ -0,0 +1,66 @@
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+use time::Date;
+
+use crate::{Database, model::osu::DbRankSnapshot};
+
+impl Database {
+    /// Returns all stored rank snapshots of a user, sorted by date.
+    pub async fn select_rank_snapshots(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+    ) -> Result<Vec<DbRankSnapshot>> {
+        let query = sqlx::query_as!(
+            DbRankSnapshot,
+            r#"
+SELECT
+  date,
+  global_rank
+FROM
+  osu_rank_snapshots
+WHERE
+  user_id = $1
+  AND gamemode = $2
+ORDER BY
+  date ASC"#,
+            user_id as i32,
+            mode as i16,
+        );
+
+        query.fetch_all(self).await.wrap_err("Failed to fetch all")
+    }
+
+    pub async fn upsert_rank_snapshot(
+        &self,
+        user_id: u32,
+        mode: GameMode,
+        date: Date,
+        global_rank: u32,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO
+  osu_rank_snapshots(user_id, gamemode, date, global_rank)
+VALUES
+  ($1, $2, $3, $4)
+ON CONFLICT
+  (user_id, gamemode, date)
+DO
+  UPDATE
+SET
+  global_rank = $4"#,
+            user_id as i32,
+            mode as i16,
+            date,
+            global_rank as i32,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("Failed to execute query")?;
+
+        Ok(())
+    }
+}
@@ -8,7 +8,7 @@ use time::OffsetDateTime;
 
 use crate::{
     Database,
-    model::osu::{DbUserStatsEntry, OsuUserStatsColumnName},
+    model::osu::{DbEfficiencyPeer, DbUserStatsEntry, OsuUserStatsColumnName},
 };
 
 fn convert_entries<V>(entries: Vec<DbUserStatsEntry<V>>) -> Vec<UserStatsEntry<V>> {
@@ -684,12 +684,113 @@ WHERE
         Ok(names)
     }
 
+    pub async fn select_osu_users_pp<S>(
+        &self,
+        user_ids: &[i32],
+        mode: GameMode,
+    ) -> Result<HashMap<u32, f32, S>>
+    where
+        S: Default + BuildHasher,
+    {
+        let query = sqlx::query!(
+            r#"
+SELECT 
+  user_id, 
+  pp 
+FROM 
+  osu_user_mode_stats 
+WHERE 
+  user_id = ANY($1) 
+  AND gamemode = $2"#,
+            user_ids,
+            mode as i16,
+        );
+
+        let mut pps = HashMap::with_capacity_and_hasher(user_ids.len(), S::default());
+        let mut rows = query.fetch(self);
+
+        while let Some(row_res) = rows.next().await {
+            let row = row_res.wrap_err("failed to fetch next")?;
+            pps.insert(row.user_id as u32, row.pp);
+        }
+
+        Ok(pps)
+    }
+
     pub async fn upsert_osu_user(&self, user: &UserExtended, mode: GameMode) -> Result<()> {
         let mut tx = self.begin().await.wrap_err("failed to begin transaction")?;
 
+        // Keep the previously stored name around if it changed
         let query = sqlx::query!(
             r#"
-INSERT INTO osu_user_names (user_id, username) 
+INSERT INTO osu_user_aliases (user_id, username)
+SELECT
+  user_id,
+  username
+FROM
+  osu_user_names
+WHERE
+  user_id = $1
+  AND username <> $2 ON CONFLICT (user_id, username) DO
+UPDATE
+SET
+  observed_at = NOW()"#,
+            user.user_id as i32,
+            user.username.as_str()
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("failed to execute observed alias query")?;
+
+        let previous_names: Vec<_> = user
+            .previous_usernames
+            .iter()
+            .flatten()
+            .map(Username::as_str)
+            .filter(|name| *name != user.username.as_str())
+            .collect();
+
+        if !previous_names.is_empty() {
+            let query = sqlx::query!(
+                r#"
+INSERT INTO osu_user_aliases (user_id, username)
+SELECT
+  $1,
+  username
+FROM
+  UNNEST($2::VARCHAR[]) AS aliases (username) ON CONFLICT (user_id, username) DO NOTHING"#,
+                user.user_id as i32,
+                &previous_names as _,
+            );
+
+            query
+                .execute(&mut *tx)
+                .await
+                .wrap_err("failed to execute previous usernames query")?;
+        }
+
+        // The user might have reclaimed one of its previous names
+        let query = sqlx::query!(
+            r#"
+DELETE FROM
+  osu_user_aliases
+WHERE
+  user_id = $1
+  AND username = $2"#,
+            user.user_id as i32,
+            user.username.as_str()
+        );
+
+        query
+            .execute(&mut *tx)
+            .await
+            .wrap_err("failed to execute reclaimed alias query")?;
+
+        let query = sqlx::query!(
+            r#"
+INSERT INTO osu_user_names (user_id, username)
 VALUES 
   ($1, $2) ON CONFLICT (user_id) DO 
 UPDATE 
@@ -831,6 +932,47 @@ SET
         Ok(())
     }
 
+    /// Stats of users with a global rank between `min_rank` and `max_rank`
+    /// that were updated within the last week and played at least an hour.
+    pub async fn select_osu_user_efficiency_peers(
+        &self,
+        mode: GameMode,
+        min_rank: u32,
+        max_rank: u32,
+        limit: usize,
+    ) -> Result<Vec<DbEfficiencyPeer>> {
+        let query = sqlx::query_as!(
+            DbEfficiencyPeer,
+            r#"
+SELECT 
+  global_rank, 
+  pp, 
+  playcount, 
+  playtime 
+FROM 
+  osu_user_mode_stats 
+WHERE 
+  gamemode = $1 
+  AND global_rank BETWEEN $2 AND $3 
+  AND playcount > 0 
+  AND playtime >= 3600 
+  AND NOW() - last_update < interval '7 days' 
+ORDER BY 
+  RANDOM() 
+LIMIT 
+  $4"#,
+            mode as i16,
+            min_rank as i32,
+            max_rank.min(i32::MAX as u32) as i32,
+            limit as i64,
+        );
+
+        query
+            .fetch_all(self)
+            .await
+            .wrap_err("Failed to fetch all")
+    }
+
     pub async fn delete_osu_user_stats(&self, user_id: u32) -> Result<()> {
         let mut conn = self
             .acquire()
@@ -0,0 +1,79 @@
+use eyre::{Result, WrapErr};
+use twilight_model::id::{Id, marker::GuildMarker};
+
+use crate::{Database, model::refscript::DbRefscript};
+
+impl Database {
+    pub async fn select_guild_refscript(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Option<DbRefscript>> {
+        let query = sqlx::query_as!(
+            DbRefscript,
+            r#"
+SELECT 
+  mappool, 
+  template 
+FROM 
+  guild_refscripts 
+WHERE 
+  guild_id = $1"#,
+            guild_id.get() as i64
+        );
+
+        query
+            .fetch_optional(self)
+            .await
+            .wrap_err("failed to fetch optional")
+    }
+
+    pub async fn upsert_guild_refscript_mappool(
+        &self,
+        guild_id: Id<GuildMarker>,
+        mappool: &str,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO guild_refscripts (guild_id, mappool) 
+VALUES 
+  ($1, $2) ON CONFLICT (guild_id) DO 
+UPDATE 
+SET 
+  mappool = $2"#,
+            guild_id.get() as i64,
+            mappool,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    pub async fn upsert_guild_refscript_template(
+        &self,
+        guild_id: Id<GuildMarker>,
+        template: Option<&str>,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO guild_refscripts (guild_id, template) 
+VALUES 
+  ($1, $2) ON CONFLICT (guild_id) DO 
+UPDATE 
+SET 
+  template = $2"#,
+            guild_id.get() as i64,
+            template,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,115 @@
+use eyre::{Result, WrapErr};
+use time::OffsetDateTime;
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, GuildMarker, UserMarker},
+};
+
+use crate::{Database, model::scheduled_match::DbScheduledMatch};
+
+impl Database {
+    pub async fn insert_scheduled_match(
+        &self,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+        player1: Id<UserMarker>,
+        player2: Id<UserMarker>,
+        start_at: OffsetDateTime,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO scheduled_matches (
+  guild_id, channel_id, player1, player2, start_at
+) 
+VALUES 
+  ($1, $2, $3, $4, $5)"#,
+            guild_id.get() as i64,
+            channel_id.get() as i64,
+            player1.get() as i64,
+            player2.get() as i64,
+            start_at,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+
+    /// Returns the guild's matches that have not started yet, sorted by
+    /// start time.
+    pub async fn select_upcoming_matches(
+        &self,
+        guild_id: Id<GuildMarker>,
+    ) -> Result<Vec<DbScheduledMatch>> {
+        let query = sqlx::query_as!(
+            DbScheduledMatch,
+            r#"
+SELECT 
+  match_id, 
+  channel_id, 
+  player1, 
+  player2, 
+  start_at 
+FROM 
+  scheduled_matches 
+WHERE 
+  guild_id = $1 
+  AND start_at > NOW() 
+ORDER BY 
+  start_at ASC"#,
+            guild_id.get() as i64,
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    /// Returns all matches that start before `until` and whose participants
+    /// have not been reminded yet.
+    pub async fn select_due_match_reminders(
+        &self,
+        until: OffsetDateTime,
+    ) -> Result<Vec<DbScheduledMatch>> {
+        let query = sqlx::query_as!(
+            DbScheduledMatch,
+            r#"
+SELECT 
+  match_id, 
+  channel_id, 
+  player1, 
+  player2, 
+  start_at 
+FROM 
+  scheduled_matches 
+WHERE 
+  NOT reminded 
+  AND start_at <= $1 
+  AND start_at > NOW()"#,
+            until,
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    pub async fn update_match_reminded(&self, match_id: i32) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+UPDATE 
+  scheduled_matches 
+SET 
+  reminded = TRUE 
+WHERE 
+  match_id = $1"#,
+            match_id
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+}
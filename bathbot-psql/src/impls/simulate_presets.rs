@@ -0,0 +1,81 @@
+use eyre::{Result, WrapErr};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use crate::{Database, model::simulate_preset::DbSimulatePreset};
+
+impl Database {
+    pub async fn select_simulate_presets(
+        &self,
+        user_id: Id<UserMarker>,
+    ) -> Result<Vec<DbSimulatePreset>> {
+        let query = sqlx::query_as!(
+            DbSimulatePreset,
+            r#"
+SELECT 
+  name, 
+  mods, 
+  acc, 
+  misses, 
+  clock_rate, 
+  ar, 
+  cs, 
+  hp, 
+  od, 
+  set_on_lazer 
+FROM 
+  user_simulate_presets 
+WHERE 
+  discord_id = $1 
+ORDER BY 
+  name"#,
+            user_id.get() as i64
+        );
+
+        query.fetch_all(self).await.wrap_err("failed to fetch all")
+    }
+
+    pub async fn upsert_simulate_preset(
+        &self,
+        user_id: Id<UserMarker>,
+        preset: &DbSimulatePreset,
+    ) -> Result<()> {
+        let query = sqlx::query!(
+            r#"
+INSERT INTO user_simulate_presets (
+  discord_id, name, mods, acc, misses, 
+  clock_rate, ar, cs, hp, od, set_on_lazer
+) 
+VALUES 
+  ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) ON CONFLICT (discord_id, name) DO 
+UPDATE 
+SET 
+  mods = $3, 
+  acc = $4, 
+  misses = $5, 
+  clock_rate = $6, 
+  ar = $7, 
+  cs = $8, 
+  hp = $9, 
+  od = $10, 
+  set_on_lazer = $11"#,
+            user_id.get() as i64,
+            preset.name,
+            preset.mods,
+            preset.acc,
+            preset.misses,
+            preset.clock_rate,
+            preset.ar,
+            preset.cs,
+            preset.hp,
+            preset.od,
+            preset.set_on_lazer,
+        );
+
+        query
+            .execute(self)
+            .await
+            .wrap_err("failed to execute query")?;
+
+        Ok(())
+    }
+}
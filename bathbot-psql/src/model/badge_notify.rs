@@ -0,0 +1,9 @@
+pub struct DbBadgeNotifyChannel {
+    pub guild_id: i64,
+    pub channel_id: i64,
+}
+
+pub struct DbSeenBadges {
+    pub osu_id: i32,
+    pub badge_ids: Vec<i32>,
+}
@@ -0,0 +1,65 @@
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, CommandOption, CreateOption)]
+#[repr(u8)]
+pub enum CardBackground {
+    #[default]
+    #[option(name = "Title", value = "title")]
+    Title = 0,
+    #[option(name = "Profile cover", value = "cover")]
+    Cover = 1,
+    #[option(name = "Newbie", value = "newbie")]
+    Newbie = 2,
+    #[option(name = "Novice", value = "novice")]
+    Novice = 3,
+    #[option(name = "Rookie", value = "rookie")]
+    Rookie = 4,
+    #[option(name = "Apprentice", value = "apprentice")]
+    Apprentice = 5,
+    #[option(name = "Advanced", value = "advanced")]
+    Advanced = 6,
+    #[option(name = "Outstanding", value = "outstanding")]
+    Outstanding = 7,
+    #[option(name = "Seasoned", value = "seasoned")]
+    Seasoned = 8,
+    #[option(name = "Professional", value = "professional")]
+    Professional = 9,
+    #[option(name = "Expert", value = "expert")]
+    Expert = 10,
+    #[option(name = "Master", value = "master")]
+    Master = 11,
+    #[option(name = "Legendary", value = "legendary")]
+    Legendary = 12,
+    #[option(name = "God", value = "god")]
+    God = 13,
+}
+
+impl From<CardBackground> for i16 {
+    fn from(background: CardBackground) -> Self {
+        background as Self
+    }
+}
+
+impl TryFrom<i16> for CardBackground {
+    type Error = ();
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Title),
+            1 => Ok(Self::Cover),
+            2 => Ok(Self::Newbie),
+            3 => Ok(Self::Novice),
+            4 => Ok(Self::Rookie),
+            5 => Ok(Self::Apprentice),
+            6 => Ok(Self::Advanced),
+            7 => Ok(Self::Outstanding),
+            8 => Ok(Self::Seasoned),
+            9 => Ok(Self::Professional),
+            10 => Ok(Self::Expert),
+            11 => Ok(Self::Master),
+            12 => Ok(Self::Legendary),
+            13 => Ok(Self::God),
+            _ => Err(()),
+        }
+    }
+}
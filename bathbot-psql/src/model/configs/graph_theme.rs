@@ -0,0 +1,29 @@
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, CommandOption, CreateOption)]
+#[repr(u8)]
+pub enum GraphTheme {
+    #[default]
+    #[option(name = "Dark", value = "dark")]
+    Dark = 0,
+    #[option(name = "Light", value = "light")]
+    Light = 1,
+}
+
+impl From<GraphTheme> for i16 {
+    fn from(theme: GraphTheme) -> Self {
+        theme as Self
+    }
+}
+
+impl TryFrom<i16> for GraphTheme {
+    type Error = ();
+
+    fn try_from(value: i16) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::Dark),
+            1 => Ok(Self::Light),
+            _ => Err(()),
+        }
+    }
+}
@@ -1,4 +1,5 @@
 use sqlx::types::JsonValue;
+use twilight_model::id::{Id, marker::ChannelMarker};
 
 use super::{Authorities, HideSolutions, Retries, ScoreData, list_size::ListSize};
 
@@ -13,6 +14,12 @@ pub struct DbGuildConfig {
     pub allow_custom_skins: Option<bool>,
     pub hide_medal_solution: Option<i16>,
     pub score_data: Option<i16>,
+    pub unicode_titles: Option<bool>,
+    pub pulse_channel: Option<i64>,
+    pub intro_accent: Option<i32>,
+    pub intro_label: Option<String>,
+    pub image_channels: Vec<i64>,
+    pub osu_server: Option<String>,
 }
 
 #[derive(Clone)]
@@ -26,6 +33,19 @@ pub struct GuildConfig {
     pub allow_custom_skins: Option<bool>,
     pub hide_medal_solution: Option<HideSolutions>,
     pub score_data: Option<ScoreData>,
+    pub unicode_titles: Option<bool>,
+    /// Channel that is renamed periodically to show server stats
+    pub pulse_channel: Option<Id<ChannelMarker>>,
+    /// Accent color of map intro cards as `0xRRGGBB`
+    pub intro_accent: Option<u32>,
+    /// Text shown on map intro cards, usually the tournament name
+    pub intro_label: Option<String>,
+    /// Channels in which responses may include attachments; all channels if
+    /// empty
+    pub image_channels: Vec<Id<ChannelMarker>>,
+    /// Name of the configured private osu! server that requests target
+    /// instead of bancho
+    pub osu_server: Option<String>,
 }
 
 impl GuildConfig {
@@ -44,6 +64,12 @@ impl Default for GuildConfig {
             allow_custom_skins: Default::default(),
             hide_medal_solution: Default::default(),
             score_data: Default::default(),
+            unicode_titles: Default::default(),
+            pulse_channel: Default::default(),
+            intro_accent: Default::default(),
+            intro_label: Default::default(),
+            image_channels: Default::default(),
+            osu_server: Default::default(),
         }
     }
 }
@@ -62,6 +88,12 @@ impl From<DbGuildConfig> for GuildConfig {
             allow_custom_skins,
             hide_medal_solution,
             score_data,
+            unicode_titles,
+            pulse_channel,
+            intro_accent,
+            intro_label,
+            image_channels,
+            osu_server,
         } = config;
 
         let authorities = Authorities::deserialize(&authorities);
@@ -90,6 +122,15 @@ impl From<DbGuildConfig> for GuildConfig {
                 .map(HideSolutions::try_from)
                 .and_then(Result::ok),
             score_data: score_data.map(ScoreData::try_from).and_then(Result::ok),
+            unicode_titles,
+            pulse_channel: pulse_channel.map(|id| Id::new(id as u64)),
+            intro_accent: intro_accent.map(|color| color as u32),
+            intro_label,
+            image_channels: image_channels
+                .into_iter()
+                .map(|id| Id::new(id as u64))
+                .collect(),
+            osu_server,
         }
     }
 }
@@ -1,5 +1,7 @@
 pub use self::{
     authorities::{Authorities, Authority},
+    card_background::CardBackground,
+    graph_theme::GraphTheme,
     guild::{DbGuildConfig, GuildConfig},
     hide_solutions::HideSolutions,
     list_size::ListSize,
@@ -10,6 +12,8 @@ pub use self::{
 };
 
 mod authorities;
+mod card_background;
+mod graph_theme;
 mod guild;
 mod hide_solutions;
 mod list_size;
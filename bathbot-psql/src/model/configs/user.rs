@@ -3,7 +3,7 @@ use rosu_v2::prelude::{GameMode, Username};
 use sqlx::types::Json;
 use time::UtcOffset;
 
-use super::{Retries, ScoreData, list_size::ListSize};
+use super::{CardBackground, GraphTheme, Retries, ScoreData, list_size::ListSize};
 
 pub struct DbUserConfig {
     pub list_size: Option<i16>,
@@ -15,6 +15,14 @@ pub struct DbUserConfig {
     pub timezone_seconds: Option<i32>,
     pub render_button: Option<bool>,
     pub score_data: Option<i16>,
+    pub graph_theme: Option<i16>,
+    pub accessibility: Option<bool>,
+    pub data_saver: Option<bool>,
+    pub profile_badges: Option<bool>,
+    pub unicode_titles: Option<bool>,
+    pub card_background: Option<i16>,
+    pub card_accent_color: Option<i32>,
+    pub graph_days: Option<i16>,
 }
 
 pub trait OsuId {
@@ -46,6 +54,16 @@ pub struct UserConfig<O: OsuId> {
     pub timezone: Option<UtcOffset>,
     pub render_button: Option<bool>,
     pub score_data: Option<ScoreData>,
+    pub graph_theme: Option<GraphTheme>,
+    pub accessibility: Option<bool>,
+    pub data_saver: Option<bool>,
+    pub profile_badges: Option<bool>,
+    pub unicode_titles: Option<bool>,
+    pub card_background: Option<CardBackground>,
+    /// RGB color encoded as `0xRRGGBB`
+    pub card_accent_color: Option<u32>,
+    /// Amount of days graphs cover by default
+    pub graph_days: Option<u16>,
 }
 
 impl<O: OsuId> Default for UserConfig<O> {
@@ -61,6 +79,14 @@ impl<O: OsuId> Default for UserConfig<O> {
             timezone: None,
             render_button: None,
             score_data: None,
+            graph_theme: None,
+            accessibility: None,
+            data_saver: None,
+            profile_badges: None,
+            unicode_titles: None,
+            card_background: None,
+            card_accent_color: None,
+            graph_days: None,
         }
     }
 }
@@ -78,6 +104,14 @@ impl From<DbUserConfig> for UserConfig<OsuUserId> {
             timezone_seconds,
             render_button,
             score_data,
+            graph_theme,
+            accessibility,
+            data_saver,
+            profile_badges,
+            unicode_titles,
+            card_background,
+            card_accent_color,
+            graph_days,
         } = config;
 
         Self {
@@ -92,6 +126,16 @@ impl From<DbUserConfig> for UserConfig<OsuUserId> {
                 .map(Result::unwrap),
             render_button,
             score_data: score_data.map(ScoreData::try_from).and_then(Result::ok),
+            graph_theme: graph_theme.map(GraphTheme::try_from).and_then(Result::ok),
+            accessibility,
+            data_saver,
+            profile_badges,
+            unicode_titles,
+            card_background: card_background
+                .map(CardBackground::try_from)
+                .and_then(Result::ok),
+            card_accent_color: card_accent_color.map(|color| color as u32),
+            graph_days: graph_days.map(|days| days as u16),
         }
     }
 }
@@ -0,0 +1,20 @@
+pub struct DbMilestoneChannel {
+    pub guild_id: i64,
+    pub channel_id: i64,
+}
+
+pub struct DbMilestoneStats {
+    pub user_id: i32,
+    pub gamemode: i16,
+    pub pp: f32,
+    pub playcount: i32,
+    pub global_rank: i32,
+}
+
+pub struct DbMilestoneSeen {
+    pub osu_id: i32,
+    pub gamemode: i16,
+    pub pp: i32,
+    pub playcount: i32,
+    pub rank: i32,
+}
@@ -1,4 +1,9 @@
+pub mod badge_notify;
 pub mod configs;
 pub mod games;
+pub mod milestones;
 pub mod osu;
+pub mod refscript;
 pub mod render;
+pub mod scheduled_match;
+pub mod simulate_preset;
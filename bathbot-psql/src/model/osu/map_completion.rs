@@ -0,0 +1,5 @@
+pub struct DbMapCompletion {
+    pub passed: bool,
+    pub completion: i16,
+    pub count: i64,
+}
@@ -10,6 +10,8 @@ pub struct DbBeatmapset {
     pub user_id: i32,
     pub artist: String,
     pub title: String,
+    pub artist_unicode: Option<String>,
+    pub title_unicode: Option<String>,
     pub creator: String,
     pub rank_status: i16,
     pub ranked_date: Option<OffsetDateTime>,
@@ -1,7 +1,11 @@
-pub use self::{bookmark::*, map::*, mapset::*, tracked_user::*, user::*};
+pub use self::{
+    bookmark::*, map::*, map_completion::*, mapset::*, rank_snapshot::*, tracked_user::*, user::*,
+};
 
 mod bookmark;
 mod map;
+mod map_completion;
 mod mapset;
+mod rank_snapshot;
 mod tracked_user;
 mod user;
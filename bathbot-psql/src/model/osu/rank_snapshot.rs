@@ -0,0 +1,6 @@
+use time::Date;
+
+pub struct DbRankSnapshot {
+    pub date: Date,
+    pub global_rank: i32,
+}
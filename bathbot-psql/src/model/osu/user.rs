@@ -31,6 +31,14 @@ impl TryFrom<DbCountryCode> for [u8; 2] {
     }
 }
 
+pub struct DbEfficiencyPeer {
+    pub global_rank: i32,
+    pub pp: f32,
+    pub playcount: i32,
+    /// Playtime in seconds
+    pub playtime: i32,
+}
+
 // Be sure to keep structure in sync with
 // `bathbot_model::user_stats::UserStatsEntry`!
 #[derive(FromRow)]
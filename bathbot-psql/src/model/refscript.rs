@@ -0,0 +1,4 @@
+pub struct DbRefscript {
+    pub mappool: String,
+    pub template: Option<String>,
+}
@@ -0,0 +1,9 @@
+use time::OffsetDateTime;
+
+pub struct DbScheduledMatch {
+    pub match_id: i32,
+    pub channel_id: i64,
+    pub player1: i64,
+    pub player2: i64,
+    pub start_at: OffsetDateTime,
+}
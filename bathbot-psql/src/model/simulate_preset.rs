@@ -0,0 +1,12 @@
+pub struct DbSimulatePreset {
+    pub name: String,
+    pub mods: Option<String>,
+    pub acc: Option<f32>,
+    pub misses: Option<i32>,
+    pub clock_rate: Option<f64>,
+    pub ar: Option<f32>,
+    pub cs: Option<f32>,
+    pub hp: Option<f32>,
+    pub od: Option<f32>,
+    pub set_on_lazer: bool,
+}
@@ -0,0 +1,120 @@
+use std::{
+    sync::Arc,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use axum::{
+    body::Bytes,
+    extract::State,
+    http::{HeaderMap, StatusCode},
+};
+use hmac::{Hmac, Mac};
+use rosu_v2::prelude::Score;
+use sha2::Sha256;
+
+use crate::state::AppState;
+
+/// Header containing the hex encoded HMAC-SHA256 signature of
+/// `{timestamp}.{body}`, optionally prefixed with `sha256=`.
+const SIGNATURE_HEADER: &str = "x-signature-256";
+
+/// Header containing the unix timestamp in seconds at which the request was
+/// signed.
+const TIMESTAMP_HEADER: &str = "x-signature-timestamp";
+
+/// Requests whose timestamp deviates further from the current time are
+/// rejected so that captured requests can't be replayed later on.
+const TIMESTAMP_TOLERANCE_SECS: u64 = 300;
+
+/// Receive a score from a third-party tracker and pass it on to the tracking.
+pub async fn post_score(
+    State(state): State<Arc<AppState>>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> StatusCode {
+    let Some(ref webhook) = state.score_webhook else {
+        return StatusCode::NOT_FOUND;
+    };
+
+    let Some(timestamp) = headers
+        .get(TIMESTAMP_HEADER)
+        .and_then(|header| header.to_str().ok())
+        .and_then(|timestamp| timestamp.parse::<u64>().ok())
+    else {
+        return StatusCode::UNAUTHORIZED;
+    };
+
+    let Some(signature) = headers
+        .get(SIGNATURE_HEADER)
+        .and_then(|header| header.to_str().ok())
+        .map(|header| header.trim_start_matches("sha256="))
+        .and_then(|signature| hex::decode(signature).ok())
+    else {
+        return StatusCode::UNAUTHORIZED;
+    };
+
+    if !verify_signature(webhook.secret.as_bytes(), timestamp, &body, &signature) {
+        return StatusCode::UNAUTHORIZED;
+    }
+
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |duration| duration.as_secs());
+
+    if now.abs_diff(timestamp) > TIMESTAMP_TOLERANCE_SECS {
+        return StatusCode::UNAUTHORIZED;
+    }
+
+    let score: Score = match serde_json::from_slice(&body) {
+        Ok(score) => score,
+        Err(err) => {
+            debug!(?err, "Failed to deserialize webhook score");
+
+            return StatusCode::UNPROCESSABLE_ENTITY;
+        }
+    };
+
+    if webhook.tx.send(score).is_err() {
+        warn!("Score webhook receiver was dropped");
+
+        return StatusCode::SERVICE_UNAVAILABLE;
+    }
+
+    StatusCode::ACCEPTED
+}
+
+fn verify_signature(secret: &[u8], timestamp: u64, body: &[u8], signature: &[u8]) -> bool {
+    let Ok(mut mac) = Hmac::<Sha256>::new_from_slice(secret) else {
+        return false;
+    };
+
+    mac.update(timestamp.to_string().as_bytes());
+    mac.update(b".");
+    mac.update(body);
+
+    // Constant-time comparison
+    mac.verify_slice(signature).is_ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn signature() {
+        let secret = b"secret";
+        let body = br#"{"id":123}"#;
+
+        let timestamp = 1_700_000_000;
+
+        let mut mac = Hmac::<Sha256>::new_from_slice(secret).unwrap();
+        mac.update(b"1700000000.");
+        mac.update(body);
+        let signature = mac.finalize().into_bytes();
+
+        assert!(verify_signature(secret, timestamp, body, &signature));
+        assert!(!verify_signature(b"other", timestamp, body, &signature));
+        assert!(!verify_signature(secret, timestamp, br#"{"id":124}"#, &signature));
+        assert!(!verify_signature(secret, timestamp + 1, body, &signature));
+    }
+}
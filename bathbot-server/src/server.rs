@@ -5,7 +5,7 @@ use axum::{
     http::StatusCode,
     middleware,
     response::Response,
-    routing::{get, get_service},
+    routing::{get, get_service, post},
 };
 use eyre::Result;
 use hyper::Request;
@@ -24,6 +24,7 @@ use crate::{
         guild_count::get_guild_count,
         metrics::get_metrics,
         osudirect::redirect_osudirect,
+        scores::post_score,
     },
     standby::AuthenticationStandby,
     state::AppState,
@@ -96,6 +97,7 @@ impl Server {
             .route("/guild_count", get(get_guild_count))
             .nest("/auth", Self::auth_app(website_path))
             .route("/osudirect/{mapset_id}", get(redirect_osudirect))
+            .route("/scores", post(post_score))
             .layer(CorsLayer::permissive())
             .layer(middleware::from_fn_with_state(state, track_metrics))
             .layer(trace)
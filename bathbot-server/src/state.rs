@@ -12,6 +12,8 @@ use hyper_util::{
 };
 use metrics::describe_histogram;
 use metrics_exporter_prometheus::PrometheusHandle;
+use rosu_v2::prelude::Score;
+use tokio::sync::mpsc::UnboundedSender;
 
 use crate::standby::AuthenticationStandby;
 
@@ -26,6 +28,14 @@ pub struct AppState {
     pub twitch_token: Box<str>,
     pub redirect_base: Box<str>,
     pub standby: Arc<AuthenticationStandby>,
+    pub score_webhook: Option<ScoreWebhook>,
+}
+
+/// Receives scores of third-party trackers.
+pub struct ScoreWebhook {
+    /// Shared secret with which request bodies must be signed
+    pub secret: Box<str>,
+    pub tx: UnboundedSender<Score>,
 }
 
 pub struct AppStateBuilder {
@@ -37,6 +47,9 @@ pub struct AppStateBuilder {
     pub twitch_client_id: String,
     pub twitch_token: String,
     pub redirect_base: String,
+    /// Enables the score webhook if specified
+    pub score_webhook_secret: Option<String>,
+    pub score_tx: UnboundedSender<Score>,
 }
 
 impl AppStateBuilder {
@@ -50,6 +63,8 @@ impl AppStateBuilder {
             twitch_client_id,
             twitch_token,
             redirect_base,
+            score_webhook_secret,
+            score_tx,
         } = self;
 
         let crypto_provider = rustls::crypto::ring::default_provider();
@@ -91,6 +106,10 @@ impl AppStateBuilder {
             twitch_token: twitch_token.into_boxed_str(),
             redirect_base: redirect_base.into_boxed_str(),
             standby,
+            score_webhook: score_webhook_secret.map(|secret| ScoreWebhook {
+                secret: secret.into_boxed_str(),
+                tx: score_tx,
+            }),
         };
 
         Ok((state, website_path))
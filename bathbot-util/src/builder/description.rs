@@ -0,0 +1,129 @@
+use std::fmt::{Result as FmtResult, Write};
+
+use crate::constants::DESCRIPTION_SIZE;
+
+/// Space that is kept free for the note about omitted entries.
+const NOTE_RESERVE: usize = 32;
+
+/// Builds a description out of multiple entries while making sure it does not
+/// exceed the embed description limit.
+///
+/// Content is written into the pending entry through [`Write`] and committed
+/// with [`DescriptionBuilder::finish_entry`]. If an entry does not fit anymore,
+/// it and all following entries are omitted and a note is appended instead.
+pub struct DescriptionBuilder {
+    description: String,
+    /// Length of `description` in chars
+    len: usize,
+    pending: String,
+    limit: usize,
+    omitted: usize,
+}
+
+impl DescriptionBuilder {
+    pub fn new() -> Self {
+        Self::with_limit(DESCRIPTION_SIZE)
+    }
+
+    pub fn with_limit(limit: usize) -> Self {
+        Self {
+            description: String::with_capacity(512),
+            len: 0,
+            pending: String::new(),
+            limit,
+            omitted: 0,
+        }
+    }
+
+    /// Commit the pending entry if it still fits, otherwise omit it.
+    pub fn finish_entry(&mut self) {
+        if self.pending.is_empty() {
+            return;
+        }
+
+        let entry_len = self.pending.chars().count();
+
+        if self.omitted == 0 && self.len + entry_len + NOTE_RESERVE <= self.limit {
+            self.description.push_str(&self.pending);
+            self.len += entry_len;
+        } else {
+            self.omitted += 1;
+        }
+
+        self.pending.clear();
+    }
+
+    /// Whether no entry has been committed or is pending.
+    pub fn is_empty(&self) -> bool {
+        self.description.is_empty() && self.pending.is_empty()
+    }
+
+    /// Commits the pending entry and returns the description without
+    /// trailing newlines.
+    pub fn build(mut self) -> String {
+        self.finish_entry();
+
+        let trimmed_len = self.description.trim_end_matches('\n').len();
+        self.description.truncate(trimmed_len);
+
+        if self.omitted > 0 {
+            let _ = write!(self.description, "\n*+{} more on this page*", self.omitted);
+        }
+
+        self.description
+    }
+}
+
+impl Default for DescriptionBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Write for DescriptionBuilder {
+    fn write_str(&mut self, s: &str) -> FmtResult {
+        self.pending.push_str(s);
+
+        Ok(())
+    }
+
+    fn write_char(&mut self, c: char) -> FmtResult {
+        self.pending.push(c);
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Write;
+
+    use super::DescriptionBuilder;
+
+    #[test]
+    fn fits() {
+        let mut builder = DescriptionBuilder::new();
+
+        for i in 0..3 {
+            let _ = writeln!(builder, "entry {i}");
+            builder.finish_entry();
+        }
+
+        assert_eq!(builder.build(), "entry 0\nentry 1\nentry 2");
+    }
+
+    #[test]
+    fn truncates_at_entry_boundary() {
+        let mut builder = DescriptionBuilder::with_limit(60);
+
+        for i in 0..4 {
+            let _ = writeln!(builder, "entry {i}");
+            builder.finish_entry();
+        }
+
+        assert_eq!(
+            builder.build(),
+            "entry 0\nentry 1\nentry 2\n*+1 more on this page*"
+        );
+    }
+}
@@ -11,7 +11,7 @@ use super::EmbedBuilder;
 pub struct MessageBuilder<'c> {
     pub content: Option<Cow<'c, str>>,
     pub embed: EmbedOption,
-    pub attachment: Option<Attachment>,
+    pub attachments: Vec<Attachment>,
     pub components: Option<Vec<Component>>,
 }
 
@@ -81,8 +81,11 @@ impl<'c> MessageBuilder<'c> {
         self
     }
 
+    /// Add an attachment to the message.
     pub fn attachment(mut self, name: impl Into<String>, bytes: Vec<u8>) -> Self {
-        self.attachment = Some(Attachment::from_bytes(name.into(), bytes, 1));
+        let id = self.attachments.len() as u64 + 1;
+        self.attachments
+            .push(Attachment::from_bytes(name.into(), bytes, id));
 
         self
     }
@@ -92,6 +95,57 @@ impl<'c> MessageBuilder<'c> {
 
         self
     }
+
+    /// Remove the attachments and mention them in the embed or content
+    /// instead.
+    ///
+    /// Embed images that refer to an attachment are removed as well.
+    pub fn omit_attachment(mut self, reason: &str) -> Self {
+        if self.attachments.is_empty() {
+            return self;
+        }
+
+        let filenames: Vec<_> = self
+            .attachments
+            .drain(..)
+            .map(|attachment| format!("`{}`", attachment.filename))
+            .collect();
+
+        let notice = format!("🖼️ Omitted {} {reason}", filenames.join(", "));
+
+        match self.embed {
+            EmbedOption::Include(ref mut embed) => {
+                let is_attachment = |url: &Option<String>| {
+                    url.as_deref()
+                        .is_some_and(|url| url.starts_with("attachment://"))
+                };
+
+                if is_attachment(&embed.image_url) {
+                    embed.image_url = None;
+                }
+
+                if is_attachment(&embed.thumbnail_url) {
+                    embed.thumbnail_url = None;
+                }
+
+                match embed.description {
+                    Some(ref mut description) => {
+                        description.push_str("\n\n");
+                        description.push_str(&notice);
+                    }
+                    None => embed.description = Some(notice),
+                }
+            }
+            EmbedOption::Clear | EmbedOption::None => {
+                self.content = match self.content.take() {
+                    Some(content) => Some(format!("{content}\n{notice}").into()),
+                    None => Some(notice.into()),
+                };
+            }
+        }
+
+        self
+    }
 }
 
 impl From<EmbedBuilder> for MessageBuilder<'_> {
@@ -1,13 +1,16 @@
 pub use self::{
     author::AuthorBuilder,
+    description::DescriptionBuilder,
     embed::{EmbedBuilder, attachment},
     footer::FooterBuilder,
     message::MessageBuilder,
 };
 
 mod author;
+mod description;
 mod embed;
 mod footer;
 mod message;
+mod plain_text;
 
 pub mod modal;
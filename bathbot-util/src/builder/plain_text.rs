@@ -0,0 +1,74 @@
+use std::fmt::Write;
+
+use super::EmbedBuilder;
+use crate::matcher;
+
+/// Maximum length of a message's content.
+const CONTENT_LIMIT: usize = 2000;
+
+impl EmbedBuilder {
+    /// Serialize the embed into plain text that screen readers can read in
+    /// order, unlike embeds with many inline fields.
+    ///
+    /// Custom emotes are replaced with their name and the result is
+    /// truncated so that it fits into a message's content.
+    pub fn to_plain_text(&self) -> String {
+        let mut text = String::new();
+
+        if let Some(ref author) = self.author {
+            let _ = writeln!(text, "{}", author.name);
+        }
+
+        if let Some(ref title) = self.title {
+            let _ = writeln!(text, "**{title}**");
+        }
+
+        if let Some(ref description) = self.description {
+            let _ = writeln!(text, "{description}");
+        }
+
+        for field in self.fields.iter() {
+            let _ = writeln!(text, "{}: {}", field.name, field.value);
+        }
+
+        if let Some(ref footer) = self.footer {
+            let _ = writeln!(text, "{}", footer.text);
+        }
+
+        let mut text = matcher::replace_custom_emotes(text.trim_end()).into_owned();
+
+        if text.chars().count() > CONTENT_LIMIT {
+            text = text.chars().take(CONTENT_LIMIT - 1).collect();
+            text.push('…');
+        }
+
+        text
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use twilight_model::channel::message::embed::EmbedField;
+
+    use crate::{EmbedBuilder, FooterBuilder};
+
+    #[test]
+    fn plain_text_order() {
+        let field = |name: &str, value: &str| EmbedField {
+            inline: true,
+            name: name.to_owned(),
+            value: value.to_owned(),
+        };
+
+        let embed = EmbedBuilder::new()
+            .title("Title")
+            .description("<:grade_S:1234> 1. Score")
+            .fields(vec![field("a", "1"), field("b", "2")])
+            .footer(FooterBuilder::new("Page 1/2"));
+
+        assert_eq!(
+            embed.to_plain_text(),
+            "**Title**\ngrade_S 1. Score\na: 1\nb: 2\nPage 1/2"
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use time::{
-    OffsetDateTime,
+    OffsetDateTime, PrimitiveDateTime, UtcOffset,
     format_description::{
         Component, FormatItem,
         modifier::{Day, Hour, Minute, Month, OffsetHour, OffsetMinute, Second, Year},
@@ -182,10 +182,85 @@ pub const DATETIME_Z_FORMAT: &[FormatItem<'_>] = &[
     FormatItem::Literal(b"Z"),
 ];
 
+/// Parse a timezone such as `UTC`, `UTC+2`, `GMT-5:30`, or `+09:00`.
+pub fn parse_utc_offset(s: &str) -> Option<UtcOffset> {
+    let s = s.trim();
+
+    let offset = match s.get(..3) {
+        Some(prefix)
+            if prefix.eq_ignore_ascii_case("utc") || prefix.eq_ignore_ascii_case("gmt") =>
+        {
+            &s[3..]
+        }
+        _ => s,
+    };
+
+    if offset.is_empty() {
+        return Some(UtcOffset::UTC);
+    }
+
+    let (sign, offset) = match offset.as_bytes()[0] {
+        b'+' => (1, &offset[1..]),
+        b'-' => (-1, &offset[1..]),
+        _ => return None,
+    };
+
+    let (hours, minutes) = match offset.split_once(':') {
+        Some((hours, minutes)) => (hours.parse::<i8>().ok()?, minutes.parse::<i8>().ok()?),
+        None => (offset.parse::<i8>().ok()?, 0),
+    };
+
+    if !(0..=14).contains(&hours) || !(0..60).contains(&minutes) {
+        return None;
+    }
+
+    UtcOffset::from_hms(sign * hours, sign * minutes, 0).ok()
+}
+
+/// Parse a date and time such as `2025-05-01 18:00` that is given in the
+/// timezone of `offset`.
+pub fn parse_local_datetime(s: &str, offset: UtcOffset) -> Option<OffsetDateTime> {
+    let s = s.trim();
+
+    PrimitiveDateTime::parse(s, SHORT_NAIVE_DATETIME_FORMAT)
+        .or_else(|_| PrimitiveDateTime::parse(s, NAIVE_DATETIME_FORMAT))
+        .ok()
+        .map(|datetime| datetime.assume_offset(offset))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_parse_utc_offset() {
+        assert_eq!(parse_utc_offset("UTC"), Some(UtcOffset::UTC));
+        assert_eq!(
+            parse_utc_offset("utc+2").map(UtcOffset::whole_hours),
+            Some(2)
+        );
+        assert_eq!(
+            parse_utc_offset("GMT-5:30").map(UtcOffset::as_hms),
+            Some((-5, -30, 0))
+        );
+        assert_eq!(
+            parse_utc_offset("+09:00").map(UtcOffset::whole_hours),
+            Some(9)
+        );
+        assert_eq!(parse_utc_offset("UTC+15"), None);
+        assert_eq!(parse_utc_offset("CEST"), None);
+    }
+
+    #[test]
+    fn test_parse_local_datetime() {
+        let offset = parse_utc_offset("UTC+2").unwrap();
+        let datetime = parse_local_datetime("2025-05-01 18:00", offset).unwrap();
+
+        assert_eq!(datetime.to_offset(UtcOffset::UTC).hour(), 16);
+        assert!(parse_local_datetime("2025-05-01 18:00:30", offset).is_some());
+        assert!(parse_local_datetime("tomorrow", offset).is_none());
+    }
+
     #[test]
     fn test_sec_to_minsec() {
         assert_eq!(SecToMinSec::new(92).to_string(), String::from("1:32"));
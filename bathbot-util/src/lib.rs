@@ -19,10 +19,14 @@ pub mod numbers;
 pub mod osu;
 pub mod query;
 pub mod string_cmp;
+pub mod units;
 
 pub use self::{
     buckets::{Bucket, BucketName, Buckets},
-    builder::{AuthorBuilder, EmbedBuilder, FooterBuilder, MessageBuilder, attachment, modal},
+    builder::{
+        AuthorBuilder, DescriptionBuilder, EmbedBuilder, FooterBuilder, MessageBuilder, attachment,
+        modal,
+    },
     cow::CowUtils,
     exp_backoff::ExponentialBackoff,
     ext::*,
@@ -19,6 +19,11 @@ pub fn is_custom_emote(msg: &str) -> bool {
     EMOJI_MATCHER.is_match(msg)
 }
 
+/// Replace all custom emotes with their name.
+pub fn replace_custom_emotes(msg: &str) -> Cow<'_, str> {
+    EMOJI_MATCHER.replace_all(msg, "$2")
+}
+
 enum MentionType {
     Role,
     User,
@@ -205,6 +210,13 @@ pub fn get_mods(msg: &str) -> Option<ModSelection> {
     }
 }
 
+/// Parse a hex color code such as `#ff66aa` into `0xRRGGBB`.
+pub fn get_hex_color(msg: &str) -> Option<u32> {
+    HEX_COLOR_MATCHER
+        .captures(msg)
+        .and_then(|caps| u32::from_str_radix(&caps[1], 16).ok())
+}
+
 #[allow(dead_code)]
 pub fn is_hit_results(msg: &str) -> bool {
     HIT_RESULTS_MATCHER.is_match(msg)
@@ -242,6 +254,8 @@ define_regex! {
 
     EMOJI_MATCHER: r"<(a?):([^:\n]+):(\d+)>";
 
+    HEX_COLOR_MATCHER: r"^#?([0-9a-fA-F]{6})$";
+
     SEVEN_TWO_SEVEN: "(?P<num>7[.,]?2[.,]?7)";
 
     OSU_SCORE_URL_MATCHER: r"https://osu\.ppy\.sh/scores/(?:(osu|taiko|mania|fruits)/)?(\d+)";
@@ -1,14 +1,24 @@
-use std::fmt::{Display, Formatter, Result as FmtResult};
+use std::fmt::{Display, Formatter, Result as FmtResult, Write};
 
 use rosu_v2::model::mods::{
     GameMod, GameMods,
     generated_mods::{
-        DaycoreCatch, DaycoreMania, DaycoreOsu, DaycoreTaiko, DoubleTimeCatch, DoubleTimeMania,
+        AdaptiveSpeedMania, AdaptiveSpeedOsu, AdaptiveSpeedTaiko, DaycoreCatch, DaycoreMania,
+        DaycoreOsu, DaycoreTaiko, DifficultyAdjustCatch, DifficultyAdjustMania,
+        DifficultyAdjustOsu, DifficultyAdjustTaiko, DoubleTimeCatch, DoubleTimeMania,
         DoubleTimeOsu, DoubleTimeTaiko, HalfTimeCatch, HalfTimeMania, HalfTimeOsu, HalfTimeTaiko,
-        NightcoreCatch, NightcoreMania, NightcoreOsu, NightcoreTaiko,
+        NightcoreCatch, NightcoreMania, NightcoreOsu, NightcoreTaiko, WindDownCatch, WindDownMania,
+        WindDownOsu, WindDownTaiko, WindUpCatch, WindUpMania, WindUpOsu, WindUpTaiko,
     },
 };
 
+/// If the mods formatted with all settings exceed this length, only the
+/// speed change settings will be shown.
+const MAX_LEN: usize = 32;
+
+/// How many settings of a single mod are shown before the rest is cut off.
+const MAX_SETTINGS_PER_MOD: usize = 2;
+
 pub struct ModsFormatter<'a> {
     mods: &'a GameMods,
     legacy_order: bool,
@@ -19,9 +29,9 @@ impl<'a> ModsFormatter<'a> {
         Self { mods, legacy_order }
     }
 
-    fn format_mods(&self, f: &mut Formatter<'_>) -> FmtResult {
+    fn format_mods(&self, w: &mut impl Write, all_settings: bool) -> FmtResult {
         for gamemod in self.mods.iter() {
-            f.write_str(gamemod.acronym().as_str())?;
+            w.write_str(gamemod.acronym().as_str())?;
 
             match gamemod {
                 GameMod::HalfTimeOsu(HalfTimeOsu { speed_change, .. })
@@ -41,9 +51,101 @@ impl<'a> ModsFormatter<'a> {
                 | GameMod::DoubleTimeMania(DoubleTimeMania { speed_change, .. })
                 | GameMod::NightcoreMania(NightcoreMania { speed_change, .. }) => {
                     if let Some(speed_change) = speed_change {
-                        write!(f, "({}x)", (*speed_change * 100.0).round() / 100.0)?
+                        write!(w, "({}x)", round(*speed_change))?
+                    }
+                }
+                _ if !all_settings => {}
+                GameMod::WindUpOsu(WindUpOsu {
+                    initial_rate,
+                    final_rate,
+                    ..
+                })
+                | GameMod::WindDownOsu(WindDownOsu {
+                    initial_rate,
+                    final_rate,
+                    ..
+                })
+                | GameMod::WindUpTaiko(WindUpTaiko {
+                    initial_rate,
+                    final_rate,
+                    ..
+                })
+                | GameMod::WindDownTaiko(WindDownTaiko {
+                    initial_rate,
+                    final_rate,
+                    ..
+                })
+                | GameMod::WindUpCatch(WindUpCatch {
+                    initial_rate,
+                    final_rate,
+                    ..
+                })
+                | GameMod::WindDownCatch(WindDownCatch {
+                    initial_rate,
+                    final_rate,
+                    ..
+                })
+                | GameMod::WindUpMania(WindUpMania {
+                    initial_rate,
+                    final_rate,
+                    ..
+                })
+                | GameMod::WindDownMania(WindDownMania {
+                    initial_rate,
+                    final_rate,
+                    ..
+                }) => match (initial_rate, final_rate) {
+                    (Some(initial), Some(r#final)) => {
+                        write!(w, "({}x→{}x)", round(*initial), round(*r#final))?
+                    }
+                    (Some(initial), None) => write!(w, "({}x→)", round(*initial))?,
+                    (None, Some(r#final)) => write!(w, "(→{}x)", round(*r#final))?,
+                    (None, None) => {}
+                },
+                GameMod::AdaptiveSpeedOsu(AdaptiveSpeedOsu { initial_rate, .. })
+                | GameMod::AdaptiveSpeedTaiko(AdaptiveSpeedTaiko { initial_rate, .. })
+                | GameMod::AdaptiveSpeedMania(AdaptiveSpeedMania { initial_rate, .. }) => {
+                    if let Some(initial_rate) = initial_rate {
+                        write!(w, "({}x)", round(*initial_rate))?
                     }
                 }
+                GameMod::DifficultyAdjustOsu(DifficultyAdjustOsu {
+                    circle_size,
+                    approach_rate,
+                    drain_rate,
+                    overall_difficulty,
+                    ..
+                })
+                | GameMod::DifficultyAdjustCatch(DifficultyAdjustCatch {
+                    circle_size,
+                    approach_rate,
+                    drain_rate,
+                    overall_difficulty,
+                    ..
+                }) => {
+                    let settings = [
+                        ("AR", approach_rate),
+                        ("CS", circle_size),
+                        ("OD", overall_difficulty),
+                        ("HP", drain_rate),
+                    ];
+
+                    write_settings(w, settings)?;
+                }
+                GameMod::DifficultyAdjustTaiko(DifficultyAdjustTaiko {
+                    drain_rate,
+                    overall_difficulty,
+                    ..
+                })
+                | GameMod::DifficultyAdjustMania(DifficultyAdjustMania {
+                    drain_rate,
+                    overall_difficulty,
+                    ..
+                }) => {
+                    let settings = [("OD", overall_difficulty), ("HP", drain_rate)];
+
+                    write_settings(w, settings)?;
+                }
                 _ => {}
             }
         }
@@ -70,9 +172,106 @@ impl Display for ModsFormatter<'_> {
         }
 
         if self.legacy_order {
-            self.legacacy_format_mods(f)
-        } else {
-            self.format_mods(f)
+            return self.legacacy_format_mods(f);
         }
+
+        let mut buf = String::new();
+        self.format_mods(&mut buf, true)?;
+
+        // Too many settings clutter the output so only keep the important ones
+        if buf.chars().count() > MAX_LEN {
+            buf.clear();
+            self.format_mods(&mut buf, false)?;
+        }
+
+        f.write_str(&buf)
+    }
+}
+
+/// Writes e.g. `(AR10,CS4)` for all specified settings.
+fn write_settings<const N: usize, T: Copy + Into<f64>>(
+    w: &mut impl Write,
+    settings: [(&str, &Option<T>); N],
+) -> FmtResult {
+    let mut written = 0;
+
+    for (name, value) in settings {
+        let Some(value) = value else { continue };
+
+        if written == MAX_SETTINGS_PER_MOD {
+            return w.write_str(",…)");
+        }
+
+        w.write_char(if written == 0 { '(' } else { ',' })?;
+        write!(w, "{name}{}", round(*value))?;
+        written += 1;
+    }
+
+    if written > 0 {
+        w.write_char(')')?;
+    }
+
+    Ok(())
+}
+
+fn round(value: impl Into<f64>) -> f64 {
+    (value.into() * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn format(gamemods: impl IntoIterator<Item = GameMod>) -> String {
+        let mods: GameMods = gamemods.into_iter().collect();
+
+        ModsFormatter::new(&mods, false).to_string()
+    }
+
+    #[test]
+    fn speed_change() {
+        let dt = GameMod::DoubleTimeOsu(DoubleTimeOsu {
+            speed_change: Some(1.3),
+            ..Default::default()
+        });
+
+        assert_eq!(format([dt]), "DT(1.3x)");
+    }
+
+    #[test]
+    fn difficulty_adjust_truncated() {
+        let da = GameMod::DifficultyAdjustOsu(DifficultyAdjustOsu {
+            approach_rate: Some(10.0),
+            circle_size: Some(4.0),
+            drain_rate: Some(5.0),
+            ..Default::default()
+        });
+
+        assert_eq!(format([da]), "DA(AR10,CS4,…)");
+    }
+
+    #[test]
+    fn too_long_keeps_speed_change() {
+        let dt = GameMod::DoubleTimeOsu(DoubleTimeOsu {
+            speed_change: Some(1.25),
+            ..Default::default()
+        });
+
+        let da = GameMod::DifficultyAdjustOsu(DifficultyAdjustOsu {
+            approach_rate: Some(9.5),
+            circle_size: Some(4.2),
+            ..Default::default()
+        });
+
+        let wu = GameMod::WindUpOsu(WindUpOsu {
+            initial_rate: Some(0.75),
+            final_rate: Some(1.5),
+            ..Default::default()
+        });
+
+        let formatted = format([dt, da, wu]);
+
+        assert!(formatted.contains("DT(1.25x)"));
+        assert!(!formatted.contains("AR9.5"));
     }
 }
@@ -5,9 +5,15 @@ use std::{
     slice::Iter,
 };
 
-use rosu_v2::prelude::{
-    GameMod, GameModIntermode, GameMode, GameMods, GameModsIntermode, Grade, LegacyScoreStatistics,
-    Score, ScoreStatistics,
+use rosu_v2::{
+    model::mods::generated_mods::{
+        AdaptiveSpeedMania, AdaptiveSpeedOsu, AdaptiveSpeedTaiko, WindDownCatch, WindDownMania,
+        WindDownOsu, WindDownTaiko, WindUpCatch, WindUpMania, WindUpOsu, WindUpTaiko,
+    },
+    prelude::{
+        GameMod, GameModIntermode, GameMode, GameMods, GameModsIntermode, Grade,
+        LegacyScoreStatistics, Score, ScoreStatistics,
+    },
 };
 
 use crate::{constants::OSU_BASE, matcher, numbers::round};
@@ -291,6 +297,69 @@ impl ModSelection {
     }
 }
 
+/// The clock rate that the mods effectively apply.
+///
+/// Unlike [`GameMods::clock_rate`], mods with a variable rate such as `WU`,
+/// `WD`, or `AS` are considered by approximating their average rate.
+pub fn effective_clock_rate(mods: &GameMods) -> f64 {
+    if let Some(clock_rate) = mods.clock_rate() {
+        return clock_rate;
+    }
+
+    // Variable rate mods are incompatible with fixed rate mods so only they
+    // need to be considered here
+    mods.iter()
+        .map(|gamemod| match gamemod {
+            GameMod::WindUpOsu(WindUpOsu {
+                initial_rate,
+                final_rate,
+                ..
+            })
+            | GameMod::WindUpTaiko(WindUpTaiko {
+                initial_rate,
+                final_rate,
+                ..
+            })
+            | GameMod::WindUpCatch(WindUpCatch {
+                initial_rate,
+                final_rate,
+                ..
+            })
+            | GameMod::WindUpMania(WindUpMania {
+                initial_rate,
+                final_rate,
+                ..
+            }) => (initial_rate.unwrap_or(1.0) + final_rate.unwrap_or(1.5)) / 2.0,
+            GameMod::WindDownOsu(WindDownOsu {
+                initial_rate,
+                final_rate,
+                ..
+            })
+            | GameMod::WindDownTaiko(WindDownTaiko {
+                initial_rate,
+                final_rate,
+                ..
+            })
+            | GameMod::WindDownCatch(WindDownCatch {
+                initial_rate,
+                final_rate,
+                ..
+            })
+            | GameMod::WindDownMania(WindDownMania {
+                initial_rate,
+                final_rate,
+                ..
+            }) => (initial_rate.unwrap_or(1.0) + final_rate.unwrap_or(0.75)) / 2.0,
+            GameMod::AdaptiveSpeedOsu(AdaptiveSpeedOsu { initial_rate, .. })
+            | GameMod::AdaptiveSpeedTaiko(AdaptiveSpeedTaiko { initial_rate, .. })
+            | GameMod::AdaptiveSpeedMania(AdaptiveSpeedMania { initial_rate, .. }) => {
+                initial_rate.unwrap_or(1.0)
+            }
+            _ => 1.0,
+        })
+        .product()
+}
+
 pub fn flag_url(country_code: &str) -> String {
     // format!("{OSU_BASE}/images/flags/{country_code}.png") // from osu itself but
     // outdated
@@ -882,8 +951,32 @@ fn mania_grade_legacy(
 
 #[cfg(test)]
 mod tests {
+    use rosu_v2::model::mods::generated_mods::DoubleTimeOsu;
+
     use super::*;
 
+    #[test]
+    fn effective_clock_rate_fixed() {
+        let dt: GameMods = [GameMod::DoubleTimeOsu(DoubleTimeOsu {
+            speed_change: Some(1.2),
+            ..Default::default()
+        })]
+        .into_iter()
+        .collect();
+
+        assert!((effective_clock_rate(&dt) - 1.2).abs() < f64::EPSILON);
+        assert!((effective_clock_rate(&GameMods::new()) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn effective_clock_rate_variable() {
+        let wu: GameMods = [GameMod::WindUpOsu(Default::default())]
+            .into_iter()
+            .collect();
+
+        assert!((effective_clock_rate(&wu) - 1.25).abs() < f64::EPSILON);
+    }
+
     #[test]
     fn mod_selection_filter_exclude() {
         let hdnc: GameMods = [
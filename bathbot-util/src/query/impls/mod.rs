@@ -31,6 +31,10 @@ fn try_update_len(length: &mut OptionalRange<f32>, op: Operator, value: &str) ->
     length.try_update_value(op, len * scale, scale / 2.0)
 }
 
+fn try_update_percent(percent: &mut OptionalRange<f32>, op: Operator, value: &str) -> bool {
+    percent.try_update(op, value.trim_end_matches('%'), 0.05)
+}
+
 fn display_range<T>(content: &mut String, name: &str, range: &OptionalRange<T>)
 where
     OptionalRange<T>: Debug,
@@ -17,6 +17,7 @@ pub struct RegularCriteria<'q> {
     pub length: OptionalRange<f32>,
     pub bpm: OptionalRange<f32>,
     pub keys: OptionalRange<f32>,
+    pub ln: OptionalRange<f32>,
 
     pub artist: OptionalText<'q>,
     pub creator: OptionalText<'q>,
@@ -42,6 +43,7 @@ impl<'q> IFilterCriteria<'q> for RegularCriteria<'q> {
             "artist" => self.artist.try_update(op, value),
             "title" => self.title.try_update(op, value),
             "key" | "keys" => self.keys.try_update(op, &value, 0.5),
+            "ln" | "lnpercent" => super::try_update_percent(&mut self.ln, op, &value),
             _ => false,
         }
     }
@@ -56,6 +58,7 @@ impl<'q> IFilterCriteria<'q> for RegularCriteria<'q> {
             length,
             bpm,
             keys,
+            ln,
             artist,
             creator,
             title,
@@ -69,6 +72,7 @@ impl<'q> IFilterCriteria<'q> for RegularCriteria<'q> {
             && length.is_empty()
             && bpm.is_empty()
             && keys.is_empty()
+            && ln.is_empty()
             && artist.is_empty()
             && creator.is_empty()
             && title.is_empty())
@@ -84,6 +88,7 @@ impl<'q> IFilterCriteria<'q> for RegularCriteria<'q> {
             length,
             bpm,
             keys,
+            ln,
             artist,
             creator,
             title,
@@ -97,6 +102,7 @@ impl<'q> IFilterCriteria<'q> for RegularCriteria<'q> {
         display_range(content, "Stars", stars);
         display_range(content, "BPM", bpm);
         display_range(content, "Keys", keys);
+        display_range(content, "LN%", ln);
         display_range(content, "AR", ar);
         display_range(content, "AR", ar);
 
@@ -24,6 +24,7 @@ pub struct TopCriteria<'q> {
     pub combo: OptionalRange<u32>,
     pub miss: OptionalRange<u32>,
     pub keys: OptionalRange<f32>,
+    pub ln: OptionalRange<f32>,
 
     pub date: OptionalRange<Date>,
     pub ranked_date: OptionalRange<Date>,
@@ -57,6 +58,7 @@ impl<'q> IFilterCriteria<'q> for TopCriteria<'q> {
                 self.miss.try_update(op, &value, 0)
             }
             "key" | "keys" => self.keys.try_update(op, &value, 0.5),
+            "ln" | "lnpercent" => super::try_update_percent(&mut self.ln, op, &value),
 
             "date" | "scoredate" | "ended_at" => self.date.try_update_date(op, &value),
             "ranked" | "rankeddate" | "ranked_date" => self.ranked_date.try_update_date(op, &value),
@@ -84,6 +86,7 @@ impl<'q> IFilterCriteria<'q> for TopCriteria<'q> {
             combo,
             miss,
             keys,
+            ln,
             date,
             ranked_date,
             artist,
@@ -105,6 +108,7 @@ impl<'q> IFilterCriteria<'q> for TopCriteria<'q> {
             && combo.is_empty()
             && miss.is_empty()
             && keys.is_empty()
+            && ln.is_empty()
             && date.is_empty()
             && ranked_date.is_empty()
             && artist.is_empty()
@@ -128,6 +132,7 @@ impl<'q> IFilterCriteria<'q> for TopCriteria<'q> {
             combo,
             miss,
             keys,
+            ln,
             date,
             ranked_date,
             artist,
@@ -149,6 +154,7 @@ impl<'q> IFilterCriteria<'q> for TopCriteria<'q> {
         display_range(content, "Misses", miss);
         display_range(content, "Score", score);
         display_range(content, "Keys", keys);
+        display_range(content, "LN%", ln);
 
         display_text(content, "Artist", artist);
         display_text(content, "Title", title);
@@ -0,0 +1,61 @@
+//! Canonical precision for the values that show up in most embeds.
+//!
+//! Using these instead of `round` or manual `{:.2}` ensures that the same
+//! value looks the same regardless of which command displays it.
+
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+/// Performance points with two decimals e.g. `123.40`.
+#[derive(Copy, Clone, Debug)]
+pub struct Pp(pub f32);
+
+impl Display for Pp {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:.2}", self.0)
+    }
+}
+
+/// Star rating with two decimals e.g. `6.70`.
+#[derive(Copy, Clone, Debug)]
+pub struct Stars(pub f32);
+
+impl Display for Stars {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "{:.2}", self.0)
+    }
+}
+
+/// Accuracy with at most two decimals and without trailing zeros e.g. `98.5`.
+#[derive(Copy, Clone, Debug)]
+pub struct Acc(pub f32);
+
+impl Display for Acc {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        let s = format!("{:.2}", self.0);
+        let trimmed = s.trim_end_matches('0').trim_end_matches('.');
+
+        f.write_str(trimmed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pp_and_stars() {
+        assert_eq!(Pp(312.4).to_string(), "312.40");
+        assert_eq!(Pp(1000.0).to_string(), "1000.00");
+        assert_eq!(Stars(6.789).to_string(), "6.79");
+    }
+
+    #[test]
+    fn acc() {
+        assert_eq!(Acc(98.5).to_string(), "98.5");
+        assert_eq!(Acc(98.123).to_string(), "98.12");
+        assert_eq!(Acc(100.0).to_string(), "100");
+        assert_eq!(Acc(99.999).to_string(), "100");
+        assert_eq!(Acc(90.0).to_string(), "90");
+        assert_eq!(Acc(0.0).to_string(), "0");
+    }
+}
@@ -1,6 +1,5 @@
 use std::time::Duration;
 
-use bathbot_util::MessageBuilder;
 use eyre::{Report, Result, WrapErr};
 use tokio::{
     sync::watch::{self, Receiver},
@@ -8,11 +7,11 @@ use tokio::{
 };
 
 use super::{
-    ActiveMessage, BuildPage, FullActiveMessage, IActiveMessage,
+    ActiveMessage, FullActiveMessage, IActiveMessage,
     origin::{ActiveMessageOrigin, ActiveMessageOriginError},
     response::ActiveResponse,
 };
-use crate::core::Context;
+use crate::core::{Context, spawn_with_osu_server};
 
 pub struct ActiveMessagesBuilder {
     inner: ActiveMessage,
@@ -50,22 +49,25 @@ impl ActiveMessagesBuilder {
                 start_by_update,
             } = builder;
 
-            let BuildPage {
-                embed,
-                content,
-                defer: _,
-            } = active_msg
+            let plain_text = match orig.user_id() {
+                Some(user_id) => match Context::user_config().accessibility(user_id).await {
+                    Ok(accessibility) => accessibility,
+                    Err(err) => {
+                        warn!(?err, "Failed to get accessibility config");
+
+                        false
+                    }
+                },
+                None => false,
+            };
+
+            let page = active_msg
                 .build_page()
                 .await
                 .wrap_err("Failed to build page")?;
 
             let components = active_msg.build_components();
-
-            let mut builder = MessageBuilder::new().embed(embed).components(components);
-
-            if let Some(ref content) = content {
-                builder = builder.content(content.as_ref());
-            }
+            let mut builder = page.into_message(components, plain_text);
 
             if let Some((name, bytes)) = attachment {
                 builder = builder.attachment(name, bytes);
@@ -92,6 +94,7 @@ impl ActiveMessagesBuilder {
                 let full = FullActiveMessage {
                     active_msg,
                     activity_tx,
+                    plain_text,
                 };
 
                 Context::get().active_msgs.insert(msg, full).await;
@@ -115,7 +118,7 @@ impl ActiveMessagesBuilder {
     }
 
     fn spawn_timeout(mut rx: Receiver<()>, response: ActiveResponse, until_timeout: Duration) {
-        tokio::spawn(async move {
+        spawn_with_osu_server(async move {
             loop {
                 tokio::select! {
                     res = rx.changed() => if res.is_ok() {
@@ -11,7 +11,7 @@ use bathbot_util::{
 };
 use eyre::{Result, WrapErr};
 use twilight_model::{
-    channel::message::Component,
+    channel::message::{Component, embed::EmbedField},
     id::{Id, marker::UserMarker},
 };
 
@@ -29,6 +29,10 @@ pub struct BadgesPagination {
     #[pagination(per_page = 1)]
     badges: Box<[OsekaiBadge]>,
     owners: BTreeMap<usize, Box<[OsekaiBadgeOwner]>>,
+    /// Shown if some data could not be retrieved
+    warning: Option<EmbedField>,
+    /// Rarity score of the user whose badges are shown
+    score: Option<f32>,
     msg_owner: Id<UserMarker>,
     pages: Pages,
 }
@@ -77,15 +81,23 @@ impl IActiveMessage for BadgesPagination {
 
         let awarded_at = badge.awarded_at.format(DATE_FORMAT).unwrap();
 
-        let fields = fields![
+        let mut fields = fields![
             "Owners", owners_str, false;
             "Awarded at", awarded_at, true;
             "Name", badge.name.to_string(), true;
         ];
 
+        fields.extend(self.warning.clone());
+
         let page = pages.curr_page();
         let pages = pages.last_page();
-        let footer_text = format!("Page {page}/{pages} • Check out osekai.net for more info");
+        let mut footer_text = format!("Page {page}/{pages} • ");
+
+        if let Some(score) = self.score {
+            let _ = write!(footer_text, "Badge score: {score:.2} • ");
+        }
+
+        footer_text.push_str("Check out osekai.net for more info");
 
         let url = format!("https://osekai.net/badges/?badge={}", badge.badge_id);
 
@@ -13,6 +13,7 @@ use bathbot_util::{
     datetime::SecToMinSec,
     fields,
     numbers::round,
+    units::{Pp, Stars},
 };
 use eyre::{Report, Result, WrapErr};
 use rosu_pp::{Beatmap, Difficulty, Performance, any::HitResultPriority};
@@ -205,9 +206,9 @@ impl BookmarksPagination {
                 idx = i + start + 1,
                 map = MapFormat::new(&map.artist, &map.title, &map.version),
                 map_id = map.map_id,
-                stars = round(stars),
+                stars = Stars(stars),
                 mode = Emote::from(map.mode),
-                pp = round(max_pp),
+                pp = Pp(max_pp),
                 len = SecToMinSec::new(map.seconds_total),
                 bpm_emote = Emote::Bpm,
                 bpm = round(map.bpm),
@@ -292,9 +293,9 @@ impl BookmarksPagination {
                 title = map.title.cow_escape_markdown(),
                 version = map.version.cow_escape_markdown(),
                 map_id = map.map_id,
-                stars = round(stars),
-                pp_97 = round(pp_97),
-                pp = round(max_pp),
+                stars = Stars(stars),
+                pp_97 = Pp(pp_97),
+                pp = Pp(max_pp),
                 len = SecToMinSec::new(map.seconds_total),
                 bpm_emote = Emote::Bpm,
                 bpm = round(map.bpm),
@@ -5,7 +5,7 @@ use bathbot_model::{ScoreSlim, embed_builder::ScoreEmbedSettings};
 use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
     CowUtils, EmbedBuilder, FooterBuilder, ModsFormatter, ScoreExt, constants::OSU_BASE,
-    datetime::HowLongAgoDynamic, numbers::round,
+    datetime::HowLongAgoDynamic, units::Acc,
 };
 use eyre::Result;
 use rosu_v2::prelude::{GameMode, Score};
@@ -226,7 +226,7 @@ fn write_compact_entry(
         stars = entry.stars,
         pp_format = if pp_idx == Some(i) { "**" } else { "~~" },
         pp = entry.score.pp,
-        acc = round(entry.score.accuracy),
+        acc = Acc(entry.score.accuracy),
         combo = entry.score.max_combo,
         miss = MissFormat::new(&entry.score, entry.max_combo),
         timestamp = HowLongAgoDynamic::new(&entry.score.ended_at),
@@ -5,6 +5,7 @@ use bathbot_util::{
     EmbedBuilder, ModsFormatter,
     constants::OSU_BASE,
     numbers::{WithComma, round},
+    units::{Acc, Pp},
 };
 use eyre::{Result, WrapErr};
 use image::{GenericImageView, ImageBuffer};
@@ -68,13 +69,14 @@ impl ScorePp {
 
         let player = UserCompact::from(&ranking.ranking[idx]);
 
-        let mut plays = Context::osu()
-            .user_scores(player.user_id)
-            .limit(100)
-            .mode(mode)
-            .best()
-            .await
-            .wrap_err("Failed to get user scores")?;
+        let mut plays = Context::osu_request(|osu| {
+            osu.user_scores(player.user_id)
+                .limit(100)
+                .mode(mode)
+                .best()
+        })
+        .await
+        .wrap_err("Failed to get user scores")?;
 
         plays.sort_unstable_by(|a, b| {
             let a_pp = (a.pp.unwrap_or(0.0) - prev_pp).abs();
@@ -184,6 +186,8 @@ impl ScorePp {
     }
 
     pub(super) fn play_string(&self, pp_visible: bool) -> String {
+        let pp = Pp(self.pp);
+
         format!(
             "**{map} +{mods}**\n{grade} {score} • **{acc}%** • **{combo}x**{max_combo} {miss}• **{pp}pp**",
             map = self.map_string,
@@ -192,7 +196,7 @@ impl ScorePp {
             // the score for obvious reasons.
             grade = grade_emote(self.grade),
             score = WithComma::new(self.score),
-            acc = self.acc,
+            acc = Acc(self.acc),
             combo = self.combo,
             max_combo = match self.max_combo {
                 Some(ref combo) => format!("/{combo}x"),
@@ -204,7 +208,7 @@ impl ScorePp {
                 String::new()
             },
             pp = if pp_visible {
-                &self.pp as &dyn Display
+                &pp as &dyn Display
             } else {
                 &"???" as &dyn Display
             }
@@ -259,11 +263,12 @@ impl ScorePp {
             )
             .into_boxed_str(),
             mods: score.mods,
+            // Rounded so that guesses are compared on the displayed values
             pp: round(score.pp.unwrap_or(0.0)),
             combo: score.max_combo,
             max_combo,
             score: score.score,
-            acc: round(score.accuracy),
+            acc: score.accuracy,
             miss_count: score.statistics.miss,
             grade: score.grade,
         }
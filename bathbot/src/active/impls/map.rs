@@ -7,20 +7,29 @@ use bathbot_util::{
     datetime::SecToMinSec,
     fields,
     numbers::{WithComma, round},
+    osu::effective_clock_rate,
+};
+use eyre::{Report, Result, WrapErr};
+use rosu_pp::{
+    Difficulty,
+    any::{DifficultyAttributes, HitResultPriority},
 };
-use eyre::{Result, WrapErr};
-use rosu_pp::{Difficulty, any::HitResultPriority};
 use rosu_v2::prelude::{
     BeatmapExtended, BeatmapsetExtended, GameMode, GameModsIntermode, Username,
 };
 use twilight_model::{
-    channel::message::Component,
+    channel::message::{
+        Component,
+        component::{ActionRow, Button, ButtonStyle},
+        embed::EmbedField,
+    },
     id::{Id, marker::UserMarker},
 };
 
 use crate::{
     active::{
         BuildPage, ComponentResult, IActiveMessage,
+        map_preview::download_links,
         pagination::{Pages, handle_pagination_component, handle_pagination_modal},
     },
     commands::osu::CustomAttrs,
@@ -41,6 +50,8 @@ pub struct MapPagination {
     attrs: CustomAttrs,
     origin: MessageOrigin,
     content: Box<str>,
+    /// Whether additional difficulty attributes should be shown
+    expanded: bool,
     msg_owner: Id<UserMarker>,
     pages: Pages,
 }
@@ -62,25 +73,13 @@ impl IActiveMessage for MapPagination {
             self.mapset.title.as_str().cow_escape_markdown()
         );
 
-        #[cfg(not(feature = "server"))]
-        let url = "https://www.google.com";
-
-        #[cfg(feature = "server")]
-        let url = &crate::core::BotConfig::get().server.public_url;
-
-        let download_value = format!(
-            "[osu!direct]({url}/osudirect/{mapset_id})\n\
-            [catboy.best](https://catboy.best/d/{mapset_id})\n\
-            [osu.direct](https://osu.direct/d/{mapset_id})\n\
-            [nerinyan.moe](https://api.nerinyan.moe/d/{mapset_id})",
-            mapset_id = map.mapset_id,
-        );
+        let download_value = download_links(map.mapset_id);
 
         let mut seconds_total = map.seconds_total;
         let mut seconds_drain = map.seconds_drain;
         let mut bpm = map.bpm as f64;
 
-        let clock_rate = self.mods.legacy_clock_rate();
+        let clock_rate = effective_clock_rate(&self.mods.clone().with_mode(map.mode));
         seconds_total = (seconds_total as f64 / clock_rate) as u32;
         seconds_drain = (seconds_drain as f64 / clock_rate) as u32;
         bpm *= clock_rate;
@@ -120,7 +119,7 @@ impl IActiveMessage for MapPagination {
         const ACCS: [f32; 4] = [95.0, 97.0, 99.0, 100.0];
         let mut pps = Vec::with_capacity(ACCS.len());
 
-        let stars = if rosu_map.check_suspicion().is_ok() {
+        let (stars, diff_attrs) = if rosu_map.check_suspicion().is_ok() {
             let mut attrs = Difficulty::new()
                 .mods(&self.mods)
                 .clock_rate(clock_rate)
@@ -147,13 +146,13 @@ impl IActiveMessage for MapPagination {
                 attrs = pp_result.into();
             }
 
-            attrs.stars()
+            (attrs.stars(), Some(attrs))
         } else {
             for _ in ACCS.iter() {
                 pps.push("0".to_owned());
             }
 
-            0.0
+            (0.0, None)
         };
 
         let mut pp_values = String::with_capacity(128);
@@ -242,6 +241,15 @@ impl IActiveMessage for MapPagination {
 
         fields![fields { field_name, pp_values, false }];
 
+        if self.expanded {
+            match diff_attrs {
+                Some(ref attrs) => push_attribute_fields(&mut fields, attrs),
+                None => fields![fields {
+                    "More attributes", "Unavailable for this map".to_owned(), false
+                }],
+            }
+        }
+
         let (date_text, timestamp) = if let Some(ranked_date) = self.mapset.ranked_date {
             (format!("{:?}", map.status), ranked_date)
         } else {
@@ -312,11 +320,50 @@ impl IActiveMessage for MapPagination {
     }
 
     fn build_components(&self) -> Vec<Component> {
-        self.pages.components()
+        let mut components = self.pages.components();
+
+        let label = if self.expanded {
+            "Less attributes"
+        } else {
+            "More attributes"
+        };
+
+        let button = Button {
+            custom_id: Some("map_attributes".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some(label.to_owned()),
+            style: ButtonStyle::Secondary,
+            url: None,
+            sku_id: None,
+        };
+
+        components.push(Component::ActionRow(ActionRow {
+            components: vec![Component::Button(button)],
+        }));
+
+        components
     }
 
     async fn handle_component(&mut self, component: &mut InteractionComponent) -> ComponentResult {
-        handle_pagination_component(component, self.msg_owner, true, &mut self.pages).await
+        if component.data.custom_id != "map_attributes" {
+            return handle_pagination_component(component, self.msg_owner, true, &mut self.pages)
+                .await;
+        }
+
+        match component.user_id() {
+            Ok(user_id) if user_id == self.msg_owner => {}
+            Ok(_) => return ComponentResult::Ignore,
+            Err(err) => return ComponentResult::Err(err),
+        }
+
+        if let Err(err) = component.defer().await.map_err(Report::new) {
+            return ComponentResult::Err(err.wrap_err("Failed to defer component"));
+        }
+
+        self.expanded = !self.expanded;
+
+        ComponentResult::BuildPage
     }
 
     async fn handle_modal(&mut self, modal: &mut InteractionModal) -> Result<()> {
@@ -330,6 +377,75 @@ impl MapPagination {
     }
 }
 
+fn push_attribute_fields(fields: &mut Vec<EmbedField>, attrs: &DifficultyAttributes) {
+    match attrs {
+        DifficultyAttributes::Osu(attrs) => {
+            let difficulty = format!(
+                "Aim: `{aim:.2}★` Speed: `{speed:.2}★`\n\
+                Flashlight: `{fl:.2}★`\n\
+                Slider factor: `{slider_factor:.3}`\n\
+                Speed notes: `{speed_notes:.2}`",
+                aim = attrs.aim,
+                speed = attrs.speed,
+                fl = attrs.flashlight,
+                slider_factor = attrs.slider_factor,
+                speed_notes = attrs.speed_note_count,
+            );
+
+            let combo = format!(
+                "Circles: `{}` Sliders: `{}`\n\
+                Slider ticks: `{}` Spinners: `{}`\n\
+                Max combo: `{}x`",
+                attrs.n_circles,
+                attrs.n_sliders,
+                attrs.n_large_ticks,
+                attrs.n_spinners,
+                attrs.max_combo,
+            );
+
+            fields![fields {
+                "Difficulty", difficulty, true;
+                "Combo breakdown", combo, true;
+            }];
+        }
+        DifficultyAttributes::Taiko(attrs) => {
+            let difficulty = format!(
+                "Stamina: `{:.2}` Rhythm: `{:.2}`\n\
+                Color: `{:.2}` Peak: `{:.2}`",
+                attrs.stamina, attrs.rhythm, attrs.color, attrs.peak,
+            );
+
+            let combo = format!("Max combo: `{}x`", attrs.max_combo);
+
+            fields![fields {
+                "Difficulty", difficulty, true;
+                "Combo breakdown", combo, true;
+            }];
+        }
+        DifficultyAttributes::Catch(attrs) => {
+            let combo = format!(
+                "Fruits: `{}` Droplets: `{}`\n\
+                Tiny droplets: `{}`\n\
+                Max combo: `{}x`",
+                attrs.n_fruits,
+                attrs.n_droplets,
+                attrs.n_tiny_droplets,
+                attrs.max_combo(),
+            );
+
+            fields![fields { "Combo breakdown", combo, true }];
+        }
+        DifficultyAttributes::Mania(attrs) => {
+            let combo = format!(
+                "Objects: `{}`\nMax combo: `{}x`",
+                attrs.n_objects, attrs.max_combo,
+            );
+
+            fields![fields { "Combo breakdown", combo, true }];
+        }
+    }
+}
+
 async fn creator_name(map: &BeatmapExtended, mapset: &BeatmapsetExtended) -> Option<Username> {
     if map.creator_id == mapset.creator_id {
         return None;
@@ -0,0 +1,154 @@
+use std::fmt::Write;
+
+use bathbot_macros::PaginationBuilder;
+use bathbot_util::{
+    CowUtils, EmbedBuilder, FooterBuilder, attachment,
+    constants::OSU_BASE,
+    datetime::HowLongAgoDynamic,
+    numbers::{WithComma, round},
+};
+use eyre::Result;
+use rosu_v2::prelude::{BeatmapsetExtended, Username};
+use time::OffsetDateTime;
+use twilight_model::{
+    channel::message::Component,
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    active::{
+        BuildPage, ComponentResult, IActiveMessage,
+        pagination::{Pages, handle_pagination_component, handle_pagination_modal},
+    },
+    util::interaction::{InteractionComponent, InteractionModal},
+};
+
+/// Comments longer than this are cut off.
+const MAX_COMMENT_LEN: usize = 300;
+
+#[derive(PaginationBuilder)]
+pub struct MapCommentsPagination {
+    mapset: BeatmapsetExtended,
+    #[pagination(per_page = 5)]
+    comments: Box<[MapComment]>,
+    /// Whether the mapset has user ratings to show in the attached image
+    ratings: bool,
+    msg_owner: Id<UserMarker>,
+    pages: Pages,
+}
+
+pub struct MapComment {
+    pub user_id: Option<u32>,
+    pub username: Username,
+    pub message: String,
+    pub votes: u32,
+    pub created_at: OffsetDateTime,
+}
+
+impl IActiveMessage for MapCommentsPagination {
+    async fn build_page(&mut self) -> Result<BuildPage> {
+        let pages = &self.pages;
+        let idx = pages.index();
+        let end = self.comments.len().min(idx + pages.per_page());
+
+        let mut description = String::with_capacity(1024);
+
+        if let Some((avg, count)) = self.average_rating() {
+            let _ = writeln!(
+                description,
+                "Average rating: **{avg}** / 10 ({count} vote{plural})\n",
+                avg = round(avg),
+                count = WithComma::new(count),
+                plural = if count == 1 { "" } else { "s" }
+            );
+        }
+
+        for comment in self.comments[idx..end].iter() {
+            let name = comment.username.cow_escape_markdown();
+
+            let _ = match comment.user_id {
+                Some(user_id) => write!(description, "**[{name}]({OSU_BASE}u/{user_id})**"),
+                None => write!(description, "**{name}**"),
+            };
+
+            let mut message = comment.message.trim().cow_escape_markdown().into_owned();
+
+            if message.chars().count() > MAX_COMMENT_LEN {
+                let cut = message
+                    .char_indices()
+                    .nth(MAX_COMMENT_LEN - 3)
+                    .map_or(message.len(), |(i, _)| i);
+
+                message.truncate(cut);
+                message.push_str("...");
+            }
+
+            let _ = writeln!(
+                description,
+                " • :thumbsup: {votes} • {ago}\n{message}\n",
+                votes = comment.votes,
+                ago = HowLongAgoDynamic::new(&comment.created_at),
+            );
+        }
+
+        if self.comments.is_empty() {
+            description.push_str("Nobody has commented on this mapset yet");
+        }
+
+        let title = format!(
+            "{artist} - {title}",
+            artist = self.mapset.artist.cow_escape_markdown(),
+            title = self.mapset.title.cow_escape_markdown(),
+        );
+
+        let footer_text = if self.comments.is_empty() {
+            "No comments".to_owned()
+        } else {
+            format!(
+                "Page {page}/{pages} • Most upvoted among the latest comments",
+                page = pages.curr_page(),
+                pages = pages.last_page()
+            )
+        };
+
+        let mut embed = EmbedBuilder::new()
+            .description(description)
+            .footer(FooterBuilder::new(footer_text))
+            .title(title)
+            .url(format!("{OSU_BASE}beatmapsets/{}", self.mapset.mapset_id));
+
+        if self.ratings {
+            embed = embed.image(attachment("map_ratings.png"));
+        }
+
+        Ok(BuildPage::new(embed, false))
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        self.pages.components()
+    }
+
+    async fn handle_component(&mut self, component: &mut InteractionComponent) -> ComponentResult {
+        handle_pagination_component(component, self.msg_owner, false, &mut self.pages).await
+    }
+
+    async fn handle_modal(&mut self, modal: &mut InteractionModal) -> Result<()> {
+        handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages).await
+    }
+}
+
+impl MapCommentsPagination {
+    /// Average user rating and amount of ratings, if there are any.
+    fn average_rating(&self) -> Option<(f32, u32)> {
+        let ratings = self.mapset.ratings.as_deref()?;
+
+        let (sum, count) = ratings
+            .iter()
+            .zip(0..)
+            .fold((0, 0), |(sum, count), (&amount, rating)| {
+                (sum + amount * rating, count + amount)
+            });
+
+        (count > 0).then(|| (sum as f32 / count as f32, count))
+    }
+}
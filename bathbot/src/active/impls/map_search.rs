@@ -35,7 +35,7 @@ impl IActiveMessage for MapSearchPagination {
         let should_request_more = self.defer();
 
         if should_request_more {
-            let next_fut = self.search_result.get_next(Context::osu());
+            let next_fut = Context::osu_request(|osu| self.search_result.get_next(osu));
 
             if let Some(mut next_search_result) = next_fut.await.transpose()? {
                 let idx = self.pages.index();
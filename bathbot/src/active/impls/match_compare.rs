@@ -1,11 +1,8 @@
 use std::{borrow::Cow, cmp::Reverse, collections::HashMap, fmt::Write, mem};
 
 use bathbot_util::{
-    AuthorBuilder, CowUtils, EmbedBuilder, FooterBuilder, IntHasher,
-    constants::OSU_BASE,
-    fields,
-    numbers::{WithComma, round},
-    osu::calculate_grade,
+    AuthorBuilder, CowUtils, EmbedBuilder, FooterBuilder, IntHasher, constants::OSU_BASE, fields,
+    numbers::WithComma, osu::calculate_grade, units::Acc,
 };
 use eyre::Result;
 use rosu_v2::prelude::{
@@ -338,7 +335,7 @@ impl MatchComparePagination {
                 name_len = sizes.name,
                 mods = score.mods,
                 mods_len = sizes.mods,
-                acc = round(score.accuracy),
+                acc = Acc(score.accuracy),
                 combo = score.combo,
                 combo_len = sizes.combo,
                 score = score.score_str,
@@ -50,10 +50,18 @@ impl IActiveMessage for MedalsMissingPagination {
 
         for (i, medal) in medals.iter().enumerate() {
             match medal {
-                MedalType::Group(g) => {
-                    let _ = writeln!(description, "__**{g}:**__");
+                MedalType::Group {
+                    group,
+                    owned,
+                    total,
+                } => {
+                    let _ = writeln!(
+                        description,
+                        "__**{group}:**__ `{bar}` {owned}/{total}",
+                        bar = ProgressBar::new(*owned, *total),
+                    );
 
-                    if let Some(MedalType::Group(_)) = medals.get(i + 1) {
+                    if let Some(MedalType::Group { .. }) = medals.get(i + 1) {
                         description.push_str("All medals acquired\n");
                     } else if i == medals.len() - 1 && includes_last {
                         description.push_str("All medals acquired");
@@ -122,6 +130,41 @@ impl IActiveMessage for MedalsMissingPagination {
     }
 }
 
+/// Unicode bar indicating how many medals of a group are owned.
+struct ProgressBar {
+    filled: usize,
+}
+
+impl ProgressBar {
+    const LEN: usize = 10;
+
+    fn new(owned: usize, total: usize) -> Self {
+        let filled = if total == 0 {
+            Self::LEN
+        } else {
+            (owned * Self::LEN + total / 2) / total
+        };
+
+        Self {
+            filled: filled.min(Self::LEN),
+        }
+    }
+}
+
+impl Display for ProgressBar {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        for _ in 0..self.filled {
+            f.write_str("█")?;
+        }
+
+        for _ in self.filled..Self::LEN {
+            f.write_str("░")?;
+        }
+
+        Ok(())
+    }
+}
+
 enum HoverFormatter {
     Rarity(f32),
     MedalId(u32),
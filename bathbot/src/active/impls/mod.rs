@@ -13,6 +13,7 @@ pub use self::{
     higherlower::HigherLowerGame,
     leaderboard::LeaderboardPagination,
     map::MapPagination,
+    map_comments::{MapComment, MapCommentsPagination},
     map_search::MapSearchPagination,
     match_compare::MatchComparePagination,
     match_costs::MatchCostPagination,
@@ -25,6 +26,7 @@ pub use self::{
     osekai::{MedalCountPagination, MedalRarityPagination},
     osustats::{OsuStatsBestPagination, OsuStatsPlayersPagination, OsuStatsScoresPagination},
     profile::ProfileMenu,
+    rank_graph::RankGraphPagination,
     ranking::RankingPagination,
     ranking_countries::RankingCountriesPagination,
     recent_list::RecentListPagination,
@@ -50,6 +52,7 @@ mod help;
 mod higherlower;
 mod leaderboard;
 mod map;
+mod map_comments;
 mod map_search;
 mod match_compare;
 mod match_costs;
@@ -59,6 +62,7 @@ mod nochoke;
 mod osekai;
 mod osustats;
 mod profile;
+mod rank_graph;
 mod ranking;
 mod ranking_countries;
 mod recent_list;
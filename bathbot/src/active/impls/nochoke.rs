@@ -2,8 +2,8 @@ use std::fmt::{Display, Formatter, Result as FmtResult, Write};
 
 use bathbot_macros::PaginationBuilder;
 use bathbot_util::{
-    CowUtils, EmbedBuilder, FooterBuilder, ModsFormatter, ScoreExt, constants::OSU_BASE,
-    datetime::HowLongAgoDynamic, numbers::WithComma,
+    CowUtils, DescriptionBuilder, EmbedBuilder, FooterBuilder, ModsFormatter, ScoreExt,
+    constants::OSU_BASE, datetime::HowLongAgoDynamic, numbers::WithComma,
 };
 use eyre::Result;
 use twilight_model::{
@@ -52,7 +52,7 @@ impl IActiveMessage for NoChokePagination {
             .to_native();
 
         let pp_diff = (100.0 * (self.unchoked_pp - pp_raw)).round() / 100.0;
-        let mut description = String::with_capacity(512);
+        let mut description = DescriptionBuilder::new();
 
         for entry in entries {
             let NochokeEntry {
@@ -96,6 +96,8 @@ impl IActiveMessage for NoChokePagination {
                 new_combo = entry.unchoked_max_combo(),
                 score_timestamp = HowLongAgoDynamic::new(&original_score.ended_at)
             );
+
+            description.finish_entry();
         }
 
         let title = format!(
@@ -118,7 +120,7 @@ impl IActiveMessage for NoChokePagination {
 
         let embed = EmbedBuilder::new()
             .author(self.user.author_builder(false))
-            .description(description)
+            .description(description.build())
             .footer(FooterBuilder::new(footer_text))
             .thumbnail(self.user.avatar_url.as_ref())
             .title(title);
@@ -6,7 +6,8 @@ use bathbot_util::{
     AuthorBuilder, EmbedBuilder, FooterBuilder, ModsFormatter,
     constants::OSU_BASE,
     datetime::{DATE_FORMAT, HowLongAgoDynamic},
-    numbers::{WithComma, round},
+    numbers::WithComma,
+    units::{Acc, Pp},
 };
 use eyre::Result;
 use rosu_v2::prelude::GameMode;
@@ -100,8 +101,8 @@ impl IActiveMessage for OsuStatsBestPagination {
                 user = score.user.username,
                 user_id = score.user.user_id,
                 grade = config.grade(score.grade),
-                pp = round(score.pp),
-                acc = round(score.accuracy),
+                pp = Pp(score.pp),
+                acc = Acc(score.accuracy),
                 combo = ComboFormatter::new(score.max_combo, Some(score.map.max_combo)),
                 appendix = OrderAppendix::new(score, self.sort),
             );
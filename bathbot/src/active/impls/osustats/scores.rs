@@ -3,10 +3,8 @@ use std::{collections::BTreeMap, fmt::Write};
 use bathbot_macros::PaginationBuilder;
 use bathbot_model::{OsuStatsParams, OsuStatsScoresRaw, ScoreSlim};
 use bathbot_util::{
-    CowUtils, EmbedBuilder, FooterBuilder, ModsFormatter,
-    constants::OSU_BASE,
-    datetime::HowLongAgoDynamic,
-    numbers::{WithComma, round},
+    CowUtils, DescriptionBuilder, EmbedBuilder, FooterBuilder, ModsFormatter, constants::OSU_BASE,
+    datetime::HowLongAgoDynamic, numbers::WithComma, units::Acc,
 };
 use eyre::Result;
 use rosu_v2::prelude::{GameMode, Grade, ScoreStatistics};
@@ -154,7 +152,7 @@ impl IActiveMessage for OsuStatsScoresPagination {
         let pages = pages.last_page();
 
         let entries = self.entries.range(index..index + per_page);
-        let mut description = String::with_capacity(1024);
+        let mut description = DescriptionBuilder::new();
 
         for (_, entry) in entries {
             let OsuStatsEntry {
@@ -178,12 +176,14 @@ impl IActiveMessage for OsuStatsScoresPagination {
                 map_id = map.map_id(),
                 mods = ModsFormatter::new(&score.mods, true),
                 pp = PpFormatter::new(Some(score.pp), Some(*max_pp)),
-                acc = round(score.accuracy),
+                acc = Acc(score.accuracy),
                 score = WithComma::new(score.score),
                 combo = ComboFormatter::new(score.max_combo, Some(*max_combo)),
                 hits = HitResultFormatter::new(score.mode, &score.statistics),
                 ago = HowLongAgoDynamic::new(&score.ended_at),
             );
+
+            description.finish_entry();
         }
 
         let footer = FooterBuilder::new(format!(
@@ -193,7 +193,7 @@ impl IActiveMessage for OsuStatsScoresPagination {
 
         let embed = EmbedBuilder::new()
             .author(self.user.author_builder(false))
-            .description(description)
+            .description(description.build())
             .footer(footer)
             .thumbnail(self.user.avatar_url.as_ref());
 
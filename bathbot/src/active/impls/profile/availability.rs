@@ -80,7 +80,7 @@ impl Availability<MapperNames> {
                         .iter()
                         .filter_map(|(id, _)| (!names.contains_key(id)).then_some(*id));
 
-                    match Context::osu().users(id_iter).await {
+                    match Context::osu_request(|osu| osu.users(id_iter)).await {
                         Ok(users) => names
                             .extend(users.into_iter().map(|user| (user.user_id, user.username))),
                         Err(err) => warn!(?err, "Failed to get mapper names"),
@@ -6,8 +6,9 @@ use bathbot_util::{
     constants::OSU_BASE,
     datetime::{HowLongAgoText, NAIVE_DATETIME_FORMAT, SecToMinSec},
     fields,
-    numbers::{MinMaxAvg, Number, WithComma, round},
+    numbers::{MinMaxAvg, Number, WithComma},
     osu::{BonusPP, total_score_to_reach_level},
+    units::Stars,
 };
 use eyre::Result;
 use rkyv::rancor::{Panic, ResultExt};
@@ -49,6 +50,7 @@ pub struct ProfileMenu {
     discord_id: Option<Id<UserMarker>>,
     tz: Option<UtcOffset>,
     legacy_scores: bool,
+    with_badges: bool,
     skin_url: Availability<SkinUrl>,
     scores: Availability<Box<[Score]>>,
     score_rank: Availability<ScoreData>,
@@ -176,6 +178,7 @@ impl ProfileMenu {
         tz: Option<UtcOffset>,
         osutrack_peaks: Option<RankAccPeaks>,
         legacy_scores: bool,
+        with_badges: bool,
         kind: ProfileKind,
         origin: MessageOrigin,
         msg_owner: Id<UserMarker>,
@@ -186,6 +189,7 @@ impl ProfileMenu {
             tz,
             osutrack_peaks,
             legacy_scores,
+            with_badges,
             kind,
             msg_owner,
             skin_url: Availability::NotRequested,
@@ -254,7 +258,7 @@ impl ProfileMenu {
         }
 
         let embed = EmbedBuilder::new()
-            .author(self.user.author_builder(true))
+            .author(self.user.author_builder_with_badges(true, self.with_badges))
             .description(description)
             .footer(self.footer())
             .thumbnail(self.user.avatar_url.as_ref());
@@ -439,7 +443,7 @@ impl ProfileMenu {
             "Hits per play", WithComma::new(hits_per_play).to_string(), true;
             "Peak accuracy", peak_acc, true;
             "Accuracy", format!("[{acc:.2}%]({origin} \"{acc}%\")", acc = stats.accuracy, origin = self.origin), true;
-            "Recommended", format!("{}★", round(recommended_stars)), true;
+            "Recommended", format!("{}★", Stars(recommended_stars)), true;
             "Max combo", WithComma::new(stats.max_combo.to_native()).to_string(), true;
             "Medals", medals.to_string(), true;
             "Combined grades", combined_grades_value, true;
@@ -452,7 +456,7 @@ impl ProfileMenu {
         ];
 
         let embed = EmbedBuilder::new()
-            .author(self.user.author_builder(true))
+            .author(self.user.author_builder_with_badges(true, self.with_badges))
             .description(description)
             .fields(fields)
             .footer(self.footer())
@@ -637,7 +641,7 @@ impl ProfileMenu {
         };
 
         let embed = EmbedBuilder::new()
-            .author(self.user.author_builder(true))
+            .author(self.user.author_builder_with_badges(true, self.with_badges))
             .description(description)
             .thumbnail(self.user.avatar_url.as_ref());
 
@@ -739,7 +743,7 @@ impl ProfileMenu {
         };
 
         let embed = EmbedBuilder::new()
-            .author(self.user.author_builder(true))
+            .author(self.user.author_builder_with_badges(true, self.with_badges))
             .description(description)
             .fields(fields)
             .thumbnail(self.user.avatar_url.as_ref());
@@ -808,7 +812,7 @@ impl ProfileMenu {
         }
 
         let embed = EmbedBuilder::new()
-            .author(self.user.author_builder(true))
+            .author(self.user.author_builder_with_badges(true, self.with_badges))
             .description(description)
             .thumbnail(self.user.avatar_url.as_ref());
 
@@ -873,7 +877,7 @@ impl ProfileMenu {
         }
 
         let embed = EmbedBuilder::new()
-            .author(self.user.author_builder(true))
+            .author(self.user.author_builder_with_badges(true, self.with_badges))
             .description(description)
             .fields(fields)
             .thumbnail(self.user.avatar_url.as_ref());
@@ -0,0 +1,158 @@
+use bathbot_util::{EmbedBuilder, attachment};
+use eyre::{ContextCompat, Result, WrapErr};
+use twilight_model::{
+    channel::message::{
+        Component,
+        component::{ActionRow, Button, ButtonStyle},
+    },
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    active::{BuildPage, ComponentResult, IActiveMessage},
+    commands::osu::{GraphPalette, draw_rank_graph, rank_graph_range},
+    manager::redis::osu::CachedUser,
+    util::{CachedUserExt, ComponentExt, interaction::InteractionComponent},
+};
+
+pub struct RankGraphPagination {
+    user: CachedUser,
+    /// Rank history including stored snapshots
+    history: Box<[u32]>,
+    palette: GraphPalette,
+    from: u16,
+    until: u16,
+    uncertainty: bool,
+    /// Graph of the initial time range so it doesn't need to be drawn again
+    graph: Option<Vec<u8>>,
+    msg_owner: Id<UserMarker>,
+}
+
+impl RankGraphPagination {
+    /// Buttons' custom id suffix and the amount of days they show.
+    ///
+    /// `All` shows the whole history, including stored snapshots.
+    const WINDOWS: [(&'static str, &'static str, u16); 5] = [
+        ("7", "7d", 7),
+        ("30", "30d", 30),
+        ("90", "90d", 90),
+        ("365", "1y", 365),
+        ("all", "All", u16::MAX),
+    ];
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        user: CachedUser,
+        history: Box<[u32]>,
+        palette: GraphPalette,
+        from: Option<u16>,
+        until: Option<u16>,
+        uncertainty: bool,
+        graph: Vec<u8>,
+        msg_owner: Id<UserMarker>,
+    ) -> Self {
+        let (from, until) = rank_graph_range(from, until);
+
+        let mut this = Self {
+            user,
+            history,
+            palette,
+            from,
+            until,
+            uncertainty,
+            graph: Some(graph),
+            msg_owner,
+        };
+
+        // The graph is drawn only up until the end of the history
+        this.until = this.until.min(this.history_len());
+
+        this
+    }
+
+    fn history_len(&self) -> u16 {
+        self.history.len().min(u16::MAX as usize) as u16
+    }
+}
+
+impl IActiveMessage for RankGraphPagination {
+    async fn build_page(&mut self) -> Result<BuildPage> {
+        let graph = match self.graph.take() {
+            Some(graph) => graph,
+            None => draw_rank_graph(
+                &self.history,
+                self.from,
+                self.until,
+                self.uncertainty,
+                self.palette,
+            )
+            .wrap_err("Failed to draw rank graph")?
+            .wrap_err("Missing rank data for time range")?,
+        };
+
+        let embed = EmbedBuilder::new()
+            .author(self.user.author_builder(false))
+            .image(attachment("graph.png"));
+
+        Ok(BuildPage::new(embed, true).attachment("graph.png", graph))
+    }
+
+    fn build_components(&self) -> Vec<Component> {
+        let len = self.history_len();
+
+        let components = Self::WINDOWS
+            .iter()
+            .map(|&(suffix, label, days)| {
+                let days = days.min(len);
+                let is_current = self.from == 0 && self.until == days;
+
+                Button {
+                    custom_id: Some(format!("rank_graph_{suffix}")),
+                    // At least two days are required to draw a graph
+                    disabled: days < 2 || is_current,
+                    emoji: None,
+                    label: Some(label.to_owned()),
+                    style: if is_current {
+                        ButtonStyle::Primary
+                    } else {
+                        ButtonStyle::Secondary
+                    },
+                    url: None,
+                    sku_id: None,
+                }
+            })
+            .map(Component::Button)
+            .collect();
+
+        vec![Component::ActionRow(ActionRow { components })]
+    }
+
+    async fn handle_component(&mut self, component: &mut InteractionComponent) -> ComponentResult {
+        let user_id = match component.user_id() {
+            Ok(user_id) => user_id,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        if user_id != self.msg_owner {
+            return ComponentResult::Ignore;
+        }
+
+        let suffix = component.data.custom_id.trim_start_matches("rank_graph_");
+
+        let Some(&(_, _, days)) = Self::WINDOWS.iter().find(|(s, ..)| *s == suffix) else {
+            return ComponentResult::Err(eyre!(
+                "Unknown rank graph component `{}`",
+                component.data.custom_id
+            ));
+        };
+
+        self.from = 0;
+        self.until = days.min(self.history_len());
+
+        if let Err(err) = component.defer().await {
+            warn!(?err, "Failed to defer component");
+        }
+
+        ComponentResult::BuildPage
+    }
+}
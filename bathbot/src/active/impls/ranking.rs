@@ -302,11 +302,10 @@ impl RankingPagination {
                     entries.extend(iter);
                 }
                 RankingKind::RankedScore { mode } => {
-                    let ranking = Context::osu()
-                        .score_rankings(*mode)
-                        .page(page)
-                        .await
-                        .wrap_err("Failed to get ranking page")?;
+                    let ranking =
+                        Context::osu_request(|osu| osu.score_rankings(*mode).page(page))
+                            .await
+                            .wrap_err("Failed to get ranking page")?;
 
                     let RankingEntries::Amount(ref mut entries) = self.entries else {
                         unreachable!()
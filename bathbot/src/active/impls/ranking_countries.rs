@@ -54,11 +54,12 @@ impl IActiveMessage for RankingCountriesPagination {
 
             let offset = page - 1;
 
-            let mut ranking = Context::osu()
-                .country_rankings(self.mode)
-                .page(page as u32)
-                .await
-                .wrap_err("Failed to get country rankings")?;
+            let mode = self.mode;
+
+            let mut ranking =
+                Context::osu_request(|osu| osu.country_rankings(mode).page(page as u32))
+                    .await
+                    .wrap_err("Failed to get country rankings")?;
 
             let iter = ranking
                 .ranking
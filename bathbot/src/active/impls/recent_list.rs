@@ -1,24 +1,33 @@
-use std::{collections::HashMap, fmt::Write};
+use std::{
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+};
 
 use bathbot_macros::PaginationBuilder;
 use bathbot_util::{
-    CowUtils, EmbedBuilder, FooterBuilder, IntHasher, constants::OSU_BASE,
-    datetime::HowLongAgoDynamic, numbers::round,
+    Authored, CowUtils, DescriptionBuilder, EmbedBuilder, FooterBuilder, IntHasher,
+    constants::OSU_BASE, datetime::HowLongAgoDynamic, units::Acc,
 };
-use eyre::Result;
+use eyre::{Result, eyre};
 use rosu_v2::prelude::GameMode;
 use twilight_model::{
-    channel::message::Component,
+    channel::message::{
+        Component,
+        component::{ActionRow, SelectMenu, SelectMenuOption, SelectMenuType},
+    },
     id::{Id, marker::UserMarker},
 };
 
 use crate::{
     active::{
-        BuildPage, ComponentResult, IActiveMessage,
+        ActiveMessages, BuildPage, ComponentResult, IActiveMessage,
+        impls::{SimulateAttributes, SimulateComponents, SimulateData, SimulateMap, TopOldVersion},
+        map_preview::{MAP_PREVIEW_ID, handle_map_preview_component, map_preview_menu},
         pagination::{Pages, handle_pagination_component, handle_pagination_modal},
     },
     commands::osu::RecentListEntry,
-    embeds::{ComboFormatter, KeyFormatter, PpFormatter},
+    core::spawn_with_osu_server,
+    embeds::{ComboFormatter, KeyFormatter, LnFormatter, PpFormatter},
     manager::{OsuMap, redis::osu::CachedUser},
     util::{
         CachedUserExt,
@@ -48,7 +57,7 @@ impl IActiveMessage for RecentListPagination {
         let pages = pages.last_page();
         let footer_text = format!("Page {page}/{pages}");
 
-        let mut description = String::with_capacity(512);
+        let mut description = DescriptionBuilder::new();
 
         for entry in entries {
             let RecentListEntry {
@@ -58,14 +67,51 @@ impl IActiveMessage for RecentListPagination {
                 stars,
                 max_pp,
                 max_combo,
+                attempts,
+                retry_streak,
             } = entry;
 
             let map = self.maps.get(map_id).expect("missing map");
 
+            if let Some(attempts) = attempts {
+                let _ = writeln!(
+                    description,
+                    "**#{i} {grade}\t[{artist} - {title}]({OSU_BASE}s/{mapset_id})**{streak}\n\
+                    {count} attempt{plural} on {difficulties} difficult{suffix} • \
+                    Best: [{version}]({OSU_BASE}b/{map_id}) [{stars:.2}★]\n\
+                    {pp}\t[ {combo} ]\t({acc}%)\t{ago}",
+                    i = *idx + 1,
+                    grade = GradeCompletionFormatter::new(score, self.user.mode, map.n_objects()),
+                    artist = map.artist().cow_escape_markdown(),
+                    title = map.title().cow_escape_markdown(),
+                    mapset_id = map.mapset_id(),
+                    streak = RetrySpam(*retry_streak),
+                    count = attempts.count,
+                    plural = if attempts.count == 1 { "" } else { "s" },
+                    difficulties = attempts.difficulties,
+                    suffix = if attempts.difficulties == 1 {
+                        "y"
+                    } else {
+                        "ies"
+                    },
+                    version = map.version().cow_escape_markdown(),
+                    map_id = map.map_id(),
+                    pp = PpFormatter::new(Some(score.pp), Some(*max_pp)),
+                    combo = ComboFormatter::new(score.max_combo, Some(*max_combo)),
+                    acc = Acc(score.accuracy),
+                    ago = HowLongAgoDynamic::new(&score.ended_at)
+                );
+
+                description.finish_entry();
+
+                continue;
+            }
+
             let _ = write!(
                 description,
-                "**#{i} {grade}\t[{title} [{version}]]({OSU_BASE}b/{map_id})** [{stars:.2}★]",
+                "**#{i} {grade}\t[{title} [{version}]]({OSU_BASE}b/{map_id})** [{stars:.2}★]{streak}",
                 i = *idx + 1,
+                streak = RetrySpam(*retry_streak),
                 grade = GradeCompletionFormatter::new(score, self.user.mode, map.n_objects()),
                 title = map.title().cow_escape_markdown(),
                 version = map.version().cow_escape_markdown(),
@@ -75,27 +121,30 @@ impl IActiveMessage for RecentListPagination {
             if score.mode == GameMode::Mania {
                 let _ = write!(
                     description,
-                    "\t{}",
-                    KeyFormatter::new(&score.mods, map.attributes().build().cs as f32)
+                    "\t{} {}",
+                    KeyFormatter::new(&score.mods, map.attributes().build().cs as f32),
+                    LnFormatter::new(map.ln_percent()),
                 );
             }
 
-            description.push('\n');
-
             let _ = writeln!(
                 description,
-                "{pp}\t[ {combo} ]\t({acc}%)\t{ago}",
+                "\n{pp}\t[ {combo} ]\t({acc}%)\t{ago}",
                 pp = PpFormatter::new(Some(score.pp), Some(*max_pp)),
                 combo = ComboFormatter::new(score.max_combo, Some(*max_combo)),
-                acc = round(score.accuracy),
+                acc = Acc(score.accuracy),
                 ago = HowLongAgoDynamic::new(&score.ended_at)
             );
-        }
 
-        if description.is_empty() {
-            "No recent scores found".clone_into(&mut description);
+            description.finish_entry();
         }
 
+        let description = if description.is_empty() {
+            "No recent scores found".to_owned()
+        } else {
+            description.build()
+        };
+
         let embed = EmbedBuilder::new()
             .author(self.user.author_builder(false))
             .description(description)
@@ -107,10 +156,73 @@ impl IActiveMessage for RecentListPagination {
     }
 
     fn build_components(&self) -> Vec<Component> {
-        self.pages.components()
+        let mut components = self.pages.components();
+
+        let end_idx = self
+            .entries
+            .len()
+            .min(self.pages.index() + self.pages.per_page());
+        let entries = &self.entries[self.pages.index()..end_idx];
+
+        if entries.is_empty() {
+            return components;
+        }
+
+        let options = entries
+            .iter()
+            .map(|entry| {
+                let map = self.maps.get(&entry.map_id).expect("missing map");
+                let mut label = format!("#{} {} [{}]", entry.idx + 1, map.title(), map.version());
+
+                if label.chars().count() > 100 {
+                    label = label.chars().take(99).collect();
+                    label.push('…');
+                }
+
+                SelectMenuOption {
+                    default: false,
+                    description: None,
+                    emoji: None,
+                    label,
+                    value: entry.idx.to_string(),
+                }
+            })
+            .collect();
+
+        let menu = SelectMenu {
+            custom_id: "recent_list_fc".to_owned(),
+            disabled: false,
+            max_values: None,
+            min_values: None,
+            options: Some(options),
+            placeholder: Some("What if this score were an FC?".to_owned()),
+            channel_types: None,
+            default_values: None,
+            kind: SelectMenuType::Text,
+        };
+
+        components.push(Component::ActionRow(ActionRow {
+            components: vec![Component::SelectMenu(menu)],
+        }));
+
+        let maps = entries.iter().filter_map(|entry| {
+            let map = self.maps.get(&entry.map_id)?;
+
+            Some((entry.idx, map))
+        });
+
+        components.extend(map_preview_menu(maps));
+
+        components
     }
 
     async fn handle_component(&mut self, component: &mut InteractionComponent) -> ComponentResult {
+        if component.data.custom_id == "recent_list_fc" {
+            return self.handle_fc_menu(component).await;
+        } else if component.data.custom_id == MAP_PREVIEW_ID {
+            return handle_map_preview_component(component, self.maps.values()).await;
+        }
+
         handle_pagination_component(component, self.msg_owner, false, &mut self.pages).await
     }
 
@@ -118,3 +230,84 @@ impl IActiveMessage for RecentListPagination {
         handle_pagination_modal(modal, self.msg_owner, false, &mut self.pages).await
     }
 }
+
+impl RecentListPagination {
+    /// Open the simulate view for the selected score without its misses.
+    async fn handle_fc_menu(&mut self, component: &mut InteractionComponent) -> ComponentResult {
+        let user_id = match component.user_id() {
+            Ok(user_id) => user_id,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        if user_id != self.msg_owner {
+            return ComponentResult::Ignore;
+        }
+
+        let Some(value) = component.data.values.pop() else {
+            return ComponentResult::Err(eyre!("Missing value in recent list menu"));
+        };
+
+        let Some(entry) = value
+            .parse::<usize>()
+            .ok()
+            .and_then(|idx| self.entries.iter().find(|entry| entry.idx == idx))
+        else {
+            return ComponentResult::Err(eyre!("Unknown recent list menu value `{value}`"));
+        };
+
+        let Some(map) = self.maps.get(&entry.map_id).cloned() else {
+            return ComponentResult::Err(eyre!("Missing map {} in recent list", entry.map_id));
+        };
+
+        let score = &entry.score;
+        let stats = score.statistics.as_legacy(score.mode);
+
+        let data = SimulateData {
+            mods: Some(score.mods.clone()),
+            acc: None,
+            n_geki: Some(stats.count_geki),
+            n_katu: Some(stats.count_katu),
+            n300: Some(stats.count_300 + stats.count_miss),
+            n100: Some(stats.count_100),
+            n50: Some(stats.count_50),
+            n_miss: Some(0),
+            n_slider_ends: None,
+            n_large_ticks: None,
+            combo: Some(entry.max_combo),
+            score: None,
+            bpm: None,
+            clock_rate: None,
+            version: TopOldVersion::latest(score.mode),
+            attrs: SimulateAttributes::default(),
+            max_combo: entry.max_combo,
+            set_on_lazer: score.set_on_lazer,
+        };
+
+        let active = SimulateComponents::new(SimulateMap::Full(map), data, self.msg_owner);
+        let channel_id = component.message.channel_id;
+
+        // Spawn in new task so that we're sure to callback the component in time
+        spawn_with_osu_server(async move {
+            let begin_fut = ActiveMessages::builder(active).begin(channel_id);
+
+            if let Err(err) = begin_fut.await {
+                error!(?err, "Failed to begin simulate message for recent score");
+            }
+        });
+
+        ComponentResult::BuildPage
+    }
+}
+
+/// Badge for entries whose map was retried excessively in a row.
+struct RetrySpam(usize);
+
+impl Display for RetrySpam {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.0 > RecentListEntry::RETRY_SPAM {
+            write!(f, "\t🔁 `{}x in a row`", self.0)
+        } else {
+            Ok(())
+        }
+    }
+}
@@ -9,7 +9,8 @@ use bathbot_util::{
     CowUtils, EmbedBuilder, FooterBuilder, IntHasher, ModsFormatter,
     constants::{OSU_BASE, RELAX_ICON_URL},
     datetime::HowLongAgoDynamic,
-    numbers::{WithComma, round},
+    numbers::WithComma,
+    units::Acc,
 };
 use eyre::Result;
 use twilight_interactions::command::{CommandOption, CreateOption};
@@ -21,6 +22,7 @@ use twilight_model::{
 use crate::{
     active::{
         BuildPage, ComponentResult, IActiveMessage,
+        map_preview::{MAP_PREVIEW_ID, handle_map_preview_component, map_preview_menu},
         pagination::{Pages, handle_pagination_component, handle_pagination_modal},
     },
     commands::osu::relax::relax_author_builder,
@@ -124,7 +126,7 @@ impl IActiveMessage for RelaxTopPagination {
                 map_id = score.beatmap_id,
                 mods = ModsFormatter::new(mods, false),
                 pp = PpFormatter::new(score_pp, Some(max_pp)),
-                acc = round(score.accuracy),
+                acc = Acc(score.accuracy),
                 score = WithComma::new(score.total_score),
                 combo = ComboFormatter::new(score.combo, Some(max_combo)),
                 miss = MissFormat(count_miss),
@@ -150,10 +152,26 @@ impl IActiveMessage for RelaxTopPagination {
     }
 
     fn build_components(&self) -> Vec<Component> {
-        self.pages.components()
+        let mut components = self.pages.components();
+
+        let pages = &self.pages;
+        let end_idx = self.scores.len().min(pages.index() + pages.per_page());
+
+        let maps = self.scores[pages.index()..end_idx]
+            .iter()
+            .zip(pages.index()..)
+            .filter_map(|(score, idx)| Some((idx, self.maps.get(&score.beatmap_id)?)));
+
+        components.extend(map_preview_menu(maps));
+
+        components
     }
 
     async fn handle_component(&mut self, component: &mut InteractionComponent) -> ComponentResult {
+        if component.data.custom_id == MAP_PREVIEW_ID {
+            return handle_map_preview_component(component, self.maps.values()).await;
+        }
+
         handle_pagination_component(component, self.msg_owner, true, &mut self.pages).await
     }
 
@@ -1,13 +1,14 @@
-use rosu_pp::any::HitResultPriority;
-use rosu_v2::{
-    mods,
-    prelude::{GameMod, GameMods},
+use rosu_pp::{
+    any::HitResultPriority, mania::ManiaPerformanceAttributes, osu::OsuPerformanceAttributes,
+    taiko::TaikoPerformanceAttributes,
 };
+use rosu_v2::{mods, prelude::GameMods};
 
 use super::{attrs::SimulateAttributes, state::ScoreState, top_old::TopOldVersion};
 use crate::{
     active::impls::SimulateMap,
     commands::osu::{TopOldCatchVersion, TopOldManiaVersion, TopOldOsuVersion, TopOldTaikoVersion},
+    util::osu::score_multiplier,
 };
 
 pub struct SimulateData {
@@ -56,6 +57,7 @@ impl SimulateData {
                     $( with_diff: $with_diff:tt, )?
                     $( with_lazer: $with_lazer:tt, )?
                     $( fallible: $fallible:tt, )?
+                    $( breakdown: $breakdown:tt, )?
                 }
             ) => {{
                 let map = map.pp_map();
@@ -76,6 +78,7 @@ impl SimulateData {
 
                     let pp = attrs.pp;
                     let stars = attrs.difficulty.stars;
+                    let breakdown = simulate!(@BREAKDOWN $( $breakdown )? attrs);
 
                     let max_new = simulate!(@MAX_NEW $max_new attrs map);
 
@@ -90,9 +93,9 @@ impl SimulateData {
                     $( let attrs = simulate!(@UNWRAP $fallible attrs); )?
                     let max_pp = attrs.pp;
 
-                    (stars, pp, max_pp)
+                    (stars, pp, max_pp, breakdown)
                 } else {
-                    (0.0, 0.0, 0.0)
+                    (0.0, 0.0, 0.0, None)
                 }
             }};
             ( @WITH_LAZER true $calc:ident ) => { $calc.lazer(self.set_on_lazer) };
@@ -115,6 +118,8 @@ impl SimulateData {
             ( @WITH_DIFF $( $other:tt )* ) => {
                 compile_error!(concat!("with_diff must be bool; got `", $( stringify!($other) ),*, "`"))
             };
+            ( @BREAKDOWN true $attrs:ident ) => { Some(PpBreakdown::from(&$attrs)) };
+            ( @BREAKDOWN $attrs:ident ) => { None };
             ( @PRIO $calc:ident A $( $rest:tt )* ) => {
                 if self.acc.is_some() {
                     $calc = $calc.hitresult_priority(HitResultPriority::Fastest);
@@ -128,7 +133,7 @@ impl SimulateData {
             };
         }
 
-        let (stars, pp, max_pp) = match self.version {
+        let (stars, pp, max_pp, breakdown) = match self.version {
             TopOldVersion::Osu(TopOldOsuVersion::May14July14) => simulate! {
                 rosu_pp_older::osu_2014_may::OsuPP {
                     combo: combo,
@@ -307,6 +312,7 @@ impl SimulateData {
                     max_new: attrs,
                     with_lazer: true,
                     fallible: true,
+                    breakdown: true,
                 }
             },
             TopOldVersion::Taiko(TopOldTaikoVersion::March14September20) => simulate! {
@@ -361,6 +367,7 @@ impl SimulateData {
                     mods: mods.clone(),
                     max_new: attrs,
                     fallible: true,
+                    breakdown: true,
                 }
             },
             TopOldVersion::Taiko(TopOldTaikoVersion::October24March25) => simulate! {
@@ -475,6 +482,7 @@ impl SimulateData {
                     max_new: attrs,
                     with_lazer: true,
                     fallible: true,
+                    breakdown: true,
                 }
             },
         };
@@ -563,44 +571,11 @@ impl SimulateData {
             clock_rate,
             combo_ratio,
             score_state,
+            breakdown,
         }
     }
 }
 
-fn score_multiplier(mods: &GameMods) -> f32 {
-    mods.iter()
-        .map(|gamemod| match gamemod {
-            GameMod::HalfTimeOsu(_) | GameMod::HalfTimeTaiko(_) | GameMod::HalfTimeCatch(_) => 0.3,
-            GameMod::EasyOsu(_)
-            | GameMod::NoFailOsu(_)
-            | GameMod::EasyTaiko(_)
-            | GameMod::NoFailTaiko(_)
-            | GameMod::EasyCatch(_)
-            | GameMod::NoFailCatch(_)
-            | GameMod::EasyMania(_)
-            | GameMod::NoFailMania(_)
-            | GameMod::HalfTimeMania(_) => 0.5,
-            GameMod::SpunOutOsu(_) => 0.9,
-            GameMod::HardRockOsu(_)
-            | GameMod::HiddenOsu(_)
-            | GameMod::HardRockTaiko(_)
-            | GameMod::HiddenTaiko(_)
-            | GameMod::DoubleTimeCatch(_)
-            | GameMod::NightcoreCatch(_)
-            | GameMod::HiddenCatch(_) => 1.06,
-            GameMod::DoubleTimeOsu(_)
-            | GameMod::NightcoreOsu(_)
-            | GameMod::FlashlightOsu(_)
-            | GameMod::DoubleTimeTaiko(_)
-            | GameMod::NightcoreTaiko(_)
-            | GameMod::FlashlightTaiko(_)
-            | GameMod::HardRockCatch(_)
-            | GameMod::FlashlightCatch(_) => 1.12,
-            _ => 1.0,
-        })
-        .product()
-}
-
 pub(super) struct SimulateValues {
     pub stars: f32,
     pub pp: f32,
@@ -608,6 +583,37 @@ pub(super) struct SimulateValues {
     pub clock_rate: Option<f64>,
     pub combo_ratio: ComboOrRatio,
     pub score_state: StateOrScore,
+    /// Only available for the current pp versions
+    pub breakdown: Option<PpBreakdown>,
+}
+
+/// The pp of each skill that contributes to the total pp.
+pub(super) struct PpBreakdown(pub Vec<(&'static str, f64)>);
+
+impl From<&OsuPerformanceAttributes> for PpBreakdown {
+    fn from(attrs: &OsuPerformanceAttributes) -> Self {
+        Self(vec![
+            ("Aim", attrs.pp_aim),
+            ("Speed", attrs.pp_speed),
+            ("Accuracy", attrs.pp_acc),
+            ("Flashlight", attrs.pp_flashlight),
+        ])
+    }
+}
+
+impl From<&TaikoPerformanceAttributes> for PpBreakdown {
+    fn from(attrs: &TaikoPerformanceAttributes) -> Self {
+        Self(vec![
+            ("Difficulty", attrs.pp_difficulty),
+            ("Accuracy", attrs.pp_acc),
+        ])
+    }
+}
+
+impl From<&ManiaPerformanceAttributes> for PpBreakdown {
+    fn from(attrs: &ManiaPerformanceAttributes) -> Self {
+        Self(vec![("Difficulty", attrs.pp_difficulty)])
+    }
 }
 
 pub(super) enum StateOrScore {
@@ -5,16 +5,18 @@ use std::{
     str::FromStr,
 };
 
+use bathbot_psql::model::simulate_preset::DbSimulatePreset;
 use bathbot_util::{
-    Authored, CowUtils, EmbedBuilder, FooterBuilder,
+    Authored, CowUtils, EmbedBuilder, FooterBuilder, attachment,
     constants::OSU_BASE,
     datetime::SecToMinSec,
     fields,
     modal::{ModalBuilder, TextInputBuilder},
     numbers::{WithComma, round},
     osu::calculate_grade,
+    units::{Acc, Pp, Stars},
 };
-use eyre::{ContextCompat, Report, Result};
+use eyre::{ContextCompat, Report, Result, WrapErr};
 use rosu_pp::{
     Beatmap,
     model::{
@@ -26,15 +28,22 @@ use rosu_v2::{
     model::mods::{
         GameMod, GameMods,
         generated_mods::{
-            DifficultyAdjustCatch, DifficultyAdjustMania, DifficultyAdjustOsu,
-            DifficultyAdjustTaiko,
+            DaycoreCatch, DaycoreMania, DaycoreOsu, DaycoreTaiko, DifficultyAdjustCatch,
+            DifficultyAdjustMania, DifficultyAdjustOsu, DifficultyAdjustTaiko, DoubleTimeCatch,
+            DoubleTimeMania, DoubleTimeOsu, DoubleTimeTaiko, HalfTimeCatch, HalfTimeMania,
+            HalfTimeOsu, HalfTimeTaiko, NightcoreCatch, NightcoreMania, NightcoreOsu,
+            NightcoreTaiko,
         },
     },
     mods,
     prelude::{GameMode, GameModsIntermode, Grade},
 };
 use twilight_model::{
-    channel::message::{Component, embed::EmbedField},
+    channel::message::{
+        Component,
+        component::{ActionRow, Button, ButtonStyle},
+        embed::EmbedField,
+    },
     id::{Id, marker::UserMarker},
 };
 
@@ -42,11 +51,14 @@ pub use self::{attrs::SimulateAttributes, data::SimulateData, top_old::TopOldVer
 use crate::{
     active::{
         BuildPage, ComponentResult, IActiveMessage,
-        impls::simulate::data::{ComboOrRatio, SimulateValues, StateOrScore},
+        impls::simulate::data::{ComboOrRatio, PpBreakdown, SimulateValues, StateOrScore},
+    },
+    commands::osu::{
+        GraphAxes, GraphPalette, GraphRenderer, LineGraph, parsed_map::AttachedSimulateMap,
     },
-    commands::osu::parsed_map::AttachedSimulateMap,
+    core::Context,
     embeds::{ComboFormatter, HitResultFormatter, KeyFormatter, PpFormatter},
-    manager::OsuMap,
+    manager::{OsuMap, SimulatePresetManager},
     util::{
         ComponentExt, Emote, ModalExt,
         interaction::{InteractionComponent, InteractionModal},
@@ -59,11 +71,25 @@ mod data;
 mod state;
 mod top_old;
 
+const PRESET_NAME_MAX_LEN: usize = 32;
+
+const SWEEP_W: u32 = 900;
+const SWEEP_H: u32 = 450;
+
 pub struct SimulateComponents {
     map: SimulateMap,
     data: SimulateData,
     defer: bool,
     msg_owner: Id<UserMarker>,
+    /// Shown once above the embed e.g. after saving a preset
+    note: Option<String>,
+    /// Second mod combination whose results are shown next to the main ones
+    compare_mods: Option<GameMods>,
+    show_breakdown: bool,
+    /// Combo at which a hypothetical miss happens
+    miss_at: Option<u32>,
+    /// Palette of the pp-per-accuracy graph, if it's shown
+    sweep: Option<GraphPalette>,
 }
 
 impl IActiveMessage for SimulateComponents {
@@ -143,8 +169,25 @@ impl IActiveMessage for SimulateComponents {
             clock_rate,
             combo_ratio,
             score_state,
+            breakdown,
         } = self.data.simulate(&self.map);
 
+        let compare = self
+            .compare_mods
+            .clone()
+            .map(|compare_mods| self.simulate_compare(compare_mods));
+
+        let miss_table = self
+            .miss_at
+            .map(|miss_at| self.miss_position_table(miss_at));
+
+        let sweep = match self.sweep {
+            Some(palette) if !matches!(score_state, StateOrScore::Neither) => {
+                Some(self.draw_sweep(palette)?)
+            }
+            Some(_) | None => None,
+        };
+
         let mods = self
             .data
             .mods
@@ -159,6 +202,7 @@ impl IActiveMessage for SimulateComponents {
         };
 
         let mut too_suspicious = false;
+        let mut acc_value = None;
 
         let (score, acc, hits) = match score_state {
             StateOrScore::Score(score) => {
@@ -185,10 +229,12 @@ impl IActiveMessage for SimulateComponents {
                     stats.legacy_accuracy(mode)
                 };
 
+                acc_value = Some(acc);
+
                 let acc = EmbedField {
                     inline: true,
                     name: "Acc".to_owned(),
-                    value: format!("{}%", round(acc)),
+                    value: format!("{}%", Acc(acc)),
                 };
 
                 let hits = EmbedField {
@@ -285,6 +331,39 @@ impl IActiveMessage for SimulateComponents {
             fields.push(hits);
         }
 
+        if self.show_breakdown && !too_suspicious {
+            let value = match breakdown {
+                Some(PpBreakdown(skills)) => {
+                    let mut value = String::new();
+
+                    for (skill, pp) in skills {
+                        let _ = writeln!(value, "{skill}: **{}**pp", Pp(pp as f32));
+                    }
+
+                    value
+                }
+                None => "Not available for this version".to_owned(),
+            };
+
+            fields![fields { "PP breakdown", value, false; }];
+        }
+
+        if let Some(table) = miss_table.filter(|_| !too_suspicious) {
+            fields![fields { "Miss position", table, false; }];
+        }
+
+        if let Some(other) = compare.filter(|_| !too_suspicious) {
+            let main = ComparedValues {
+                mods: mods_str(&mods),
+                stars,
+                pp,
+                max_pp,
+                acc: acc_value,
+            };
+
+            fields![fields { "Comparison", comparison_table(&main, &other), false; }];
+        }
+
         let map_info = self
             .map
             .map_info(stars, mods.as_ref(), self.data.clock_rate);
@@ -295,7 +374,9 @@ impl IActiveMessage for SimulateComponents {
             .footer(footer)
             .title(title);
 
-        if let Some(image) = image {
+        if sweep.is_some() {
+            embed = embed.image(attachment("sim_sweep.png"));
+        } else if let Some(image) = image {
             embed = embed.image(image);
         }
 
@@ -303,14 +384,122 @@ impl IActiveMessage for SimulateComponents {
             embed = embed.url(url);
         }
 
-        let content = "Simulated score:";
+        let content = match self.note.take() {
+            Some(note) => format!("{note}\nSimulated score:"),
+            None => "Simulated score:".to_owned(),
+        };
+
         let defer = mem::replace(&mut self.defer, true);
 
-        Ok(BuildPage::new(embed, defer).content(content))
+        let mut page = BuildPage::new(embed, defer).content(content);
+
+        if let Some(bytes) = sweep {
+            page = page.attachment("sim_sweep.png", bytes);
+        }
+
+        Ok(page)
     }
 
     fn build_components(&self) -> Vec<Component> {
-        self.data.version.components(self.data.set_on_lazer)
+        let mut components = self.data.version.components(self.data.set_on_lazer);
+
+        let save = Button {
+            custom_id: Some("sim_save_preset".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("Save preset".to_owned()),
+            style: ButtonStyle::Success,
+            url: None,
+            sku_id: None,
+        };
+
+        let load = Button {
+            custom_id: Some("sim_load_preset".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("Load preset".to_owned()),
+            style: ButtonStyle::Success,
+            url: None,
+            sku_id: None,
+        };
+
+        let compare = Button {
+            custom_id: Some("sim_compare".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some("Compare mods".to_owned()),
+            style: ButtonStyle::Primary,
+            url: None,
+            sku_id: None,
+        };
+
+        let breakdown_label = if self.show_breakdown {
+            "Hide breakdown"
+        } else {
+            "Show breakdown"
+        };
+
+        let breakdown = Button {
+            custom_id: Some("sim_breakdown".to_owned()),
+            disabled: false,
+            emoji: None,
+            label: Some(breakdown_label.to_owned()),
+            style: ButtonStyle::Primary,
+            url: None,
+            sku_id: None,
+        };
+
+        let miss_at = Button {
+            custom_id: Some("sim_miss_at".to_owned()),
+            disabled: matches!(self.data.version, TopOldVersion::Mania(_)),
+            emoji: None,
+            label: Some("Miss position".to_owned()),
+            style: ButtonStyle::Primary,
+            url: None,
+            sku_id: None,
+        };
+
+        // Once shown, the graph stays to keep the message's attachment in sync
+        let sweep = Button {
+            custom_id: Some("sim_sweep".to_owned()),
+            disabled: self.sweep.is_some() || !self.data.version.has_acc(),
+            emoji: None,
+            label: Some("Sweep".to_owned()),
+            style: ButtonStyle::Primary,
+            url: None,
+            sku_id: None,
+        };
+
+        components.push(Component::ActionRow(ActionRow {
+            components: vec![
+                Component::Button(save),
+                Component::Button(load),
+                Component::Button(compare),
+                Component::Button(breakdown),
+                Component::Button(miss_at),
+            ],
+        }));
+
+        // All five rows might already be in use so squeeze the button into
+        // the first row of buttons that still has room
+        let free_row = components.iter_mut().find_map(|component| match component {
+            Component::ActionRow(row)
+                if row.components.len() < 5
+                    && matches!(row.components.first(), Some(Component::Button(_))) =>
+            {
+                Some(row)
+            }
+            _ => None,
+        });
+
+        match free_row {
+            Some(row) => row.components.push(Component::Button(sweep)),
+            None => components.push(Component::ActionRow(ActionRow {
+                components: vec![Component::Button(sweep)],
+            })),
+        }
+
+        components
     }
 
     async fn handle_component(&mut self, component: &mut InteractionComponent) -> ComponentResult {
@@ -331,6 +520,20 @@ impl IActiveMessage for SimulateComponents {
 
                 ModalBuilder::new("sim_mods", "Specify mods").input(input)
             }
+            "sim_compare" => {
+                let input = TextInputBuilder::new("sim_compare", "Mods")
+                    .placeholder("E.g. hd or hdhr, leave empty to stop comparing")
+                    .required(false);
+
+                ModalBuilder::new("sim_compare", "Compare with other mods").input(input)
+            }
+            "sim_miss_at" => {
+                let input = TextInputBuilder::new("sim_miss_at", "Combo at the miss")
+                    .placeholder("E.g. 420, leave empty to hide the table")
+                    .required(false);
+
+                ModalBuilder::new("sim_miss_at", "Where does the miss happen?").input(input)
+            }
             "sim_combo" => {
                 let input = TextInputBuilder::new("sim_combo", "Combo")
                     .placeholder("Integer")
@@ -466,6 +669,42 @@ impl IActiveMessage for SimulateComponents {
                     .input(hp)
                     .input(od)
             }
+            "sim_save_preset" => {
+                let input = TextInputBuilder::new("sim_preset_name", "Preset name")
+                    .placeholder("E.g. my DT choke settings")
+                    .max_len(PRESET_NAME_MAX_LEN as u16);
+
+                ModalBuilder::new("sim_save_preset", "Save the current settings as preset")
+                    .input(input)
+            }
+            "sim_load_preset" => return self.handle_load_preset(component).await,
+            "sim_breakdown" => {
+                if let Err(err) = component.defer().await.map_err(Report::new) {
+                    return ComponentResult::Err(err.wrap_err("Failed to defer component"));
+                }
+
+                self.show_breakdown = !self.show_breakdown;
+
+                return ComponentResult::BuildPage;
+            }
+            "sim_sweep" => {
+                if let Err(err) = component.defer().await.map_err(Report::new) {
+                    return ComponentResult::Err(err.wrap_err("Failed to defer component"));
+                }
+
+                let theme = match Context::user_config().with_osu_id(self.msg_owner).await {
+                    Ok(config) => config.graph_theme,
+                    Err(err) => {
+                        warn!(?err, "Failed to get user config for graph theme");
+
+                        None
+                    }
+                };
+
+                self.sweep = Some(GraphPalette::new(theme.unwrap_or_default()));
+
+                return ComponentResult::BuildPage;
+            }
             "sim_osu_version" | "sim_taiko_version" | "sim_catch_version" | "sim_mania_version" => {
                 return self.handle_topold_menu(component).await;
             }
@@ -496,7 +735,17 @@ impl IActiveMessage for SimulateComponents {
 
         match modal.data.custom_id.as_str() {
             "sim_mods" => {
-                let mods_res = input.map(|s| {
+                let (mods_input, rate) = match input.map(split_rate) {
+                    Some(Some((mods_input, rate))) => (Some(mods_input), rate),
+                    Some(None) => {
+                        debug!(input, "Invalid rate for simulate mods");
+
+                        return Ok(());
+                    }
+                    None => (None, None),
+                };
+
+                let mods_res = mods_input.as_deref().map(|s| {
                     s.trim_start_matches('+')
                         .trim_end_matches('!')
                         .parse::<GameModsIntermode>()
@@ -512,7 +761,14 @@ impl IActiveMessage for SimulateComponents {
                     None => None,
                 };
 
-                match mods.map(|mods| mods.try_with_mode(self.map.mode())) {
+                let mods = mods
+                    .map(|mods| mods.try_with_mode(self.map.mode()))
+                    .map(|mods| match rate {
+                        Some(rate) => mods.and_then(|mods| with_speed_change(mods, rate)),
+                        None => mods,
+                    });
+
+                match mods {
                     Some(Some(mods)) if mods.is_valid() => self.data.mods = Some(mods),
                     None => self.data.mods = None,
                     Some(Some(mods)) => {
@@ -527,6 +783,38 @@ impl IActiveMessage for SimulateComponents {
                     }
                 }
             }
+            "sim_compare" => {
+                let mods_res = input.map(|s| {
+                    s.trim_start_matches('+')
+                        .trim_end_matches('!')
+                        .parse::<GameModsIntermode>()
+                });
+
+                let mods = match mods_res {
+                    Some(Ok(value)) => Some(value),
+                    Some(Err(_)) => {
+                        debug!(input, "Failed to parse simulate compare mods");
+
+                        return Ok(());
+                    }
+                    None => None,
+                };
+
+                match mods.map(|mods| mods.try_with_mode(self.map.mode())) {
+                    Some(Some(mods)) if mods.is_valid() => self.compare_mods = Some(mods),
+                    None => self.compare_mods = None,
+                    Some(Some(mods)) => {
+                        debug!("Incompatible compare mods {mods}");
+
+                        return Ok(());
+                    }
+                    Some(None) => {
+                        debug!(input, "Invalid compare mods for mode");
+
+                        return Ok(());
+                    }
+                }
+            }
             "sim_acc" => match input.map(str::parse::<f32>) {
                 Some(Ok(value)) => self.data.acc = Some(value.clamp(0.0, 100.0)),
                 Some(Err(_)) => {
@@ -536,6 +824,15 @@ impl IActiveMessage for SimulateComponents {
                 }
                 None => self.data.acc = None,
             },
+            "sim_miss_at" => match input.map(str::parse) {
+                Some(Ok(value)) => self.miss_at = Some(value),
+                Some(Err(_)) => {
+                    debug!(input, "Failed to parse simulate miss position");
+
+                    return Ok(());
+                }
+                None => self.miss_at = None,
+            },
             "sim_combo" => match input.map(str::parse) {
                 Some(Ok(value)) => self.data.combo = Some(value),
                 Some(Err(_)) => {
@@ -636,6 +933,8 @@ impl IActiveMessage for SimulateComponents {
                 self.data.clock_rate = parse_attr(&*modal, "sim_clock_rate");
                 self.data.bpm = parse_attr(&*modal, "sim_bpm");
             }
+            "sim_save_preset" => self.save_preset(input.unwrap_or_default()).await,
+            "sim_load_preset" => self.load_preset(input.unwrap_or_default()).await,
             other => warn!(name = %other, ?modal, "Unknown simulate modal"),
         }
 
@@ -654,9 +953,300 @@ impl SimulateComponents {
             data,
             msg_owner,
             defer: true,
+            note: None,
+            compare_mods: None,
+            show_breakdown: false,
+            miss_at: None,
+            sweep: None,
         }
     }
 
+    /// Graph of pp values for the current settings across 90% to 100% accuracy.
+    fn draw_sweep(&mut self, palette: GraphPalette) -> Result<Vec<u8>> {
+        let acc = self.data.acc;
+        let n_geki = self.data.n_geki.take();
+        let n_katu = self.data.n_katu.take();
+        let n300 = self.data.n300.take();
+        let n100 = self.data.n100.take();
+        let n50 = self.data.n50.take();
+
+        let points: Vec<_> = (0..=20)
+            .map(|i| {
+                let acc = 90.0 + i as f32 * 0.5;
+                self.data.acc = Some(acc);
+
+                (acc as f64, self.data.simulate(&self.map).pp as f64)
+            })
+            .collect();
+
+        self.data.acc = acc;
+        self.data.n_geki = n_geki;
+        self.data.n_katu = n_katu;
+        self.data.n300 = n300;
+        self.data.n100 = n100;
+        self.data.n50 = n50;
+
+        let mods = self
+            .data
+            .mods
+            .as_ref()
+            .map_or_else(|| mods_str(&GameMods::new()), mods_str);
+
+        let graph = LineGraph {
+            axes: GraphAxes {
+                caption: format!("PP per accuracy ({mods})"),
+                x_desc: Some("Accuracy"),
+                y_desc: Some("PP"),
+            },
+            points: &points,
+            x_labels: 11,
+            x_fmt: &|acc| format!("{acc}%"),
+            y_fmt: &|pp| format!("{pp:.0}"),
+        };
+
+        graph
+            .render(SWEEP_W, SWEEP_H, palette)
+            .wrap_err("Failed to draw sweep graph")
+    }
+
+    /// Table of pp values for misses at various positions of the map.
+    ///
+    /// Multiple misses are assumed to happen right after another so the
+    /// score's combo is the longer of the two segments around them.
+    fn miss_position_table(&mut self, miss_at: u32) -> String {
+        if matches!(self.data.version, TopOldVersion::Mania(_)) {
+            return "Not applicable for mania".to_owned();
+        }
+
+        let max_combo = self.data.max_combo;
+        let n_miss = self.data.n_miss.unwrap_or(0).max(1);
+        let last = max_combo.saturating_sub(n_miss);
+
+        let mut positions = vec![miss_at.min(last), 0, last];
+        positions.extend([0.25, 0.5, 0.75].map(|part| (last as f32 * part) as u32));
+        positions.sort_unstable();
+        positions.dedup();
+
+        let combo = self.data.combo;
+        let misses = self.data.n_miss;
+        self.data.n_miss = Some(n_miss);
+
+        let mut table = String::from("```\nMiss at   Combo   PP\n");
+
+        for pos in positions {
+            let score_combo = pos.max(last - pos);
+            self.data.combo = Some(score_combo);
+            let pp = self.data.simulate(&self.map).pp;
+
+            let _ = writeln!(
+                table,
+                "{pos:>6}x  {score_combo:>5}x  {pp}{marker}",
+                pp = Pp(pp),
+                marker = if pos == miss_at.min(last) { " <" } else { "" },
+            );
+        }
+
+        self.data.combo = combo;
+        self.data.n_miss = misses;
+
+        table.push_str("```");
+
+        table
+    }
+
+    /// Simulate the current settings with other mods.
+    fn simulate_compare(&mut self, compare_mods: GameMods) -> ComparedValues {
+        let main_mods = mem::replace(&mut self.data.mods, Some(compare_mods));
+        let values = self.data.simulate(&self.map);
+        let compare_mods = mem::replace(&mut self.data.mods, main_mods).unwrap_or_default();
+
+        let acc = match values.score_state {
+            StateOrScore::State(state) => {
+                let map = self.data.set_on_lazer.then_some(self.map.pp_map());
+                let (mode, stats, max_stats) = state.into_parts(map);
+
+                let acc = if self.data.set_on_lazer {
+                    stats.accuracy(mode, &max_stats)
+                } else {
+                    stats.legacy_accuracy(mode)
+                };
+
+                Some(acc)
+            }
+            StateOrScore::Score(_) | StateOrScore::Neither => None,
+        };
+
+        ComparedValues {
+            mods: mods_str(&compare_mods),
+            stars: values.stars,
+            pp: values.pp,
+            max_pp: values.max_pp,
+            acc,
+        }
+    }
+
+    async fn handle_load_preset(
+        &mut self,
+        component: &mut InteractionComponent,
+    ) -> ComponentResult {
+        let presets = match Context::simulate_presets().get(self.msg_owner).await {
+            Ok(presets) => presets,
+            Err(err) => return ComponentResult::Err(err),
+        };
+
+        if presets.is_empty() {
+            if let Err(err) = component.defer().await.map_err(Report::new) {
+                return ComponentResult::Err(err.wrap_err("Failed to defer component"));
+            }
+
+            self.note = Some("You don't have any presets yet, save one first".to_owned());
+
+            return ComponentResult::BuildPage;
+        }
+
+        let mut placeholder = String::from("Available: ");
+
+        for (preset, i) in presets.iter().zip(0..) {
+            if i > 0 {
+                placeholder.push_str(", ");
+            }
+
+            // Placeholders can be at most 100 characters long
+            if placeholder.len() + preset.name.len() > 96 {
+                placeholder.push_str("...");
+
+                break;
+            }
+
+            placeholder.push_str(&preset.name);
+        }
+
+        let input = TextInputBuilder::new("sim_preset_name", "Preset name")
+            .placeholder(placeholder)
+            .max_len(PRESET_NAME_MAX_LEN as u16);
+
+        let modal = ModalBuilder::new("sim_load_preset", "Load a preset").input(input);
+
+        ComponentResult::CreateModal(modal)
+    }
+
+    async fn save_preset(&mut self, name: &str) {
+        let name = name.trim();
+
+        if name.is_empty() || name.chars().count() > PRESET_NAME_MAX_LEN {
+            self.note = Some(format!(
+                "Preset names must be between 1 and {PRESET_NAME_MAX_LEN} characters long"
+            ));
+
+            return;
+        }
+
+        let manager = Context::simulate_presets();
+
+        let presets = match manager.get(self.msg_owner).await {
+            Ok(presets) => presets,
+            Err(err) => {
+                warn!(?err);
+                self.note = Some("Failed to save preset".to_owned());
+
+                return;
+            }
+        };
+
+        let is_new = presets.iter().all(|preset| preset.name != name);
+
+        if is_new && presets.len() >= SimulatePresetManager::MAX_PRESETS {
+            self.note = Some(format!(
+                "You can have at most {} presets, overwrite an existing one instead",
+                SimulatePresetManager::MAX_PRESETS
+            ));
+
+            return;
+        }
+
+        let data = &self.data;
+
+        let preset = DbSimulatePreset {
+            name: name.to_owned(),
+            mods: data
+                .mods
+                .as_ref()
+                .filter(|mods| !mods.is_empty())
+                .map(GameMods::to_string),
+            acc: data.acc,
+            misses: data.n_miss.map(|n| n as i32),
+            clock_rate: data.clock_rate,
+            ar: data.attrs.ar,
+            cs: data.attrs.cs,
+            hp: data.attrs.hp,
+            od: data.attrs.od,
+            set_on_lazer: data.set_on_lazer,
+        };
+
+        self.note = match manager.store(self.msg_owner, &preset).await {
+            Ok(_) => Some(format!("Saved preset `{name}`")),
+            Err(err) => {
+                warn!(?err);
+
+                Some("Failed to save preset".to_owned())
+            }
+        };
+    }
+
+    async fn load_preset(&mut self, name: &str) {
+        let name = name.trim();
+
+        let presets = match Context::simulate_presets().get(self.msg_owner).await {
+            Ok(presets) => presets,
+            Err(err) => {
+                warn!(?err);
+                self.note = Some("Failed to load preset".to_owned());
+
+                return;
+            }
+        };
+
+        let Some(preset) = presets
+            .into_iter()
+            .find(|preset| preset.name.eq_ignore_ascii_case(name))
+        else {
+            self.note = Some(format!("You don't have a preset called `{name}`"));
+
+            return;
+        };
+
+        let mode = self.map.mode();
+
+        // Mods that don't fit the map's mode are ignored
+        self.data.mods = preset
+            .mods
+            .as_deref()
+            .and_then(|mods| mods.parse::<GameModsIntermode>().ok())
+            .and_then(|mods| mods.try_with_mode(mode))
+            .filter(GameMods::is_valid);
+
+        self.data.acc = preset.acc;
+        self.data.n_miss = preset.misses.map(|n| n as u32);
+        self.data.attrs.ar = preset.ar;
+        self.data.attrs.cs = preset.cs;
+        self.data.attrs.hp = preset.hp;
+        self.data.attrs.od = preset.od;
+        self.data.set_on_lazer = preset.set_on_lazer;
+
+        if preset.clock_rate.is_some() {
+            self.data.bpm = None;
+        }
+
+        self.data.clock_rate = preset.clock_rate;
+
+        if !preset.set_on_lazer {
+            self.data.n_slider_ends = None;
+            self.data.n_large_ticks = None;
+        }
+
+        self.note = Some(format!("Loaded preset `{}`", preset.name));
+    }
+
     async fn handle_topold_menu(
         &mut self,
         component: &mut InteractionComponent,
@@ -894,9 +1484,230 @@ impl SimulateMap {
                     ar = round(attrs.ar as f32),
                     od = round(attrs.od as f32),
                     hp = round(attrs.hp as f32),
-                    stars = round(stars),
+                    stars = Stars(stars),
                 )
             }
         }
     }
 }
+
+struct ComparedValues {
+    mods: String,
+    stars: f32,
+    pp: f32,
+    max_pp: f32,
+    acc: Option<f32>,
+}
+
+/// Split off a custom rate for rate-changing mods e.g. `hddt1.3` or
+/// `DT(1.3x)`.
+///
+/// Returns `None` if the rate is out of bounds for its mod.
+fn split_rate(input: &str) -> Option<(String, Option<f64>)> {
+    const RATE_MODS: [(&str, f64, f64); 4] = [
+        ("DT", 1.01, 2.0),
+        ("NC", 1.01, 2.0),
+        ("HT", 0.5, 0.99),
+        ("DC", 0.5, 0.99),
+    ];
+
+    let bytes = input.as_bytes();
+
+    for i in 0..bytes.len().saturating_sub(2) {
+        let acronym = &bytes[i..i + 2];
+
+        let Some((_, min, max)) = RATE_MODS
+            .iter()
+            .find(|(rate_mod, ..)| acronym.eq_ignore_ascii_case(rate_mod.as_bytes()))
+        else {
+            continue;
+        };
+
+        let rest = &input[i + 2..];
+        let (parens, rest) = match rest.strip_prefix('(') {
+            Some(rest) => (true, rest),
+            None => (false, rest),
+        };
+
+        let len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+
+        if len == 0 {
+            continue;
+        }
+
+        let rate: f64 = rest[..len].parse().ok()?;
+
+        if !(*min..=*max).contains(&rate) {
+            return None;
+        }
+
+        let mut suffix = rest[len..].trim_start_matches(['x', 'X']);
+
+        if parens {
+            suffix = suffix.strip_prefix(')')?;
+        }
+
+        return Some((format!("{}{suffix}", &input[..i + 2]), Some(rate)));
+    }
+
+    Some((input.to_owned(), None))
+}
+
+/// Apply the rate to all rate-changing mods.
+///
+/// Returns `None` if there are no such mods.
+fn with_speed_change(mods: GameMods, rate: f64) -> Option<GameMods> {
+    let mut applied = false;
+
+    let mods = mods
+        .into_iter()
+        .map(|mut gamemod| {
+            match gamemod {
+                GameMod::HalfTimeOsu(HalfTimeOsu {
+                    ref mut speed_change,
+                    ..
+                })
+                | GameMod::DaycoreOsu(DaycoreOsu {
+                    ref mut speed_change,
+                    ..
+                })
+                | GameMod::DoubleTimeOsu(DoubleTimeOsu {
+                    ref mut speed_change,
+                    ..
+                })
+                | GameMod::NightcoreOsu(NightcoreOsu {
+                    ref mut speed_change,
+                    ..
+                })
+                | GameMod::HalfTimeTaiko(HalfTimeTaiko {
+                    ref mut speed_change,
+                    ..
+                })
+                | GameMod::DaycoreTaiko(DaycoreTaiko {
+                    ref mut speed_change,
+                    ..
+                })
+                | GameMod::DoubleTimeTaiko(DoubleTimeTaiko {
+                    ref mut speed_change,
+                    ..
+                })
+                | GameMod::NightcoreTaiko(NightcoreTaiko {
+                    ref mut speed_change,
+                    ..
+                })
+                | GameMod::HalfTimeCatch(HalfTimeCatch {
+                    ref mut speed_change,
+                    ..
+                })
+                | GameMod::DaycoreCatch(DaycoreCatch {
+                    ref mut speed_change,
+                    ..
+                })
+                | GameMod::DoubleTimeCatch(DoubleTimeCatch {
+                    ref mut speed_change,
+                    ..
+                })
+                | GameMod::NightcoreCatch(NightcoreCatch {
+                    ref mut speed_change,
+                    ..
+                })
+                | GameMod::HalfTimeMania(HalfTimeMania {
+                    ref mut speed_change,
+                    ..
+                })
+                | GameMod::DaycoreMania(DaycoreMania {
+                    ref mut speed_change,
+                    ..
+                })
+                | GameMod::DoubleTimeMania(DoubleTimeMania {
+                    ref mut speed_change,
+                    ..
+                })
+                | GameMod::NightcoreMania(NightcoreMania {
+                    ref mut speed_change,
+                    ..
+                }) => {
+                    *speed_change = Some(rate);
+                    applied = true;
+                }
+                _ => {}
+            }
+
+            gamemod
+        })
+        .collect();
+
+    applied.then_some(mods)
+}
+
+fn mods_str(mods: &GameMods) -> String {
+    if mods.is_empty() {
+        "+NM".to_owned()
+    } else {
+        format!("+{mods}")
+    }
+}
+
+/// Codeblock with both results next to each other and their difference.
+fn comparison_table(main: &ComparedValues, other: &ComparedValues) -> String {
+    let mut rows = vec![
+        (
+            "Stars",
+            Stars(main.stars).to_string(),
+            Stars(other.stars).to_string(),
+            format!("{:+.2}", other.stars - main.stars),
+        ),
+        (
+            "PP",
+            Pp(main.pp).to_string(),
+            Pp(other.pp).to_string(),
+            format!("{:+.2}", other.pp - main.pp),
+        ),
+        (
+            "Max PP",
+            Pp(main.max_pp).to_string(),
+            Pp(other.max_pp).to_string(),
+            format!("{:+.2}", other.max_pp - main.max_pp),
+        ),
+    ];
+
+    if let (Some(main_acc), Some(other_acc)) = (main.acc, other.acc) {
+        rows.push((
+            "Acc",
+            format!("{}%", Acc(main_acc)),
+            format!("{}%", Acc(other_acc)),
+            format!("{:+.2}%", other_acc - main_acc),
+        ));
+    }
+
+    let main_len = rows
+        .iter()
+        .map(|(_, value, ..)| value.len())
+        .fold(main.mods.len(), usize::max);
+
+    let other_len = rows
+        .iter()
+        .map(|(_, _, value, _)| value.len())
+        .fold(other.mods.len(), usize::max);
+
+    let mut table = String::from("```\n");
+
+    let _ = writeln!(
+        table,
+        "{:<6}  {:>main_len$}  {:>other_len$}  Delta",
+        "", main.mods, other.mods,
+    );
+
+    for (name, main, other, delta) in rows {
+        let _ = writeln!(
+            table,
+            "{name:<6}  {main:>main_len$}  {other:>other_len$}  {delta}"
+        );
+    }
+
+    table.push_str("```");
+
+    table
+}
@@ -4,6 +4,7 @@ use rosu_pp::{
     Beatmap, Performance, any::HitResultPriority, catch::CatchScoreState, mania::ManiaScoreState,
     osu::OsuScoreState, taiko::TaikoScoreState,
 };
+use rosu_v2::prelude::GameMode;
 use twilight_model::channel::message::{
     Component,
     component::{ActionRow, Button, ButtonStyle, SelectMenu, SelectMenuOption, SelectMenuType},
@@ -23,6 +24,24 @@ pub enum TopOldVersion {
 }
 
 impl TopOldVersion {
+    /// The current pp version of the mode.
+    pub fn latest(mode: GameMode) -> Self {
+        match mode {
+            GameMode::Osu => Self::Osu(TopOldOsuVersion::March25Now),
+            GameMode::Taiko => Self::Taiko(TopOldTaikoVersion::March25Now),
+            GameMode::Catch => Self::Catch(TopOldCatchVersion::October24Now),
+            GameMode::Mania => Self::Mania(TopOldManiaVersion::October24Now),
+        }
+    }
+
+    /// Whether scores of this version can be simulated through accuracy.
+    pub fn has_acc(self) -> bool {
+        !matches!(
+            self,
+            Self::Mania(TopOldManiaVersion::March14May18 | TopOldManiaVersion::May18October22)
+        )
+    }
+
     pub fn from_menu_str(s: &str) -> Option<Self> {
         let version = match s {
             "sim_osu_march25_now" => Self::Osu(TopOldOsuVersion::March25Now),
@@ -17,9 +17,10 @@ use bathbot_util::{
     datetime::{HowLongAgoDynamic, HowLongAgoText, SHORT_NAIVE_DATETIME_FORMAT, SecToMinSec},
     fields,
     numbers::round,
+    units::{Acc, Pp, Stars},
 };
 use eyre::{Report, Result};
-use rosu_pp::model::beatmap::BeatmapAttributes;
+use rosu_pp::{any::PerformanceAttributes, model::beatmap::BeatmapAttributes};
 use rosu_render::{ClientError as OrdrError, client::error::ApiError as OrdrApiError};
 use rosu_v2::{
     error::OsuError,
@@ -31,6 +32,7 @@ use twilight_model::{
     channel::message::{
         Component, EmojiReactionType,
         component::{ActionRow, Button, ButtonStyle},
+        embed::EmbedField,
     },
     guild::Permissions,
     id::{
@@ -49,7 +51,7 @@ use crate::{
         osu::{OngoingRender, ProgressResponse, RENDERER_NAME, RenderStatus, RenderStatusInner},
         utility::{ScoreEmbedData, ScoreEmbedDataWrap},
     },
-    core::{Context, commands::OwnedCommandOrigin},
+    core::{Context, commands::OwnedCommandOrigin, spawn_with_osu_server},
     embeds::HitResultFormatter,
     manager::{ReplayError, redis::osu::CachedUser},
     util::{
@@ -65,6 +67,7 @@ pub struct SingleScorePagination {
     score_data: ScoreData,
     msg_owner: Id<UserMarker>,
     pages: Pages,
+    show_pp_breakdown: bool,
 
     author: AuthorBuilder,
     content: SingleScoreContent,
@@ -91,6 +94,7 @@ impl SingleScorePagination {
             score_data,
             msg_owner,
             pages,
+            show_pp_breakdown: false,
             author: user.author_builder(false),
             content,
         }
@@ -146,11 +150,21 @@ impl SingleScorePagination {
             data.append_to_description(&score.score, &score.map, &mut description);
         }
 
-        let builder = embed
+        let mut builder = embed
             .author(self.author.clone())
             .description(description)
             .url(url);
 
+        let pp_breakdown = if self.show_pp_breakdown {
+            pp_breakdown_field(score).await
+        } else {
+            None
+        };
+
+        if let Some(field) = pp_breakdown {
+            builder.push_field(field);
+        }
+
         Ok(BuildPage::new(builder, false).content(content))
     }
 
@@ -219,7 +233,7 @@ impl SingleScorePagination {
                 let channel_id = component.message.channel_id;
 
                 // Spawn in new task so that we're sure to callback the component in time
-                tokio::spawn(async move {
+                spawn_with_osu_server(async move {
                     let cached = CachedRender::new(score_id, video_url, true, owner);
                     let begin_fut = ActiveMessages::builder(cached).begin(channel_id);
 
@@ -241,7 +255,7 @@ impl SingleScorePagination {
             return self.render_cooldown_response(component, cooldown).await;
         }
 
-        tokio::spawn(Self::render_response(
+        spawn_with_osu_server(Self::render_response(
             (component.message.id, component.message.channel_id),
             component.permissions,
             score_id,
@@ -471,35 +485,53 @@ impl IActiveMessage for SingleScorePagination {
             .try_get()
             .expect("score data not yet expanded");
 
-        if score.miss_analyzer.is_some() || score.replay_score_id.is_some() {
-            let mut components = Vec::with_capacity(2);
-
-            if score.miss_analyzer.is_some() {
-                components.push(Component::Button(Button {
-                    custom_id: Some("miss_analyzer".to_owned()),
-                    disabled: false,
-                    emoji: Some(Emote::Miss.reaction_type()),
-                    label: Some("Miss analyzer".to_owned()),
-                    style: ButtonStyle::Primary,
-                    url: None,
-                    sku_id: None,
-                }));
-            }
+        let mut components = Vec::with_capacity(3);
+
+        if score.miss_analyzer.is_some() {
+            components.push(Component::Button(Button {
+                custom_id: Some("miss_analyzer".to_owned()),
+                disabled: false,
+                emoji: Some(Emote::Miss.reaction_type()),
+                label: Some("Miss analyzer".to_owned()),
+                style: ButtonStyle::Primary,
+                url: None,
+                sku_id: None,
+            }));
+        }
 
-            if score.replay_score_id.is_some() {
-                components.push(Component::Button(Button {
-                    custom_id: Some("render".to_owned()),
-                    disabled: false,
-                    emoji: Some(EmojiReactionType::Unicode {
-                        name: "🎥".to_owned(),
-                    }),
-                    label: Some("Render".to_owned()),
-                    style: ButtonStyle::Primary,
-                    url: None,
-                    sku_id: None,
-                }));
-            }
+        if score.replay_score_id.is_some() {
+            components.push(Component::Button(Button {
+                custom_id: Some("render".to_owned()),
+                disabled: false,
+                emoji: Some(EmojiReactionType::Unicode {
+                    name: "🎥".to_owned(),
+                }),
+                label: Some("Render".to_owned()),
+                style: ButtonStyle::Primary,
+                url: None,
+                sku_id: None,
+            }));
+        }
+
+        if score.score.mode == GameMode::Osu {
+            let label = if self.show_pp_breakdown {
+                "Hide pp breakdown"
+            } else {
+                "Show pp breakdown"
+            };
 
+            components.push(Component::Button(Button {
+                custom_id: Some("pp_breakdown".to_owned()),
+                disabled: false,
+                emoji: None,
+                label: Some(label.to_owned()),
+                style: ButtonStyle::Secondary,
+                url: None,
+                sku_id: None,
+            }));
+        }
+
+        if !components.is_empty() {
             all_components.push(Component::ActionRow(ActionRow { components }));
         }
 
@@ -518,6 +550,21 @@ impl IActiveMessage for SingleScorePagination {
         match component.data.custom_id.as_str() {
             "render" => self.handle_render_button(component).await,
             "miss_analyzer" => self.handle_miss_analyzer_button(component).await,
+            "pp_breakdown" => {
+                if user_id != self.msg_owner {
+                    return ComponentResult::Ignore;
+                }
+
+                if let Err(err) = component.defer().await {
+                    return ComponentResult::Err(
+                        Report::new(err).wrap_err("Failed to defer component"),
+                    );
+                }
+
+                self.show_pp_breakdown = !self.show_pp_breakdown;
+
+                ComponentResult::BuildPage
+            }
             _ => {
                 if user_id != self.msg_owner {
                     return ComponentResult::Ignore;
@@ -537,6 +584,36 @@ impl IActiveMessage for SingleScorePagination {
     }
 }
 
+/// Field showing the aim, speed, accuracy, and flashlight pp components of an
+/// osu!standard score.
+async fn pp_breakdown_field(data: &ScoreEmbedData) -> Option<EmbedField> {
+    if data.score.mode != GameMode::Osu {
+        return None;
+    }
+
+    let PerformanceAttributes::Osu(attrs) = Context::pp(&data.map)
+        .score(&data.score)
+        .performance()
+        .await?
+    else {
+        return None;
+    };
+
+    let value = format!(
+        "Aim: `{aim:.2}pp` • Speed: `{speed:.2}pp` • Accuracy: `{acc:.2}pp` • Flashlight: `{fl:.2}pp`",
+        aim = attrs.pp_aim,
+        speed = attrs.pp_speed,
+        acc = attrs.pp_acc,
+        fl = attrs.pp_flashlight,
+    );
+
+    Some(EmbedField {
+        inline: false,
+        name: "PP breakdown".to_owned(),
+        value,
+    })
+}
+
 pub enum SingleScoreContent {
     SameForAll(String),
     OnlyForIndex { idx: usize, content: String },
@@ -783,7 +860,7 @@ fn apply_settings(
     );
 
     if settings.show_sr_in_title {
-        let _ = write!(title, " [{}★]", round(data.stars));
+        let _ = write!(title, " [{}★]", Stars(data.stars));
     }
 
     let mut builder = EmbedBuilder::new().fields(fields).title(title);
@@ -861,7 +938,7 @@ fn write_value(
             let _ = write!(writer, "{}", ScoreFormatter::new(&data.score, score_data));
         }
         Value::Accuracy => {
-            let _ = write!(writer, "{}%", round(data.score.accuracy));
+            let _ = write!(writer, "{}%", Acc(data.score.accuracy));
         }
         Value::ScoreDate => {
             let score_date = data.score.ended_at;
@@ -974,7 +1051,7 @@ fn write_value(
             }
         }
         Value::Stars => {
-            let _ = write!(writer, "{}★", round(data.stars));
+            let _ = write!(writer, "{}★", Stars(data.stars));
         }
         Value::Length => {
             let clock_rate = map_attrs.clock_rate as f32;
@@ -8,7 +8,7 @@ use bathbot_macros::PaginationBuilder;
 use bathbot_model::SnipeRecent;
 use bathbot_util::{
     CowUtils, EmbedBuilder, FooterBuilder, IntHasher, ModsFormatter, constants::OSU_BASE,
-    datetime::HowLongAgoDynamic, numbers::round,
+    datetime::HowLongAgoDynamic, units::Acc,
 };
 use eyre::{Result, WrapErr};
 use rosu_pp::any::DifficultyAttributes;
@@ -89,7 +89,7 @@ impl IActiveMessage for SnipeDifferencePagination {
                 version = score.version.cow_escape_markdown(),
                 id = score.map_id,
                 mods = ModsFormatter::new(mods.as_ref(), true),
-                acc = round(score.accuracy),
+                acc = Acc(score.accuracy),
             );
 
             let _ = match self.diff {
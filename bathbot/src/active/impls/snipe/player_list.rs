@@ -7,10 +7,8 @@ use std::{
 use bathbot_macros::PaginationBuilder;
 use bathbot_model::{SnipeScore, SnipeScoreParams};
 use bathbot_util::{
-    CowUtils, EmbedBuilder, FooterBuilder, IntHasher, ModsFormatter,
-    constants::OSU_BASE,
-    datetime::HowLongAgoDynamic,
-    numbers::{WithComma, round},
+    CowUtils, EmbedBuilder, FooterBuilder, IntHasher, ModsFormatter, constants::OSU_BASE,
+    datetime::HowLongAgoDynamic, numbers::WithComma, units::Acc,
 };
 use eyre::{Result, WrapErr};
 use twilight_model::{
@@ -146,7 +144,7 @@ impl IActiveMessage for SnipePlayerListPagination {
                 mods = ModsFormatter::new(&mods, true),
                 pp = PpFormatter::new(score.pp, Some(max_pp)),
                 stars = score.stars,
-                acc = round(score.accuracy),
+                acc = Acc(score.accuracy),
                 score = WithComma::new(score.score),
                 combo = ComboFormatter::new(score.max_combo, max_combo),
                 miss = MissFormat(count_miss),
@@ -2,10 +2,12 @@ use std::fmt::{Display, Formatter, Result as FmtResult, Write};
 
 use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
-    CowUtils, EmbedBuilder, FooterBuilder, ModsFormatter, ScoreExt,
+    CowUtils, DescriptionBuilder, EmbedBuilder, FooterBuilder, ModsFormatter, ScoreExt,
     constants::OSU_BASE,
     datetime::HowLongAgoDynamic,
     numbers::{WithComma, round},
+    osu::effective_clock_rate,
+    units::{Acc, Pp, Stars},
 };
 use eyre::Result;
 use rosu_v2::prelude::GameMode;
@@ -18,13 +20,14 @@ use twilight_model::{
 use crate::{
     active::{
         BuildPage, ComponentResult, IActiveMessage,
+        map_preview::{MAP_PREVIEW_ID, handle_map_preview_component, map_preview_menu},
         pagination::{Pages, handle_pagination_component, handle_pagination_modal},
     },
     commands::{
         osu::TopScoreOrder,
         utility::{ScoreEmbedDataHalf, ScoreEmbedDataWrap},
     },
-    embeds::{ComboFormatter, HitResultFormatter, PpFormatter},
+    embeds::{ComboFormatter, HitResultFormatter, LnFormatter, PpFormatter},
     manager::{OsuMap, redis::osu::CachedUser},
     util::{
         CachedUserExt, Emote,
@@ -88,7 +91,7 @@ impl TopPagination {
     }
 
     fn condensed_description(&self, entries: &[ScoreEmbedDataWrap]) -> String {
-        let mut description = String::with_capacity(1024);
+        let mut description = DescriptionBuilder::new();
         let legacy_mods_order = self.score_data.is_legacy();
 
         for entry in entries {
@@ -114,13 +117,12 @@ impl TopPagination {
                     + 1,
                 map = MapFormat::from(map),
                 map_id = map.map_id(),
-                stars = round(*stars),
+                stars = Stars(*stars),
                 grade = GradeFormatter::new(score.grade, Some(score.score_id), score.is_legacy()),
-                pp = round(score.pp),
-                acc = if self.sort_by == TopScoreOrder::Acc {
-                    round_5(score.accuracy)
-                } else {
-                    round(score.accuracy)
+                pp = Pp(score.pp),
+                acc = AccFormat {
+                    acc: score.accuracy,
+                    precise: self.sort_by == TopScoreOrder::Acc,
                 },
                 combo = score.max_combo,
                 miss = MissFormat(score.statistics.miss),
@@ -133,13 +135,15 @@ impl TopPagination {
                     self.score_data
                 ),
             );
+
+            description.finish_entry();
         }
 
-        description
+        description.build()
     }
 
     fn condensed_description_mania(&self, entries: &[ScoreEmbedDataWrap]) -> String {
-        let mut description = String::with_capacity(1024);
+        let mut description = DescriptionBuilder::new();
         let legacy_mods_order = self.score_data.is_legacy();
 
         for entry in entries {
@@ -158,7 +162,7 @@ impl TopPagination {
 
             let _ = writeln!(
                 description,
-                "**#{idx} [{map}]({OSU_BASE}b/{map_id})** [{stars}★]\n\
+                "**#{idx} [{map}]({OSU_BASE}b/{map_id})** [{stars}★] {ln}\n\
                 {grade} **{pp}pp** {acc}% `{score}` {{{n320}/{n300}/../{miss}}} **+{mods}** {appendix}",
                 idx = original_idx
                     .or(pb_idx.as_ref().and_then(|idx| idx.idx))
@@ -166,13 +170,13 @@ impl TopPagination {
                     + 1,
                 map = MapFormat::from(map),
                 map_id = map.map_id(),
-                stars = round(*stars),
+                stars = Stars(*stars),
+                ln = LnFormatter::new(map.ln_percent()),
                 grade = GradeFormatter::new(score.grade, Some(score.score_id), score.is_legacy()),
-                pp = round(score.pp),
-                acc = if self.sort_by == TopScoreOrder::Acc {
-                    round_5(score.accuracy)
-                } else {
-                    round(score.accuracy)
+                pp = Pp(score.pp),
+                acc = AccFormat {
+                    acc: score.accuracy,
+                    precise: self.sort_by == TopScoreOrder::Acc,
                 },
                 // currently ignoring classic scoring, should it be considered for mania?
                 score = ScoreFormat(score.score),
@@ -188,9 +192,11 @@ impl TopPagination {
                     self.score_data
                 ),
             );
+
+            description.finish_entry();
         }
 
-        description
+        description.build()
     }
 
     fn build_detailed(&self) -> BuildPage {
@@ -199,7 +205,7 @@ impl TopPagination {
         let scores = &self.entries[pages.index()..end_idx];
         let legacy_mods_order = self.score_data.is_legacy();
 
-        let mut description = String::with_capacity(512);
+        let mut description = DescriptionBuilder::new();
 
         for entry in scores {
             let entry = entry.get_half();
@@ -215,10 +221,9 @@ impl TopPagination {
                 ..
             } = entry;
 
-            let _ = writeln!(
+            let _ = write!(
                 description,
-                "**#{idx} [{title} [{version}]]({OSU_BASE}b/{id}) +{mods}** [{stars:.2}★]\n\
-                {grade} {pp} • {acc}% • {score}\n[ {combo} ] • {hits} • {appendix}",
+                "**#{idx} [{title} [{version}]]({OSU_BASE}b/{id}) +{mods}** [{stars:.2}★]",
                 idx = original_idx
                     .or(pb_idx.as_ref().and_then(|idx| idx.idx))
                     .expect("missing idx")
@@ -227,12 +232,20 @@ impl TopPagination {
                 version = map.version().cow_escape_markdown(),
                 id = map.map_id(),
                 mods = ModsFormatter::new(&score.mods, legacy_mods_order),
+            );
+
+            if map.mode() == GameMode::Mania {
+                let _ = write!(description, " {}", LnFormatter::new(map.ln_percent()));
+            }
+
+            let _ = writeln!(
+                description,
+                "\n{grade} {pp} • {acc}% • {score}\n[ {combo} ] • {hits} • {appendix}",
                 grade = GradeFormatter::new(score.grade, Some(score.score_id), score.is_legacy()),
                 pp = PpFormatter::new(Some(score.pp), Some(*max_pp)),
-                acc = if self.sort_by == TopScoreOrder::Acc {
-                    round_5(score.accuracy)
-                } else {
-                    round(score.accuracy)
+                acc = AccFormat {
+                    acc: score.accuracy,
+                    precise: self.sort_by == TopScoreOrder::Acc,
                 },
                 score = ScoreFormatter::new(score, self.score_data),
                 combo = ComboFormatter::new(score.max_combo, Some(*max_combo)),
@@ -245,9 +258,9 @@ impl TopPagination {
                     self.score_data
                 ),
             );
-        }
 
-        description.pop();
+            description.finish_entry();
+        }
 
         let footer_text = format!(
             "Page {}/{} • Mode: {}",
@@ -258,7 +271,7 @@ impl TopPagination {
 
         let embed = EmbedBuilder::new()
             .author(self.user.author_builder(false))
-            .description(description)
+            .description(description.build())
             .footer(FooterBuilder::new(footer_text))
             .thumbnail(self.user.avatar_url.as_ref());
 
@@ -276,10 +289,37 @@ impl IActiveMessage for TopPagination {
     }
 
     fn build_components(&self) -> Vec<Component> {
-        self.pages.components()
+        let mut components = self.pages.components();
+
+        let pages = &self.pages;
+        let end_idx = self.entries.len().min(pages.index() + pages.per_page());
+
+        let maps = self.entries[pages.index()..end_idx]
+            .iter()
+            .zip(pages.index()..)
+            .map(|(entry, i)| {
+                let entry = entry.get_half();
+
+                let idx = entry
+                    .original_idx
+                    .or(entry.pb_idx.as_ref().and_then(|idx| idx.idx))
+                    .unwrap_or(i);
+
+                (idx, &entry.map)
+            });
+
+        components.extend(map_preview_menu(maps));
+
+        components
     }
 
     async fn handle_component(&mut self, component: &mut InteractionComponent) -> ComponentResult {
+        if component.data.custom_id == MAP_PREVIEW_ID {
+            let maps = self.entries.iter().map(|entry| &entry.get_half().map);
+
+            return handle_map_preview_component(component, maps).await;
+        }
+
         handle_pagination_component(component, self.msg_owner, false, &mut self.pages).await
     }
 
@@ -528,6 +568,23 @@ impl Display for MapFormat<'_> {
     }
 }
 
+/// Accuracy with five decimals if `precise` is set.
+struct AccFormat {
+    acc: f32,
+    precise: bool,
+}
+
+impl Display for AccFormat {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if self.precise {
+            Display::fmt(&round_5(self.acc), f)
+        } else {
+            Display::fmt(&Acc(self.acc), f)
+        }
+    }
+}
+
 struct MissFormat(u32);
 
 impl Display for MissFormat {
@@ -585,7 +642,7 @@ impl Display for OrderAppendix<'_> {
         match self.sort_by {
             TopScoreOrder::Ar => write!(f, "`AR {}`", round(self.entry.ar() as f32)),
             TopScoreOrder::Bpm => {
-                let clock_rate = self.entry.score.mods.clock_rate().unwrap_or(1.0);
+                let clock_rate = effective_clock_rate(&self.entry.score.mods);
 
                 write!(
                     f,
@@ -595,7 +652,7 @@ impl Display for OrderAppendix<'_> {
             }
             TopScoreOrder::Cs => write!(f, "`CS {}`", round(self.entry.cs() as f32)),
             TopScoreOrder::Length => {
-                let clock_rate = self.entry.score.mods.clock_rate().unwrap_or(1.0);
+                let clock_rate = effective_clock_rate(&self.entry.score.mods);
 
                 let secs = (self.entry.map.seconds_drain() as f64 / clock_rate) as u32;
 
@@ -2,10 +2,8 @@ use std::fmt::Write;
 
 use bathbot_macros::PaginationBuilder;
 use bathbot_util::{
-    CowUtils, EmbedBuilder, FooterBuilder, ModsFormatter, ScoreExt,
-    constants::OSU_BASE,
-    datetime::HowLongAgoDynamic,
-    numbers::{WithComma, round},
+    CowUtils, DescriptionBuilder, EmbedBuilder, FooterBuilder, ModsFormatter, ScoreExt,
+    constants::OSU_BASE, datetime::HowLongAgoDynamic, numbers::WithComma, units::Acc,
 };
 use eyre::Result;
 use rosu_v2::prelude::GameMode;
@@ -49,7 +47,7 @@ impl IActiveMessage for TopIfPagination {
         let end_idx = self.entries.len().min(pages.index() + pages.per_page());
         let entries = &self.entries[pages.index()..end_idx];
 
-        let mut description = String::with_capacity(512);
+        let mut description = DescriptionBuilder::new();
 
         for entry in entries {
             let TopIfEntry {
@@ -74,15 +72,15 @@ impl IActiveMessage for TopIfPagination {
                 grade =
                     GradeFormatter::new(score.grade, Some(score.score_id), entry.score.is_legacy()),
                 pp = PpFormatter::new(Some(score.pp), Some(*max_pp)),
-                acc = round(score.accuracy),
+                acc = Acc(score.accuracy),
                 score = WithComma::new(score.score),
                 combo = ComboFormatter::new(score.max_combo, Some(*max_combo)),
                 hits = HitResultFormatter::new(self.mode, &score.statistics),
                 ago = HowLongAgoDynamic::new(&score.ended_at)
             );
-        }
 
-        description.pop();
+            description.finish_entry();
+        }
 
         let mut footer_text = format!("Page {}/{}", pages.curr_page(), pages.last_page());
 
@@ -104,7 +102,7 @@ impl IActiveMessage for TopIfPagination {
 
         let embed = EmbedBuilder::new()
             .author(self.user.author_builder(false))
-            .description(description)
+            .description(description.build())
             .footer(FooterBuilder::new(footer_text))
             .thumbnail(self.user.avatar_url.as_ref())
             .title(title);
@@ -0,0 +1,204 @@
+use std::fmt::Write;
+
+use bathbot_util::{
+    AuthorBuilder, CowUtils, EmbedBuilder, FooterBuilder, MessageBuilder, constants::OSU_BASE,
+    datetime::SecToMinSec, fields, numbers::round,
+};
+use eyre::{Result, WrapErr, eyre};
+use rosu_pp::Difficulty;
+use twilight_model::channel::message::{
+    Component,
+    component::{ActionRow, SelectMenu, SelectMenuOption, SelectMenuType},
+};
+
+use super::ComponentResult;
+use crate::{
+    manager::OsuMap,
+    util::{ComponentExt, Emote, interaction::InteractionComponent},
+};
+
+/// Custom id of the select menu created by [`map_preview_menu`].
+pub const MAP_PREVIEW_ID: &str = "map_preview";
+
+/// Select menu listing the maps of a page so that one of them can be previewed.
+///
+/// `maps` are the page's maps alongside their index in the list. Maps that
+/// appear multiple times are only listed once. Returns `None` if there are no
+/// maps.
+pub fn map_preview_menu<'m>(
+    maps: impl IntoIterator<Item = (usize, &'m OsuMap)>,
+) -> Option<Component> {
+    let mut map_ids = Vec::new();
+
+    let options: Vec<_> = maps
+        .into_iter()
+        .filter(|(_, map)| {
+            let map_id = map.map_id();
+            let new = !map_ids.contains(&map_id);
+            map_ids.push(map_id);
+
+            new
+        })
+        .take(25)
+        .map(|(idx, map)| {
+            let mut label = format!("#{} {} [{}]", idx + 1, map.title(), map.version());
+
+            if label.chars().count() > 100 {
+                label = label.chars().take(99).collect();
+                label.push('…');
+            }
+
+            SelectMenuOption {
+                default: false,
+                description: None,
+                emoji: None,
+                label,
+                value: map.map_id().to_string(),
+            }
+        })
+        .collect();
+
+    if options.is_empty() {
+        return None;
+    }
+
+    let menu = SelectMenu {
+        custom_id: MAP_PREVIEW_ID.to_owned(),
+        disabled: false,
+        max_values: None,
+        min_values: None,
+        options: Some(options),
+        placeholder: Some("Preview a map".to_owned()),
+        channel_types: None,
+        default_values: None,
+        kind: SelectMenuType::Text,
+    };
+
+    Some(Component::ActionRow(ActionRow {
+        components: vec![Component::SelectMenu(menu)],
+    }))
+}
+
+/// Respond to the [`map_preview_menu`] with an ephemeral embed of the selected
+/// map.
+///
+/// Since only the invoker can see the response, anyone may use the menu.
+pub async fn handle_map_preview_component<'m>(
+    component: &mut InteractionComponent,
+    maps: impl IntoIterator<Item = &'m OsuMap>,
+) -> ComponentResult {
+    async fn inner<'m>(
+        component: &mut InteractionComponent,
+        maps: impl IntoIterator<Item = &'m OsuMap>,
+    ) -> Result<()> {
+        let value = component
+            .data
+            .values
+            .pop()
+            .ok_or_else(|| eyre!("Missing value in map preview menu"))?;
+
+        let map = value
+            .parse::<u32>()
+            .ok()
+            .and_then(|map_id| maps.into_iter().find(|map| map.map_id() == map_id))
+            .ok_or_else(|| eyre!("Unknown map preview menu value `{value}`"))?;
+
+        let builder = MessageBuilder::new().embed(map_preview_embed(map));
+
+        component
+            .ephemeral(builder)
+            .await
+            .wrap_err("Failed to send map preview")?;
+
+        Ok(())
+    }
+
+    match inner(component, maps).await {
+        Ok(_) => ComponentResult::Ignore,
+        Err(err) => ComponentResult::Err(err),
+    }
+}
+
+fn map_preview_embed(map: &OsuMap) -> EmbedBuilder {
+    let stars = if map.pp_map.check_suspicion().is_ok() {
+        Difficulty::new().calculate(&map.pp_map).stars()
+    } else {
+        0.0
+    };
+
+    let attrs = map.attributes().build();
+
+    let mut info_value = String::with_capacity(128);
+
+    let _ = write!(
+        info_value,
+        "Stars: `{stars:.2}★` Length: `{length}` BPM: `{bpm}` Objects: `{objects}`\n\
+        CS: `{cs}` AR: `{ar}` OD: `{od}` HP: `{hp}` Spinners: `{spinners}`",
+        length = SecToMinSec::new(map.seconds_drain()),
+        bpm = round(map.bpm()),
+        objects = map.n_objects(),
+        cs = round(attrs.cs as f32),
+        ar = round(attrs.ar as f32),
+        od = round(attrs.od as f32),
+        hp = round(attrs.hp as f32),
+        spinners = map.n_spinners(),
+    );
+
+    let info_name = format!(
+        "{mode} __[{version}]__",
+        mode = Emote::from(map.mode()),
+        version = map.version().cow_escape_markdown()
+    );
+
+    let mut fields = Vec::with_capacity(2);
+
+    fields![fields {
+        info_name, info_value, true;
+        "Download", download_links(map.mapset_id()), true;
+    }];
+
+    let title = format!(
+        "{artist} - {title}",
+        artist = map.artist().cow_escape_markdown(),
+        title = map.title().cow_escape_markdown(),
+    );
+
+    let description = format!(
+        ":musical_note: [Song preview](https://b.ppy.sh/preview/{mapset_id}.mp3) \
+        :frame_photo: [Full background](https://assets.ppy.sh/beatmaps/{mapset_id}/covers/raw.jpg)",
+        mapset_id = map.mapset_id()
+    );
+
+    let author = AuthorBuilder::new(format!("Created by {}", map.creator()));
+
+    let mut embed = EmbedBuilder::new()
+        .author(author)
+        .description(description)
+        .fields(fields)
+        .footer(FooterBuilder::new(format!("{:?}", map.status())))
+        .image(map.cover())
+        .title(title)
+        .url(format!("{OSU_BASE}b/{}", map.map_id()));
+
+    if let Some(ranked_date) = map.ranked_date() {
+        embed = embed.timestamp(ranked_date);
+    }
+
+    embed
+}
+
+/// Links to download the mapset through various mirrors.
+pub fn download_links(mapset_id: u32) -> String {
+    #[cfg(not(feature = "server"))]
+    let url = "https://www.google.com";
+
+    #[cfg(feature = "server")]
+    let url = &crate::core::BotConfig::get().server.public_url;
+
+    format!(
+        "[osu!direct]({url}/osudirect/{mapset_id})\n\
+        [catboy.best](https://catboy.best/d/{mapset_id})\n\
+        [osu.direct](https://osu.direct/d/{mapset_id})\n\
+        [nerinyan.moe](https://api.nerinyan.moe/d/{mapset_id})"
+    )
+}
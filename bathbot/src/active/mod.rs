@@ -1,9 +1,10 @@
 use std::time::{Duration, Instant};
 
-use bathbot_util::{EmbedBuilder, IntHasher, MessageBuilder, modal::ModalBuilder};
+use bathbot_util::{Authored, EmbedBuilder, IntHasher, MessageBuilder, modal::ModalBuilder};
 use enum_dispatch::enum_dispatch;
 use eyre::{ContextCompat, Report, Result, WrapErr};
 use flexmap::tokio::TokioMutexMap;
+use futures::StreamExt;
 use impls::relax::top::RelaxTopPagination;
 use tokio::sync::watch::Sender;
 use twilight_model::{
@@ -18,21 +19,21 @@ use self::{
         BackgroundGameSetup, BadgesPagination, BookmarksPagination, CachedRender,
         ChangelogPagination, CompareMostPlayedPagination, CompareScoresPagination,
         CompareTopPagination, DailyChallengeTodayPagination, HelpInteractionCommand,
-        HelpPrefixMenu, HigherLowerGame, LeaderboardPagination, MapPagination, MapSearchPagination,
-        MatchComparePagination, MatchCostPagination, MedalCountPagination, MedalRarityPagination,
-        MedalsCommonPagination, MedalsListPagination, MedalsMissingPagination,
-        MedalsRecentPagination, MostPlayedPagination, NoChokePagination, OsuStatsBestPagination,
-        OsuStatsPlayersPagination, OsuStatsScoresPagination, ProfileMenu,
-        RankingCountriesPagination, RankingPagination, RecentListPagination, RenderSettingsActive,
-        ScoreEmbedBuilderActive, SettingsImport, SimulateComponents, SingleScorePagination,
-        SkinsPagination, SlashCommandsPagination, SnipeCountryListPagination,
-        SnipeDifferencePagination, SnipePlayerListPagination, TopIfPagination, TopPagination,
-        TrackListPagination,
+        HelpPrefixMenu, HigherLowerGame, LeaderboardPagination, MapCommentsPagination,
+        MapPagination, MapSearchPagination, MatchComparePagination, MatchCostPagination,
+        MedalCountPagination, MedalRarityPagination, MedalsCommonPagination, MedalsListPagination,
+        MedalsMissingPagination, MedalsRecentPagination, MostPlayedPagination, NoChokePagination,
+        OsuStatsBestPagination, OsuStatsPlayersPagination, OsuStatsScoresPagination, ProfileMenu,
+        RankGraphPagination, RankingCountriesPagination, RankingPagination, RecentListPagination,
+        RenderSettingsActive, ScoreEmbedBuilderActive, SettingsImport, SimulateComponents,
+        SingleScorePagination, SkinsPagination, SlashCommandsPagination,
+        SnipeCountryListPagination, SnipeDifferencePagination, SnipePlayerListPagination,
+        TopIfPagination, TopPagination, TrackListPagination,
     },
     response::ActiveResponse,
 };
 use crate::{
-    core::{BotMetrics, Context, EventKind},
+    core::{BotMetrics, Context, EventKind, commands::restrict_attachment},
     util::{
         ComponentExt, ModalExt,
         interaction::{InteractionComponent, InteractionModal},
@@ -42,6 +43,7 @@ use crate::{
 pub mod impls;
 
 mod builder;
+mod map_preview;
 mod origin;
 mod pagination;
 mod response;
@@ -61,6 +63,7 @@ pub enum ActiveMessage {
     HelpPrefixMenu,
     HigherLowerGame,
     LeaderboardPagination,
+    MapCommentsPagination,
     MapPagination,
     MapSearchPagination,
     MatchComparePagination,
@@ -77,6 +80,7 @@ pub enum ActiveMessage {
     OsuStatsPlayersPagination,
     OsuStatsScoresPagination,
     ProfileMenu,
+    RankGraphPagination,
     RankingPagination,
     RankingCountriesPagination,
     RecentListPagination,
@@ -99,6 +103,8 @@ pub enum ActiveMessage {
 struct FullActiveMessage {
     active_msg: ActiveMessage,
     activity_tx: Sender<()>,
+    /// Whether pages should include a plain-text version of their embed
+    plain_text: bool,
 }
 
 pub struct ActiveMessages {
@@ -135,6 +141,7 @@ impl ActiveMessages {
         let Some(FullActiveMessage {
             active_msg,
             activity_tx,
+            plain_text,
         }) = guard.get_mut()
         else {
             return error!(
@@ -147,20 +154,24 @@ impl ActiveMessages {
         async fn handle_component_inner(
             active_msg: &mut ActiveMessage,
             activity_tx: &Sender<()>,
+            plain_text: bool,
             component: &mut InteractionComponent,
         ) {
             match active_msg.handle_component(component).await {
                 ComponentResult::BuildPage => match active_msg.build_page().await {
                     Ok(build) => {
-                        let mut builder = MessageBuilder::new()
-                            .embed(build.embed)
-                            .components(active_msg.build_components());
-
-                        if let Some(ref content) = build.content {
-                            builder = builder.content(content.as_ref());
-                        }
+                        let defer = build.defer;
+                        let builder = build.into_message(active_msg.build_components(), plain_text);
+
+                        let builder = restrict_attachment(
+                            builder,
+                            component.guild_id,
+                            component.channel_id,
+                            component.user_id().ok(),
+                        )
+                        .await;
 
-                        if build.defer {
+                        if defer {
                             if let Err(err) = component.update(builder).await {
                                 BotMetrics::inc_command_error(
                                     "component",
@@ -226,7 +237,7 @@ impl ActiveMessages {
             }
         }
 
-        handle_component_inner(active_msg, activity_tx, &mut component).await;
+        handle_component_inner(active_msg, activity_tx, *plain_text, &mut component).await;
 
         let elapsed = start.elapsed();
         BotMetrics::observe_command("component", component.data.custom_id, elapsed);
@@ -245,6 +256,7 @@ impl ActiveMessages {
         let Some(FullActiveMessage {
             active_msg,
             activity_tx,
+            plain_text,
         }) = guard.get_mut()
         else {
             return error!(name = %modal.data.custom_id, ?modal, "Unknown modal");
@@ -253,6 +265,7 @@ impl ActiveMessages {
         async fn handle_modal_inner(
             active_msg: &mut ActiveMessage,
             activity_tx: &Sender<()>,
+            plain_text: bool,
             modal: &mut InteractionModal,
         ) {
             if let Err(err) = active_msg.handle_modal(modal).await {
@@ -263,15 +276,18 @@ impl ActiveMessages {
 
             match active_msg.build_page().await {
                 Ok(build) => {
-                    let mut builder = MessageBuilder::new()
-                        .embed(build.embed)
-                        .components(active_msg.build_components());
-
-                    if let Some(ref content) = build.content {
-                        builder = builder.content(content.as_ref());
-                    }
+                    let defer = build.defer;
+                    let builder = build.into_message(active_msg.build_components(), plain_text);
+
+                    let builder = restrict_attachment(
+                        builder,
+                        modal.guild_id,
+                        modal.channel_id,
+                        modal.user_id().ok(),
+                    )
+                    .await;
 
-                    if build.defer {
+                    if defer {
                         if let Err(err) = modal.update(builder).await {
                             BotMetrics::inc_command_error("modal", modal.data.custom_id.clone());
 
@@ -305,7 +321,7 @@ impl ActiveMessages {
             }
         }
 
-        handle_modal_inner(active_msg, activity_tx, &mut modal).await;
+        handle_modal_inner(active_msg, activity_tx, *plain_text, &mut modal).await;
 
         let elapsed = start.elapsed();
         BotMetrics::observe_command("modal", modal.data.custom_id, elapsed);
@@ -315,6 +331,29 @@ impl ActiveMessages {
         self.inner.clear().await
     }
 
+    /// Remove messages whose timeout task is no longer running and which
+    /// would thus never be removed otherwise.
+    ///
+    /// Returns the amount of such messages.
+    pub async fn prune_orphaned(&self, dry_run: bool) -> usize {
+        let mut orphaned = Vec::new();
+        let mut stream = self.inner.iter();
+
+        while let Some(guard) = stream.next().await {
+            if guard.value().activity_tx.is_closed() {
+                orphaned.push(*guard.key());
+            }
+        }
+
+        if !dry_run {
+            for &msg in orphaned.iter() {
+                self.remove(msg).await;
+            }
+        }
+
+        orphaned.len()
+    }
+
     pub async fn remove(&self, msg: Id<MessageMarker>) {
         self.remove_full(msg).await;
     }
@@ -388,6 +427,7 @@ pub struct BuildPage {
     embed: EmbedBuilder,
     defer: bool,
     content: Option<Box<str>>,
+    attachment: Option<(String, Vec<u8>)>,
 }
 
 impl BuildPage {
@@ -396,6 +436,7 @@ impl BuildPage {
             embed,
             defer,
             content: None,
+            attachment: None,
         }
     }
 
@@ -405,9 +446,54 @@ impl BuildPage {
         self
     }
 
+    /// Attach a file to the page, replacing the previous page's attachment.
+    pub fn attachment(mut self, name: impl Into<String>, bytes: Vec<u8>) -> Self {
+        self.attachment = Some((name.into(), bytes));
+
+        self
+    }
+
     pub fn into_embed(self) -> EmbedBuilder {
         self.embed
     }
+
+    /// Create a message for the page.
+    ///
+    /// If `plain_text` is set, the content additionally contains the embed
+    /// serialized as plain text, as long as it fits.
+    fn into_message(self, components: Vec<Component>, plain_text: bool) -> MessageBuilder<'static> {
+        let Self {
+            embed,
+            defer: _,
+            content,
+            attachment,
+        } = self;
+
+        let content = match (content, plain_text) {
+            (content, false) => content.map(String::from),
+            (None, true) => Some(embed.to_plain_text()),
+            (Some(content), true) => {
+                let combined = format!("{content}\n\n{}", embed.to_plain_text());
+
+                if combined.chars().count() <= 2000 {
+                    Some(combined)
+                } else {
+                    Some(String::from(content))
+                }
+            }
+        };
+
+        let mut builder = MessageBuilder::new().embed(embed).components(components);
+
+        if let Some((name, bytes)) = attachment {
+            builder = builder.attachment(name, bytes);
+        }
+
+        match content {
+            Some(content) => builder.content(content),
+            None => builder,
+        }
+    }
 }
 
 pub enum ComponentResult {
@@ -7,7 +7,10 @@ use twilight_http::{
 };
 use twilight_model::{
     channel::Message,
-    id::{Id, marker::ChannelMarker},
+    id::{
+        Id,
+        marker::{ChannelMarker, UserMarker},
+    },
 };
 
 use crate::{
@@ -21,6 +24,13 @@ pub enum ActiveMessageOrigin<'d> {
 }
 
 impl ActiveMessageOrigin<'_> {
+    pub(super) fn user_id(&self) -> Option<Id<UserMarker>> {
+        match self {
+            Self::Channel(_) => None,
+            Self::Command(orig) => orig.user_id().ok(),
+        }
+    }
+
     pub(super) async fn create_message(
         &self,
         builder: MessageBuilder<'_>,
@@ -186,6 +186,16 @@ async fn command_help(
         eb = eb.author(author);
     }
 
+    if cmd.flags.bancho_only() {
+        let field = EmbedField {
+            name: "Only available for bancho".to_owned(),
+            value: "Servers that target a private osu! server can't use this command".to_owned(),
+            inline: false,
+        };
+
+        fields.push(field);
+    }
+
     let footer_text = if cmd.flags.only_guilds() || cmd.flags.authority() {
         "Only available in servers"
     } else {
@@ -1,30 +1,38 @@
 use std::{borrow::Cow, cmp::Reverse};
 
 use bathbot_macros::{HasName, SlashCommand};
-use bathbot_model::OsekaiBadge;
+use bathbot_model::{OsekaiBadge, command_fields::EnableDisable};
 use eyre::Result;
 use twilight_interactions::command::{
     AutocompleteValue, CommandModel, CommandOption, CreateCommand, CreateOption,
 };
-use twilight_model::id::{Id, marker::UserMarker};
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, UserMarker},
+};
 
-use self::{query::*, user::*};
+pub use self::notify::badge_notify_loop;
+use self::{notify::notify, query::*, user::*};
 use crate::{
     commands::{DISCORD_OPTION_DESC, DISCORD_OPTION_HELP},
     util::{InteractionCommandExt, interaction::InteractionCommand},
 };
 
+mod notify;
 mod query;
 mod user;
 
 #[derive(CreateCommand, SlashCommand)]
 #[command(name = "badges", desc = "Display info about badges")]
 #[allow(dead_code)]
+#[flags(BANCHO_ONLY)]
 pub enum Badges<'a> {
     #[command(name = "query")]
     Query(BadgesQuery),
     #[command(name = "user")]
     User(BadgesUser<'a>),
+    #[command(name = "notify")]
+    Notify(BadgesNotify),
 }
 
 #[derive(CommandModel)]
@@ -33,6 +41,8 @@ enum Badges_<'a> {
     Query(BadgesQuery_<'a>),
     #[command(name = "user")]
     User(BadgesUser<'a>),
+    #[command(name = "notify")]
+    Notify(BadgesNotify),
 }
 
 const BADGE_QUERY_DESC: &str = "Display all badges matching the query";
@@ -65,6 +75,35 @@ pub struct BadgesUser<'a> {
     sort: Option<BadgesOrder>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(min_value = 2007, desc = "Only show badges awarded in this year")]
+    year: Option<i32>,
+    #[command(
+        desc = "Only show badges whose name or description contains this text",
+        help = "Only show badges whose name or description contains this text.\n\
+        The comparison is case-insensitive so e.g. `owc` matches all osu! World Cup badges."
+    )]
+    name_filter: Option<String>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "notify",
+    desc = "Get notified about new badges",
+    help = "Get notified about new badges.\n\
+    Without specifying a channel, you will receive a DM whenever your linked osu! profile \
+    receives a new badge.\n\
+    Server authorities can specify a channel in which new badges of all linked members \
+    of the server will be announced. To disable announcements, specify any channel.\n\
+    Badges are checked every two hours."
+)]
+pub struct BadgesNotify {
+    #[command(desc = "Choose whether notifications should be enabled or disabled")]
+    action: EnableDisable,
+    #[command(
+        desc = "Specify a channel to announce new badges of all server members in",
+        channel_types = "guild_text guild_announcement"
+    )]
+    channel: Option<Id<ChannelMarker>>,
 }
 
 #[derive(CommandOption, CreateOption, Default)]
@@ -76,6 +115,8 @@ pub enum BadgesOrder {
     Date,
     #[option(name = "Owner count", value = "owners")]
     Owners,
+    #[option(name = "Rarity", value = "rarity")]
+    Rarity,
 }
 
 impl BadgesOrder {
@@ -84,10 +125,19 @@ impl BadgesOrder {
             Self::Alphabet => badges.sort_unstable_by(|a, b| a.name.cmp(&b.name)),
             Self::Date => badges.sort_unstable_by_key(|badge| Reverse(badge.awarded_at)),
             Self::Owners => badges.sort_unstable_by_key(|badge| Reverse(badge.users.len())),
+            Self::Rarity => badges.sort_unstable_by_key(|badge| badge.users.len()),
         }
     }
 }
 
+/// Sum of `1 / owner_count` over all badges so that rare badges weigh more.
+fn badge_score(badges: &[OsekaiBadge]) -> f32 {
+    badges
+        .iter()
+        .map(|badge| 1.0 / badge.users.len().max(1) as f32)
+        .sum()
+}
+
 pub async fn slash_badges(mut command: InteractionCommand) -> Result<()> {
     match Badges_::from_interaction(command.input_data())? {
         Badges_::Query(args) => match args.name {
@@ -98,5 +148,6 @@ pub async fn slash_badges(mut command: InteractionCommand) -> Result<()> {
             }
         },
         Badges_::User(args) => user((&mut command).into(), args).await,
+        Badges_::Notify(args) => notify(command, args).await,
     }
 }
@@ -0,0 +1,289 @@
+use std::{
+    collections::{HashMap, HashSet},
+    time::Duration,
+};
+
+use bathbot_model::command_fields::EnableDisable;
+use bathbot_util::{
+    EmbedBuilder, IntHasher, MessageBuilder,
+    constants::{GENERAL_ISSUE, OSU_BASE},
+};
+use eyre::{Report, Result};
+use tokio::time::{interval, sleep};
+use twilight_model::{
+    channel::message::Embed,
+    id::{Id, marker::ChannelMarker},
+};
+
+use super::BadgesNotify;
+use crate::{
+    core::{Context, commands::checks::check_authority},
+    util::{Authored, InteractionCommandExt, interaction::InteractionCommand},
+};
+
+pub(super) async fn notify(command: InteractionCommand, args: BadgesNotify) -> Result<()> {
+    let owner = command.user_id()?;
+
+    let content = match (args.action, args.channel) {
+        (action, Some(channel)) => {
+            let Some(guild_id) = command.guild_id else {
+                let content = "Announcing new badges in a channel is only possible in servers";
+                command.error(content).await?;
+
+                return Ok(());
+            };
+
+            match check_authority(owner, Some(guild_id)).await {
+                Ok(None) => {}
+                Ok(Some(content)) => {
+                    command.error(content).await?;
+
+                    return Ok(());
+                }
+                Err(err) => {
+                    let _ = command.error(GENERAL_ISSUE).await;
+
+                    return Err(err.wrap_err("Failed to check authority status"));
+                }
+            }
+
+            let manager = Context::badge_notify();
+
+            match action {
+                EnableDisable::Enable => match manager.set_channel(guild_id, channel).await {
+                    Ok(_) => format!(
+                        "New badges of this server's linked members will now be announced in <#{channel}>"
+                    ),
+                    Err(err) => {
+                        let _ = command.error(GENERAL_ISSUE).await;
+
+                        return Err(err);
+                    }
+                },
+                EnableDisable::Disable => match manager.remove_channel(guild_id).await {
+                    Ok(true) => "New badges will no longer be announced in this server".to_owned(),
+                    Ok(false) => "New badges were not announced in this server anyway".to_owned(),
+                    Err(err) => {
+                        let _ = command.error(GENERAL_ISSUE).await;
+
+                        return Err(err);
+                    }
+                },
+            }
+        }
+        (EnableDisable::Enable, None) => {
+            match Context::user_config().osu_id(owner).await {
+                Ok(Some(_)) => {}
+                Ok(None) => {
+                    let content = "You need to link your discord to an osu! profile first \
+                        via `/link` so I know which badges to check";
+                    command.error(content).await?;
+
+                    return Ok(());
+                }
+                Err(err) => {
+                    let _ = command.error(GENERAL_ISSUE).await;
+
+                    return Err(err);
+                }
+            }
+
+            if let Err(err) = Context::badge_notify().add_user(owner).await {
+                let _ = command.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+
+            "I will send you a DM whenever your linked osu! profile receives a new badge".to_owned()
+        }
+        (EnableDisable::Disable, None) => match Context::badge_notify().remove_user(owner).await {
+            Ok(true) => "You will no longer be notified about new badges".to_owned(),
+            Ok(false) => "You were not subscribed to badge notifications anyway".to_owned(),
+            Err(err) => {
+                let _ = command.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        },
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+/// Periodically compare the cached osekai badges against the last seen badges
+/// of all users that are relevant for notifications and announce new ones.
+pub async fn badge_notify_loop() {
+    // Give the cache some time to fill up after booting
+    sleep(Duration::from_secs(60 * 15)).await;
+
+    // Osekai badges are cached for two hours
+    let mut interval = interval(Duration::from_secs(2 * 60 * 60));
+
+    loop {
+        interval.tick().await;
+
+        if let Err(err) = notify_new_badges().await {
+            warn!(?err, "Failed to notify about new badges");
+        }
+    }
+}
+
+async fn notify_new_badges() -> Result<()> {
+    let manager = Context::badge_notify();
+
+    let mut channel_targets = Vec::new();
+
+    for (guild_id, channel_id) in manager.channels().await? {
+        let members: Vec<_> = match Context::cache().members(guild_id).await {
+            Ok(members) => members.into_iter().map(|id| id as i64).collect(),
+            Err(err) => {
+                warn!(guild = guild_id.get(), ?err, "Failed to get guild members");
+
+                continue;
+            }
+        };
+
+        let osu_ids = Context::user_config().linked_osu_ids(&members).await?;
+        channel_targets.push((channel_id, osu_ids));
+    }
+
+    let mut user_targets = Vec::new();
+
+    for user_id in manager.users().await? {
+        if let Some(osu_id) = Context::user_config().osu_id(user_id).await? {
+            user_targets.push((user_id, osu_id));
+        }
+    }
+
+    let relevant: HashSet<u32, IntHasher> = channel_targets
+        .iter()
+        .flat_map(|(_, osu_ids)| osu_ids.iter().copied())
+        .chain(user_targets.iter().map(|(_, osu_id)| *osu_id))
+        .collect();
+
+    if relevant.is_empty() {
+        return Ok(());
+    }
+
+    let badges = Context::redis()
+        .badges()
+        .await
+        .map_err(|err| Report::new(err).wrap_err("Failed to get badges"))?;
+
+    // Current badges of each relevant user as indices into `badges`
+    let mut current: HashMap<u32, Vec<usize>, IntHasher> = HashMap::default();
+
+    for (i, badge) in badges.iter().enumerate() {
+        for user_id in badge.users.iter().map(|id| id.to_native()) {
+            if relevant.contains(&user_id) {
+                current.entry(user_id).or_default().push(i);
+            }
+        }
+    }
+
+    let relevant: Vec<_> = relevant.into_iter().collect();
+    let seen = manager.seen(&relevant).await?;
+
+    // Embeds of new badges for each osu! user that received some
+    let mut new_badges: HashMap<u32, Vec<Embed>, IntHasher> = HashMap::default();
+
+    for osu_id in relevant {
+        let badge_idxs = current.get(&osu_id).map_or(&[][..], Vec::as_slice);
+
+        let mut badge_ids: Vec<_> = badge_idxs
+            .iter()
+            .map(|&i| badges[i].badge_id.to_native())
+            .collect();
+
+        badge_ids.sort_unstable();
+
+        // Users that were not seen before are only stored, not announced
+        let Some(seen_ids) = seen.get(&osu_id) else {
+            manager.set_seen(osu_id, &badge_ids).await?;
+
+            continue;
+        };
+
+        if *seen_ids == badge_ids {
+            continue;
+        }
+
+        let username = match Context::osu_user().name(osu_id).await {
+            Ok(Some(name)) => name.to_string(),
+            Ok(None) => format!("User id {osu_id}"),
+            Err(err) => {
+                warn!(?err, "Failed to get username");
+
+                format!("User id {osu_id}")
+            }
+        };
+
+        let embeds: Vec<_> = badge_idxs
+            .iter()
+            .map(|&i| &badges[i])
+            .filter(|badge| !seen_ids.contains(&badge.badge_id.to_native()))
+            .map(|badge| {
+                let description = format!(
+                    "[{username}]({OSU_BASE}u/{osu_id}) received a new badge: **{}**",
+                    badge.description.as_ref()
+                );
+
+                EmbedBuilder::new()
+                    .description(description)
+                    .thumbnail(badge.image_url.as_ref())
+                    .build()
+            })
+            .collect();
+
+        manager.set_seen(osu_id, &badge_ids).await?;
+
+        if !embeds.is_empty() {
+            new_badges.insert(osu_id, embeds);
+        }
+    }
+
+    if new_badges.is_empty() {
+        return Ok(());
+    }
+
+    for (channel_id, osu_ids) in channel_targets {
+        for osu_id in osu_ids {
+            if let Some(embeds) = new_badges.get(&osu_id) {
+                send_embeds(channel_id, embeds).await;
+            }
+        }
+    }
+
+    for (user_id, osu_id) in user_targets {
+        let Some(embeds) = new_badges.get(&osu_id) else {
+            continue;
+        };
+
+        match Context::http().create_private_channel(user_id).await {
+            Ok(res) => match res.model().await {
+                Ok(channel) => send_embeds(channel.id, embeds).await,
+                Err(err) => warn!(?err, "Failed to deserialize DM channel"),
+            },
+            Err(err) => warn!(user = user_id.get(), ?err, "Failed to create DM channel"),
+        }
+    }
+
+    Ok(())
+}
+
+async fn send_embeds(channel_id: Id<ChannelMarker>, embeds: &[Embed]) {
+    // Discord allows at most 10 embeds per message
+    for chunk in embeds.chunks(10) {
+        if let Err(err) = Context::http()
+            .create_message(channel_id)
+            .embeds(chunk)
+            .await
+        {
+            let err = Report::new(err).wrap_err("Failed to send badge notification");
+            warn!(channel = channel_id.get(), ?err);
+        }
+    }
+}
@@ -26,6 +26,7 @@ use crate::{
 #[usage("[badge name]")]
 #[examples("osu! world cup 2024")]
 #[aliases("badge", "badgequery", "badgesquery", "bq")]
+#[flags(BANCHO_ONLY)]
 #[group(AllModes)]
 async fn prefix_badges(msg: &Message, args: Args<'_>, perms: Option<Permissions>) -> Result<()> {
     let orig = CommandOrigin::from_msg(msg, perms);
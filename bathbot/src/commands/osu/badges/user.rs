@@ -14,7 +14,7 @@ use rkyv::{
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
 use twilight_model::guild::Permissions;
 
-use super::BadgesUser;
+use super::{BadgesUser, badge_score};
 use crate::{
     active::{ActiveMessages, impls::BadgesPagination},
     commands::osu::{badges::BADGE_USER_DESC, require_link, user_not_found},
@@ -23,7 +23,7 @@ use crate::{
         commands::{CommandOrigin, prefix::Args},
     },
     manager::redis::osu::{UserArgs, UserArgsError},
-    util::osu::get_combined_thumbnail,
+    util::{PartialResult, osu::get_combined_thumbnail},
 };
 
 impl<'m> BadgesUser<'m> {
@@ -43,6 +43,8 @@ impl<'m> BadgesUser<'m> {
             name,
             discord,
             sort: None,
+            year: None,
+            name_filter: None,
         }
     }
 }
@@ -52,6 +54,7 @@ impl<'m> BadgesUser<'m> {
 #[usage("[username]")]
 #[examples("bubbleman")]
 #[aliases("userbadges", "userbadge", "badgeuser")]
+#[flags(BANCHO_ONLY)]
 #[group(AllModes)]
 async fn prefix_badgesuser(
     msg: &Message,
@@ -84,6 +87,7 @@ pub(super) async fn user(orig: CommandOrigin<'_>, args: BadgesUser<'_>) -> Resul
     let badges_fut = Context::redis().badges();
 
     let (user_args, badges_res) = tokio::join!(user_args_fut, badges_fut);
+    let mut partial = PartialResult::new();
 
     let (user_id_raw, user_id) = match user_args {
         UserArgs::Args(args) => (args.user_id, user_id),
@@ -96,12 +100,20 @@ pub(super) async fn user(orig: CommandOrigin<'_>, args: BadgesUser<'_>) -> Resul
 
             return orig.error(content).await;
         }
-        UserArgs::Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
-            let err = Report::new(err).wrap_err("Failed to get user");
+        // Badges only require the user id so we can do without the user
+        UserArgs::Err(err) => match user_id {
+            UserId::Id(id) => {
+                partial.fail("the osu! user", err);
 
-            return Err(err);
-        }
+                (id, user_id)
+            }
+            UserId::Name(_) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+                let err = Report::new(err).wrap_err("Failed to get user");
+
+                return Err(err);
+            }
+        },
     };
 
     let badges = match badges_res {
@@ -119,20 +131,33 @@ pub(super) async fn user(orig: CommandOrigin<'_>, args: BadgesUser<'_>) -> Resul
         .map(|badge| rkyv::api::deserialize_using::<_, _, Panic>(badge, &mut ()).always_ok())
         .collect();
 
+    // The score considers all badges of the user, regardless of filters
+    let score = badge_score(&badges);
+    let total = badges.len();
+
+    if let Some(year) = args.year {
+        badges.retain(|badge| badge.awarded_at.year() == year);
+    }
+
+    if let Some(ref filter) = args.name_filter {
+        let filter = filter.to_lowercase();
+
+        badges.retain(|badge| {
+            badge.name.to_lowercase().contains(&filter)
+                || badge.description.to_lowercase().contains(&filter)
+        });
+    }
+
     args.sort.unwrap_or_default().apply(&mut badges);
 
     let owners = if let Some(badge) = badges.first() {
-        let owners_fut = Context::client().get_osekai_badge_owners(badge.badge_id);
-
-        match owners_fut.await {
-            Ok(owners) => owners,
-            Err(err) => {
-                let _ = orig.error(OSEKAI_ISSUE).await;
-                let wrap = format!("Failed to get badge owners for badge id {}", badge.badge_id);
+        let owners_res = Context::client()
+            .get_osekai_badge_owners(badge.badge_id)
+            .await;
 
-                return Err(err.wrap_err(wrap));
-            }
-        }
+        partial
+            .take("the badge owners", owners_res)
+            .unwrap_or_default()
     } else {
         let user_id = match user_id {
             UserId::Id(user_id) => match Context::osu_user().name(user_id).await {
@@ -147,9 +172,13 @@ pub(super) async fn user(orig: CommandOrigin<'_>, args: BadgesUser<'_>) -> Resul
             user_id @ UserId::Name(_) => user_id,
         };
 
-        let content = match user_id {
-            UserId::Id(user_id) => format!("User with id {user_id} has no badges :("),
-            UserId::Name(name) => format!("User `{name}` has no badges :("),
+        let content = match (user_id, total) {
+            (UserId::Id(user_id), 0) => format!("User with id {user_id} has no badges :("),
+            (UserId::Name(name), 0) => format!("User `{name}` has no badges :("),
+            (UserId::Id(user_id), _) => {
+                format!("User with id {user_id} has no badges matching the filters")
+            }
+            (UserId::Name(name), _) => format!("User `{name}` has no badges matching the filters"),
         };
 
         let builder = MessageBuilder::new().embed(content);
@@ -184,6 +213,8 @@ pub(super) async fn user(orig: CommandOrigin<'_>, args: BadgesUser<'_>) -> Resul
     let pagination = BadgesPagination::builder()
         .badges(badges.into_boxed_slice())
         .owners(owners_map)
+        .warning(partial.warning_field())
+        .score(Some(score))
         .msg_owner(owner)
         .build();
 
@@ -3,13 +3,13 @@ use std::fmt::{Display, Formatter, Result as FmtResult};
 use bathbot_macros::msg_command;
 use bathbot_psql::model::configs::ListSize;
 use bathbot_util::{Authored, MessageOrigin, constants::GENERAL_ISSUE, osu::MapIdType};
-use eyre::{Report, Result};
-use rosu_v2::prelude::OsuError;
+use eyre::Result;
 use twilight_model::channel::Message;
 
 use crate::{
     active::{ActiveMessages, impls::BookmarksPagination},
     core::Context,
+    manager::MapError,
     util::{InteractionCommandExt, interaction::InteractionCommand, osu::MapOrScore},
 };
 
@@ -65,9 +65,9 @@ async fn bookmark_map(mut command: InteractionCommand) -> Result<()> {
         }
     };
 
-    let mapset = match Context::osu().beatmapset_from_map_id(map_id).await {
+    let mapset = match Context::osu_map().request_mapset_from_map_id(map_id).await {
         Ok(mapset) => mapset,
-        Err(OsuError::NotFound) => {
+        Err(MapError::NotFound) => {
             let content = format!(
                 "I found the map id {map_id} in [this message]({url}) \
                 but I couldn't find a map with that id",
@@ -78,10 +78,10 @@ async fn bookmark_map(mut command: InteractionCommand) -> Result<()> {
 
             return Ok(());
         }
-        Err(err) => {
+        Err(MapError::Report(err)) => {
             let _ = command.error(GENERAL_ISSUE).await;
 
-            return Err(Report::new(err).wrap_err("Failed to get mapset"));
+            return Err(err.wrap_err("Failed to get mapset"));
         }
     };
 
@@ -37,6 +37,7 @@ use crate::{
     participate based on the rank restrictions.\n\
     There are various formulas around but this command uses `rank^(0.9937^(badges^2))`."
 )]
+#[flags(BANCHO_ONLY)]
 pub struct Bws<'a> {
     #[command(desc = "Specify a username")]
     name: Option<Cow<'a, str>>,
@@ -135,6 +136,7 @@ async fn slash_bws(mut command: InteractionCommand) -> Result<()> {
 )]
 #[usage("[username] [rank=integer] [badges=integer]")]
 #[examples("badewanne3", "badewanne3 rank=1234 badges=10", "badewanne3 badges=3")]
+#[flags(BANCHO_ONLY)]
 #[group(Osu)]
 async fn prefix_bws(msg: &Message, args: Args<'_>) -> Result<()> {
     match Bws::args(args) {
@@ -1,32 +1,47 @@
-use std::{borrow::Cow, collections::HashMap};
+use std::{borrow::Cow, collections::HashMap, path::Path};
 
-use bathbot_cards::{BathbotCard, RequiredAttributes};
-use bathbot_macros::{HasName, SlashCommand, command};
+use bathbot_cards::{
+    BackgroundPreset, BathbotCard, CardBackground as BackgroundImage, CardFrame, ReadyToDraw,
+    RequiredAttributes, UserNext,
+};
+use bathbot_macros::{HasMods, HasName, SlashCommand, command};
 use bathbot_model::command_fields::GameModeOption;
-use bathbot_psql::model::configs::ScoreData;
+use bathbot_psql::model::configs::{CardBackground, ScoreData};
 use bathbot_util::{
     EmbedBuilder, IntHasher, MessageBuilder, attachment,
-    constants::{GENERAL_ISSUE, OSEKAI_ISSUE},
+    constants::GENERAL_ISSUE,
     datetime::DATE_FORMAT,
     matcher,
-    osu::flag_url_size,
+    osu::{ModSelection, flag_url_size},
 };
+use bytes::Bytes;
 use eyre::{Report, Result, WrapErr};
 use futures::{TryStreamExt, stream::FuturesUnordered};
-use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
-use time::OffsetDateTime;
+use image::{
+    Delay, Frame, RgbaImage,
+    codecs::gif::{GifEncoder, Repeat},
+};
+use rosu_v2::{
+    model::GameMode,
+    prelude::{OsuError, Score, Username},
+    request::UserId,
+};
+use time::{Date, Month, OffsetDateTime};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::id::{Id, marker::UserMarker};
 
-use super::{require_link, user_not_found};
+use super::{HasMods, ModsResult, require_link, user_not_found};
 use crate::{
     commands::{DISCORD_OPTION_DESC, DISCORD_OPTION_HELP},
     core::{
         BotConfig, Context,
         commands::{CommandOrigin, prefix::Args},
     },
-    manager::redis::osu::{UserArgs, UserArgsError},
-    util::{CachedUserExt, InteractionCommandExt, interaction::InteractionCommand},
+    manager::redis::osu::{CachedUser, UserArgs, UserArgsError},
+    util::{
+        CachedUserExt, InteractionCommandExt, PartialResult, ProgressReporter,
+        interaction::InteractionCommand,
+    },
 };
 
 const CARD_HELP: &str = "Create a visual user card containing various fun values about the user.\n\
@@ -35,6 +50,16 @@ Only the accuracy values for [catch](https://www.desmos.com/calculator/cg59pywpr
 and [mania](https://www.desmos.com/calculator/b30p1awwft) come from custom formulas \
 that are based on score accuracy, map OD, object count, and star rating.\n\
 Note that only the user's top100 is considered while calculating card values.\n\
+When specifying a second user to compare with, a versus card is drawn instead \
+that shows both users' skills side by side and highlights who wins each skill.\n\
+The background and accent color of cards can be adjusted with the `background` \
+and `accent_color` options, or set as default in `/config`.\n\
+With the `year` option, only top scores that were set until the end of that year \
+are considered, resulting in a historical card. \
+Note that rank, level, and medals are still the user's current values.\n\
+Similarly, the `mods` option only considers top scores that match the given mods, \
+e.g. `+dt` for a DT card or `+nm!` for a nomod card.\n\
+With `animated` enabled, the card will be a GIF in which the values count up.\n\
 Titles consist of three parts: **prefix**, **descriptions**, and **suffix**.\n\n\
 - The **prefix** is determined by checking the highest skill value \
 for thresholds:\n\
@@ -76,7 +101,7 @@ values to each other:\n  \
 - High accuracy but low strain: `Rhythm Enjoyer`\n    \
 - High strain but low accuracy: `Masher` / `Droplet Dodger`";
 
-#[derive(CommandModel, CreateCommand, SlashCommand, HasName)]
+#[derive(CommandModel, CreateCommand, SlashCommand, HasMods, HasName)]
 #[command(name = "card", desc = "Create a user card", help = CARD_HELP)]
 pub struct Card<'a> {
     #[command(desc = "Specify a gamemode")]
@@ -85,18 +110,72 @@ pub struct Card<'a> {
     name: Option<Cow<'a, str>>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(
+        desc = "Specify a second username to compare with",
+        help = "Specify a second username to draw a versus card that compares \
+        the skills of both users"
+    )]
+    compare: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Choose the background of the card",
+        help = "Choose the background of the card.\n\
+        `Title` uses the background that belongs to the card's title, \
+        `Profile cover` uses the cover of the user's osu! profile, \
+        and any other choice always uses the background of that title.\n\
+        The default can be set with the `/config` command."
+    )]
+    background: Option<CardBackground>,
+    #[command(
+        desc = "Specify an accent color as hex code, e.g. #ff66aa",
+        help = "Specify an accent color as hex code, e.g. `#ff66aa`.\n\
+        The header and footer of the card will be tinted in that color instead of black.\n\
+        The default can be set with the `/config` command."
+    )]
+    accent_color: Option<Cow<'a, str>>,
+    #[command(
+        min_value = 2007,
+        desc = "Only consider top scores set until the end of this year",
+        help = "Only consider top scores that were set until the end of this year.\n\
+        Skill values and title will be recalculated based on those scores \
+        so you can see what your card would have looked like back then."
+    )]
+    year: Option<i32>,
+    #[command(
+        desc = "Specify mods (`+mods` for included, `+mods!` for exact, `-mods!` for excluded)",
+        help = "Only consider top scores that match the specified mods.\n\
+        Mods must be given as `+mods` for included mods, `+mods!` for exact mods, \
+        or `-mods!` for excluded mods.\n\
+        Examples:\n\
+        - `+dt`: Scores must have at least `DT` but can also have more other mods\n\
+        - `+hdhr!`: Scores must have exactly `HDHR`\n\
+        - `-ezhd!`: Scores must have neither `EZ` nor `HD` e.g. `HDDT` would get filtered out\n\
+        - `+nm!`: Scores must be nomod"
+    )]
+    mods: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Animate the card's values filling up",
+        help = "Draw the card as GIF in which the skill values count up and the bars fill up.\n\
+        Not available when comparing with a second user."
+    )]
+    animated: Option<bool>,
 }
 
 impl<'m> Card<'m> {
     fn args(mode: Option<GameModeOption>, args: Args<'m>) -> Self {
         let mut name = None;
         let mut discord = None;
+        let mut compare = None;
+        let mut mods = None;
 
         for arg in args {
             if let Some(id) = matcher::get_mention_user(arg) {
                 discord = Some(id);
-            } else {
+            } else if matcher::get_mods(arg).is_some() {
+                mods = Some(arg.into());
+            } else if name.is_none() {
                 name = Some(arg.into());
+            } else {
+                compare = Some(arg.into());
             }
         }
 
@@ -104,6 +183,12 @@ impl<'m> Card<'m> {
             mode,
             name,
             discord,
+            compare,
+            background: None,
+            accent_color: None,
+            year: None,
+            mods,
+            animated: None,
         }
     }
 }
@@ -111,8 +196,8 @@ impl<'m> Card<'m> {
 #[command]
 #[desc("Create a user card")]
 #[help(CARD_HELP)]
-#[usage("[username]")]
-#[examples("peppy")]
+#[usage("[username] [username to compare] [+mods]")]
+#[examples("peppy", "peppy mrekk", "peppy +dt")]
 #[group(Osu)]
 async fn prefix_card(msg: &Message, args: Args<'_>) -> Result<()> {
     let args = Card::args(None, args);
@@ -123,8 +208,8 @@ async fn prefix_card(msg: &Message, args: Args<'_>) -> Result<()> {
 #[command]
 #[desc("Create a taiko user card")]
 #[help(CARD_HELP)]
-#[usage("[username]")]
-#[examples("peppy")]
+#[usage("[username] [username to compare] [+mods]")]
+#[examples("peppy", "peppy mrekk", "peppy +dt")]
 #[aliases("cardt")]
 #[group(Taiko)]
 async fn prefix_cardtaiko(msg: &Message, args: Args<'_>) -> Result<()> {
@@ -136,8 +221,8 @@ async fn prefix_cardtaiko(msg: &Message, args: Args<'_>) -> Result<()> {
 #[command]
 #[desc("Create a ctb user card")]
 #[help(CARD_HELP)]
-#[usage("[username]")]
-#[examples("peppy")]
+#[usage("[username] [username to compare] [+mods]")]
+#[examples("peppy", "peppy mrekk", "peppy +dt")]
 #[aliases("cardcatch", "cardc")]
 #[group(Catch)]
 async fn prefix_cardctb(msg: &Message, args: Args<'_>) -> Result<()> {
@@ -149,8 +234,8 @@ async fn prefix_cardctb(msg: &Message, args: Args<'_>) -> Result<()> {
 #[command]
 #[desc("Create a mania user card")]
 #[help(CARD_HELP)]
-#[usage("[username]")]
-#[examples("peppy")]
+#[usage("[username] [username to compare] [+mods]")]
+#[examples("peppy", "peppy mrekk", "peppy +dt")]
 #[aliases("cardm")]
 #[group(Mania)]
 async fn prefix_cardmania(msg: &Message, args: Args<'_>) -> Result<()> {
@@ -194,125 +279,513 @@ async fn card(orig: CommandOrigin<'_>, args: Card<'_>) -> Result<()> {
         },
     };
 
-    let user_args = UserArgs::rosu_id(&user_id, mode).await;
-    let scores_fut = Context::osu_scores()
-        // changing the limit value requires adjusting card title thresholds
-        .top(100, legacy_scores)
-        .exec_with_user(user_args);
-    let medals_fut = Context::redis().medals();
+    let background = args
+        .background
+        .or(config.card_background)
+        .unwrap_or_default();
+
+    let accent_color = match args.accent_color.as_deref() {
+        Some(color) => match matcher::get_hex_color(color) {
+            Some(color) => Some(color),
+            None => {
+                let content = format!(
+                    "Failed to parse `{color}` as color.\n\
+                    Be sure to specify it as hex code, e.g. `#ff66aa`."
+                );
+
+                return orig.error_with_help(content).await;
+            }
+        },
+        None => config.card_accent_color,
+    };
 
-    let (user, scores, total_medals) = match tokio::join!(scores_fut, medals_fut) {
-        (Ok((user, scores)), Ok(medals)) => (user, scores, medals.len()),
-        (Err(UserArgsError::Osu(OsuError::NotFound)), _) => {
-            let content = user_not_found(user_id).await;
+    let before = match args.year {
+        Some(year) if year > OffsetDateTime::now_utc().year() => {
+            let content = format!("The year {year} did not happen yet");
 
-            return orig.error(content).await;
+            return orig.error_with_help(content).await;
         }
-        (Err(err), _) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
-            let err = Report::new(err).wrap_err("Failed to get user");
+        Some(year) => match Date::from_calendar_date(year + 1, Month::January, 1) {
+            Ok(date) => Some(date.midnight().assume_utc()),
+            Err(_) => return orig.error_with_help("Invalid year").await,
+        },
+        None => None,
+    };
 
-            return Err(err);
-        }
-        (_, Err(err)) => {
-            let _ = orig.error(OSEKAI_ISSUE).await;
+    let mods = match args.mods() {
+        ModsResult::Mods(mods) => Some(mods),
+        ModsResult::None => None,
+        ModsResult::Invalid => {
+            let content = "Failed to parse mods.\n\
+                If you want included mods, specify it e.g. as `+hrdt`.\n\
+                If you want exact mods, specify it e.g. as `+hdhr!`.\n\
+                And if you want to exclude mods, specify it e.g. as `-hdnf!`.";
 
-            return Err(Report::new(err).wrap_err("Failed to get cached medals"));
+            return orig.error_with_help(content).await;
         }
     };
 
-    if scores.is_empty() {
-        let content = "Looks like they don't have any scores on that mode";
-        orig.error(content).await?;
+    let filter = CardFilter {
+        year: args.year,
+        before,
+        mods,
+    };
+
+    let animated = args.animated.unwrap_or(false);
 
-        return Ok(());
+    if animated && args.compare.is_some() {
+        let content = "Versus cards can't be animated, try again without `animated`";
+
+        return orig.error_with_help(content).await;
     }
 
-    let maps: HashMap<_, _, IntHasher> = scores
-        .iter()
-        .map(|score| async {
-            let map = Context::osu_map()
-                .pp_map(score.map_id)
-                .await
-                .wrap_err("Failed to get pp map")?;
+    let compare_id = args
+        .compare
+        .as_deref()
+        .map(|name| UserId::Name(name.into()));
+
+    let date = match args.year {
+        Some(year) => format!("Historical • {year}"),
+        None => OffsetDateTime::now_utc()
+            .date()
+            .format(DATE_FORMAT)
+            .unwrap(),
+    };
 
-            let difficulty = Context::pp_parsed(&map, mode)
-                .lazer(score.set_on_lazer)
-                .mods(score.mods.clone())
-                .difficulty()
-                .await
-                .expect("suspicious maps in top scores are a false positive")
-                .to_owned();
+    let label = filter.label();
 
-            let attrs = RequiredAttributes {
-                difficulty,
-                od: map.od,
-            };
+    // Three steps per user plus the drawing
+    let steps = if compare_id.is_some() { 7 } else { 4 };
+    let mut progress = ProgressReporter::start(&orig, steps).await;
 
-            Ok::<_, Report>((score.map_id, attrs))
-        })
-        .collect::<FuturesUnordered<_>>()
-        .try_collect()
-        .await?;
+    let cover = background == CardBackground::Cover;
+    let data_fut = CardData::fetch(&mut progress, &user_id, mode, legacy_scores, cover, &filter);
+    let medals_fut = Context::redis().medals();
 
-    let client = Context::client();
-    let pfp_fut = client.get_avatar(user.avatar_url.as_ref());
-    let flag_url = flag_url_size(user.country_code.as_str(), 70);
-    let flag_fut = client.get_flag(&flag_url);
+    let (data_res, medals_res) = tokio::join!(data_fut, medals_fut);
 
-    let (pfp, flag) = match tokio::join!(pfp_fut, flag_fut) {
-        (Ok(pfp), Ok(flag)) => (pfp, flag),
-        (Err(err), _) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+    let mut data = match data_res {
+        Ok(data) => data,
+        Err(err) => return err.handle(progress, user_id, &filter).await,
+    };
 
-            return Err(err.wrap_err("Failed to acquire card avatar"));
+    let mut compare_data = match compare_id {
+        Some(compare_id) => {
+            let fetch_fut = CardData::fetch(
+                &mut progress,
+                &compare_id,
+                mode,
+                legacy_scores,
+                cover,
+                &filter,
+            );
+
+            match fetch_fut.await {
+                Ok(data) => Some(data),
+                Err(err) => return err.handle(progress, compare_id, &filter).await,
+            }
         }
-        (_, Err(err)) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
+        None => None,
+    };
+
+    let mut partial = PartialResult::new();
+    let total_medals = partial
+        .take("the total medal count", medals_res)
+        .map(|medals| medals.len());
 
-            return Err(err.wrap_err("Failed to acquire card flag"));
+    let filename = if animated { "card.gif" } else { "card.png" };
+
+    let mut embed = EmbedBuilder::new()
+        .author(data.user.author_builder(false))
+        .image(attachment(filename));
+
+    if let Some(ref compare_data) = compare_data {
+        let title = format!(
+            "{} vs {}",
+            data.user.username.as_str(),
+            compare_data.user.username.as_str()
+        );
+
+        embed = embed.title(title);
+    }
+
+    let _permit = match Context::render_queue().acquire(&orig).await {
+        Ok(permit) => permit,
+        Err(err) => {
+            let _ = progress.error(GENERAL_ISSUE).await;
+
+            return Err(err);
         }
     };
 
-    let stats = user.statistics.as_ref().expect("missing stats");
+    progress.advance("drawing the card").await;
+
+    let assets = &BotConfig::get().paths.assets;
+    let style = CardStyle {
+        background,
+        accent_color,
+        label: label.as_deref(),
+    };
 
-    let medals = user.medals.len();
+    let card = data.prepare(total_medals, &date, assets, style);
 
-    let today = OffsetDateTime::now_utc()
-        .date()
-        .format(DATE_FORMAT)
-        .unwrap();
+    let card_res = match compare_data {
+        Some(ref mut compare_data) => {
+            let other = compare_data.prepare(total_medals, &date, assets, style);
 
-    let card_res = BathbotCard::new(mode, &scores, maps, legacy_scores)
-        .user(user.username.as_str(), stats.level.float())
-        .ranks(
-            stats.global_rank.to_native(),
-            stats.country_rank.to_native(),
-        )
-        .medals(medals as u32, total_medals as u32)
-        .bytes(&pfp, &flag)
-        .date(&today)
-        .assets(BotConfig::get().paths.assets.clone())
-        .draw();
+            card.draw_versus(other).map_err(Report::new)
+        }
+        None if animated => match card.draw_frames(ANIMATION_FRAMES) {
+            Ok(frames) => encode_gif(frames).await,
+            Err(err) => Err(Report::new(err)),
+        },
+        None => card.draw().map_err(Report::new),
+    };
 
     let bytes = match card_res {
         Ok(bytes) => bytes,
         Err(err) => {
-            let _ = orig.error("Failed to draw the card :(").await;
+            let _ = progress.error("Failed to draw the card :(").await;
 
-            return Err(Report::new(err).wrap_err("Failed to draw card"));
+            return Err(err.wrap_err("Failed to draw card"));
         }
     };
 
-    let embed = EmbedBuilder::new()
-        .author(user.author_builder(false))
-        .image(attachment("card.png"));
+    if let Some(field) = partial.warning_field() {
+        embed = embed.fields(vec![field]);
+    }
 
     let builder = MessageBuilder::new()
-        .attachment("card.png", bytes)
+        .attachment(filename, bytes)
         .embed(embed);
 
-    orig.create_message(builder).await?;
+    progress.finish(builder).await
+}
+
+const ANIMATION_FRAMES: usize = 12;
+const FRAME_DELAY_MS: u32 = 80;
+/// How long the complete card is shown before the animation restarts
+const LAST_FRAME_DELAY_MS: u32 = 4000;
+
+async fn encode_gif(frames: Vec<CardFrame>) -> Result<Vec<u8>> {
+    let encode = move || {
+        let mut bytes = Vec::new();
+        let mut encoder = GifEncoder::new_with_speed(&mut bytes, 30);
+        encoder
+            .set_repeat(Repeat::Infinite)
+            .wrap_err("Failed to set gif repeat")?;
+
+        let last = frames.len().saturating_sub(1);
+
+        for (i, frame) in frames.into_iter().enumerate() {
+            let CardFrame {
+                width,
+                height,
+                rgba,
+            } = frame;
+
+            let img = RgbaImage::from_raw(width, height, rgba).wrap_err("Invalid frame size")?;
+            let delay_ms = if i == last {
+                LAST_FRAME_DELAY_MS
+            } else {
+                FRAME_DELAY_MS
+            };
+            let delay = Delay::from_numer_denom_ms(delay_ms, 1);
+
+            encoder
+                .encode_frame(Frame::from_parts(img, 0, 0, delay))
+                .wrap_err("Failed to encode frame")?;
+        }
+
+        drop(encoder);
+
+        Ok(bytes)
+    };
+
+    tokio::task::spawn_blocking(encode)
+        .await
+        .wrap_err("Failed to join gif encoding")?
+}
+
+/// Everything that is required to draw a user's card.
+struct CardData<'a> {
+    user: CachedUser,
+    card: BathbotCard<'a, UserNext>,
+    pfp: Bytes,
+    flag: Bytes,
+    cover: Option<Bytes>,
+}
+
+/// How the command's author wants cards to look.
+#[derive(Copy, Clone)]
+struct CardStyle<'l> {
+    background: CardBackground,
+    /// RGB color encoded as `0xRRGGBB`
+    accent_color: Option<u32>,
+    label: Option<&'l str>,
+}
+
+/// Restricts which top scores are considered for a card.
+struct CardFilter {
+    year: Option<i32>,
+    /// Only scores that were set before this are considered
+    before: Option<OffsetDateTime>,
+    mods: Option<ModSelection>,
+}
+
+impl CardFilter {
+    fn is_empty(&self) -> bool {
+        self.before.is_none() && self.mods.is_none()
+    }
+
+    fn filter_scores(&self, scores: &mut Vec<Score>) {
+        if let Some(before) = self.before {
+            scores.retain(|score| score.ended_at < before);
+        }
+
+        if let Some(ref selection) = self.mods {
+            selection.filter_scores(scores);
+        }
+    }
+
+    /// Text on the card that describes the mod selection.
+    fn label(&self) -> Option<String> {
+        let label = match self.mods.as_ref()? {
+            ModSelection::Include(mods) | ModSelection::Exact(mods) if mods.is_empty() => {
+                "Mods: NM".to_owned()
+            }
+            ModSelection::Include(mods) => format!("Mods: +{mods}"),
+            ModSelection::Exclude { mods, nomod: true } if mods.is_empty() => {
+                "Mods: -NM".to_owned()
+            }
+            ModSelection::Exclude { mods, nomod: false } => format!("Mods: -{mods}"),
+            ModSelection::Exclude { mods, nomod: true } => format!("Mods: -NM (without {mods})"),
+            ModSelection::Exact(mods) => format!("Mods: {mods}"),
+        };
+
+        Some(label)
+    }
+}
+
+impl<'a> CardData<'a> {
+    async fn fetch(
+        progress: &mut ProgressReporter<'_, '_>,
+        user_id: &UserId,
+        mode: GameMode,
+        legacy_scores: bool,
+        cover: bool,
+        filter: &CardFilter,
+    ) -> Result<Self, CardDataError> {
+        progress.advance("fetching user and top scores").await;
+
+        let user_args = UserArgs::rosu_id(user_id, mode).await;
+
+        let scores_res = Context::osu_scores()
+            // changing the limit value requires adjusting card title thresholds
+            .top(100, legacy_scores)
+            .exec_with_user(user_args)
+            .await;
+
+        let (user, mut scores) = match scores_res {
+            Ok(tuple) => tuple,
+            Err(UserArgsError::Osu(OsuError::NotFound)) => return Err(CardDataError::NotFound),
+            Err(err) => {
+                let err = Report::new(err).wrap_err("Failed to get user");
+
+                return Err(CardDataError::Other(err));
+            }
+        };
+
+        filter.filter_scores(&mut scores);
+
+        if scores.is_empty() {
+            let name = user.username.as_str().into();
+
+            return Err(CardDataError::NoScores(name));
+        }
+
+        progress.advance("calculating difficulty attributes").await;
+
+        let maps = scores
+            .iter()
+            .map(|score| async {
+                let map = Context::osu_map()
+                    .pp_map(score.map_id)
+                    .await
+                    .wrap_err("Failed to get pp map")?;
+
+                let difficulty = Context::pp_parsed(&map, mode)
+                    .lazer(score.set_on_lazer)
+                    .mods(score.mods.clone())
+                    .difficulty()
+                    .await
+                    .expect("suspicious maps in top scores are a false positive")
+                    .to_owned();
+
+                let attrs = RequiredAttributes {
+                    difficulty,
+                    od: map.od,
+                };
+
+                Ok::<_, Report>((score.map_id, attrs))
+            })
+            .collect::<FuturesUnordered<_>>()
+            .try_collect::<HashMap<_, _, IntHasher>>()
+            .await
+            .map_err(CardDataError::Other)?;
+
+        progress.advance("fetching avatar and flag").await;
+
+        let client = Context::client();
+        let pfp_fut = client.get_avatar(user.avatar_url.as_ref());
+        let flag_url = flag_url_size(user.country_code.as_str(), 70);
+        let flag_fut = client.get_flag(&flag_url);
+
+        let cover_fut = async {
+            if !cover {
+                return None;
+            }
+
+            match client.get_user_cover(user.cover_url.as_ref()).await {
+                Ok(cover) => Some(cover),
+                Err(err) => {
+                    // The card can still be drawn with its title background
+                    warn!(?err, "Failed to acquire card cover");
 
-    Ok(())
+                    None
+                }
+            }
+        };
+
+        let (pfp, flag, cover) = match tokio::join!(pfp_fut, flag_fut, cover_fut) {
+            (Ok(pfp), Ok(flag), cover) => (pfp, flag, cover),
+            (Err(err), ..) => {
+                return Err(CardDataError::Other(
+                    err.wrap_err("Failed to acquire card avatar"),
+                ));
+            }
+            (_, Err(err), _) => {
+                return Err(CardDataError::Other(
+                    err.wrap_err("Failed to acquire card flag"),
+                ));
+            }
+        };
+
+        Ok(Self {
+            card: BathbotCard::new(mode, &scores, maps, legacy_scores),
+            user,
+            pfp,
+            flag,
+            cover,
+        })
+    }
+
+    fn prepare(
+        &'a mut self,
+        total_medals: Option<usize>,
+        today: &'a str,
+        assets: &Path,
+        style: CardStyle<'a>,
+    ) -> &'a BathbotCard<'a, ReadyToDraw> {
+        let Self {
+            user,
+            card,
+            pfp,
+            flag,
+            cover,
+        } = self;
+
+        let stats = user.statistics.as_ref().expect("missing stats");
+        let medals = user.medals.len();
+
+        let card = card
+            .user(user.username.as_str(), stats.level.float())
+            .ranks(
+                stats.global_rank.to_native(),
+                stats.country_rank.to_native(),
+            )
+            // Without the total, the user's own count is the best we can show
+            .medals(medals as u32, total_medals.unwrap_or(medals).max(1) as u32)
+            .bytes(pfp, flag)
+            .date(today)
+            .assets(assets.to_owned())
+            .background(background_image(style.background, cover.as_deref()));
+
+        if let Some(color) = style.accent_color {
+            card.accent_color(((color >> 16) as u8, (color >> 8) as u8, color as u8));
+        }
+
+        if let Some(label) = style.label {
+            card.label(label);
+        }
+
+        card
+    }
+}
+
+fn background_image(background: CardBackground, cover: Option<&[u8]>) -> BackgroundImage<'_> {
+    let preset = match background {
+        CardBackground::Title => return BackgroundImage::Title,
+        // Without cover, e.g. if it failed to download, fall back to the title
+        CardBackground::Cover => {
+            return cover.map_or(BackgroundImage::Title, BackgroundImage::Image);
+        }
+        CardBackground::Newbie => BackgroundPreset::Newbie,
+        CardBackground::Novice => BackgroundPreset::Novice,
+        CardBackground::Rookie => BackgroundPreset::Rookie,
+        CardBackground::Apprentice => BackgroundPreset::Apprentice,
+        CardBackground::Advanced => BackgroundPreset::Advanced,
+        CardBackground::Outstanding => BackgroundPreset::Outstanding,
+        CardBackground::Seasoned => BackgroundPreset::Seasoned,
+        CardBackground::Professional => BackgroundPreset::Professional,
+        CardBackground::Expert => BackgroundPreset::Expert,
+        CardBackground::Master => BackgroundPreset::Master,
+        CardBackground::Legendary => BackgroundPreset::Legendary,
+        CardBackground::God => BackgroundPreset::God,
+    };
+
+    BackgroundImage::Preset(preset)
+}
+
+enum CardDataError {
+    NotFound,
+    NoScores(Username),
+    Other(Report),
+}
+
+impl CardDataError {
+    async fn handle(
+        self,
+        progress: ProgressReporter<'_, '_>,
+        user_id: UserId,
+        filter: &CardFilter,
+    ) -> Result<()> {
+        match self {
+            Self::NotFound => {
+                let content = user_not_found(user_id).await;
+
+                progress.error(content).await
+            }
+            Self::NoScores(name) => {
+                let content = match filter.year {
+                    Some(year) if filter.mods.is_none() => format!(
+                        "Looks like `{name}` doesn't have any top scores \
+                        on that mode that were set until {year}"
+                    ),
+                    _ if !filter.is_empty() => format!(
+                        "Looks like `{name}` doesn't have any top scores \
+                        on that mode that match the filters"
+                    ),
+                    _ => format!("Looks like `{name}` doesn't have any scores on that mode"),
+                };
+
+                progress.error(content).await
+            }
+            Self::Other(err) => {
+                let _ = progress.error(GENERAL_ISSUE).await;
+
+                Err(err)
+            }
+        }
+    }
 }
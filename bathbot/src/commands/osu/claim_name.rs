@@ -42,6 +42,7 @@ This is to prevent people from stealing the usernames of active or recently reti
 
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(name = "claimname", desc = CLAIMNAME_DESC, help = CLAIMNAME_HELP)]
+#[flags(BANCHO_ONLY)]
 pub struct ClaimName<'a> {
     #[command(desc = "Specify a username")]
     name: Cow<'a, str>,
@@ -63,6 +64,7 @@ impl<'m> ClaimName<'m> {
 #[usage("[username]")]
 #[examples("peppy")]
 #[aliases("cn")]
+#[flags(BANCHO_ONLY)]
 #[group(AllModes)]
 async fn prefix_claimname(msg: &Message, args: Args<'_>) -> Result<()> {
     match ClaimName::args(args) {
@@ -110,6 +110,8 @@ pub struct CompareScore<'a> {
     discord: Option<Id<UserMarker>>,
     #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
     score_data: Option<ScoreData>,
+    #[command(desc = SCORE_RENDER_DESC, help = SCORE_RENDER_HELP)]
+    render: Option<ScoreRender>,
 }
 
 #[derive(CommandModel)]
@@ -125,6 +127,7 @@ pub struct CompareScoreAutocomplete<'a> {
     pub grade: Option<GradeOption>,
     pub discord: Option<Id<UserMarker>>,
     pub score_data: Option<ScoreData>,
+    pub render: Option<ScoreRender>,
 }
 
 #[derive(Copy, Clone, CommandOption, CreateOption)]
@@ -27,6 +27,7 @@ use crate::{
 #[usage("[name1] [name2]")]
 #[example("badewanne3 \"nathan on osu\"")]
 #[aliases("commonmostplayed", "mpc")]
+#[flags(BANCHO_ONLY)]
 #[group(AllModes)]
 async fn prefix_mostplayedcommon(msg: &Message, args: Args<'_>) -> Result<()> {
     let mut args_ = CompareMostPlayed::default();
@@ -68,6 +69,15 @@ pub(super) async fn mostplayed(
     orig: CommandOrigin<'_>,
     mut args: CompareMostPlayed<'_>,
 ) -> Result<()> {
+    // The prefix command is flagged but the slash command shares `/compare`
+    if let Some(server) = Context::osu_server() {
+        let content = format!(
+            "Most played maps are only available for bancho but this server targets `{server}`"
+        );
+
+        return orig.error(content).await;
+    }
+
     let owner = orig.user_id()?;
 
     let user_id1 = match extract_user_id(&mut args).await {
@@ -193,19 +203,22 @@ async fn get_user_and_scores(
 ) -> Result<(CachedUser, Vec<MostPlayedMap>), UserArgsError> {
     match UserArgs::rosu_id(user_id, GameMode::Osu).await {
         UserArgs::Args(args) => {
-            let score_fut = Context::osu().user_most_played(args.user_id).limit(100);
+            let score_fut =
+                Context::osu_request(|osu| osu.user_most_played(args.user_id).limit(100));
             let user_fut = Context::redis().osu_user_from_args(args);
 
             let (user_res, scores_res) = tokio::join!(user_fut, score_fut);
 
             Ok((user_res?, scores_res?))
         }
-        UserArgs::User { user, .. } => Context::osu()
-            .user_most_played(user.user_id.to_native())
-            .limit(100)
-            .await
-            .map(|scores| (user, scores))
-            .map_err(UserArgsError::Osu),
+        UserArgs::User { user, .. } => {
+            let user_id = user.user_id.to_native();
+
+            Context::osu_request(|osu| osu.user_most_played(user_id).limit(100))
+                .await
+                .map(|scores| (user, scores))
+                .map_err(UserArgsError::Osu)
+        }
         UserArgs::Err(err) => Err(err),
     }
 }
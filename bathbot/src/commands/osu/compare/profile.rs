@@ -7,7 +7,7 @@ use bathbot_util::{
     constants::GENERAL_ISSUE,
     matcher,
     numbers::MinMaxAvg,
-    osu::{BonusPP, UserStats},
+    osu::{BonusPP, UserStats, effective_clock_rate},
 };
 use eyre::{Report, Result, WrapErr};
 use image::{
@@ -412,11 +412,7 @@ impl CompareResult {
 
                 let map = score.map.as_ref().unwrap();
 
-                let seconds_drain = if let Some(clock_rate) = score.mods.clock_rate() {
-                    map.seconds_drain as f64 / clock_rate
-                } else {
-                    map.seconds_drain as f64
-                };
+                let seconds_drain = map.seconds_drain as f64 / effective_clock_rate(&score.mods);
 
                 map_len.add(seconds_drain as f32);
 
@@ -41,7 +41,10 @@ use crate::{
     },
     commands::{
         DISCORD_OPTION_DESC, DISCORD_OPTION_HELP,
-        osu::{HasMods, ModsResult, map_strains_graph, require_link, user_not_found},
+        osu::{
+            HasMods, ModsResult, SCORE_RENDER_DESC, SCORE_RENDER_HELP, ScoreRender,
+            map_strains_graph, require_link, send_score_card, user_not_found,
+        },
         utility::{SCORE_DATA_DESC, SCORE_DATA_HELP, ScoreEmbedData, ScoreEmbedDataPersonalBest},
     },
     core::commands::{
@@ -111,6 +114,8 @@ pub struct Cs<'a> {
     discord: Option<Id<UserMarker>>,
     #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
     score_data: Option<ScoreData>,
+    #[command(desc = SCORE_RENDER_DESC, help = SCORE_RENDER_HELP)]
+    render: Option<ScoreRender>,
 }
 
 #[derive(CreateCommand, SlashCommand)]
@@ -166,6 +171,8 @@ pub struct CompareScore_<'a> {
     discord: Option<Id<UserMarker>>,
     #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
     score_data: Option<ScoreData>,
+    #[command(desc = SCORE_RENDER_DESC, help = SCORE_RENDER_HELP)]
+    render: Option<ScoreRender>,
 }
 
 #[derive(HasMods, HasName)]
@@ -180,6 +187,7 @@ pub(super) struct CompareScoreArgs<'a> {
     index: Option<u32>,
     grade: Option<Grade>,
     score_data: Option<ScoreData>,
+    render: Option<ScoreRender>,
 }
 
 impl<'m> CompareScoreArgs<'m> {
@@ -221,6 +229,7 @@ impl<'m> CompareScoreArgs<'m> {
             },
             grade: None,
             score_data: None,
+            render: None,
         }
     }
 }
@@ -263,6 +272,7 @@ impl<'a> TryFrom<CompareScoreAutocomplete<'a>> for CompareScoreArgs<'a> {
             index: args.index,
             grade: args.grade.map(Grade::from),
             score_data: args.score_data,
+            render: args.render,
         })
     }
 }
@@ -420,11 +430,7 @@ pub async fn slash_compare(
 
     match CompareScoreArgs::try_from(args) {
         Ok(args) => score(command.into(), args).await,
-        Err(content) => {
-            command.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(command).error_with_help(content).await,
     }
 }
 
@@ -474,6 +480,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
         difficulty,
         mode,
         grade,
+        render,
         ..
     } = args;
 
@@ -488,7 +495,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
                 return orig.error(content).await;
             }
             Some(MapOrScore::Score { id, mode }) => {
-                return compare_from_score(orig, id, mode, settings, score_data).await;
+                return compare_from_score(orig, id, mode, settings, score_data, render).await;
             }
             None => {
                 let idx = match index {
@@ -593,7 +600,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
 
             return orig.error(content).await;
         }
-        (_, Err(OsuError::NotFound)) => {
+        (_, Err(UserArgsError::Osu(OsuError::NotFound))) => {
             let content = "Beatmap was not found. Maybe unranked?";
 
             return orig.error(content).await;
@@ -722,6 +729,13 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: CompareScoreArgs<'_>) -
         .map(|(i, _)| i)
         .unwrap_or(0);
 
+    if let Some(ScoreRender::Image) = render {
+        return match entries.first() {
+            Some(entry) => send_score_card(orig, user.username.as_str(), entry).await,
+            None => orig.error("No scores found").await,
+        };
+    }
+
     let graph = match entries.first() {
         Some(entry) if matches!(settings.image, SettingsImage::ImageWithStrains) => {
             prepare_graph(entry).await
@@ -881,13 +895,26 @@ async fn compare_from_score(
     mode: Option<GameMode>,
     settings: ScoreEmbedSettings,
     score_data: ScoreData,
+    render: Option<ScoreRender>,
 ) -> Result<()> {
-    let mut score_fut = Context::osu().score(score_id);
+    if let Some(server) = Context::osu_server() {
+        let content = format!(
+            "Score ids can only be looked up on bancho but this server targets `{server}`"
+        );
 
-    if let Some(mode) = mode {
-        score_fut = score_fut.mode(mode);
+        return orig.error(content).await;
     }
 
+    let score_fut = Context::osu_request(|osu| {
+        let mut score_fut = osu.score(score_id);
+
+        if let Some(mode) = mode {
+            score_fut = score_fut.mode(mode);
+        }
+
+        score_fut
+    });
+
     let mut score = match score_fut.await {
         Ok(score) => score,
         Err(OsuError::NotFound) => {
@@ -1058,6 +1085,10 @@ async fn compare_from_score(
         twitch: None,
     };
 
+    if let Some(ScoreRender::Image) = render {
+        return send_score_card(orig, user.username.as_str(), &entry).await;
+    }
+
     let graph = if matches!(settings.image, SettingsImage::ImageWithStrains) {
         prepare_graph(&entry).await
     } else {
@@ -1127,7 +1158,7 @@ async fn prepare_graph(entry: &ScoreEmbedData) -> Option<(String, Vec<u8>)> {
     let fut = map_strains_graph(
         &entry.map.pp_map,
         entry.score.mods.clone(),
-        entry.map.cover(),
+        Some(entry.map.cover()),
         SingleScorePagination::IMAGE_W,
         SingleScorePagination::IMAGE_H,
     );
@@ -0,0 +1,581 @@
+use std::{borrow::Cow, fmt::Write};
+
+use bathbot_macros::{HasName, SlashCommand};
+use bathbot_model::command_fields::{GameModeOption, GradeOption};
+use bathbot_util::{
+    CowUtils, EmbedBuilder, FooterBuilder, MessageBuilder,
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE, OSU_BASE},
+};
+use eyre::{Report, Result};
+use futures::{StreamExt, stream};
+use rosu_v2::{
+    model::GameMode,
+    prelude::{BeatmapsetSearchSort, Grade, OsuError, RankStatus},
+    request::UserId,
+};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use super::{require_link, user_not_found};
+use crate::{
+    Context,
+    commands::{DISCORD_OPTION_DESC, DISCORD_OPTION_HELP},
+    core::commands::CommandOrigin,
+    manager::redis::osu::{UserArgs, UserArgsError},
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+/// Max amount of maps whose scores are checked
+const MAX_MAPS: usize = 100;
+
+/// Max amount of search result pages to go through
+const MAX_SEARCH_PAGES: usize = 4;
+
+/// Amount of missing maps listed in the embed
+const LIST_LEN: usize = 15;
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "completion",
+    desc = "List ranked maps on which a user is missing a score or grade",
+    help = "List ranked maps on which a user is missing a score or grade.\n\
+    The `filter` option accepts whitespace separated criteria:\n\
+    - `year=2020`: only maps ranked in that year\n\
+    - `stars>=5`, `stars<6.5`, ...: only difficulties within that star range; \
+    `stars=5` is short for `stars>=5 stars<=6`\n\
+    Up to 100 difficulties that match the filter are checked, starting with the \
+    earliest ranked ones. The results can be exported as csv or as osu! collection."
+)]
+pub struct Completion<'a> {
+    #[command(desc = "Specify filter criteria e.g. `year=2020 stars>=5 stars<6`")]
+    filter: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify a grade the user should be missing",
+        help = "Specify a grade the user should be missing.\n\
+        If none is specified, maps without any score are listed."
+    )]
+    grade: Option<GradeOption>,
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Export the missing maps")]
+    export: Option<CompletionExport>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
+    discord: Option<Id<UserMarker>>,
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption)]
+pub enum CompletionExport {
+    #[option(name = "CSV", value = "csv")]
+    Csv,
+    #[option(name = "osu! collection", value = "collection")]
+    Collection,
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct CompletionFilter {
+    year: Option<i32>,
+    min_stars: Option<f32>,
+    max_stars: Option<f32>,
+}
+
+impl CompletionFilter {
+    fn parse(input: &str) -> Result<Self, String> {
+        let mut filter = Self::default();
+
+        for arg in input.split_whitespace() {
+            let arg = arg.to_ascii_lowercase();
+
+            if let Some(year) = arg.strip_prefix("year=") {
+                match year.parse() {
+                    Ok(year) => filter.year = Some(year),
+                    Err(_) => return Err(format!("Failed to parse year `{year}`")),
+                }
+
+                continue;
+            }
+
+            let Some(stars) = arg.strip_prefix("stars") else {
+                return Err(format!(
+                    "Unknown filter `{arg}`, expected `year=...` or `stars...`"
+                ));
+            };
+
+            let (op, value) = match stars.find(|c: char| c.is_ascii_digit() || c == '.') {
+                Some(idx) => stars.split_at(idx),
+                None => return Err(format!("Missing star rating in `{arg}`")),
+            };
+
+            let Ok(value) = value.parse::<f32>() else {
+                return Err(format!("Failed to parse star rating in `{arg}`"));
+            };
+
+            match op {
+                ">" | ">=" => filter.min_stars = Some(value),
+                "<" | "<=" => filter.max_stars = Some(value),
+                "=" => {
+                    filter.min_stars = Some(value);
+                    filter.max_stars = Some(value + 1.0);
+                }
+                _ => return Err(format!("Unknown comparison in `{arg}`")),
+            }
+        }
+
+        Ok(filter)
+    }
+
+    /// Query for the osu! search so that results already roughly match the
+    /// filter.
+    fn query(&self) -> String {
+        let mut query = String::new();
+
+        if let Some(year) = self.year {
+            let _ = write!(query, "ranked={year} ");
+        }
+
+        if let Some(min) = self.min_stars {
+            let _ = write!(query, "stars>={min} ");
+        }
+
+        if let Some(max) = self.max_stars {
+            let _ = write!(query, "stars<={max} ");
+        }
+
+        query.truncate(query.trim_end().len());
+
+        query
+    }
+
+    fn matches(&self, stars: f32, ranked_year: Option<i32>) -> bool {
+        self.min_stars.is_none_or(|min| stars >= min)
+            && self.max_stars.is_none_or(|max| stars <= max)
+            && self.year.is_none_or(|year| ranked_year == Some(year))
+    }
+}
+
+struct MissingMap {
+    map_id: u32,
+    mapset_id: u32,
+    artist: String,
+    title: String,
+    version: String,
+    stars: f32,
+    checksum: Option<String>,
+    /// Grade of the user's score, if any
+    grade: Option<Grade>,
+}
+
+async fn slash_completion(mut command: InteractionCommand) -> Result<()> {
+    let args = Completion::from_interaction(command.input_data())?;
+
+    completion((&mut command).into(), args).await
+}
+
+async fn completion(orig: CommandOrigin<'_>, args: Completion<'_>) -> Result<()> {
+    let filter = match args.filter.as_deref().map(CompletionFilter::parse) {
+        Some(Ok(filter)) => filter,
+        Some(Err(content)) => return orig.error(content).await,
+        None => CompletionFilter::default(),
+    };
+
+    let owner = orig.user_id()?;
+
+    let config = match Context::user_config().with_osu_id(owner).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match config.osu {
+            Some(user_id) => UserId::Id(user_id),
+            None => return require_link(&orig).await,
+        },
+    };
+
+    let mode = args
+        .mode
+        .map(GameMode::from)
+        .or(config.mode)
+        .unwrap_or(GameMode::Osu);
+
+    let user_args = UserArgs::rosu_id(&user_id, mode).await;
+
+    let user = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user,
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user");
+
+            return Err(err);
+        }
+    };
+
+    let maps = match search_maps(&filter, mode).await {
+        Ok(maps) => maps,
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+
+            return Err(Report::new(err).wrap_err("Failed to search maps"));
+        }
+    };
+
+    let guild_score_data = match orig.guild_id() {
+        Some(guild_id) => {
+            Context::guild_config()
+                .peek(guild_id, |config| config.score_data)
+                .await
+        }
+        None => None,
+    };
+
+    let legacy_scores = config
+        .score_data
+        .or(guild_score_data)
+        .unwrap_or_default()
+        .is_legacy();
+
+    let checked = maps.len();
+    let required = args.grade.map(Grade::from);
+    let user_id = user.user_id.to_native();
+
+    let mut missing = match missing_maps(maps, user_id, mode, required, legacy_scores).await {
+        Ok(missing) => missing,
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    missing.sort_unstable_by(|a, b| a.stars.total_cmp(&b.stars));
+
+    let name = user.username.as_str().cow_escape_markdown();
+
+    let title = match args.grade {
+        Some(grade) => format!(
+            "Maps on which {name} is missing grade {} ({mode:?})",
+            grade_name(grade)
+        ),
+        None => format!("Maps on which {name} has no score ({mode:?})"),
+    };
+
+    let mut description = String::with_capacity(LIST_LEN * 96);
+
+    for map in missing.iter().take(LIST_LEN) {
+        let _ = write!(
+            description,
+            "• [{artist} - {title} [{version}]]({OSU_BASE}b/{map_id}) `{stars:.2}★`",
+            artist = map.artist.cow_escape_markdown(),
+            title = map.title.cow_escape_markdown(),
+            version = map.version.cow_escape_markdown(),
+            map_id = map.map_id,
+            stars = map.stars,
+        );
+
+        if let Some(grade) = map.grade {
+            let _ = write!(description, " • {grade:?}");
+        }
+
+        description.push('\n');
+    }
+
+    if missing.len() > LIST_LEN {
+        let _ = writeln!(description, "…and {} more", missing.len() - LIST_LEN);
+    } else if missing.is_empty() {
+        description.push_str("Nothing missing, well done!");
+    }
+
+    let footer = format!(
+        "Missing {} out of {checked} checked difficulties",
+        missing.len()
+    );
+
+    let embed = EmbedBuilder::new()
+        .title(title)
+        .description(description)
+        .footer(FooterBuilder::new(footer));
+
+    let mut builder = MessageBuilder::new().embed(embed);
+
+    if !missing.is_empty() {
+        builder = match args.export {
+            Some(CompletionExport::Csv) => builder.attachment("completion.csv", csv(&missing)),
+            Some(CompletionExport::Collection) => {
+                let name = format!("{} completion", user.username);
+
+                builder.attachment("collection.db", collection(&name, &missing))
+            }
+            None => builder,
+        };
+    }
+
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+/// Ranked difficulties matching the filter, earliest ranked first.
+async fn search_maps(
+    filter: &CompletionFilter,
+    mode: GameMode,
+) -> Result<Vec<MissingMap>, OsuError> {
+    let query = filter.query();
+
+    let search_fut = Context::osu_request(|osu| {
+        let search_fut = osu
+            .beatmapset_search()
+            .mode(mode)
+            .status(Some(RankStatus::Ranked))
+            .sort(BeatmapsetSearchSort::ApprovedDate, false);
+
+        if query.is_empty() {
+            search_fut
+        } else {
+            search_fut.query(query)
+        }
+    });
+
+    let mut search_result = search_fut.await?;
+
+    let mut maps = Vec::new();
+
+    for _ in 0..MAX_SEARCH_PAGES {
+        for mapset in search_result.mapsets.drain(..) {
+            let ranked_year = mapset.ranked_date.map(|date| date.year());
+
+            let mapset_maps = mapset
+                .maps
+                .into_iter()
+                .flatten()
+                .filter(|map| map.mode == mode && filter.matches(map.stars, ranked_year));
+
+            for map in mapset_maps {
+                maps.push(MissingMap {
+                    map_id: map.map_id,
+                    mapset_id: map.mapset_id,
+                    artist: mapset.artist.clone(),
+                    title: mapset.title.clone(),
+                    version: map.version,
+                    stars: map.stars,
+                    checksum: map.checksum,
+                    grade: None,
+                });
+            }
+        }
+
+        if maps.len() >= MAX_MAPS {
+            break;
+        }
+
+        match Context::osu_request(|osu| search_result.get_next(osu)).await {
+            Some(next) => search_result = next?,
+            None => break,
+        }
+    }
+
+    maps.truncate(MAX_MAPS);
+
+    Ok(maps)
+}
+
+/// Keep only the maps on which the user has no score or a score below the
+/// required grade.
+async fn missing_maps(
+    maps: Vec<MissingMap>,
+    user_id: u32,
+    mode: GameMode,
+    required: Option<Grade>,
+    legacy_scores: bool,
+) -> Result<Vec<MissingMap>> {
+    /// Max amount of simultaneous requests
+    const CONCURRENCY: usize = 8;
+
+    let mut score_stream = stream::iter(maps)
+        .map(|map| async move {
+            let score_fut = Context::osu_scores().user_on_map_single(
+                user_id,
+                map.map_id,
+                mode,
+                None,
+                legacy_scores,
+            );
+
+            (map, score_fut.await)
+        })
+        .buffer_unordered(CONCURRENCY);
+
+    let mut missing = Vec::new();
+
+    while let Some((mut map, res)) = score_stream.next().await {
+        match res {
+            Ok(score) => {
+                let Some(required) = required else { continue };
+
+                if grade_value(score.score.grade) < grade_value(required) {
+                    map.grade = Some(score.score.grade);
+                    missing.push(map);
+                }
+            }
+            Err(OsuError::NotFound) => missing.push(map),
+            Err(err) => {
+                let wrap = format!("Failed to get score on map {}", map.map_id);
+
+                return Err(Report::new(err).wrap_err(wrap));
+            }
+        }
+    }
+
+    Ok(missing)
+}
+
+/// Silver grades are considered equal to their regular counterpart.
+fn grade_value(grade: Grade) -> u8 {
+    match grade {
+        Grade::F => 0,
+        Grade::D => 1,
+        Grade::C => 2,
+        Grade::B => 3,
+        Grade::A => 4,
+        Grade::S | Grade::SH => 5,
+        Grade::X | Grade::XH => 6,
+    }
+}
+
+fn grade_name(grade: GradeOption) -> &'static str {
+    match grade {
+        GradeOption::SS => "SS",
+        GradeOption::S => "S",
+        GradeOption::A => "A",
+        GradeOption::B => "B",
+        GradeOption::C => "C",
+        GradeOption::D => "D",
+        GradeOption::F => "F",
+    }
+}
+
+fn csv(maps: &[MissingMap]) -> Vec<u8> {
+    let mut content = "map_id,mapset_id,artist,title,version,stars,grade\n".to_owned();
+
+    for map in maps {
+        let _ = writeln!(
+            content,
+            "{},{},{},{},{},{:.2},{}",
+            map.map_id,
+            map.mapset_id,
+            csv_field(&map.artist),
+            csv_field(&map.title),
+            csv_field(&map.version),
+            map.stars,
+            map.grade
+                .map_or(Cow::Borrowed(""), |grade| format!("{grade:?}").into()),
+        );
+    }
+
+    content.into_bytes()
+}
+
+fn csv_field(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\"")).into()
+    } else {
+        field.into()
+    }
+}
+
+/// Encode the maps as a `collection.db` file containing a single collection.
+///
+/// Maps without checksum can't be part of a collection and are skipped.
+fn collection(name: &str, maps: &[MissingMap]) -> Vec<u8> {
+    const VERSION: i32 = 20150203;
+
+    let checksums: Vec<_> = maps
+        .iter()
+        .filter_map(|map| map.checksum.as_deref())
+        .collect();
+
+    let mut bytes = Vec::with_capacity(16 + name.len() + checksums.len() * 34);
+    bytes.extend_from_slice(&VERSION.to_le_bytes());
+    bytes.extend_from_slice(&1_i32.to_le_bytes());
+    write_osu_string(&mut bytes, name);
+    bytes.extend_from_slice(&(checksums.len() as i32).to_le_bytes());
+
+    for checksum in checksums {
+        write_osu_string(&mut bytes, checksum);
+    }
+
+    bytes
+}
+
+/// Strings in osu! files are prefixed with `0x0b` and their ULEB128 encoded
+/// length.
+fn write_osu_string(bytes: &mut Vec<u8>, s: &str) {
+    bytes.push(0x0b);
+
+    let mut len = s.len();
+
+    loop {
+        let mut byte = (len & 0x7f) as u8;
+        len >>= 7;
+
+        if len > 0 {
+            byte |= 0x80;
+        }
+
+        bytes.push(byte);
+
+        if len == 0 {
+            break;
+        }
+    }
+
+    bytes.extend_from_slice(s.as_bytes());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_filter() {
+        assert_eq!(
+            CompletionFilter::parse("year=2020 stars>=5 stars<6.5"),
+            Ok(CompletionFilter {
+                year: Some(2020),
+                min_stars: Some(5.0),
+                max_stars: Some(6.5),
+            })
+        );
+        assert_eq!(
+            CompletionFilter::parse("STARS=4"),
+            Ok(CompletionFilter {
+                year: None,
+                min_stars: Some(4.0),
+                max_stars: Some(5.0),
+            })
+        );
+        assert!(CompletionFilter::parse("length<100").is_err());
+        assert!(CompletionFilter::parse("stars!5").is_err());
+    }
+
+    #[test]
+    fn osu_string() {
+        let mut bytes = Vec::new();
+        write_osu_string(&mut bytes, "abc");
+        assert_eq!(bytes, [0x0b, 3, b'a', b'b', b'c']);
+
+        let mut bytes = Vec::new();
+        write_osu_string(&mut bytes, &"a".repeat(200));
+        assert_eq!(bytes[..3], [0x0b, 0xc8, 0x01]);
+    }
+}
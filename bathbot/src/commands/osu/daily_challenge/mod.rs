@@ -16,6 +16,7 @@ mod user;
 
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(name = "dailychallenge", desc = "Daily challenge statistics")]
+#[flags(BANCHO_ONLY)]
 pub enum DailyChallenge<'a> {
     #[command(name = "user")]
     User(DailyChallengeUser<'a>),
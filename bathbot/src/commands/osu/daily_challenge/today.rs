@@ -27,6 +27,7 @@ use crate::{
 #[command]
 #[desc(DC_TODAY_DESC)]
 #[aliases("dc", "dct", "dctoday", "dailychallengetoday")]
+#[flags(BANCHO_ONLY)]
 #[group(AllModes)]
 async fn prefix_dailychallenge(
     msg: &Message,
@@ -92,8 +93,9 @@ impl DailyChallengeDay {
         let map_id = playlist_map.map_id.to_native();
         let checksum = playlist_map.checksum.as_deref();
 
-        let leaderboard_fut = Context::osu().room_leaderboard(room_id);
-        let scores_fut = Context::osu().playlist_scores(room_id, playlist_item_id);
+        let leaderboard_fut = Context::osu_request(|osu| osu.room_leaderboard(room_id));
+        let scores_fut =
+            Context::osu_request(|osu| osu.playlist_scores(room_id, playlist_item_id));
         let map_fut = Context::osu_map().map(map_id, checksum);
 
         let (leaderboard, scores, map) = match tokio::join!(leaderboard_fut, scores_fut, map_fut) {
@@ -41,6 +41,7 @@ impl<'m> DailyChallengeUser<'m> {
 #[usage("[username]")]
 #[examples("peppy")]
 #[aliases("dcu", "dcuser", "dcp", "dcprofile", "dailychallengeprofile")]
+#[flags(BANCHO_ONLY)]
 #[group(AllModes)]
 async fn prefix_dailychallengeuser(
     msg: &Message,
@@ -0,0 +1,338 @@
+use std::{borrow::Cow, fmt::Write};
+
+use bathbot_macros::{HasName, SlashCommand, command};
+use bathbot_psql::model::osu::DbEfficiencyPeer;
+use bathbot_util::{
+    EmbedBuilder, FooterBuilder, MessageBuilder, attachment,
+    constants::GENERAL_ISSUE,
+    matcher,
+    numbers::{WithComma, round},
+};
+use eyre::{Report, Result};
+use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use super::{GraphAxes, GraphPalette, GraphRenderer, ScatterGraph, require_link, user_not_found};
+use crate::{
+    Context,
+    commands::{DISCORD_OPTION_DESC, DISCORD_OPTION_HELP},
+    core::commands::CommandOrigin,
+    manager::redis::osu::{UserArgs, UserArgsError},
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+const W: u32 = 900;
+const H: u32 = 450;
+
+/// At most this many peers are compared against
+const PEER_LIMIT: usize = 1000;
+
+/// Percentiles are only shown if at least this many peers are known
+const MIN_PEERS: usize = 25;
+
+/// Efficiency of a user, either the command's target or one of their peers.
+#[derive(Copy, Clone)]
+struct UserEfficiency {
+    rank: u32,
+    pp_per_hour: f32,
+    pp_per_play: f32,
+}
+
+impl UserEfficiency {
+    fn new(rank: u32, pp: f32, playcount: u32, playtime_secs: u32) -> Self {
+        Self {
+            rank,
+            pp_per_hour: pp / (playtime_secs as f32 / 3600.0),
+            pp_per_play: pp / playcount as f32,
+        }
+    }
+}
+
+impl From<DbEfficiencyPeer> for UserEfficiency {
+    fn from(peer: DbEfficiencyPeer) -> Self {
+        Self::new(
+            peer.global_rank as u32,
+            peer.pp,
+            peer.playcount as u32,
+            peer.playtime as u32,
+        )
+    }
+}
+
+/// Peers are users with a global rank between half and double of the given
+/// rank.
+fn peer_ranks(rank: u32) -> (u32, u32) {
+    ((rank / 2).max(1), rank.saturating_mul(2))
+}
+
+#[derive(CommandModel, CreateCommand, Default, HasName, SlashCommand)]
+#[command(
+    name = "efficiency",
+    desc = "Compare a user's pp per hour and per play against their peers",
+    help = "Compare a user's pp per hour of playtime and pp per play against \
+    osu!standard players around their global rank.\n\
+    Peers are users between half and double the global rank whose profile the bot \
+    has seen within the last week so the comparison depends on who uses the bot."
+)]
+#[flags(BANCHO_ONLY)]
+pub struct Efficiency<'a> {
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
+    discord: Option<Id<UserMarker>>,
+}
+
+async fn slash_efficiency(mut command: InteractionCommand) -> Result<()> {
+    let args = Efficiency::from_interaction(command.input_data())?;
+
+    efficiency((&mut command).into(), args).await
+}
+
+#[command]
+#[desc("Compare a user's pp per hour and per play against their peers")]
+#[help(
+    "Compare a user's pp per hour of playtime and pp per play against \
+    osu!standard players around their global rank.\n\
+    Peers are users between half and double the global rank whose profile the bot \
+    has seen within the last week so the comparison depends on who uses the bot."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[alias("eff")]
+#[flags(BANCHO_ONLY)]
+#[group(Osu)]
+async fn prefix_efficiency(msg: &Message, mut args: Args<'_>) -> Result<()> {
+    let args = match args.next() {
+        Some(arg) => match matcher::get_mention_user(arg) {
+            Some(id) => Efficiency {
+                name: None,
+                discord: Some(id),
+            },
+            None => Efficiency {
+                name: Some(Cow::Borrowed(arg)),
+                discord: None,
+            },
+        },
+        None => Efficiency::default(),
+    };
+
+    efficiency(msg.into(), args).await
+}
+
+async fn efficiency(orig: CommandOrigin<'_>, args: Efficiency<'_>) -> Result<()> {
+    let owner = orig.user_id()?;
+
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match Context::user_config().osu_id(owner).await {
+            Ok(Some(user_id)) => UserId::Id(user_id),
+            Ok(None) => return require_link(&orig).await,
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        },
+    };
+
+    let user_args = UserArgs::rosu_id(&user_id, GameMode::Osu).await;
+
+    let user = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user,
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user");
+
+            return Err(err);
+        }
+    };
+
+    let Some(stats) = user.statistics.as_ref() else {
+        return orig.error("Failed to get the user's statistics").await;
+    };
+
+    let pp = stats.pp.to_native();
+    let rank = stats.global_rank.to_native();
+    let playcount = stats.playcount.to_native();
+    let hours = stats.playtime.to_native() as f32 / 3600.0;
+
+    if rank == 0 || playcount == 0 || hours < 1.0 {
+        let content = format!(
+            "`{}` needs a global rank and some playtime to compare their efficiency",
+            user.username.as_str()
+        );
+
+        return orig.error(content).await;
+    }
+
+    let target = UserEfficiency::new(rank, pp, playcount, stats.playtime.to_native());
+    let (min_rank, max_rank) = peer_ranks(rank);
+
+    let peers_fut =
+        Context::osu_user().efficiency_peers(GameMode::Osu, min_rank, max_rank, PEER_LIMIT);
+
+    let peers: Vec<UserEfficiency> = match peers_fut.await {
+        Ok(peers) => peers.into_iter().map(UserEfficiency::from).collect(),
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let mut description = format!(
+        "**pp per hour:** `{per_hour}`\n**pp per play:** `{per_play}`\n\n",
+        per_hour = round(target.pp_per_hour),
+        per_play = round(target.pp_per_play),
+    );
+
+    let min_rank = WithComma::new(min_rank);
+    let max_rank = WithComma::new(max_rank);
+
+    let graph = if peers.len() >= MIN_PEERS {
+        let per_hour: Vec<_> = peers.iter().map(|peer| peer.pp_per_hour).collect();
+        let per_play: Vec<_> = peers.iter().map(|peer| peer.pp_per_play).collect();
+
+        let _ = write!(
+            description,
+            "More efficient than **{hour_share}%** per hour and **{play_share}%** per play \
+            of {count} known users between rank #{min_rank} and #{max_rank}\n\
+            Their median: `{median_hour}` pp per hour • `{median_play}` pp per play",
+            hour_share = share_below(target.pp_per_hour, &per_hour).round(),
+            play_share = share_below(target.pp_per_play, &per_play).round(),
+            count = peers.len(),
+            median_hour = round(median(per_hour)),
+            median_play = round(median(per_play)),
+        );
+
+        match efficiency_graph(user.username.as_str(), target, &peers) {
+            Ok(graph) => Some(graph),
+            Err(err) => {
+                warn!(?err, "Failed to create efficiency graph");
+
+                None
+            }
+        }
+    } else {
+        let _ = write!(
+            description,
+            "Not enough users between rank #{min_rank} and #{max_rank} are known \
+            to compare against",
+        );
+
+        None
+    };
+
+    let footer = format!(
+        "{pp}pp • {playcount} plays • {hours} hours played",
+        pp = WithComma::new(pp),
+        playcount = WithComma::new(playcount),
+        hours = WithComma::new(hours.round() as u32),
+    );
+
+    let mut embed = EmbedBuilder::new()
+        .title(format!("Efficiency of {}", user.username.as_str()))
+        .description(description)
+        .footer(FooterBuilder::new(footer));
+
+    let mut builder = MessageBuilder::new();
+
+    if let Some(graph) = graph {
+        embed = embed.image(attachment("efficiency.png"));
+        builder = builder.attachment("efficiency.png", graph);
+    }
+
+    orig.create_message(builder.embed(embed)).await?;
+
+    Ok(())
+}
+
+/// Percentage of `values` that are less than `value`.
+fn share_below(value: f32, values: &[f32]) -> f32 {
+    if values.is_empty() {
+        return 0.0;
+    }
+
+    let below = values.iter().filter(|&&other| other < value).count();
+
+    100.0 * below as f32 / values.len() as f32
+}
+
+fn median(mut values: Vec<f32>) -> f32 {
+    if values.is_empty() {
+        return 0.0;
+    }
+
+    values.sort_unstable_by(f32::total_cmp);
+    let mid = values.len() / 2;
+
+    if values.len() % 2 == 0 {
+        (values[mid - 1] + values[mid]) / 2.0
+    } else {
+        values[mid]
+    }
+}
+
+fn efficiency_graph(
+    username: &str,
+    target: UserEfficiency,
+    peers: &[UserEfficiency],
+) -> Result<Vec<u8>> {
+    let points: Vec<_> = peers
+        .iter()
+        .map(|peer| (f64::from(peer.rank).log10(), f64::from(peer.pp_per_hour)))
+        .collect();
+
+    let graph = ScatterGraph {
+        axes: GraphAxes {
+            caption: format!("{username} among peers"),
+            x_desc: Some("Global rank"),
+            y_desc: Some("pp per hour"),
+        },
+        points: &points,
+        highlight: (
+            f64::from(target.rank).log10(),
+            f64::from(target.pp_per_hour),
+        ),
+        x_labels: 8,
+        x_fmt: &|exp| format!("#{}", WithComma::new(10_f64.powf(*exp).round() as u64)),
+        y_fmt: &|pp| format!("{pp:.1}"),
+    };
+
+    graph.render(W, H, GraphPalette::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn shares() {
+        let values = [1.0, 2.0, 3.0, 4.0];
+
+        assert_eq!(share_below(0.5, &values), 0.0);
+        assert_eq!(share_below(2.5, &values), 50.0);
+        assert_eq!(share_below(5.0, &values), 100.0);
+        assert_eq!(share_below(1.0, &[]), 0.0);
+    }
+
+    #[test]
+    fn medians() {
+        assert_eq!(median(vec![3.0, 1.0, 2.0]), 2.0);
+        assert_eq!(median(vec![4.0, 1.0, 3.0, 2.0]), 2.5);
+        assert_eq!(median(Vec::new()), 0.0);
+    }
+
+    #[test]
+    fn rank_window() {
+        assert_eq!(peer_ranks(1), (1, 2));
+        assert_eq!(peer_ranks(1000), (500, 2000));
+        assert_eq!(peer_ranks(u32::MAX).1, u32::MAX);
+    }
+}
@@ -0,0 +1,382 @@
+use std::{borrow::Cow, fmt::Write};
+
+use bathbot_macros::{HasName, SlashCommand};
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_util::{
+    CowUtils, EmbedBuilder, FooterBuilder, MessageBuilder, ModsFormatter,
+    constants::{GENERAL_ISSUE, OSU_BASE},
+    datetime::HowLongAgoDynamic,
+    units::{Acc, Pp},
+};
+use eyre::{Report, Result};
+use rosu_v2::{
+    model::GameMode,
+    prelude::{OsuError, Username},
+    request::UserId,
+};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use super::user_not_found;
+use crate::{
+    Context,
+    commands::{DISCORD_OPTION_DESC, DISCORD_OPTION_HELP},
+    core::commands::CommandOrigin,
+    manager::{
+        FavoritesManager,
+        redis::osu::{CachedUser, UserArgs, UserArgsError},
+    },
+    util::{InteractionCommandExt, interaction::InteractionCommand, osu::grade_emote},
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "favorites",
+    desc = "Keep a shortlist of your favorite players",
+    help = "Keep a shortlist of up to 25 favorite players.\n\
+    Use `/favorites recent` to see what your favorites have been up to lately."
+)]
+pub enum Favorites<'a> {
+    #[command(name = "add")]
+    Add(FavoritesAdd<'a>),
+    #[command(name = "remove")]
+    Remove(FavoritesRemove<'a>),
+    #[command(name = "list")]
+    List(FavoritesList),
+    #[command(name = "recent")]
+    Recent(FavoritesRecent),
+}
+
+#[derive(CommandModel, CreateCommand, HasName)]
+#[command(name = "add", desc = "Add a player to your favorites")]
+pub struct FavoritesAdd<'a> {
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
+    discord: Option<Id<UserMarker>>,
+}
+
+#[derive(CommandModel, CreateCommand, HasName)]
+#[command(name = "remove", desc = "Remove a player from your favorites")]
+pub struct FavoritesRemove<'a> {
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
+    discord: Option<Id<UserMarker>>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "List all of your favorite players")]
+pub struct FavoritesList;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "recent",
+    desc = "Show the latest top score of each favorite player",
+    help = "Show the latest top score of each favorite player.\n\
+    Only scores within a player's top 100 are considered."
+)]
+pub struct FavoritesRecent {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+}
+
+async fn slash_favorites(mut command: InteractionCommand) -> Result<()> {
+    match Favorites::from_interaction(command.input_data())? {
+        Favorites::Add(args) => add((&mut command).into(), args).await,
+        Favorites::Remove(args) => remove((&mut command).into(), args).await,
+        Favorites::List(_) => list((&mut command).into()).await,
+        Favorites::Recent(args) => recent((&mut command).into(), args).await,
+    }
+}
+
+async fn add(orig: CommandOrigin<'_>, args: FavoritesAdd<'_>) -> Result<()> {
+    let Some(user_id) = user_id!(orig, args) else {
+        return orig
+            .error("You must specify a username or discord user")
+            .await;
+    };
+
+    let Some(user) = request_user(&orig, user_id).await? else {
+        return Ok(());
+    };
+
+    let owner = orig.user_id()?;
+    let favorites = Context::favorites();
+
+    let count = match favorites.get(owner).await {
+        Ok(favorites) => favorites.len(),
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    if count >= FavoritesManager::MAX_FAVORITES {
+        let content = format!(
+            "You can't have more than {} favorites, remove one first",
+            FavoritesManager::MAX_FAVORITES
+        );
+
+        return orig.error(content).await;
+    }
+
+    let added = match favorites.add(owner, user.user_id.to_native()).await {
+        Ok(added) => added,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let name = user.username.as_str().cow_escape_markdown();
+
+    let content = if added {
+        format!("Added `{name}` to your favorites")
+    } else {
+        format!("`{name}` is already one of your favorites")
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+async fn remove(orig: CommandOrigin<'_>, args: FavoritesRemove<'_>) -> Result<()> {
+    let Some(user_id) = user_id!(orig, args) else {
+        return orig
+            .error("You must specify a username or discord user")
+            .await;
+    };
+
+    let Some(user) = request_user(&orig, user_id).await? else {
+        return Ok(());
+    };
+
+    let owner = orig.user_id()?;
+
+    let removed = match Context::favorites()
+        .remove(owner, user.user_id.to_native())
+        .await
+    {
+        Ok(removed) => removed,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let name = user.username.as_str().cow_escape_markdown();
+
+    if !removed {
+        let content = format!("`{name}` is not one of your favorites");
+
+        return orig.error(content).await;
+    }
+
+    let content = format!("Removed `{name}` from your favorites");
+    let builder = MessageBuilder::new().embed(content);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+async fn list(orig: CommandOrigin<'_>) -> Result<()> {
+    let Some(user_ids) = favorite_ids(&orig).await? else {
+        return Ok(());
+    };
+
+    let names = match favorite_names(&user_ids).await {
+        Ok(names) => names,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let mut description = String::with_capacity(user_ids.len() * 32);
+
+    for (user_id, name) in user_ids.iter().zip(names) {
+        let _ = writeln!(
+            description,
+            "• [{name}]({OSU_BASE}u/{user_id})",
+            name = name.cow_escape_markdown(),
+        );
+    }
+
+    let footer = format!(
+        "{} / {} favorites",
+        user_ids.len(),
+        FavoritesManager::MAX_FAVORITES
+    );
+
+    let embed = EmbedBuilder::new()
+        .title("Favorite players")
+        .description(description)
+        .footer(FooterBuilder::new(footer));
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+async fn recent(orig: CommandOrigin<'_>, args: FavoritesRecent) -> Result<()> {
+    let owner = orig.user_id()?;
+
+    let config = match Context::user_config().with_osu_id(owner).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let Some(user_ids) = favorite_ids(&orig).await? else {
+        return Ok(());
+    };
+
+    let mode = args
+        .mode
+        .map(GameMode::from)
+        .or(config.mode)
+        .unwrap_or(GameMode::Osu);
+
+    let guild_score_data = match orig.guild_id() {
+        Some(guild_id) => {
+            Context::guild_config()
+                .peek(guild_id, |config| config.score_data)
+                .await
+        }
+        None => None,
+    };
+
+    let legacy_scores = config
+        .score_data
+        .or(guild_score_data)
+        .unwrap_or_default()
+        .is_legacy();
+
+    let scores_fut = Context::osu_scores().users_latest_top(&user_ids, mode, legacy_scores);
+
+    let (scores_res, names_res) = tokio::join!(scores_fut, favorite_names(&user_ids));
+
+    let (scores, names) = match (scores_res, names_res) {
+        (Ok(scores), Ok(names)) => (scores, names),
+        (Err(err), _) | (_, Err(err)) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let mut description = String::with_capacity(scores.len() * 128);
+
+    for score in scores.iter() {
+        let name = user_ids
+            .iter()
+            .position(|&user_id| user_id == score.user_id)
+            .map_or("?", |idx| names[idx].as_str());
+
+        let (map, mapset) = match (score.map.as_ref(), score.mapset.as_ref()) {
+            (Some(map), Some(mapset)) => (map, mapset),
+            _ => continue,
+        };
+
+        let _ = writeln!(
+            description,
+            "**{name}**: {grade} [{artist} - {title} [{version}]]({OSU_BASE}b/{map_id}) \
+            **+{mods}**\n{pp}pp • {acc}% {ago}",
+            name = name.cow_escape_markdown(),
+            grade = grade_emote(score.grade),
+            artist = mapset.artist.cow_escape_markdown(),
+            title = mapset.title.cow_escape_markdown(),
+            version = map.version.cow_escape_markdown(),
+            map_id = map.map_id,
+            mods = ModsFormatter::new(&score.mods, false),
+            pp = Pp(score.pp.unwrap_or(0.0)),
+            acc = Acc(score.accuracy),
+            ago = HowLongAgoDynamic::new(&score.ended_at),
+        );
+    }
+
+    if description.is_empty() {
+        description.push_str("None of your favorites have top scores in this mode");
+    }
+
+    let embed = EmbedBuilder::new()
+        .title(format!("Latest top scores of favorites ({mode:?})"))
+        .description(description);
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+/// Returns `None` if the user has no favorites in which case they already
+/// received a response.
+async fn favorite_ids(orig: &CommandOrigin<'_>) -> Result<Option<Vec<u32>>> {
+    let user_ids = match Context::favorites().get(orig.user_id()?).await {
+        Ok(user_ids) => user_ids,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    if user_ids.is_empty() {
+        let content = "You don't have any favorites yet, add some via `/favorites add`";
+        orig.error(content).await?;
+
+        return Ok(None);
+    }
+
+    Ok(Some(user_ids))
+}
+
+/// Usernames for the given user ids in the same order.
+async fn favorite_names(user_ids: &[u32]) -> Result<Vec<Username>> {
+    let ids: Vec<_> = user_ids.iter().map(|&user_id| user_id as i32).collect();
+    let mut names = Context::osu_user().names(&ids).await?;
+
+    let names = user_ids
+        .iter()
+        .map(|user_id| {
+            names
+                .remove(user_id)
+                .unwrap_or_else(|| format!("User id {user_id}").into())
+        })
+        .collect();
+
+    Ok(names)
+}
+
+/// Returns `None` if the user was not found in which case the author already
+/// received a response.
+async fn request_user(orig: &CommandOrigin<'_>, user_id: UserId) -> Result<Option<CachedUser>> {
+    let user_args = UserArgs::rosu_id(&user_id, GameMode::Osu).await;
+
+    match Context::redis().osu_user(user_args).await {
+        Ok(user) => Ok(Some(user)),
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+            orig.error(content).await?;
+
+            Ok(None)
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user");
+
+            Err(err)
+        }
+    }
+}
@@ -141,9 +141,9 @@ async fn slash_fix(mut command: InteractionCommand) -> Result<()> {
     match FixArgs::try_from(args) {
         Ok(args) => fix((&mut command).into(), args).await,
         Err(content) => {
-            command.error(content).await?;
-
-            Ok(())
+            CommandOrigin::from(&mut command)
+                .error_with_help(content)
+                .await
         }
     }
 }
@@ -190,7 +190,7 @@ async fn fix(orig: CommandOrigin<'_>, args: FixArgs<'_>) -> Result<()> {
             let content = "Failed to parse mods. Be sure to either specify them directly \
             or through the `+mods` / `+mods!` syntax e.g. `hdhr` or `+hdhr!`";
 
-            return orig.error(content).await;
+            return orig.error_with_help(content).await;
         }
     };
 
@@ -421,11 +421,15 @@ async fn request_by_score(
     user_id: UserId,
     legacy_scores: bool,
 ) -> ScoreResult {
-    let mut score_fut = Context::osu().score(score_id);
+    let score_fut = Context::osu_request(|osu| {
+        let mut score_fut = osu.score(score_id);
 
-    if let Some(mode) = mode {
-        score_fut = score_fut.mode(mode);
-    }
+        if let Some(mode) = mode {
+            score_fut = score_fut.mode(mode);
+        }
+
+        score_fut
+    });
 
     let score = match score_fut.await {
         Ok(score) => score,
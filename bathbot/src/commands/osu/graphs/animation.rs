@@ -0,0 +1,80 @@
+use eyre::{ContextCompat, Result, WrapErr};
+use image::{
+    Delay, Frame, RgbaImage,
+    codecs::gif::{GifEncoder, Repeat},
+};
+use skia_safe::{AlphaType, ColorType, ImageInfo, Surface, surfaces};
+
+use super::{H, W};
+
+/// Amount of frames of an animated graph.
+const FRAMES: usize = 20;
+
+const FRAME_DELAY_MS: u32 = 80;
+
+/// The last frame is shown a little longer before the animation loops.
+const LAST_FRAME_DELAY_MS: u32 = 3000;
+
+/// Encode a gif whose frames progressively reveal the graph's data.
+///
+/// `draw_frame` is called for each frame alongside the amount of data points
+/// that should be shown; the last frame shows all `len` of them.
+pub fn animate<F>(len: usize, mut draw_frame: F) -> Result<Vec<u8>>
+where
+    F: FnMut(&mut Surface, usize) -> Result<()>,
+{
+    let mut frames = Vec::with_capacity(FRAMES);
+
+    for i in 1..=FRAMES {
+        let revealed = (len * i).div_ceil(FRAMES).max(2.min(len));
+
+        let mut surface = surfaces::raster_n32_premul((W as i32, H as i32))
+            .wrap_err("Failed to create surface")?;
+
+        draw_frame(&mut surface, revealed)?;
+
+        let delay_ms = if i == FRAMES {
+            LAST_FRAME_DELAY_MS
+        } else {
+            FRAME_DELAY_MS
+        };
+
+        let delay = Delay::from_numer_denom_ms(delay_ms, 1);
+        frames.push(Frame::from_parts(read_pixels(&mut surface)?, 0, 0, delay));
+    }
+
+    let mut bytes = Vec::new();
+
+    {
+        // Highest speed since quality is of little concern for graphs
+        let mut encoder = GifEncoder::new_with_speed(&mut bytes, 30);
+
+        encoder
+            .set_repeat(Repeat::Infinite)
+            .wrap_err("Failed to set gif repeat")?;
+
+        encoder
+            .encode_frames(frames)
+            .wrap_err("Failed to encode gif frames")?;
+    }
+
+    Ok(bytes)
+}
+
+fn read_pixels(surface: &mut Surface) -> Result<RgbaImage> {
+    let info = ImageInfo::new(
+        (W as i32, H as i32),
+        ColorType::RGBA8888,
+        AlphaType::Unpremul,
+        None,
+    );
+
+    let row_bytes = W as usize * 4;
+    let mut pixels = vec![0; row_bytes * H as usize];
+
+    if !surface.read_pixels(&info, &mut pixels, row_bytes, (0, 0)) {
+        bail!("Failed to read surface pixels");
+    }
+
+    RgbaImage::from_raw(W, H, pixels).wrap_err("Invalid pixel buffer size")
+}
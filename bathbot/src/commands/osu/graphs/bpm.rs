@@ -1,10 +1,13 @@
 use std::{borrow::Cow, cell::RefCell, rc::Rc, time::Duration};
 
 use bathbot_macros::command;
-use bathbot_util::{matcher, osu::MapIdType};
+use bathbot_util::{
+    matcher,
+    osu::{MapIdType, effective_clock_rate},
+};
 use eyre::{ContextCompat, Result, WrapErr};
 use plotters::{
-    chart::ChartBuilder,
+    chart::{ChartBuilder, SeriesLabelPosition},
     prelude::{DrawingArea, Rectangle},
     series::LineSeries,
     style::{BLACK, Color, FontDesc, RGBColor, WHITE},
@@ -22,10 +25,13 @@ use rosu_v2::prelude::GameMods;
 use skia_safe::{EncodedImageFormat, surfaces};
 use twilight_model::{channel::Message, guild::Permissions};
 
-use super::{BitMapElement, Graph, H, W, get_map_cover};
+use super::{BitMapElement, Graph, GraphData, H, W, get_map_cover};
 use crate::{
     commands::osu::{GraphMapBpm, graphs::GRAPH_BPM_DESC},
-    core::commands::{CommandOrigin, prefix::Args},
+    core::{
+        Context,
+        commands::{CommandOrigin, prefix::Args},
+    },
     util::{ChannelExt, osu::MapOrScore},
 };
 
@@ -33,9 +39,12 @@ impl<'m> GraphMapBpm<'m> {
     async fn args(msg: &Message, args: Args<'m>) -> Result<Self, String> {
         let mut map = None;
         let mut mods = None;
+        let mut all_diffs = None;
 
         for arg in args {
-            if matcher::get_osu_map_id(arg)
+            if arg.eq_ignore_ascii_case("all") {
+                all_diffs = Some(true);
+            } else if matcher::get_osu_map_id(arg)
                 .map(MapIdType::Map)
                 .or_else(|| matcher::get_osu_mapset_id(arg).map(MapIdType::Set))
                 .is_some()
@@ -46,7 +55,8 @@ impl<'m> GraphMapBpm<'m> {
             } else {
                 let content = format!(
                     "Failed to parse `{arg}`.\n\
-                    Be sure you specify either a valid map id, map url, or mod combination."
+                    Be sure you specify either a valid map id, map url, mod combination, \
+                    or `all`."
                 );
 
                 return Err(content);
@@ -63,107 +73,96 @@ impl<'m> GraphMapBpm<'m> {
             }
         }
 
-        Ok(Self { map, mods })
+        Ok(Self {
+            map,
+            mods,
+            file: None,
+            all_diffs,
+            data: None,
+        })
     }
 }
 
 #[command]
 #[desc(GRAPH_BPM_DESC)]
-#[usage("[map url / id] [+mods]")]
-#[examples("240404 +hddt", "https://osu.ppy.sh/beatmapsets/902425 +hr")]
+#[usage("[map url / id] [+mods] [all]")]
+#[examples("240404 +hddt", "https://osu.ppy.sh/beatmapsets/902425 +hr all")]
 #[aliases("bpm")]
 #[group(AllModes)]
 async fn prefix_graphbpm(msg: &Message, args: Args<'_>, perms: Option<Permissions>) -> Result<()> {
+    let orig = CommandOrigin::from_msg(msg, perms);
+
     let args = match GraphMapBpm::args(msg, args).await {
         Ok(args) => args,
-        Err(content) => {
-            msg.error(content).await?;
-
-            return Ok(());
-        }
+        Err(content) => return orig.error_with_help(content).await,
     };
 
-    let orig = CommandOrigin::from_msg(msg, perms);
-
     super::graph(orig, Graph::MapBpm(args)).await
 }
 
-pub async fn map_bpm_graph(map: &Beatmap, mods: GameMods, cover_url: &str) -> Result<Vec<u8>> {
-    let mut start_timestamp = map
-        .hit_objects
-        .first()
-        .zip(map.timing_points.first())
-        .map(|(h, tp)| h.start_time.min(tp.time))
-        .unwrap_or(0.0);
-
-    let mut last_timestamp = map.hit_objects.last().map_or(0.0, |h| match h.kind {
-        HitObjectKind::Circle | HitObjectKind::Slider(_) => h.start_time,
-        HitObjectKind::Spinner(Spinner { duration })
-        | HitObjectKind::Hold(HoldNote { duration }) => h.start_time + duration,
-    });
-
-    let start_bpm = map
-        .timing_points
-        .first()
-        .map_or(TimingPoint::DEFAULT_BPM, TimingPoint::bpm);
-
-    let mut points = Vec::with_capacity(2 * map.timing_points.len());
-
-    if let Some(h) = map.hit_objects.first() {
-        if map
-            .timing_points
-            .first()
-            .is_some_and(|tp| tp.time > h.start_time)
-        {
-            points.push((h.start_time, start_bpm));
-        }
-    }
+/// A difficulty whose bpm should be drawn.
+pub struct BpmDifficulty<'a> {
+    pub version: &'a str,
+    pub pp_map: &'a Beatmap,
+}
 
-    let iter = map
-        .timing_points
+/// How many other difficulties can be drawn alongside the main one.
+pub const MAX_OTHER_DIFFS: usize = 6;
+
+/// Colors for the bpm lines of other difficulties of the mapset.
+const OTHER_COLORS: [RGBColor; MAX_OTHER_DIFFS] = [
+    RGBColor(102, 204, 255),
+    RGBColor(255, 102, 170),
+    RGBColor(255, 217, 102),
+    RGBColor(178, 102, 255),
+    RGBColor(255, 153, 85),
+    RGBColor(170, 170, 170),
+];
+
+pub async fn map_bpm_graph(
+    orig: &CommandOrigin<'_>,
+    main: BpmDifficulty<'_>,
+    others: &[BpmDifficulty<'_>],
+    mods: GameMods,
+    cover_url: Option<&str>,
+) -> Result<(Vec<u8>, GraphData)> {
+    let clock_rate = effective_clock_rate(&mods);
+
+    let main_points = BpmPoints::new(main.pp_map, clock_rate);
+
+    let other_points: Vec<_> = others
         .iter()
-        .scan(start_bpm, |prev_bpm, tp| {
-            let bpm = tp.bpm();
-            let points = [(tp.time, *prev_bpm), (tp.time, bpm)];
-            *prev_bpm = bpm;
+        .map(|other| BpmPoints::new(other.pp_map, clock_rate))
+        .collect();
 
-            Some(points)
-        })
-        .flatten();
+    let mut data = GraphData::new(&["difficulty", "timestamp_ms", "bpm"]);
 
-    points.extend(iter);
+    let difficulties = [(main.version, &main_points)]
+        .into_iter()
+        .chain(others.iter().map(|other| other.version).zip(&other_points));
 
-    if map
-        .timing_points
-        .last()
-        .is_some_and(|tp| tp.time < last_timestamp)
-    {
-        let (_, last_bpm) = points[points.len() - 1];
-        points.push((last_timestamp, last_bpm));
-    } else if map.timing_points.is_empty() {
-        points.extend([
-            (0.0, TimingPoint::DEFAULT_BPM),
-            (last_timestamp, TimingPoint::DEFAULT_BPM),
-        ]);
-    }
-
-    let clock_rate = mods.clock_rate().unwrap_or(1.0);
-
-    if clock_rate != 1.0 {
-        for (time, bpm) in points.iter_mut() {
-            *time /= clock_rate;
-            *bpm *= clock_rate;
+    for (version, points) in difficulties {
+        for (timestamp, bpm) in points.points.iter() {
+            data.push_row(&[&version, timestamp, bpm]);
         }
-
-        start_timestamp /= clock_rate;
-        last_timestamp /= clock_rate;
     }
 
-    let (min_bpm, max_bpm) = points
-        .iter()
-        .map(|(_, bpm)| (*bpm, *bpm))
-        .reduce(|(min, max), (a, b)| (min.min(a), max.max(b)))
-        .unwrap_or((TimingPoint::DEFAULT_BPM, TimingPoint::DEFAULT_BPM));
+    let (start_timestamp, last_timestamp, min_bpm, max_bpm) = other_points.iter().fold(
+        (
+            main_points.start_timestamp,
+            main_points.last_timestamp,
+            main_points.min_bpm,
+            main_points.max_bpm,
+        ),
+        |(start, last, min, max), points| {
+            (
+                start.min(points.start_timestamp),
+                last.max(points.last_timestamp),
+                min.min(points.min_bpm),
+                max.max(points.max_bpm),
+            )
+        },
+    );
 
     let bpm_range = (max_bpm - min_bpm).max(5.0);
     let lower_limit = min_bpm - bpm_range * 0.2;
@@ -171,6 +170,9 @@ pub async fn map_bpm_graph(map: &Beatmap, mods: GameMods, cover_url: &str) -> Re
 
     let cover_res = get_map_cover(cover_url, W, H).await;
 
+    // Only the drawing itself waits in the render queue
+    let _permit = Context::render_queue().acquire(orig).await?;
+
     let mut surface =
         surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
 
@@ -180,7 +182,7 @@ pub async fn map_bpm_graph(map: &Beatmap, mods: GameMods, cover_url: &str) -> Re
 
         // Add background
         match cover_res {
-            Ok(background) => {
+            Ok(Some(background)) => {
                 let background = background.blur(2.0);
                 let elem = BitMapElement::new(background, (0, 0));
                 root.draw(&elem).wrap_err("Failed to draw background")?;
@@ -189,6 +191,10 @@ pub async fn map_bpm_graph(map: &Beatmap, mods: GameMods, cover_url: &str) -> Re
                 root.draw(&rect)
                     .wrap_err("Failed to draw darkening rectangle")?;
             }
+            Ok(None) => {
+                root.fill(&RGBColor(19, 43, 33))
+                    .wrap_err("Failed to fill background")?;
+            }
             Err(err) => {
                 warn!(?err, "Failed to get mapset cover");
 
@@ -232,18 +238,57 @@ pub async fn map_bpm_graph(map: &Beatmap, mods: GameMods, cover_url: &str) -> Re
             .draw()
             .wrap_err("Failed to draw mesh")?;
 
-        let series = LineSeries::new(points.iter().copied(), WHITE.mix(0.3).stroke_width(6));
+        let colors = OTHER_COLORS.iter();
+
+        for ((other, points), color) in others.iter().zip(other_points.iter()).zip(colors) {
+            let color = *color;
+
+            let series = LineSeries::new(points.points.iter().copied(), color.stroke_width(2));
+            chart
+                .draw_series(series)
+                .wrap_err_with(|| format!("Failed to draw series of {}", other.version))?
+                .label(other.version)
+                .legend(move |(x, y)| {
+                    Rectangle::new([(x, y - 2), (x + 14, y + 2)], color.filled())
+                });
+        }
+
+        let series = LineSeries::new(
+            main_points.points.iter().copied(),
+            WHITE.mix(0.3).stroke_width(6),
+        );
         chart
             .draw_series(series)
             .wrap_err("Failed to draw white series")?;
 
         let series = LineSeries::new(
-            points.iter().copied(),
+            main_points.points.iter().copied(),
             RGBColor(0, 208, 138).stroke_width(2),
         );
-        chart
+        let series = chart
             .draw_series(series)
             .wrap_err("Failed to draw green series")?;
+
+        if !others.is_empty() {
+            series.label(main.version).legend(|(x, y)| {
+                Rectangle::new(
+                    [(x, y - 2), (x + 14, y + 2)],
+                    RGBColor(0, 208, 138).filled(),
+                )
+            });
+
+            let legend_style =
+                FontDesc::new(FontFamily::SansSerif, 14.0, FontStyle::Bold).color(&WHITE);
+
+            chart
+                .configure_series_labels()
+                .border_style(WHITE.mix(0.6))
+                .background_style(BLACK.mix(0.6))
+                .label_font(legend_style)
+                .position(SeriesLabelPosition::UpperRight)
+                .draw()
+                .wrap_err("Failed to draw legend")?;
+        }
     }
 
     let png_bytes = surface
@@ -252,5 +297,100 @@ pub async fn map_bpm_graph(map: &Beatmap, mods: GameMods, cover_url: &str) -> Re
         .wrap_err("Failed to encode image")?
         .to_vec();
 
-    Ok(png_bytes)
+    Ok((png_bytes, data))
+}
+
+/// Bpm changes of a map over time.
+struct BpmPoints {
+    points: Vec<(f64, f64)>,
+    start_timestamp: f64,
+    last_timestamp: f64,
+    min_bpm: f64,
+    max_bpm: f64,
+}
+
+impl BpmPoints {
+    fn new(map: &Beatmap, clock_rate: f64) -> Self {
+        let mut start_timestamp = map
+            .hit_objects
+            .first()
+            .zip(map.timing_points.first())
+            .map(|(h, tp)| h.start_time.min(tp.time))
+            .unwrap_or(0.0);
+
+        let mut last_timestamp = map.hit_objects.last().map_or(0.0, |h| match h.kind {
+            HitObjectKind::Circle | HitObjectKind::Slider(_) => h.start_time,
+            HitObjectKind::Spinner(Spinner { duration })
+            | HitObjectKind::Hold(HoldNote { duration }) => h.start_time + duration,
+        });
+
+        let start_bpm = map
+            .timing_points
+            .first()
+            .map_or(TimingPoint::DEFAULT_BPM, TimingPoint::bpm);
+
+        let mut points = Vec::with_capacity(2 * map.timing_points.len());
+
+        if let Some(h) = map.hit_objects.first() {
+            if map
+                .timing_points
+                .first()
+                .is_some_and(|tp| tp.time > h.start_time)
+            {
+                points.push((h.start_time, start_bpm));
+            }
+        }
+
+        let iter = map
+            .timing_points
+            .iter()
+            .scan(start_bpm, |prev_bpm, tp| {
+                let bpm = tp.bpm();
+                let points = [(tp.time, *prev_bpm), (tp.time, bpm)];
+                *prev_bpm = bpm;
+
+                Some(points)
+            })
+            .flatten();
+
+        points.extend(iter);
+
+        if map
+            .timing_points
+            .last()
+            .is_some_and(|tp| tp.time < last_timestamp)
+        {
+            let (_, last_bpm) = points[points.len() - 1];
+            points.push((last_timestamp, last_bpm));
+        } else if map.timing_points.is_empty() {
+            points.extend([
+                (0.0, TimingPoint::DEFAULT_BPM),
+                (last_timestamp, TimingPoint::DEFAULT_BPM),
+            ]);
+        }
+
+        if clock_rate != 1.0 {
+            for (time, bpm) in points.iter_mut() {
+                *time /= clock_rate;
+                *bpm *= clock_rate;
+            }
+
+            start_timestamp /= clock_rate;
+            last_timestamp /= clock_rate;
+        }
+
+        let (min_bpm, max_bpm) = points
+            .iter()
+            .map(|(_, bpm)| (*bpm, *bpm))
+            .reduce(|(min, max), (a, b)| (min.min(a), max.max(b)))
+            .unwrap_or((TimingPoint::DEFAULT_BPM, TimingPoint::DEFAULT_BPM));
+
+        Self {
+            points,
+            start_timestamp,
+            last_timestamp,
+            min_bpm,
+            max_bpm,
+        }
+    }
 }
@@ -0,0 +1,174 @@
+use bathbot_util::constants::GENERAL_ISSUE;
+use eyre::{Report, Result, WrapErr};
+use rosu_v2::{
+    prelude::{GameMode, OsuError},
+    request::UserId,
+};
+
+use super::{GraphCompareTop, GraphData, GraphPalette, top_index::top_graph_index_compare};
+use crate::{
+    commands::osu::{UserExtraction, user_not_found},
+    core::{Context, commands::CommandOrigin},
+    manager::redis::osu::{CachedUser, UserArgs, UserArgsError},
+};
+
+async fn extract_user_id(args: &mut GraphCompareTop<'_>) -> UserExtraction {
+    if let Some(name) = args.name1.take().or_else(|| args.name2.take()) {
+        UserExtraction::Id(UserId::Name(name.as_ref().into()))
+    } else if let Some(discord) = args.discord1.take().or_else(|| args.discord2.take()) {
+        match Context::user_config().osu_id(discord).await {
+            Ok(Some(user_id)) => UserExtraction::Id(UserId::Id(user_id)),
+            Ok(None) => {
+                UserExtraction::Content(format!("<@{discord}> is not linked to an osu!profile"))
+            }
+            Err(err) => UserExtraction::Err(err),
+        }
+    } else {
+        UserExtraction::None
+    }
+}
+
+pub async fn compare_top_graph(
+    orig: &CommandOrigin<'_>,
+    mut args: GraphCompareTop<'_>,
+    mode: GameMode,
+    legacy_scores: bool,
+    palette: GraphPalette,
+) -> Result<Option<(CachedUser, Vec<u8>, GraphData)>> {
+    let user_id1 = match extract_user_id(&mut args).await {
+        UserExtraction::Id(user_id) => user_id,
+        UserExtraction::Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+        UserExtraction::Content(content) => {
+            orig.error(content).await?;
+
+            return Ok(None);
+        }
+        UserExtraction::None => {
+            let content = "You need to specify at least one osu username. \
+            If you're not linked, you must specify two names.";
+            orig.error(content).await?;
+
+            return Ok(None);
+        }
+    };
+
+    let user_id2 = match extract_user_id(&mut args).await {
+        UserExtraction::Id(user_id) => user_id,
+        UserExtraction::Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+        UserExtraction::Content(content) => {
+            orig.error(content).await?;
+
+            return Ok(None);
+        }
+        UserExtraction::None => match Context::user_config().osu_id(orig.user_id()?).await {
+            Ok(Some(user_id)) => UserId::Id(user_id),
+            Ok(None) => {
+                let content =
+                    "Since you're not linked with the `/link` command, you must specify two names.";
+                orig.error(content).await?;
+
+                return Ok(None);
+            }
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        },
+    };
+
+    if user_id1 == user_id2 {
+        orig.error("Give two different names").await?;
+
+        return Ok(None);
+    }
+
+    let user_args1 = UserArgs::rosu_id(&user_id1, mode).await;
+    let user_args2 = UserArgs::rosu_id(&user_id2, mode).await;
+    let score_args = Context::osu_scores().top(200, legacy_scores);
+
+    let fut1 = score_args.clone().exec_with_user(user_args1);
+    let fut2 = score_args.exec_with_user(user_args2);
+
+    let (user1, user2, scores1, scores2) = match tokio::join!(fut1, fut2) {
+        (Ok((user1, scores1)), Ok((user2, scores2))) => (user1, user2, scores1, scores2),
+        (Err(UserArgsError::Osu(OsuError::NotFound)), _) => {
+            let content = user_not_found(user_id1).await;
+            orig.error(content).await?;
+
+            return Ok(None);
+        }
+        (_, Err(UserArgsError::Osu(OsuError::NotFound))) => {
+            let content = user_not_found(user_id2).await;
+            orig.error(content).await?;
+
+            return Ok(None);
+        }
+        (Err(err), _) | (_, Err(err)) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user or scores");
+
+            return Err(err);
+        }
+    };
+
+    if user1.user_id == user2.user_id {
+        orig.error("Give two different users").await?;
+
+        return Ok(None);
+    }
+
+    let empty = if scores1.is_empty() {
+        Some(&user1)
+    } else if scores2.is_empty() {
+        Some(&user2)
+    } else {
+        None
+    };
+
+    if let Some(user) = empty {
+        let content = format!("`{}`'s top scores are empty", user.username.as_str());
+        orig.error(content).await?;
+
+        return Ok(None);
+    }
+
+    let name1 = user1.username.as_str();
+    let name2 = user2.username.as_str();
+
+    let caption = format!(
+        "{name1} vs {name2} {mode}top200",
+        mode = match mode {
+            GameMode::Osu => "",
+            GameMode::Taiko => "taiko ",
+            GameMode::Catch => "ctb ",
+            GameMode::Mania => "mania ",
+        }
+    );
+
+    let graph_fut =
+        top_graph_index_compare(orig, caption, (name1, &scores1), (name2, &scores2), palette);
+
+    let (bytes, data) = match graph_fut
+        .await
+        .wrap_err("Failed to create top comparison graph")
+    {
+        Ok(graph) => graph,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            warn!("{err:?}");
+
+            return Ok(None);
+        }
+    };
+
+    Ok(Some((user1, bytes, data)))
+}
@@ -0,0 +1,112 @@
+use std::ops::ControlFlow;
+
+use bathbot_util::constants::GENERAL_ISSUE;
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+
+use super::{
+    GraphData, GraphMap, GraphMapCompletion, GraphPalette, H, MapResult, W, draw_queued,
+    get_graph_map, graph_palette,
+    render::{GraphAxes, GraphRenderer, Histogram},
+};
+use crate::{
+    core::{Context, commands::CommandOrigin},
+    manager::MapCompletions,
+};
+
+pub async fn map_completion(
+    orig: &CommandOrigin<'_>,
+    args: GraphMapCompletion<'_>,
+) -> Result<ControlFlow<(), MapResult>> {
+    let mode = args.mode.map(GameMode::from);
+    let palette = graph_palette(orig, args.theme).await;
+
+    let map = match get_graph_map(orig, args.map.as_deref(), None, mode, None).await? {
+        ControlFlow::Continue(map) => map,
+        ControlFlow::Break(()) => return Ok(ControlFlow::Break(())),
+    };
+
+    // No file was attached so the map was retrieved through its id
+    let GraphMap::Full(ref osu_map) = map else {
+        return Ok(ControlFlow::Break(()));
+    };
+
+    let mode = map.mode();
+
+    if mode == GameMode::Catch {
+        let content = "Completion statistics are not available for osu!ctb";
+
+        return orig.error(content).await.map(ControlFlow::Break);
+    }
+
+    let completions = match Context::osu_scores()
+        .map_completions(osu_map.map_id(), mode)
+        .await
+    {
+        Ok(completions) => completions,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    if completions.total() == 0 {
+        let content = "I haven't seen any recent scores on this map yet.\n\
+            Scores are gathered whenever someone checks their recent scores.";
+
+        return orig.error(content).await.map(ControlFlow::Break);
+    }
+
+    let bytes = match draw_queued(orig, || draw_completions(&completions, palette)).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to draw completion graph"));
+        }
+    };
+
+    let data = completion_data(&completions);
+
+    Ok(ControlFlow::Continue(MapResult::new(&map, bytes, data)))
+}
+
+/// Amount of fails per completion bucket alongside the amount of passes.
+fn completion_data(completions: &MapCompletions) -> GraphData {
+    let mut data = GraphData::new(&["completion_from", "completion_to", "fails"]);
+
+    for (i, fails) in completions.fails.iter().enumerate() {
+        let from = i * 10;
+        data.push_row(&[&from, &(from + 10), fails]);
+    }
+
+    data.push_row(&[&"passes", &"", &completions.passes]);
+
+    data
+}
+
+fn draw_completions(completions: &MapCompletions, palette: GraphPalette) -> Result<Vec<u8>> {
+    let total = completions.total();
+    let pass_rate = 100.0 * completions.passes as f32 / total as f32;
+
+    let caption = format!(
+        "Pass rate: {pass_rate:.1}% of {total} observed score{plural}",
+        plural = if total == 1 { "" } else { "s" }
+    );
+
+    let histogram = Histogram {
+        axes: GraphAxes {
+            caption,
+            x_desc: Some("Completion at the time of failing"),
+            y_desc: Some("#  of  fails"),
+        },
+        start: 0,
+        bucket_width: 10,
+        counts: &completions.fails,
+        x_labels: 11,
+        x_fmt: &|completion| format!("{completion}%"),
+    };
+
+    histogram.render(W, H, palette)
+}
@@ -0,0 +1,71 @@
+use std::fmt::{Display, Write};
+
+use bathbot_util::datetime::DATETIME_Z_FORMAT;
+use time::{OffsetDateTime, UtcOffset};
+
+/// Raw data series of a graph so users can create their own charts.
+///
+/// Rows are written as CSV with the given header as first line.
+pub struct GraphData {
+    csv: String,
+}
+
+impl GraphData {
+    pub fn new(header: &[&str]) -> Self {
+        let mut data = Self { csv: String::new() };
+        let header: Vec<&dyn Display> = header.iter().map(|name| name as &dyn Display).collect();
+        data.push_row(&header);
+
+        data
+    }
+
+    pub fn push_row(&mut self, values: &[&dyn Display]) {
+        for (i, value) in values.iter().enumerate() {
+            if i > 0 {
+                self.csv.push(',');
+            }
+
+            let start = self.csv.len();
+            let _ = write!(self.csv, "{value}");
+
+            // Quote the field if it would otherwise break the format
+            if self.csv[start..].contains([',', '"', '\n']) {
+                let field = self.csv.split_off(start).replace('"', "\"\"");
+                let _ = write!(self.csv, "\"{field}\"");
+            }
+        }
+
+        self.csv.push('\n');
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.csv.into_bytes()
+    }
+}
+
+/// ISO 8601 representation of the datetime in UTC.
+pub fn csv_datetime(datetime: OffsetDateTime) -> String {
+    datetime
+        .to_offset(UtcOffset::UTC)
+        .format(DATETIME_Z_FORMAT)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escaped_rows() {
+        let mut data = GraphData::new(&["date", "name"]);
+        data.push_row(&[&"2024-01-01", &"plain"]);
+        data.push_row(&[&20240102, &"a, \"quoted\" name"]);
+
+        let csv = String::from_utf8(data.into_bytes()).unwrap();
+
+        assert_eq!(
+            csv,
+            "date,name\n2024-01-01,plain\n20240102,\"a, \"\"quoted\"\" name\"\n"
+        );
+    }
+}
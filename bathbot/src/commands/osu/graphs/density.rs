@@ -0,0 +1,104 @@
+use bathbot_util::osu::effective_clock_rate;
+use eyre::Result;
+use rosu_pp::Beatmap;
+use rosu_v2::prelude::GameMods;
+
+use super::{
+    GraphData, GraphPalette, H, W,
+    render::{GraphAxes, GraphRenderer, LineGraph},
+};
+
+/// Length in seconds of the windows in which objects are counted.
+const WINDOW: f64 = 1.0;
+
+pub fn map_density_graph(
+    map: &Beatmap,
+    mods: &GameMods,
+    palette: GraphPalette,
+) -> Result<(Vec<u8>, GraphData)> {
+    let clock_rate = effective_clock_rate(mods);
+    let start_times = map.hit_objects.iter().map(|h| h.start_time);
+    let points = object_density(start_times, clock_rate);
+
+    let mut data = GraphData::new(&["timestamp_s", "objects_per_second"]);
+
+    for (timestamp, density) in points.iter() {
+        data.push_row(&[timestamp, density]);
+    }
+
+    let peak = points
+        .iter()
+        .map(|(_, density)| *density)
+        .fold(0.0, f64::max);
+    let len = points
+        .last()
+        .map_or(0.0, |(timestamp, _)| timestamp + WINDOW);
+    let average = map.hit_objects.len() as f64 / len.max(WINDOW);
+
+    let caption = format!("Objects per second (peak {peak:.0}, average {average:.2})");
+
+    let graph = LineGraph {
+        axes: GraphAxes {
+            caption,
+            x_desc: None,
+            y_desc: Some("Objects per second"),
+        },
+        points: &points,
+        x_labels: 10,
+        x_fmt: &|secs| {
+            let secs = *secs as u32;
+
+            format!("{}:{:02}", secs / 60, secs % 60)
+        },
+        y_fmt: &|density| format!("{density:.0}"),
+    };
+
+    graph.render(W, H, palette).map(|bytes| (bytes, data))
+}
+
+/// Amount of objects per second for consecutive windows of [`WINDOW`]
+/// seconds.
+///
+/// Start times are given in milliseconds of map time and are adjusted to the
+/// clock rate so the density is based on the time it takes to play the map.
+fn object_density(start_times: impl Iterator<Item = f64>, clock_rate: f64) -> Vec<(f64, f64)> {
+    let mut counts: Vec<u32> = Vec::new();
+
+    for start_time in start_times {
+        let secs = start_time / clock_rate / 1000.0;
+        let idx = (secs.max(0.0) / WINDOW) as usize;
+
+        if idx >= counts.len() {
+            counts.resize(idx + 1, 0);
+        }
+
+        counts[idx] += 1;
+    }
+
+    counts
+        .into_iter()
+        .enumerate()
+        .map(|(i, count)| (i as f64 * WINDOW, count as f64 / WINDOW))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn density_per_window() {
+        let start_times = [0.0, 500.0, 900.0, 2100.0, 2200.0];
+        let density = object_density(start_times.into_iter(), 1.0);
+
+        assert_eq!(density, vec![(0.0, 3.0), (1.0, 0.0), (2.0, 2.0)]);
+    }
+
+    #[test]
+    fn density_with_clock_rate() {
+        let start_times = [0.0, 1000.0, 1500.0, 2900.0];
+        let density = object_density(start_times.into_iter(), 1.5);
+
+        assert_eq!(density, vec![(0.0, 2.0), (1.0, 2.0)]);
+    }
+}
@@ -1,10 +1,11 @@
-use std::{borrow::Cow, cell::RefCell, mem, rc::Rc, time::Duration};
+use std::{borrow::Cow, cell::RefCell, fmt::Display, mem, rc::Rc, time::Duration};
 
 use bathbot_macros::command;
 use bathbot_model::command_fields::GameModeOption;
 use bathbot_util::{matcher, osu::MapIdType};
 use enterpolation::{Curve, linear::Linear};
 use eyre::{ContextCompat, Result, WrapErr};
+use image::DynamicImage;
 use plotters::{
     coord::{Shift, types::RangedCoordf64},
     prelude::*,
@@ -18,10 +19,13 @@ use rosu_v2::prelude::GameMods;
 use skia_safe::{BlendMode, EncodedImageFormat, surfaces};
 use twilight_model::{channel::Message, guild::Permissions};
 
-use super::{BitMapElement, Graph, GraphMapStrains, get_map_cover};
+use super::{BitMapElement, Graph, GraphData, GraphMapStrains, get_map_cover};
 use crate::{
-    core::commands::{CommandOrigin, prefix::Args},
-    util::{ChannelExt, osu::MapOrScore},
+    core::{
+        Context,
+        commands::{CommandOrigin, prefix::Args},
+    },
+    util::osu::MapOrScore,
 };
 
 impl<'m> GraphMapStrains<'m> {
@@ -32,20 +36,30 @@ impl<'m> GraphMapStrains<'m> {
     ) -> Result<Self, String> {
         let mut map = None;
         let mut mods = None;
+        let mut mods2 = None;
+        let mut combined = None;
 
         for arg in args {
-            if matcher::get_osu_map_id(arg)
+            if arg.eq_ignore_ascii_case("combined") {
+                combined = Some(true);
+            } else if matcher::get_osu_map_id(arg)
                 .map(MapIdType::Map)
                 .or_else(|| matcher::get_osu_mapset_id(arg).map(MapIdType::Set))
                 .is_some()
             {
                 map = Some(Cow::Borrowed(arg));
             } else if matcher::get_mods(arg).is_some() {
-                mods = Some(Cow::Borrowed(arg));
+                // A second mod combination is compared against the first
+                if mods.is_none() {
+                    mods = Some(Cow::Borrowed(arg));
+                } else {
+                    mods2 = Some(Cow::Borrowed(arg));
+                }
             } else {
                 let content = format!(
                     "Failed to parse `{arg}`.\n\
-                    Be sure you specify either a valid map id, map url, or mod combination."
+                    Be sure you specify either a valid map id, map url, mod combination, \
+                    or `combined`."
                 );
 
                 return Err(content);
@@ -62,14 +76,26 @@ impl<'m> GraphMapStrains<'m> {
             }
         }
 
-        Ok(Self { map, mods, mode })
+        Ok(Self {
+            map,
+            mods,
+            mods2,
+            mode,
+            file: None,
+            combined,
+            data: None,
+        })
     }
 }
 
 #[command]
 #[desc("Display a map's strains over time")]
-#[usage("[map url / id] [+mods]")]
-#[examples("240404 +hddt", "https://osu.ppy.sh/beatmapsets/902425 +hr")]
+#[usage("[map url / id] [+mods] [+mods to compare] [combined]")]
+#[examples(
+    "240404 +hddt",
+    "https://osu.ppy.sh/beatmapsets/902425 +hr combined",
+    "240404 +nm +dt"
+)]
 #[aliases("strains")]
 #[group(Osu)]
 async fn prefix_graphstrains(
@@ -81,18 +107,18 @@ async fn prefix_graphstrains(
         Ok(args) => {
             super::graph(CommandOrigin::from_msg(msg, perms), Graph::MapStrains(args)).await
         }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
 #[command]
 #[desc("Display a taiko map's strains over time")]
-#[usage("[map url / id] [+mods]")]
-#[examples("240404 +hddt", "https://osu.ppy.sh/beatmapsets/902425 +hr")]
+#[usage("[map url / id] [+mods] [+mods to compare] [combined]")]
+#[examples(
+    "240404 +hddt",
+    "https://osu.ppy.sh/beatmapsets/902425 +hr combined",
+    "240404 +nm +dt"
+)]
 #[aliases("strainstaiko")]
 #[group(Taiko)]
 async fn prefix_graphstrainstaiko(
@@ -104,18 +130,18 @@ async fn prefix_graphstrainstaiko(
         Ok(args) => {
             super::graph(CommandOrigin::from_msg(msg, perms), Graph::MapStrains(args)).await
         }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
 #[command]
 #[desc("Display a ctb map's strains over time")]
-#[usage("[map url / id] [+mods]")]
-#[examples("240404 +hddt", "https://osu.ppy.sh/beatmapsets/902425 +hr")]
+#[usage("[map url / id] [+mods] [+mods to compare] [combined]")]
+#[examples(
+    "240404 +hddt",
+    "https://osu.ppy.sh/beatmapsets/902425 +hr combined",
+    "240404 +nm +dt"
+)]
 #[aliases("strainsctb", "graphstrainscatch", "strainscatch")]
 #[group(Catch)]
 async fn prefix_graphstrainsctb(
@@ -127,18 +153,18 @@ async fn prefix_graphstrainsctb(
         Ok(args) => {
             super::graph(CommandOrigin::from_msg(msg, perms), Graph::MapStrains(args)).await
         }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
 #[command]
 #[desc("Display a mania map's strains over time")]
-#[usage("[map url / id] [+mods]")]
-#[examples("240404 +hddt", "https://osu.ppy.sh/beatmapsets/902425 +hr")]
+#[usage("[map url / id] [+mods] [+mods to compare] [combined]")]
+#[examples(
+    "240404 +hddt",
+    "https://osu.ppy.sh/beatmapsets/902425 +hr combined",
+    "240404 +nm +dt"
+)]
 #[aliases("strainsmania")]
 #[group(Mania)]
 async fn prefix_graphstrainsmania(
@@ -150,68 +176,150 @@ async fn prefix_graphstrainsmania(
         Ok(args) => {
             super::graph(CommandOrigin::from_msg(msg, perms), Graph::MapStrains(args)).await
         }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
 const LEGEND_H: u32 = 25;
 
+type StrainsChart<'a, 'b> =
+    ChartContext<'a, SkiaBackend<'b>, Cartesian2d<RangedCoordf64, RangedCoordf64>>;
+
 pub async fn map_strains_graph(
     map: &Beatmap,
     mods: GameMods,
-    cover_url: &str,
+    cover_url: Option<&str>,
     w: u32,
     h: u32,
 ) -> Result<Vec<u8>> {
-    let strains = GraphStrains::new(map, mods)?;
+    map_strains_graph_with_data(None, map, mods, cover_url, w, h, false)
+        .await
+        .map(|(bytes, _)| bytes)
+}
+
+/// Same as [`map_strains_graph`] but also provides the drawn strain values.
+///
+/// If `combined` is set, all skills are merged into a single curve. If `orig`
+/// is given, the drawing waits for a render slot of the invoker.
+pub(super) async fn map_strains_graph_with_data(
+    orig: Option<&CommandOrigin<'_>>,
+    map: &Beatmap,
+    mods: GameMods,
+    cover_url: Option<&str>,
+    w: u32,
+    h: u32,
+    combined: bool,
+) -> Result<(Vec<u8>, GraphData)> {
+    let mut strains = GraphStrains::new(map, mods)?;
+
+    if combined {
+        strains.combine();
+    }
+
+    let data = strains.data();
+    let last_timestamp = strains.last_timestamp();
+    let max_strain = strains.max_strain();
     let cover_res = get_map_cover(cover_url, w, h).await;
 
-    let last_timestamp = ((NEW_STRAIN_COUNT - 2) as f64
-        * strains.strains.section_len()
-        * strains.strains_count as f64)
-        / NEW_STRAIN_COUNT as f64;
-
-    let max_strain = match &strains.strains {
-        Strains::Osu(OsuStrains {
-            aim,
-            aim_no_sliders,
-            speed,
-            flashlight,
-        }) => aim
-            .iter()
-            .zip(aim_no_sliders)
-            .zip(speed)
-            .zip(flashlight)
-            .fold(0.0_f64, |max, (((a, b), c), d)| {
-                max.max(*a).max(*b).max(*c).max(*d)
-            }),
-        Strains::Taiko(TaikoStrains {
-            color,
-            reading,
-            rhythm,
-            stamina,
-            single_color_stamina,
-        }) => color
-            .iter()
-            .zip(rhythm)
-            .zip(stamina)
-            .zip(single_color_stamina)
-            .zip(reading)
-            .fold(0.0_f64, |max, ((((a, b), c), d), e)| {
-                max.max(*a).max(*b).max(*c).max(*d).max(*e)
-            }),
-        Strains::Catch(CatchStrains { movement }) => movement
-            .iter()
-            .fold(0.0_f64, |max, strain| max.max(*strain)),
-        Strains::Mania(ManiaStrains { strains }) => {
-            strains.iter().fold(0.0_f64, |max, strain| max.max(*strain))
-        }
+    let _permit = match orig {
+        Some(orig) => Some(Context::render_queue().acquire(orig).await?),
+        None => None,
     };
 
+    draw_graph(
+        cover_res,
+        w,
+        h,
+        last_timestamp,
+        max_strain,
+        |backend, chart, legend_area, text_style| {
+            draw_mode_strains(backend, chart, strains, legend_area, text_style)
+        },
+    )
+    .map(|bytes| (bytes, data))
+}
+
+/// Draw the combined strains of two mod combinations on the same axes.
+///
+/// Timestamps are in map time so that sections of both curves line up even
+/// if the mods have different clock rates.
+pub async fn map_strains_comparison_graph(
+    orig: &CommandOrigin<'_>,
+    map: &Beatmap,
+    mods: [GameMods; 2],
+    cover_url: Option<&str>,
+    w: u32,
+    h: u32,
+) -> Result<(Vec<u8>, GraphData)> {
+    let [first, second] = mods.map(|mods| ComparisonCurve::new(map, mods));
+    let (first, second) = (first?, second?);
+
+    let mut data = GraphData::new(&["mods", "timestamp_ms", "strain"]);
+
+    for curve in [&first, &second] {
+        for (i, strain) in curve.strains.iter().enumerate() {
+            data.push_row(&[&curve.label, &(i as f64 * curve.factor), strain]);
+        }
+    }
+
+    let last_timestamp = first.last_timestamp.max(second.last_timestamp);
+
+    let max_strain = first
+        .strains
+        .iter()
+        .chain(second.strains.iter())
+        .fold(0.0_f64, |max, strain| max.max(*strain));
+
+    let cover_res = get_map_cover(cover_url, w, h).await;
+
+    // Only the drawing itself waits in the render queue
+    let _permit = Context::render_queue().acquire(orig).await?;
+
+    draw_graph(
+        cover_res,
+        w,
+        h,
+        last_timestamp,
+        max_strain,
+        |backend, chart, legend_area, text_style| {
+            let mut legend_x: i32 = 8;
+
+            for (curve, color) in [(first, CYAN), (second, RED)] {
+                let ComparisonCurve {
+                    label,
+                    strains,
+                    factor,
+                    last_timestamp: _,
+                } = curve;
+
+                draw_series(backend, chart, &strains, &label, factor, color)?;
+                draw_legend(legend_area, &label, color, text_style, &mut legend_x)?;
+            }
+
+            Ok(())
+        },
+    )
+    .map(|bytes| (bytes, data))
+}
+
+/// Draw the mapset cover as background, the mesh, and then let `draw_strains`
+/// handle the strain curves and legend.
+fn draw_graph<F>(
+    cover_res: Result<Option<DynamicImage>>,
+    w: u32,
+    h: u32,
+    last_timestamp: f64,
+    max_strain: f64,
+    draw_strains: F,
+) -> Result<Vec<u8>>
+where
+    F: FnOnce(
+        &Rc<RefCell<SkiaBackend<'_>>>,
+        &mut StrainsChart<'_, '_>,
+        &DrawingArea<SkiaBackend<'_>, Shift>,
+        &TextStyle<'_>,
+    ) -> Result<()>,
+{
     if max_strain <= f64::EPSILON {
         bail!("no non-zero strain point");
     }
@@ -225,7 +333,7 @@ pub async fn map_strains_graph(
 
         // Add background
         match cover_res {
-            Ok(background) => {
+            Ok(Some(background)) => {
                 let background = background.blur(2.0);
                 let elem = BitMapElement::new(background, (0, 0));
                 root.draw(&elem).wrap_err("Failed to draw background")?;
@@ -234,6 +342,10 @@ pub async fn map_strains_graph(
                 root.draw(&rect)
                     .wrap_err("Failed to draw darkening rectangle")?;
             }
+            Ok(None) => {
+                root.fill(&RGBColor(19, 43, 33))
+                    .wrap_err("Failed to fill background")?;
+            }
             Err(err) => {
                 warn!(?err, "Failed to get mapset cover");
 
@@ -276,7 +388,7 @@ pub async fn map_strains_graph(
             .draw()
             .wrap_err("Failed to draw mesh")?;
 
-        draw_mode_strains(&backend, &mut chart, strains, &legend_area, &text_style)?;
+        draw_strains(&backend, &mut chart, &legend_area, &text_style)?;
     }
 
     let png_bytes = surface
@@ -290,105 +402,32 @@ pub async fn map_strains_graph(
 
 fn draw_mode_strains(
     backend: &Rc<RefCell<SkiaBackend<'_>>>,
-    chart: &mut ChartContext<'_, SkiaBackend<'_>, Cartesian2d<RangedCoordf64, RangedCoordf64>>,
+    chart: &mut StrainsChart<'_, '_>,
     strains: GraphStrains,
     legend_area: &DrawingArea<SkiaBackend<'_>, Shift>,
     text_style: &TextStyle<'_>,
 ) -> Result<()> {
+    let factor = strains.factor();
+
     let GraphStrains {
         strains,
-        strains_count,
+        strains_count: _,
+        combined,
     } = strains;
 
-    let orig_count = strains_count as f64;
-
-    let new_count = match strains {
-        Strains::Osu(ref strains) => strains.aim.len(),
-        Strains::Taiko(ref strains) => strains.color.len(),
-        Strains::Catch(ref strains) => strains.movement.len(),
-        Strains::Mania(ref strains) => strains.strains.len(),
-    } as f64;
-
-    let section_len = strains.section_len();
-
     let mut legend_x: i32 = 8;
 
-    let factor = section_len * orig_count / new_count;
-
     macro_rules! draw_line {
         ( $label:literal, $strains:expr, $color:ident ) => {{
             draw_series(backend, chart, &$strains, $label, factor, $color)?;
-            draw_line(legend_area, $label, $color, text_style, &mut legend_x)?;
+            draw_legend(legend_area, $label, $color, text_style, &mut legend_x)?;
         }};
     }
 
-    fn draw_series(
-        backend: &Rc<RefCell<SkiaBackend<'_>>>,
-        chart: &mut ChartContext<'_, SkiaBackend<'_>, Cartesian2d<RangedCoordf64, RangedCoordf64>>,
-        strains: &[f64],
-        label: &str,
-        factor: f64,
-        color: RGBColor,
-    ) -> Result<()> {
-        backend
-            .borrow_mut()
-            .set_blend_mode(Some(BlendMode::Lighten));
-
-        let timestamp_iter = strains
-            .iter()
-            .enumerate()
-            .map(move |(i, strain)| (i as f64 * factor, *strain));
-
-        let series = AreaSeries::new(timestamp_iter, 0.0, color.mix(0.20))
-            .border_style(color.stroke_width(2));
-
-        chart
-            .draw_series(series)
-            .wrap_err_with(|| format!("Failed to draw {label} series"))?;
-
-        backend.borrow_mut().set_blend_mode(None);
-
-        Ok(())
-    }
-
-    fn draw_line(
-        legend_area: &DrawingArea<SkiaBackend<'_>, Shift>,
-        label: &str,
-        color: RGBColor,
-        text_style: &TextStyle<'_>,
-        legend_x: &mut i32,
-    ) -> Result<()> {
-        let rect = Rectangle::new(
-            [
-                (*legend_x, (LEGEND_H as f32 * 0.42) as i32),
-                (*legend_x + 16, (LEGEND_H as f32 * 0.58) as i32),
-            ],
-            color.filled(),
-        );
-
-        legend_area
-            .draw(&rect)
-            .wrap_err("Failed to draw legend rectangle")?;
-
-        *legend_x += 26;
-
-        let ((min_x, min_y), (max_x, max_y)) = text_style
-            .font
-            .layout_box(label)
-            .wrap_err("Failed to get legend layout box")?;
-
-        let width = max_x - min_x;
-        let height = max_y - min_y;
-
-        let text_pos = (*legend_x, (LEGEND_H as i32 - 8 - height));
-
-        legend_area
-            .draw_text(label, text_style, text_pos)
-            .wrap_err("Failed to draw legend text")?;
-
-        *legend_x += width + 10;
+    if let Some(combined) = combined {
+        draw_line!("Strain", combined, CYAN);
 
-        Ok(())
+        return Ok(());
     }
 
     match strains {
@@ -412,6 +451,106 @@ fn draw_mode_strains(
     Ok(())
 }
 
+fn draw_series(
+    backend: &Rc<RefCell<SkiaBackend<'_>>>,
+    chart: &mut StrainsChart<'_, '_>,
+    strains: &[f64],
+    label: &str,
+    factor: f64,
+    color: RGBColor,
+) -> Result<()> {
+    backend
+        .borrow_mut()
+        .set_blend_mode(Some(BlendMode::Lighten));
+
+    let timestamp_iter = strains
+        .iter()
+        .enumerate()
+        .map(move |(i, strain)| (i as f64 * factor, *strain));
+
+    let series =
+        AreaSeries::new(timestamp_iter, 0.0, color.mix(0.20)).border_style(color.stroke_width(2));
+
+    chart
+        .draw_series(series)
+        .wrap_err_with(|| format!("Failed to draw {label} series"))?;
+
+    backend.borrow_mut().set_blend_mode(None);
+
+    Ok(())
+}
+
+fn draw_legend(
+    legend_area: &DrawingArea<SkiaBackend<'_>, Shift>,
+    label: &str,
+    color: RGBColor,
+    text_style: &TextStyle<'_>,
+    legend_x: &mut i32,
+) -> Result<()> {
+    let rect = Rectangle::new(
+        [
+            (*legend_x, (LEGEND_H as f32 * 0.42) as i32),
+            (*legend_x + 16, (LEGEND_H as f32 * 0.58) as i32),
+        ],
+        color.filled(),
+    );
+
+    legend_area
+        .draw(&rect)
+        .wrap_err("Failed to draw legend rectangle")?;
+
+    *legend_x += 26;
+
+    let ((min_x, min_y), (max_x, max_y)) = text_style
+        .font
+        .layout_box(label)
+        .wrap_err("Failed to get legend layout box")?;
+
+    let width = max_x - min_x;
+    let height = max_y - min_y;
+
+    let text_pos = (*legend_x, (LEGEND_H as i32 - 8 - height));
+
+    legend_area
+        .draw_text(label, text_style, text_pos)
+        .wrap_err("Failed to draw legend text")?;
+
+    *legend_x += width + 10;
+
+    Ok(())
+}
+
+/// Combined strains of a mod combination, scaled to map time.
+struct ComparisonCurve {
+    label: String,
+    strains: Vec<f64>,
+    factor: f64,
+    last_timestamp: f64,
+}
+
+impl ComparisonCurve {
+    fn new(map: &Beatmap, mods: GameMods) -> Result<Self> {
+        let label = if mods.is_empty() {
+            "NM".to_owned()
+        } else {
+            mods.to_string()
+        };
+
+        // Must match the rate rosu-pp used for the strain timestamps
+        let clock_rate = mods.clock_rate().unwrap_or(1.0);
+        let strains = GraphStrains::new(map, mods)?;
+        let factor = strains.factor() * clock_rate;
+        let last_timestamp = strains.last_timestamp() * clock_rate;
+
+        Ok(Self {
+            label,
+            strains: strains.into_total(),
+            factor,
+            last_timestamp,
+        })
+    }
+}
+
 const NEW_STRAIN_COUNT: usize = 200;
 
 struct GraphStrains {
@@ -419,6 +558,8 @@ struct GraphStrains {
     strains: Strains,
     /// The initial amount of strains
     strains_count: usize,
+    /// Sum of all skill strains if they should not be drawn individually
+    combined: Option<Vec<f64>>,
 }
 
 impl GraphStrains {
@@ -494,6 +635,168 @@ impl GraphStrains {
         Ok(Self {
             strains,
             strains_count,
+            combined: None,
         })
     }
+
+    fn last_timestamp(&self) -> f64 {
+        ((NEW_STRAIN_COUNT - 2) as f64 * self.strains.section_len() * self.strains_count as f64)
+            / NEW_STRAIN_COUNT as f64
+    }
+
+    /// Distance between two smoothed strain values
+    fn factor(&self) -> f64 {
+        let new_count = match self.strains {
+            Strains::Osu(ref strains) => strains.aim.len(),
+            Strains::Taiko(ref strains) => strains.color.len(),
+            Strains::Catch(ref strains) => strains.movement.len(),
+            Strains::Mania(ref strains) => strains.strains.len(),
+        } as f64;
+
+        self.strains.section_len() * self.strains_count as f64 / new_count
+    }
+
+    fn max_strain(&self) -> f64 {
+        match (&self.combined, &self.strains) {
+            (Some(combined), _) => combined
+                .iter()
+                .fold(0.0_f64, |max, strain| max.max(*strain)),
+            (
+                None,
+                Strains::Osu(OsuStrains {
+                    aim,
+                    aim_no_sliders,
+                    speed,
+                    flashlight,
+                }),
+            ) => aim
+                .iter()
+                .zip(aim_no_sliders)
+                .zip(speed)
+                .zip(flashlight)
+                .fold(0.0_f64, |max, (((a, b), c), d)| {
+                    max.max(*a).max(*b).max(*c).max(*d)
+                }),
+            (
+                None,
+                Strains::Taiko(TaikoStrains {
+                    color,
+                    reading,
+                    rhythm,
+                    stamina,
+                    single_color_stamina,
+                }),
+            ) => color
+                .iter()
+                .zip(rhythm)
+                .zip(stamina)
+                .zip(single_color_stamina)
+                .zip(reading)
+                .fold(0.0_f64, |max, ((((a, b), c), d), e)| {
+                    max.max(*a).max(*b).max(*c).max(*d).max(*e)
+                }),
+            (None, Strains::Catch(CatchStrains { movement })) => movement
+                .iter()
+                .fold(0.0_f64, |max, strain| max.max(*strain)),
+            (None, Strains::Mania(ManiaStrains { strains })) => {
+                strains.iter().fold(0.0_f64, |max, strain| max.max(*strain))
+            }
+        }
+    }
+
+    /// Timestamps alongside the value of each strain curve that is drawn.
+    fn data(&self) -> GraphData {
+        let curves: Vec<(&str, &[f64])> = match (&self.combined, &self.strains) {
+            (Some(combined), _) => vec![("strain", combined.as_slice())],
+            (None, Strains::Osu(strains)) => vec![
+                ("aim", strains.aim.as_slice()),
+                ("aim_sliders", strains.aim_no_sliders.as_slice()),
+                ("speed", strains.speed.as_slice()),
+                ("flashlight", strains.flashlight.as_slice()),
+            ],
+            (None, Strains::Taiko(strains)) => vec![
+                ("stamina", strains.stamina.as_slice()),
+                (
+                    "stamina_single_color",
+                    strains.single_color_stamina.as_slice(),
+                ),
+                ("color", strains.color.as_slice()),
+                ("rhythm", strains.rhythm.as_slice()),
+                ("reading", strains.reading.as_slice()),
+            ],
+            (None, Strains::Catch(strains)) => vec![("movement", strains.movement.as_slice())],
+            (None, Strains::Mania(strains)) => vec![("strain", strains.strains.as_slice())],
+        };
+
+        let header: Vec<_> = ["timestamp_ms"]
+            .into_iter()
+            .chain(curves.iter().map(|(name, _)| *name))
+            .collect();
+
+        let mut data = GraphData::new(&header);
+        let factor = self.factor();
+        let len = curves
+            .iter()
+            .map(|(_, curve)| curve.len())
+            .min()
+            .unwrap_or(0);
+
+        for i in 0..len {
+            let timestamp = i as f64 * factor;
+
+            let row: Vec<&dyn Display> = [&timestamp as &dyn Display]
+                .into_iter()
+                .chain(curves.iter().map(|(_, curve)| &curve[i] as &dyn Display))
+                .collect();
+
+            data.push_row(&row);
+        }
+
+        data
+    }
+
+    /// A single strain curve regardless of the mode.
+    fn into_total(mut self) -> Vec<f64> {
+        self.combine();
+
+        if let Some(combined) = self.combined {
+            return combined;
+        }
+
+        match self.strains {
+            Strains::Catch(strains) => strains.movement,
+            Strains::Mania(strains) => strains.strains,
+            // Combined strains are always available for these modes
+            Strains::Osu(_) | Strains::Taiko(_) => Vec::new(),
+        }
+    }
+
+    /// Merge all skills into a single strain curve.
+    ///
+    /// Modes with only one skill are kept as is.
+    fn combine(&mut self) {
+        fn sum(skills: &[&[f64]]) -> Vec<f64> {
+            let len = skills.iter().map(|skill| skill.len()).min().unwrap_or(0);
+
+            (0..len)
+                .map(|i| skills.iter().map(|skill| skill[i]).sum())
+                .collect()
+        }
+
+        self.combined = match self.strains {
+            // `aim_no_sliders` only contains the slider part of `aim` at
+            // this point so it must not be added again
+            Strains::Osu(ref strains) => {
+                Some(sum(&[&strains.aim, &strains.speed, &strains.flashlight]))
+            }
+            // `single_color_stamina` is just a variant of `stamina`
+            Strains::Taiko(ref strains) => Some(sum(&[
+                &strains.color,
+                &strains.reading,
+                &strains.rhythm,
+                &strains.stamina,
+            ])),
+            Strains::Catch(_) | Strains::Mania(_) => None,
+        };
+    }
 }
@@ -9,7 +9,7 @@ use rkyv::{
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
 use twilight_model::guild::Permissions;
 
-use super::{Graph, GraphMedals, H, W};
+use super::{Graph, GraphData, GraphMedals, GraphPalette, H, W, csv_datetime, draw_queued};
 use crate::{
     commands::osu::{graphs::GRAPH_MEDALS_DESC, medals::stats as medals_stats, user_not_found},
     core::{
@@ -32,7 +32,12 @@ impl<'m> GraphMedals<'m> {
             }
         }
 
-        Self { name, discord }
+        Self {
+            name,
+            discord,
+            theme: None,
+            data: None,
+        }
     }
 }
 
@@ -55,7 +60,8 @@ async fn prefix_graphmedals(
 pub async fn medals_graph(
     orig: &CommandOrigin<'_>,
     user_id: UserId,
-) -> Result<Option<(CachedUser, Vec<u8>)>> {
+    palette: GraphPalette,
+) -> Result<Option<(CachedUser, Vec<u8>, GraphData)>> {
     let user_args = UserArgs::rosu_id(&user_id, GameMode::Osu).await;
 
     let user = match Context::redis().osu_user(user_args).await {
@@ -82,7 +88,13 @@ pub async fn medals_graph(
 
     medals.sort_unstable_by_key(|medal| medal.achieved_at);
 
-    let bytes = match medals_stats::graph(&medals, W, H) {
+    let mut data = GraphData::new(&["date", "medal_id", "medal_count"]);
+
+    for (i, medal) in medals.iter().enumerate() {
+        data.push_row(&[&csv_datetime(medal.achieved_at), &medal.medal_id, &(i + 1)]);
+    }
+
+    let bytes = match draw_queued(orig, || medals_stats::graph(&medals, W, H, palette)).await {
         Ok(Some(graph)) => graph,
         Ok(None) => {
             let content = format!("`{}` does not have any medals", user.username.as_str());
@@ -99,5 +111,5 @@ pub async fn medals_graph(
         }
     };
 
-    Ok(Some((user, bytes)))
+    Ok(Some((user, bytes, data)))
 }
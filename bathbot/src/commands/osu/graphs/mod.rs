@@ -1,13 +1,13 @@
-use std::{borrow::Cow, iter, ops::ControlFlow};
+use std::{borrow::Cow, collections::HashMap, iter, ops::ControlFlow};
 
 use bathbot_macros::{HasMods, HasName, SlashCommand, command};
 use bathbot_model::{
     Countries,
     command_fields::{GameModeOption, ShowHideOption, TimezoneOption},
 };
-use bathbot_psql::model::configs::ScoreData;
+use bathbot_psql::model::configs::{GraphTheme, ScoreData};
 use bathbot_util::{
-    AuthorBuilder, CowUtils, EmbedBuilder, FooterBuilder, MessageBuilder, attachment,
+    AuthorBuilder, CowUtils, EmbedBuilder, FooterBuilder, IntHasher, MessageBuilder, attachment,
     constants::{GENERAL_ISSUE, OSU_BASE},
     matcher,
     osu::{MapIdType, ModSelection, ModsResult},
@@ -17,38 +17,59 @@ use image::{DynamicImage, GenericImageView, RgbaImage};
 use plotters::element::{Drawable, PointCollection};
 use plotters_backend::{BackendCoord, DrawingBackend, DrawingErrorKind};
 use plotters_skia::SkiaBackend;
+use rosu_pp::Beatmap;
 use rosu_v2::{
     prelude::{GameMode, GameMods, OsuError},
     request::UserId,
 };
 use time::UtcOffset;
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
-use twilight_model::id::{
-    Id,
-    marker::{ChannelMarker, UserMarker},
+use twilight_model::{
+    channel::Attachment,
+    id::{
+        Id,
+        marker::{ChannelMarker, UserMarker},
+    },
 };
 
-pub use self::map_strains::map_strains_graph;
 use self::{
-    bpm::map_bpm_graph,
+    bpm::{BpmDifficulty, MAX_OTHER_DIFFS, map_bpm_graph},
+    compare_top::compare_top_graph,
+    completion::map_completion,
+    data::{GraphData, csv_datetime},
+    density::map_density_graph,
+    map_strains::map_strains_graph_with_data,
     medals::medals_graph,
     osutrack::osutrack_graph,
     playcount_replays::{ProfileGraphFlags, playcount_replays_graph},
+    playtime::playtime_graph,
+    pp_distribution::pp_distribution_graph,
     rank::rank_graph,
+    ranking_history::map_ranking_history,
     score_rank::score_rank_graph,
+    sessions::sessions_graph,
     snipe_count::snipe_count_graph,
+    snipe_gains_losses::snipe_gains_losses_graph,
     sniped::sniped_graph,
     top_date::top_graph_date,
     top_index::top_graph_index,
+    top_spread::{top_graph_acc, top_graph_stars},
     top_time::{top_graph_time_day, top_graph_time_hour},
 };
+pub use self::{
+    map_strains::{map_strains_comparison_graph, map_strains_graph},
+    rank::{draw_rank_graph, rank_graph_range},
+    render::{GraphArea, GraphAxes, GraphRenderer, Histogram, LineGraph, PieChart, ScatterGraph},
+    theme::GraphPalette,
+};
 use super::{SnipeGameMode, UserIdResult, require_link, user_not_found};
 use crate::{
+    active::{ActiveMessages, impls::RankGraphPagination},
     commands::{
         DISCORD_OPTION_DESC, DISCORD_OPTION_HELP,
-        osu::{HasMods, HasName as HasNameTrait},
+        osu::{HasMods, HasName as HasNameTrait, parsed_map::parse_attached_map},
     },
-    core::{Context, commands::CommandOrigin},
+    core::{BotMetrics, Context, commands::CommandOrigin},
     manager::{
         MapError, OsuMap,
         redis::osu::{CachedUser, UserArgs, UserArgsError},
@@ -56,17 +77,31 @@ use crate::{
     util::{CachedUserExt, InteractionCommandExt, interaction::InteractionCommand},
 };
 
+mod animation;
 mod bpm;
+mod compare_top;
+mod completion;
+mod data;
+mod density;
 mod map_strains;
 mod medals;
 mod osutrack;
 mod playcount_replays;
+mod playtime;
+mod pp_distribution;
 mod rank;
+mod ranking_history;
+mod render;
 mod score_rank;
+mod sessions;
 mod snipe_count;
+mod snipe_gains_losses;
 mod sniped;
+mod theme;
+mod top;
 mod top_date;
 mod top_index;
+mod top_spread;
 mod top_time;
 
 #[derive(CommandModel, CreateCommand, SlashCommand)]
@@ -74,6 +109,12 @@ mod top_time;
 pub enum Graph<'a> {
     #[command(name = "bpm")]
     MapBpm(GraphMapBpm<'a>),
+    #[command(name = "compare_top")]
+    CompareTop(GraphCompareTop<'a>),
+    #[command(name = "completion")]
+    MapCompletion(GraphMapCompletion<'a>),
+    #[command(name = "density")]
+    MapDensity(GraphMapDensity<'a>),
     #[command(name = "strains")]
     MapStrains(GraphMapStrains<'a>),
     #[command(name = "medals")]
@@ -82,18 +123,40 @@ pub enum Graph<'a> {
     OsuTrack(GraphOsuTrack),
     #[command(name = "playcount_replays")]
     PlaycountReplays(GraphPlaycountReplays<'a>),
+    #[command(name = "playtime")]
+    Playtime(GraphPlaytime<'a>),
+    #[command(name = "pp_distribution")]
+    PpDistribution(GraphPpDistribution<'a>),
     #[command(name = "rank")]
     Rank(GraphRank<'a>),
+    #[command(name = "ranking_history")]
+    MapRankingHistory(GraphMapRankingHistory<'a>),
     #[command(name = "score_rank")]
     ScoreRank(GraphScoreRank<'a>),
+    #[command(name = "sessions")]
+    Sessions(GraphSessions<'a>),
     #[command(name = "sniped")]
     Sniped(GraphSniped<'a>),
     #[command(name = "snipe_count")]
     SnipeCount(GraphSnipeCount<'a>),
+    #[command(name = "snipe_gains_losses")]
+    SnipeGainsLosses(GraphSnipeGainsLosses<'a>),
     #[command(name = "top")]
     Top(GraphTop),
 }
 
+const GRAPH_THEME_DESC: &str = "Specify a color theme, defaults to your config";
+
+const GRAPH_ANIMATE_DESC: &str = "Whether the graph should be an animated gif";
+
+const GRAPH_ANIMATE_HELP: &str = "Whether the graph should be an animated gif.\n\
+The animation progressively reveals the curve. Note that it takes a few seconds longer to create.";
+
+const GRAPH_DATA_DESC: &str = "Whether the graph's data should be attached as csv file";
+
+const GRAPH_DATA_HELP: &str = "Whether the graph's data should be attached as csv file.\n\
+The file contains the raw data series so you can create your own charts with it.";
+
 const GRAPH_BPM_DESC: &str = "Display a map's bpm over time";
 
 #[derive(CommandModel, CreateCommand, HasMods)]
@@ -111,6 +174,126 @@ pub struct GraphMapBpm<'a> {
         help = "Specify mods either directly or through the explicit `+mods!` / `+mods` syntax e.g. `hdhr` or `+hdhr!`"
     )]
     mods: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a .osu file")]
+    file: Option<Attachment>,
+    #[command(
+        desc = "Whether all difficulties of the mapset should be drawn",
+        help = "Draw the bpm of all other difficulties of the mapset on top.\n\
+        If a mapset id is given, the difficulty with the most objects is picked as main map."
+    )]
+    all_diffs: Option<bool>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "compare_top",
+    desc = "Compare the top scores pp of two users",
+    help = "Compare the top scores pp of two users.\n\
+    Both top200 lists are drawn by index on the same chart \
+    and the legend shows where the two curves intersect."
+)]
+pub struct GraphCompareTop<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name1: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a username")]
+    name2: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify a linked discord user",
+        help = "Instead of specifying an osu! username with the `name1` option, \
+        you can use this option to choose a discord user.\n\
+        Only works on users who have used the `/link` command."
+    )]
+    discord1: Option<Id<UserMarker>>,
+    #[command(desc = "Specify a linked discord user")]
+    discord2: Option<Id<UserMarker>>,
+    #[command(desc = GRAPH_THEME_DESC)]
+    theme: Option<GraphTheme>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "completion",
+    desc = "Display how far players get into a map before failing",
+    help = "Display the pass rate of a map and how far players get into it before failing.\n\
+    The data consists of all recent scores I've seen through commands like `/recent`, \
+    so it's neither complete nor up-to-date but it indicates where the map's difficulty spikes are.\n\
+    Not available for osu!ctb."
+)]
+pub struct GraphMapCompletion<'a> {
+    #[command(
+        desc = "Specify a map url or map id",
+        help = "Specify a map either by map url or map id.\n\
+        If none is specified, it will search in the recent channel history \
+        and pick the first map it can find."
+    )]
+    map: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = GRAPH_THEME_DESC)]
+    theme: Option<GraphTheme>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
+}
+
+#[derive(CommandModel, CreateCommand, HasMods)]
+#[command(
+    name = "density",
+    desc = "Display a map's objects per second over time",
+    help = "Display a map's objects per second over time.\n\
+    Objects are counted within each second of playtime so speed-changing mods \
+    like DT or HT affect the density."
+)]
+pub struct GraphMapDensity<'a> {
+    #[command(
+        desc = "Specify a map url or map id",
+        help = "Specify a map either by map url or map id.\n\
+        If none is specified, it will search in the recent channel history \
+        and pick the first map it can find."
+    )]
+    map: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify mods e.g. hdhr or nm",
+        help = "Specify mods either directly or through the explicit `+mods!` / `+mods` syntax e.g. `hdhr` or `+hdhr!`"
+    )]
+    mods: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a .osu file")]
+    file: Option<Attachment>,
+    #[command(desc = GRAPH_THEME_DESC)]
+    theme: Option<GraphTheme>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "ranking_history",
+    desc = "Display the pp of a map's top scores by the date they were set",
+    help = "Display the pp of a map's top 50 global leaderboard scores by the date they were set.\n\
+    Shows how the leaderboard developed over time and how much newer scores power-crept \
+    older ones. The current #1 score is highlighted."
+)]
+pub struct GraphMapRankingHistory<'a> {
+    #[command(
+        desc = "Specify a map url or map id",
+        help = "Specify a map either by map url or map id.\n\
+        If none is specified, it will search in the recent channel history \
+        and pick the first map it can find."
+    )]
+    map: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = GRAPH_THEME_DESC)]
+    theme: Option<GraphTheme>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
 }
 
 #[derive(CommandModel, CreateCommand, HasMods)]
@@ -128,8 +311,26 @@ pub struct GraphMapStrains<'a> {
         help = "Specify mods either directly or through the explicit `+mods!` / `+mods` syntax e.g. `hdhr` or `+hdhr!`"
     )]
     mods: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify a second mod combination to compare against e.g. dt",
+        help = "Specify a second mod combination e.g. `dt`.\n\
+        If specified, the combined strains of both mod combinations are drawn on the same axes \
+        so you can compare how e.g. rate changes affect difficulty spikes.\n\
+        Timestamps are in map time so both curves line up."
+    )]
+    mods2: Option<Cow<'a, str>>,
     #[command(desc = "Specify a gamemode")]
     mode: Option<GameModeOption>,
+    #[command(desc = "Specify a .osu file")]
+    file: Option<Attachment>,
+    #[command(
+        desc = "Whether all skills should be merged into one strain curve",
+        help = "If enabled, skills such as aim, speed, or rhythm will be summed up \
+        into a single curve instead of drawing each skill as its own curve."
+    )]
+    combined: Option<bool>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
 }
 
 const GRAPH_MEDALS_DESC: &str = "Display a user's medal progress over time";
@@ -141,6 +342,10 @@ pub struct GraphMedals<'a> {
     name: Option<Cow<'a, str>>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = GRAPH_THEME_DESC)]
+    theme: Option<GraphTheme>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
 }
 
 #[derive(CommandModel, CreateCommand)]
@@ -172,6 +377,10 @@ pub struct GraphOsuTrackPpRank {
     name: Option<String>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = GRAPH_ANIMATE_DESC, help = GRAPH_ANIMATE_HELP)]
+    animate: Option<bool>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
 }
 
 #[derive(CommandModel, CreateCommand, HasName)]
@@ -186,6 +395,8 @@ pub struct GraphOsuTrackScore {
     name: Option<String>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
 }
 
 #[derive(CommandModel, CreateCommand, HasName)]
@@ -200,6 +411,8 @@ pub struct GraphOsuTrackHitRatios {
     name: Option<String>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
 }
 
 #[derive(CommandModel, CreateCommand, HasName)]
@@ -214,6 +427,8 @@ pub struct GraphOsuTrackPlaycount {
     name: Option<String>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
 }
 
 #[derive(CommandModel, CreateCommand, HasName)]
@@ -225,6 +440,8 @@ pub struct GraphOsuTrackAccuracy {
     name: Option<String>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
 }
 
 #[derive(CommandModel, CreateCommand, HasName)]
@@ -236,6 +453,8 @@ pub struct GraphOsuTrackGrades {
     name: Option<String>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
 }
 
 const GRAPH_PLAYCOUNT_DESC: &str = "Display a user's playcount and replays watched over time";
@@ -253,6 +472,50 @@ pub struct GraphPlaycountReplays<'a> {
     replays: Option<ShowHideOption>,
     #[command(desc = "Specify if the badges should be included")]
     badges: Option<ShowHideOption>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
+}
+
+const GRAPH_PLAYTIME_DESC: &str = "Display a user's estimated playtime per month";
+
+#[derive(CommandModel, CreateCommand, HasName)]
+#[command(
+    name = "playtime",
+    desc = GRAPH_PLAYTIME_DESC,
+    help = "Display a user's estimated playtime per month.\n\
+    The playtime is estimated by multiplying the monthly playcount \
+    with the average map length of the user's recent plays."
+)]
+pub struct GraphPlaytime<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
+    discord: Option<Id<UserMarker>>,
+    #[command(desc = "Only show the playtime of this year", min_value = 2007)]
+    year: Option<i32>,
+    #[command(desc = GRAPH_THEME_DESC)]
+    theme: Option<GraphTheme>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
+}
+
+const GRAPH_PP_DISTRIBUTION_DESC: &str = "Display a user's raw and weighted top scores pp";
+
+#[derive(CommandModel, CreateCommand, HasName)]
+#[command(name = "pp_distribution", desc = GRAPH_PP_DISTRIBUTION_DESC)]
+pub struct GraphPpDistribution<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
+    discord: Option<Id<UserMarker>>,
+    #[command(desc = GRAPH_THEME_DESC)]
+    theme: Option<GraphTheme>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
 }
 
 const GRAPH_RANK_DESC: &str = "Display a user's rank progression over time";
@@ -264,12 +527,40 @@ pub struct GraphRank<'a> {
     mode: Option<GameModeOption>,
     #[command(desc = "Specify a username")]
     name: Option<Cow<'a, str>>,
-    #[command(desc = "From this many days ago", min_value = 0, max_value = 90)]
-    from: Option<u8>,
-    #[command(desc = "Until this many days ago", min_value = 0, max_value = 90)]
-    until: Option<u8>,
+    #[command(
+        desc = "From this many days ago",
+        help = "From this many days ago.\n\
+        The osu!api only provides the last 90 days of rank history. \
+        Older days are only available for users that are tracked somewhere.",
+        min_value = 0,
+        max_value = 3650
+    )]
+    from: Option<u16>,
+    #[command(
+        desc = "Until this many days ago",
+        help = "Until this many days ago.\n\
+        The osu!api only provides the last 90 days of rank history. \
+        Older days are only available for users that are tracked somewhere.",
+        min_value = 0,
+        max_value = 3650
+    )]
+    until: Option<u16>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = GRAPH_THEME_DESC)]
+    theme: Option<GraphTheme>,
+    #[command(desc = GRAPH_ANIMATE_DESC, help = GRAPH_ANIMATE_HELP)]
+    animate: Option<bool>,
+    #[command(
+        desc = "Whether gaps in the rank history should be shaded by their uncertainty",
+        help = "Whether gaps in the rank history should be shaded by their uncertainty.\n\
+        Days without rank data are interpolated and drawn as dashed line. \
+        With this option, a band around that line indicates how much the rank \
+        could have changed in the meantime."
+    )]
+    uncertainty: Option<bool>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
 }
 
 const GRAPH_SCORE_RANK_DESC: &str = "Display a user's score rank progression over time";
@@ -287,6 +578,36 @@ pub struct GraphScoreRank<'a> {
     until: Option<u8>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = GRAPH_THEME_DESC)]
+    theme: Option<GraphTheme>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
+}
+
+const GRAPH_SESSIONS_DESC: &str = "Display a user's recent play sessions";
+
+#[derive(CommandModel, CreateCommand, HasName)]
+#[command(
+    name = "sessions",
+    desc = GRAPH_SESSIONS_DESC,
+    help = "Display a user's recent play sessions.\n\
+    Recent scores are grouped into sessions whenever there is a break of at most 20 minutes \
+    between them. Each session is labeled with the sum of its pp.\n\
+    Note that the osu!api only provides recent scores of the last 24 hours."
+)]
+pub struct GraphSessions<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a timezone")]
+    timezone: Option<TimezoneOption>,
+    #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
+    discord: Option<Id<UserMarker>>,
+    #[command(desc = GRAPH_THEME_DESC)]
+    theme: Option<GraphTheme>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
 }
 
 const GRAPH_SNIPED_DESC: &str = "Display sniped users of the past 8 weeks";
@@ -300,6 +621,8 @@ pub struct GraphSniped<'a> {
     name: Option<Cow<'a, str>>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
 }
 
 const GRAPH_SNIPE_COUNT_DESC: &str = "Display how a user's national #1 count progressed";
@@ -313,12 +636,45 @@ pub struct GraphSnipeCount<'a> {
     name: Option<Cow<'a, str>>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
 }
 
+const GRAPH_SNIPE_GAINS_LOSSES_DESC: &str = "Display a user's daily national #1 gains and losses";
+
+#[derive(CommandModel, CreateCommand, HasName)]
+#[command(
+    name = "snipe_gains_losses",
+    desc = GRAPH_SNIPE_GAINS_LOSSES_DESC,
+    help = "Display a user's daily national #1 gains and losses as bars above and below zero.\n\
+    Data is provided by [huismetbenen](https://snipe.huismetbenen.nl/) for osu!standard \
+    and [kittenroleplay](https://snipes.kittenroleplay.com) for osu!mania and osu!catch."
+)]
+pub struct GraphSnipeGainsLosses<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<SnipeGameMode>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
+    discord: Option<Id<UserMarker>>,
+    #[command(
+        desc = "Amount of days to show, defaults to 30",
+        min_value = 1,
+        max_value = 90
+    )]
+    days: Option<u32>,
+    #[command(desc = GRAPH_THEME_DESC)]
+    theme: Option<GraphTheme>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
+}
+
+const GRAPH_TOP_DESC: &str = "Display a user's top scores pp";
+
 #[derive(CommandModel, CreateCommand, HasName)]
 #[command(
     name = "top",
-    desc = "Display a user's top scores pp",
+    desc = GRAPH_TOP_DESC,
     help = "Display a user's top scores pp.\n\
     The timezone option is only relevant for the `Time` order."
 )]
@@ -333,6 +689,58 @@ pub struct GraphTop {
     timezone: Option<TimezoneOption>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
+    #[command(desc = GRAPH_THEME_DESC)]
+    theme: Option<GraphTheme>,
+    #[command(desc = GRAPH_DATA_DESC, help = GRAPH_DATA_HELP)]
+    data: Option<bool>,
+}
+
+impl Graph<'_> {
+    /// Whether the graph's data should be attached as well.
+    fn with_data(&self) -> bool {
+        let data = match self {
+            Graph::MapBpm(args) => args.data,
+            Graph::CompareTop(args) => args.data,
+            Graph::MapCompletion(args) => args.data,
+            Graph::MapDensity(args) => args.data,
+            Graph::MapStrains(args) => args.data,
+            Graph::Medals(args) => args.data,
+            Graph::OsuTrack(GraphOsuTrack::PpRank(args)) => args.data,
+            Graph::OsuTrack(GraphOsuTrack::Score(args)) => args.data,
+            Graph::OsuTrack(GraphOsuTrack::HitRatios(args)) => args.data,
+            Graph::OsuTrack(GraphOsuTrack::Playcount(args)) => args.data,
+            Graph::OsuTrack(GraphOsuTrack::Accuracy(args)) => args.data,
+            Graph::OsuTrack(GraphOsuTrack::Grades(args)) => args.data,
+            Graph::PlaycountReplays(args) => args.data,
+            Graph::Playtime(args) => args.data,
+            Graph::PpDistribution(args) => args.data,
+            Graph::Rank(args) => args.data,
+            Graph::MapRankingHistory(args) => args.data,
+            Graph::ScoreRank(args) => args.data,
+            Graph::Sessions(args) => args.data,
+            Graph::Sniped(args) => args.data,
+            Graph::SnipeCount(args) => args.data,
+            Graph::SnipeGainsLosses(args) => args.data,
+            Graph::Top(args) => args.data,
+        };
+
+        data.unwrap_or(false)
+    }
+
+    /// Whether the graph's data is only available for bancho users.
+    fn bancho_only(&self) -> bool {
+        matches!(
+            self,
+            Graph::Medals(_)
+                | Graph::OsuTrack(_)
+                | Graph::Rank(_)
+                | Graph::MapRankingHistory(_)
+                | Graph::ScoreRank(_)
+                | Graph::Sniped(_)
+                | Graph::SnipeCount(_)
+                | Graph::SnipeGainsLosses(_)
+        )
+    }
 }
 
 #[derive(CommandOption, CreateOption)]
@@ -345,6 +753,10 @@ pub enum GraphTopOrder {
     TimeByHour,
     #[option(name = "Time by day", value = "time_d")]
     TimeByDay,
+    #[option(name = "Accuracy", value = "acc")]
+    Accuracy,
+    #[option(name = "Stars", value = "stars")]
+    Stars,
 }
 
 async fn slash_graph(mut command: InteractionCommand) -> Result<()> {
@@ -354,15 +766,25 @@ async fn slash_graph(mut command: InteractionCommand) -> Result<()> {
 }
 
 async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
+    if let Some(server) = Context::osu_server().filter(|_| args.bancho_only()) {
+        let content =
+            format!("That graph is only available for bancho but this server targets `{server}`");
+
+        return orig.error(content).await;
+    }
+
     let mut author_fn: fn(CachedUser) -> AuthorBuilder =
         |user: CachedUser| user.author_builder(false);
     let mut footer = None;
+    let mut filename = "graph.png";
+    let with_data = args.with_data();
 
     let tuple_option = match args {
         Graph::MapBpm(args) => {
             return match map_bpm(&orig, args).await {
                 Ok(ControlFlow::Continue(map)) => {
-                    orig.create_message(map.into()).await?;
+                    orig.create_message(map.into_builder(with_data)).await?;
+                    BotMetrics::inc_graph_rendered();
 
                     Ok(())
                 }
@@ -373,7 +795,8 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
         Graph::MapStrains(args) => {
             return match map_strains(&orig, args).await {
                 Ok(ControlFlow::Continue(map)) => {
-                    orig.create_message(map.into()).await?;
+                    orig.create_message(map.into_builder(with_data)).await?;
+                    BotMetrics::inc_graph_rendered();
 
                     Ok(())
                 }
@@ -381,6 +804,77 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
                 Err(err) => Err(err.wrap_err("Failed to create map strains graph")),
             };
         }
+        Graph::MapCompletion(args) => {
+            return match map_completion(&orig, args).await {
+                Ok(ControlFlow::Continue(map)) => {
+                    orig.create_message(map.into_builder(with_data)).await?;
+                    BotMetrics::inc_graph_rendered();
+
+                    Ok(())
+                }
+                Ok(ControlFlow::Break(())) => Ok(()),
+                Err(err) => Err(err.wrap_err("Failed to create map completion graph")),
+            };
+        }
+        Graph::MapDensity(args) => {
+            return match map_density(&orig, args).await {
+                Ok(ControlFlow::Continue(map)) => {
+                    orig.create_message(map.into_builder(with_data)).await?;
+                    BotMetrics::inc_graph_rendered();
+
+                    Ok(())
+                }
+                Ok(ControlFlow::Break(())) => Ok(()),
+                Err(err) => Err(err.wrap_err("Failed to create map density graph")),
+            };
+        }
+        Graph::MapRankingHistory(args) => {
+            return match map_ranking_history(&orig, args).await {
+                Ok(ControlFlow::Continue(map)) => {
+                    orig.create_message(map.into_builder(with_data)).await?;
+                    BotMetrics::inc_graph_rendered();
+
+                    Ok(())
+                }
+                Ok(ControlFlow::Break(())) => Ok(()),
+                Err(err) => Err(err.wrap_err("Failed to create map ranking history graph")),
+            };
+        }
+        Graph::CompareTop(args) => {
+            let owner = orig.user_id()?;
+
+            let config = match Context::user_config().with_osu_id(owner).await {
+                Ok(config) => config,
+                Err(err) => {
+                    let _ = orig.error(GENERAL_ISSUE).await;
+
+                    return Err(err.wrap_err("failed to get user config"));
+                }
+            };
+
+            let mode = args
+                .mode
+                .map(GameMode::from)
+                .or(config.mode)
+                .unwrap_or(GameMode::Osu);
+
+            let legacy_scores = match config.score_data {
+                Some(score_data) => score_data.is_legacy(),
+                None => match orig.guild_id() {
+                    Some(guild_id) => Context::guild_config()
+                        .peek(guild_id, |config| config.score_data)
+                        .await
+                        .is_some_and(ScoreData::is_legacy),
+                    None => false,
+                },
+            };
+
+            let palette = GraphPalette::new(args.theme.or(config.graph_theme).unwrap_or_default());
+
+            compare_top_graph(&orig, args, mode, legacy_scores, palette)
+                .await
+                .wrap_err("Failed to create top comparison graph")?
+        }
         Graph::Medals(args) => {
             let user_id = match user_id!(orig, args) {
                 Some(user_id) => user_id,
@@ -395,7 +889,9 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
                 },
             };
 
-            medals_graph(&orig, user_id)
+            let palette = graph_palette(&orig, args.theme).await;
+
+            medals_graph(&orig, user_id, palette)
                 .await
                 .wrap_err("failed to create medals graph")?
         }
@@ -453,6 +949,14 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
 
             footer = Some(FooterBuilder::new("Data provided by ameobea.me/osutrack"));
 
+            if let GraphOsuTrack::PpRank(GraphOsuTrackPpRank {
+                animate: Some(true),
+                ..
+            }) = args
+            {
+                filename = "graph.gif";
+            }
+
             osutrack_graph(&orig, user_id, mode, args)
                 .await
                 .wrap_err("Failed to create osutrack graph")?
@@ -493,22 +997,96 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
                 .await
                 .wrap_err("failed to create profile graph")?
         }
-        Graph::Rank(args) => {
+        Graph::Playtime(args) => {
             let (user_id, mode) = user_id_mode!(orig, args);
             let user_args = UserArgs::rosu_id(&user_id, mode).await;
+            let defaults = GraphDefaults::new(&orig).await;
+            let legacy_scores = defaults.legacy_scores(&orig).await;
+            let palette = defaults.palette(args.theme);
 
-            rank_graph(&orig, user_id, user_args, args.from, args.until)
+            playtime_graph(&orig, user_id, user_args, args.year, legacy_scores, palette)
                 .await
-                .wrap_err("Failed to create rank graph")?
+                .wrap_err("Failed to create playtime graph")?
+        }
+        Graph::PpDistribution(args) => {
+            let (user_id, mode) = user_id_mode!(orig, args);
+            let user_args = UserArgs::rosu_id(&user_id, mode).await;
+            let defaults = GraphDefaults::new(&orig).await;
+            let legacy_scores = defaults.legacy_scores(&orig).await;
+            let palette = defaults.palette(args.theme);
+
+            pp_distribution_graph(&orig, user_id, user_args, legacy_scores, palette)
+                .await
+                .wrap_err("Failed to create pp distribution graph")?
+        }
+        Graph::Rank(args) => {
+            let (user_id, mode) = user_id_mode!(orig, args);
+            let user_args = UserArgs::rosu_id(&user_id, mode).await;
+            let defaults = GraphDefaults::new(&orig).await;
+            let palette = defaults.palette(args.theme);
+            let until = args.until.or(defaults.days);
+
+            let animate = args.animate.unwrap_or(false);
+            let uncertainty = args.uncertainty.unwrap_or(false);
+
+            let graph_fut = rank_graph(
+                &orig,
+                user_id,
+                user_args,
+                args.from,
+                until,
+                animate,
+                uncertainty,
+                palette,
+            );
+
+            let Some((user, history, graph, data)) =
+                graph_fut.await.wrap_err("Failed to create rank graph")?
+            else {
+                return Ok(());
+            };
+
+            // Animated graphs can't be paginated and paginating would leave
+            // the attached data outdated so those are sent as is
+            if animate || with_data {
+                if animate {
+                    filename = "graph.gif";
+                }
+
+                Some((user, graph, data))
+            } else {
+                let pagination = RankGraphPagination::new(
+                    user,
+                    history,
+                    palette,
+                    args.from,
+                    until,
+                    uncertainty,
+                    graph,
+                    orig.user_id()?,
+                );
+
+                return ActiveMessages::builder(pagination)
+                    .start_by_update(true)
+                    .begin(orig)
+                    .await;
+            }
         }
         Graph::ScoreRank(args) => {
             let (user_id, mode) = user_id_mode!(orig, args);
+            let defaults = GraphDefaults::new(&orig).await;
+            let palette = defaults.palette(args.theme);
 
-            let tuple_option = score_rank_graph(&orig, user_id, mode, args.from, args.until)
+            // Score rank history only goes back 90 days
+            let until = args
+                .until
+                .or_else(|| defaults.days.map(|days| days.min(90) as u8));
+
+            let tuple_option = score_rank_graph(&orig, user_id, mode, args.from, until, palette)
                 .await
                 .wrap_err("Failed to create score rank graph")?;
 
-            let Some((author, graph)) = tuple_option else {
+            let Some((author, graph, data)) = tuple_option else {
                 return Ok(());
             };
 
@@ -516,14 +1094,69 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
                 .author(author)
                 .image(attachment("graph.png"));
 
-            let builder = MessageBuilder::new()
+            let mut builder = MessageBuilder::new()
                 .embed(embed)
                 .attachment("graph.png", graph);
 
+            if with_data {
+                builder = builder.attachment("graph_data.csv", data.into_bytes());
+            }
+
             orig.create_message(builder).await?;
+            BotMetrics::inc_graph_rendered();
 
             return Ok(());
         }
+        Graph::Sessions(args) => {
+            let owner = orig.user_id()?;
+
+            let config = match Context::user_config().with_osu_id(owner).await {
+                Ok(config) => config,
+                Err(err) => {
+                    let _ = orig.error(GENERAL_ISSUE).await;
+
+                    return Err(err.wrap_err("failed to get user config"));
+                }
+            };
+
+            let mode = args
+                .mode
+                .map(GameMode::from)
+                .or(config.mode)
+                .unwrap_or(GameMode::Osu);
+
+            let (user_id, no_user_specified) = match user_id!(orig, args) {
+                Some(user_id) => (user_id, false),
+                None => match config.osu {
+                    Some(user_id) => (UserId::Id(user_id), true),
+                    None => return require_link(&orig).await,
+                },
+            };
+
+            let user_args = UserArgs::rosu_id(&user_id, mode).await;
+
+            let tz = args
+                .timezone
+                .map(UtcOffset::from)
+                .or_else(|| no_user_specified.then_some(config.timezone).flatten());
+
+            let legacy_scores = match config.score_data {
+                Some(score_data) => score_data.is_legacy(),
+                None => match orig.guild_id() {
+                    Some(guild_id) => Context::guild_config()
+                        .peek(guild_id, |config| config.score_data)
+                        .await
+                        .is_some_and(ScoreData::is_legacy),
+                    None => false,
+                },
+            };
+
+            let palette = GraphPalette::new(args.theme.or(config.graph_theme).unwrap_or_default());
+
+            sessions_graph(&orig, user_id, user_args, tz, legacy_scores, palette)
+                .await
+                .wrap_err("Failed to create sessions graph")?
+        }
         Graph::Sniped(args) => {
             let (user_id, mode) = user_id_mode!(orig, args);
             footer = Some(FooterBuilder::new("Data provided by snipe.huismetbenen.nl"));
@@ -540,6 +1173,44 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
                 .await
                 .wrap_err("failed to create snipe count graph")?
         }
+        Graph::SnipeGainsLosses(args) => {
+            let owner = orig.user_id()?;
+
+            let config = match Context::user_config().with_osu_id(owner).await {
+                Ok(config) => config,
+                Err(err) => {
+                    let _ = orig.error(GENERAL_ISSUE).await;
+
+                    return Err(err.wrap_err("failed to get user config"));
+                }
+            };
+
+            let mode = args
+                .mode
+                .map(GameMode::from)
+                .or(config.mode)
+                .unwrap_or(GameMode::Osu);
+
+            let user_id = match user_id!(orig, args) {
+                Some(user_id) => user_id,
+                None => match config.osu {
+                    Some(user_id) => UserId::Id(user_id),
+                    None => return require_link(&orig).await,
+                },
+            };
+
+            footer = Some(FooterBuilder::new("Data provided by snipe.huismetbenen.nl"));
+            let days = args
+                .days
+                .or(config.graph_days.map(u32::from))
+                .unwrap_or(30)
+                .clamp(1, 90);
+            let palette = GraphPalette::new(args.theme.or(config.graph_theme).unwrap_or_default());
+
+            snipe_gains_losses_graph(&orig, user_id, mode, days, palette)
+                .await
+                .wrap_err("Failed to create snipe gains and losses graph")?
+        }
         Graph::Top(args) => {
             let owner = orig.user_id()?;
 
@@ -584,29 +1255,44 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
                 },
             };
 
-            top_graph(&orig, user_id, user_args, args.order, tz, legacy_scores)
-                .await
-                .wrap_err("failed to create top graph")?
+            let palette = GraphPalette::new(args.theme.or(config.graph_theme).unwrap_or_default());
+
+            top_graph(
+                &orig,
+                user_id,
+                user_args,
+                args.order,
+                tz,
+                legacy_scores,
+                palette,
+            )
+            .await
+            .wrap_err("failed to create top graph")?
         }
     };
 
-    let Some((user, graph)) = tuple_option else {
+    let Some((user, graph, data)) = tuple_option else {
         return Ok(());
     };
 
     let mut embed = EmbedBuilder::new()
         .author(author_fn(user))
-        .image(attachment("graph.png"));
+        .image(attachment(filename));
 
     if let Some(footer) = footer {
         embed = embed.footer(footer);
     }
 
-    let builder = MessageBuilder::new()
+    let mut builder = MessageBuilder::new()
         .embed(embed)
-        .attachment("graph.png", graph);
+        .attachment(filename, graph);
+
+    if with_data {
+        builder = builder.attachment("graph_data.csv", data.into_bytes());
+    }
 
     orig.create_message(builder).await?;
+    BotMetrics::inc_graph_rendered();
 
     Ok(())
 }
@@ -614,34 +1300,153 @@ async fn graph(orig: CommandOrigin<'_>, args: Graph<'_>) -> Result<()> {
 const W: u32 = 1350;
 const H: u32 = 711;
 
+/// Draw while holding a render slot of the invoker so that gathering the data
+/// beforehand doesn't wait in the render queue.
+async fn draw_queued<T>(orig: &CommandOrigin<'_>, draw: impl FnOnce() -> Result<T>) -> Result<T> {
+    let _permit = Context::render_queue().acquire(orig).await?;
+
+    draw()
+}
+
+/// Use the given theme or fall back to the author's configured one.
+async fn graph_palette(orig: &CommandOrigin<'_>, theme: Option<GraphTheme>) -> GraphPalette {
+    if let Some(theme) = theme {
+        return GraphPalette::new(theme);
+    }
+
+    GraphDefaults::new(orig).await.palette(None)
+}
+
+/// Graph settings of the invoker's config.
+#[derive(Default)]
+struct GraphDefaults {
+    theme: Option<GraphTheme>,
+    days: Option<u16>,
+    score_data: Option<ScoreData>,
+}
+
+impl GraphDefaults {
+    async fn new(orig: &CommandOrigin<'_>) -> Self {
+        let Ok(user_id) = orig.user_id() else {
+            return Self::default();
+        };
+
+        match Context::user_config().with_osu_id(user_id).await {
+            Ok(config) => Self {
+                theme: config.graph_theme,
+                days: config.graph_days,
+                score_data: config.score_data,
+            },
+            Err(err) => {
+                warn!(?err, "Failed to get user config for graph defaults");
+
+                Self::default()
+            }
+        }
+    }
+
+    /// Palette of the given theme or the configured one.
+    fn palette(&self, theme: Option<GraphTheme>) -> GraphPalette {
+        GraphPalette::new(theme.or(self.theme).unwrap_or_default())
+    }
+
+    /// Whether legacy scores should be requested, falling back to the guild's
+    /// score data if the invoker has none configured.
+    async fn legacy_scores(&self, orig: &CommandOrigin<'_>) -> bool {
+        match (self.score_data, orig.guild_id()) {
+            (Some(score_data), _) => score_data.is_legacy(),
+            (None, Some(guild_id)) => Context::guild_config()
+                .peek(guild_id, |config| config.score_data)
+                .await
+                .is_some_and(ScoreData::is_legacy),
+            (None, None) => false,
+        }
+    }
+}
+
 struct MapResult {
     bytes: Vec<u8>,
+    data: GraphData,
     title: String,
-    url: String,
+    url: Option<String>,
 }
 
 impl MapResult {
-    fn new(map: &OsuMap, bytes: Vec<u8>) -> Self {
-        Self {
-            bytes,
-            title: format!("{} - {} [{}]", map.artist(), map.title(), map.version()),
-            url: format!("{OSU_BASE}b/{}", map.map_id()),
+    fn new(map: &GraphMap, bytes: Vec<u8>, data: GraphData) -> Self {
+        match map {
+            GraphMap::Full(map) => Self {
+                bytes,
+                data,
+                title: format!("{} - {} [{}]", map.artist(), map.title(), map.version()),
+                url: Some(format!("{OSU_BASE}b/{}", map.map_id())),
+            },
+            GraphMap::Attached { filename, .. } => Self {
+                bytes,
+                data,
+                title: filename.as_ref().to_owned(),
+                url: None,
+            },
         }
     }
-}
 
-impl From<MapResult> for MessageBuilder<'_> {
-    fn from(map: MapResult) -> Self {
-        let embed = EmbedBuilder::new()
+    fn into_builder(self, with_data: bool) -> MessageBuilder<'static> {
+        let mut embed = EmbedBuilder::new()
             .image(attachment("graph.png"))
-            .title(map.title)
-            .url(map.url);
+            .title(self.title);
+
+        if let Some(url) = self.url {
+            embed = embed.url(url);
+        }
+
+        let builder = MessageBuilder::new()
+            .embed(embed)
+            .attachment("graph.png", self.bytes);
+
+        if with_data {
+            builder.attachment("graph_data.csv", self.data.into_bytes())
+        } else {
+            builder
+        }
+    }
+}
+
+/// A map either retrieved by its id or parsed from an attached .osu file.
+enum GraphMap {
+    Full(OsuMap),
+    Attached { pp_map: Beatmap, filename: Box<str> },
+}
+
+impl GraphMap {
+    fn pp_map(&self) -> &Beatmap {
+        match self {
+            Self::Full(map) => &map.pp_map,
+            Self::Attached { pp_map, .. } => pp_map,
+        }
+    }
+
+    fn mode(&self) -> GameMode {
+        (self.pp_map().mode as u8).into()
+    }
+
+    fn cover(&self) -> Option<&str> {
+        match self {
+            Self::Full(map) => Some(map.cover()),
+            Self::Attached { .. } => None,
+        }
+    }
 
-        Self::new().embed(embed).attachment("graph.png", map.bytes)
+    fn version(&self) -> &str {
+        match self {
+            Self::Full(map) => map.version(),
+            Self::Attached { filename, .. } => filename,
+        }
     }
 }
 
-async fn get_map_id(map: Option<&str>, channel_id: Id<ChannelMarker>) -> Result<u32, &'static str> {
+pub(super) async fn get_map_id(
+    map: Option<&str>,
+    channel_id: Id<ChannelMarker>,
+) -> Result<MapIdType, &'static str> {
     let map = match map.map(|arg| {
         matcher::get_osu_map_id(arg)
             .map(MapIdType::Map)
@@ -656,8 +1461,8 @@ async fn get_map_id(map: Option<&str>, channel_id: Id<ChannelMarker>) -> Result<
         None => None,
     };
 
-    let map_id = if let Some(id) = map {
-        id
+    if let Some(id) = map {
+        Ok(id)
     } else {
         let Ok(msgs) = Context::retrieve_channel_history(channel_id).await else {
             return Err(
@@ -667,50 +1472,126 @@ async fn get_map_id(map: Option<&str>, channel_id: Id<ChannelMarker>) -> Result<
             );
         };
 
-        match Context::find_map_id_in_msgs(&msgs, 0).await {
-            Some(id) => id,
-            None => {
-                return Err(
-                    "No beatmap specified and none found in recent channel history. Try \
-                    specifying a map either by url or by map id.",
-                );
-            }
+        Context::find_map_id_in_msgs(&msgs, 0).await.ok_or(
+            "No beatmap specified and none found in recent channel history. Try specifying a \
+            map either by url or by map id.",
+        )
+    }
+}
+
+/// Retrieve the map either from the attached file or through its id.
+///
+/// Mapset ids are only accepted if `mapset_diffs` is given, in which case the
+/// mapset's remaining difficulties are stored in it.
+async fn get_graph_map(
+    orig: &CommandOrigin<'_>,
+    map: Option<&str>,
+    file: Option<Attachment>,
+    mode: Option<GameMode>,
+    mapset_diffs: Option<&mut Option<Vec<OsuMap>>>,
+) -> Result<ControlFlow<(), GraphMap>> {
+    if let Some(attachment) = file {
+        return match parse_attached_map(orig, &attachment, mode).await? {
+            Some(pp_map) => Ok(ControlFlow::Continue(GraphMap::Attached {
+                pp_map,
+                filename: attachment.filename.into(),
+            })),
+            None => Ok(ControlFlow::Break(())),
+        };
+    }
+
+    let map_id = match get_map_id(map, orig.channel_id()).await {
+        Ok(MapIdType::Set(_)) if mapset_diffs.is_none() => {
+            let content = "Looks like you gave me a mapset id, I need a map id though";
+
+            return orig.error_with_help(content).await.map(ControlFlow::Break);
         }
+        Ok(map_id) => map_id,
+        Err(content) => return orig.error_with_help(content).await.map(ControlFlow::Break),
     };
 
-    let MapIdType::Map(map_id) = map_id else {
-        return Err("Looks like you gave me a mapset id, I need a map id though");
-    };
+    let map_res = match map_id {
+        MapIdType::Map(map_id) => Context::osu_map().map(map_id, None).await,
+        MapIdType::Set(mapset_id) => mapset_maps(mapset_id).await.and_then(|mut maps| {
+            let idx = maps
+                .iter()
+                .enumerate()
+                .max_by_key(|(_, map)| map.n_objects())
+                .map(|(idx, _)| idx)
+                .ok_or(MapError::NotFound)?;
 
-    Ok(map_id)
-}
+            let map = maps.swap_remove(idx);
 
-async fn map_bpm(
-    orig: &CommandOrigin<'_>,
-    args: GraphMapBpm<'_>,
-) -> Result<ControlFlow<(), MapResult>> {
-    let mods_res = args.mods();
+            if let Some(mapset_diffs) = mapset_diffs {
+                *mapset_diffs = Some(maps);
+            }
 
-    let map_id = match get_map_id(args.map.as_deref(), orig.channel_id()).await {
-        Ok(map_id) => map_id,
-        Err(content) => return orig.error(content).await.map(ControlFlow::Break),
+            Ok(map)
+        }),
     };
 
-    let map = match Context::osu_map().map(map_id, None).await {
-        Ok(map) => map,
+    match map_res {
+        Ok(mut map) => {
+            if let Some(mode) = mode {
+                map.convert_mut(mode);
+            }
+
+            Ok(ControlFlow::Continue(GraphMap::Full(map)))
+        }
         Err(MapError::NotFound) => {
-            let content = format!(
-                "Could not find beatmap with id `{map_id}`. \
-                Did you give me a mapset id instead of a map id?",
-            );
+            let content = match map_id {
+                MapIdType::Map(map_id) => format!(
+                    "Could not find beatmap with id `{map_id}`. \
+                    Did you give me a mapset id instead of a map id?",
+                ),
+                MapIdType::Set(mapset_id) => {
+                    format!("Could not find beatmapset with id `{mapset_id}`")
+                }
+            };
 
-            return orig.error(content).await.map(ControlFlow::Break);
+            orig.error(content).await.map(ControlFlow::Break)
         }
         Err(MapError::Report(err)) => {
             let _ = orig.error(GENERAL_ISSUE).await;
 
-            return Err(err);
+            Err(err)
         }
+    }
+}
+
+/// Retrieve all difficulties of a mapset.
+async fn mapset_maps(mapset_id: u32) -> Result<Vec<OsuMap>, MapError> {
+    let versions = Context::osu_map().versions_by_mapset(mapset_id).await?;
+
+    let maps_id_checksum: HashMap<_, _, IntHasher> = versions
+        .iter()
+        .map(|version| (version.map_id, None))
+        .collect();
+
+    let maps = Context::osu_map().maps(&maps_id_checksum).await?;
+
+    Ok(maps.into_values().collect())
+}
+
+async fn map_bpm(
+    orig: &CommandOrigin<'_>,
+    args: GraphMapBpm<'_>,
+) -> Result<ControlFlow<(), MapResult>> {
+    let mods_res = args.mods();
+    let all_diffs = args.all_diffs.unwrap_or(false);
+
+    let mut mapset_diffs = None;
+    let map_fut = get_graph_map(
+        orig,
+        args.map.as_deref(),
+        args.file,
+        None,
+        Some(&mut mapset_diffs),
+    );
+
+    let map = match map_fut.await? {
+        ControlFlow::Continue(map) => map,
+        ControlFlow::Break(()) => return Ok(ControlFlow::Break(())),
     };
 
     let mods = match mods_res {
@@ -733,7 +1614,7 @@ async fn map_bpm(
                         or do not belong to any single mode"
                     );
 
-                    return orig.error(content).await.map(ControlFlow::Break);
+                    return orig.error_with_help(content).await.map(ControlFlow::Break);
                 }
             }
         }
@@ -744,13 +1625,54 @@ async fn map_bpm(
             If you want exact mods, specify it e.g. as `+hdhr!`.\n\
             And if you want to exclude mods, specify it e.g. as `-hdnf!`.";
 
-            return orig.error(content).await.map(ControlFlow::Break);
+            return orig.error_with_help(content).await.map(ControlFlow::Break);
+        }
+    };
+
+    let others = match map {
+        GraphMap::Full(ref map) if all_diffs => {
+            // Only fetch the difficulties if a map id was specified
+            let maps_res = match mapset_diffs {
+                Some(maps) => Ok(maps),
+                None => mapset_maps(map.mapset_id()).await,
+            };
+
+            match maps_res {
+                Ok(mut maps) => {
+                    maps.retain(|other| other.map_id() != map.map_id());
+                    maps.sort_unstable_by(|a, b| b.n_objects().cmp(&a.n_objects()));
+
+                    // Every other difficulty needs its own color in the legend
+                    maps.truncate(MAX_OTHER_DIFFS);
+
+                    maps
+                }
+                Err(err) => {
+                    warn!(?err, "Failed to get mapset difficulties");
+
+                    Vec::new()
+                }
+            }
         }
+        GraphMap::Full(_) | GraphMap::Attached { .. } => Vec::new(),
     };
 
-    let bytes = map_bpm_graph(&map.pp_map, mods, map.cover()).await?;
+    let others: Vec<_> = others
+        .iter()
+        .map(|other| BpmDifficulty {
+            version: other.version(),
+            pp_map: &other.pp_map,
+        })
+        .collect();
+
+    let main = BpmDifficulty {
+        version: map.version(),
+        pp_map: map.pp_map(),
+    };
 
-    Ok(ControlFlow::Continue(MapResult::new(&map, bytes)))
+    let (bytes, data) = map_bpm_graph(orig, main, &others, mods, map.cover()).await?;
+
+    Ok(ControlFlow::Continue(MapResult::new(&map, bytes, data)))
 }
 
 async fn map_strains(
@@ -758,72 +1680,103 @@ async fn map_strains(
     args: GraphMapStrains<'_>,
 ) -> Result<ControlFlow<(), MapResult>> {
     let mods_res = args.mods();
+    let mode = args.mode.map(GameMode::from);
 
-    let map_id = match get_map_id(args.map.as_deref(), orig.channel_id()).await {
-        Ok(map_id) => map_id,
-        Err(content) => return orig.error(content).await.map(ControlFlow::Break),
+    let map = match get_graph_map(orig, args.map.as_deref(), args.file, mode, None).await? {
+        ControlFlow::Continue(map) => map,
+        ControlFlow::Break(()) => return Ok(ControlFlow::Break(())),
     };
 
+    let mode = mode.unwrap_or(map.mode());
+
+    let mods = match strains_mods(mods_res, mode) {
+        Ok(mods) => mods,
+        Err(content) => return orig.error_with_help(content).await.map(ControlFlow::Break),
+    };
+
+    if let Some(mods2) = args.mods2.as_deref() {
+        let mods2 = match strains_mods(ModSelection::parse(Some(mods2)), mode) {
+            Ok(mods2) => mods2,
+            Err(content) => return orig.error_with_help(content).await.map(ControlFlow::Break),
+        };
+
+        let mods = [mods, mods2];
+        let graph_fut = map_strains_comparison_graph(orig, map.pp_map(), mods, map.cover(), W, H);
+        let (bytes, data) = graph_fut.await?;
+
+        return Ok(ControlFlow::Continue(MapResult::new(&map, bytes, data)));
+    }
+
+    let combined = args.combined.unwrap_or(false);
+    let graph_fut =
+        map_strains_graph_with_data(Some(orig), map.pp_map(), mods, map.cover(), W, H, combined);
+    let (bytes, data) = graph_fut.await?;
+
+    Ok(ControlFlow::Continue(MapResult::new(&map, bytes, data)))
+}
+
+async fn map_density(
+    orig: &CommandOrigin<'_>,
+    args: GraphMapDensity<'_>,
+) -> Result<ControlFlow<(), MapResult>> {
+    let mods_res = args.mods();
     let mode = args.mode.map(GameMode::from);
+    let palette = graph_palette(orig, args.theme).await;
 
-    let map = match Context::osu_map().map(map_id, None).await {
-        Ok(mut map) => {
-            if let Some(mode) = mode {
-                map.convert_mut(mode);
-            }
+    let map = match get_graph_map(orig, args.map.as_deref(), args.file, mode, None).await? {
+        ControlFlow::Continue(map) => map,
+        ControlFlow::Break(()) => return Ok(ControlFlow::Break(())),
+    };
 
-            map
-        }
-        Err(MapError::NotFound) => {
-            let content = format!(
-                "Could not find beatmap with id `{map_id}`. \
-                        Did you give me a mapset id instead of a map id?",
-            );
+    let mode = mode.unwrap_or(map.mode());
 
-            return orig.error(content).await.map(ControlFlow::Break);
-        }
-        Err(MapError::Report(err)) => {
+    let mods = match strains_mods(mods_res, mode) {
+        Ok(mods) => mods,
+        Err(content) => return orig.error_with_help(content).await.map(ControlFlow::Break),
+    };
+
+    if map.pp_map().hit_objects.is_empty() {
+        let content = "The map does not have any hit objects";
+
+        return orig.error(content).await.map(ControlFlow::Break);
+    }
+
+    let draw = || map_density_graph(map.pp_map(), &mods, palette);
+
+    let (bytes, data) = match draw_queued(orig, draw).await {
+        Ok(graph) => graph,
+        Err(err) => {
             let _ = orig.error(GENERAL_ISSUE).await;
 
-            return Err(err);
+            return Err(err.wrap_err("Failed to draw density graph"));
         }
     };
 
-    let mode = mode.unwrap_or(map.mode());
+    Ok(ControlFlow::Continue(MapResult::new(&map, bytes, data)))
+}
 
-    let mods = match mods_res {
+/// Validate the parsed mods for strain and density graphs.
+fn strains_mods(mods_res: ModsResult, mode: GameMode) -> Result<GameMods, Cow<'static, str>> {
+    match mods_res {
         ModsResult::Mods(ModSelection::Include(mods) | ModSelection::Exact(mods)) => {
             match mods.try_with_mode(mode) {
-                Some(mods) if mods.is_valid() => mods,
-                Some(_) => {
-                    let content = format!(
-                        "Looks like some mods in `{mods}` are incompatible with each other"
-                    );
-
-                    return orig.error(content).await.map(ControlFlow::Break);
-                }
+                Some(mods) if mods.is_valid() => Ok(mods),
+                Some(_) => Err(format!(
+                    "Looks like some mods in `{mods}` are incompatible with each other"
+                )
+                .into()),
                 None => {
-                    let content =
-                        format!("The mods `{mods}` are incompatible with the mode {mode:?}");
-
-                    return orig.error(content).await.map(ControlFlow::Break);
+                    Err(format!("The mods `{mods}` are incompatible with the mode {mode:?}").into())
                 }
             }
         }
-        ModsResult::Mods(ModSelection::Exclude { .. }) | ModsResult::None => GameMods::new(),
-        ModsResult::Invalid => {
-            let content = "Failed to parse mods.\n\
+        ModsResult::Mods(ModSelection::Exclude { .. }) | ModsResult::None => Ok(GameMods::new()),
+        ModsResult::Invalid => Err("Failed to parse mods.\n\
             If you want included mods, specify it e.g. as `+hrdt`.\n\
             If you want exact mods, specify it e.g. as `+hdhr!`.\n\
-            And if you want to exclude mods, specify it e.g. as `-hdnf!`.";
-
-            return orig.error(content).await.map(ControlFlow::Break);
-        }
-    };
-
-    let bytes = map_strains_graph(&map.pp_map, mods, map.cover(), W, H).await?;
-
-    Ok(ControlFlow::Continue(MapResult::new(&map, bytes)))
+            And if you want to exclude mods, specify it e.g. as `-hdnf!`."
+            .into()),
+    }
 }
 
 async fn top_graph(
@@ -833,7 +1786,8 @@ async fn top_graph(
     order: GraphTopOrder,
     tz: Option<UtcOffset>,
     legacy_scores: bool,
-) -> Result<Option<(CachedUser, Vec<u8>)>> {
+    palette: GraphPalette,
+) -> Result<Option<(CachedUser, Vec<u8>, GraphData)>> {
     let scores_fut = Context::osu_scores()
         .top(200, legacy_scores)
         .exec_with_user(user_args);
@@ -879,21 +1833,27 @@ async fn top_graph(
     let tz = tz.unwrap_or_else(|| Countries::code(country_code).to_timezone());
 
     let graph_result = match order {
-        GraphTopOrder::Date => top_graph_date(caption, &mut scores)
+        GraphTopOrder::Date => top_graph_date(orig, caption, &mut scores, palette)
             .await
             .wrap_err("Failed to create top date graph"),
-        GraphTopOrder::Index => top_graph_index(caption, &scores)
+        GraphTopOrder::Index => top_graph_index(orig, caption, &scores, palette)
             .await
             .wrap_err("Failed to create top index graph"),
-        GraphTopOrder::TimeByHour => top_graph_time_hour(caption, &mut scores, tz)
+        GraphTopOrder::TimeByHour => top_graph_time_hour(orig, caption, &mut scores, tz, palette)
             .await
             .wrap_err("Failed to create top time hour graph"),
-        GraphTopOrder::TimeByDay => top_graph_time_day(caption, &mut scores, tz)
+        GraphTopOrder::TimeByDay => top_graph_time_day(orig, caption, &mut scores, tz, palette)
             .await
             .wrap_err("Failed to create top time day graph"),
+        GraphTopOrder::Accuracy => top_graph_acc(orig, caption, &scores, palette)
+            .await
+            .wrap_err("Failed to create top accuracy graph"),
+        GraphTopOrder::Stars => top_graph_stars(orig, caption, &scores, palette)
+            .await
+            .wrap_err("Failed to create top stars graph"),
     };
 
-    let bytes = match graph_result {
+    let (bytes, data) = match graph_result {
         Ok(graph) => graph,
         Err(err) => {
             let _ = orig.error(GENERAL_ISSUE).await;
@@ -903,16 +1863,19 @@ async fn top_graph(
         }
     };
 
-    Ok(Some((user, bytes)))
+    Ok(Some((user, bytes, data)))
 }
 
-async fn get_map_cover(url: &str, w: u32, h: u32) -> Result<DynamicImage> {
+/// Returns `None` if there is no cover url e.g. for attached `.osu` files.
+async fn get_map_cover(url: Option<&str>, w: u32, h: u32) -> Result<Option<DynamicImage>> {
+    let Some(url) = url else { return Ok(None) };
+
     let bytes = Context::client().get_mapset_cover(url).await?;
 
     let cover =
         image::load_from_memory(&bytes).wrap_err("Failed to load mapset cover from memory")?;
 
-    Ok(cover.thumbnail_exact(w, h))
+    Ok(Some(cover.thumbnail_exact(w, h)))
 }
 
 pub struct BitMapElement<C> {
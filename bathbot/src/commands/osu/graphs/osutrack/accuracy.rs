@@ -14,10 +14,23 @@ use skia_safe::{EncodedImageFormat, surfaces};
 use time::OffsetDateTime;
 
 use crate::{
-    commands::osu::graphs::{H, W},
+    commands::osu::graphs::{GraphData, H, W, csv_datetime},
     util::Monthly,
 };
 
+pub(super) fn data(history: &[ArchivedOsuTrackHistoryEntry]) -> GraphData {
+    let mut data = GraphData::new(&["date", "accuracy"]);
+
+    for entry in history {
+        data.push_row(&[
+            &csv_datetime(entry.timestamp()),
+            &entry.accuracy.to_native(),
+        ]);
+    }
+
+    data
+}
+
 pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>> {
     let mut min_acc: f32 = 100.0;
     let mut max_acc: f32 = 0.0;
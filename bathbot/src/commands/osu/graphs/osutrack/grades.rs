@@ -12,10 +12,25 @@ use plotters_skia::SkiaBackend;
 use skia_safe::{EncodedImageFormat, surfaces};
 
 use crate::{
-    commands::osu::graphs::{H, W},
+    commands::osu::graphs::{GraphData, H, W, csv_datetime},
     util::Monthly,
 };
 
+pub(super) fn data(history: &[ArchivedOsuTrackHistoryEntry]) -> GraphData {
+    let mut data = GraphData::new(&["date", "count_ss", "count_s", "count_a"]);
+
+    for entry in history {
+        data.push_row(&[
+            &csv_datetime(entry.timestamp()),
+            &entry.count_ss.to_native(),
+            &entry.count_s.to_native(),
+            &entry.count_a.to_native(),
+        ]);
+    }
+
+    data
+}
+
 pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>> {
     // The caller already checked that `history` is not empty so indexing here
     // can't panic.
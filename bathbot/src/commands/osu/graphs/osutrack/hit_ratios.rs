@@ -12,10 +12,40 @@ use rosu_v2::model::GameMode;
 use skia_safe::{EncodedImageFormat, surfaces};
 
 use crate::{
-    commands::osu::graphs::{H, W},
+    commands::osu::graphs::{GraphData, H, W, csv_datetime},
     util::Monthly,
 };
 
+pub(super) fn data(mode: GameMode, history: &[ArchivedOsuTrackHistoryEntry]) -> GraphData {
+    // osu!taiko has no 50s
+    if mode == GameMode::Taiko {
+        let mut data = GraphData::new(&["date", "ratio_300", "ratio_100"]);
+
+        for entry in history {
+            data.push_row(&[
+                &csv_datetime(entry.timestamp()),
+                &entry.ratio_count300(),
+                &entry.ratio_count100(),
+            ]);
+        }
+
+        return data;
+    }
+
+    let mut data = GraphData::new(&["date", "ratio_300", "ratio_100", "ratio_50"]);
+
+    for entry in history {
+        data.push_row(&[
+            &csv_datetime(entry.timestamp()),
+            &entry.ratio_count300(),
+            &entry.ratio_count100(),
+            &entry.ratio_count50(),
+        ]);
+    }
+
+    data
+}
+
 pub(super) fn graph(mode: GameMode, history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>> {
     let mut min_300: f32 = 100.0;
     let mut max_300: f32 = 0.0;
@@ -2,7 +2,7 @@ use bathbot_util::constants::GENERAL_ISSUE;
 use eyre::{Report, Result};
 use rosu_v2::{error::OsuError, model::GameMode, request::UserId};
 
-use super::GraphOsuTrack;
+use super::{GraphData, GraphOsuTrack, draw_queued};
 use crate::{
     commands::osu::user_not_found,
     core::{Context, commands::CommandOrigin},
@@ -21,7 +21,7 @@ pub async fn osutrack_graph(
     user_id: UserId,
     mode: GameMode,
     args: GraphOsuTrack,
-) -> Result<Option<(CachedUser, Vec<u8>)>> {
+) -> Result<Option<(CachedUser, Vec<u8>, GraphData)>> {
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
 
     let user = match Context::redis().osu_user(user_args).await {
@@ -62,7 +62,19 @@ pub async fn osutrack_graph(
         }
     };
 
-    let res = match args {
+    let data = match args {
+        GraphOsuTrack::PpRank(_) => pp_rank::data(&history),
+        GraphOsuTrack::Score(_) => score::data(&history),
+        GraphOsuTrack::HitRatios(_) => hit_ratios::data(mode, &history),
+        GraphOsuTrack::Playcount(_) => playcount::data(&history),
+        GraphOsuTrack::Accuracy(_) => accuracy::data(&history),
+        GraphOsuTrack::Grades(_) => grades::data(&history),
+    };
+
+    let draw = || match args {
+        GraphOsuTrack::PpRank(ref args) if args.animate == Some(true) => {
+            pp_rank::animated(&history)
+        }
         GraphOsuTrack::PpRank(_) => pp_rank::graph(&history),
         GraphOsuTrack::Score(_) => score::graph(&history),
         GraphOsuTrack::HitRatios(_) => hit_ratios::graph(mode, &history),
@@ -71,5 +83,7 @@ pub async fn osutrack_graph(
         GraphOsuTrack::Grades(_) => grades::graph(&history),
     };
 
-    Ok(Some((user, res?)))
+    let bytes = draw_queued(orig, draw).await?;
+
+    Ok(Some((user, bytes, data)))
 }
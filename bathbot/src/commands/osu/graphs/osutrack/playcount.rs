@@ -15,10 +15,23 @@ use skia_safe::{EncodedImageFormat, surfaces};
 use time::{Date, OffsetDateTime, Time};
 
 use crate::{
-    commands::osu::graphs::{H, W},
+    commands::osu::graphs::{GraphData, H, W, csv_datetime},
     util::Monthly,
 };
 
+pub(super) fn data(history: &[ArchivedOsuTrackHistoryEntry]) -> GraphData {
+    let mut data = GraphData::new(&["date", "playcount"]);
+
+    for entry in history {
+        data.push_row(&[
+            &csv_datetime(entry.timestamp()),
+            &entry.playcount.to_native(),
+        ]);
+    }
+
+    data
+}
+
 pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>> {
     // The caller already checked that `history` is not empty so indexing here
     // can't panic.
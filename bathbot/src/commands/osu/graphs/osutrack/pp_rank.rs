@@ -1,5 +1,3 @@
-use std::iter;
-
 use bathbot_model::ArchivedOsuTrackHistoryEntry;
 use bathbot_util::numbers::WithComma;
 use eyre::{ContextCompat, Result, WrapErr};
@@ -11,28 +9,73 @@ use plotters::{
 };
 use plotters_backend::FontStyle;
 use plotters_skia::SkiaBackend;
-use skia_safe::{EncodedImageFormat, surfaces};
+use skia_safe::{EncodedImageFormat, Surface, surfaces};
 use time::OffsetDateTime;
 
 use crate::{
-    commands::osu::graphs::{H, W},
+    commands::osu::graphs::{GraphData, H, W, animation::animate, csv_datetime},
     util::Monthly,
 };
 
+pub(super) fn data(history: &[ArchivedOsuTrackHistoryEntry]) -> GraphData {
+    let mut data = GraphData::new(&["date", "pp", "rank"]);
+
+    for entry in history {
+        data.push_row(&[
+            &csv_datetime(entry.timestamp()),
+            &entry.pp.to_native(),
+            &entry.pp_rank.to_native(),
+        ]);
+    }
+
+    data
+}
+
 pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>> {
+    let mut surface =
+        surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
+
+    draw(&mut surface, history, history.len())?;
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok(png_bytes)
+}
+
+pub(super) fn animated(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>> {
+    animate(history.len(), |surface, revealed| {
+        draw(surface, history, revealed)
+    })
+}
+
+/// Draws the graph with only the first `revealed` entries of `history`.
+///
+/// Axes are always based on the full history so that they remain the same
+/// across frames of an animation.
+fn draw(
+    surface: &mut Surface,
+    history: &[ArchivedOsuTrackHistoryEntry],
+    revealed: usize,
+) -> Result<()> {
     let mut min_rank = u32::MAX;
     let mut max_rank = 0_u32;
     let mut min_rank_datetime = OffsetDateTime::now_utc();
+    let mut min_rank_idx = 0;
 
     let mut min_pp = f32::MAX;
     let mut max_pp = 0.0_f32;
 
-    for entry in history {
+    for (idx, entry) in history.iter().enumerate() {
         max_rank = max_rank.max(entry.pp_rank.to_native());
 
         if min_rank > entry.pp_rank.to_native() {
             min_rank = entry.pp_rank.to_native();
             min_rank_datetime = entry.timestamp();
+            min_rank_idx = idx;
         }
 
         min_pp = min_pp.min(entry.pp.to_native());
@@ -46,9 +89,6 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
     let start = history[0].timestamp();
     let end = history[history.len() - 1].timestamp();
 
-    let mut surface =
-        surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
-
     {
         let mut root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
 
@@ -101,6 +141,7 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
         // Series
         let rank_data = history
             .iter()
+            .take(revealed)
             .map(|entry| (entry.timestamp(), -(entry.pp_rank.to_native() as i32)));
 
         let rank_style = RGBColor(0, 116, 193).stroke_width(2);
@@ -115,12 +156,15 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
         let peak_style = GREEN.stroke_width(2);
         let circle = Circle::new((min_rank_datetime, max_rank), 9_u32, peak_style);
 
+        let circle = (min_rank_idx < revealed).then_some(circle);
+
         chart
-            .draw_series(iter::once(circle))
+            .draw_series(circle)
             .wrap_err("Failed to draw peak circle")?;
 
         let pp_data = history
             .iter()
+            .take(revealed)
             .map(|entry| (entry.timestamp(), entry.pp.to_native()));
 
         let pp_style = RGBColor(0, 246, 193).stroke_width(2);
@@ -143,11 +187,5 @@ pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>>
             .wrap_err("Failed to draw legend")?;
     }
 
-    let png_bytes = surface
-        .image_snapshot()
-        .encode(None, EncodedImageFormat::PNG, None)
-        .wrap_err("Failed to encode image")?
-        .to_vec();
-
-    Ok(png_bytes)
+    Ok(())
 }
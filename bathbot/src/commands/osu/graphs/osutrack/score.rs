@@ -11,10 +11,25 @@ use plotters_skia::SkiaBackend;
 use skia_safe::{EncodedImageFormat, surfaces};
 
 use crate::{
-    commands::osu::graphs::{H, W},
+    commands::osu::graphs::{GraphData, H, W, csv_datetime},
     util::Monthly,
 };
 
+pub(super) fn data(history: &[ArchivedOsuTrackHistoryEntry]) -> GraphData {
+    let mut data = GraphData::new(&["date", "total_score", "ranked_score", "level"]);
+
+    for entry in history {
+        data.push_row(&[
+            &csv_datetime(entry.timestamp()),
+            &entry.total_score.to_native(),
+            &entry.ranked_score.to_native(),
+            &entry.level.to_native(),
+        ]);
+    }
+
+    data
+}
+
 pub(super) fn graph(history: &[ArchivedOsuTrackHistoryEntry]) -> Result<Vec<u8>> {
     let mut min_score = u64::MAX;
     let mut max_score = 0_u64;
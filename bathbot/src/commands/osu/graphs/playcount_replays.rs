@@ -1,4 +1,4 @@
-use std::iter;
+use std::{collections::BTreeMap, iter};
 
 use bathbot_macros::command;
 use bathbot_model::rosu_v2::user::MonthlyCountRkyv;
@@ -32,7 +32,7 @@ use skia_safe::{EncodedImageFormat, Surface, surfaces};
 use time::{Date, Month, OffsetDateTime};
 use twilight_model::guild::Permissions;
 
-use super::{BitMapElement, Graph, GraphPlaycountReplays, H, W};
+use super::{BitMapElement, Graph, GraphData, GraphPlaycountReplays, H, W};
 use crate::{
     commands::osu::{graphs::GRAPH_PLAYCOUNT_DESC, user_not_found},
     core::{
@@ -62,6 +62,7 @@ impl<'m> GraphPlaycountReplays<'m> {
             playcount: None,
             replays: None,
             badges: None,
+            data: None,
         }
     }
 }
@@ -86,7 +87,7 @@ pub async fn playcount_replays_graph(
     orig: &CommandOrigin<'_>,
     user_id: UserId,
     flags: ProfileGraphFlags,
-) -> Result<Option<(CachedUser, Vec<u8>)>> {
+) -> Result<Option<(CachedUser, Vec<u8>, GraphData)>> {
     let user_args = UserArgs::rosu_id(&user_id, GameMode::Osu).await;
 
     let mut user = match Context::redis().osu_user(user_args).await {
@@ -105,12 +106,14 @@ pub async fn playcount_replays_graph(
         }
     };
 
+    let data = monthly_data(&mut user, flags);
+
     let params = ProfileGraphParams::new(&mut user)
         .width(W)
         .height(H)
         .flags(flags);
 
-    let bytes = match graphs(params).await {
+    let bytes = match graphs(orig, params).await {
         Ok(GraphResult::Ok(graph)) => graph,
         Ok(GraphResult::NotEnoughDatapoints) => {
             let content = format!(
@@ -138,7 +141,36 @@ pub async fn playcount_replays_graph(
         }
     };
 
-    Ok(Some((user, bytes)))
+    Ok(Some((user, bytes, data)))
+}
+
+/// Monthly playcount and replays watched for each month that is drawn.
+fn monthly_data(user: &mut CachedUser, flags: ProfileGraphFlags) -> GraphData {
+    let (playcounts, replays) = prepare_monthly_counts(user, flags);
+
+    let mut months = BTreeMap::<Date, (Option<i32>, Option<i32>)>::new();
+
+    if flags.playcount() {
+        for count in playcounts.iter() {
+            months.entry(count.start_date).or_default().0 = Some(count.count);
+        }
+    }
+
+    if flags.replays() {
+        for count in replays.iter() {
+            months.entry(count.start_date).or_default().1 = Some(count.count);
+        }
+    }
+
+    let mut data = GraphData::new(&["month", "playcount", "replays_watched"]);
+
+    for (month, (playcount, replays)) in months {
+        let playcount = playcount.map_or_else(String::new, |count| count.to_string());
+        let replays = replays.map_or_else(String::new, |count| count.to_string());
+        data.push_row(&[&month, &playcount, &replays]);
+    }
+
+    data
 }
 
 bitflags! {
@@ -231,7 +263,7 @@ async fn gather_badges(user: &mut CachedUser, flags: ProfileGraphFlags) -> Resul
         .await
 }
 
-async fn graphs(params: ProfileGraphParams<'_>) -> Result<GraphResult> {
+async fn graphs(orig: &CommandOrigin<'_>, params: ProfileGraphParams<'_>) -> Result<GraphResult> {
     let w = params.w;
     let h = params.h;
 
@@ -239,12 +271,17 @@ async fn graphs(params: ProfileGraphParams<'_>) -> Result<GraphResult> {
         .await
         .wrap_err("Failed to gather badges")?;
 
+    if params.flags == ProfileGraphFlags::BADGES && badges.is_empty() {
+        return Ok(GraphResult::NoBadges);
+    }
+
+    // Only the drawing itself waits in the render queue
+    let _permit = Context::render_queue().acquire(orig).await?;
+
     let mut surface =
         surfaces::raster_n32_premul((w as i32, h as i32)).wrap_err("Failed to create surface")?;
 
-    if params.flags == ProfileGraphFlags::BADGES && badges.is_empty() {
-        return Ok(GraphResult::NoBadges);
-    } else if !draw(&mut surface, params, &badges)? {
+    if !draw(&mut surface, params, &badges)? {
         return Ok(GraphResult::NotEnoughDatapoints);
     }
 
@@ -652,7 +689,7 @@ fn prepare_monthly_counts(
     (playcounts, replays)
 }
 
-fn spoof_monthly_counts(counts: &mut Vec<MonthlyCount>) {
+pub(super) fn spoof_monthly_counts(counts: &mut Vec<MonthlyCount>) {
     // Fill in months inbetween entries
     let (mut year, mut month) = match counts.as_slice() {
         [] | [_] => return,
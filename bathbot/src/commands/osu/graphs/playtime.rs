@@ -0,0 +1,282 @@
+use bathbot_macros::command;
+use bathbot_model::{command_fields::GameModeOption, rosu_v2::user::MonthlyCountRkyv};
+use bathbot_util::{constants::GENERAL_ISSUE, matcher, osu::effective_clock_rate};
+use eyre::{Report, Result};
+use rkyv::{
+    rancor::{Panic, ResultExt},
+    with::{Map, With},
+};
+use rosu_v2::{
+    prelude::{OsuError, Score},
+    request::UserId,
+};
+use time::Date;
+use twilight_model::guild::Permissions;
+
+use super::{
+    GRAPH_PLAYTIME_DESC, Graph, GraphData, GraphPalette, GraphPlaytime, H, W, draw_queued,
+    playcount_replays::spoof_monthly_counts,
+    render::{GraphAxes, GraphRenderer, LineGraph},
+};
+use crate::{
+    commands::osu::user_not_found,
+    core::{
+        Context,
+        commands::{CommandOrigin, prefix::Args},
+    },
+    manager::redis::osu::{CachedUser, UserArgs, UserArgsError},
+};
+
+impl<'m> GraphPlaytime<'m> {
+    fn args(mode: Option<GameModeOption>, args: Args<'m>) -> Result<Self, String> {
+        let mut name = None;
+        let mut discord = None;
+        let mut year = None;
+
+        for arg in args {
+            if let Some(value) = arg.strip_prefix("year=") {
+                match value.parse() {
+                    Ok(value) => year = Some(value),
+                    Err(_) => return Err(format!("Failed to parse year `{value}`")),
+                }
+            } else if let Some(id) = matcher::get_mention_user(arg) {
+                discord = Some(id);
+            } else {
+                name = Some(arg.into());
+            }
+        }
+
+        Ok(Self {
+            mode,
+            name,
+            discord,
+            year,
+            theme: None,
+            data: None,
+        })
+    }
+}
+
+#[command]
+#[desc(GRAPH_PLAYTIME_DESC)]
+#[usage("[username] [year=number]")]
+#[examples("peppy year=2023")]
+#[group(Osu)]
+async fn prefix_graphplaytime(
+    msg: &Message,
+    args: Args<'_>,
+    perms: Option<Permissions>,
+) -> Result<()> {
+    let orig = CommandOrigin::from_msg(msg, perms);
+
+    let args = match GraphPlaytime::args(None, args) {
+        Ok(args) => args,
+        Err(content) => return orig.error_with_help(content).await,
+    };
+
+    super::graph(orig, Graph::Playtime(args)).await
+}
+
+#[command]
+#[desc(GRAPH_PLAYTIME_DESC)]
+#[usage("[username] [year=number]")]
+#[examples("peppy year=2023")]
+#[group(Taiko)]
+async fn prefix_graphplaytimetaiko(
+    msg: &Message,
+    args: Args<'_>,
+    perms: Option<Permissions>,
+) -> Result<()> {
+    let orig = CommandOrigin::from_msg(msg, perms);
+
+    let args = match GraphPlaytime::args(Some(GameModeOption::Taiko), args) {
+        Ok(args) => args,
+        Err(content) => return orig.error_with_help(content).await,
+    };
+
+    super::graph(orig, Graph::Playtime(args)).await
+}
+
+#[command]
+#[desc(GRAPH_PLAYTIME_DESC)]
+#[usage("[username] [year=number]")]
+#[examples("peppy year=2023")]
+#[aliases("graphplaytimecatch")]
+#[group(Catch)]
+async fn prefix_graphplaytimectb(
+    msg: &Message,
+    args: Args<'_>,
+    perms: Option<Permissions>,
+) -> Result<()> {
+    let orig = CommandOrigin::from_msg(msg, perms);
+
+    let args = match GraphPlaytime::args(Some(GameModeOption::Catch), args) {
+        Ok(args) => args,
+        Err(content) => return orig.error_with_help(content).await,
+    };
+
+    super::graph(orig, Graph::Playtime(args)).await
+}
+
+#[command]
+#[desc(GRAPH_PLAYTIME_DESC)]
+#[usage("[username] [year=number]")]
+#[examples("peppy year=2023")]
+#[group(Mania)]
+async fn prefix_graphplaytimemania(
+    msg: &Message,
+    args: Args<'_>,
+    perms: Option<Permissions>,
+) -> Result<()> {
+    let orig = CommandOrigin::from_msg(msg, perms);
+
+    let args = match GraphPlaytime::args(Some(GameModeOption::Mania), args) {
+        Ok(args) => args,
+        Err(content) => return orig.error_with_help(content).await,
+    };
+
+    super::graph(orig, Graph::Playtime(args)).await
+}
+
+pub async fn playtime_graph(
+    orig: &CommandOrigin<'_>,
+    user_id: UserId,
+    user_args: UserArgs,
+    year: Option<i32>,
+    legacy_scores: bool,
+    palette: GraphPalette,
+) -> Result<Option<(CachedUser, Vec<u8>, GraphData)>> {
+    let scores_fut = Context::osu_scores()
+        .recent(legacy_scores)
+        .limit(100)
+        .exec_with_user(user_args);
+
+    let (user, scores) = match scores_fut.await {
+        Ok(tuple) => tuple,
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+            orig.error(content).await?;
+
+            return Ok(None);
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user or scores");
+
+            return Err(err);
+        }
+    };
+
+    let Some(avg_len) = average_map_len(&scores) else {
+        let content = format!(
+            "`{}` has no recent scores so the average map length can't be estimated",
+            user.username.as_str()
+        );
+
+        orig.error(content).await?;
+
+        return Ok(None);
+    };
+
+    let mut playcounts = rkyv::api::deserialize_using::<_, _, Panic>(
+        With::<_, Map<MonthlyCountRkyv>>::cast(&user.monthly_playcounts),
+        &mut (),
+    )
+    .always_ok();
+
+    spoof_monthly_counts(&mut playcounts);
+
+    if let Some(year) = year {
+        playcounts.retain(|count| count.start_date.year() == year);
+    }
+
+    if playcounts.len() < 2 {
+        let content = match year {
+            Some(year) => format!(
+                "`{}` does not have enough playcount data points in {year}",
+                user.username.as_str()
+            ),
+            None => format!(
+                "`{}` does not have enough playcount data points",
+                user.username.as_str()
+            ),
+        };
+
+        orig.error(content).await?;
+
+        return Ok(None);
+    }
+
+    let hours: Vec<_> = playcounts
+        .iter()
+        .map(|count| (count.start_date, count.count as f64 * avg_len / 3600.0))
+        .collect();
+
+    let mut data = GraphData::new(&["month", "playcount", "estimated_hours"]);
+
+    for (count, (_, hours)) in playcounts.iter().zip(hours.iter()) {
+        data.push_row(&[&count.start_date, &count.count, hours]);
+    }
+
+    let total: f64 = hours.iter().map(|(_, hours)| hours).sum();
+    let username = user.username.as_str();
+
+    let caption = format!(
+        "{username}'{genitive} estimated playtime{in_year}: {total:.0} hours",
+        genitive = if username.ends_with('s') { "" } else { "s" },
+        in_year = year.map_or_else(String::new, |year| format!(" in {year}")),
+    );
+
+    let bytes = match draw_queued(orig, || draw_graph(caption, &hours, palette)).await {
+        Ok(graph) => graph,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            warn!(?err, "Failed to draw playtime graph");
+
+            return Ok(None);
+        }
+    };
+
+    Ok(Some((user, bytes, data)))
+}
+
+/// Average length in seconds of the maps in the given scores, adjusted for
+/// the clock rate.
+fn average_map_len(scores: &[Score]) -> Option<f64> {
+    let (sum, count) = scores
+        .iter()
+        .filter_map(|score| {
+            let map = score.map.as_ref()?;
+            let clock_rate = effective_clock_rate(&score.mods);
+
+            Some(map.seconds_total as f64 / clock_rate)
+        })
+        .fold((0.0, 0), |(sum, count), len| (sum + len, count + 1));
+
+    (count > 0).then(|| sum / count as f64)
+}
+
+fn draw_graph(caption: String, hours: &[(Date, f64)], palette: GraphPalette) -> Result<Vec<u8>> {
+    let points: Vec<_> = hours
+        .iter()
+        .map(|(date, hours)| (date.to_julian_day() as f64, *hours))
+        .collect();
+
+    let graph = LineGraph {
+        axes: GraphAxes {
+            caption,
+            x_desc: None,
+            y_desc: Some("Estimated hours"),
+        },
+        points: &points,
+        x_labels: 10,
+        x_fmt: &|day| {
+            Date::from_julian_day(*day as i32)
+                .map(|date| format!("{}-{}", date.year(), date.month() as u8))
+                .unwrap_or_default()
+        },
+        y_fmt: &|hours| format!("{hours:.0}h"),
+    };
+
+    graph.render(W, H, palette)
+}
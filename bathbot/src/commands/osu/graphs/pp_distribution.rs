@@ -0,0 +1,308 @@
+use bathbot_macros::command;
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_util::{constants::GENERAL_ISSUE, matcher, osu::BonusPP};
+use eyre::{Report, Result, WrapErr};
+use plotters::{
+    prelude::{ChartBuilder, IntoDrawingArea, PathElement, Rectangle, SeriesLabelPosition},
+    series::{AreaSeries, LineSeries},
+    style::{Color, RGBColor},
+};
+use plotters_backend::FontStyle;
+use plotters_skia::SkiaBackend;
+use rosu_v2::{
+    prelude::{GameMode, OsuError, Score},
+    request::UserId,
+};
+use skia_safe::{EncodedImageFormat, surfaces};
+use twilight_model::guild::Permissions;
+
+use super::{
+    GRAPH_PP_DISTRIBUTION_DESC, Graph, GraphData, GraphPalette, GraphPpDistribution, H, W,
+    draw_queued,
+};
+use crate::{
+    commands::osu::user_not_found,
+    core::{
+        Context,
+        commands::{CommandOrigin, prefix::Args},
+    },
+    manager::redis::osu::{CachedUser, UserArgs, UserArgsError},
+};
+
+impl<'m> GraphPpDistribution<'m> {
+    fn args(mode: Option<GameModeOption>, args: Args<'m>) -> Self {
+        let mut name = None;
+        let mut discord = None;
+
+        for arg in args {
+            if let Some(id) = matcher::get_mention_user(arg) {
+                discord = Some(id);
+            } else {
+                name = Some(arg.into());
+            }
+        }
+
+        Self {
+            mode,
+            name,
+            discord,
+            theme: None,
+            data: None,
+        }
+    }
+}
+
+#[command]
+#[desc(GRAPH_PP_DISTRIBUTION_DESC)]
+#[usage("[username]")]
+#[examples("peppy")]
+#[group(Osu)]
+async fn prefix_graphppdistribution(
+    msg: &Message,
+    args: Args<'_>,
+    perms: Option<Permissions>,
+) -> Result<()> {
+    let args = GraphPpDistribution::args(None, args);
+    let orig = CommandOrigin::from_msg(msg, perms);
+
+    super::graph(orig, Graph::PpDistribution(args)).await
+}
+
+#[command]
+#[desc(GRAPH_PP_DISTRIBUTION_DESC)]
+#[usage("[username]")]
+#[examples("peppy")]
+#[group(Taiko)]
+async fn prefix_graphppdistributiontaiko(
+    msg: &Message,
+    args: Args<'_>,
+    perms: Option<Permissions>,
+) -> Result<()> {
+    let args = GraphPpDistribution::args(Some(GameModeOption::Taiko), args);
+    let orig = CommandOrigin::from_msg(msg, perms);
+
+    super::graph(orig, Graph::PpDistribution(args)).await
+}
+
+#[command]
+#[desc(GRAPH_PP_DISTRIBUTION_DESC)]
+#[usage("[username]")]
+#[examples("peppy")]
+#[aliases("graphppdistributioncatch")]
+#[group(Catch)]
+async fn prefix_graphppdistributionctb(
+    msg: &Message,
+    args: Args<'_>,
+    perms: Option<Permissions>,
+) -> Result<()> {
+    let args = GraphPpDistribution::args(Some(GameModeOption::Catch), args);
+    let orig = CommandOrigin::from_msg(msg, perms);
+
+    super::graph(orig, Graph::PpDistribution(args)).await
+}
+
+#[command]
+#[desc(GRAPH_PP_DISTRIBUTION_DESC)]
+#[usage("[username]")]
+#[examples("peppy")]
+#[group(Mania)]
+async fn prefix_graphppdistributionmania(
+    msg: &Message,
+    args: Args<'_>,
+    perms: Option<Permissions>,
+) -> Result<()> {
+    let args = GraphPpDistribution::args(Some(GameModeOption::Mania), args);
+    let orig = CommandOrigin::from_msg(msg, perms);
+
+    super::graph(orig, Graph::PpDistribution(args)).await
+}
+
+pub async fn pp_distribution_graph(
+    orig: &CommandOrigin<'_>,
+    user_id: UserId,
+    user_args: UserArgs,
+    legacy_scores: bool,
+    palette: GraphPalette,
+) -> Result<Option<(CachedUser, Vec<u8>, GraphData)>> {
+    let scores_fut = Context::osu_scores()
+        .top(200, legacy_scores)
+        .exec_with_user(user_args);
+
+    let (user, scores) = match scores_fut.await {
+        Ok(tuple) => tuple,
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+            orig.error(content).await?;
+
+            return Ok(None);
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user or scores");
+
+            return Err(err);
+        }
+    };
+
+    if scores.is_empty() {
+        let content = "User's top scores are empty";
+        orig.error(content).await?;
+
+        return Ok(None);
+    }
+
+    let mut data = GraphData::new(&["index", "raw_pp", "weighted_pp", "map_id"]);
+
+    for (i, score) in (1..).zip(scores.iter()) {
+        let raw = score.pp.unwrap_or(0.0);
+        let weighted = score.weight.as_ref().map_or(0.0, |weight| weight.pp);
+        data.push_row(&[&i, &raw, &weighted, &score.map_id]);
+    }
+
+    let mut bonus_pp = BonusPP::new();
+
+    for (i, score) in scores.iter().enumerate().take(100) {
+        if let Some(ref weight) = score.weight {
+            bonus_pp.update(weight.pp, i);
+        }
+    }
+
+    let bonus_pp = user
+        .statistics
+        .as_ref()
+        .map_or(0.0, |stats| bonus_pp.calculate(stats));
+
+    let username = user.username.as_str();
+
+    let caption = format!(
+        "{username}'{genitive} {mode}top200 pp distribution",
+        genitive = if username.ends_with('s') { "" } else { "s" },
+        mode = match user.mode {
+            GameMode::Osu => "",
+            GameMode::Taiko => "taiko ",
+            GameMode::Catch => "ctb ",
+            GameMode::Mania => "mania ",
+        }
+    );
+
+    let draw = || draw_graph(caption, &scores, bonus_pp, palette);
+
+    let bytes = match draw_queued(orig, draw).await {
+        Ok(graph) => graph,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            warn!(?err, "Failed to draw pp distribution graph");
+
+            return Ok(None);
+        }
+    };
+
+    Ok(Some((user, bytes, data)))
+}
+
+fn draw_graph(
+    caption: String,
+    scores: &[Score],
+    bonus_pp: f32,
+    palette: GraphPalette,
+) -> Result<Vec<u8>> {
+    let max = scores
+        .iter()
+        .filter_map(|score| score.pp)
+        .fold(bonus_pp, f32::max);
+
+    let max_adj = max + 5.0;
+
+    let mut surface =
+        surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
+
+    {
+        let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
+
+        let background = palette.background;
+        root.fill(&background)
+            .wrap_err("Failed to fill background")?;
+
+        let caption_style = ("sans-serif", 25_i32, FontStyle::Bold, &palette.text);
+
+        let mut chart = ChartBuilder::on(&root)
+            .x_label_area_size(40_i32)
+            .y_label_area_size(60_i32)
+            .margin_top(5_i32)
+            .margin_right(15_i32)
+            .caption(caption, caption_style)
+            .build_cartesian_2d(1..scores.len() + 1, 0.0_f32..max_adj)
+            .wrap_err("Failed to build chart")?;
+
+        chart
+            .configure_mesh()
+            .x_desc("Index")
+            .y_label_formatter(&|pp| format!("{pp:.0}pp"))
+            .label_style(("sans-serif", 16_i32, &palette.text))
+            .bold_line_style(palette.mesh.mix(0.3))
+            .axis_style(palette.axis)
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &palette.text))
+            .draw()
+            .wrap_err("Failed to draw mesh")?;
+
+        let raw_color = palette.area;
+        let iter = (1..).zip(scores).filter_map(|(i, s)| Some((i, s.pp?)));
+
+        let series = AreaSeries::new(iter, 0.0, raw_color.mix(0.5).filled())
+            .border_style(raw_color.stroke_width(2));
+
+        chart
+            .draw_series(series)
+            .wrap_err("Failed to draw raw pp area")?
+            .label("Raw pp")
+            .legend(move |(x, y)| {
+                Rectangle::new([(x, y - 5), (x + 10, y + 5)], raw_color.filled())
+            });
+
+        let weighted_color = palette.border;
+
+        let iter = (1..)
+            .zip(scores)
+            .filter_map(|(i, s)| Some((i, s.weight.as_ref()?.pp)));
+
+        let series = AreaSeries::new(iter, 0.0, weighted_color.mix(0.7).filled())
+            .border_style(weighted_color.stroke_width(2));
+
+        chart
+            .draw_series(series)
+            .wrap_err("Failed to draw weighted pp area")?
+            .label("Weighted pp")
+            .legend(move |(x, y)| {
+                Rectangle::new([(x, y - 5), (x + 10, y + 5)], weighted_color.filled())
+            });
+
+        let bonus_color = RGBColor(255, 102, 170);
+        let bonus_line = [(1, bonus_pp), (scores.len(), bonus_pp)];
+
+        chart
+            .draw_series(LineSeries::new(bonus_line, bonus_color.stroke_width(2)))
+            .wrap_err("Failed to draw bonus pp line")?
+            .label(format!("Bonus pp: {bonus_pp:.2}pp"))
+            .legend(move |(x, y)| {
+                PathElement::new(vec![(x, y), (x + 10, y)], bonus_color.stroke_width(2))
+            });
+
+        chart
+            .configure_series_labels()
+            .border_style(palette.text.mix(0.6).stroke_width(1))
+            .background_style(palette.legend_background)
+            .position(SeriesLabelPosition::UpperRight)
+            .legend_area_size(15_i32)
+            .label_font(("sans-serif", 16_i32, FontStyle::Bold, &palette.text))
+            .draw()
+            .wrap_err("Failed to draw legend")?;
+    }
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok(png_bytes)
+}
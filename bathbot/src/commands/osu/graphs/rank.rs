@@ -2,23 +2,25 @@ use std::iter;
 
 use bathbot_macros::command;
 use bathbot_model::command_fields::GameModeOption;
+use bathbot_psql::model::osu::DbRankSnapshot;
 use bathbot_util::{constants::GENERAL_ISSUE, matcher, numbers::WithComma};
 use eyre::{ContextCompat, Report, Result, WrapErr};
 use plotters::{
-    prelude::{ChartBuilder, Circle, IntoDrawingArea, SeriesLabelPosition},
-    series::AreaSeries,
-    style::{BLACK, Color, GREEN, RED, RGBColor, ShapeStyle, WHITE},
+    prelude::{ChartBuilder, Circle, IntoDrawingArea, Polygon, SeriesLabelPosition},
+    series::{AreaSeries, DashedLineSeries, LineSeries},
+    style::{BLACK, Color, GREEN, RED, RGBColor, ShapeStyle},
 };
 use plotters_backend::FontStyle;
 use plotters_skia::SkiaBackend;
 use rosu_v2::{prelude::OsuError, request::UserId};
-use skia_safe::{EncodedImageFormat, surfaces};
+use skia_safe::{EncodedImageFormat, Surface, surfaces};
+use time::{Date, Duration, OffsetDateTime};
 use twilight_model::guild::Permissions;
 
 use super::{Graph, GraphRank};
 use crate::{
     commands::osu::{
-        graphs::{GRAPH_RANK_DESC, H, W},
+        graphs::{GRAPH_RANK_DESC, GraphData, GraphPalette, H, W, animation::animate, draw_queued},
         user_not_found,
     },
     core::{
@@ -47,6 +49,10 @@ impl<'m> GraphRank<'m> {
             discord,
             from: None,
             until: None,
+            theme: None,
+            animate: None,
+            uncertainty: None,
+            data: None,
         }
     }
 }
@@ -112,202 +118,540 @@ async fn prefix_graphrankmania(
     super::graph(orig, Graph::Rank(args)).await
 }
 
+#[allow(clippy::too_many_arguments)]
 pub async fn rank_graph(
     orig: &CommandOrigin<'_>,
     user_id: UserId,
     user_args: UserArgs,
-    from: Option<u8>,
-    until: Option<u8>,
-) -> Result<Option<(CachedUser, Vec<u8>)>> {
-    fn draw_graph(user: &CachedUser, from: u8, until: u8) -> Result<Option<Vec<u8>>> {
-        if user.rank_history.len() < 90 - from as usize {
+    from: Option<u16>,
+    until: Option<u16>,
+    animate: bool,
+    uncertainty: bool,
+    palette: GraphPalette,
+) -> Result<Option<(CachedUser, Box<[u32]>, Vec<u8>, GraphData)>> {
+    let user = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user,
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+            orig.error(content).await?;
+
             return Ok(None);
         }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user");
 
-        let history = &user.rank_history[90 - until as usize..90 - from as usize];
-
-        let mut min = u32::MAX;
-        let mut max = 0;
+            return Err(err);
+        }
+    };
 
-        let mut min_idx = 0;
-        let mut max_idx = 0;
+    let history = rank_history(&user).await;
+    let (from_unwrapped, until_unwrapped) = rank_graph_range(from, until);
+
+    let draw = || {
+        if animate {
+            animate_rank_graph(
+                &history,
+                from_unwrapped,
+                until_unwrapped,
+                uncertainty,
+                palette,
+            )
+        } else {
+            draw_rank_graph(
+                &history,
+                from_unwrapped,
+                until_unwrapped,
+                uncertainty,
+                palette,
+            )
+        }
+    };
 
-        for (&rank, i) in history.iter().zip(from as usize..) {
-            let rank = rank.to_native();
+    let bytes = match draw_queued(orig, draw).await {
+        Ok(Some(graph)) => graph,
+        Ok(None) => {
+            let mut content = format!(
+                "`{name}` has no available rank data",
+                name = user.username.as_str()
+            );
 
-            if rank == 0 {
-                continue;
+            if from.is_some() || until.is_some() {
+                content.push_str(" for this time range");
             }
 
-            if rank < min {
-                min = rank;
-                min_idx = i;
+            orig.error(content).await?;
 
-                if rank > max {
-                    max = rank;
-                    max_idx = i;
-                }
-            } else if rank > max {
-                max = rank;
-                max_idx = i;
-            }
+            return Ok(None);
         }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            warn!(?err, "Failed to draw rank graph");
 
-        let y_label_area_size = if max > 1_000_000 {
-            85
-        } else if max > 100_000 {
-            80
-        } else if max > 10_000 {
-            75
-        } else if max > 1000 {
-            70
-        } else if max > 100 {
-            65
-        } else if max > 10 {
-            60
-        } else {
-            50
-        };
+            return Ok(None);
+        }
+    };
+
+    let today = OffsetDateTime::now_utc().date();
+    let data = rank_data(&history, from_unwrapped, until_unwrapped, today);
 
-        let (min, max) = (-(max as i32), -(min as i32));
+    Ok(Some((user, history, bytes, data)))
+}
 
-        let mut surface = surfaces::raster_n32_premul((W as i32, H as i32))
-            .wrap_err("Failed to create surface")?;
+/// Daily ranks between `from` and `until` days ago, skipping days without
+/// data.
+fn rank_data(history: &[u32], from: u16, until: u16, today: Date) -> GraphData {
+    let mut data = GraphData::new(&["date", "rank"]);
 
-        {
-            let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
+    let Some((history, until)) = rank_graph_slice(history, from, until) else {
+        return data;
+    };
 
-            let background = RGBColor(19, 43, 33);
-            root.fill(&background)
-                .wrap_err("Failed to fill background")?;
+    for (i, rank) in history.iter().enumerate() {
+        if *rank == 0 {
+            continue;
+        }
 
-            let style: fn(RGBColor) -> ShapeStyle = |color| ShapeStyle {
-                color: color.to_rgba(),
-                filled: false,
-                stroke_width: 1,
-            };
+        let days_ago = until as i64 - 1 - i as i64;
+        data.push_row(&[&(today - Duration::days(days_ago)), rank]);
+    }
 
-            let mut chart = ChartBuilder::on(&root)
-                .x_label_area_size(40)
-                .y_label_area_size(y_label_area_size)
-                .margin(10)
-                .margin_left(6)
-                .build_cartesian_2d(from as u32..(until as u32).saturating_sub(1), min..max)
-                .wrap_err("Failed to build chart")?;
+    data
+}
 
-            chart
-                .configure_mesh()
-                .disable_y_mesh()
-                .x_labels(20)
-                .x_desc("Days ago")
-                .x_label_formatter(&|x| format!("{}", (until + from) as u32 - *x))
-                .y_label_formatter(&|y| format!("{}", -*y))
-                .y_desc("Rank")
-                .label_style(("sans-serif", 15, &WHITE))
-                .bold_line_style(WHITE.mix(0.3))
-                .axis_style(RGBColor(7, 18, 14))
-                .axis_desc_style(("sans-serif", 16, FontStyle::Bold, &WHITE))
-                .draw()
-                .wrap_err("Failed to draw mesh")?;
-
-            let data = (from as u32..)
-                .zip(history.iter().map(|rank| -(rank.to_native() as i32)))
-                .skip_while(|(_, rank)| *rank == 0)
-                .take_while(|(_, rank)| *rank != 0);
-
-            let area_style = RGBColor(2, 186, 213).mix(0.7).filled();
-            let border_style = style(RGBColor(0, 208, 138)).stroke_width(3);
-            let series = AreaSeries::new(data, min, area_style).border_style(border_style);
-            chart.draw_series(series).wrap_err("Failed to draw area")?;
-
-            let max_coords = (min_idx as u32, max);
-            let circle = Circle::new(max_coords, 9_u32, style(GREEN).stroke_width(2));
+/// The osu!api only provides the last 90 days of rank history so older days
+/// are filled in with daily snapshots of tracked users.
+///
+/// The last entry is the current rank and days without data are `0`.
+async fn rank_history(user: &CachedUser) -> Box<[u32]> {
+    let api_history: Vec<_> = user
+        .rank_history
+        .iter()
+        .map(|rank| rank.to_native())
+        .collect();
 
-            chart
-                .draw_series(iter::once(circle))
-                .wrap_err("Failed to draw max circle")?
-                .label(format!("Peak: #{}", WithComma::new(-max)))
-                .legend(|(x, y)| Circle::new((x, y), 5_u32, style(GREEN).stroke_width(2)));
+    let snapshots_fut = Context::psql().select_rank_snapshots(user.user_id.to_native(), user.mode);
 
-            let min_coords = (max_idx as u32, min);
-            let circle = Circle::new(min_coords, 9_u32, style(RED).stroke_width(2));
+    match snapshots_fut.await {
+        Ok(snapshots) => {
+            let today = OffsetDateTime::now_utc().date();
 
-            chart
-                .draw_series(iter::once(circle))
-                .wrap_err("Failed to draw min circle")?
-                .label(format!("Worst: #{}", WithComma::new(-min)))
-                .legend(|(x, y)| Circle::new((x, y), 5_u32, style(RED).stroke_width(2)));
+            merge_rank_history(api_history, &snapshots, today)
+        }
+        Err(err) => {
+            warn!(?err, "Failed to get rank snapshots");
 
-            let limit = (until - from) / 2 + from;
+            api_history.into_boxed_slice()
+        }
+    }
+}
 
-            let position = if min_idx >= limit as usize {
-                SeriesLabelPosition::UpperLeft
-            } else {
-                SeriesLabelPosition::UpperRight
-            };
+fn merge_rank_history(
+    api_history: Vec<u32>,
+    snapshots: &[DbRankSnapshot],
+    today: Date,
+) -> Box<[u32]> {
+    let days_ago =
+        |snapshot: &DbRankSnapshot| usize::try_from((today - snapshot.date).whole_days());
 
-            chart
-                .configure_series_labels()
-                .border_style(BLACK.stroke_width(2))
-                .background_style(RGBColor(192, 192, 192))
-                .position(position)
-                .legend_area_size(13)
-                .label_font(("sans-serif", 15, FontStyle::Bold))
-                .draw()
-                .wrap_err("Failed to draw legend")?;
+    let Some(oldest) = snapshots.iter().filter_map(|s| days_ago(s).ok()).max() else {
+        return api_history.into_boxed_slice();
+    };
+
+    let len = api_history.len().max(oldest + 1);
+    let mut history = vec![0; len];
+
+    for snapshot in snapshots {
+        if let Ok(days_ago) = days_ago(snapshot) {
+            history[len - 1 - days_ago] = snapshot.global_rank as u32;
         }
+    }
 
-        let png_bytes = surface
-            .image_snapshot()
-            .encode(None, EncodedImageFormat::PNG, None)
-            .wrap_err("Failed to encode image")?
-            .to_vec();
+    // The osu!api data takes precedence for the days it covers
+    let offset = len - api_history.len();
 
-        Ok(Some(png_bytes))
+    for (entry, rank) in history[offset..].iter_mut().zip(api_history) {
+        if rank > 0 {
+            *entry = rank;
+        }
     }
 
-    let user = match Context::redis().osu_user(user_args).await {
-        Ok(user) => user,
-        Err(UserArgsError::Osu(OsuError::NotFound)) => {
-            let content = user_not_found(user_id).await;
-            orig.error(content).await?;
+    history.into_boxed_slice()
+}
 
-            return Ok(None);
+/// Fill in defaults for the `from` and `until` days and make sure the range
+/// spans at least two days.
+pub fn rank_graph_range(from: Option<u16>, until: Option<u16>) -> (u16, u16) {
+    let from = from.unwrap_or(0);
+    let until = u16::max(until.unwrap_or(90), from.saturating_add(2));
+
+    (from, until)
+}
+
+/// Draw the rank history between `from` and `until` days ago.
+///
+/// If `uncertainty` is set, gaps in the history are shaded by how much the
+/// rank could have changed in the meantime.
+///
+/// Returns `None` if the history does not cover at least two days of the
+/// time range.
+pub fn draw_rank_graph(
+    history: &[u32],
+    from: u16,
+    until: u16,
+    uncertainty: bool,
+    palette: GraphPalette,
+) -> Result<Option<Vec<u8>>> {
+    let Some((history, until)) = rank_graph_slice(history, from, until) else {
+        return Ok(None);
+    };
+
+    let mut surface =
+        surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
+
+    draw_rank_chart(
+        &mut surface,
+        history,
+        from,
+        until,
+        history.len(),
+        uncertainty,
+        palette,
+    )?;
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok(Some(png_bytes))
+}
+
+/// Same as [`draw_rank_graph`] but encodes a gif that progressively reveals
+/// the rank history.
+pub fn animate_rank_graph(
+    history: &[u32],
+    from: u16,
+    until: u16,
+    uncertainty: bool,
+    palette: GraphPalette,
+) -> Result<Option<Vec<u8>>> {
+    let Some((history, until)) = rank_graph_slice(history, from, until) else {
+        return Ok(None);
+    };
+
+    animate(history.len(), |surface, revealed| {
+        draw_rank_chart(
+            surface,
+            history,
+            from,
+            until,
+            revealed,
+            uncertainty,
+            palette,
+        )
+    })
+    .map(Some)
+}
+
+/// Returns the part of the history between `from` and `until` days ago
+/// alongside the clamped `until` value.
+fn rank_graph_slice(history: &[u32], from: u16, until: u16) -> Option<(&[u32], u16)> {
+    let len = history.len();
+    let until = until.min(len.min(u16::MAX as usize) as u16);
+
+    if until < from.saturating_add(2) {
+        return None;
+    }
+
+    Some((&history[len - until as usize..len - from as usize], until))
+}
+
+/// Draw the chart onto the surface while only showing the first `revealed`
+/// entries of the history.
+fn draw_rank_chart(
+    surface: &mut Surface,
+    history: &[u32],
+    from: u16,
+    until: u16,
+    revealed: usize,
+    uncertainty: bool,
+    palette: GraphPalette,
+) -> Result<()> {
+    let mut min = u32::MAX;
+    let mut max = 0;
+
+    let mut min_idx = 0;
+    let mut max_idx = 0;
+
+    for (&rank, i) in history.iter().zip(from as usize..) {
+        if rank == 0 {
+            continue;
         }
-        Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
-            let err = Report::new(err).wrap_err("Failed to get user");
 
-            return Err(err);
+        if rank < min {
+            min = rank;
+            min_idx = i;
+
+            if rank > max {
+                max = rank;
+                max_idx = i;
+            }
+        } else if rank > max {
+            max = rank;
+            max_idx = i;
         }
+    }
+
+    let y_label_area_size = if max > 1_000_000 {
+        85
+    } else if max > 100_000 {
+        80
+    } else if max > 10_000 {
+        75
+    } else if max > 1000 {
+        70
+    } else if max > 100 {
+        65
+    } else if max > 10 {
+        60
+    } else {
+        50
     };
 
-    let from_unwrapped = from.unwrap_or(0);
-    let until_unwrapped = u8::max(until.unwrap_or(90), u8::min(from_unwrapped + 2, 90));
+    let (min, max) = (-(max as i32), -(min as i32));
 
-    let bytes = match draw_graph(&user, from_unwrapped, until_unwrapped) {
-        Ok(Some(graph)) => graph,
-        Ok(None) => {
-            let mut content = format!(
-                "`{name}` has no available rank data",
-                name = user.username.as_str()
-            );
+    {
+        let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
 
-            if from.is_some() || until.is_some() {
-                content.push_str(" for this time range");
+        let background = palette.background;
+        root.fill(&background)
+            .wrap_err("Failed to fill background")?;
+
+        let style: fn(RGBColor) -> ShapeStyle = |color| ShapeStyle {
+            color: color.to_rgba(),
+            filled: false,
+            stroke_width: 1,
+        };
+
+        let mut chart = ChartBuilder::on(&root)
+            .x_label_area_size(40)
+            .y_label_area_size(y_label_area_size)
+            .margin(10)
+            .margin_left(6)
+            .build_cartesian_2d(from as u32..(until as u32).saturating_sub(1), min..max)
+            .wrap_err("Failed to build chart")?;
+
+        chart
+            .configure_mesh()
+            .disable_y_mesh()
+            .x_labels(20)
+            .x_desc("Days ago")
+            .x_label_formatter(&|x| format!("{}", until as u32 + from as u32 - *x))
+            .y_label_formatter(&|y| format!("{}", -*y))
+            .y_desc("Rank")
+            .label_style(("sans-serif", 15, &palette.text))
+            .bold_line_style(palette.mesh.mix(0.3))
+            .axis_style(palette.axis)
+            .axis_desc_style(("sans-serif", 16, FontStyle::Bold, &palette.text))
+            .draw()
+            .wrap_err("Failed to draw mesh")?;
+
+        // Days without data are skipped rather than ending the series since
+        // stored snapshots may have gaps
+        let points: Vec<_> = (from as u32..)
+            .zip(history.iter().map(|&rank| -(rank as i32)))
+            .take(revealed)
+            .filter(|(_, rank)| *rank != 0)
+            .collect();
+
+        let area_style = palette.area.mix(0.7).filled();
+        let series = AreaSeries::new(points.iter().copied(), min, area_style);
+        chart.draw_series(series).wrap_err("Failed to draw area")?;
+
+        let runs = split_at_gaps(&points);
+
+        if uncertainty {
+            let volatility = rank_volatility(&points);
+            let band_style = palette.border.mix(0.3).filled();
+
+            for (prev, next) in runs.iter().zip(runs.iter().skip(1)) {
+                let (Some(&start), Some(&end)) = (prev.last(), next.first()) else {
+                    continue;
+                };
+
+                let band = gap_band(start, end, volatility, min, max);
+                let polygon = Polygon::new(band, band_style);
+
+                chart
+                    .draw_series(iter::once(polygon))
+                    .wrap_err("Failed to draw uncertainty")?;
             }
+        }
 
-            orig.error(content).await?;
+        let border_style = style(palette.border).stroke_width(3);
 
-            return Ok(None);
+        for run in runs.iter() {
+            let series = LineSeries::new(run.iter().copied(), border_style);
+            chart
+                .draw_series(series)
+                .wrap_err("Failed to draw rank line")?;
         }
-        Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
-            warn!(?err, "Failed to draw rank graph");
 
-            return Ok(None);
+        // Interpolated lines across gaps are dashed so they're not mistaken
+        // for actual data
+        for (prev, next) in runs.iter().zip(runs.iter().skip(1)) {
+            let (Some(&start), Some(&end)) = (prev.last(), next.first()) else {
+                continue;
+            };
+
+            let series = DashedLineSeries::new([start, end], 10, 8, border_style);
+
+            chart
+                .draw_series(series)
+                .wrap_err("Failed to draw interpolated rank line")?;
         }
-    };
 
-    Ok(Some((user, bytes)))
+        // Circles are only drawn once their data point has been revealed
+        let is_revealed = |idx: usize| (idx - from as usize) < revealed;
+
+        let max_coords = (min_idx as u32, max);
+        let circle = Circle::new(max_coords, 9_u32, style(GREEN).stroke_width(2));
+
+        chart
+            .draw_series(iter::once(circle).filter(|_| is_revealed(min_idx)))
+            .wrap_err("Failed to draw max circle")?
+            .label(format!("Peak: #{}", WithComma::new(-max)))
+            .legend(|(x, y)| Circle::new((x, y), 5_u32, style(GREEN).stroke_width(2)));
+
+        let min_coords = (max_idx as u32, min);
+        let circle = Circle::new(min_coords, 9_u32, style(RED).stroke_width(2));
+
+        chart
+            .draw_series(iter::once(circle).filter(|_| is_revealed(max_idx)))
+            .wrap_err("Failed to draw min circle")?
+            .label(format!("Worst: #{}", WithComma::new(-min)))
+            .legend(|(x, y)| Circle::new((x, y), 5_u32, style(RED).stroke_width(2)));
+
+        let limit = (until - from) / 2 + from;
+
+        let position = if min_idx >= limit as usize {
+            SeriesLabelPosition::UpperLeft
+        } else {
+            SeriesLabelPosition::UpperRight
+        };
+
+        chart
+            .configure_series_labels()
+            .border_style(BLACK.stroke_width(2))
+            .background_style(RGBColor(192, 192, 192))
+            .position(position)
+            .legend_area_size(13)
+            .label_font(("sans-serif", 15, FontStyle::Bold))
+            .draw()
+            .wrap_err("Failed to draw legend")?;
+    }
+
+    Ok(())
+}
+
+/// Gaps in the rank history that span more days than this are interpolated.
+const MAX_GAP: u32 = 3;
+
+/// Split the points into runs without gaps longer than [`MAX_GAP`] days.
+fn split_at_gaps(points: &[(u32, i32)]) -> Vec<&[(u32, i32)]> {
+    let mut runs = Vec::new();
+    let mut start = 0;
+
+    for (i, window) in points.windows(2).enumerate() {
+        if window[1].0 - window[0].0 > MAX_GAP {
+            runs.push(&points[start..=i]);
+            start = i + 1;
+        }
+    }
+
+    if start < points.len() {
+        runs.push(&points[start..]);
+    }
+
+    runs
+}
+
+/// Typical rank change per day, estimated as random walk over all
+/// consecutive data points.
+fn rank_volatility(points: &[(u32, i32)]) -> f64 {
+    let (sum, count) = points
+        .windows(2)
+        .map(|window| {
+            let days = (window[1].0 - window[0].0) as f64;
+            let diff = (window[1].1 - window[0].1) as f64;
+
+            diff * diff / days
+        })
+        .fold((0.0, 0), |(sum, count), variance| {
+            (sum + variance, count + 1)
+        });
+
+    if count == 0 {
+        return 0.0;
+    }
+
+    (sum / count as f64).sqrt()
+}
+
+/// Polygon around the interpolated line between `start` and `end` that widens
+/// the further a day is from both data points.
+///
+/// The band is clamped to the chart's `min` and `max` rank.
+fn gap_band(
+    start: (u32, i32),
+    end: (u32, i32),
+    volatility: f64,
+    min: i32,
+    max: i32,
+) -> Vec<(u32, i32)> {
+    let total = (end.0 - start.0) as f64;
+
+    let (upper, lower): (Vec<_>, Vec<_>) = (start.0..=end.0)
+        .map(|day| {
+            let elapsed = (day - start.0) as f64;
+            let rank = start.1 as f64 + (end.1 - start.1) as f64 * elapsed / total;
+
+            // Standard deviation of a random walk pinned at both ends
+            let deviation = volatility * (elapsed * (total - elapsed) / total).sqrt();
+
+            let upper = ((rank + deviation).round() as i32).min(max);
+            let lower = ((rank - deviation).round() as i32).max(min);
+
+            ((day, upper), (day, lower))
+        })
+        .unzip();
+
+    upper.into_iter().chain(lower.into_iter().rev()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_rank_gaps() {
+        let points = [(0, -10), (1, -11), (5, -12), (6, -12), (20, -15)];
+        let runs = split_at_gaps(&points);
+
+        assert_eq!(runs, [&points[..2], &points[2..4], &points[4..]]);
+    }
+
+    #[test]
+    fn gap_band_pinned_at_data() {
+        let band = gap_band((0, -100), (4, -120), 10.0, -1000, 0);
+
+        assert_eq!(band.len(), 10);
+        assert_eq!(band[0], (0, -100));
+        assert_eq!(band[4], (4, -120));
+        assert_eq!(band[5], (4, -120));
+        assert_eq!(band[9], (0, -100));
+        assert!(band[2].1 > -110 && band[7].1 < -110);
+    }
 }
@@ -0,0 +1,149 @@
+use std::ops::ControlFlow;
+
+use bathbot_psql::model::configs::ScoreData;
+use bathbot_util::constants::GENERAL_ISSUE;
+use eyre::{ContextCompat, Result, WrapErr};
+use rosu_v2::prelude::{GameMode, Score};
+use time::Date;
+
+use super::{
+    GraphData, GraphMap, GraphMapRankingHistory, GraphPalette, H, MapResult, W, csv_datetime,
+    draw_queued, get_graph_map,
+    render::{GraphAxes, GraphRenderer, ScatterGraph},
+};
+use crate::core::{Context, commands::CommandOrigin};
+
+/// Amount of leaderboard scores to draw
+const LIMIT: u32 = 50;
+
+pub async fn map_ranking_history(
+    orig: &CommandOrigin<'_>,
+    args: GraphMapRankingHistory<'_>,
+) -> Result<ControlFlow<(), MapResult>> {
+    let config = match Context::user_config().with_osu_id(orig.user_id()?).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get user config"));
+        }
+    };
+
+    let mode = args.mode.map(GameMode::from);
+    let palette = GraphPalette::new(args.theme.or(config.graph_theme).unwrap_or_default());
+
+    let map = match get_graph_map(orig, args.map.as_deref(), None, mode, None).await? {
+        ControlFlow::Continue(map) => map,
+        ControlFlow::Break(()) => return Ok(ControlFlow::Break(())),
+    };
+
+    // No file was attached so the map was retrieved through its id
+    let GraphMap::Full(ref osu_map) = map else {
+        return Ok(ControlFlow::Break(()));
+    };
+
+    let legacy_scores = match config.score_data {
+        Some(score_data) => score_data.is_legacy(),
+        None => match orig.guild_id() {
+            Some(guild_id) => Context::guild_config()
+                .peek(guild_id, |config| config.score_data)
+                .await
+                .is_some_and(ScoreData::is_legacy),
+            None => false,
+        },
+    };
+
+    let scores_fut = Context::osu_scores().map_leaderboard(
+        osu_map.map_id(),
+        map.mode(),
+        None,
+        LIMIT,
+        legacy_scores,
+    );
+
+    let scores = match scores_fut.await {
+        Ok(scores) => scores,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let points = ranking_points(&scores);
+
+    if points.is_empty() {
+        let content = "The leaderboard of this map has no scores with pp";
+
+        return orig.error(content).await.map(ControlFlow::Break);
+    }
+
+    let bytes = match draw_queued(orig, || draw_ranking_history(&points, palette)).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to draw ranking history graph"));
+        }
+    };
+
+    let data = ranking_data(&scores);
+
+    Ok(ControlFlow::Continue(MapResult::new(&map, bytes, data)))
+}
+
+/// Leaderboard position, date, and pp of each score with pp.
+fn ranking_data(scores: &[Score]) -> GraphData {
+    let mut data = GraphData::new(&["position", "date", "pp", "user_id"]);
+
+    for (i, score) in scores.iter().enumerate() {
+        let Some(pp) = score.pp.filter(|&pp| pp > 0.0) else {
+            continue;
+        };
+
+        data.push_row(&[&(i + 1), &csv_datetime(score.ended_at), &pp, &score.user_id]);
+    }
+
+    data
+}
+
+/// Julian day on which each score was set alongside its pp. The top score
+/// comes first.
+fn ranking_points(scores: &[Score]) -> Vec<(f64, f64)> {
+    scores
+        .iter()
+        .filter_map(|score| {
+            let pp = score.pp.filter(|&pp| pp > 0.0)?;
+
+            Some((score.ended_at.date().to_julian_day() as f64, pp as f64))
+        })
+        .collect()
+}
+
+fn draw_ranking_history(points: &[(f64, f64)], palette: GraphPalette) -> Result<Vec<u8>> {
+    let (highlight, rest) = points.split_first().wrap_err("No points to draw")?;
+
+    let caption = format!(
+        "Top {} leaderboard scores by date, #1 is highlighted",
+        points.len()
+    );
+
+    let graph = ScatterGraph {
+        axes: GraphAxes {
+            caption,
+            x_desc: Some("Date set"),
+            y_desc: Some("pp"),
+        },
+        points: rest,
+        highlight: *highlight,
+        x_labels: 10,
+        x_fmt: &|day| {
+            Date::from_julian_day(*day as i32)
+                .map(|date| format!("{}-{:02}", date.year(), date.month() as u8))
+                .unwrap_or_default()
+        },
+        y_fmt: &|pp| format!("{pp:.0}"),
+    };
+
+    graph.render(W, H, palette)
+}
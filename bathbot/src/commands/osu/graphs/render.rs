@@ -0,0 +1,311 @@
+use eyre::{ContextCompat, Result, WrapErr};
+use plotters::{
+    coord::Shift,
+    element::Pie,
+    prelude::{ChartBuilder, Circle, DrawingArea, IntoDrawingArea, Rectangle},
+    series::AreaSeries,
+    style::{Color, IntoFont, RGBColor},
+};
+use plotters_backend::FontStyle;
+use plotters_skia::SkiaBackend;
+use skia_safe::{EncodedImageFormat, surfaces};
+
+use super::GraphPalette;
+
+pub type GraphArea<'a> = DrawingArea<SkiaBackend<'a>, Shift>;
+
+/// A graph that can be rendered by any command without having to deal with
+/// surfaces, theming, or encoding itself.
+pub trait GraphRenderer {
+    /// Draw the graph onto an area whose background is already filled.
+    fn draw(&self, root: &GraphArea<'_>, palette: GraphPalette) -> Result<()>;
+
+    /// Render the graph into PNG bytes.
+    fn render(&self, w: u32, h: u32, palette: GraphPalette) -> Result<Vec<u8>> {
+        let mut surface = surfaces::raster_n32_premul((w as i32, h as i32))
+            .wrap_err("Failed to create surface")?;
+
+        {
+            let root = SkiaBackend::new(surface.canvas(), w, h).into_drawing_area();
+
+            root.fill(&palette.background)
+                .wrap_err("Failed to fill background")?;
+
+            self.draw(&root, palette)?;
+        }
+
+        let png_bytes = surface
+            .image_snapshot()
+            .encode(None, EncodedImageFormat::PNG, None)
+            .wrap_err("Failed to encode image")?
+            .to_vec();
+
+        Ok(png_bytes)
+    }
+}
+
+/// Caption and axis descriptions of a standard graph.
+pub struct GraphAxes {
+    pub caption: String,
+    pub x_desc: Option<&'static str>,
+    pub y_desc: Option<&'static str>,
+}
+
+/// Area below a line through the given points, starting at zero.
+pub struct LineGraph<'a> {
+    pub axes: GraphAxes,
+    pub points: &'a [(f64, f64)],
+    pub x_labels: usize,
+    pub x_fmt: &'a dyn Fn(&f64) -> String,
+    pub y_fmt: &'a dyn Fn(&f64) -> String,
+}
+
+impl GraphRenderer for LineGraph<'_> {
+    fn draw(&self, root: &GraphArea<'_>, palette: GraphPalette) -> Result<()> {
+        let first = self.points.first().wrap_err("No points to draw")?.0;
+        let last = self.points.last().wrap_err("No points to draw")?.0;
+        let max = self.points.iter().map(|(_, y)| *y).fold(1.0, f64::max);
+
+        let mut chart = ChartBuilder::on(root)
+            .margin(9_i32)
+            .x_label_area_size(x_label_area_size(&self.axes))
+            .y_label_area_size(60_i32)
+            .caption(&self.axes.caption, caption_style(&palette.text))
+            .build_cartesian_2d(first..last, 0.0..max * 1.05)
+            .wrap_err("Failed to build chart")?;
+
+        let mut mesh = chart.configure_mesh();
+
+        mesh.light_line_style(palette.mesh.mix(0.0))
+            .disable_x_mesh()
+            .x_labels(self.x_labels)
+            .x_label_formatter(self.x_fmt)
+            .y_label_formatter(self.y_fmt)
+            .label_style(("sans-serif", 16_i32, &palette.text))
+            .bold_line_style(palette.mesh.mix(0.3))
+            .axis_style(palette.axis)
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &palette.text));
+
+        if let Some(desc) = self.axes.x_desc {
+            mesh.x_desc(desc);
+        }
+
+        if let Some(desc) = self.axes.y_desc {
+            mesh.y_desc(desc);
+        }
+
+        mesh.draw().wrap_err("Failed to draw mesh")?;
+
+        let series = AreaSeries::new(
+            self.points.iter().copied(),
+            0.0,
+            palette.area.mix(0.5).filled(),
+        )
+        .border_style(palette.border.stroke_width(2));
+
+        chart.draw_series(series).wrap_err("Failed to draw area")?;
+
+        Ok(())
+    }
+}
+
+/// Bars for counts of consecutive buckets with equal width.
+pub struct Histogram<'a> {
+    pub axes: GraphAxes,
+    pub start: u32,
+    pub bucket_width: u32,
+    pub counts: &'a [usize],
+    pub x_labels: usize,
+    pub x_fmt: &'a dyn Fn(&u32) -> String,
+}
+
+impl GraphRenderer for Histogram<'_> {
+    fn draw(&self, root: &GraphArea<'_>, palette: GraphPalette) -> Result<()> {
+        let end = self.start + self.counts.len() as u32 * self.bucket_width;
+        let max = self.counts.iter().copied().max().unwrap_or(0).max(1);
+
+        let mut chart = ChartBuilder::on(root)
+            .x_label_area_size(x_label_area_size(&self.axes))
+            .y_label_area_size(60_i32)
+            .margin_top(5_i32)
+            .margin_right(15_i32)
+            .caption(&self.axes.caption, caption_style(&palette.text))
+            .build_cartesian_2d(self.start..end, 0_usize..max + max / 10 + 1)
+            .wrap_err("Failed to build chart")?;
+
+        let mut mesh = chart.configure_mesh();
+
+        mesh.disable_x_mesh()
+            .x_labels(self.x_labels)
+            .x_label_formatter(self.x_fmt)
+            .label_style(("sans-serif", 16_i32, &palette.text))
+            .bold_line_style(palette.mesh.mix(0.3))
+            .axis_style(palette.axis)
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &palette.text));
+
+        if let Some(desc) = self.axes.x_desc {
+            mesh.x_desc(desc);
+        }
+
+        if let Some(desc) = self.axes.y_desc {
+            mesh.y_desc(desc);
+        }
+
+        mesh.draw().wrap_err("Failed to draw mesh")?;
+
+        let bar_style = palette.area.mix(0.8).filled();
+        let border_style = palette.border.stroke_width(2);
+
+        let bars = (self.start..)
+            .step_by(self.bucket_width as usize)
+            .zip(self.counts)
+            .filter(|(_, count)| **count > 0)
+            .flat_map(|(start, count)| {
+                let coords = [(start, 0), (start + self.bucket_width, *count)];
+
+                [
+                    Rectangle::new(coords, bar_style),
+                    Rectangle::new(coords, border_style),
+                ]
+            });
+
+        chart.draw_series(bars).wrap_err("Failed to draw bars")?;
+
+        Ok(())
+    }
+}
+
+/// Points scattered across the graph with one of them highlighted.
+pub struct ScatterGraph<'a> {
+    pub axes: GraphAxes,
+    pub points: &'a [(f64, f64)],
+    pub highlight: (f64, f64),
+    pub x_labels: usize,
+    pub x_fmt: &'a dyn Fn(&f64) -> String,
+    pub y_fmt: &'a dyn Fn(&f64) -> String,
+}
+
+impl GraphRenderer for ScatterGraph<'_> {
+    fn draw(&self, root: &GraphArea<'_>, palette: GraphPalette) -> Result<()> {
+        let all_points = || self.points.iter().chain([&self.highlight]);
+
+        let (x_min, x_max) = all_points().fold((f64::MAX, f64::MIN), |(min, max), (x, _)| {
+            (min.min(*x), max.max(*x))
+        });
+
+        let y_max = all_points().map(|(_, y)| *y).fold(1.0, f64::max);
+        let x_pad = ((x_max - x_min) * 0.05).max(0.1);
+
+        let mut chart = ChartBuilder::on(root)
+            .margin(9_i32)
+            .x_label_area_size(x_label_area_size(&self.axes))
+            .y_label_area_size(60_i32)
+            .caption(&self.axes.caption, caption_style(&palette.text))
+            .build_cartesian_2d(x_min - x_pad..x_max + x_pad, 0.0..y_max * 1.1)
+            .wrap_err("Failed to build chart")?;
+
+        let mut mesh = chart.configure_mesh();
+
+        mesh.light_line_style(palette.mesh.mix(0.0))
+            .x_labels(self.x_labels)
+            .x_label_formatter(self.x_fmt)
+            .y_label_formatter(self.y_fmt)
+            .label_style(("sans-serif", 16_i32, &palette.text))
+            .bold_line_style(palette.mesh.mix(0.3))
+            .axis_style(palette.axis)
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &palette.text));
+
+        if let Some(desc) = self.axes.x_desc {
+            mesh.x_desc(desc);
+        }
+
+        if let Some(desc) = self.axes.y_desc {
+            mesh.y_desc(desc);
+        }
+
+        mesh.draw().wrap_err("Failed to draw mesh")?;
+
+        let point_style = palette.area.mix(0.6).filled();
+        let points = self
+            .points
+            .iter()
+            .map(|point| Circle::new(*point, 5_i32, point_style));
+
+        chart
+            .draw_series(points)
+            .wrap_err("Failed to draw points")?;
+
+        let highlight = [
+            Circle::new(self.highlight, 10_i32, palette.border.filled()),
+            Circle::new(self.highlight, 10_i32, palette.text.stroke_width(2)),
+        ];
+
+        chart
+            .draw_series(highlight)
+            .wrap_err("Failed to draw highlighted point")?;
+
+        Ok(())
+    }
+}
+
+/// Colors for pie slices after the palette's own area and border colors.
+const SLICE_COLORS: [RGBColor; 4] = [
+    RGBColor(239, 71, 111),
+    RGBColor(255, 209, 102),
+    RGBColor(155, 93, 229),
+    RGBColor(241, 91, 181),
+];
+
+/// Labeled slices that are sized relative to their sum.
+pub struct PieChart<'a> {
+    pub caption: String,
+    pub slices: &'a [(String, f64)],
+}
+
+impl GraphRenderer for PieChart<'_> {
+    fn draw(&self, root: &GraphArea<'_>, palette: GraphPalette) -> Result<()> {
+        let root = root
+            .titled(&self.caption, caption_style(&palette.text))
+            .wrap_err("Failed to draw caption")?;
+
+        let (w, h) = root.dim_in_pixel();
+        let center = (w as i32 / 2, h as i32 / 2);
+        let radius = f64::from(w.min(h)) * 0.35;
+
+        let sizes: Vec<_> = self.slices.iter().map(|(_, size)| *size).collect();
+        let labels: Vec<_> = self
+            .slices
+            .iter()
+            .map(|(label, _)| label.as_str())
+            .collect();
+
+        let colors: Vec<_> = [palette.area, palette.border]
+            .into_iter()
+            .chain(SLICE_COLORS)
+            .cycle()
+            .take(self.slices.len())
+            .collect();
+
+        let mut pie = Pie::new(&center, &radius, &sizes, &colors, &labels);
+        pie.start_angle(-90.0);
+        pie.label_style(("sans-serif", 18_i32).into_font().color(&palette.text));
+        pie.percentages(
+            ("sans-serif", 16_i32)
+                .into_font()
+                .color(&palette.background),
+        );
+
+        root.draw(&pie).wrap_err("Failed to draw pie")?;
+
+        Ok(())
+    }
+}
+
+pub(super) fn caption_style(color: &RGBColor) -> (&'static str, i32, FontStyle, &RGBColor) {
+    ("sans-serif", 25_i32, FontStyle::Bold, color)
+}
+
+/// Axis labels need more room if there's also a description below them.
+pub(super) fn x_label_area_size(axes: &GraphAxes) -> i32 {
+    if axes.x_desc.is_some() { 45 } else { 20 }
+}
@@ -7,7 +7,7 @@ use eyre::{ContextCompat, Report, Result, WrapErr};
 use plotters::{
     prelude::{ChartBuilder, Circle, IntoDrawingArea, SeriesLabelPosition},
     series::AreaSeries,
-    style::{BLACK, Color, GREEN, RED, RGBColor, ShapeStyle, WHITE},
+    style::{BLACK, Color, GREEN, RED, RGBColor, ShapeStyle},
 };
 use plotters_backend::FontStyle;
 use plotters_skia::SkiaBackend;
@@ -19,7 +19,7 @@ use twilight_model::guild::Permissions;
 use super::{Graph, GraphScoreRank};
 use crate::{
     commands::osu::{
-        graphs::{GRAPH_SCORE_RANK_DESC, H, W},
+        graphs::{GRAPH_SCORE_RANK_DESC, GraphData, GraphPalette, H, W, csv_datetime, draw_queued},
         rank, user_not_found,
     },
     core::{
@@ -48,6 +48,8 @@ impl<'m> GraphScoreRank<'m> {
             discord,
             from: None,
             until: None,
+            theme: None,
+            data: None,
         }
     }
 }
@@ -123,7 +125,8 @@ pub async fn score_rank_graph(
     mode: GameMode,
     from: Option<u8>,
     until: Option<u8>,
-) -> Result<Option<(AuthorBuilder, Vec<u8>)>> {
+    palette: GraphPalette,
+) -> Result<Option<(AuthorBuilder, Vec<u8>, GraphData)>> {
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
 
     let user = match Context::redis().osu_user(user_args).await {
@@ -157,7 +160,16 @@ pub async fn score_rank_graph(
     let from_unwrapped = from.unwrap_or(0);
     let until_unwrapped = u8::max(until.unwrap_or(90), u8::min(from_unwrapped + 2, 90));
 
-    let bytes = match draw_graph(respektive_user.as_ref(), from_unwrapped, until_unwrapped) {
+    let draw = || {
+        draw_graph(
+            respektive_user.as_ref(),
+            from_unwrapped,
+            until_unwrapped,
+            palette,
+        )
+    };
+
+    let bytes = match draw_queued(orig, draw).await {
         Ok(Some(graph)) => graph,
         Ok(None) => {
             let mut content = format!(
@@ -181,12 +193,37 @@ pub async fn score_rank_graph(
         }
     };
 
+    let data = score_rank_data(respektive_user.as_ref(), from_unwrapped, until_unwrapped);
+
     let author = rank::author(&user, respektive_user.as_ref());
 
-    Ok(Some((author, bytes)))
+    Ok(Some((author, bytes, data)))
+}
+
+/// Daily score ranks of the drawn time range, oldest first.
+fn score_rank_data(user: Option<&RespektiveUser>, from: u8, until: u8) -> GraphData {
+    let mut data = GraphData::new(&["date", "score_rank"]);
+
+    let history = user
+        .and_then(|user| user.rank_history.as_deref())
+        .and_then(|history| history.get(from as usize..until as usize))
+        .unwrap_or_default();
+
+    for entry in history.iter().rev() {
+        if let Some(rank) = entry.rank.filter(|&rank| rank > 0) {
+            data.push_row(&[&csv_datetime(entry.date), &rank]);
+        }
+    }
+
+    data
 }
 
-fn draw_graph(user: Option<&RespektiveUser>, from: u8, until: u8) -> Result<Option<Vec<u8>>> {
+fn draw_graph(
+    user: Option<&RespektiveUser>,
+    from: u8,
+    until: u8,
+    palette: GraphPalette,
+) -> Result<Option<Vec<u8>>> {
     let Some(user) = user else { return Ok(None) };
     let Some(ref rank_history) = user.rank_history else {
         return Ok(None);
@@ -249,7 +286,7 @@ fn draw_graph(user: Option<&RespektiveUser>, from: u8, until: u8) -> Result<Opti
     {
         let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
+        let background = palette.background;
         root.fill(&background)
             .wrap_err("Failed to fill background")?;
 
@@ -275,10 +312,10 @@ fn draw_graph(user: Option<&RespektiveUser>, from: u8, until: u8) -> Result<Opti
             .x_label_formatter(&|x| format!("{}", (until + from) as u32 - *x))
             .y_label_formatter(&|y| format!("{}", -*y))
             .y_desc("Rank")
-            .label_style(("sans-serif", 15, &WHITE))
-            .bold_line_style(WHITE.mix(0.3))
-            .axis_style(RGBColor(7, 18, 14))
-            .axis_desc_style(("sans-serif", 16, FontStyle::Bold, &WHITE))
+            .label_style(("sans-serif", 15, &palette.text))
+            .bold_line_style(palette.mesh.mix(0.3))
+            .axis_style(palette.axis)
+            .axis_desc_style(("sans-serif", 16, FontStyle::Bold, &palette.text))
             .draw()
             .wrap_err("Failed to draw mesh")?;
 
@@ -294,8 +331,8 @@ fn draw_graph(user: Option<&RespektiveUser>, from: u8, until: u8) -> Result<Opti
             ))
         });
 
-        let area_style = RGBColor(2, 186, 213).mix(0.7).filled();
-        let border_style = style(RGBColor(0, 208, 138)).stroke_width(3);
+        let area_style = palette.area.mix(0.7).filled();
+        let border_style = style(palette.border).stroke_width(3);
         let series = AreaSeries::new(data, min, area_style).border_style(border_style);
         chart.draw_series(series).wrap_err("Failed to draw area")?;
 
@@ -0,0 +1,413 @@
+use std::fmt::Write;
+
+use bathbot_macros::command;
+use bathbot_model::{Countries, command_fields::GameModeOption};
+use bathbot_util::{constants::GENERAL_ISSUE, matcher, osu::effective_clock_rate};
+use eyre::{ContextCompat, Report, Result, WrapErr};
+use plotters::{
+    prelude::{BindKeyPoints, ChartBuilder, IntoDrawingArea, Rectangle, Text},
+    style::{Color, IntoFont},
+};
+use plotters_backend::{
+    FontStyle,
+    text_anchor::{HPos, Pos, VPos},
+};
+use plotters_skia::SkiaBackend;
+use rosu_v2::{
+    prelude::{GameMode, OsuError, Score},
+    request::UserId,
+};
+use skia_safe::{EncodedImageFormat, surfaces};
+use time::{Date, Duration, OffsetDateTime, Time, UtcOffset};
+use twilight_model::guild::Permissions;
+
+use super::{
+    GRAPH_SESSIONS_DESC, Graph, GraphData, GraphPalette, GraphSessions, H, W, csv_datetime,
+    draw_queued,
+};
+use crate::{
+    commands::osu::user_not_found,
+    core::{
+        Context,
+        commands::{CommandOrigin, prefix::Args},
+    },
+    manager::redis::osu::{CachedUser, UserArgs, UserArgsError},
+};
+
+/// Break between two scores after which a new session starts.
+const SESSION_BREAK: Duration = Duration::minutes(20);
+
+/// Amount of most recent days that will be drawn.
+const MAX_DAYS: usize = 14;
+
+impl<'m> GraphSessions<'m> {
+    fn args(mode: Option<GameModeOption>, args: Args<'m>) -> Self {
+        let mut name = None;
+        let mut discord = None;
+
+        for arg in args {
+            if let Some(id) = matcher::get_mention_user(arg) {
+                discord = Some(id);
+            } else {
+                name = Some(arg.into());
+            }
+        }
+
+        Self {
+            mode,
+            name,
+            timezone: None,
+            discord,
+            theme: None,
+            data: None,
+        }
+    }
+}
+
+#[command]
+#[desc(GRAPH_SESSIONS_DESC)]
+#[usage("[username]")]
+#[examples("peppy")]
+#[group(Osu)]
+async fn prefix_graphsessions(
+    msg: &Message,
+    args: Args<'_>,
+    perms: Option<Permissions>,
+) -> Result<()> {
+    let args = GraphSessions::args(None, args);
+    let orig = CommandOrigin::from_msg(msg, perms);
+
+    super::graph(orig, Graph::Sessions(args)).await
+}
+
+#[command]
+#[desc(GRAPH_SESSIONS_DESC)]
+#[usage("[username]")]
+#[examples("peppy")]
+#[group(Taiko)]
+async fn prefix_graphsessionstaiko(
+    msg: &Message,
+    args: Args<'_>,
+    perms: Option<Permissions>,
+) -> Result<()> {
+    let args = GraphSessions::args(Some(GameModeOption::Taiko), args);
+    let orig = CommandOrigin::from_msg(msg, perms);
+
+    super::graph(orig, Graph::Sessions(args)).await
+}
+
+#[command]
+#[desc(GRAPH_SESSIONS_DESC)]
+#[usage("[username]")]
+#[examples("peppy")]
+#[aliases("graphsessionscatch")]
+#[group(Catch)]
+async fn prefix_graphsessionsctb(
+    msg: &Message,
+    args: Args<'_>,
+    perms: Option<Permissions>,
+) -> Result<()> {
+    let args = GraphSessions::args(Some(GameModeOption::Catch), args);
+    let orig = CommandOrigin::from_msg(msg, perms);
+
+    super::graph(orig, Graph::Sessions(args)).await
+}
+
+#[command]
+#[desc(GRAPH_SESSIONS_DESC)]
+#[usage("[username]")]
+#[examples("peppy")]
+#[group(Mania)]
+async fn prefix_graphsessionsmania(
+    msg: &Message,
+    args: Args<'_>,
+    perms: Option<Permissions>,
+) -> Result<()> {
+    let args = GraphSessions::args(Some(GameModeOption::Mania), args);
+    let orig = CommandOrigin::from_msg(msg, perms);
+
+    super::graph(orig, Graph::Sessions(args)).await
+}
+
+pub async fn sessions_graph(
+    orig: &CommandOrigin<'_>,
+    user_id: UserId,
+    user_args: UserArgs,
+    tz: Option<UtcOffset>,
+    legacy_scores: bool,
+    palette: GraphPalette,
+) -> Result<Option<(CachedUser, Vec<u8>, GraphData)>> {
+    let scores_fut = Context::osu_scores()
+        .recent(legacy_scores)
+        .limit(1000)
+        .exec_with_user(user_args);
+
+    let (user, scores) = match scores_fut.await {
+        Ok(tuple) => tuple,
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+            orig.error(content).await?;
+
+            return Ok(None);
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user or scores");
+
+            return Err(err);
+        }
+    };
+
+    if scores.is_empty() {
+        let content = format!("`{}` has no recent scores", user.username.as_str());
+        orig.error(content).await?;
+
+        return Ok(None);
+    }
+
+    let tz = tz.unwrap_or_else(|| Countries::code(user.country_code.as_str()).to_timezone());
+    let sessions = detect_sessions(&scores, tz);
+
+    let mut data = GraphData::new(&["start", "end", "pp"]);
+
+    for session in sessions.iter() {
+        data.push_row(&[
+            &csv_datetime(session.start),
+            &csv_datetime(session.end),
+            &session.pp,
+        ]);
+    }
+
+    let username = user.username.as_str();
+
+    let mut caption = format!(
+        "{username}'{genitive} recent {mode}sessions",
+        genitive = if username.ends_with('s') { "" } else { "s" },
+        mode = match user.mode {
+            GameMode::Osu => "",
+            GameMode::Taiko => "taiko ",
+            GameMode::Catch => "ctb ",
+            GameMode::Mania => "mania ",
+        }
+    );
+
+    let (h, m, _) = tz.as_hms();
+    let _ = write!(caption, " (UTC{h:+}");
+
+    if m != 0 {
+        let _ = write!(caption, ":{}", m.abs());
+    }
+
+    caption.push(')');
+
+    let bytes = match draw_queued(orig, || draw_sessions(caption, &sessions, palette)).await {
+        Ok(graph) => graph,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            warn!(?err, "Failed to draw sessions graph");
+
+            return Ok(None);
+        }
+    };
+
+    Ok(Some((user, bytes, data)))
+}
+
+struct Session {
+    start: OffsetDateTime,
+    end: OffsetDateTime,
+    pp: f32,
+}
+
+/// Group scores into sessions based on the break between them.
+///
+/// Each score's start is estimated through the drain length of its map.
+fn detect_sessions(scores: &[Score], tz: UtcOffset) -> Vec<Session> {
+    let mut spans: Vec<_> = scores
+        .iter()
+        .map(|score| {
+            let end = score.ended_at.to_offset(tz);
+
+            let drain = score.map.as_ref().map_or(0, |map| map.seconds_drain);
+            let clock_rate = effective_clock_rate(&score.mods);
+            let len = Duration::seconds_f64(drain as f64 / clock_rate);
+
+            // Failed scores don't award any pp
+            let pp = if score.passed {
+                score.pp.unwrap_or(0.0)
+            } else {
+                0.0
+            };
+
+            (end - len, end, pp)
+        })
+        .collect();
+
+    spans.sort_unstable_by_key(|(start, ..)| *start);
+
+    let mut sessions: Vec<Session> = Vec::new();
+
+    for (start, end, pp) in spans {
+        match sessions.last_mut() {
+            Some(session) if start - session.end <= SESSION_BREAK => {
+                session.end = session.end.max(end);
+                session.pp += pp;
+            }
+            _ => sessions.push(Session { start, end, pp }),
+        }
+    }
+
+    sessions
+}
+
+/// A part of a session that lies within a single day.
+struct Segment {
+    row: usize,
+    start: f32,
+    end: f32,
+    pp: Option<f32>,
+}
+
+fn hour_of_day(time: Time) -> f32 {
+    time.hour() as f32 + time.minute() as f32 / 60.0 + time.second() as f32 / 3600.0
+}
+
+fn draw_sessions(caption: String, sessions: &[Session], palette: GraphPalette) -> Result<Vec<u8>> {
+    let last_day = sessions
+        .last()
+        .map(|session| session.end.date())
+        .wrap_err("No sessions to draw")?;
+
+    let first_day = sessions
+        .first()
+        .map(|session| session.start.date())
+        .unwrap_or(last_day)
+        .max(last_day - Duration::days(MAX_DAYS as i64 - 1));
+
+    let days: Vec<Date> = (0..)
+        .map_while(|i| first_day.checked_add(Duration::days(i)))
+        .take_while(|date| *date <= last_day)
+        .collect();
+
+    let mut segments = Vec::new();
+
+    for session in sessions {
+        let mut date = session.start.date();
+
+        // Split sessions that go past midnight into one segment per day
+        while date <= session.end.date() {
+            let start = if date == session.start.date() {
+                hour_of_day(session.start.time())
+            } else {
+                0.0
+            };
+
+            let end = if date == session.end.date() {
+                hour_of_day(session.end.time())
+            } else {
+                24.0
+            };
+
+            // Label the pp on the session's last segment
+            let pp = (date == session.end.date()).then_some(session.pp);
+
+            if let Some(row) = days.iter().position(|day| *day == date) {
+                segments.push(Segment {
+                    row,
+                    start,
+                    end,
+                    pp,
+                });
+            }
+
+            let Some(next) = date.next_day() else { break };
+            date = next;
+        }
+    }
+
+    let rows = days.len();
+    let key_points: Vec<_> = (0..rows).map(|row| row as f32).collect();
+
+    let mut surface =
+        surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
+
+    {
+        let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
+
+        let background = palette.background;
+        root.fill(&background)
+            .wrap_err("failed to fill background")?;
+
+        let caption_style = ("sans-serif", 25_i32, FontStyle::Bold, &palette.text);
+
+        let y_range = (-0.5..rows as f32 - 0.5).with_key_points(key_points);
+
+        let mut chart = ChartBuilder::on(&root)
+            .x_label_area_size(40_i32)
+            .y_label_area_size(110_i32)
+            .margin_top(5_i32)
+            .margin_right(15_i32)
+            .caption(caption, caption_style)
+            .build_cartesian_2d(0.0_f32..24.0, y_range)
+            .wrap_err("failed to build chart")?;
+
+        chart
+            .configure_mesh()
+            .disable_y_mesh()
+            .x_labels(13)
+            .x_desc("Hour of the day")
+            .x_label_formatter(&|hour| format!("{hour:.0}:00"))
+            .y_label_formatter(&|row| {
+                days.get(row.round() as usize)
+                    .map(|date| format!("{} {:02}", &date.month().to_string()[..3], date.day()))
+                    .unwrap_or_default()
+            })
+            .label_style(("sans-serif", 16_i32, &palette.text))
+            .bold_line_style(palette.mesh.mix(0.3))
+            .axis_style(palette.axis)
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &palette.text))
+            .draw()
+            .wrap_err("failed to draw mesh")?;
+
+        let bar_style = palette.area.mix(0.8).filled();
+        let border_style = palette.border.stroke_width(2);
+
+        let bars = segments.iter().flat_map(|segment| {
+            let row = segment.row as f32;
+            let coords = [(segment.start, row - 0.35), (segment.end, row + 0.35)];
+
+            [
+                Rectangle::new(coords, bar_style),
+                Rectangle::new(coords, border_style),
+            ]
+        });
+
+        chart
+            .draw_series(bars)
+            .wrap_err("failed to draw sessions")?;
+
+        let label_style = ("sans-serif", 15_i32, FontStyle::Bold)
+            .into_font()
+            .color(&palette.text)
+            .pos(Pos::new(HPos::Left, VPos::Center));
+
+        let labels = segments.iter().filter_map(|segment| {
+            let pp = segment.pp?;
+            let pos = ((segment.end + 0.1).min(23.0), segment.row as f32);
+
+            Some(Text::new(format!("{pp:.0}pp"), pos, label_style.clone()))
+        });
+
+        chart
+            .draw_series(labels)
+            .wrap_err("failed to draw session labels")?;
+    }
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok(png_bytes)
+}
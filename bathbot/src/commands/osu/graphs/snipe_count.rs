@@ -4,7 +4,7 @@ use eyre::{Report, Result};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
 use twilight_model::guild::Permissions;
 
-use super::{Graph, GraphSnipeCount, H, W};
+use super::{Graph, GraphData, GraphSnipeCount, H, W, draw_queued};
 use crate::{
     commands::osu::{
         SnipeGameMode, graphs::GRAPH_SNIPE_COUNT_DESC, player_snipe_stats, user_not_found,
@@ -33,6 +33,7 @@ impl<'m> GraphSnipeCount<'m> {
             mode,
             name,
             discord,
+            data: None,
         }
     }
 }
@@ -90,7 +91,7 @@ pub async fn snipe_count_graph(
     orig: &CommandOrigin<'_>,
     user_id: UserId,
     mode: GameMode,
-) -> Result<Option<(CachedUser, Vec<u8>)>> {
+) -> Result<Option<(CachedUser, Vec<u8>, GraphData)>> {
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
 
     let user = match Context::redis().osu_user(user_args).await {
@@ -153,9 +154,20 @@ pub async fn snipe_count_graph(
         return Ok(None);
     };
 
-    let graph_result = player_snipe_stats::graphs(&history, &player.count_sr_spread, W, H);
+    // Both the #1 count history and the star rating spread are drawn
+    let mut data = GraphData::new(&["series", "key", "count"]);
 
-    let bytes = match graph_result {
+    for (date, count) in history.iter() {
+        data.push_row(&[&"national_firsts", date, count]);
+    }
+
+    for (stars, count) in player.count_sr_spread.iter() {
+        data.push_row(&[&"stars", stars, count]);
+    }
+
+    let draw = || player_snipe_stats::graphs(&history, &player.count_sr_spread, W, H);
+
+    let bytes = match draw_queued(orig, draw).await {
         Ok(graph) => graph,
         Err(err) => {
             let _ = orig.error(GENERAL_ISSUE).await;
@@ -165,5 +177,5 @@ pub async fn snipe_count_graph(
         }
     };
 
-    Ok(Some((user, bytes)))
+    Ok(Some((user, bytes, data)))
 }
@@ -0,0 +1,391 @@
+use std::iter;
+
+use bathbot_macros::command;
+use bathbot_model::SnipeRecent;
+use bathbot_util::{constants::GENERAL_ISSUE, matcher};
+use eyre::{Report, Result, WrapErr};
+use plotters::{
+    prelude::{ChartBuilder, PathElement, Rectangle, SeriesLabelPosition},
+    style::{Color, RGBColor},
+};
+use plotters_backend::FontStyle;
+use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
+use time::{Date, Duration, OffsetDateTime};
+use twilight_model::guild::Permissions;
+
+use super::{
+    GRAPH_SNIPE_GAINS_LOSSES_DESC, Graph, GraphData, GraphPalette, GraphSnipeGainsLosses, H, W,
+    draw_queued,
+    render::{GraphArea, GraphAxes, GraphRenderer, caption_style, x_label_area_size},
+};
+use crate::{
+    commands::osu::{SnipeGameMode, user_not_found},
+    core::{
+        Context,
+        commands::{CommandOrigin, prefix::Args},
+    },
+    manager::redis::osu::{CachedUser, UserArgs, UserArgsError},
+};
+
+impl<'m> GraphSnipeGainsLosses<'m> {
+    fn args(mode: Option<SnipeGameMode>, args: Args<'m>) -> Result<Self, String> {
+        let mut name = None;
+        let mut discord = None;
+        let mut days = None;
+
+        for arg in args {
+            if let Some(value) = arg.strip_prefix("days=") {
+                match value.parse() {
+                    Ok(value) => days = Some(value),
+                    Err(_) => return Err(format!("Failed to parse amount of days `{value}`")),
+                }
+            } else if let Some(id) = matcher::get_mention_user(arg) {
+                discord = Some(id);
+            } else {
+                name = Some(arg.into());
+            }
+        }
+
+        Ok(Self {
+            mode,
+            name,
+            discord,
+            days,
+            theme: None,
+            data: None,
+        })
+    }
+}
+
+#[command]
+#[desc(GRAPH_SNIPE_GAINS_LOSSES_DESC)]
+#[usage("[username] [days=number]")]
+#[examples("peppy days=60")]
+#[group(Osu)]
+async fn prefix_graphsnipegainslosses(
+    msg: &Message,
+    args: Args<'_>,
+    perms: Option<Permissions>,
+) -> Result<()> {
+    let orig = CommandOrigin::from_msg(msg, perms);
+
+    let args = match GraphSnipeGainsLosses::args(None, args) {
+        Ok(args) => args,
+        Err(content) => return orig.error_with_help(content).await,
+    };
+
+    super::graph(orig, Graph::SnipeGainsLosses(args)).await
+}
+
+#[command]
+#[desc(GRAPH_SNIPE_GAINS_LOSSES_DESC)]
+#[usage("[username] [days=number]")]
+#[examples("peppy days=60")]
+#[aliases("graphsnipegainslossescatch")]
+#[group(Catch)]
+async fn prefix_graphsnipegainslossesctb(
+    msg: &Message,
+    args: Args<'_>,
+    perms: Option<Permissions>,
+) -> Result<()> {
+    let orig = CommandOrigin::from_msg(msg, perms);
+
+    let args = match GraphSnipeGainsLosses::args(Some(SnipeGameMode::Catch), args) {
+        Ok(args) => args,
+        Err(content) => return orig.error_with_help(content).await,
+    };
+
+    super::graph(orig, Graph::SnipeGainsLosses(args)).await
+}
+
+#[command]
+#[desc(GRAPH_SNIPE_GAINS_LOSSES_DESC)]
+#[usage("[username] [days=number]")]
+#[examples("peppy days=60")]
+#[group(Mania)]
+async fn prefix_graphsnipegainslossesmania(
+    msg: &Message,
+    args: Args<'_>,
+    perms: Option<Permissions>,
+) -> Result<()> {
+    let orig = CommandOrigin::from_msg(msg, perms);
+
+    let args = match GraphSnipeGainsLosses::args(Some(SnipeGameMode::Mania), args) {
+        Ok(args) => args,
+        Err(content) => return orig.error_with_help(content).await,
+    };
+
+    super::graph(orig, Graph::SnipeGainsLosses(args)).await
+}
+
+const LOSS_COLOR: RGBColor = RGBColor(230, 70, 80);
+
+pub async fn snipe_gains_losses_graph(
+    orig: &CommandOrigin<'_>,
+    user_id: UserId,
+    mode: GameMode,
+    days: u32,
+    palette: GraphPalette,
+) -> Result<Option<(CachedUser, Vec<u8>, GraphData)>> {
+    let user_args = UserArgs::rosu_id(&user_id, mode).await;
+
+    let user = match Context::redis().osu_user(user_args).await {
+        Ok(user) => user,
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+            orig.error(content).await?;
+
+            return Ok(None);
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user");
+
+            return Err(err);
+        }
+    };
+
+    let country_code = user.country_code.as_str();
+    let username = user.username.as_str();
+
+    if !Context::huismetbenen()
+        .is_supported(country_code, mode)
+        .await
+    {
+        let content = format!("`{username}`'s country {country_code} is not supported :(");
+        orig.error(content).await?;
+
+        return Ok(None);
+    }
+
+    let now = OffsetDateTime::now_utc();
+    let since = now - Duration::days(days as i64);
+    let osu_id = user.user_id.to_native();
+
+    let client = Context::client();
+    let gains_fut = client.get_national_snipes(osu_id, true, since, mode);
+    let losses_fut = client.get_national_snipes(osu_id, false, since, mode);
+
+    let (gains, losses) = match tokio::try_join!(gains_fut, losses_fut) {
+        Ok(tuple) => tuple,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get national snipes"));
+        }
+    };
+
+    if gains.is_empty() && losses.is_empty() {
+        let content = format!(
+            "`{username}` neither gained nor lost any national #1s in the last {days} days"
+        );
+        orig.error(content).await?;
+
+        return Ok(None);
+    }
+
+    let start = since.date().next_day().unwrap_or(since.date());
+    let gains = count_per_day(&gains, start, days as usize);
+    let losses = count_per_day(&losses, start, days as usize);
+
+    let mut data = GraphData::new(&["date", "gains", "losses"]);
+
+    for ((date, gained), lost) in iter::successors(Some(start), |date| date.next_day())
+        .zip(gains.iter())
+        .zip(losses.iter())
+    {
+        data.push_row(&[&date, gained, lost]);
+    }
+
+    let caption = format!(
+        "National #1 gains and losses of {username} in the last {days} days \
+        (+{gained} / -{lost})",
+        gained = gains.iter().sum::<u32>(),
+        lost = losses.iter().sum::<u32>(),
+    );
+
+    let graph = GainsLossesGraph {
+        axes: GraphAxes {
+            caption,
+            x_desc: None,
+            y_desc: Some("National #1s"),
+        },
+        start,
+        gains: &gains,
+        losses: &losses,
+    };
+
+    let bytes = match draw_queued(orig, || graph.render(W, H, palette)).await {
+        Ok(graph) => graph,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            warn!(?err, "Failed to draw snipe gains and losses graph");
+
+            return Ok(None);
+        }
+    };
+
+    Ok(Some((user, bytes, data)))
+}
+
+/// Amount of snipes on each of the `days` days starting at `start`.
+fn count_per_day(snipes: &[SnipeRecent], start: Date, days: usize) -> Vec<u32> {
+    let mut counts = vec![0; days];
+
+    for date in snipes.iter().filter_map(|snipe| snipe.date) {
+        let day = (date.date() - start).whole_days();
+
+        if let Some(count) = usize::try_from(day)
+            .ok()
+            .and_then(|day| counts.get_mut(day))
+        {
+            *count += 1;
+        }
+    }
+
+    counts
+}
+
+/// Gains above and losses below the zero line for each day.
+struct GainsLossesGraph<'a> {
+    axes: GraphAxes,
+    start: Date,
+    gains: &'a [u32],
+    losses: &'a [u32],
+}
+
+impl GraphRenderer for GainsLossesGraph<'_> {
+    fn draw(&self, root: &GraphArea<'_>, palette: GraphPalette) -> Result<()> {
+        let days = self.gains.len() as f64;
+        let max_gain = self.gains.iter().copied().max().unwrap_or(0).max(1) as f64;
+        let max_loss = self.losses.iter().copied().max().unwrap_or(0).max(1) as f64;
+
+        let mut chart = ChartBuilder::on(root)
+            .margin(9_i32)
+            .x_label_area_size(x_label_area_size(&self.axes))
+            .y_label_area_size(60_i32)
+            .caption(&self.axes.caption, caption_style(&palette.text))
+            .build_cartesian_2d(0.0..days, -max_loss * 1.1..max_gain * 1.1)
+            .wrap_err("Failed to build chart")?;
+
+        let mut mesh = chart.configure_mesh();
+
+        mesh.light_line_style(palette.mesh.mix(0.0))
+            .disable_x_mesh()
+            .x_labels(10)
+            .x_label_formatter(&|day| {
+                let date = self.start + Duration::days(*day as i64);
+
+                format!("{:02}-{:02}", date.month() as u8, date.day())
+            })
+            .y_label_formatter(&|count| format!("{}", count.abs().round()))
+            .label_style(("sans-serif", 16_i32, &palette.text))
+            .bold_line_style(palette.mesh.mix(0.3))
+            .axis_style(palette.axis)
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &palette.text));
+
+        if let Some(desc) = self.axes.y_desc {
+            mesh.y_desc(desc);
+        }
+
+        mesh.draw().wrap_err("Failed to draw mesh")?;
+
+        let gain_style = palette.border.mix(0.8).filled();
+        let loss_style = LOSS_COLOR.mix(0.8).filled();
+
+        let bar = |day: usize, count: f64| [(day as f64 + 0.15, 0.0), (day as f64 + 0.85, count)];
+
+        let gains = self
+            .gains
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count > 0)
+            .map(|(day, count)| Rectangle::new(bar(day, *count as f64), gain_style));
+
+        chart
+            .draw_series(gains)
+            .wrap_err("Failed to draw gains")?
+            .label("Gained")
+            .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 20, y + 5)], gain_style));
+
+        let losses = self
+            .losses
+            .iter()
+            .enumerate()
+            .filter(|(_, count)| **count > 0)
+            .map(|(day, count)| Rectangle::new(bar(day, -(*count as f64)), loss_style));
+
+        chart
+            .draw_series(losses)
+            .wrap_err("Failed to draw losses")?
+            .label("Lost")
+            .legend(move |(x, y)| Rectangle::new([(x, y - 5), (x + 20, y + 5)], loss_style));
+
+        let zero_line = PathElement::new(vec![(0.0, 0.0), (days, 0.0)], palette.text);
+
+        chart
+            .draw_series([zero_line])
+            .wrap_err("Failed to draw zero line")?;
+
+        chart
+            .configure_series_labels()
+            .background_style(palette.legend_background)
+            .position(SeriesLabelPosition::UpperLeft)
+            .legend_area_size(30_i32)
+            .label_font(("sans-serif", 20_i32, &palette.text))
+            .draw()
+            .wrap_err("Failed to draw legend")?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{Month, Time};
+
+    use super::*;
+
+    fn date(year: i32, month: Month, day: u8) -> Date {
+        Date::from_calendar_date(year, month, day).unwrap()
+    }
+
+    fn snipe(date: Date, hour: u8) -> SnipeRecent {
+        SnipeRecent {
+            map_id: 0,
+            user_id: 0,
+            pp: None,
+            stars: None,
+            accuracy: 100.0,
+            date: Some(
+                date.with_time(Time::from_hms(hour, 0, 0).unwrap())
+                    .assume_utc(),
+            ),
+            mods: None,
+            max_combo: None,
+            artist: Box::from(""),
+            title: Box::from(""),
+            version: Box::from(""),
+            sniper: None,
+            sniper_id: 0,
+            sniped: None,
+            sniped_id: None,
+        }
+    }
+
+    #[test]
+    fn counts_snipes_per_day() {
+        let start = date(2025, Month::January, 1);
+
+        let snipes = [
+            snipe(start, 10),
+            snipe(start, 23),
+            snipe(date(2025, Month::January, 3), 0),
+            // Outside of the range
+            snipe(date(2024, Month::December, 31), 12),
+            snipe(date(2025, Month::January, 4), 12),
+        ];
+
+        assert_eq!(count_per_day(&snipes, start, 3), [2, 0, 1]);
+    }
+}
@@ -1,10 +1,12 @@
+use std::iter;
+
 use bathbot_macros::command;
 use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE, matcher};
 use eyre::{Report, Result};
 use rosu_v2::{model::GameMode, prelude::OsuError, request::UserId};
 use twilight_model::guild::Permissions;
 
-use super::{Graph, GraphSniped, H, W};
+use super::{Graph, GraphData, GraphSniped, H, W, csv_datetime, draw_queued};
 use crate::{
     commands::osu::{SnipeGameMode, graphs::GRAPH_SNIPED_DESC, sniped, user_not_found},
     core::{
@@ -31,6 +33,7 @@ impl<'m> GraphSniped<'m> {
             mode,
             name,
             discord,
+            data: None,
         }
     }
 }
@@ -88,7 +91,7 @@ pub async fn sniped_graph(
     orig: &CommandOrigin<'_>,
     user_id: UserId,
     mode: GameMode,
-) -> Result<Option<(CachedUser, Vec<u8>)>> {
+) -> Result<Option<(CachedUser, Vec<u8>, GraphData)>> {
     let user_args = UserArgs::rosu_id(&user_id, mode).await;
 
     let user = match Context::redis().osu_user(user_args).await {
@@ -135,7 +138,26 @@ pub async fn sniped_graph(
         return Ok(None);
     };
 
-    let bytes = match sniped::graphs(username, &mut sniper, &mut snipee, W, H) {
+    // Collect the data before the graph accumulates the counts
+    let mut data = GraphData::new(&["direction", "week_start", "week_end", "player", "count"]);
+
+    let weeks = iter::repeat("sniped_by_user")
+        .zip(sniper.iter())
+        .chain(iter::repeat("sniped_the_user").zip(snipee.iter()));
+
+    for (direction, week) in weeks {
+        let start = csv_datetime(week.from);
+        let end = csv_datetime(week.until);
+
+        for player in week.players.iter() {
+            let name = player.username.as_str();
+            data.push_row(&[&direction, &start, &end, &name, &player.count]);
+        }
+    }
+
+    let draw = || sniped::graphs(username, &mut sniper, &mut snipee, W, H);
+
+    let bytes = match draw_queued(orig, draw).await {
         Ok(Some(graph)) => graph,
         Ok(None) => {
             let content = format!(
@@ -154,5 +176,5 @@ pub async fn sniped_graph(
         }
     };
 
-    Ok(Some((user, bytes)))
+    Ok(Some((user, bytes, data)))
 }
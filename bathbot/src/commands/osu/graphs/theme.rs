@@ -0,0 +1,45 @@
+use bathbot_psql::model::configs::GraphTheme;
+use plotters::style::RGBColor;
+
+/// Colors that graphs are drawn with.
+#[derive(Copy, Clone)]
+pub struct GraphPalette {
+    pub background: RGBColor,
+    pub text: RGBColor,
+    pub mesh: RGBColor,
+    pub axis: RGBColor,
+    pub legend_background: RGBColor,
+    pub area: RGBColor,
+    pub border: RGBColor,
+}
+
+impl GraphPalette {
+    pub const fn new(theme: GraphTheme) -> Self {
+        match theme {
+            GraphTheme::Dark => Self {
+                background: RGBColor(19, 43, 33),
+                text: RGBColor(255, 255, 255),
+                mesh: RGBColor(255, 255, 255),
+                axis: RGBColor(7, 18, 14),
+                legend_background: RGBColor(7, 23, 17),
+                area: RGBColor(2, 186, 213),
+                border: RGBColor(0, 208, 138),
+            },
+            GraphTheme::Light => Self {
+                background: RGBColor(248, 249, 250),
+                text: RGBColor(32, 34, 37),
+                mesh: RGBColor(64, 68, 75),
+                axis: RGBColor(160, 164, 170),
+                legend_background: RGBColor(233, 236, 239),
+                area: RGBColor(0, 137, 178),
+                border: RGBColor(0, 150, 100),
+            },
+        }
+    }
+}
+
+impl Default for GraphPalette {
+    fn default() -> Self {
+        Self::new(GraphTheme::Dark)
+    }
+}
@@ -0,0 +1,137 @@
+use bathbot_macros::command;
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_util::matcher;
+use eyre::Result;
+use twilight_model::guild::Permissions;
+
+use super::{GRAPH_TOP_DESC, Graph, GraphTop, GraphTopOrder};
+use crate::core::commands::{CommandOrigin, prefix::Args};
+
+impl GraphTop {
+    fn args(mode: Option<GameModeOption>, args: Args<'_>) -> Result<Self, String> {
+        let mut name = None;
+        let mut discord = None;
+        let mut order = None;
+
+        for arg in args {
+            if let Some(value) = arg.strip_prefix("order=") {
+                match GraphTopOrder::parse(value) {
+                    Some(value) => order = Some(value),
+                    None => {
+                        let content = format!(
+                            "Failed to parse order `{value}`.\n\
+                            Must be either `date`, `index`, `time_h`, `time_d`, `acc`, or `stars`."
+                        );
+
+                        return Err(content);
+                    }
+                }
+            } else if let Some(id) = matcher::get_mention_user(arg) {
+                discord = Some(id);
+            } else {
+                name = Some(arg.to_owned());
+            }
+        }
+
+        Ok(Self {
+            order: order.unwrap_or(GraphTopOrder::Index),
+            mode,
+            name,
+            timezone: None,
+            discord,
+            theme: None,
+            data: None,
+        })
+    }
+}
+
+impl GraphTopOrder {
+    fn parse(value: &str) -> Option<Self> {
+        match value.to_ascii_lowercase().as_str() {
+            "date" => Some(Self::Date),
+            "index" => Some(Self::Index),
+            "time_h" | "hour" => Some(Self::TimeByHour),
+            "time_d" | "day" => Some(Self::TimeByDay),
+            "acc" | "accuracy" => Some(Self::Accuracy),
+            "stars" => Some(Self::Stars),
+            _ => None,
+        }
+    }
+}
+
+#[command]
+#[desc(GRAPH_TOP_DESC)]
+#[usage("[username] [order=date/index/time_h/time_d/acc/stars]")]
+#[examples("peppy order=date", "order=acc")]
+#[group(Osu)]
+async fn prefix_graphtop(msg: &Message, args: Args<'_>, perms: Option<Permissions>) -> Result<()> {
+    let orig = CommandOrigin::from_msg(msg, perms);
+
+    let args = match GraphTop::args(None, args) {
+        Ok(args) => args,
+        Err(content) => return orig.error_with_help(content).await,
+    };
+
+    super::graph(orig, Graph::Top(args)).await
+}
+
+#[command]
+#[desc(GRAPH_TOP_DESC)]
+#[usage("[username] [order=date/index/time_h/time_d/acc/stars]")]
+#[examples("peppy order=date", "order=acc")]
+#[group(Taiko)]
+async fn prefix_graphtoptaiko(
+    msg: &Message,
+    args: Args<'_>,
+    perms: Option<Permissions>,
+) -> Result<()> {
+    let orig = CommandOrigin::from_msg(msg, perms);
+
+    let args = match GraphTop::args(Some(GameModeOption::Taiko), args) {
+        Ok(args) => args,
+        Err(content) => return orig.error_with_help(content).await,
+    };
+
+    super::graph(orig, Graph::Top(args)).await
+}
+
+#[command]
+#[desc(GRAPH_TOP_DESC)]
+#[usage("[username] [order=date/index/time_h/time_d/acc/stars]")]
+#[examples("peppy order=date", "order=acc")]
+#[aliases("graphtopcatch")]
+#[group(Catch)]
+async fn prefix_graphtopctb(
+    msg: &Message,
+    args: Args<'_>,
+    perms: Option<Permissions>,
+) -> Result<()> {
+    let orig = CommandOrigin::from_msg(msg, perms);
+
+    let args = match GraphTop::args(Some(GameModeOption::Catch), args) {
+        Ok(args) => args,
+        Err(content) => return orig.error_with_help(content).await,
+    };
+
+    super::graph(orig, Graph::Top(args)).await
+}
+
+#[command]
+#[desc(GRAPH_TOP_DESC)]
+#[usage("[username] [order=date/index/time_h/time_d/acc/stars]")]
+#[examples("peppy order=date", "order=acc")]
+#[group(Mania)]
+async fn prefix_graphtopmania(
+    msg: &Message,
+    args: Args<'_>,
+    perms: Option<Permissions>,
+) -> Result<()> {
+    let orig = CommandOrigin::from_msg(msg, perms);
+
+    let args = match GraphTop::args(Some(GameModeOption::Mania), args) {
+        Ok(args) => args,
+        Err(content) => return orig.error_with_help(content).await,
+    };
+
+    super::graph(orig, Graph::Top(args)).await
+}
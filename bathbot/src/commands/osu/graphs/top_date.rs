@@ -2,17 +2,25 @@ use eyre::{ContextCompat, Result, WrapErr};
 use plotters::{
     prelude::{ChartBuilder, Circle, EmptyElement, IntoDrawingArea, SeriesLabelPosition},
     series::PointSeries,
-    style::{Color, RGBColor, WHITE},
+    style::Color,
 };
 use plotters_backend::FontStyle;
 use plotters_skia::SkiaBackend;
 use rosu_v2::prelude::Score;
 use skia_safe::{EncodedImageFormat, surfaces};
 
-use super::{H, W};
-use crate::util::Monthly;
+use super::{GraphData, GraphPalette, H, W, csv_datetime};
+use crate::{
+    core::{Context, commands::CommandOrigin},
+    util::Monthly,
+};
 
-pub async fn top_graph_date(caption: String, scores: &mut [Score]) -> Result<Vec<u8>> {
+pub async fn top_graph_date(
+    orig: &CommandOrigin<'_>,
+    caption: String,
+    scores: &mut [Score],
+    palette: GraphPalette,
+) -> Result<(Vec<u8>, GraphData)> {
     let max = scores.first().and_then(|s| s.pp).unwrap_or(0.0);
     let max_adj = max + 5.0;
 
@@ -22,20 +30,31 @@ pub async fn top_graph_date(caption: String, scores: &mut [Score]) -> Result<Vec
     scores.sort_unstable_by_key(|s| s.ended_at);
     let dates: Vec<_> = scores.iter().map(|s| s.ended_at).collect();
 
+    let mut data = GraphData::new(&["date", "pp", "map_id"]);
+
+    for score in scores.iter() {
+        if let Some(ref pp) = score.pp {
+            data.push_row(&[&csv_datetime(score.ended_at), pp, &score.map_id]);
+        }
+    }
+
     let first = dates[0];
     let last = dates[dates.len() - 1];
 
+    // Only the drawing itself waits in the render queue
+    let _permit = Context::render_queue().acquire(orig).await?;
+
     let mut surface =
         surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
 
     {
         let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
+        let background = palette.background;
         root.fill(&background)
             .wrap_err("failed to fill background")?;
 
-        let caption_style = ("sans-serif", 25_i32, FontStyle::Bold, &WHITE);
+        let caption_style = ("sans-serif", 25_i32, FontStyle::Bold, &palette.text);
 
         let mut chart = ChartBuilder::on(&root)
             .x_label_area_size(40_i32)
@@ -51,15 +70,15 @@ pub async fn top_graph_date(caption: String, scores: &mut [Score]) -> Result<Vec
             .disable_x_mesh()
             .y_label_formatter(&|pp| format!("{pp:.0}pp"))
             .x_label_formatter(&|datetime| datetime.date().to_string())
-            .label_style(("sans-serif", 16_i32, &WHITE))
-            .bold_line_style(WHITE.mix(0.3))
-            .axis_style(RGBColor(7, 18, 14))
-            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .label_style(("sans-serif", 16_i32, &palette.text))
+            .bold_line_style(palette.mesh.mix(0.3))
+            .axis_style(palette.axis)
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &palette.text))
             .draw()
             .wrap_err("failed to draw mesh")?;
 
-        let point_style = RGBColor(2, 186, 213).mix(0.7).filled();
-        let border_style = WHITE.mix(0.9).stroke_width(1);
+        let point_style = palette.area.mix(0.7).filled();
+        let border_style = palette.text.mix(0.9).stroke_width(1);
 
         let iter = scores.iter().filter_map(|s| Some((s.ended_at, s.pp?)));
 
@@ -87,11 +106,11 @@ pub async fn top_graph_date(caption: String, scores: &mut [Score]) -> Result<Vec
 
         chart
             .configure_series_labels()
-            .border_style(WHITE.mix(0.6).stroke_width(1))
-            .background_style(RGBColor(7, 23, 17))
+            .border_style(palette.text.mix(0.6).stroke_width(1))
+            .background_style(palette.legend_background)
             .position(SeriesLabelPosition::MiddleLeft)
             .legend_area_size(0_i32)
-            .label_font(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .label_font(("sans-serif", 16_i32, FontStyle::Bold, &palette.text))
             .draw()
             .wrap_err("failed to draw legend")?;
     }
@@ -102,5 +121,5 @@ pub async fn top_graph_date(caption: String, scores: &mut [Score]) -> Result<Vec
         .wrap_err("Failed to encode image")?
         .to_vec();
 
-    Ok(png_bytes)
+    Ok((png_bytes, data))
 }
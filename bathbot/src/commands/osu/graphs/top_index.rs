@@ -1,7 +1,7 @@
 use eyre::{ContextCompat, Result, WrapErr};
 use plotters::{
-    prelude::{ChartBuilder, EmptyElement, IntoDrawingArea, SeriesLabelPosition},
-    series::AreaSeries,
+    prelude::{ChartBuilder, EmptyElement, IntoDrawingArea, Rectangle, SeriesLabelPosition},
+    series::{AreaSeries, LineSeries},
     style::{Color, RGBColor, WHITE},
 };
 use plotters_backend::FontStyle;
@@ -9,26 +9,43 @@ use plotters_skia::SkiaBackend;
 use rosu_v2::prelude::Score;
 use skia_safe::{EncodedImageFormat, surfaces};
 
-use super::{H, W};
+use super::{GraphData, GraphPalette, H, W};
+use crate::core::{Context, commands::CommandOrigin};
+
+pub async fn top_graph_index(
+    orig: &CommandOrigin<'_>,
+    caption: String,
+    scores: &[Score],
+    palette: GraphPalette,
+) -> Result<(Vec<u8>, GraphData)> {
+    let mut data = GraphData::new(&["index", "pp", "map_id"]);
+
+    for (i, score) in (1..).zip(scores) {
+        if let Some(ref pp) = score.pp {
+            data.push_row(&[&i, pp, &score.map_id]);
+        }
+    }
 
-pub async fn top_graph_index(caption: String, scores: &[Score]) -> Result<Vec<u8>> {
     let max = scores.first().and_then(|s| s.pp).unwrap_or(0.0);
     let max_adj = max + 5.0;
 
     let min = scores.last().and_then(|s| s.pp).unwrap_or(0.0);
     let min_adj = (min - 5.0).max(0.0);
 
+    // Only the drawing itself waits in the render queue
+    let _permit = Context::render_queue().acquire(orig).await?;
+
     let mut surface =
         surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
 
     {
         let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
+        let background = palette.background;
         root.fill(&background)
             .wrap_err("failed to fill background")?;
 
-        let caption_style = ("sans-serif", 25_i32, FontStyle::Bold, &WHITE);
+        let caption_style = ("sans-serif", 25_i32, FontStyle::Bold, &palette.text);
 
         let mut chart = ChartBuilder::on(&root)
             .x_label_area_size(40_i32)
@@ -42,15 +59,15 @@ pub async fn top_graph_index(caption: String, scores: &[Score]) -> Result<Vec<u8
         chart
             .configure_mesh()
             .y_label_formatter(&|pp| format!("{pp:.0}pp"))
-            .label_style(("sans-serif", 16_i32, &WHITE))
-            .bold_line_style(WHITE.mix(0.3))
-            .axis_style(RGBColor(7, 18, 14))
-            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .label_style(("sans-serif", 16_i32, &palette.text))
+            .bold_line_style(palette.mesh.mix(0.3))
+            .axis_style(palette.axis)
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &palette.text))
             .draw()
             .wrap_err("failed to draw mesh")?;
 
-        let area_style = RGBColor(2, 186, 213).mix(0.7).filled();
-        let border_style = RGBColor(0, 208, 138).stroke_width(3);
+        let area_style = palette.area.mix(0.7).filled();
+        let border_style = palette.border.stroke_width(3);
         let iter = (1..).zip(scores).filter_map(|(i, s)| Some((i, s.pp?)));
         let series = AreaSeries::new(iter, 0.0, area_style).border_style(border_style);
 
@@ -76,11 +93,11 @@ pub async fn top_graph_index(caption: String, scores: &[Score]) -> Result<Vec<u8
 
         chart
             .configure_series_labels()
-            .border_style(WHITE.mix(0.6).stroke_width(1))
-            .background_style(RGBColor(7, 23, 17))
+            .border_style(palette.text.mix(0.6).stroke_width(1))
+            .background_style(palette.legend_background)
             .position(SeriesLabelPosition::UpperRight)
             .legend_area_size(0_i32)
-            .label_font(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .label_font(("sans-serif", 16_i32, FontStyle::Bold, &palette.text))
             .draw()
             .wrap_err("failed to draw legend")?;
     }
@@ -91,5 +108,141 @@ pub async fn top_graph_index(caption: String, scores: &[Score]) -> Result<Vec<u8
         .wrap_err("Failed to encode image")?
         .to_vec();
 
-    Ok(png_bytes)
+    Ok((png_bytes, data))
+}
+
+/// Color of the second curve when comparing two top lists.
+const SECOND_COLOR: RGBColor = RGBColor(235, 96, 160);
+
+/// Draw the pp-by-index curves of two top lists on the same chart.
+pub async fn top_graph_index_compare(
+    orig: &CommandOrigin<'_>,
+    caption: String,
+    (name1, scores1): (&str, &[Score]),
+    (name2, scores2): (&str, &[Score]),
+    palette: GraphPalette,
+) -> Result<(Vec<u8>, GraphData)> {
+    let pps1: Vec<_> = scores1.iter().filter_map(|s| s.pp).collect();
+    let pps2: Vec<_> = scores2.iter().filter_map(|s| s.pp).collect();
+
+    let (min, max) = pps1
+        .iter()
+        .chain(pps2.iter())
+        .fold((f32::MAX, f32::MIN), |(min, max), &pp| {
+            (min.min(pp), max.max(pp))
+        });
+
+    if min > max {
+        bail!("No pp values to draw");
+    }
+
+    let max_adj = max + 5.0;
+    let min_adj = (min - 5.0).max(0.0);
+    let len = pps1.len().max(pps2.len()).max(2);
+
+    let mut data = GraphData::new(&["index", name1, name2]);
+
+    for i in 0..pps1.len().max(pps2.len()) {
+        let pp1 = pps1.get(i).map_or_else(String::new, f32::to_string);
+        let pp2 = pps2.get(i).map_or_else(String::new, f32::to_string);
+        data.push_row(&[&(i + 1), &pp1, &pp2]);
+    }
+
+    // Only the drawing itself waits in the render queue
+    let _permit = Context::render_queue().acquire(orig).await?;
+
+    let mut surface =
+        surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
+
+    {
+        let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
+
+        let background = palette.background;
+        root.fill(&background)
+            .wrap_err("failed to fill background")?;
+
+        let caption_style = ("sans-serif", 25_i32, FontStyle::Bold, &palette.text);
+
+        let mut chart = ChartBuilder::on(&root)
+            .x_label_area_size(40_i32)
+            .y_label_area_size(60_i32)
+            .margin_top(5_i32)
+            .margin_right(15_i32)
+            .caption(caption, caption_style)
+            .build_cartesian_2d(1..len, min_adj..max_adj)
+            .wrap_err("failed to build chart")?;
+
+        chart
+            .configure_mesh()
+            .y_label_formatter(&|pp| format!("{pp:.0}pp"))
+            .label_style(("sans-serif", 16_i32, &palette.text))
+            .bold_line_style(palette.mesh.mix(0.3))
+            .axis_style(palette.axis)
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &palette.text))
+            .draw()
+            .wrap_err("failed to draw mesh")?;
+
+        let curves = [(name1, &pps1, palette.border), (name2, &pps2, SECOND_COLOR)];
+
+        for (name, pps, color) in curves {
+            let iter = (1..).zip(pps.iter().copied());
+            let series = LineSeries::new(iter, color.stroke_width(3));
+
+            chart
+                .draw_series(series)
+                .wrap_err_with(|| format!("failed to draw curve of {name}"))?
+                .label(name)
+                .legend(move |(x, y)| {
+                    Rectangle::new([(x, y - 2), (x + 14, y + 2)], color.filled())
+                });
+        }
+
+        let intersection = match curve_intersection(&pps1, &pps2) {
+            Some((idx, pp)) => format!("Intersection: #{idx} ({pp:.2}pp)"),
+            None => "No intersection".to_owned(),
+        };
+
+        // Draw empty series for additional label
+        let series = LineSeries::new(std::iter::empty::<(usize, f32)>(), WHITE);
+
+        chart
+            .draw_series(series)
+            .wrap_err("failed to draw empty series")?
+            .label(intersection)
+            .legend(EmptyElement::at);
+
+        chart
+            .configure_series_labels()
+            .border_style(palette.text.mix(0.6).stroke_width(1))
+            .background_style(palette.legend_background)
+            .position(SeriesLabelPosition::UpperRight)
+            .legend_area_size(20_i32)
+            .label_font(("sans-serif", 16_i32, FontStyle::Bold, &palette.text))
+            .draw()
+            .wrap_err("failed to draw legend")?;
+    }
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok((png_bytes, data))
+}
+
+/// Find the first index at which the two curves cross each other.
+///
+/// Returns the 1-based index and the pp value of the first curve at that
+/// index.
+fn curve_intersection(pps1: &[f32], pps2: &[f32]) -> Option<(usize, f32)> {
+    let mut iter = pps1.iter().zip(pps2).map(|(a, b)| a - b);
+    let first = iter.find(|diff| *diff != 0.0)?;
+
+    let idx = pps1
+        .iter()
+        .zip(pps2)
+        .position(|(a, b)| (a - b) * first < 0.0)?;
+
+    Some((idx + 1, pps1[idx]))
 }
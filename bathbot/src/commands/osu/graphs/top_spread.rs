@@ -0,0 +1,214 @@
+use eyre::{ContextCompat, Result, WrapErr};
+use plotters::{
+    prelude::{ChartBuilder, Circle, EmptyElement, IntoDrawingArea, SeriesLabelPosition},
+    series::PointSeries,
+    style::Color,
+};
+use plotters_backend::FontStyle;
+use plotters_skia::SkiaBackend;
+use rosu_v2::prelude::Score;
+use skia_safe::{EncodedImageFormat, surfaces};
+
+use super::{GraphData, GraphPalette, H, W, draw_queued};
+use crate::core::{Context, commands::CommandOrigin};
+
+pub async fn top_graph_acc(
+    orig: &CommandOrigin<'_>,
+    caption: String,
+    scores: &[Score],
+    palette: GraphPalette,
+) -> Result<(Vec<u8>, GraphData)> {
+    let points: Vec<_> = scores
+        .iter()
+        .filter_map(|s| Some((s.accuracy, s.pp?)))
+        .collect();
+
+    let data = spread_data("accuracy", &points);
+
+    let draw = || {
+        draw_spread(
+            caption,
+            &points,
+            "Accuracy",
+            &|acc| format!("{acc:.1}%"),
+            palette,
+        )
+    };
+
+    let bytes = draw_queued(orig, draw).await?;
+
+    Ok((bytes, data))
+}
+
+pub async fn top_graph_stars(
+    orig: &CommandOrigin<'_>,
+    caption: String,
+    scores: &[Score],
+    palette: GraphPalette,
+) -> Result<(Vec<u8>, GraphData)> {
+    let mut points = Vec::with_capacity(scores.len());
+
+    for score in scores {
+        let Some(pp) = score.pp else { continue };
+
+        let nomod_stars = score.map.as_ref().map_or(0.0, |map| map.stars);
+
+        // Only mod-adjusted star ratings need to be calculated
+        let stars = if score.mods.is_empty() {
+            nomod_stars
+        } else {
+            let attrs_fut =
+                Context::osu_map().difficulty(score.map_id, score.mode, score.mods.clone());
+
+            match attrs_fut.await {
+                Ok(Some(attrs)) => attrs.stars() as f32,
+                Ok(None) => nomod_stars,
+                Err(err) => {
+                    warn!(?err, map_id = score.map_id, "Failed to calculate stars");
+
+                    nomod_stars
+                }
+            }
+        };
+
+        points.push((stars, pp));
+    }
+
+    let data = spread_data("stars", &points);
+
+    let draw = || {
+        draw_spread(
+            caption,
+            &points,
+            "Stars",
+            &|stars| format!("{stars:.1}★"),
+            palette,
+        )
+    };
+
+    let bytes = draw_queued(orig, draw).await?;
+
+    Ok((bytes, data))
+}
+
+fn spread_data(x_name: &str, points: &[(f32, f32)]) -> GraphData {
+    let mut data = GraphData::new(&[x_name, "pp"]);
+
+    for (x, pp) in points {
+        data.push_row(&[x, pp]);
+    }
+
+    data
+}
+
+/// Draw a scatter plot with pp on the y-axis.
+fn draw_spread(
+    caption: String,
+    points: &[(f32, f32)],
+    x_desc: &str,
+    x_label_formatter: &dyn Fn(&f32) -> String,
+    palette: GraphPalette,
+) -> Result<Vec<u8>> {
+    if points.is_empty() {
+        bail!("No points to draw");
+    }
+
+    let (min_x, max_x, min_pp, max_pp) = points.iter().fold(
+        (f32::MAX, f32::MIN, f32::MAX, f32::MIN),
+        |(min_x, max_x, min_pp, max_pp), &(x, pp)| {
+            (min_x.min(x), max_x.max(x), min_pp.min(pp), max_pp.max(pp))
+        },
+    );
+
+    // Pad the x-axis so points don't stick to the edges
+    let x_pad = ((max_x - min_x) * 0.02).max(0.1);
+    let min_x_adj = min_x - x_pad;
+    let max_x_adj = max_x + x_pad;
+
+    let max_pp_adj = max_pp + 5.0;
+    let min_pp_adj = (min_pp - 5.0).max(0.0);
+
+    let mut surface =
+        surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
+
+    {
+        let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
+
+        let background = palette.background;
+        root.fill(&background)
+            .wrap_err("failed to fill background")?;
+
+        let caption_style = ("sans-serif", 25_i32, FontStyle::Bold, &palette.text);
+
+        let mut chart = ChartBuilder::on(&root)
+            .x_label_area_size(50_i32)
+            .y_label_area_size(60_i32)
+            .margin_top(5_i32)
+            .margin_right(15_i32)
+            .caption(caption, caption_style)
+            .build_cartesian_2d(min_x_adj..max_x_adj, min_pp_adj..max_pp_adj)
+            .wrap_err("failed to build chart")?;
+
+        chart
+            .configure_mesh()
+            .disable_x_mesh()
+            .x_desc(x_desc)
+            .y_label_formatter(&|pp| format!("{pp:.0}pp"))
+            .x_label_formatter(x_label_formatter)
+            .label_style(("sans-serif", 16_i32, &palette.text))
+            .bold_line_style(palette.mesh.mix(0.3))
+            .axis_style(palette.axis)
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &palette.text))
+            .draw()
+            .wrap_err("failed to draw mesh")?;
+
+        let point_style = palette.area.mix(0.7).filled();
+        let border_style = palette.text.mix(0.9).stroke_width(1);
+
+        let series = PointSeries::of_element(
+            points.iter().copied(),
+            3_i32,
+            point_style,
+            &|coord, size, style| EmptyElement::at(coord) + Circle::new((0, 0), size, style),
+        );
+
+        chart
+            .draw_series(series)
+            .wrap_err("failed to draw main points")?
+            .label(format!(
+                "{x_desc}: {} - {}",
+                x_label_formatter(&min_x),
+                x_label_formatter(&max_x)
+            ))
+            .legend(EmptyElement::at);
+
+        let series = PointSeries::of_element(
+            points.iter().copied(),
+            3_i32,
+            border_style,
+            &|coord, size, style| EmptyElement::at(coord) + Circle::new((0, 0), size, style),
+        );
+
+        chart
+            .draw_series(series)
+            .wrap_err("failed to draw point borders")?;
+
+        chart
+            .configure_series_labels()
+            .border_style(palette.text.mix(0.6).stroke_width(1))
+            .background_style(palette.legend_background)
+            .position(SeriesLabelPosition::UpperLeft)
+            .legend_area_size(0_i32)
+            .label_font(("sans-serif", 16_i32, FontStyle::Bold, &palette.text))
+            .draw()
+            .wrap_err("failed to draw legend")?;
+    }
+
+    let png_bytes = surface
+        .image_snapshot()
+        .encode(None, EncodedImageFormat::PNG, None)
+        .wrap_err("Failed to encode image")?
+        .to_vec();
+
+    Ok(png_bytes)
+}
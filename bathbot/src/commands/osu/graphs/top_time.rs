@@ -20,13 +20,18 @@ use rosu_v2::prelude::Score;
 use skia_safe::{EncodedImageFormat, Surface, surfaces};
 use time::{Duration, OffsetDateTime, UtcOffset};
 
-use crate::commands::osu::graphs::{H, W};
+use crate::{
+    commands::osu::graphs::{GraphData, GraphPalette, H, W, csv_datetime},
+    core::{Context, commands::CommandOrigin},
+};
 
 pub async fn top_graph_time_hour(
+    orig: &CommandOrigin<'_>,
     mut caption: String,
     scores: &mut [Score],
     tz: UtcOffset,
-) -> Result<Vec<u8>> {
+    palette: GraphPalette,
+) -> Result<(Vec<u8>, GraphData)> {
     fn date_to_value(date: OffsetDateTime) -> u32 {
         date.hour() as u32 * 60 + date.minute() as u32
     }
@@ -52,20 +57,25 @@ pub async fn top_graph_time_hour(
     }
 
     scores.sort_unstable_by_key(|s| s.ended_at.time());
+    let data = time_data(scores);
 
     let max_hours = hours.iter().max().map_or(0, |count| *count as u32);
 
+    // Only the drawing itself waits in the render queue
+    let _permit = Context::render_queue().acquire(orig).await?;
+
     let mut surface =
         surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
 
     {
         let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
+        let background = palette.background;
         root.fill(&background)
             .wrap_err("Failed to fill background")?;
 
-        let caption_style = TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&WHITE);
+        let caption_style =
+            TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&palette.text);
 
         let x_label_area_size = 50;
         let y_label_area_size = 60;
@@ -94,18 +104,18 @@ pub async fn top_graph_time_hour(
             .disable_y_axis()
             .x_labels(24)
             .x_desc("Hour of the day")
-            .label_style(("sans-serif", 16_i32, &WHITE))
-            .axis_style(RGBColor(7, 18, 14))
-            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .label_style(("sans-serif", 16_i32, &palette.text))
+            .axis_style(palette.axis)
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &palette.text))
             .draw()
             .wrap_err("Failed to draw primary bar mesh")?;
 
         chart
             .configure_secondary_axes()
             .y_desc("#  of  plays  set")
-            .label_style(("sans-serif", 16_i32, &WHITE))
-            .axis_style(RGBColor(7, 18, 14))
-            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .label_style(("sans-serif", 16_i32, &palette.text))
+            .axis_style(palette.axis)
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &palette.text))
             .draw()
             .wrap_err("Failed to draw secondary mesh")?;
 
@@ -127,7 +137,7 @@ pub async fn top_graph_time_hour(
             .wrap_err("Failed to build point chart")?
             .set_secondary_coord(0_u32..24 * 60, min_adj..max_adj);
 
-        draw_point_mesh(&mut chart)?;
+        draw_point_mesh(&mut chart, palette)?;
 
         draw_points(
             &mut chart,
@@ -136,17 +146,20 @@ pub async fn top_graph_time_hour(
             max,
             min,
             (W as f32 / 4.5) as i32,
+            palette,
         )?;
     }
 
-    encode_surface(&mut surface)
+    encode_surface(&mut surface).map(|bytes| (bytes, data))
 }
 
 pub async fn top_graph_time_day(
+    orig: &CommandOrigin<'_>,
     mut caption: String,
     scores: &mut [Score],
     tz: UtcOffset,
-) -> Result<Vec<u8>> {
+    palette: GraphPalette,
+) -> Result<(Vec<u8>, GraphData)> {
     fn date_to_value(date: OffsetDateTime) -> u32 {
         date.weekday() as u32 * 24 * 60 + date.hour() as u32 * 60 + date.minute() as u32
     }
@@ -172,20 +185,25 @@ pub async fn top_graph_time_day(
     }
 
     scores.sort_unstable_by_key(|s| s.ended_at.time());
+    let data = time_data(scores);
 
     let max_days = days.iter().max().map_or(0, |count| *count as u32);
 
+    // Only the drawing itself waits in the render queue
+    let _permit = Context::render_queue().acquire(orig).await?;
+
     let mut surface =
         surfaces::raster_n32_premul((W as i32, H as i32)).wrap_err("Failed to create surface")?;
 
     {
         let root = SkiaBackend::new(surface.canvas(), W, H).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
+        let background = palette.background;
         root.fill(&background)
             .wrap_err("Failed to fill background")?;
 
-        let caption_style = TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&WHITE);
+        let caption_style =
+            TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&palette.text);
 
         let x_label_area_size = 35;
         let y_label_area_size = 60;
@@ -213,18 +231,18 @@ pub async fn top_graph_time_day(
             .disable_y_mesh()
             .disable_y_axis()
             .x_labels(7)
-            .label_style(("sans-serif", 16_i32, &WHITE))
-            .axis_style(RGBColor(7, 18, 14))
-            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .label_style(("sans-serif", 16_i32, &palette.text))
+            .axis_style(palette.axis)
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &palette.text))
             .draw()
             .wrap_err("Failed to draw primary bar mesh")?;
 
         chart
             .configure_secondary_axes()
             .y_desc("#  of  plays  set")
-            .label_style(("sans-serif", 16_i32, &WHITE))
-            .axis_style(RGBColor(7, 18, 14))
-            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+            .label_style(("sans-serif", 16_i32, &palette.text))
+            .axis_style(palette.axis)
+            .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &palette.text))
             .draw()
             .wrap_err("Failed to draw secondary mesh")?;
 
@@ -246,7 +264,7 @@ pub async fn top_graph_time_day(
             .wrap_err("Failed to build point chart")?
             .set_secondary_coord(0_u32..7 * 24 * 60, min_adj..max_adj);
 
-        draw_point_mesh(&mut chart)?;
+        draw_point_mesh(&mut chart, palette)?;
 
         draw_points(
             &mut chart,
@@ -255,10 +273,29 @@ pub async fn top_graph_time_day(
             max,
             min,
             (W as f32 / 6.5) as i32,
+            palette,
         )?;
     }
 
-    encode_surface(&mut surface)
+    encode_surface(&mut surface).map(|bytes| (bytes, data))
+}
+
+/// Scores' dates alongside their hour and weekday in the chosen timezone.
+fn time_data(scores: &[Score]) -> GraphData {
+    let mut data = GraphData::new(&["date", "hour", "weekday", "pp"]);
+
+    for score in scores {
+        let Some(ref pp) = score.pp else { continue };
+
+        data.push_row(&[
+            &csv_datetime(score.ended_at),
+            &score.ended_at.hour(),
+            &score.ended_at.weekday(),
+            pp,
+        ]);
+    }
+
+    data
 }
 
 fn encode_surface(surface: &mut Surface) -> Result<Vec<u8>> {
@@ -276,16 +313,16 @@ type Chart<'a> = DualCoordChartContext<
     Cartesian2d<RangedCoordu32, RangedCoordf32>,
 >;
 
-fn draw_point_mesh(chart: &mut Chart<'_>) -> Result<()> {
+fn draw_point_mesh(chart: &mut Chart<'_>, palette: GraphPalette) -> Result<()> {
     chart
         .configure_mesh()
         .disable_x_mesh()
         .disable_x_axis()
         .y_label_formatter(&|pp| format!("{pp:.0}pp"))
-        .label_style(("sans-serif", 16_i32, &WHITE))
-        .bold_line_style(WHITE.mix(0.3))
-        .axis_style(RGBColor(7, 18, 14))
-        .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+        .label_style(("sans-serif", 16_i32, &palette.text))
+        .bold_line_style(palette.mesh.mix(0.3))
+        .axis_style(palette.axis)
+        .axis_desc_style(("sans-serif", 16_i32, FontStyle::Bold, &palette.text))
         .draw()
         .wrap_err("Failed to draw point mesh")
 }
@@ -297,6 +334,7 @@ fn draw_points(
     max_pp: f32,
     min_pp: f32,
     legend_pos: i32,
+    palette: GraphPalette,
 ) -> Result<()> {
     // Draw secondary axis just to hide its values so that
     // the left hand values aren't displayed instead
@@ -307,8 +345,8 @@ fn draw_points(
         .draw()
         .wrap_err("Failed to draw secondary points")?;
 
-    let point_style = RGBColor(2, 186, 213).mix(0.7).filled();
-    let border_style = WHITE.mix(0.9).stroke_width(1);
+    let point_style = palette.area.mix(0.7).filled();
+    let border_style = palette.text.mix(0.9).stroke_width(1);
 
     let iter = scores
         .iter()
@@ -340,11 +378,11 @@ fn draw_points(
 
     chart
         .configure_series_labels()
-        .border_style(WHITE.mix(0.6).stroke_width(1))
-        .background_style(RGBColor(7, 23, 17))
+        .border_style(palette.text.mix(0.6).stroke_width(1))
+        .background_style(palette.legend_background)
         .position(SeriesLabelPosition::Coordinate(legend_pos, 10))
         .legend_area_size(0_i32)
-        .label_font(("sans-serif", 16_i32, FontStyle::Bold, &WHITE))
+        .label_font(("sans-serif", 16_i32, FontStyle::Bold, &palette.text))
         .draw()
         .wrap_err("Failed to draw legend")?;
 
@@ -1,11 +1,16 @@
-use std::{borrow::Cow, cmp::Reverse, collections::HashMap};
+use std::{
+    borrow::Cow,
+    cmp::{Ordering, Reverse},
+    collections::HashMap,
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+};
 
 use bathbot_macros::{HasMods, SlashCommand, command};
-use bathbot_model::command_fields::GameModeOption;
+use bathbot_model::{LeaderboardSnapshotEntry, command_fields::GameModeOption};
 use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
-    IntHasher, ScoreExt,
-    constants::{GENERAL_ISSUE, OSU_API_ISSUE},
+    CowUtils, EmbedBuilder, FooterBuilder, IntHasher, MessageBuilder, ScoreExt,
+    constants::{GENERAL_ISSUE, OSU_API_ISSUE, OSU_BASE},
     matcher,
     osu::{MapIdType, ModSelection},
 };
@@ -19,7 +24,10 @@ use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand,
 use twilight_model::{
     channel::Message,
     guild::Permissions,
-    id::{Id, marker::UserMarker},
+    id::{
+        Id,
+        marker::{GuildMarker, UserMarker},
+    },
 };
 
 use super::{HasMods, ModsResult};
@@ -32,12 +40,22 @@ use crate::{
         MapError, Mods, OsuMap,
         redis::osu::{CachedUser, UserArgs, UserArgsError},
     },
-    util::{ChannelExt, InteractionCommandExt, interaction::InteractionCommand, osu::MapOrScore},
+    util::{InteractionCommandExt, interaction::InteractionCommand, osu::MapOrScore},
 };
 
 #[derive(CommandModel, CreateCommand, SlashCommand)]
-#[command(name = "leaderboard", desc = "Display the global leaderboard of a map")]
-pub struct Leaderboard<'a> {
+#[command(name = "leaderboard", desc = "Display the leaderboard of a map")]
+#[flags(BANCHO_ONLY)]
+pub enum Leaderboard<'a> {
+    #[command(name = "global")]
+    Global(LeaderboardGlobal<'a>),
+    #[command(name = "server")]
+    Server(LeaderboardServer<'a>),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "global", desc = "Display the global leaderboard of a map")]
+pub struct LeaderboardGlobal<'a> {
     #[command(
         desc = "Specify a map url or map id",
         help = "Specify a map either by map url or map id.\n\
@@ -61,6 +79,45 @@ pub struct Leaderboard<'a> {
     sort: Option<LeaderboardSort>,
     #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
     score_data: Option<ScoreData>,
+    #[command(
+        desc = "Show what changed since the last time someone in this server checked",
+        help = "Compare the current top 50 with the leaderboard of the last time \
+        someone in this server used this option on the same map.\n\
+        New entries and position changes will be highlighted.\n\
+        Snapshots are kept for 30 days."
+    )]
+    diff: Option<bool>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "server",
+    desc = "Display the leaderboard of a map among linked server members",
+    help = "Display the leaderboard of a map among linked server members.\n\
+    For each member of this server that is linked to an osu! user, \
+    their best score on the map will be retrieved.\n\
+    Members that are not linked through the `/link` command won't show up."
+)]
+pub struct LeaderboardServer<'a> {
+    #[command(
+        desc = "Specify a map url or map id",
+        help = "Specify a map either by map url or map id.\n\
+        If none is specified, it will search in the recent channel history \
+        and pick the first map it can find."
+    )]
+    map: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify mods e.g. hdhr or nm",
+        help = "Specify mods either directly or through the explicit `+mod!` / `+mod` syntax, \
+        e.g. `hdhr` or `+hdhr!`, and filter out all scores that don't match those mods."
+    )]
+    mods: Option<Cow<'a, str>>,
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Choose how the scores should be ordered")]
+    sort: Option<LeaderboardSort>,
+    #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
+    score_data: Option<ScoreData>,
 }
 
 #[derive(Copy, Clone, Default, CommandOption, CreateOption, Eq, PartialEq)]
@@ -128,6 +185,8 @@ struct LeaderboardArgs<'a> {
     mode: Option<GameMode>,
     sort: LeaderboardSort,
     score_data: Option<ScoreData>,
+    diff: bool,
+    server: bool,
 }
 
 impl<'m> LeaderboardArgs<'m> {
@@ -138,9 +197,15 @@ impl<'m> LeaderboardArgs<'m> {
     ) -> Result<LeaderboardArgs<'m>, String> {
         let mut map = None;
         let mut mods = None;
-
-        for arg in args.take(2) {
-            if let Some(id) = matcher::get_osu_map_id(arg)
+        let mut diff = false;
+        let mut server = false;
+
+        for arg in args.take(4) {
+            if arg == "diff" {
+                diff = true;
+            } else if arg == "server" {
+                server = true;
+            } else if let Some(id) = matcher::get_osu_map_id(arg)
                 .map(MapIdType::Map)
                 .or_else(|| matcher::get_osu_mapset_id(arg).map(MapIdType::Set))
             {
@@ -150,7 +215,7 @@ impl<'m> LeaderboardArgs<'m> {
             } else {
                 let content = format!(
                     "Failed to parse `{arg}`.\n\
-                    Must be either a map id, map url, or mods.",
+                    Must be either a map id, map url, mods, `diff`, or `server`.",
                 );
 
                 return Err(content);
@@ -177,6 +242,8 @@ impl<'m> LeaderboardArgs<'m> {
             mode,
             sort,
             score_data: None,
+            diff,
+            server,
         })
     }
 }
@@ -185,43 +252,59 @@ impl<'a> TryFrom<Leaderboard<'a>> for LeaderboardArgs<'a> {
     type Error = &'static str;
 
     fn try_from(args: Leaderboard<'a>) -> Result<Self, Self::Error> {
-        let map = match args.map {
-            Some(map) => {
-                if let Some(id) = matcher::get_osu_map_id(&map)
-                    .map(MapIdType::Map)
-                    .or_else(|| matcher::get_osu_mapset_id(&map).map(MapIdType::Set))
-                {
-                    Some(id)
-                } else {
-                    return Err(
-                        "Failed to parse map url. Be sure you specify a valid map id or url to a map.",
-                    );
-                }
-            }
-            None => None,
-        };
-
-        Ok(Self {
-            map,
-            mods: args.mods,
-            mode: args.mode.map(GameMode::from),
-            sort: args.sort.unwrap_or_default(),
-            score_data: args.score_data,
-        })
+        match args {
+            Leaderboard::Global(args) => Ok(Self {
+                map: parse_map(args.map.as_deref())?,
+                mods: args.mods,
+                mode: args.mode.map(GameMode::from),
+                sort: args.sort.unwrap_or_default(),
+                score_data: args.score_data,
+                diff: args.diff.unwrap_or(false),
+                server: false,
+            }),
+            Leaderboard::Server(args) => Ok(Self {
+                map: parse_map(args.map.as_deref())?,
+                mods: args.mods,
+                mode: args.mode.map(GameMode::from),
+                sort: args.sort.unwrap_or_default(),
+                score_data: args.score_data,
+                diff: false,
+                server: true,
+            }),
+        }
     }
 }
 
+fn parse_map(map: Option<&str>) -> Result<Option<MapIdType>, &'static str> {
+    let Some(map) = map else { return Ok(None) };
+
+    matcher::get_osu_map_id(map)
+        .map(MapIdType::Map)
+        .or_else(|| matcher::get_osu_mapset_id(map).map(MapIdType::Set))
+        .map(Some)
+        .ok_or("Failed to parse map url. Be sure you specify a valid map id or url to a map.")
+}
+
 #[command]
 #[desc("Display the global leaderboard of a map")]
 #[help(
     "Display the global leaderboard of a given map.\n\
     If no map is given, I will choose the last map \
     I can find in the embeds of this channel.\n\
-    Mods can be specified."
+    Mods can be specified.\n\
+    Add `diff` to see what changed since the last time \
+    someone in this server checked the map with `diff`.\n\
+    Add `server` to only show scores of linked members of this server."
+)]
+#[usage("[map url / map id] [mods] [diff / server]")]
+#[example(
+    "2240404",
+    "https://osu.ppy.sh/beatmapsets/902425#osu/2240404",
+    "2240404 diff",
+    "2240404 +hd server"
 )]
-#[usage("[map url / map id] [mods]")]
-#[example("2240404", "https://osu.ppy.sh/beatmapsets/902425#osu/2240404")]
 #[alias("lb")]
+#[flags(BANCHO_ONLY)]
 #[group(AllModes)]
 async fn prefix_leaderboard(
     msg: &Message,
@@ -230,11 +313,7 @@ async fn prefix_leaderboard(
 ) -> Result<()> {
     match LeaderboardArgs::args(msg, args, None).await {
         Ok(args) => leaderboard(CommandOrigin::from_msg(msg, permissions), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -249,6 +328,7 @@ async fn prefix_leaderboard(
 #[usage("[map url / map id] [mods]")]
 #[example("2240404", "https://osu.ppy.sh/beatmapsets/902425#osu/2240404")]
 #[alias("lbt")]
+#[flags(BANCHO_ONLY)]
 #[group(Taiko)]
 async fn prefix_leaderboardtaiko(
     msg: &Message,
@@ -257,11 +337,7 @@ async fn prefix_leaderboardtaiko(
 ) -> Result<()> {
     match LeaderboardArgs::args(msg, args, Some(GameMode::Taiko)).await {
         Ok(args) => leaderboard(CommandOrigin::from_msg(msg, permissions), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -276,6 +352,7 @@ async fn prefix_leaderboardtaiko(
 #[usage("[map url / map id] [mods]")]
 #[example("2240404", "https://osu.ppy.sh/beatmapsets/902425#osu/2240404")]
 #[alias("lbc", "leaderboardcatch")]
+#[flags(BANCHO_ONLY)]
 #[group(Catch)]
 async fn prefix_leaderboardctb(
     msg: &Message,
@@ -284,11 +361,7 @@ async fn prefix_leaderboardctb(
 ) -> Result<()> {
     match LeaderboardArgs::args(msg, args, Some(GameMode::Catch)).await {
         Ok(args) => leaderboard(CommandOrigin::from_msg(msg, permissions), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -303,6 +376,7 @@ async fn prefix_leaderboardctb(
 #[usage("[map url / map id] [mods]")]
 #[example("2240404", "https://osu.ppy.sh/beatmapsets/902425#osu/2240404")]
 #[alias("lbm")]
+#[flags(BANCHO_ONLY)]
 #[group(Mania)]
 async fn prefix_leaderboardmania(
     msg: &Message,
@@ -311,11 +385,7 @@ async fn prefix_leaderboardmania(
 ) -> Result<()> {
     match LeaderboardArgs::args(msg, args, Some(GameMode::Mania)).await {
         Ok(args) => leaderboard(CommandOrigin::from_msg(msg, permissions), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -325,9 +395,9 @@ async fn slash_leaderboard(mut command: InteractionCommand) -> Result<()> {
     match LeaderboardArgs::try_from(args) {
         Ok(args) => leaderboard((&mut command).into(), args).await,
         Err(content) => {
-            command.error(content).await?;
-
-            Ok(())
+            CommandOrigin::from(&mut command)
+                .error_with_help(content)
+                .await
         }
     }
 }
@@ -342,7 +412,37 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
             If you want exact mods, specify it e.g. as `+hdhr!`.\n\
             And if you want to exclude mods, specify it e.g. as `-hdnf!`.";
 
-            return orig.error(content).await;
+            return orig.error_with_help(content).await;
+        }
+    };
+
+    let diff_guild = match (args.diff, orig.guild_id()) {
+        (false, _) => None,
+        (true, Some(_)) if mods.is_some() => {
+            return orig
+                .error_with_help("The `diff` option can't be combined with mods")
+                .await;
+        }
+        (true, Some(guild_id)) => Some(guild_id),
+        (true, None) => {
+            return orig
+                .error("The `diff` option can only be used in servers")
+                .await;
+        }
+    };
+
+    let server_guild = match (args.server, orig.guild_id()) {
+        (false, _) => None,
+        (true, Some(_)) if args.diff => {
+            return orig
+                .error_with_help("The `diff` option can't be combined with the server leaderboard")
+                .await;
+        }
+        (true, Some(guild_id)) => Some(guild_id),
+        (true, None) => {
+            return orig
+                .error("The server leaderboard can only be used in servers")
+                .await;
         }
     };
 
@@ -355,7 +455,7 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
 
     let map_id = match map_id_res {
         Ok(map_id) => map_id,
-        Err(content) => return orig.error(content).await,
+        Err(content) => return orig.error_with_help(content).await,
     };
 
     let config = config_res?;
@@ -415,16 +515,28 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
 
     const SCORE_COUNT: usize = 100;
 
-    let scores_fut = Context::osu_scores().map_leaderboard(
-        map_id,
-        mode,
-        specify_mods.clone(),
-        SCORE_COUNT as u32,
-        legacy_scores,
-    );
+    let scores_fut = async {
+        match server_guild {
+            Some(guild_id) => {
+                server_scores(guild_id, map_id, mode, specify_mods.clone(), legacy_scores).await
+            }
+            None => {
+                Context::osu_scores()
+                    .map_leaderboard(
+                        map_id,
+                        mode,
+                        specify_mods.clone(),
+                        SCORE_COUNT as u32,
+                        legacy_scores,
+                    )
+                    .await
+            }
+        }
+    };
 
+    // The author's score will be among the server scores if there is one
     let user_fut = get_user_score(
-        config.osu,
+        config.osu.filter(|_| server_guild.is_none()),
         map_id,
         mode,
         specify_mods.clone(),
@@ -473,6 +585,17 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
                 score.score,
                 score.pos,
             ),
+        })
+        .or_else(|| {
+            let user_id = config.osu.filter(|_| server_guild.is_some())?;
+
+            scores
+                .iter()
+                .find(|score| score.user_id == user_id)
+                .map(|score| LeaderboardUserScore {
+                    discord_id: owner,
+                    score: score.clone(),
+                })
         });
 
     if let Some(ModSelection::Exclude { ref mods, nomod }) = mods {
@@ -485,12 +608,21 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
         }
     }
 
+    if let Some(guild_id) = diff_guild {
+        return leaderboard_diff(orig, guild_id, &map, &scores).await;
+    }
+
     let amount = scores.len();
 
-    let mut content = if mods.is_some() {
-        format!("I found {amount} scores with the specified mods on the map's leaderboard")
-    } else {
-        format!("I found {amount} scores on the map's leaderboard")
+    let mut content = match (server_guild, mods.is_some()) {
+        (Some(_), true) => {
+            format!("I found {amount} scores with the specified mods of linked server members")
+        }
+        (Some(_), false) => format!("I found {amount} scores of linked server members"),
+        (None, true) => {
+            format!("I found {amount} scores with the specified mods on the map's leaderboard")
+        }
+        (None, false) => format!("I found {amount} scores on the map's leaderboard"),
     };
 
     let mut stars = 0.0;
@@ -524,6 +656,146 @@ async fn leaderboard(orig: CommandOrigin<'_>, args: LeaderboardArgs<'_>) -> Resu
         .await
 }
 
+/// Compare the top scores with the previous snapshot of the guild and store
+/// the current scores as new snapshot.
+async fn leaderboard_diff(
+    orig: CommandOrigin<'_>,
+    guild_id: Id<GuildMarker>,
+    map: &OsuMap,
+    scores: &[LeaderboardScore],
+) -> Result<()> {
+    const DIFF_COUNT: usize = 50;
+
+    let scores = &scores[..scores.len().min(DIFF_COUNT)];
+
+    let entries = scores
+        .iter()
+        .map(|score| LeaderboardSnapshotEntry {
+            user_id: score.user_id,
+            score_id: score.score_id,
+        })
+        .collect();
+
+    let snapshot_fut =
+        Context::redis().swap_leaderboard_snapshot(guild_id, map.map_id(), map.mode(), entries);
+
+    let prev = match snapshot_fut.await {
+        Ok(Some(prev)) => prev,
+        Ok(None) => {
+            let content = "There was no previous snapshot of this map's leaderboard \
+                for this server so I stored the current one.\n\
+                Use this option again later on to see what changed.";
+
+            let builder = MessageBuilder::new().embed(content);
+            orig.create_message(builder).await?;
+
+            return Ok(());
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(Report::new(err).wrap_err("Failed to swap leaderboard snapshot"));
+        }
+    };
+
+    let mut description = String::new();
+
+    for score in scores {
+        let prev_idx = prev
+            .iter()
+            .position(|entry| entry.user_id.to_native() == score.user_id);
+
+        let change = match prev_idx {
+            None => Cow::Borrowed("🆕"),
+            Some(idx) if prev[idx].score_id.to_native() != score.score_id => Cow::Owned(format!(
+                "{} (new score)",
+                PositionChange::new(idx, score.pos)
+            )),
+            Some(idx) if idx + 1 != score.pos => {
+                Cow::Owned(PositionChange::new(idx, score.pos).to_string())
+            }
+            Some(_) => continue,
+        };
+
+        let _ = writeln!(
+            description,
+            "**{pos}.** [{name}]({OSU_BASE}u/{user_id}) {change}",
+            pos = score.pos,
+            name = score.username.cow_escape_markdown(),
+            user_id = score.user_id,
+        );
+    }
+
+    let dropped = prev
+        .iter()
+        .filter(|entry| {
+            let user_id = entry.user_id.to_native();
+
+            scores.iter().all(|score| score.user_id != user_id)
+        })
+        .count();
+
+    if description.is_empty() {
+        description.push_str("No changes since the last snapshot");
+    }
+
+    let footer = match dropped {
+        0 => format!("Compared the top {}", scores.len()),
+        1 => format!("Compared the top {} • 1 player dropped out", scores.len()),
+        _ => format!(
+            "Compared the top {} • {dropped} players dropped out",
+            scores.len()
+        ),
+    };
+
+    let title = format!(
+        "{} - {} [{}]",
+        map.artist().cow_escape_markdown(),
+        map.title().cow_escape_markdown(),
+        map.version().cow_escape_markdown(),
+    );
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .footer(FooterBuilder::new(footer))
+        .title(title)
+        .url(format!("{OSU_BASE}b/{}", map.map_id()));
+
+    let builder = MessageBuilder::new()
+        .content("Leaderboard changes since the last snapshot:")
+        .embed(embed);
+
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+/// Displays the change from a previous 0-indexed position to a new 1-indexed
+/// position.
+struct PositionChange {
+    prev: usize,
+    curr: usize,
+}
+
+impl PositionChange {
+    fn new(prev_idx: usize, curr_pos: usize) -> Self {
+        Self {
+            prev: prev_idx + 1,
+            curr: curr_pos,
+        }
+    }
+}
+
+impl Display for PositionChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self.prev.cmp(&self.curr) {
+            Ordering::Greater => write!(f, "▲{}", self.prev - self.curr),
+            Ordering::Less => write!(f, "▼{}", self.curr - self.prev),
+            Ordering::Equal => Ok(()),
+        }
+    }
+}
+
 async fn get_map_id(orig: &CommandOrigin<'_>, map: Option<MapIdType>) -> Result<u32, &'static str> {
     match map {
         Some(MapIdType::Map(id)) => Ok(id),
@@ -552,6 +824,28 @@ async fn get_map_id(orig: &CommandOrigin<'_>, map: Option<MapIdType>) -> Result<
     }
 }
 
+/// Best scores of all linked members of the guild, sorted by score.
+async fn server_scores(
+    guild_id: Id<GuildMarker>,
+    map_id: u32,
+    mode: GameMode,
+    mods: Option<GameModsIntermode>,
+    legacy_scores: bool,
+) -> Result<Vec<Score>> {
+    let members: Vec<_> = Context::cache()
+        .members(guild_id)
+        .await?
+        .into_iter()
+        .map(|id| id as i64)
+        .collect();
+
+    let user_ids = Context::user_config().linked_osu_ids(&members).await?;
+
+    Context::osu_scores()
+        .users_on_map(&user_ids, map_id, mode, mods, legacy_scores)
+        .await
+}
+
 async fn get_user_score(
     osu_id: Option<u32>,
     map_id: u32,
@@ -586,6 +880,7 @@ async fn get_user_score(
     Ok(Some((user, score)))
 }
 
+#[derive(Clone)]
 pub struct LeaderboardScore {
     pub user_id: u32,
     pub username: Username,
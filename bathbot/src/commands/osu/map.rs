@@ -8,8 +8,8 @@ use bathbot_util::{
     osu::{MapIdType, ModSelection},
 };
 use eyre::{Report, Result};
-use rosu_v2::prelude::{GameMode, GameModsIntermode, OsuError};
-use twilight_interactions::command::{CommandModel, CreateCommand};
+use rosu_v2::prelude::{BeatmapsetExtended, GameMode, GameModsIntermode};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::{channel::Message, guild::Permissions};
 
 use super::{HasMods, ModsResult};
@@ -17,11 +17,12 @@ use crate::{
     Context,
     active::{
         ActiveMessages,
-        impls::{MapPagination, SingleScorePagination},
+        impls::{MapComment, MapCommentsPagination, MapPagination, SingleScorePagination},
     },
-    commands::osu::map_strains_graph,
+    commands::osu::{GraphAxes, GraphPalette, GraphRenderer, Histogram, map_strains_graph},
     core::commands::{CommandOrigin, prefix::Args},
-    util::{ChannelExt, InteractionCommandExt, interaction::InteractionCommand, osu::MapOrScore},
+    manager::MapError,
+    util::{InteractionCommandExt, interaction::InteractionCommand, osu::MapOrScore},
 };
 
 #[derive(CommandModel, CreateCommand, SlashCommand)]
@@ -55,6 +56,21 @@ pub struct Map<'a> {
     cs: Option<f64>,
     #[command(desc = "Specify an HP value to override the actual one")]
     hp: Option<f64>,
+    #[command(
+        desc = "Choose what to show about the map",
+        help = "Choose what to show about the map.\n\
+        `Community` shows the mapset's user rating distribution and its most upvoted recent comments."
+    )]
+    view: Option<MapView>,
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption, Default)]
+pub enum MapView {
+    #[default]
+    #[option(name = "Stats", value = "stats")]
+    Stats,
+    #[option(name = "Community", value = "community")]
+    Community,
 }
 
 #[derive(HasMods)]
@@ -62,6 +78,7 @@ struct MapArgs<'a> {
     map: Option<MapIdType>,
     mods: Option<Cow<'a, str>>,
     attrs: CustomAttrs,
+    view: MapView,
 }
 
 #[derive(Default)]
@@ -153,6 +170,7 @@ impl<'m> MapArgs<'m> {
             map,
             mods,
             attrs: CustomAttrs::default(),
+            view: MapView::Stats,
         })
     }
 }
@@ -168,6 +186,7 @@ impl<'a> TryFrom<Map<'a>> for MapArgs<'a> {
             od,
             cs,
             hp,
+            view,
         } = args;
 
         let map = match map.map(|arg| {
@@ -187,7 +206,12 @@ impl<'a> TryFrom<Map<'a>> for MapArgs<'a> {
 
         let attrs = CustomAttrs { ar, cs, hp, od };
 
-        Ok(Self { map, mods, attrs })
+        Ok(Self {
+            map,
+            mods,
+            attrs,
+            view: view.unwrap_or_default(),
+        })
     }
 }
 
@@ -207,11 +231,7 @@ impl<'a> TryFrom<Map<'a>> for MapArgs<'a> {
 async fn prefix_map(msg: &Message, args: Args<'_>, permissions: Option<Permissions>) -> Result<()> {
     match MapArgs::args(msg, args).await {
         Ok(args) => map(CommandOrigin::from_msg(msg, permissions), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -221,9 +241,9 @@ async fn slash_map(mut command: InteractionCommand) -> Result<()> {
     match MapArgs::try_from(args) {
         Ok(args) => map((&mut command).into(), args).await,
         Err(content) => {
-            command.error(content).await?;
-
-            Ok(())
+            CommandOrigin::from(&mut command)
+                .error_with_help(content)
+                .await
         }
     }
 }
@@ -240,7 +260,9 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
         }
     };
 
-    let MapArgs { map, attrs, .. } = args;
+    let MapArgs {
+        map, attrs, view, ..
+    } = args;
 
     let map_id = if let Some(id) = map {
         id
@@ -276,13 +298,13 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
     };
 
     let mapset_res = match map_id {
-        MapIdType::Map(id) => Context::osu().beatmapset_from_map_id(id).await,
-        MapIdType::Set(id) => Context::osu().beatmapset(id).await,
+        MapIdType::Map(id) => Context::osu_map().request_mapset_from_map_id(id).await,
+        MapIdType::Set(id) => Context::osu_map().request_mapset(id).await,
     };
 
     let mut mapset = match mapset_res {
         Ok(mapset) => mapset,
-        Err(OsuError::NotFound) => {
+        Err(MapError::NotFound) => {
             let content = match map_id {
                 MapIdType::Map(id) => format!("Beatmapset of map {id} was not found"),
                 MapIdType::Set(id) => format!("Beatmapset with id {id} was not found"),
@@ -290,16 +312,20 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
 
             return orig.error(content).await;
         }
-        Err(err) => {
+        Err(MapError::Report(err)) => {
             let _ = orig.error(OSU_API_ISSUE).await;
 
-            return Err(Report::new(err).wrap_err("failed to get mapset"));
+            return Err(err.wrap_err("failed to get mapset"));
         }
     };
 
     let mapset_clone = mapset.clone();
     tokio::spawn(async move { Context::osu_map().store(&mapset_clone).await });
 
+    if let MapView::Community = view {
+        return map_community(orig, mapset).await;
+    }
+
     let Some(mut maps) = mapset.maps.take().filter(|maps| !maps.is_empty()) else {
         return orig.error("The mapset has no maps").await;
     };
@@ -351,7 +377,9 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
             let w = SingleScorePagination::IMAGE_W;
             let h = SingleScorePagination::IMAGE_H;
 
-            match map_strains_graph(&map, mods_with_mode, &mapset.covers.cover, w, h).await {
+            let cover = mapset.covers.cover.as_str();
+
+            match map_strains_graph(&map, mods_with_mode, Some(cover), w, h).await {
                 Ok(graph) => Some(graph),
                 Err(err) => {
                     warn!(?err, "Failed to create graph");
@@ -378,6 +406,7 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
         .attrs(attrs)
         .origin(origin)
         .content(content.unwrap_or_default().into_boxed_str())
+        .expanded(false)
         .msg_owner(orig.user_id()?)
         .build();
 
@@ -389,3 +418,105 @@ async fn map(orig: CommandOrigin<'_>, args: MapArgs<'_>) -> Result<()> {
         .begin(orig)
         .await
 }
+
+/// Amount of recent comments to choose the most upvoted ones from.
+const COMMENT_POOL: usize = 50;
+
+async fn map_community(orig: CommandOrigin<'_>, mapset: BeatmapsetExtended) -> Result<()> {
+    let comments_res = Context::osu_request(|osu| {
+        osu.comments()
+            .commentable_type("beatmapset")
+            .commentable_id(mapset.mapset_id)
+            .sort_new()
+    })
+    .await;
+
+    let bundle = match comments_res {
+        Ok(bundle) => bundle,
+        Err(err) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+
+            return Err(Report::new(err).wrap_err("Failed to get mapset comments"));
+        }
+    };
+
+    let mut comments: Vec<_> = bundle
+        .comments
+        .into_iter()
+        .filter(|comment| comment.parent_id.is_none() && comment.deleted_at.is_none())
+        .take(COMMENT_POOL)
+        .filter_map(|comment| {
+            let message = comment
+                .message
+                .filter(|message| !message.trim().is_empty())?;
+
+            let username = comment
+                .user_id
+                .and_then(|user_id| bundle.users.iter().find(|user| user.user_id == user_id))
+                .map(|user| user.username.clone())
+                .or(comment.legacy_name)
+                .unwrap_or_else(|| "Deleted user".into());
+
+            Some(MapComment {
+                user_id: comment.user_id,
+                username,
+                message,
+                votes: comment.votes_count,
+                created_at: comment.created_at,
+            })
+        })
+        .collect();
+
+    // Stable sort so that equally voted comments stay newest first
+    comments.sort_by(|a, b| b.votes.cmp(&a.votes));
+
+    let graph = match mapset.ratings.as_deref() {
+        Some([_, ratings @ ..]) if ratings.iter().any(|&amount| amount > 0) => {
+            match ratings_graph(ratings) {
+                Ok(graph) => Some(graph),
+                Err(err) => {
+                    warn!(?err, "Failed to create ratings graph");
+
+                    None
+                }
+            }
+        }
+        _ => None,
+    };
+
+    let pagination = MapCommentsPagination::builder()
+        .mapset(mapset)
+        .comments(comments.into_boxed_slice())
+        .ratings(graph.is_some())
+        .msg_owner(orig.user_id()?)
+        .build();
+
+    ActiveMessages::builder(pagination)
+        .start_by_update(true)
+        .attachment(graph.map(|bytes| ("map_ratings.png".to_owned(), bytes)))
+        .begin(orig)
+        .await
+}
+
+/// Bars for the amount of user ratings from 1 to 10.
+fn ratings_graph(ratings: &[u32]) -> Result<Vec<u8>> {
+    const W: u32 = 675;
+    const H: u32 = 300;
+
+    let counts: Vec<_> = ratings.iter().map(|&amount| amount as usize).collect();
+
+    let histogram = Histogram {
+        axes: GraphAxes {
+            caption: "User ratings".to_owned(),
+            x_desc: None,
+            y_desc: None,
+        },
+        start: 1,
+        bucket_width: 1,
+        counts: &counts,
+        x_labels: counts.len(),
+        x_fmt: &|rating| rating.to_string(),
+    };
+
+    histogram.render(W, H, GraphPalette::default())
+}
@@ -0,0 +1,183 @@
+use bathbot_cards::MapIntroCard;
+use bathbot_macros::SlashCommand;
+use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE, matcher};
+use eyre::{Report, Result};
+use rosu_v2::model::mods::GameModsIntermode;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    core::{BotConfig, Context, commands::CommandOrigin},
+    manager::{MapError, Mods},
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+/// Accent color if the server did not configure one
+const DEFAULT_ACCENT: (u8, u8, u8) = (255, 102, 170);
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "mapintro",
+    desc = "Create an image introducing a map of a mappool",
+    help = "Create a wide image introducing a map of a mappool, meant to be shown on streams.\n\
+    The map's stats are adjusted to the mods of the slot e.g. `HR2` shows the stats with HR.\n\
+    For `NM`, `FM`, and `TB` slots the stats are shown without mods.\n\
+    Server authorities can adjust the accent color and label via `/serverconfig mapintro`."
+)]
+pub struct MapIntro {
+    #[command(desc = "Specify a map url or map id")]
+    map: String,
+    #[command(desc = "Specify the slot of the map e.g. NM1, HD2, or TB")]
+    slot: String,
+}
+
+async fn slash_mapintro(mut command: InteractionCommand) -> Result<()> {
+    let args = MapIntro::from_interaction(command.input_data())?;
+
+    mapintro((&mut command).into(), args).await
+}
+
+async fn mapintro(orig: CommandOrigin<'_>, args: MapIntro) -> Result<()> {
+    let Some(map_id) = matcher::get_osu_map_id(&args.map) else {
+        let content =
+            "Failed to parse map url. Be sure you specify a valid map id or url to a map.";
+
+        return orig.error(content).await;
+    };
+
+    let slot = args.slot.trim().to_ascii_uppercase();
+
+    let Some(mods) = slot_mods(&slot) else {
+        let content = format!(
+            "Invalid slot `{slot}`. Be sure to specify a mod group with an optional number \
+            e.g. `NM1`, `HDHR2`, or `TB`."
+        );
+
+        return orig.error(content).await;
+    };
+
+    let map = match Context::osu_map().map(map_id, None).await {
+        Ok(map) => map,
+        Err(MapError::NotFound) => {
+            let content = format!("Could not find beatmap with id `{map_id}`");
+
+            return orig.error(content).await;
+        }
+        Err(MapError::Report(err)) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let (accent, label) = match orig.guild_id() {
+        Some(guild_id) => {
+            Context::guild_config()
+                .peek(guild_id, |config| {
+                    (config.intro_accent, config.intro_label.clone())
+                })
+                .await
+        }
+        None => (None, None),
+    };
+
+    let accent = accent.map_or(DEFAULT_ACCENT, |color| {
+        ((color >> 16) as u8, (color >> 8) as u8, color as u8)
+    });
+
+    let attrs = map.attributes().mods(mods.clone()).build();
+
+    let mut calc = Context::pp(&map).mods(Mods::new(mods.clone()));
+    let stars = calc
+        .difficulty()
+        .await
+        .map_or(0.0, |attrs| attrs.stars() as f32);
+
+    let cover = match Context::client().get_mapset_cover(map.cover()).await {
+        Ok(cover) => Some(cover),
+        Err(err) => {
+            warn!(?err, "Failed to get mapset cover for map intro");
+
+            None
+        }
+    };
+
+    let mods_str = if mods.is_empty() {
+        String::new()
+    } else {
+        mods.to_string()
+    };
+
+    let card = MapIntroCard {
+        cover: cover.as_deref(),
+        artist: map.artist(),
+        title: map.title(),
+        version: map.version(),
+        creator: map.creator(),
+        slot: &slot,
+        mods: &mods_str,
+        stars,
+        bpm: map.bpm() * attrs.clock_rate as f32,
+        seconds: (f64::from(map.seconds_drain()) / attrs.clock_rate) as u32,
+        cs: attrs.cs as f32,
+        ar: attrs.ar as f32,
+        od: attrs.od as f32,
+        hp: attrs.hp as f32,
+        accent,
+        label: label.as_deref(),
+        assets: BotConfig::get().paths.assets.clone(),
+    };
+
+    let _permit = match Context::render_queue().acquire(&orig).await {
+        Ok(permit) => permit,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let bytes = match card.draw() {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = orig.error("Failed to draw the map intro :(").await;
+
+            return Err(Report::new(err).wrap_err("Failed to draw map intro card"));
+        }
+    };
+
+    let builder = MessageBuilder::new().attachment("map_intro.png", bytes);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+/// Mods that a slot is played with; `None` if the slot is invalid.
+fn slot_mods(slot: &str) -> Option<GameModsIntermode> {
+    let group = slot.trim_end_matches(|c: char| c.is_ascii_digit());
+
+    if group.is_empty() || !group.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    match group {
+        "NM" | "FM" | "TB" => Some(GameModsIntermode::new()),
+        _ => GameModsIntermode::try_from_acronyms(group),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mods_of_slots() {
+        assert_eq!(slot_mods("NM1"), Some(GameModsIntermode::new()));
+        assert_eq!(slot_mods("TB"), Some(GameModsIntermode::new()));
+        assert_eq!(
+            slot_mods("HDHR2"),
+            GameModsIntermode::try_from_acronyms("HDHR")
+        );
+        assert_eq!(slot_mods("12"), None);
+        assert_eq!(slot_mods("N-M1"), None);
+    }
+}
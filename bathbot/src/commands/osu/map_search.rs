@@ -623,7 +623,7 @@ async fn prefix_search(msg: &Message, args: Args<'_>) -> Result<()> {
 }
 
 async fn search(orig: CommandOrigin<'_>, args: Search) -> Result<()> {
-    let mut search_result = match args.request(Context::osu()).await {
+    let mut search_result = match Context::osu_request(|osu| args.request(osu)).await {
         Ok(response) => response,
         Err(err) => {
             let _ = orig.error(OSU_API_ISSUE).await;
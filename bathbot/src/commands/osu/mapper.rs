@@ -6,7 +6,7 @@ use std::{
 use bathbot_macros::{HasName, SlashCommand, command};
 use bathbot_model::{command_fields::GameModeOption, embed_builder::SettingsImage};
 use bathbot_psql::model::configs::{GuildConfig, ListSize, ScoreData};
-use bathbot_util::{CowUtils, constants::GENERAL_ISSUE, matcher};
+use bathbot_util::{CowUtils, constants::GENERAL_ISSUE, matcher, osu::effective_clock_rate};
 use eyre::{Report, Result};
 use rosu_v2::{
     prelude::{GameMode, OsuError, Score},
@@ -378,7 +378,7 @@ async fn mapper(orig: CommandOrigin<'_>, args: Mapper<'_>) -> Result<()> {
                     let fut = map_strains_graph(
                         &entry.map.pp_map,
                         entry.score.mods.clone(),
-                        entry.map.cover(),
+                        Some(entry.map.cover()),
                         SingleScorePagination::IMAGE_W,
                         SingleScorePagination::IMAGE_H,
                     );
@@ -489,9 +489,9 @@ async fn process_scores(
         Some(ScoreOrder::Length) => {
             entries.sort_by(|a, b| {
                 let a_len = a.get_half().map.seconds_drain() as f64
-                    / a.get_half().score.mods.clock_rate().unwrap_or(1.0);
+                    / effective_clock_rate(&a.get_half().score.mods);
                 let b_len = b.get_half().map.seconds_drain() as f64
-                    / b.get_half().score.mods.clock_rate().unwrap_or(1.0);
+                    / effective_clock_rate(&b.get_half().score.mods);
 
                 b_len.partial_cmp(&a_len).unwrap_or(Ordering::Equal)
             });
@@ -17,6 +17,7 @@ use crate::{
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(name = "matchcompare", desc = "Compare two multiplayer matches")]
 #[bucket(MatchCompare)]
+#[flags(BANCHO_ONLY)]
 pub struct MatchCompare {
     #[command(desc = "Specify the first match url or match id")]
     match_url_1: String,
@@ -101,16 +102,16 @@ async fn matchcompare(mut command: InteractionCommand, args: MatchCompare) -> Re
         return Ok(());
     }
 
-    let match_fut1 = Context::osu().osu_match(match_id1);
-    let match_fut2 = Context::osu().osu_match(match_id2);
+    let match_fut1 = Context::osu_request(|osu| osu.osu_match(match_id1));
+    let match_fut2 = Context::osu_request(|osu| osu.osu_match(match_id2));
 
     let output = output.unwrap_or_default();
     let comparison = comparison.unwrap_or_default();
 
     let pagination = match tokio::try_join!(match_fut1, match_fut2) {
         Ok((mut match1, mut match2)) => {
-            let previous_fut_1 = retrieve_previous(&mut match1, Context::osu());
-            let previous_fut_2 = retrieve_previous(&mut match2, Context::osu());
+            let previous_fut_1 = retrieve_previous(&mut match1);
+            let previous_fut_2 = retrieve_previous(&mut match2);
 
             if let Err(err) = tokio::try_join!(previous_fut_1, previous_fut_2) {
                 let _ = command.error(OSU_API_ISSUE).await;
@@ -9,7 +9,7 @@ use bathbot_macros::{SlashCommand, command};
 use bathbot_util::{IntHasher, constants::OSU_API_ISSUE, matcher};
 use eyre::{Report, Result};
 use rosu_v2::prelude::{
-    GameMod, GameModIntermode, GameMods, GameModsIntermode, MatchGame, MatchTeam, Osu, OsuError,
+    GameMod, GameModIntermode, GameMods, GameModsIntermode, MatchGame, MatchTeam, OsuError,
     OsuMatch, OsuResult, TeamType, User,
 };
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
@@ -31,6 +31,7 @@ use crate::{
     help = "Calculate a performance rating for each player in the given multiplayer match.\n\
     Current formula: <https://i.imgur.com/zuii7Oj.png> ([desmos](https://www.desmos.com/calculator/mm4tins990))"
 )]
+#[flags(BANCHO_ONLY)]
 pub struct MatchCost<'a> {
     #[command(desc = "Specify a match url or match id")]
     match_url: Cow<'a, str>,
@@ -110,6 +111,7 @@ impl<'m> MatchCost<'m> {
 #[usage("[match url / match id] [amount of warmups]")]
 #[examples("58320988 1", "https://osu.ppy.sh/community/matches/58320988")]
 #[aliases("mc", "matchcost")]
+#[flags(BANCHO_ONLY)]
 #[group(AllModes)]
 async fn prefix_matchcosts(msg: &Message, args: Args<'_>) -> Result<()> {
     match MatchCost::args(args) {
@@ -149,12 +151,10 @@ async fn matchcosts(orig: CommandOrigin<'_>, args: MatchCost<'_>) -> Result<()>
     let warmups = warmups.unwrap_or(0);
     let ez_mult = ez_mult.unwrap_or(1.0);
     let skip_last = skip_last.unwrap_or(0);
-    let osu = Context::osu();
-
     // Retrieve the match
-    let (osu_match, games) = match osu.osu_match(match_id).await {
+    let (osu_match, games) = match Context::osu_request(|osu| osu.osu_match(match_id)).await {
         Ok(mut osu_match) => {
-            retrieve_previous(&mut osu_match, osu).await?;
+            retrieve_previous(&mut osu_match).await?;
 
             let games_iter = osu_match
                 .drain_games()
@@ -256,13 +256,13 @@ async fn matchcosts(orig: CommandOrigin<'_>, args: MatchCost<'_>) -> Result<()>
         .await
 }
 
-pub async fn retrieve_previous(osu_match: &mut OsuMatch, osu: &Osu) -> OsuResult<()> {
+pub async fn retrieve_previous(osu_match: &mut OsuMatch) -> OsuResult<()> {
     let mut curr = &*osu_match;
     let mut prev: Option<OsuMatch> = None;
 
     // Retrieve at most 500 previous events
     for _ in 0..5 {
-        match curr.get_previous(osu).await {
+        match Context::osu_request(move |osu| curr.get_previous(osu)).await {
             Some(Ok(next_prev)) => {
                 let prev_opt = prev.take();
                 curr = &*prev.get_or_insert(next_prev);
@@ -3,7 +3,7 @@ use std::borrow::Cow;
 use bathbot_macros::{SlashCommand, command};
 use bathbot_model::command_fields::ThreadChannel;
 use bathbot_util::{
-    MessageBuilder,
+    CowUtils, MessageBuilder,
     constants::{
         GENERAL_ISSUE, INVALID_ACTION_FOR_CHANNEL_TYPE, OSU_API_ISSUE, OSU_BASE,
         THREADS_UNAVAILABLE,
@@ -11,14 +11,16 @@ use bathbot_util::{
     matcher,
 };
 use eyre::{Report, Result, WrapErr};
+use rosu_v2::prelude::MatchTeam;
 use twilight_http::{api_error::ApiError, error::ErrorType};
-use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
 use twilight_model::channel::{ChannelType, thread::AutoArchiveDuration};
 
 use crate::{
     Context,
     core::commands::CommandOrigin,
-    matchlive::MatchTrackResult,
+    manager::MapError,
+    matchlive::{BracketEntry, BracketKind, MatchAnnotateResult, MatchTrackResult},
     util::{ChannelExt, CheckPermissions, InteractionCommandExt, interaction::InteractionCommand},
 };
 
@@ -27,14 +29,22 @@ use crate::{
     name = "matchlive",
     desc = "Live track a multiplayer match",
     help = "Similar to what an mp link does, this command will \
-    keep a channel up to date about events in a multiplayer match."
+    keep a channel up to date about events in a multiplayer match.\n\
+    Use `/matchlive pick` and `/matchlive ban` to annotate the live embed with \
+    the picks and bans of each team.\n\
+    Any amount of channels across servers can track the same match, \
+    the match is only requested once and updates are shared between them."
 )]
-#[flags(AUTHORITY)]
+#[flags(AUTHORITY, BANCHO_ONLY)]
 pub enum Matchlive<'a> {
     #[command(name = "track")]
     Add(MatchliveAdd<'a>),
     #[command(name = "untrack")]
     Remove(MatchliveRemove<'a>),
+    #[command(name = "pick")]
+    Pick(MatchlivePick<'a>),
+    #[command(name = "ban")]
+    Ban(MatchliveBan<'a>),
 }
 
 #[derive(CommandModel, CreateCommand)]
@@ -53,10 +63,79 @@ pub struct MatchliveRemove<'a> {
     match_url: Cow<'a, str>,
 }
 
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "pick", desc = "Announce a team's map pick in a tracked match")]
+pub struct MatchlivePick<'a> {
+    #[command(desc = "Specify a map url or map id")]
+    map: Cow<'a, str>,
+    #[command(desc = "Specify the team that picked the map")]
+    team: MatchliveTeam,
+    #[command(
+        desc = "Specify a match url or match id",
+        help = "Specify a match url or match id.\n\
+        Only required if the channel tracks more than one match."
+    )]
+    match_url: Option<Cow<'a, str>>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "ban", desc = "Announce a team's map ban in a tracked match")]
+pub struct MatchliveBan<'a> {
+    #[command(desc = "Specify a map url or map id")]
+    map: Cow<'a, str>,
+    #[command(desc = "Specify the team that banned the map")]
+    team: MatchliveTeam,
+    #[command(
+        desc = "Specify a match url or match id",
+        help = "Specify a match url or match id.\n\
+        Only required if the channel tracks more than one match."
+    )]
+    match_url: Option<Cow<'a, str>>,
+}
+
+#[derive(Copy, Clone, CommandOption, CreateOption)]
+pub enum MatchliveTeam {
+    #[option(name = "Blue", value = "blue")]
+    Blue,
+    #[option(name = "Red", value = "red")]
+    Red,
+}
+
+impl From<MatchliveTeam> for MatchTeam {
+    fn from(team: MatchliveTeam) -> Self {
+        match team {
+            MatchliveTeam::Blue => Self::Blue,
+            MatchliveTeam::Red => Self::Red,
+        }
+    }
+}
+
 async fn slash_matchlive(mut command: InteractionCommand) -> Result<()> {
     match Matchlive::from_interaction(command.input_data())? {
         Matchlive::Add(args) => matchlive((&mut command).into(), args).await,
         Matchlive::Remove(args) => matchliveremove((&mut command).into(), Some(args)).await,
+        Matchlive::Pick(args) => {
+            let MatchlivePick {
+                map,
+                team,
+                match_url,
+            } = args;
+
+            let orig = (&mut command).into();
+
+            matchlive_annotate(orig, BracketKind::Pick, &map, team, match_url.as_deref()).await
+        }
+        Matchlive::Ban(args) => {
+            let MatchliveBan {
+                map,
+                team,
+                match_url,
+            } = args;
+
+            let orig = (&mut command).into();
+
+            matchlive_annotate(orig, BracketKind::Ban, &map, team, match_url.as_deref()).await
+        }
     }
 }
 
@@ -72,7 +151,7 @@ async fn slash_matchlive(mut command: InteractionCommand) -> Result<()> {
 #[examples("58320988", "https://osu.ppy.sh/community/matches/58320988")]
 #[alias("mla", "matchliveadd", "mlt", "matchlivetrack")]
 #[bucket(MatchLive)]
-#[flags(AUTHORITY)]
+#[flags(AUTHORITY, BANCHO_ONLY)]
 #[group(AllModes)]
 async fn prefix_matchlive(msg: &Message, mut args: Args<'_>) -> Result<()> {
     match args.next() {
@@ -103,7 +182,7 @@ async fn prefix_matchlive(msg: &Message, mut args: Args<'_>) -> Result<()> {
 #[usage("[match url / match id]")]
 #[examples("58320988", "https://osu.ppy.sh/community/matches/58320988")]
 #[alias("mlr")]
-#[flags(AUTHORITY)]
+#[flags(AUTHORITY, BANCHO_ONLY)]
 #[group(AllModes)]
 async fn prefix_matchliveremove(msg: &Message, mut args: Args<'_>) -> Result<()> {
     let args = match args.next() {
@@ -144,8 +223,9 @@ async fn matchlive(orig: CommandOrigin<'_>, args: MatchliveAdd<'_>) -> Result<()
     };
 
     let mut channel = orig.channel_id();
+    let is_thread = matches!(thread, ThreadChannel::Thread);
 
-    if let ThreadChannel::Thread = thread {
+    if is_thread {
         if orig.guild_id().is_none() {
             return orig.error(THREADS_UNAVAILABLE).await;
         }
@@ -191,7 +271,7 @@ async fn matchlive(orig: CommandOrigin<'_>, args: MatchliveAdd<'_>) -> Result<()
         }
     }
 
-    let content: &str = match Context::add_match_track(channel, match_id).await {
+    let content: &str = match Context::add_match_track(channel, match_id, is_thread).await {
         MatchTrackResult::Added => match orig {
             CommandOrigin::Message { .. } => return Ok(()),
             CommandOrigin::Interaction { command } => {
@@ -245,3 +325,87 @@ async fn matchliveremove(orig: CommandOrigin<'_>, args: Option<MatchliveRemove<'
         orig.error(content).await
     }
 }
+
+async fn matchlive_annotate(
+    orig: CommandOrigin<'_>,
+    kind: BracketKind,
+    map: &str,
+    team: MatchliveTeam,
+    match_url: Option<&str>,
+) -> Result<()> {
+    let channel = orig.channel_id();
+
+    let match_id = match match_url.map(parse_match_id) {
+        Some(Ok(id)) => id,
+        Some(Err(content)) => return orig.error(content).await,
+        None => match Context::tracks_single_match(channel).await {
+            Some(id) => id,
+            None => {
+                let content = "The channel does not track exactly one match \
+                    so you need to specify the match id.";
+
+                return orig.error(content).await;
+            }
+        },
+    };
+
+    let Some(map_id) = matcher::get_osu_map_id(map) else {
+        let content =
+            "Failed to parse map url. Be sure you specify a valid map id or url to a map.";
+
+        return orig.error(content).await;
+    };
+
+    let map = match Context::osu_map().map_slim(map_id).await {
+        Ok(map) => map,
+        Err(MapError::NotFound) => {
+            let content = format!("Could not find beatmap with id `{map_id}`");
+
+            return orig.error(content).await;
+        }
+        Err(MapError::Report(err)) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let map_name = format!(
+        "[{artist} - {title} [{version}]]({OSU_BASE}b/{map_id})",
+        artist = map.artist().cow_escape_markdown(),
+        title = map.title().cow_escape_markdown(),
+        version = map.version().cow_escape_markdown(),
+    );
+
+    let entry = BracketEntry {
+        kind,
+        team: team.into(),
+        map_id,
+        map: map_name.clone(),
+        winner: None,
+    };
+
+    let content = match Context::annotate_match_track(channel, match_id, entry).await {
+        MatchAnnotateResult::Annotated => {
+            let team = match team {
+                MatchliveTeam::Blue => ":blue_circle: Blue",
+                MatchliveTeam::Red => ":red_circle: Red",
+            };
+
+            let action = match kind {
+                BracketKind::Pick => "picked",
+                BracketKind::Ban => "banned",
+            };
+
+            let content = format!("{team} {action} {map_name}");
+            let builder = MessageBuilder::new().embed(content);
+            orig.create_message(builder).await?;
+
+            return Ok(());
+        }
+        MatchAnnotateResult::Duplicate => "That map was already picked or banned in the match",
+        MatchAnnotateResult::NotTracked => "The match wasn't tracked in this channel",
+    };
+
+    orig.error(content).await
+}
@@ -31,6 +31,7 @@ use crate::{
 #[usage("[username1] [username2]")]
 #[example("badewanne3 5joshi")]
 #[alias("medalcommon")]
+#[flags(BANCHO_ONLY)]
 #[group(AllModes)]
 pub async fn prefix_medalscommon(msg: &Message, args: Args<'_>) -> Result<()> {
     let mut args_ = MedalCommon::default();
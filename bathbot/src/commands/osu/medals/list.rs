@@ -49,6 +49,7 @@ impl<'m> MedalList<'m> {
 #[usage("[username]")]
 #[example("brandwagen")]
 #[aliases("ml", "medallist")]
+#[flags(BANCHO_ONLY)]
 #[group(AllModes)]
 async fn prefix_medalslist(
     msg: &Message,
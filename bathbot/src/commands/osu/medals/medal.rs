@@ -41,6 +41,7 @@ use crate::{
 )]
 #[usage("[medal name]")]
 #[examples(r#""50,000 plays""#, "any%")]
+#[flags(BANCHO_ONLY)]
 #[group(AllModes)]
 async fn prefix_medal(msg: &Message, args: Args<'_>) -> Result<()> {
     let name = args.rest().trim_matches('"');
@@ -21,6 +21,7 @@ use crate::{
 #[usage("[username]")]
 #[example("badewanne3")]
 #[aliases("mm", "missingmedals")]
+#[flags(BANCHO_ONLY)]
 #[group(AllModes)]
 async fn prefix_medalsmissing(msg: &Message, mut args: Args<'_>) -> Result<()> {
     let args = match args.next() {
@@ -99,35 +100,59 @@ pub(super) async fn missing(orig: CommandOrigin<'_>, args: MedalMissing<'_>) ->
         .map(MedalType::Medal)
         .collect();
 
-    medals.extend(MEDAL_GROUPS.iter().copied().map(MedalType::Group));
+    let mut progress = MEDAL_GROUPS.map(|_| (0, 0));
+
+    for medal in all_medals.iter() {
+        let Some(idx) = MEDAL_GROUPS.iter().position(|g| *g == medal.grouping) else {
+            continue;
+        };
+
+        let (owned_count, total) = &mut progress[idx];
+        *total += 1;
+
+        if owned.contains(&medal.medal_id.to_native()) {
+            *owned_count += 1;
+        }
+    }
+
+    let groups = MEDAL_GROUPS
+        .iter()
+        .zip(progress)
+        .map(|(&group, (owned, total))| MedalType::Group {
+            group,
+            owned,
+            total,
+        });
+
+    medals.extend(groups);
 
     let sort = args.sort.unwrap_or_default();
 
     match sort {
         MedalMissingOrder::Alphabet => medals.sort_unstable_by(|a, b| {
             a.group().cmp(&b.group()).then_with(|| match (a, b) {
-                (MedalType::Group(_), MedalType::Medal(_)) => Ordering::Less,
-                (MedalType::Medal(_), MedalType::Group(_)) => Ordering::Greater,
+                (MedalType::Group { .. }, MedalType::Medal(_)) => Ordering::Less,
+                (MedalType::Medal(_), MedalType::Group { .. }) => Ordering::Greater,
                 (MedalType::Medal(a), MedalType::Medal(b)) => a.name.cmp(&b.name),
-                (MedalType::Group(_), MedalType::Group(_)) => unreachable!(),
+                (MedalType::Group { .. }, MedalType::Group { .. }) => unreachable!(),
             })
         }),
         MedalMissingOrder::MedalId => medals.sort_unstable_by(|a, b| {
             a.group().cmp(&b.group()).then_with(|| match (a, b) {
-                (MedalType::Group(_), MedalType::Medal(_)) => Ordering::Less,
-                (MedalType::Medal(_), MedalType::Group(_)) => Ordering::Greater,
+                (MedalType::Group { .. }, MedalType::Medal(_)) => Ordering::Less,
+                (MedalType::Medal(_), MedalType::Group { .. }) => Ordering::Greater,
                 (MedalType::Medal(a), MedalType::Medal(b)) => a.medal_id.cmp(&b.medal_id),
-                (MedalType::Group(_), MedalType::Group(_)) => unreachable!(),
+                (MedalType::Group { .. }, MedalType::Group { .. }) => unreachable!(),
             })
         }),
         MedalMissingOrder::Rarity => medals.sort_unstable_by(|a, b| {
             a.group().cmp(&b.group()).then_with(|| match (a, b) {
-                (MedalType::Group(_), MedalType::Medal(_)) => Ordering::Less,
-                (MedalType::Medal(_), MedalType::Group(_)) => Ordering::Greater,
+                (MedalType::Group { .. }, MedalType::Medal(_)) => Ordering::Less,
+                (MedalType::Medal(_), MedalType::Group { .. }) => Ordering::Greater,
                 (MedalType::Medal(a), MedalType::Medal(b)) => {
                     b.rarity.unwrap_or(0.0).total_cmp(&a.rarity.unwrap_or(0.0))
                 }
-                (MedalType::Group(_), MedalType::Group(_)) => unreachable!(),
+                (MedalType::Group { .. }, MedalType::Group { .. }) => unreachable!(),
             })
         }),
     }
@@ -136,7 +161,7 @@ pub(super) async fn missing(orig: CommandOrigin<'_>, args: MedalMissing<'_>) ->
         .iter()
         .filter_map(|medal| match medal {
             MedalType::Medal(medal) => Some(medal.medal_id),
-            MedalType::Group(_) => None,
+            MedalType::Group { .. } => None,
         })
         .collect();
 
@@ -145,7 +170,7 @@ pub(super) async fn missing(orig: CommandOrigin<'_>, args: MedalMissing<'_>) ->
             icons.sort_unstable_by(|(a, _), (b, _)| {
                 let position_fn = |m: &MedalType, id: u32| match m {
                     MedalType::Medal(m) => m.medal_id == id,
-                    MedalType::Group(_) => false,
+                    MedalType::Group { .. } => false,
                 };
 
                 let idx_a = medals.iter().position(|m| position_fn(m, *a));
@@ -186,14 +211,20 @@ pub(super) async fn missing(orig: CommandOrigin<'_>, args: MedalMissing<'_>) ->
 }
 
 pub enum MedalType {
-    Group(MedalGroup),
+    /// Header of a medal group alongside the amount of owned and total
+    /// medals within that group
+    Group {
+        group: MedalGroup,
+        owned: usize,
+        total: usize,
+    },
     Medal(OsekaiMedal),
 }
 
 impl MedalType {
     fn group(&self) -> MedalGroup {
         match self {
-            Self::Group(g) => *g,
+            Self::Group { group, .. } => *group,
             Self::Medal(m) => m.grouping,
         }
     }
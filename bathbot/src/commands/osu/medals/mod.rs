@@ -31,6 +31,7 @@ pub mod stats;
     Check out [osekai](https://osekai.net/) for more info on medals."
 )]
 #[allow(dead_code)]
+#[flags(BANCHO_ONLY)]
 pub enum Medal<'a> {
     #[command(name = "common")]
     Common(MedalCommon<'a>),
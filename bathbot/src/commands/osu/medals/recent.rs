@@ -35,6 +35,7 @@ use crate::{
 #[usage("[username]")]
 #[examples("badewanne3", r#""im a fancy lad""#)]
 #[aliases("mr", "recentmedal")]
+#[flags(BANCHO_ONLY)]
 #[group(AllModes)]
 async fn prefix_medalrecent(msg: &Message, mut args: Args<'_>) -> Result<()> {
     let mut args_ = MedalRecent {
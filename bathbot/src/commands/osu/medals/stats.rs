@@ -22,7 +22,7 @@ use twilight_model::guild::Permissions;
 use super::MedalStats;
 use crate::{
     Context,
-    commands::osu::{require_link, user_not_found},
+    commands::osu::{GraphPalette, require_link, user_not_found},
     core::commands::CommandOrigin,
     embeds::{EmbedData, MedalStatsEmbed, StatsMedal},
     manager::redis::osu::{UserArgs, UserArgsError},
@@ -34,6 +34,7 @@ use crate::{
 #[usage("[username]")]
 #[examples("badewanne3", r#""im a fancy lad""#)]
 #[alias("ms")]
+#[flags(BANCHO_ONLY)]
 #[group(AllModes)]
 async fn prefix_medalstats(
     msg: &Message,
@@ -102,7 +103,7 @@ pub(super) async fn stats(orig: CommandOrigin<'_>, args: MedalStats<'_>) -> Resu
 
     medals.sort_unstable_by_key(|medal| medal.achieved_at);
 
-    let graph = match graph(&medals, W, H) {
+    let graph = match graph(&medals, W, H, GraphPalette::default()) {
         Ok(bytes_option) => bytes_option,
         Err(err) => {
             warn!(?err, "Failed to create graph");
@@ -148,7 +149,12 @@ pub(super) async fn stats(orig: CommandOrigin<'_>, args: MedalStats<'_>) -> Resu
 const W: u32 = 1350;
 const H: u32 = 350;
 
-pub fn graph(medals: &[MedalCompact], w: u32, h: u32) -> Result<Option<Vec<u8>>> {
+pub fn graph(
+    medals: &[MedalCompact],
+    w: u32,
+    h: u32,
+    palette: GraphPalette,
+) -> Result<Option<Vec<u8>>> {
     let (first, last) = match medals {
         [medal] => (medal.achieved_at, medal.achieved_at),
         [first, .., last] => (first.achieved_at, last.achieved_at),
@@ -161,11 +167,12 @@ pub fn graph(medals: &[MedalCompact], w: u32, h: u32) -> Result<Option<Vec<u8>>>
     {
         let mut root = SkiaBackend::new(surface.canvas(), w, h).into_drawing_area();
 
-        let background = RGBColor(19, 43, 33);
+        let background = palette.background;
         root.fill(&background)
             .wrap_err("Failed to fill background")?;
 
-        let title_style = TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&WHITE);
+        let title_style =
+            TextStyle::from(("sans-serif", 25_i32, FontStyle::Bold)).color(&palette.text);
         root = root
             .titled("Medal history", title_style)
             .wrap_err("Failed to draw title")?;
@@ -181,15 +188,15 @@ pub fn graph(medals: &[MedalCompact], w: u32, h: u32) -> Result<Option<Vec<u8>>>
         chart
             .configure_mesh()
             .disable_mesh()
-            .label_style(("sans-serif", 20, &WHITE))
-            .axis_style(RGBColor(7, 18, 14))
-            .axis_desc_style(("sans-serif", 20, FontStyle::Bold, &WHITE))
+            .label_style(("sans-serif", 20, &palette.text))
+            .axis_style(palette.axis)
+            .axis_desc_style(("sans-serif", 20, FontStyle::Bold, &palette.text))
             .draw()
             .wrap_err("Failed to draw mesh and labels")?;
 
         // Draw area
-        let area_style = RGBColor(2, 186, 213).mix(0.6).filled();
-        let border_style = RGBColor(0, 208, 138).stroke_width(3);
+        let area_style = palette.area.mix(0.6).filled();
+        let border_style = palette.border.stroke_width(3);
         let counter = MedalCounter::new(medals);
         let series = AreaSeries::new(counter, 0, area_style).border_style(border_style);
         chart.draw_series(series).wrap_err("Failed to draw area")?;
@@ -0,0 +1,168 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::Write,
+    time::Duration,
+};
+
+use bathbot_util::{
+    EmbedBuilder, IntHasher,
+    constants::{AVATAR_URL, OSU_BASE},
+};
+use eyre::{Report, Result};
+use rosu_v2::prelude::GameMode;
+use tokio::time::{interval, sleep};
+use twilight_model::{
+    channel::message::Embed,
+    id::{Id, marker::ChannelMarker},
+};
+
+use super::rules;
+use crate::core::Context;
+
+/// Periodically compare the stored osu! user stats against the last seen
+/// milestones of all members in guilds with a milestone channel and announce
+/// newly reached ones.
+pub async fn milestone_feed_loop() {
+    // Give the bot some time to settle after booting
+    sleep(Duration::from_secs(60 * 20)).await;
+
+    let mut interval = interval(Duration::from_secs(60 * 60));
+
+    loop {
+        interval.tick().await;
+
+        if let Err(err) = announce_milestones().await {
+            warn!(?err, "Failed to announce milestones");
+        }
+    }
+}
+
+async fn announce_milestones() -> Result<()> {
+    let manager = Context::milestones();
+
+    let mut channel_targets = Vec::new();
+
+    for (guild_id, channel_id) in manager.channels().await? {
+        let members: Vec<_> = match Context::cache().members(guild_id).await {
+            Ok(members) => members.into_iter().map(|id| id as i64).collect(),
+            Err(err) => {
+                warn!(guild = guild_id.get(), ?err, "Failed to get guild members");
+
+                continue;
+            }
+        };
+
+        let osu_ids = Context::user_config().linked_osu_ids(&members).await?;
+        channel_targets.push((channel_id, osu_ids));
+    }
+
+    let relevant: HashSet<u32, IntHasher> = channel_targets
+        .iter()
+        .flat_map(|(_, osu_ids)| osu_ids.iter().copied())
+        .collect();
+
+    if relevant.is_empty() {
+        return Ok(());
+    }
+
+    let relevant: Vec<_> = relevant.into_iter().collect();
+    let stats = manager.stats(&relevant).await?;
+    let seen = manager.seen(&relevant).await?;
+
+    // Embeds of new milestones for each osu! user that reached some
+    let mut new_milestones: HashMap<u32, Vec<Embed>, IntHasher> = HashMap::default();
+
+    for entry in stats {
+        let current = rules::levels(entry.pp, entry.playcount, entry.global_rank);
+
+        // Users that were not seen before are only stored, not announced
+        let Some(prev) = seen.get(&(entry.user_id, entry.mode)) else {
+            manager.set_seen(entry.user_id, entry.mode, current).await?;
+
+            continue;
+        };
+
+        let (milestones, levels) = rules::reached(*prev, current);
+
+        if milestones.is_empty() {
+            continue;
+        }
+
+        manager.set_seen(entry.user_id, entry.mode, levels).await?;
+
+        let username = match Context::osu_user().name(entry.user_id).await {
+            Ok(Some(name)) => name.to_string(),
+            Ok(None) => format!("User id {}", entry.user_id),
+            Err(err) => {
+                warn!(?err, "Failed to get username");
+
+                format!("User id {}", entry.user_id)
+            }
+        };
+
+        let mut description = format!(
+            "[{username}]({OSU_BASE}u/{user_id}/{mode}) reached ",
+            user_id = entry.user_id,
+            mode = entry.mode,
+        );
+
+        for (i, milestone) in milestones.iter().enumerate() {
+            let separator = match i {
+                0 => "",
+                _ if i + 1 == milestones.len() => " and ",
+                _ => ", ",
+            };
+
+            let _ = write!(description, "{separator}{milestone}");
+        }
+
+        let _ = write!(description, " in {}", mode_name(entry.mode));
+
+        let embed = EmbedBuilder::new()
+            .description(description)
+            .thumbnail(format!("{AVATAR_URL}{}", entry.user_id))
+            .build();
+
+        new_milestones.entry(entry.user_id).or_default().push(embed);
+    }
+
+    if new_milestones.is_empty() {
+        return Ok(());
+    }
+
+    for (channel_id, osu_ids) in channel_targets {
+        let embeds: Vec<_> = osu_ids
+            .iter()
+            .filter_map(|osu_id| new_milestones.get(osu_id))
+            .flatten()
+            .cloned()
+            .collect();
+
+        send_embeds(channel_id, &embeds).await;
+    }
+
+    Ok(())
+}
+
+fn mode_name(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Osu => "osu!standard",
+        GameMode::Taiko => "osu!taiko",
+        GameMode::Catch => "osu!ctb",
+        GameMode::Mania => "osu!mania",
+    }
+}
+
+async fn send_embeds(channel_id: Id<ChannelMarker>, embeds: &[Embed]) {
+    // Discord allows at most 10 embeds per message
+    for chunk in embeds.chunks(10) {
+        if let Err(err) = Context::http()
+            .create_message(channel_id)
+            .embeds(chunk)
+            .await
+        {
+            let err = Report::new(err).wrap_err("Failed to send milestone announcement");
+            warn!(channel = channel_id.get(), ?err);
+        }
+    }
+}
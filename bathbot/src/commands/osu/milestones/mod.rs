@@ -0,0 +1,83 @@
+use bathbot_macros::SlashCommand;
+use bathbot_model::command_fields::EnableDisable;
+use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE};
+use eyre::Result;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{Id, marker::ChannelMarker};
+
+pub use self::feed::milestone_feed_loop;
+use crate::{
+    core::Context,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+mod feed;
+mod rules;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "milestones",
+    dm_permission = false,
+    desc = "Announce milestones of this server's linked members",
+    help = "Announce milestones of this server's linked members in a channel.\n\
+    Milestones are every 1,000pp, every 100,000 playcount, and reaching a global rank \
+    of 100k, 50k, 10k, 5k, 1k, 500, 100, 50, 10, or 1 in any mode.\n\
+    Stats are taken from the bot's latest snapshot of a user i.e. whenever the user \
+    was last requested through a command. Milestones are checked every hour and each \
+    milestone is only announced once."
+)]
+#[flags(AUTHORITY, ONLY_GUILDS, BANCHO_ONLY)]
+pub struct Milestones {
+    #[command(desc = "Choose whether announcements should be enabled or disabled")]
+    action: EnableDisable,
+    #[command(
+        desc = "Specify a channel to announce milestones in, defaults to this channel",
+        channel_types = "guild_text guild_announcement"
+    )]
+    channel: Option<Id<ChannelMarker>>,
+}
+
+async fn slash_milestones(mut command: InteractionCommand) -> Result<()> {
+    let args = Milestones::from_interaction(command.input_data())?;
+
+    let Some(guild_id) = command.guild_id else {
+        command
+            .error("Milestones can only be announced in servers")
+            .await?;
+
+        return Ok(());
+    };
+
+    let manager = Context::milestones();
+
+    let content = match args.action {
+        EnableDisable::Enable => {
+            let channel = args.channel.unwrap_or(command.channel_id);
+
+            match manager.set_channel(guild_id, channel).await {
+                Ok(_) => format!(
+                    "Milestones of this server's linked members will now be announced in <#{channel}>"
+                ),
+                Err(err) => {
+                    let _ = command.error(GENERAL_ISSUE).await;
+
+                    return Err(err);
+                }
+            }
+        }
+        EnableDisable::Disable => match manager.remove_channel(guild_id).await {
+            Ok(true) => "Milestones will no longer be announced in this server".to_owned(),
+            Ok(false) => "Milestones were not announced in this server anyway".to_owned(),
+            Err(err) => {
+                let _ = command.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        },
+    };
+
+    let builder = MessageBuilder::new().embed(content);
+    command.update(builder).await?;
+
+    Ok(())
+}
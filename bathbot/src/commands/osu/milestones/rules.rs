@@ -0,0 +1,118 @@
+use std::fmt::{Display, Formatter, Result as FmtResult};
+
+use bathbot_util::numbers::WithComma;
+
+use crate::manager::MilestoneLevels;
+
+/// Every multiple of this amount of pp is a milestone
+const PP_STEP: u32 = 1000;
+
+/// Every multiple of this playcount is a milestone
+const PLAYCOUNT_STEP: u32 = 100_000;
+
+/// Global ranks that are milestones, from worst to best
+const RANK_TIERS: [u32; 10] = [100_000, 50_000, 10_000, 5_000, 1_000, 500, 100, 50, 10, 1];
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Milestone {
+    Pp(u32),
+    Playcount(u32),
+    Rank(u32),
+}
+
+impl Display for Milestone {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Pp(pp) => write!(f, "**{}pp**", WithComma::new(*pp)),
+            Self::Playcount(playcount) => {
+                write!(f, "a playcount of **{}**", WithComma::new(*playcount))
+            }
+            Self::Rank(1) => f.write_str("**rank #1**"),
+            Self::Rank(rank) => write!(f, "the **top {}**", WithComma::new(*rank)),
+        }
+    }
+}
+
+/// Highest milestones for the given stats.
+///
+/// A `global_rank` of 0 denotes an unranked user.
+pub fn levels(pp: f32, playcount: u32, global_rank: u32) -> MilestoneLevels {
+    let rank = RANK_TIERS
+        .iter()
+        .copied()
+        .rfind(|&tier| global_rank > 0 && global_rank <= tier)
+        .unwrap_or(0);
+
+    MilestoneLevels {
+        pp: (pp as u32 / PP_STEP) * PP_STEP,
+        playcount: (playcount / PLAYCOUNT_STEP) * PLAYCOUNT_STEP,
+        rank,
+    }
+}
+
+/// Compare the current levels against the previously seen ones.
+///
+/// Returns the newly reached milestones and the levels that should be stored
+/// from now on. Stored levels never decrease so that dropping below and
+/// re-reaching a milestone does not announce it again.
+pub fn reached(
+    seen: MilestoneLevels,
+    current: MilestoneLevels,
+) -> (Vec<Milestone>, MilestoneLevels) {
+    let mut milestones = Vec::new();
+    let mut levels = seen;
+
+    if current.pp > seen.pp {
+        milestones.push(Milestone::Pp(current.pp));
+        levels.pp = current.pp;
+    }
+
+    if current.playcount > seen.playcount {
+        milestones.push(Milestone::Playcount(current.playcount));
+        levels.playcount = current.playcount;
+    }
+
+    if current.rank > 0 && (seen.rank == 0 || current.rank < seen.rank) {
+        milestones.push(Milestone::Rank(current.rank));
+        levels.rank = current.rank;
+    }
+
+    (milestones, levels)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn stats_to_levels() {
+        let levels = levels(12_345.6, 199_999, 9_876);
+
+        assert_eq!(levels.pp, 12_000);
+        assert_eq!(levels.playcount, 100_000);
+        assert_eq!(levels.rank, 10_000);
+
+        assert_eq!(super::levels(999.9, 50, 0), MilestoneLevels::default());
+        assert_eq!(super::levels(0.0, 0, 1).rank, 1);
+        assert_eq!(super::levels(0.0, 0, 100_001).rank, 0);
+    }
+
+    #[test]
+    fn reached_milestones() {
+        let seen = levels(4_900.0, 90_000, 10_500);
+        let current = levels(5_100.0, 95_000, 4_000);
+
+        let (milestones, stored) = reached(seen, current);
+        assert_eq!(milestones, [Milestone::Pp(5_000), Milestone::Rank(5_000)]);
+        assert_eq!(stored, current);
+
+        // Dropping below a milestone and reaching it again is not announced
+        let dropped = levels(4_950.0, 95_000, 6_000);
+        let (milestones, stored) = reached(stored, dropped);
+        assert!(milestones.is_empty());
+        assert_eq!(stored, current);
+
+        let (milestones, _) = reached(stored, current);
+        assert!(milestones.is_empty());
+    }
+}
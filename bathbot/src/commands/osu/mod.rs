@@ -7,9 +7,20 @@ macro_rules! user_id {
             crate::commands::osu::UserIdResult::Future(fut) => match fut.await {
                 crate::commands::osu::UserIdFutureResult::Id(user_id) => Some(user_id),
                 crate::commands::osu::UserIdFutureResult::NotLinked(user_id) => {
-                    let content = format!("<@{user_id}> is not linked to an osu!profile");
+                    match crate::commands::osu::imported_osu_id($orig.guild_id(), user_id).await {
+                        Ok(Some(osu_id)) => Some(rosu_v2::request::UserId::Id(osu_id)),
+                        Ok(None) => {
+                            let content = format!("<@{user_id}> is not linked to an osu!profile");
 
-                    return $orig.error(content).await;
+                            return $orig.error(content).await;
+                        }
+                        Err(err) => {
+                            let content = bathbot_util::constants::GENERAL_ISSUE;
+                            let _ = $orig.error(content).await;
+
+                            return Err(err);
+                        }
+                    }
                 }
                 crate::commands::osu::UserIdFutureResult::Err(err) => {
                     let content = bathbot_util::constants::GENERAL_ISSUE;
@@ -67,16 +78,21 @@ use bathbot_util::osu::ModsResult;
 use eyre::{Report, Result, WrapErr};
 use rosu_v2::request::UserId;
 use twilight_interactions::command::{CommandOption, CreateOption};
-use twilight_model::id::{Id, marker::UserMarker};
+use twilight_model::id::{
+    Id,
+    marker::{GuildMarker, UserMarker},
+};
 
 pub use self::{
     badges::*, claim_name::*, compare::*, daily_challenge::*, fix::*, graphs::*, leaderboard::*,
-    map::*, map_search::*, match_compare::*, match_costs::*, medals::*, nochoke::*, osustats::*,
-    profile::*, recent::*, render::*, simulate::*, snipe::*, top::*, whatif::*,
+    map::*, map_search::*, match_compare::*, match_costs::*, medals::*, milestones::*, nochoke::*,
+    osustats::*, profile::*, recent::*, render::*, score_card::*, simulate::*, snipe::*, top::*,
+    whatif::*,
 };
 use crate::{
     Context,
     core::commands::{CommandOrigin, interaction::InteractionCommands},
+    manager::redis::osu::UserArgs,
 };
 
 mod attributes;
@@ -87,24 +103,32 @@ mod bws;
 mod cards;
 mod claim_name;
 mod compare;
+mod completion;
 mod daily_challenge;
+mod efficiency;
+mod favorites;
 mod fix;
 mod graphs;
 mod leaderboard;
 #[cfg(feature = "server")]
 mod link;
 mod map;
+mod map_intro;
 mod map_search;
 mod mapper;
 mod match_compare;
 mod match_costs;
 mod medals;
+mod milestones;
+mod mods;
 mod most_played;
 mod nochoke;
 mod osekai;
+mod osu_all;
 mod osustats;
 mod pinned;
 mod pp;
+mod preview;
 mod profile;
 mod rank;
 mod ranking;
@@ -112,10 +136,13 @@ mod ratios;
 mod recent;
 pub(crate) mod relax;
 mod render;
+mod score_card;
+mod score_source;
 mod serverleaderboard;
 mod simulate;
 mod snipe;
 mod top;
+mod verify_score;
 mod whatif;
 
 #[cfg(feature = "matchlive")]
@@ -156,6 +183,15 @@ pub enum UserIdFutureResult {
 impl UserIdFutureResult {
     async fn process(user_id: Id<UserMarker>) -> Self {
         match Context::user_config().osu_id(user_id).await {
+            // Links refer to bancho ids so private servers are assumed to
+            // know the user by the same name
+            Ok(Some(osu_id)) if Context::osu_server().is_some() => {
+                match Context::osu_user().name(osu_id).await {
+                    Ok(Some(name)) => UserIdFutureResult::Id(UserId::Name(name)),
+                    Ok(None) => UserIdFutureResult::NotLinked(user_id),
+                    Err(err) => UserIdFutureResult::Err(err),
+                }
+            }
             Ok(Some(user_id)) => UserIdFutureResult::Id(UserId::Id(user_id)),
             Ok(None) => UserIdFutureResult::NotLinked(user_id),
             Err(err) => UserIdFutureResult::Err(err),
@@ -163,6 +199,18 @@ impl UserIdFutureResult {
     }
 }
 
+/// The osu! id that the guild imported for an unlinked user through
+/// `/serverconfig importlinks`.
+pub async fn imported_osu_id(
+    guild_id: Option<Id<GuildMarker>>,
+    user_id: Id<UserMarker>,
+) -> Result<Option<u32>> {
+    match guild_id {
+        Some(guild_id) => Context::user_config().imported_osu_id(guild_id, user_id).await,
+        None => Ok(None),
+    }
+}
+
 pub async fn require_link(orig: &CommandOrigin<'_>) -> Result<()> {
     let link = InteractionCommands::get_command("link").map_or_else(
         || "`/link`".to_owned(),
@@ -179,7 +227,21 @@ pub async fn require_link(orig: &CommandOrigin<'_>) -> Result<()> {
 
 pub async fn user_not_found(user_id: UserId) -> String {
     let user_id = match user_id {
-        user_id @ UserId::Name(_) => user_id,
+        UserId::Name(name) => {
+            let alt_name = UserArgs::alt_name(&name);
+
+            match Context::osu_user().alias(&name, alt_name.as_deref()).await {
+                Ok(Some((_, current))) if !current.eq_ignore_ascii_case(&name) => {
+                    return format!(
+                        "User `{name}` was not found, they are now known as `{current}`"
+                    );
+                }
+                Ok(_) => {}
+                Err(err) => warn!("{err:?}"),
+            }
+
+            UserId::Name(name)
+        }
         UserId::Id(user_id) => match Context::osu_user().name(user_id).await {
             Ok(Some(name)) => UserId::Name(name),
             Ok(None) => UserId::Id(user_id),
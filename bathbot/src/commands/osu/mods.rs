@@ -0,0 +1,369 @@
+use std::{borrow::Cow, fmt::Write};
+
+use bathbot_macros::{SlashCommand, command};
+use bathbot_model::command_fields::GameModeOption;
+use bathbot_util::{EmbedBuilder, FooterBuilder, MessageBuilder};
+use eyre::Result;
+use rosu_v2::{
+    model::mods::{GameMod, GameModIntermode, GameModKind},
+    prelude::GameMode,
+};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::channel::message::embed::EmbedField;
+
+use crate::{
+    Context,
+    core::commands::{CommandOrigin, prefix::Args},
+    util::{InteractionCommandExt, interaction::InteractionCommand, osu::mod_multiplier},
+};
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(name = "mods", desc = "Explain what mods do")]
+#[flags(SKIP_DEFER)]
+pub enum Mods<'a> {
+    #[command(name = "info")]
+    Info(ModsInfo<'a>),
+    #[command(name = "index")]
+    Index(ModsIndex),
+}
+
+const INFO_DESC: &str = "Explain a mod's effects, score multiplier, and settings";
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "info",
+    desc = INFO_DESC,
+    help = "Explain a mod's effects, score multiplier, and settings.\n\
+    Works for all mods that are known to lazer, including lazer-only mods like \
+    `DC`, `WU`, or `AS`."
+)]
+pub struct ModsInfo<'a> {
+    #[command(desc = "Specify a mod acronym e.g. DT or WU")]
+    acronym: Cow<'a, str>,
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "index", desc = "List all mods of a gamemode")]
+pub struct ModsIndex {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+}
+
+async fn slash_mods(mut command: InteractionCommand) -> Result<()> {
+    match Mods::from_interaction(command.input_data())? {
+        Mods::Info(args) => info((&mut command).into(), args).await,
+        Mods::Index(args) => index((&mut command).into(), args).await,
+    }
+}
+
+#[command]
+#[desc(INFO_DESC)]
+#[usage("[acronym]")]
+#[examples("dt", "wu")]
+#[aliases("mod", "modsinfo")]
+#[group(AllModes)]
+async fn prefix_modinfo(msg: &Message, mut args: Args<'_>) -> Result<()> {
+    let args = ModsInfo {
+        acronym: args.next().unwrap_or_default().into(),
+        mode: None,
+    };
+
+    info(msg.into(), args).await
+}
+
+async fn info(orig: CommandOrigin<'_>, args: ModsInfo<'_>) -> Result<()> {
+    let acronym = args.acronym.trim_start_matches('+').to_uppercase();
+
+    if acronym.is_empty() {
+        return orig
+            .error_callback("You must specify a mod acronym, e.g. `dt`")
+            .await;
+    }
+
+    let mode = match args.mode {
+        Some(mode) => GameMode::from(mode),
+        None => Context::user_config()
+            .mode(orig.user_id()?)
+            .await?
+            .unwrap_or(GameMode::Osu),
+    };
+
+    let gamemod = GameMod::new(&acronym, mode);
+
+    if is_unknown(&gamemod) {
+        let other_modes: Vec<_> = MODES
+            .into_iter()
+            .filter(|&other| other != mode && !is_unknown(&GameMod::new(&acronym, other)))
+            .map(mode_name)
+            .collect();
+
+        let content = if other_modes.is_empty() {
+            format!(
+                "`{acronym}` is not a known mod.\n\
+                Use `/mods index` to see all mods of a gamemode."
+            )
+        } else {
+            format!(
+                "`{acronym}` is not a mod for {}, only for {}.\n\
+                Specify the `mode` option to see its info.",
+                mode_name(mode),
+                other_modes.join(", "),
+            )
+        };
+
+        return orig.error_callback(content).await;
+    }
+
+    let intermode = gamemod.intermode();
+
+    let incompatible =
+        gamemod
+            .incompatible_mods()
+            .iter()
+            .fold(String::new(), |mut value, acronym| {
+                if !value.is_empty() {
+                    value.push(' ');
+                }
+
+                let _ = write!(value, "`{acronym}`");
+
+                value
+            });
+
+    let mut fields = vec![
+        EmbedField {
+            inline: true,
+            name: "Type".to_owned(),
+            value: kind_name(gamemod.kind()).to_owned(),
+        },
+        EmbedField {
+            inline: true,
+            name: "Score multiplier".to_owned(),
+            value: format!("{:.2}x", mod_multiplier(&gamemod)),
+        },
+        EmbedField {
+            inline: true,
+            name: "Mode".to_owned(),
+            value: mode_name(mode).to_owned(),
+        },
+    ];
+
+    let settings = settings(&gamemod);
+
+    if !settings.is_empty() {
+        fields.push(EmbedField {
+            inline: false,
+            name: "Settings".to_owned(),
+            value: settings.join(", "),
+        });
+    }
+
+    if !incompatible.is_empty() {
+        fields.push(EmbedField {
+            inline: false,
+            name: "Incompatible with".to_owned(),
+            value: incompatible,
+        });
+    }
+
+    let footer = if intermode.bits().is_none() {
+        "Only available on lazer"
+    } else {
+        "Available on lazer and stable"
+    };
+
+    let embed = EmbedBuilder::new()
+        .title(format!("{} ({acronym})", mod_name(intermode)))
+        .description(gamemod.description())
+        .fields(fields)
+        .footer(FooterBuilder::new(footer));
+
+    let builder = MessageBuilder::new().embed(embed);
+
+    orig.callback(builder).await
+}
+
+async fn index(orig: CommandOrigin<'_>, args: ModsIndex) -> Result<()> {
+    let mode = match args.mode {
+        Some(mode) => GameMode::from(mode),
+        None => Context::user_config()
+            .mode(orig.user_id()?)
+            .await?
+            .unwrap_or(GameMode::Osu),
+    };
+
+    let acronyms = match mode {
+        GameMode::Osu => OSU_MODS,
+        GameMode::Taiko => TAIKO_MODS,
+        GameMode::Catch => CATCH_MODS,
+        GameMode::Mania => MANIA_MODS,
+    };
+
+    let mut fields: Vec<EmbedField> = KINDS
+        .iter()
+        .map(|&kind| EmbedField {
+            inline: false,
+            name: kind_name(kind).to_owned(),
+            value: String::new(),
+        })
+        .collect();
+
+    for acronym in acronyms {
+        let gamemod = GameMod::new(acronym, mode);
+
+        if is_unknown(&gamemod) {
+            continue;
+        }
+
+        let Some(idx) = KINDS.iter().position(|&kind| kind == gamemod.kind()) else {
+            continue;
+        };
+
+        let value = &mut fields[idx].value;
+
+        if !value.is_empty() {
+            value.push(' ');
+        }
+
+        let _ = write!(value, "`{acronym}`");
+
+        // Mark mods that can't be played on stable
+        if gamemod.intermode().bits().is_none() {
+            value.push('*');
+        }
+    }
+
+    fields.retain(|field| !field.value.is_empty());
+
+    let footer = "*: Only available on lazer • Use /mods info to learn more about a mod";
+
+    let embed = EmbedBuilder::new()
+        .title(format!("Mods for {}", mode_name(mode)))
+        .fields(fields)
+        .footer(FooterBuilder::new(footer));
+
+    let builder = MessageBuilder::new().embed(embed);
+
+    orig.callback(builder).await
+}
+
+fn is_unknown(gamemod: &GameMod) -> bool {
+    matches!(gamemod.intermode(), GameModIntermode::Unknown(_))
+}
+
+/// Turns e.g. `DoubleTime` into `Double Time`.
+fn mod_name(gamemod: GameModIntermode) -> String {
+    let debug = format!("{gamemod:?}");
+    let mut name = String::with_capacity(debug.len() + 2);
+
+    for (i, c) in debug.char_indices() {
+        if i > 0 && c.is_ascii_uppercase() {
+            name.push(' ');
+        }
+
+        name.push(c);
+    }
+
+    name
+}
+
+fn mode_name(mode: GameMode) -> &'static str {
+    match mode {
+        GameMode::Osu => "osu!standard",
+        GameMode::Taiko => "osu!taiko",
+        GameMode::Catch => "osu!ctb",
+        GameMode::Mania => "osu!mania",
+    }
+}
+
+fn kind_name(kind: GameModKind) -> &'static str {
+    match kind {
+        GameModKind::DifficultyReduction => "Difficulty reduction",
+        GameModKind::DifficultyIncrease => "Difficulty increase",
+        GameModKind::Conversion => "Conversion",
+        GameModKind::Automation => "Automation",
+        GameModKind::Fun => "Fun",
+        GameModKind::System => "System",
+    }
+}
+
+/// Settings that can be adjusted in lazer.
+fn settings(gamemod: &GameMod) -> &'static [&'static str] {
+    match gamemod {
+        GameMod::DifficultyAdjustOsu(_) | GameMod::DifficultyAdjustCatch(_) => &[
+            "Circle size",
+            "Approach rate",
+            "Overall difficulty",
+            "Drain rate",
+            "Extended limits",
+        ],
+        GameMod::DifficultyAdjustTaiko(_) => &[
+            "Scroll speed",
+            "Overall difficulty",
+            "Drain rate",
+            "Extended limits",
+        ],
+        GameMod::DifficultyAdjustMania(_) => {
+            &["Overall difficulty", "Drain rate", "Extended limits"]
+        }
+        _ => match gamemod.intermode() {
+            GameModIntermode::DoubleTime | GameModIntermode::HalfTime => {
+                &["Speed change", "Adjust pitch"]
+            }
+            GameModIntermode::Nightcore | GameModIntermode::Daycore => &["Speed change"],
+            GameModIntermode::WindUp | GameModIntermode::WindDown => {
+                &["Initial rate", "Final rate", "Adjust pitch"]
+            }
+            GameModIntermode::AdaptiveSpeed => &["Initial rate", "Adjust pitch"],
+            GameModIntermode::Flashlight => {
+                &["Follow delay", "Size multiplier", "Combo based size"]
+            }
+            GameModIntermode::Easy => &["Extra lives"],
+            GameModIntermode::SuddenDeath | GameModIntermode::Perfect => &["Restart on fail"],
+            GameModIntermode::Random => &["Seed"],
+            _ => &[],
+        },
+    }
+}
+
+const MODES: [GameMode; 4] = [
+    GameMode::Osu,
+    GameMode::Taiko,
+    GameMode::Catch,
+    GameMode::Mania,
+];
+
+const KINDS: [GameModKind; 6] = [
+    GameModKind::DifficultyReduction,
+    GameModKind::DifficultyIncrease,
+    GameModKind::Conversion,
+    GameModKind::Automation,
+    GameModKind::Fun,
+    GameModKind::System,
+];
+
+// Acronyms that are unknown to the current mods version are skipped
+const OSU_MODS: &[&str] = &[
+    "EZ", "NF", "HT", "DC", "HR", "SD", "PF", "DT", "NC", "HD", "TC", "FL", "BL", "ST", "AC", "TP",
+    "DA", "CL", "RD", "MR", "AL", "SG", "AT", "CN", "RX", "AP", "SO", "TR", "WG", "SI", "GR", "DF",
+    "WU", "WD", "BR", "AD", "MU", "NS", "MG", "RP", "AS", "FR", "BU", "SY", "DP", "BM", "TD",
+    "SV2",
+];
+
+const TAIKO_MODS: &[&str] = &[
+    "EZ", "NF", "HT", "DC", "HR", "SD", "PF", "DT", "NC", "HD", "FL", "AC", "DA", "CL", "RD", "SW",
+    "SG", "CS", "AT", "CN", "RX", "WU", "WD", "MU", "AS", "SV2",
+];
+
+const CATCH_MODS: &[&str] = &[
+    "EZ", "NF", "HT", "DC", "HR", "SD", "PF", "DT", "NC", "HD", "FL", "AC", "DA", "CL", "MR", "AT",
+    "CN", "RX", "WU", "WD", "FF", "MU", "NS", "SV2",
+];
+
+const MANIA_MODS: &[&str] = &[
+    "EZ", "NF", "HT", "DC", "NR", "HR", "SD", "PF", "DT", "NC", "FI", "HD", "CO", "FL", "AC", "RD",
+    "DS", "MR", "DA", "CL", "IN", "CS", "HO", "1K", "2K", "3K", "4K", "5K", "6K", "7K", "8K", "9K",
+    "10K", "AT", "CN", "WU", "WD", "MU", "AS", "SV2",
+];
@@ -22,6 +22,7 @@ use crate::{
 
 #[derive(CommandModel, CreateCommand, Default, HasName, SlashCommand)]
 #[command(name = "mostplayed", desc = "Display the most played maps of a user")]
+#[flags(BANCHO_ONLY)]
 pub struct MostPlayed<'a> {
     #[command(desc = "Specify a username")]
     name: Option<Cow<'a, str>>,
@@ -40,6 +41,7 @@ async fn slash_mostplayed(mut command: InteractionCommand) -> Result<()> {
 #[usage("[username]")]
 #[example("badewanne3")]
 #[alias("mp")]
+#[flags(BANCHO_ONLY)]
 #[group(AllModes)]
 async fn prefix_mostplayed(msg: &Message, mut args: Args<'_>) -> Result<()> {
     let args = match args.next() {
@@ -93,9 +95,8 @@ async fn mostplayed(orig: CommandOrigin<'_>, args: MostPlayed<'_>) -> Result<()>
         }
     };
 
-    let maps_fut = Context::osu()
-        .user_most_played(user.user_id.to_native())
-        .limit(100);
+    let user_id = user.user_id.to_native();
+    let maps_fut = Context::osu_request(|osu| osu.user_most_played(user_id).limit(100));
 
     let maps = match maps_fut.await {
         Ok(maps) => maps,
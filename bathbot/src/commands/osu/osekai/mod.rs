@@ -23,6 +23,7 @@ mod user_value;
     help = "Various leaderboard stats. \
     All data is provided by [osekai](https://osekai.net/)."
 )]
+#[flags(BANCHO_ONLY)]
 pub enum Osekai {
     #[command(name = "badges")]
     Badges(OsekaiBadges),
@@ -0,0 +1,329 @@
+use std::{borrow::Cow, f64::consts::FRAC_PI_2, fmt::Write};
+
+use bathbot_macros::{HasName, SlashCommand, command};
+use bathbot_util::{
+    AuthorBuilder, EmbedBuilder, MessageBuilder, attachment,
+    constants::{GENERAL_ISSUE, OSU_BASE},
+    fields, matcher,
+    numbers::WithComma,
+};
+use eyre::{Report, Result, WrapErr};
+use futures::{StreamExt, TryStreamExt, stream};
+use plotters::{
+    prelude::{ChartBuilder, PathElement, Polygon, Text},
+    style::{
+        Color, IntoFont, TextStyle,
+        text_anchor::{HPos, Pos, VPos},
+    },
+};
+use plotters_backend::FontStyle;
+use rosu_v2::{
+    prelude::{GameMode, OsuError},
+    request::UserId,
+};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use super::{GraphArea, GraphPalette, GraphRenderer, require_link, user_not_found};
+use crate::{
+    Context,
+    commands::{DISCORD_OPTION_DESC, DISCORD_OPTION_HELP},
+    core::commands::CommandOrigin,
+    manager::redis::osu::{CachedUser, UserArgs, UserArgsError},
+    util::{Emote, InteractionCommandExt, interaction::InteractionCommand},
+};
+
+const MODES: [GameMode; 4] = [
+    GameMode::Osu,
+    GameMode::Taiko,
+    GameMode::Catch,
+    GameMode::Mania,
+];
+
+const W: u32 = 700;
+const H: u32 = 600;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(name = "osu", desc = "Display osu! statistics of a user")]
+pub enum Osu<'a> {
+    #[command(name = "all")]
+    All(OsuAll<'a>),
+}
+
+#[derive(CommandModel, CreateCommand, Default, HasName)]
+#[command(
+    name = "all",
+    desc = "Display statistics of a user in all modes",
+    help = "Display the rank, pp, accuracy, and playcount of a user in all modes side by side.\n\
+    The attached chart shows the global rank of each mode on a logarithmic scale."
+)]
+pub struct OsuAll<'a> {
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
+    discord: Option<Id<UserMarker>>,
+}
+
+async fn slash_osu(mut command: InteractionCommand) -> Result<()> {
+    match Osu::from_interaction(command.input_data())? {
+        Osu::All(args) => osu_all((&mut command).into(), args).await,
+    }
+}
+
+#[command]
+#[desc("Display statistics of a user in all modes")]
+#[help(
+    "Display the rank, pp, accuracy, and playcount of a user in all modes side by side.\n\
+    The attached chart shows the global rank of each mode on a logarithmic scale."
+)]
+#[usage("[username]")]
+#[examples("badewanne3")]
+#[aliases("profileall", "allmodes")]
+#[group(AllModes)]
+async fn prefix_osuall(msg: &Message, mut args: Args<'_>) -> Result<()> {
+    let args = match args.next() {
+        Some(arg) => match matcher::get_mention_user(arg) {
+            Some(id) => OsuAll {
+                name: None,
+                discord: Some(id),
+            },
+            None => OsuAll {
+                name: Some(Cow::Borrowed(arg)),
+                discord: None,
+            },
+        },
+        None => OsuAll::default(),
+    };
+
+    osu_all(msg.into(), args).await
+}
+
+async fn osu_all(orig: CommandOrigin<'_>, args: OsuAll<'_>) -> Result<()> {
+    /// Max amount of simultaneous requests
+    const CONCURRENCY: usize = 2;
+
+    let owner = orig.user_id()?;
+
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match Context::user_config().osu_id(owner).await {
+            Ok(Some(user_id)) => UserId::Id(user_id),
+            Ok(None) => return require_link(&orig).await,
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        },
+    };
+
+    let users_res: Result<Vec<_>, _> = stream::iter(MODES)
+        .map(|mode| {
+            let user_id = &user_id;
+
+            async move {
+                let user_args = UserArgs::rosu_id(user_id, mode).await;
+
+                Context::redis().osu_user(user_args).await
+            }
+        })
+        .buffered(CONCURRENCY)
+        .try_collect()
+        .await;
+
+    let users = match users_res {
+        Ok(users) => users,
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user");
+
+            return Err(err);
+        }
+    };
+
+    let ranks: Vec<_> = users.iter().map(global_rank).collect();
+
+    let radar = RankRadar {
+        username: users[0].username.as_str(),
+        ranks: &ranks,
+    };
+
+    let graph = match radar.render(W, H, GraphPalette::default()) {
+        Ok(graph) => Some(graph),
+        Err(err) => {
+            warn!(?err, "Failed to draw rank radar");
+
+            None
+        }
+    };
+
+    let mut builder = MessageBuilder::new();
+    let mut embed = all_modes_embed(&users);
+
+    if let Some(graph) = graph {
+        embed = embed.image(attachment("all_modes.png"));
+        builder = builder.attachment("all_modes.png", graph);
+    }
+
+    orig.create_message(builder.embed(embed)).await?;
+
+    Ok(())
+}
+
+fn all_modes_embed(users: &[CachedUser]) -> EmbedBuilder {
+    let user = &users[0];
+    let user_id = user.user_id.to_native();
+
+    let author = AuthorBuilder::new(user.username.as_str()).url(format!("{OSU_BASE}u/{user_id}"));
+    let mut fields = Vec::with_capacity(MODES.len());
+
+    for (user, mode) in users.iter().zip(MODES) {
+        let mut value = String::with_capacity(128);
+
+        match user.statistics.as_ref() {
+            Some(stats) => {
+                match global_rank(user) {
+                    Some(rank) => {
+                        let _ = write!(
+                            value,
+                            "#{rank} (#{country_rank} {country})",
+                            rank = WithComma::new(rank),
+                            country_rank = WithComma::new(stats.country_rank.to_native()),
+                            country = user.country_code.as_str(),
+                        );
+                    }
+                    None => value.push_str("Unranked"),
+                }
+
+                let _ = write!(
+                    value,
+                    "\n`{pp}pp`\n`{acc:.2}%`\n{playcount} plays",
+                    pp = WithComma::new(stats.pp.to_native()),
+                    acc = stats.accuracy.to_native(),
+                    playcount = WithComma::new(stats.playcount.to_native()),
+                );
+            }
+            None => value.push_str("No statistics"),
+        }
+
+        fields![fields { format!("{} {mode:?}", Emote::from(mode)), value, true }];
+    }
+
+    EmbedBuilder::new()
+        .author(author)
+        .fields(fields)
+        .thumbnail(user.avatar_url.as_ref())
+}
+
+fn global_rank(user: &CachedUser) -> Option<u32> {
+    user.statistics
+        .as_ref()
+        .map(|stats| stats.global_rank.to_native())
+        .filter(|rank| *rank > 0)
+}
+
+/// Global ranks of all modes on the axes of a radar chart.
+struct RankRadar<'a> {
+    username: &'a str,
+    /// Global ranks in the same order as [`MODES`]
+    ranks: &'a [Option<u32>],
+}
+
+impl RankRadar<'_> {
+    /// The log10 of a rank that is considered as the outer edge of the chart
+    const WORST_RANK_LOG: f64 = 7.0;
+
+    /// Map a rank onto `0.0..=1.0` where `1.0` is the best rank.
+    fn normalize(rank: Option<u32>) -> f64 {
+        rank.map_or(0.0, |rank| {
+            (1.0 - f64::from(rank).log10() / Self::WORST_RANK_LOG).clamp(0.0, 1.0)
+        })
+    }
+
+    /// Position of the value on the axis of the mode at the given index.
+    fn point(idx: usize, value: f64) -> (f64, f64) {
+        let angle = FRAC_PI_2 - idx as f64 * FRAC_PI_2;
+
+        (value * angle.cos(), value * angle.sin())
+    }
+}
+
+impl GraphRenderer for RankRadar<'_> {
+    fn draw(&self, root: &GraphArea<'_>, palette: GraphPalette) -> Result<()> {
+        let caption = format!("Global ranks of {}", self.username);
+
+        let mut chart = ChartBuilder::on(root)
+            .margin(20_i32)
+            .caption(
+                caption,
+                ("sans-serif", 25_i32, FontStyle::Bold, &palette.text),
+            )
+            .build_cartesian_2d(-1.5_f64..1.5_f64, -1.3_f64..1.3_f64)
+            .wrap_err("Failed to build chart")?;
+
+        let ring_style = palette.mesh.mix(0.3);
+
+        let rings = [0.25, 0.5, 0.75, 1.0].map(|value| {
+            let mut points: Vec<_> = (0..MODES.len()).map(|i| Self::point(i, value)).collect();
+            points.push(points[0]);
+
+            PathElement::new(points, ring_style)
+        });
+
+        chart.draw_series(rings).wrap_err("Failed to draw rings")?;
+
+        let spokes = (0..MODES.len())
+            .map(|i| PathElement::new([(0.0, 0.0), Self::point(i, 1.0)], ring_style));
+
+        chart
+            .draw_series(spokes)
+            .wrap_err("Failed to draw spokes")?;
+
+        let points: Vec<_> = self
+            .ranks
+            .iter()
+            .enumerate()
+            .map(|(i, rank)| Self::point(i, Self::normalize(*rank)))
+            .collect();
+
+        let area = Polygon::new(points.clone(), palette.area.mix(0.5).filled());
+        chart.draw_series([area]).wrap_err("Failed to draw area")?;
+
+        let mut border = points;
+        border.push(border[0]);
+        let border = PathElement::new(border, palette.border.stroke_width(2));
+        chart
+            .draw_series([border])
+            .wrap_err("Failed to draw border")?;
+
+        let label_style = TextStyle::from(("sans-serif", 20_i32).into_font())
+            .color(&palette.text)
+            .pos(Pos::new(HPos::Center, VPos::Center));
+
+        let labels = MODES
+            .iter()
+            .zip(self.ranks)
+            .enumerate()
+            .map(|(i, (mode, rank))| {
+                let rank = match rank {
+                    Some(rank) => format!("#{}", WithComma::new(*rank)),
+                    None => "Unranked".to_owned(),
+                };
+
+                let text = format!("{mode:?}: {rank}");
+
+                Text::new(text, Self::point(i, 1.15), label_style.clone())
+            });
+
+        chart
+            .draw_series(labels)
+            .wrap_err("Failed to draw labels")?;
+
+        Ok(())
+    }
+}
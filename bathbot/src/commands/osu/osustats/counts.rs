@@ -27,6 +27,7 @@ use crate::{
     name = "osc",
     desc = "Count how often a user appears on top of map leaderboards"
 )]
+#[flags(BANCHO_ONLY)]
 pub struct Osc<'a> {
     #[command(desc = "Specify a gamemode")]
     mode: Option<GameModeOption>,
@@ -80,6 +81,7 @@ impl<'m> OsuStatsCount<'m> {
 #[usage("[username]")]
 #[example("badewanne3")]
 #[aliases("osc", "osustatscounts")]
+#[flags(BANCHO_ONLY)]
 #[group(Osu)]
 async fn prefix_osustatscount(msg: &Message, args: Args<'_>) -> Result<()> {
     let args = OsuStatsCount::args(None, args);
@@ -98,6 +100,7 @@ async fn prefix_osustatscount(msg: &Message, args: Args<'_>) -> Result<()> {
 #[usage("[username]")]
 #[example("badewanne3")]
 #[aliases("oscm", "osustatscountsmania")]
+#[flags(BANCHO_ONLY)]
 #[group(Mania)]
 async fn prefix_osustatscountmania(msg: &Message, args: Args<'_>) -> Result<()> {
     let args = OsuStatsCount::args(Some(GameModeOption::Mania), args);
@@ -116,6 +119,7 @@ async fn prefix_osustatscountmania(msg: &Message, args: Args<'_>) -> Result<()>
 #[usage("[username]")]
 #[example("badewanne3")]
 #[aliases("osct", "osustatscountstaiko")]
+#[flags(BANCHO_ONLY)]
 #[group(Taiko)]
 async fn prefix_osustatscounttaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     let args = OsuStatsCount::args(Some(GameModeOption::Taiko), args);
@@ -134,6 +138,7 @@ async fn prefix_osustatscounttaiko(msg: &Message, args: Args<'_>) -> Result<()>
 #[usage("[username]")]
 #[example("badewanne3")]
 #[aliases("oscc", "osustatscountsctb", "osustatscountcatch")]
+#[flags(BANCHO_ONLY)]
 #[group(Catch)]
 async fn prefix_osustatscountctb(msg: &Message, args: Args<'_>) -> Result<()> {
     let args = OsuStatsCount::args(Some(GameModeOption::Catch), args);
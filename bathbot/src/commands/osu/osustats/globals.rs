@@ -24,7 +24,6 @@ use crate::{
         OsuMap,
         redis::osu::{UserArgs, UserArgsError},
     },
-    util::ChannelExt,
 };
 
 const OSG_USAGE: &str = "[username] [mods] [acc=[number..]number] \
@@ -50,15 +49,12 @@ const OSG_USAGE: &str = "[username] [mods] [acc=[number..]number] \
     "vaxei sort=rank rank=1..5 +hdhr"
 )]
 #[aliases("osg", "osustatsglobal")]
+#[flags(BANCHO_ONLY)]
 #[group(Osu)]
 async fn prefix_osustatsglobals(msg: &Message, args: Args<'_>) -> Result<()> {
     match OsuStatsScores::args(None, args) {
         Ok(args) => scores(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -81,15 +77,12 @@ async fn prefix_osustatsglobals(msg: &Message, args: Args<'_>) -> Result<()> {
     "vaxei sort=rank rank=1..5 +hdhr"
 )]
 #[aliases("osgm", "osustatsglobalmania")]
+#[flags(BANCHO_ONLY)]
 #[group(Mania)]
 async fn prefix_osustatsglobalsmania(msg: &Message, args: Args<'_>) -> Result<()> {
     match OsuStatsScores::args(Some(GameModeOption::Mania), args) {
         Ok(args) => scores(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -112,15 +105,12 @@ async fn prefix_osustatsglobalsmania(msg: &Message, args: Args<'_>) -> Result<()
     "vaxei sort=rank rank=1..5 +hdhr"
 )]
 #[aliases("osgt", "osustatsglobaltaiko")]
+#[flags(BANCHO_ONLY)]
 #[group(Taiko)]
 async fn prefix_osustatsglobalstaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     match OsuStatsScores::args(Some(GameModeOption::Taiko), args) {
         Ok(args) => scores(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -143,15 +133,12 @@ async fn prefix_osustatsglobalstaiko(msg: &Message, args: Args<'_>) -> Result<()
     "vaxei sort=rank rank=1..5 +hdhr"
 )]
 #[aliases("osgc", "osustatsglobalctb", "osustatsglobalscatch")]
+#[flags(BANCHO_ONLY)]
 #[group(Catch)]
 async fn prefix_osustatsglobalsctb(msg: &Message, args: Args<'_>) -> Result<()> {
     match OsuStatsScores::args(Some(GameModeOption::Catch), args) {
         Ok(args) => scores(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
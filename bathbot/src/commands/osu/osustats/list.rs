@@ -16,7 +16,6 @@ use crate::{
     Context,
     active::{ActiveMessages, impls::OsuStatsPlayersPagination},
     core::commands::{CommandOrigin, prefix::Args},
-    util::ChannelExt,
 };
 
 impl<'a> From<OsuStatsPlayers<'a>> for OsuStatsPlayersArgs {
@@ -242,15 +241,12 @@ fn insert(
 #[usage("[rank=[num..]num] [country acronym]")]
 #[examples("rankr=42 be", "rank=1..5", "fr")]
 #[aliases("osl")]
+#[flags(BANCHO_ONLY)]
 #[group(Osu)]
 async fn prefix_osustatslist(msg: &Message, args: Args<'_>) -> Result<()> {
     match OsuStatsPlayers::args(None, args) {
         Ok(args) => players(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -269,15 +265,12 @@ async fn prefix_osustatslist(msg: &Message, args: Args<'_>) -> Result<()> {
 #[usage("[rank=[num..]num] [country acronym]")]
 #[examples("rankr=42 be", "rank=1..5", "fr")]
 #[aliases("oslm")]
+#[flags(BANCHO_ONLY)]
 #[group(Mania)]
 async fn prefix_osustatslistmania(msg: &Message, args: Args<'_>) -> Result<()> {
     match OsuStatsPlayers::args(Some(GameModeOption::Mania), args) {
         Ok(args) => players(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -296,15 +289,12 @@ async fn prefix_osustatslistmania(msg: &Message, args: Args<'_>) -> Result<()> {
 #[usage("[rank=[num..]num] [country acronym]")]
 #[examples("rankr=42 be", "rank=1..5", "fr")]
 #[aliases("oslt")]
+#[flags(BANCHO_ONLY)]
 #[group(Taiko)]
 async fn prefix_osustatslisttaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     match OsuStatsPlayers::args(Some(GameModeOption::Taiko), args) {
         Ok(args) => players(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -323,15 +313,12 @@ async fn prefix_osustatslisttaiko(msg: &Message, args: Args<'_>) -> Result<()> {
 #[usage("[rank=[num..]num] [country acronym]")]
 #[examples("rankr=42 be", "rank=1..5", "fr")]
 #[aliases("oslc", "osustatslistcatch")]
+#[flags(BANCHO_ONLY)]
 #[group(Catch)]
 async fn prefix_osustatslistctb(msg: &Message, args: Args<'_>) -> Result<()> {
     match OsuStatsPlayers::args(Some(GameModeOption::Catch), args) {
         Ok(args) => players(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -26,6 +26,7 @@ mod list;
     All data is provided by [osustats](https://osustats.ppy.sh/).\n\
     Note that the data usually __updates once per day__."
 )]
+#[flags(BANCHO_ONLY)]
 pub enum OsuStats<'a> {
     #[command(name = "count")]
     Count(OsuStatsCount<'a>),
@@ -13,7 +13,7 @@ use bathbot_util::{
     MessageOrigin,
     constants::{GENERAL_ISSUE, OSU_API_ISSUE},
     matcher,
-    osu::ModSelection,
+    osu::{ModSelection, effective_clock_rate},
     query::{IFilterCriteria, Searchable, TopCriteria},
 };
 use eyre::{Report, Result};
@@ -28,7 +28,11 @@ use twilight_model::{
     id::{Id, marker::UserMarker},
 };
 
-use super::{HasMods, ModsResult, ScoreOrder, map_strains_graph, require_link, user_not_found};
+use super::{
+    HasMods, ModsResult, ScoreOrder, map_strains_graph, require_link,
+    score_source::{PinnedScores, ScoreSource, TopScores},
+    user_not_found,
+};
 use crate::{
     Context,
     active::{
@@ -272,21 +276,18 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned<'_>) -> Result<()> {
     let legacy_scores = score_data.is_legacy();
     let missing_user = user_opt.is_none();
 
-    let scores_manager = Context::osu_scores();
+    let source = PinnedScores { legacy_scores };
     let redis = Context::redis();
-    let pinned_fut = scores_manager
-        .clone()
-        .pinned(legacy_scores)
-        .limit(100)
-        .exec(user_args);
+    let pinned_fut = source.fetch(user_args);
 
     let top100_fut = async {
         if matches!(list_size, ListSize::Single) || args.index.is_some() {
-            scores_manager
-                .top(100, legacy_scores)
-                .exec(user_args)
-                .await
-                .map(Some)
+            let top100 = TopScores {
+                limit: 100,
+                legacy_scores,
+            };
+
+            top100.fetch(user_args).await.map(Some)
         } else {
             Ok(None)
         }
@@ -306,14 +307,20 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned<'_>) -> Result<()> {
         (Ok(pinned), Ok(top100), Ok(user)) => {
             (pinned, top100, user.or(user_opt).expect("missing user"))
         }
-        (Err(OsuError::NotFound), ..)
-        | (_, Err(OsuError::NotFound), _)
+        (Err(UserArgsError::Osu(OsuError::NotFound)), ..)
+        | (_, Err(UserArgsError::Osu(OsuError::NotFound)), _)
         | (.., Err(UserArgsError::Osu(OsuError::NotFound))) => {
             let content = user_not_found(user_id).await;
 
             return orig.error(content).await;
         }
-        (Err(err), ..) | (_, Err(err), _) | (.., Err(UserArgsError::Osu(err))) => {
+        (Err(err), ..) | (_, Err(err), _) => {
+            let _ = orig.error(OSU_API_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user or prepare scores");
+
+            return Err(err);
+        }
+        (.., Err(UserArgsError::Osu(err))) => {
             let _ = orig.error(OSU_API_ISSUE).await;
             let err = Report::new(err).wrap_err("Failed to get user or prepare scores");
 
@@ -370,7 +377,7 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned<'_>) -> Result<()> {
         Some("random" | "?") => (post_len > 0).then(|| thread_rng().gen_range(1..=post_len)),
         Some(n) => match n.parse::<usize>() {
             Ok(n) if n > post_len => {
-                let mut content = format!("`{username}` only has {post_len} pinned scores");
+                let mut content = format!("`{username}` only has {post_len} {}", source.label());
 
                 if pre_len > post_len {
                     let _ = write!(content, " with the specified properties");
@@ -380,8 +387,11 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned<'_>) -> Result<()> {
             }
             Ok(n) => Some(n),
             Err(_) => {
-                let content = "Failed to parse index. \
-                Must be an integer between 1 and 200 or `random` / `?`.";
+                let content = format!(
+                    "Failed to parse index. \
+                    Must be an integer between 1 and {} or `random` / `?`.",
+                    source.capabilities().max_scores
+                );
 
                 return orig.error(content).await;
             }
@@ -409,7 +419,7 @@ async fn pinned(orig: CommandOrigin<'_>, args: Pinned<'_>) -> Result<()> {
                     let fut = map_strains_graph(
                         &entry.map.pp_map,
                         entry.score.mods.clone(),
-                        entry.map.cover(),
+                        Some(entry.map.cover()),
                         SingleScorePagination::IMAGE_W,
                         SingleScorePagination::IMAGE_H,
                     );
@@ -546,9 +556,9 @@ async fn process_scores(
         Some(ScoreOrder::Length) => {
             entries.sort_by(|a, b| {
                 let a_len = a.get_half().map.seconds_drain() as f64
-                    / a.get_half().score.mods.clock_rate().unwrap_or(1.0);
+                    / effective_clock_rate(&a.get_half().score.mods);
                 let b_len = b.get_half().map.seconds_drain() as f64
-                    / b.get_half().score.mods.clock_rate().unwrap_or(1.0);
+                    / effective_clock_rate(&b.get_half().score.mods);
 
                 b_len.partial_cmp(&a_len).unwrap_or(Ordering::Equal)
             });
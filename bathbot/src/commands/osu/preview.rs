@@ -0,0 +1,278 @@
+use std::borrow::Cow;
+
+use bathbot_client::ClientError;
+use bathbot_macros::SlashCommand;
+use bathbot_util::{
+    MessageBuilder,
+    constants::{GENERAL_ISSUE, OSU_BASE},
+    osu::MapIdType,
+};
+use eyre::{Report, Result};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use super::graphs::get_map_id;
+use crate::{
+    core::{Context, commands::CommandOrigin},
+    manager::MapError,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+/// Upper limit for the attachment so it fits into any channel
+const MAX_SIZE: usize = 8 * 1024 * 1024;
+
+/// Length of trimmed clips if none was specified
+const DEFAULT_LENGTH: f64 = 5.0;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "preview",
+    desc = "Listen to the audio preview of a map",
+    help = "Attach the audio preview of a mapset, the same snippet that plays on the website.\n\
+    Previews are usually around 10 seconds long. To only keep a part of it, specify `at` \
+    and the clip will be trimmed to `length` seconds around that point of the preview."
+)]
+pub struct Preview<'a> {
+    #[command(
+        desc = "Specify a map url or map id",
+        help = "Specify a map either by map url or map id.\n\
+        If none is specified, it will search in the recent channel history \
+        and pick the first map it can find."
+    )]
+    map: Option<Cow<'a, str>>,
+    #[command(
+        min_value = 0.0,
+        max_value = 60.0,
+        desc = "Trim the preview around this many seconds into it"
+    )]
+    at: Option<f64>,
+    #[command(
+        min_value = 1.0,
+        max_value = 10.0,
+        desc = "Length of the trimmed clip in seconds, defaults to 5"
+    )]
+    length: Option<f64>,
+}
+
+async fn slash_preview(mut command: InteractionCommand) -> Result<()> {
+    let args = Preview::from_interaction(command.input_data())?;
+
+    preview((&mut command).into(), args).await
+}
+
+async fn preview(orig: CommandOrigin<'_>, args: Preview<'_>) -> Result<()> {
+    let map_id = match get_map_id(args.map.as_deref(), orig.channel_id()).await {
+        Ok(map_id) => map_id,
+        Err(content) => return orig.error(content).await,
+    };
+
+    let mapset_id = match map_id {
+        MapIdType::Set(mapset_id) => mapset_id,
+        MapIdType::Map(map_id) => match Context::osu_map().map(map_id, None).await {
+            Ok(map) => map.mapset_id(),
+            Err(MapError::NotFound) => {
+                let content = format!("Could not find beatmap with id `{map_id}`");
+
+                return orig.error(content).await;
+            }
+            Err(MapError::Report(err)) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        },
+    };
+
+    let bytes = match Context::client().get_map_preview(mapset_id).await {
+        Ok(bytes) => bytes,
+        Err(ClientError::NotFound) => {
+            let content = format!("Mapset `{mapset_id}` has no audio preview");
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(Report::new(err).wrap_err("Failed to get map preview"));
+        }
+    };
+
+    let audio = match args.at {
+        Some(at) => {
+            let length = args.length.unwrap_or(DEFAULT_LENGTH);
+            let start = (at - length / 2.0).max(0.0);
+
+            match trim_mp3(&bytes, start, start + length) {
+                Some(trimmed) => trimmed,
+                None => {
+                    let content = format!(
+                        "Failed to trim the preview at {at}s, be sure the point lies within \
+                        the preview"
+                    );
+
+                    return orig.error(content).await;
+                }
+            }
+        }
+        None => bytes.to_vec(),
+    };
+
+    if audio.len() > MAX_SIZE {
+        return orig.error("The preview is too large to attach").await;
+    }
+
+    let builder = MessageBuilder::new()
+        .content(format!("Preview of <{OSU_BASE}s/{mapset_id}>"))
+        .attachment(format!("preview_{mapset_id}.mp3"), audio);
+
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+/// Keep only the MPEG audio frames that start between `start` and `end`
+/// seconds.
+///
+/// The leading ID3 tag and Xing/Info frame are dropped since they describe
+/// the untrimmed file. Returns `None` if no frames are within the range.
+fn trim_mp3(bytes: &[u8], start: f64, end: f64) -> Option<Vec<u8>> {
+    let mut pos = id3_len(bytes);
+    let mut time = 0.0;
+    let mut trimmed = Vec::new();
+    let mut first = true;
+
+    while let Some(frame) = bytes.get(pos..).and_then(FrameHeader::parse) {
+        let frame_bytes = bytes.get(pos..pos + frame.len)?;
+        pos += frame.len;
+
+        // The optional Xing/Info frame carries metadata, not audio
+        if first && (contains(frame_bytes, b"Xing") || contains(frame_bytes, b"Info")) {
+            first = false;
+
+            continue;
+        }
+
+        first = false;
+
+        if time >= start && time < end {
+            trimmed.extend_from_slice(frame_bytes);
+        }
+
+        time += frame.duration;
+
+        if time >= end {
+            break;
+        }
+    }
+
+    (!trimmed.is_empty()).then_some(trimmed)
+}
+
+/// Amount of bytes of a leading ID3v2 tag
+fn id3_len(bytes: &[u8]) -> usize {
+    match bytes {
+        [b'I', b'D', b'3', _, _, flags, s0, s1, s2, s3, ..] => {
+            let size = [s0, s1, s2, s3]
+                .into_iter()
+                .fold(0, |size, byte| (size << 7) | (*byte as usize & 0x7F));
+
+            // An additional footer is indicated by the fourth bit
+            let footer = if flags & 0x10 != 0 { 10 } else { 0 };
+
+            10 + size + footer
+        }
+        _ => 0,
+    }
+}
+
+fn contains(haystack: &[u8], needle: &[u8]) -> bool {
+    haystack
+        .windows(needle.len())
+        .any(|window| window == needle)
+}
+
+/// Header of an MPEG layer III frame
+struct FrameHeader {
+    /// Length of the frame in bytes, including the header
+    len: usize,
+    /// Duration of the frame in seconds
+    duration: f64,
+}
+
+impl FrameHeader {
+    const BITRATES_V1: [u32; 15] = [
+        0, 32, 40, 48, 56, 64, 80, 96, 112, 128, 160, 192, 224, 256, 320,
+    ];
+    const BITRATES_V2: [u32; 15] = [0, 8, 16, 24, 32, 40, 48, 56, 64, 80, 96, 112, 128, 144, 160];
+    const SAMPLE_RATES: [u32; 3] = [44_100, 48_000, 32_000];
+
+    fn parse(bytes: &[u8]) -> Option<Self> {
+        let [b0, b1, b2, ..] = *bytes else {
+            return None;
+        };
+
+        // Frame sync and layer III
+        if b0 != 0xFF || b1 & 0xE0 != 0xE0 || (b1 >> 1) & 0b11 != 0b01 {
+            return None;
+        }
+
+        // 0 = MPEG 2.5, 2 = MPEG 2, 3 = MPEG 1
+        let version = (b1 >> 3) & 0b11;
+        let bitrate_idx = (b2 >> 4) as usize;
+        let sample_rate_idx = ((b2 >> 2) & 0b11) as usize;
+        let padding = ((b2 >> 1) & 1) as usize;
+
+        let (bitrates, divisor, samples) = match version {
+            3 => (&Self::BITRATES_V1, 1, 1152),
+            2 => (&Self::BITRATES_V2, 2, 576),
+            0 => (&Self::BITRATES_V2, 4, 576),
+            _ => return None,
+        };
+
+        let bitrate = *bitrates.get(bitrate_idx).filter(|&&bitrate| bitrate > 0)? * 1000;
+        let sample_rate = *Self::SAMPLE_RATES.get(sample_rate_idx)? / divisor;
+
+        let len = (samples / 8 * bitrate / sample_rate) as usize + padding;
+
+        Some(Self {
+            len,
+            duration: f64::from(samples) / f64::from(sample_rate),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// MPEG 1 layer III frame of 128kbps at 44.1kHz without padding
+    fn frame(fill: u8) -> Vec<u8> {
+        let mut frame = vec![fill; 417];
+        frame[..4].copy_from_slice(&[0xFF, 0xFB, 0x90, 0x00]);
+
+        frame
+    }
+
+    #[test]
+    fn frame_header() {
+        let header = FrameHeader::parse(&frame(0)).unwrap();
+
+        assert_eq!(header.len, 417);
+        assert!((header.duration - 0.026122).abs() < 1e-6);
+    }
+
+    #[test]
+    fn trim_frames() {
+        let mut mp3 = b"ID3\x04\x00\x00\x00\x00\x00\x02ab".to_vec();
+
+        for i in 0..100 {
+            mp3.extend(frame(i));
+        }
+
+        // Frames 39 up to and including 76 start within 1s to 2s
+        let trimmed = trim_mp3(&mp3, 1.0, 2.0).unwrap();
+        assert_eq!(trimmed.len(), 38 * 417);
+        assert_eq!(trimmed[4], 39);
+
+        assert!(trim_mp3(&mp3, 10.0, 12.0).is_none());
+    }
+}
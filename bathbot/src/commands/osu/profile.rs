@@ -263,6 +263,7 @@ async fn profile(orig: CommandOrigin<'_>, args: Profile<'_>) -> Result<()> {
         tz,
         peaks,
         legacy_scores,
+        config.profile_badges.unwrap_or(false),
         kind,
         origin,
         owner,
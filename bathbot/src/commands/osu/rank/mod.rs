@@ -17,6 +17,7 @@ mod score;
 
 #[derive(CommandModel, CreateCommand, SlashCommand)]
 #[command(name = "rank", desc = "How much is missing to reach the given rank?")]
+#[flags(BANCHO_ONLY)]
 pub enum Rank<'a> {
     #[command(name = "pp")]
     Pp(RankPp<'a>),
@@ -268,6 +268,7 @@ pub(super) async fn pp(orig: CommandOrigin<'_>, args: RankPp<'_>) -> Result<()>
 #[usage("[username] [[country]number/username]")]
 #[examples("badewanne3 be50", "badewanne3 123")]
 #[alias("reach")]
+#[flags(BANCHO_ONLY)]
 #[group(Osu)]
 async fn prefix_rank(msg: &Message, args: Args<'_>) -> Result<()> {
     match RankPp::args(None, args) {
@@ -291,6 +292,7 @@ async fn prefix_rank(msg: &Message, args: Args<'_>) -> Result<()> {
 #[usage("[username] [[country]number/username]")]
 #[examples("badewanne3 be50", "badewanne3 123")]
 #[alias("rankm", "reachmania", "reachm")]
+#[flags(BANCHO_ONLY)]
 #[group(Mania)]
 async fn prefix_rankmania(msg: &Message, args: Args<'_>) -> Result<()> {
     match RankPp::args(Some(GameModeOption::Mania), args) {
@@ -314,6 +316,7 @@ async fn prefix_rankmania(msg: &Message, args: Args<'_>) -> Result<()> {
 #[usage("[username] [[country]number/username]")]
 #[examples("badewanne3 be50", "badewanne3 123")]
 #[alias("rankt", "reachtaiko", "reacht")]
+#[flags(BANCHO_ONLY)]
 #[group(Taiko)]
 async fn prefix_ranktaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     match RankPp::args(Some(GameModeOption::Taiko), args) {
@@ -337,6 +340,7 @@ async fn prefix_ranktaiko(msg: &Message, args: Args<'_>) -> Result<()> {
 #[usage("[username] [[country]number/username]")]
 #[examples("badewanne3 be50", "badewanne3 123")]
 #[alias("rankc", "reachctb", "reachc", "rankcatch", "reachcatch")]
+#[flags(BANCHO_ONLY)]
 #[group(Catch)]
 async fn prefix_rankctb(msg: &Message, args: Args<'_>) -> Result<()> {
     match RankPp::args(Some(GameModeOption::Catch), args) {
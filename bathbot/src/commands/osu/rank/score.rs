@@ -31,6 +31,7 @@ use crate::{
 #[usage("[username] [number/username]")]
 #[example("badewanne3 123")]
 #[alias("rrs")]
+#[flags(BANCHO_ONLY)]
 #[group(Osu)]
 async fn prefix_rankrankedscore(msg: &Message, args: Args<'_>) -> Result<()> {
     match RankScore::args(None, args) {
@@ -53,6 +54,7 @@ async fn prefix_rankrankedscore(msg: &Message, args: Args<'_>) -> Result<()> {
 #[usage("[username] [number/username]")]
 #[example("badewanne3 123")]
 #[alias("rrsm")]
+#[flags(BANCHO_ONLY)]
 #[group(Mania)]
 async fn prefix_rankrankedscoremania(msg: &Message, args: Args<'_>) -> Result<()> {
     match RankScore::args(Some(GameModeOption::Mania), args) {
@@ -75,6 +77,7 @@ async fn prefix_rankrankedscoremania(msg: &Message, args: Args<'_>) -> Result<()
 #[usage("[username] [number/username]")]
 #[example("badewanne3 123")]
 #[alias("rrst")]
+#[flags(BANCHO_ONLY)]
 #[group(Taiko)]
 async fn prefix_rankrankedscoretaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     match RankScore::args(Some(GameModeOption::Taiko), args) {
@@ -97,6 +100,7 @@ async fn prefix_rankrankedscoretaiko(msg: &Message, args: Args<'_>) -> Result<()
 #[usage("[username] [number/username]")]
 #[example("badewanne3 123")]
 #[aliases("rrsc", "rankrankedscorecatch")]
+#[flags(BANCHO_ONLY)]
 #[group(Catch)]
 async fn prefix_rankrankedscorectb(msg: &Message, args: Args<'_>) -> Result<()> {
     match RankScore::args(Some(GameModeOption::Catch), args) {
@@ -271,7 +275,8 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RankScore<'_>) -> Resul
         rank_holder
     } else {
         let page = (rank as usize / 50) + (rank % 50 != 0) as usize;
-        let rank_holder_fut = Context::osu().score_rankings(mode).page(page as u32);
+        let rank_holder_fut =
+            Context::osu_request(|osu| osu.score_rankings(mode).page(page as u32));
 
         match rank_holder_fut.await {
             Ok(mut rankings) => {
@@ -16,6 +16,7 @@ use crate::{
 #[command]
 #[desc("Display the osu! rankings for countries")]
 #[aliases("cr")]
+#[flags(BANCHO_ONLY)]
 #[group(Osu)]
 pub async fn prefix_countryranking(msg: &Message) -> Result<()> {
     country(msg.into(), None.into()).await
@@ -24,6 +25,7 @@ pub async fn prefix_countryranking(msg: &Message) -> Result<()> {
 #[command]
 #[desc("Display the osu!mania rankings for countries")]
 #[aliases("crm")]
+#[flags(BANCHO_ONLY)]
 #[group(Mania)]
 pub async fn prefix_countryrankingmania(msg: &Message) -> Result<()> {
     country(msg.into(), Some(GameModeOption::Mania).into()).await
@@ -32,6 +34,7 @@ pub async fn prefix_countryrankingmania(msg: &Message) -> Result<()> {
 #[command]
 #[desc("Display the osu!taiko rankings for countries")]
 #[aliases("crt")]
+#[flags(BANCHO_ONLY)]
 #[group(Taiko)]
 pub async fn prefix_countryrankingtaiko(msg: &Message) -> Result<()> {
     country(msg.into(), Some(GameModeOption::Taiko).into()).await
@@ -40,6 +43,7 @@ pub async fn prefix_countryrankingtaiko(msg: &Message) -> Result<()> {
 #[command]
 #[desc("Display the osu!ctb rankings for countries")]
 #[aliases("crc", "countryrankingcatch")]
+#[flags(BANCHO_ONLY)]
 #[group(Catch)]
 pub async fn prefix_countryrankingctb(msg: &Message) -> Result<()> {
     country(msg.into(), Some(GameModeOption::Catch).into()).await
@@ -60,7 +64,7 @@ pub(super) async fn country(orig: CommandOrigin<'_>, args: RankingCountry) -> Re
         },
     };
 
-    let mut ranking = match Context::osu().country_rankings(mode).await {
+    let mut ranking = match Context::osu_request(|osu| osu.country_rankings(mode)).await {
         Ok(ranking) => ranking,
         Err(err) => {
             let _ = orig.error(OSU_API_ISSUE).await;
@@ -16,6 +16,7 @@ mod players;
     name = "ranking",
     desc = "Show the pp, ranked score, or country ranking"
 )]
+#[flags(BANCHO_ONLY)]
 pub enum Ranking<'a> {
     #[command(name = "pp")]
     Pp(RankingPp<'a>),
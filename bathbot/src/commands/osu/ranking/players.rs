@@ -157,11 +157,15 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RankingScore<'_>) -> Re
         None => None,
     };
 
-    let mut ranking_fut = Context::osu().score_rankings(mode);
+    let ranking_fut = Context::osu_request(|osu| {
+        let mut ranking_fut = osu.score_rankings(mode);
 
-    if let Some(country) = country.as_deref() {
-        ranking_fut = ranking_fut.country(country);
-    }
+        if let Some(country) = country.as_deref() {
+            ranking_fut = ranking_fut.country(country);
+        }
+
+        ranking_fut
+    });
 
     let author_idx_fut = score_author_idx(author_id, mode, country.is_some());
 
@@ -341,6 +345,7 @@ impl Ranking {
 #[usage("[country]")]
 #[examples("", "de", "russia")]
 #[aliases("ppr", "pplb", "ppleaderboard")]
+#[flags(BANCHO_ONLY)]
 #[group(Osu)]
 pub async fn prefix_ppranking(msg: &Message, mut args: Args<'_>) -> Result<()> {
     let country = match args.next().map(check_country) {
@@ -371,6 +376,7 @@ pub async fn prefix_ppranking(msg: &Message, mut args: Args<'_>) -> Result<()> {
 #[usage("[country]")]
 #[examples("", "de", "russia")]
 #[aliases("pprm", "pplbm", "ppleaderboardmania")]
+#[flags(BANCHO_ONLY)]
 #[group(Mania)]
 pub async fn prefix_pprankingmania(msg: &Message, mut args: Args<'_>) -> Result<()> {
     let country = match args.next().map(check_country) {
@@ -401,6 +407,7 @@ pub async fn prefix_pprankingmania(msg: &Message, mut args: Args<'_>) -> Result<
 #[usage("[country]")]
 #[examples("", "de", "russia")]
 #[aliases("pprt", "pplbt", "ppleaderboardtaiko")]
+#[flags(BANCHO_ONLY)]
 #[group(Taiko)]
 pub async fn prefix_pprankingtaiko(msg: &Message, mut args: Args<'_>) -> Result<()> {
     let country = match args.next().map(check_country) {
@@ -431,6 +438,7 @@ pub async fn prefix_pprankingtaiko(msg: &Message, mut args: Args<'_>) -> Result<
 #[usage("[country]")]
 #[examples("", "de", "russia")]
 #[aliases("pprc", "pplbc", "ppleaderboardctb", "pprankingcatch")]
+#[flags(BANCHO_ONLY)]
 #[group(Catch)]
 pub async fn prefix_pprankingctb(msg: &Message, mut args: Args<'_>) -> Result<()> {
     let country = match args.next().map(check_country) {
@@ -454,6 +462,7 @@ pub async fn prefix_pprankingctb(msg: &Message, mut args: Args<'_>) -> Result<()
 #[command]
 #[desc("Display the global osu! ranked score ranking")]
 #[aliases("rsr", "rslb")]
+#[flags(BANCHO_ONLY)]
 #[group(Osu)]
 pub async fn prefix_rankedscoreranking(msg: &Message) -> Result<()> {
     score(msg.into(), None.into()).await
@@ -462,6 +471,7 @@ pub async fn prefix_rankedscoreranking(msg: &Message) -> Result<()> {
 #[command]
 #[desc("Display the global osu!mania ranked score ranking")]
 #[aliases("rsrm", "rslbm")]
+#[flags(BANCHO_ONLY)]
 #[group(Mania)]
 pub async fn prefix_rankedscorerankingmania(msg: &Message) -> Result<()> {
     score(msg.into(), Some(GameModeOption::Mania).into()).await
@@ -470,6 +480,7 @@ pub async fn prefix_rankedscorerankingmania(msg: &Message) -> Result<()> {
 #[command]
 #[desc("Display the global osu!taiko ranked score ranking")]
 #[aliases("rsrt", "rslbt")]
+#[flags(BANCHO_ONLY)]
 #[group(Taiko)]
 pub async fn prefix_rankedscorerankingtaiko(msg: &Message) -> Result<()> {
     score(msg.into(), Some(GameModeOption::Taiko).into()).await
@@ -478,6 +489,7 @@ pub async fn prefix_rankedscorerankingtaiko(msg: &Message) -> Result<()> {
 #[command]
 #[desc("Display the global osu!ctb ranked score ranking")]
 #[aliases("rsrc", "rslbc")]
+#[flags(BANCHO_ONLY)]
 #[group(Catch)]
 pub async fn prefix_rankedscorerankingctb(msg: &Message) -> Result<()> {
     score(msg.into(), Some(GameModeOption::Catch).into()).await
@@ -1,8 +1,9 @@
 use std::{
     borrow::Cow,
     cmp::{Ordering, Reverse},
-    collections::HashMap,
+    collections::{HashMap, hash_map::Entry},
     fmt::Write,
+    sync::Arc,
 };
 
 use bathbot_macros::command;
@@ -15,27 +16,28 @@ use bathbot_util::{
     CowUtils, IntHasher,
     constants::GENERAL_ISSUE,
     matcher,
-    osu::ModSelection,
+    osu::{ModSelection, effective_clock_rate},
     query::{IFilterCriteria, Searchable},
 };
-use eyre::{Report, Result};
+use eyre::Result;
+use futures::{StreamExt, stream};
 use rosu_pp::{Beatmap, Difficulty, any::DifficultyAttributes};
 use rosu_v2::{
-    prelude::{GameMode, GameMods, Grade, OsuError, Score},
+    prelude::{GameMode, GameMods, Grade, Score},
     request::UserId,
 };
 
-use super::{RecentList, RecentListUnique};
+use super::{RecentList, RecentListGroupBy, RecentListUnique};
 use crate::{
     Context,
     active::{ActiveMessages, impls::RecentListPagination},
-    commands::osu::{HasMods, ModsResult, ScoreOrder, require_link, user_not_found},
-    core::commands::{CommandOrigin, prefix::Args},
-    manager::{
-        OsuMap,
-        redis::osu::{UserArgs, UserArgsError},
+    commands::osu::{
+        HasMods, ModsResult, ScoreOrder, require_link,
+        score_source::{RecentScores, ScoreSource, fetch_scores},
     },
-    util::{ChannelExt, NativeCriteria},
+    core::commands::{CommandOrigin, prefix::Args},
+    manager::OsuMap,
+    util::NativeCriteria,
 };
 
 #[command]
@@ -44,7 +46,9 @@ use crate::{
     "Display a list of a user's most recent plays.\n\
     To filter all fails, you can specify `pass=true`.\n\
     To filter specific grades, you can specify `grade=...`.\n\
-    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`."
+    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`.\n\
+    To filter by stars or pp, you can specify `min_stars=...`, `max_stars=...`, \
+    `min_pp=...`, or `max_pp=...`."
 )]
 #[usage("[username]")]
 #[example("badewanne3")]
@@ -53,11 +57,7 @@ use crate::{
 async fn prefix_recentlist(msg: &Message, args: Args<'_>) -> Result<()> {
     match RecentList::args(None, args) {
         Ok(args) => list(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -67,7 +67,9 @@ async fn prefix_recentlist(msg: &Message, args: Args<'_>) -> Result<()> {
     "Display a list of a user's most recent mania plays.\n\
     To filter all fails, you can specify `pass=true`.\n\
     To filter specific grades, you can specify `grade=...`.\n\
-    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`."
+    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`.\n\
+    To filter by stars or pp, you can specify `min_stars=...`, `max_stars=...`, \
+    `min_pp=...`, or `max_pp=...`."
 )]
 #[usage("[username]")]
 #[example("badewanne3")]
@@ -76,11 +78,7 @@ async fn prefix_recentlist(msg: &Message, args: Args<'_>) -> Result<()> {
 async fn prefix_recentlistmania(msg: &Message, args: Args<'_>) -> Result<()> {
     match RecentList::args(Some(GameModeOption::Mania), args) {
         Ok(args) => list(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -90,7 +88,9 @@ async fn prefix_recentlistmania(msg: &Message, args: Args<'_>) -> Result<()> {
     "Display a list of a user's most recent taiko plays.\n\
     To filter all fails, you can specify `pass=true`.\n\
     To filter specific grades, you can specify `grade=...`.\n\
-    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`."
+    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`.\n\
+    To filter by stars or pp, you can specify `min_stars=...`, `max_stars=...`, \
+    `min_pp=...`, or `max_pp=...`."
 )]
 #[usage("[username]")]
 #[example("badewanne3")]
@@ -99,11 +99,7 @@ async fn prefix_recentlistmania(msg: &Message, args: Args<'_>) -> Result<()> {
 async fn prefix_recentlisttaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     match RecentList::args(Some(GameModeOption::Taiko), args) {
         Ok(args) => list(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -113,7 +109,9 @@ async fn prefix_recentlisttaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     "Display a list of a user's most recent ctb plays.\n\
     To filter all fails, you can specify `pass=true`.\n\
     To filter specific grades, you can specify `grade=...`.\n\
-    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`."
+    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`.\n\
+    To filter by stars or pp, you can specify `min_stars=...`, `max_stars=...`, \
+    `min_pp=...`, or `max_pp=...`."
 )]
 #[usage("[username]")]
 #[example("badewanne3")]
@@ -122,11 +120,7 @@ async fn prefix_recentlisttaiko(msg: &Message, args: Args<'_>) -> Result<()> {
 async fn prefix_recentlistctb(msg: &Message, args: Args<'_>) -> Result<()> {
     match RecentList::args(Some(GameModeOption::Catch), args) {
         Ok(args) => list(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -135,7 +129,9 @@ async fn prefix_recentlistctb(msg: &Message, args: Args<'_>) -> Result<()> {
 #[help(
     "Display a list of a user's most recent passes.\n\
     To filter specific grades, you can specify `grade=...`.\n\
-    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`."
+    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`.\n\
+    To filter by stars or pp, you can specify `min_stars=...`, `max_stars=...`, \
+    `min_pp=...`, or `max_pp=...`."
 )]
 #[usage("[username]")]
 #[example("badewanne3")]
@@ -148,11 +144,7 @@ async fn prefix_recentlistpass(msg: &Message, args: Args<'_>) -> Result<()> {
 
             list(msg.into(), args).await
         }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -161,7 +153,9 @@ async fn prefix_recentlistpass(msg: &Message, args: Args<'_>) -> Result<()> {
 #[help(
     "Display a list of a user's most recent mania passes.\n\
     To filter specific grades, you can specify `grade=...`.\n\
-    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`."
+    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`.\n\
+    To filter by stars or pp, you can specify `min_stars=...`, `max_stars=...`, \
+    `min_pp=...`, or `max_pp=...`."
 )]
 #[usage("[username]")]
 #[example("badewanne3")]
@@ -174,11 +168,7 @@ async fn prefix_recentlistpassmania(msg: &Message, args: Args<'_>) -> Result<()>
 
             list(msg.into(), args).await
         }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -188,7 +178,9 @@ async fn prefix_recentlistpassmania(msg: &Message, args: Args<'_>) -> Result<()>
     "Display a list of a user's most recent taiko plays.\n\
     To filter all fails, you can specify `pass=true`.\n\
     To filter specific grades, you can specify `grade=...`.\n\
-    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`."
+    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`.\n\
+    To filter by stars or pp, you can specify `min_stars=...`, `max_stars=...`, \
+    `min_pp=...`, or `max_pp=...`."
 )]
 #[usage("[username]")]
 #[example("badewanne3")]
@@ -201,11 +193,7 @@ async fn prefix_recentlistpasstaiko(msg: &Message, args: Args<'_>) -> Result<()>
 
             list(msg.into(), args).await
         }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -215,7 +203,9 @@ async fn prefix_recentlistpasstaiko(msg: &Message, args: Args<'_>) -> Result<()>
     "Display a list of a user's most recent ctb plays.\n\
     To filter all fails, you can specify `pass=true`.\n\
     To filter specific grades, you can specify `grade=...`.\n\
-    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`."
+    Available grades are `SS`, `S`, `A`, `B`, `C`, `D`, or `F`.\n\
+    To filter by stars or pp, you can specify `min_stars=...`, `max_stars=...`, \
+    `min_pp=...`, or `max_pp=...`."
 )]
 #[usage("[username]")]
 #[example("badewanne3")]
@@ -234,11 +224,7 @@ async fn prefix_recentlistpassctb(msg: &Message, args: Args<'_>) -> Result<()> {
 
             list(msg.into(), args).await
         }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -248,8 +234,13 @@ impl<'m> RecentList<'m> {
         let mut discord = None;
         let mut grade = None;
         let mut passes = None;
+        let mut min_stars = None;
+        let mut max_stars = None;
+        let mut min_pp = None;
+        let mut max_pp = None;
+        let mut group_by = None;
 
-        for arg in args.take(3).map(|arg| arg.cow_to_ascii_lowercase()) {
+        for arg in args.take(8).map(|arg| arg.cow_to_ascii_lowercase()) {
             if let Some(idx) = arg.find('=').filter(|&i| i > 0) {
                 let key = &arg[..idx];
                 let value = arg[idx + 1..].trim_end();
@@ -279,10 +270,23 @@ impl<'m> RecentList<'m> {
                         Ok(grade_) => grade = Some(grade_),
                         Err(content) => return Err(content.into()),
                     },
+                    "min_stars" | "minstars" => min_stars = Some(parse_filter(key, value)?),
+                    "max_stars" | "maxstars" => max_stars = Some(parse_filter(key, value)?),
+                    "min_pp" | "minpp" => min_pp = Some(parse_filter(key, value)?),
+                    "max_pp" | "maxpp" => max_pp = Some(parse_filter(key, value)?),
+                    "group" | "group_by" | "groupby" => match value {
+                        "mapset" | "set" | "s" => group_by = Some(RecentListGroupBy::Mapset),
+                        _ => {
+                            let content = "Failed to parse `group_by`. Must be `mapset`.";
+
+                            return Err(content.into());
+                        }
+                    },
                     _ => {
                         let content = format!(
                             "Unrecognized option `{key}`.\n\
-                            Available options are: `grade` or `pass`."
+                            Available options are: `grade`, `pass`, `min_stars`, \
+                            `max_stars`, `min_pp`, `max_pp`, or `group_by`."
                         );
 
                         return Err(content.into());
@@ -306,14 +310,26 @@ impl<'m> RecentList<'m> {
             grade,
             sort: None,
             passes,
+            min_stars,
+            max_stars,
+            min_pp,
+            max_pp,
             mods: None,
             unique: None,
+            group_by,
             discord,
             score_data: None,
         })
     }
 }
 
+fn parse_filter(key: &str, value: &str) -> Result<f32, Cow<'static, str>> {
+    match value.parse::<f32>() {
+        Ok(value) if value >= 0.0 => Ok(value),
+        _ => Err(format!("Failed to parse `{key}`. Must be a positive number.").into()),
+    }
+}
+
 pub(super) async fn list(orig: CommandOrigin<'_>, args: RecentList<'_>) -> Result<()> {
     let mods = match args.mods() {
         ModsResult::Mods(mods) => Some(mods),
@@ -355,18 +371,10 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: RecentList<'_>) -> Resul
         },
     };
 
-    let RecentList {
-        query,
-        grade,
-        passes,
-        ..
-    } = &args;
+    let RecentList { grade, passes, .. } = &args;
 
     let grade = grade.map(Grade::from);
 
-    // Retrieve the user and their recent scores
-    let user_args = UserArgs::rosu_id(&user_id, mode).await;
-
     let include_fails = match (grade, passes) {
         (Some(Grade::F), Some(true)) => return orig.error(":clown:").await,
         (_, Some(passes)) => !passes,
@@ -374,41 +382,35 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: RecentList<'_>) -> Resul
         _ => false,
     };
 
-    let scores_fut = Context::osu_scores()
-        .recent(legacy_scores)
-        .limit(100)
-        .include_fails(include_fails)
-        .exec_with_user(user_args);
-
-    let (user, scores) = match scores_fut.await {
-        Ok((user, scores)) if scores.is_empty() => {
-            let username = user.username.as_str();
-
-            let content = format!(
-                "No recent {}plays found for user `{username}`",
-                match mode {
-                    GameMode::Osu => "",
-                    GameMode::Taiko => "taiko ",
-                    GameMode::Catch => "ctb ",
-                    GameMode::Mania => "mania ",
-                },
-            );
+    // Retrieve the user and their recent scores
+    let source = RecentScores {
+        legacy_scores,
+        include_fails,
+    };
 
-            return orig.error(content).await;
-        }
-        Ok((user, scores)) => (user, scores),
-        Err(UserArgsError::Osu(OsuError::NotFound)) => {
-            let content = user_not_found(user_id).await;
+    let Some((user, scores)) = fetch_scores(&orig, &source, user_id, mode).await? else {
+        return Ok(());
+    };
 
-            return orig.error(content).await;
-        }
-        Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
-            let err = Report::new(err).wrap_err("Failed to get user or scores");
+    if scores.is_empty() {
+        let content = format!(
+            "No recent {mode}{kind} found for user `{username}`",
+            mode = match mode {
+                GameMode::Osu => "",
+                GameMode::Taiko => "taiko ",
+                GameMode::Catch => "ctb ",
+                GameMode::Mania => "mania ",
+            },
+            kind = if source.capabilities().fails {
+                "plays"
+            } else {
+                "passes"
+            },
+            username = user.username.as_str(),
+        );
 
-            return Err(err);
-        }
-    };
+        return orig.error(content).await;
+    }
 
     let (entries, maps) = match process_scores(scores, &args, mode, mods.as_ref()).await {
         Ok(entries) => entries,
@@ -419,7 +421,7 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: RecentList<'_>) -> Resul
         }
     };
 
-    let content = message_content(grade, mods.as_ref(), query.as_deref()).unwrap_or_default();
+    let content = message_content(&args, grade, mods.as_ref()).unwrap_or_default();
 
     let pagination = RecentListPagination::builder()
         .user(user)
@@ -436,9 +438,9 @@ pub(super) async fn list(orig: CommandOrigin<'_>, args: RecentList<'_>) -> Resul
 }
 
 fn message_content(
+    args: &RecentList<'_>,
     grade: Option<Grade>,
     mods: Option<&ModSelection>,
-    query: Option<&str>,
 ) -> Option<String> {
     let mut content = String::new();
 
@@ -465,7 +467,18 @@ fn message_content(
         content.push('`');
     }
 
-    if let Some(query) = query {
+    write_range(&mut content, "Stars", args.min_stars, args.max_stars);
+    write_range(&mut content, "PP", args.min_pp, args.max_pp);
+
+    if let Some(RecentListGroupBy::Mapset) = args.group_by {
+        if !content.is_empty() {
+            content.push_str(" ~ ");
+        }
+
+        content.push_str("`Grouped by mapset`");
+    }
+
+    if let Some(query) = args.query.as_deref() {
         if !content.is_empty() {
             content.push_str(" ~ ");
         }
@@ -476,6 +489,23 @@ fn message_content(
     (!content.is_empty()).then_some(content)
 }
 
+fn write_range(content: &mut String, name: &str, min: Option<f32>, max: Option<f32>) {
+    if min.is_none() && max.is_none() {
+        return;
+    }
+
+    if !content.is_empty() {
+        content.push_str(" ~ ");
+    }
+
+    let _ = match (min, max) {
+        (Some(min), Some(max)) => write!(content, "`{name}: {min} - {max}`"),
+        (Some(min), None) => write!(content, "`{name}: ≥ {min}`"),
+        (None, Some(max)) => write!(content, "`{name}: ≤ {max}`"),
+        (None, None) => unreachable!(),
+    };
+}
+
 pub struct RecentListEntry {
     pub idx: usize,
     pub score: ScoreSlim,
@@ -485,6 +515,21 @@ pub struct RecentListEntry {
     pub stars: f32,
     pub max_pp: f32,
     pub max_combo: u32,
+    /// Only present if retries were grouped
+    pub attempts: Option<RecentListAttempts>,
+    /// How often the map was played in a row around this entry
+    pub retry_streak: usize,
+}
+
+impl RecentListEntry {
+    /// Retry streaks above this are flagged as retry spam
+    pub const RETRY_SPAM: usize = 5;
+}
+
+/// Retries of a mapset that were collapsed into a single entry
+pub struct RecentListAttempts {
+    pub count: usize,
+    pub difficulties: usize,
 }
 
 async fn process_scores(
@@ -499,6 +544,11 @@ async fn process_scores(
         passes,
         sort,
         unique,
+        group_by,
+        min_stars,
+        max_stars,
+        min_pp,
+        max_pp,
         ..
     } = args;
 
@@ -547,21 +597,32 @@ async fn process_scores(
         maps.values_mut().for_each(|map| map.convert_mut(mode));
     }
 
-    let scores = scores
+    let retry_streaks = retry_streaks(scores.iter().map(|score| score.map_id));
+
+    let scores: Vec<_> = scores
         .into_iter()
         .enumerate()
-        .filter(|(_, score)| score_filter(score));
+        .filter(|(_, score)| score_filter(score))
+        .collect();
 
-    let mut cached_attrs = CachedAttributes::default();
+    let cached_attrs = CachedAttributes::new(scores.iter().map(|(_, score)| score), &maps).await;
+
+    let in_range = |value: f32, min: Option<f32>, max: Option<f32>| {
+        min.is_none_or(|min| value >= min) && max.is_none_or(|max| value <= max)
+    };
 
     for (idx, score) in scores {
         let Some(map) = maps.get(&score.map_id) else {
             continue;
         };
 
+        if filter_criteria.as_ref().is_some_and(|c| !c.matches_ln(map)) {
+            continue;
+        }
+
         let pp_map = &map.pp_map;
 
-        let attrs = cached_attrs.get(pp_map, &score).await;
+        let attrs = cached_attrs.get(&score);
 
         let mut stars = 0.0;
         let mut max_combo = 0;
@@ -597,6 +658,10 @@ async fn process_scores(
             max_pp = pp;
         }
 
+        if !in_range(stars, *min_stars, *max_stars) || !in_range(pp, *min_pp, *max_pp) {
+            continue;
+        }
+
         let map_id = score.map_id;
         let score = ScoreSlim::new(score, pp);
 
@@ -607,11 +672,17 @@ async fn process_scores(
             max_pp,
             stars,
             max_combo,
+            attempts: None,
+            retry_streak: retry_streaks[idx],
         };
 
         entries.push(entry);
     }
 
+    if let Some(RecentListGroupBy::Mapset) = group_by {
+        entries = group_by_mapset(entries, &maps);
+    }
+
     match unique {
         None => {}
         Some(RecentListUnique::HighestPp) => {
@@ -674,8 +745,8 @@ async fn process_scores(
                 let a_map = maps.get(&a.map_id).expect("missing map");
                 let b_map = maps.get(&b.map_id).expect("missing map");
 
-                let a_len = a_map.seconds_drain() as f64 / a.score.mods.clock_rate().unwrap_or(1.0);
-                let b_len = b_map.seconds_drain() as f64 / b.score.mods.clock_rate().unwrap_or(1.0);
+                let a_len = a_map.seconds_drain() as f64 / effective_clock_rate(&a.score.mods);
+                let b_len = b_map.seconds_drain() as f64 / effective_clock_rate(&b.score.mods);
 
                 b_len
                     .partial_cmp(&a_len)
@@ -746,10 +817,83 @@ async fn process_scores(
     Ok((entries, maps))
 }
 
+/// Collapse entries of the same mapset into their best entry while keeping
+/// the order of each mapset's first appearance.
+fn group_by_mapset(
+    entries: Vec<RecentListEntry>,
+    maps: &HashMap<u32, OsuMap, IntHasher>,
+) -> Vec<RecentListEntry> {
+    let mut groups: Vec<Vec<RecentListEntry>> = Vec::new();
+    let mut indices = HashMap::with_hasher(IntHasher);
+
+    for entry in entries {
+        let mapset_id = maps.get(&entry.map_id).map_or(0, OsuMap::mapset_id);
+
+        match indices.entry(mapset_id) {
+            Entry::Occupied(e) => groups[*e.get()].push(entry),
+            Entry::Vacant(e) => {
+                e.insert(groups.len());
+                groups.push(vec![entry]);
+            }
+        }
+    }
+
+    groups
+        .into_iter()
+        .filter_map(|group| {
+            let count = group.len();
+            let retry_streak = group.iter().map(|entry| entry.retry_streak).max();
+
+            let mut map_ids: Vec<_> = group.iter().map(|entry| entry.map_id).collect();
+            map_ids.sort_unstable();
+            map_ids.dedup();
+
+            let mut best = group.into_iter().max_by(|a, b| {
+                (a.score.grade != Grade::F)
+                    .cmp(&(b.score.grade != Grade::F))
+                    .then_with(|| a.score.pp.total_cmp(&b.score.pp))
+                    .then_with(|| a.score.score.cmp(&b.score.score))
+            })?;
+
+            best.attempts = Some(RecentListAttempts {
+                count,
+                difficulties: map_ids.len(),
+            });
+            best.retry_streak = retry_streak.unwrap_or(0);
+
+            Some(best)
+        })
+        .collect()
+}
+
+/// For each score, the length of the run of consecutive plays on the same map
+/// that the score belongs to.
+fn retry_streaks(map_ids: impl IntoIterator<Item = u32>) -> Vec<usize> {
+    let mut streaks = Vec::new();
+    let mut run_start = 0;
+    let mut prev = None;
+
+    for (i, map_id) in map_ids.into_iter().enumerate() {
+        if prev != Some(map_id) {
+            let len = i - run_start;
+            streaks[run_start..].fill(len);
+            run_start = i;
+            prev = Some(map_id);
+        }
+
+        streaks.push(0);
+    }
+
+    let len = streaks.len() - run_start;
+    streaks[run_start..].fill(len);
+
+    streaks
+}
+
 // It takes a long time to calculate attributes for maps like /b/5023039 with
-// 32k+ objects so we want to cache as much as possible in case users have
-// the same long map multiple times with the same mods in their recent plays.
-#[derive(Default)]
+// 32k+ objects so we want to calculate each combination only once in case
+// users have the same long map multiple times with the same mods in their
+// recent plays. Unique combinations are calculated concurrently.
 struct CachedAttributes {
     // `GameMods` implements neither `Hash` nor `Ord` so we cannot use
     // `HashMap` or `BTreeMap` and need to use `Vec` instead.
@@ -757,36 +901,101 @@ struct CachedAttributes {
 }
 
 impl CachedAttributes {
-    /// Returns `None` if the map is too suspicious.
-    async fn get(&mut self, map: &Beatmap, score: &Score) -> Option<DifficultyAttributes> {
-        let map_id = score.map_id;
-        let lazer = score.set_on_lazer;
+    /// Max amount of simultaneous calculations
+    const CONCURRENCY: usize = 4;
+
+    async fn new<'s>(
+        scores: impl Iterator<Item = &'s Score>,
+        maps: &HashMap<u32, OsuMap, IntHasher>,
+    ) -> Self {
+        let mut pending: Vec<(u32, GameMods, bool)> = Vec::new();
+
+        // Maps are shared between the calculations of their combinations.
+        // Suspicious maps are `None` and won't be calculated.
+        let mut pp_maps: HashMap<u32, Option<Arc<Beatmap>>, IntHasher> = HashMap::default();
+
+        for score in scores {
+            let map_id = score.map_id;
+            let lazer = score.set_on_lazer;
+
+            let Some(map) = maps.get(&map_id) else {
+                continue;
+            };
+
+            let is_pending = pending
+                .iter()
+                .any(|(id, mods, l)| *id == map_id && *mods == score.mods && *l == lazer);
+
+            if is_pending {
+                continue;
+            }
+
+            let pp_map = pp_maps.entry(map_id).or_insert_with(|| {
+                let pp_map = &map.pp_map;
 
-        let res = self.entries.iter().find(|entry| {
-            entry.map_id == map_id && entry.mods == score.mods && entry.lazer == lazer
-        });
+                pp_map
+                    .check_suspicion()
+                    .is_ok()
+                    .then(|| Arc::new(pp_map.clone()))
+            });
 
-        if let Some(entry) = res {
-            return Some(entry.attrs.clone());
-        } else if map.check_suspicion().is_err() {
-            return None;
+            if pp_map.is_some() {
+                pending.push((map_id, score.mods.clone(), lazer));
+            }
         }
 
-        let mods = score.mods.clone();
+        let mut attrs_stream = stream::iter(pending)
+            .map(|(map_id, mods, lazer)| {
+                let pp_map = pp_maps.get(&map_id).cloned().flatten();
+
+                async move {
+                    let pp_map = pp_map?;
+
+                    let calc = tokio::task::spawn_blocking(move || {
+                        let attrs = Difficulty::new()
+                            .mods(mods.clone())
+                            .lazer(lazer)
+                            .calculate(&pp_map);
+
+                        (mods, attrs)
+                    });
+
+                    match calc.await {
+                        Ok((mods, attrs)) => Some(CachedEntry {
+                            map_id,
+                            lazer,
+                            mods,
+                            attrs,
+                        }),
+                        Err(err) => {
+                            warn!(map_id, ?err, "Failed to join difficulty calculation");
+
+                            None
+                        }
+                    }
+                }
+            })
+            .buffer_unordered(Self::CONCURRENCY);
 
-        let attrs = Difficulty::new()
-            .mods(mods.clone())
-            .lazer(lazer)
-            .calculate(map);
+        let mut entries = Vec::new();
 
-        self.entries.push(CachedEntry {
-            map_id,
-            lazer,
-            mods,
-            attrs: attrs.clone(),
-        });
+        while let Some(entry) = attrs_stream.next().await {
+            entries.extend(entry);
+        }
 
-        Some(attrs)
+        Self { entries }
+    }
+
+    /// Returns `None` if the map is too suspicious.
+    fn get(&self, score: &Score) -> Option<DifficultyAttributes> {
+        self.entries
+            .iter()
+            .find(|entry| {
+                entry.map_id == score.map_id
+                    && entry.mods == score.mods
+                    && entry.lazer == score.set_on_lazer
+            })
+            .map(|entry| entry.attrs.clone())
     }
 }
 
@@ -796,3 +1005,16 @@ struct CachedEntry {
     mods: GameMods,
     attrs: DifficultyAttributes,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn consecutive_retries() {
+        let streaks = retry_streaks([1, 1, 1, 2, 3, 3, 1]);
+
+        assert_eq!(streaks, [3, 3, 3, 1, 2, 2, 1]);
+        assert!(retry_streaks([]).is_empty());
+    }
+}
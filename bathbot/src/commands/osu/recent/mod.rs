@@ -10,13 +10,17 @@ use twilight_model::id::{Id, marker::UserMarker};
 
 use self::fix::*;
 pub use self::{leaderboard::*, list::*, score::*};
-use super::{HasMods, ModsResult, ScoreOrder, TopArgs, TopScoreOrder};
+use super::{
+    HasMods, ModsResult, SCORE_RENDER_DESC, SCORE_RENDER_HELP, ScoreOrder, ScoreRender, TopArgs,
+    TopScoreOrder,
+};
 use crate::{
     commands::{
         DISCORD_OPTION_DESC, DISCORD_OPTION_HELP,
         osu::{LeaderboardSort, top},
         utility::{SCORE_DATA_DESC, SCORE_DATA_HELP},
     },
+    core::commands::CommandOrigin,
     util::{InteractionCommandExt, interaction::InteractionCommand},
 };
 
@@ -73,6 +77,8 @@ pub struct RecentScore<'a> {
     discord: Option<Id<UserMarker>>,
     #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
     score_data: Option<ScoreData>,
+    #[command(desc = SCORE_RENDER_DESC, help = SCORE_RENDER_HELP)]
+    render: Option<ScoreRender>,
 }
 
 #[derive(CommandModel, CreateCommand, HasMods, HasName)]
@@ -223,6 +229,14 @@ pub struct RecentList<'a> {
     sort: Option<ScoreOrder>,
     #[command(desc = "Specify whether only passes should be considered")]
     passes: Option<bool>,
+    #[command(min_value = 0.0, desc = "Scores must have at least X stars")]
+    min_stars: Option<f32>,
+    #[command(min_value = 0.0, desc = "Scores must have at most X stars")]
+    max_stars: Option<f32>,
+    #[command(min_value = 0.0, desc = "Scores must have at least X pp")]
+    min_pp: Option<f32>,
+    #[command(min_value = 0.0, desc = "Scores must have at most X pp")]
+    max_pp: Option<f32>,
     #[command(
         desc = "Specify mods (`+mods` for included, `+mods!` for exact, `-mods!` for excluded)",
         help = "Filter out all scores that don't match the specified mods.\n\
@@ -237,12 +251,24 @@ pub struct RecentList<'a> {
     mods: Option<Cow<'a, str>>,
     #[command(desc = "Show each map-mod pair only once")]
     unique: Option<RecentListUnique>,
+    #[command(
+        desc = "Collapse retries of the same mapset into one entry",
+        help = "Collapse retries across all difficulties of the same mapset into one entry.\n\
+        The entry shows the amount of attempts and the best score among them."
+    )]
+    group_by: Option<RecentListGroupBy>,
     #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
     discord: Option<Id<UserMarker>>,
     #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
     score_data: Option<ScoreData>,
 }
 
+#[derive(Copy, Clone, CreateOption, CommandOption)]
+pub enum RecentListGroupBy {
+    #[option(name = "Mapset", value = "mapset")]
+    Mapset,
+}
+
 #[derive(Copy, Clone, CreateOption, CommandOption)]
 pub enum RecentListUnique {
     #[option(name = "Highest PP", value = "pp")]
@@ -393,9 +419,9 @@ async fn slash_recent(mut command: InteractionCommand) -> Result<()> {
         Recent::Best(args) => match TopArgs::try_from(args) {
             Ok(args) => top((&mut command).into(), args).await,
             Err(content) => {
-                command.error(content).await?;
-
-                Ok(())
+                CommandOrigin::from(&mut command)
+                    .error_with_help(content)
+                    .await
             }
         },
         Recent::Leaderboard(args) => leaderboard((&mut command).into(), args).await,
@@ -410,9 +436,9 @@ async fn slash_rb(mut command: InteractionCommand) -> Result<()> {
     match TopArgs::try_from(RecentBest::from(args)) {
         Ok(args) => top((&mut command).into(), args).await,
         Err(content) => {
-            command.error(content).await?;
-
-            Ok(())
+            CommandOrigin::from(&mut command)
+                .error_with_help(content)
+                .await
         }
     }
 }
@@ -28,12 +28,15 @@ use crate::{
     },
     commands::{
         DISCORD_OPTION_DESC, DISCORD_OPTION_HELP,
-        osu::{map_strains_graph, require_link, user_not_found},
+        osu::{
+            SCORE_RENDER_DESC, SCORE_RENDER_HELP, ScoreRender, map_strains_graph, require_link,
+            send_score_card, user_not_found,
+        },
         utility::{MissAnalyzerCheck, SCORE_DATA_DESC, SCORE_DATA_HELP, ScoreEmbedDataWrap},
     },
     core::commands::{CommandOrigin, interaction::InteractionCommands, prefix::Args},
     manager::redis::osu::{UserArgs, UserArgsError, UserArgsSlim},
-    util::{ChannelExt, CheckPermissions, InteractionCommandExt, interaction::InteractionCommand},
+    util::{CheckPermissions, InteractionCommandExt, interaction::InteractionCommand},
 };
 
 const RECENT_USAGE: &str = "[username] [pass=true/false] [grade=grade[..grade]]";
@@ -57,11 +60,7 @@ const RECENT_USAGE: &str = "[username] [pass=true/false] [grade=grade[..grade]]"
 async fn prefix_recent(msg: &Message, args: Args<'_>) -> Result<()> {
     match RecentScore::args(None, args) {
         Ok(args) => score(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -84,11 +83,7 @@ async fn prefix_recent(msg: &Message, args: Args<'_>) -> Result<()> {
 async fn prefix_recentmania(msg: &Message, args: Args<'_>) -> Result<()> {
     match RecentScore::args(Some(GameModeOption::Mania), args) {
         Ok(args) => score(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -111,11 +106,7 @@ async fn prefix_recentmania(msg: &Message, args: Args<'_>) -> Result<()> {
 async fn prefix_recenttaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     match RecentScore::args(Some(GameModeOption::Taiko), args) {
         Ok(args) => score(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -138,11 +129,7 @@ async fn prefix_recenttaiko(msg: &Message, args: Args<'_>) -> Result<()> {
 async fn prefix_recentctb(msg: &Message, args: Args<'_>) -> Result<()> {
     match RecentScore::args(Some(GameModeOption::Catch), args) {
         Ok(args) => score(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 #[command]
@@ -167,11 +154,7 @@ async fn prefix_recentpass(msg: &Message, args: Args<'_>) -> Result<()> {
 
             score(msg.into(), args).await
         }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -197,11 +180,7 @@ async fn prefix_recentpassmania(msg: &Message, args: Args<'_>) -> Result<()> {
 
             score(msg.into(), args).await
         }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -227,11 +206,7 @@ async fn prefix_recentpasstaiko(msg: &Message, args: Args<'_>) -> Result<()> {
 
             score(msg.into(), args).await
         }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -258,11 +233,7 @@ async fn prefix_recentpassctb(msg: &Message, args: Args<'_>) -> Result<()> {
 
             score(msg.into(), args).await
         }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -272,6 +243,7 @@ impl<'m> RecentScore<'m> {
         let mut discord = None;
         let mut grade = None;
         let mut passes = None;
+        let mut render = None;
         let num = args.num;
 
         for arg in args.take(3).map(|arg| arg.cow_to_ascii_lowercase()) {
@@ -304,10 +276,20 @@ impl<'m> RecentScore<'m> {
                         Ok(grade_) => grade = Some(grade_),
                         Err(content) => return Err(content.into()),
                     },
+                    "render" => match value {
+                        "embed" => render = Some(ScoreRender::Embed),
+                        "image" | "img" => render = Some(ScoreRender::Image),
+                        _ => {
+                            let content =
+                                "Failed to parse `render`. Must be either `embed` or `image`.";
+
+                            return Err(content.into());
+                        }
+                    },
                     _ => {
                         let content = format!(
                             "Unrecognized option `{key}`.\n\
-                            Available options are: `grade` or `pass`."
+                            Available options are: `grade`, `pass`, or `render`."
                         );
 
                         return Err(content.into());
@@ -332,6 +314,7 @@ impl<'m> RecentScore<'m> {
             passes,
             discord,
             score_data: None,
+            render,
         })
     }
 }
@@ -378,6 +361,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         passes,
         index,
         score_data,
+        render,
         ..
     } = args;
 
@@ -687,6 +671,23 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
         }
     }
 
+    if let Some(ScoreRender::Image) = render {
+        let Some(entry) = entries.get_mut(num) else {
+            return Ok(());
+        };
+
+        let entry = match entry.get_mut().await {
+            Ok(entry) => entry,
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("Failed to get score data"));
+            }
+        };
+
+        return send_score_card(orig, user.username.as_str(), entry).await;
+    }
+
     let graph = match entries.get_mut(num) {
         Some(entry) if matches!(settings.image, SettingsImage::ImageWithStrains) => {
             match entry.get_mut().await {
@@ -694,7 +695,7 @@ pub(super) async fn score(orig: CommandOrigin<'_>, args: RecentScore<'_>) -> Res
                     let fut = map_strains_graph(
                         &entry.map.pp_map,
                         entry.score.mods.clone(),
-                        entry.map.cover(),
+                        Some(entry.map.cover()),
                         SingleScorePagination::IMAGE_W,
                         SingleScorePagination::IMAGE_H,
                     );
@@ -821,6 +822,8 @@ pub struct Rs<'a> {
     discord: Option<Id<UserMarker>>,
     #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
     score_data: Option<ScoreData>,
+    #[command(desc = SCORE_RENDER_DESC, help = SCORE_RENDER_HELP)]
+    render: Option<ScoreRender>,
 }
 
 impl<'a> From<Rs<'a>> for RecentScore<'a> {
@@ -834,6 +837,7 @@ impl<'a> From<Rs<'a>> for RecentScore<'a> {
             passes,
             discord,
             score_data,
+            render,
         } = args;
 
         Self {
@@ -844,6 +848,7 @@ impl<'a> From<Rs<'a>> for RecentScore<'a> {
             passes,
             discord,
             score_data,
+            render,
         }
     }
 }
@@ -0,0 +1,86 @@
+use bathbot_cards::ScoreCard;
+use bathbot_util::{MessageBuilder, constants::GENERAL_ISSUE, datetime::DATE_FORMAT};
+use eyre::{Report, Result};
+use twilight_interactions::command::{CommandOption, CreateOption};
+
+use crate::{
+    commands::utility::ScoreEmbedData,
+    core::{BotConfig, Context, commands::CommandOrigin},
+};
+
+pub const SCORE_RENDER_DESC: &str = "Choose whether the score should be shown as embed or image";
+
+pub const SCORE_RENDER_HELP: &str = "Choose whether the score should be shown as embed or image.\n\
+The image is meant for sharing the score outside of discord.";
+
+#[derive(Copy, Clone, Default, CommandOption, CreateOption)]
+pub enum ScoreRender {
+    #[default]
+    #[option(name = "Embed", value = "embed")]
+    Embed,
+    #[option(name = "Image", value = "image")]
+    Image,
+}
+
+/// Draw a [`ScoreCard`] for the given score and send it as attachment.
+pub async fn send_score_card(
+    orig: CommandOrigin<'_>,
+    username: &str,
+    entry: &ScoreEmbedData,
+) -> Result<()> {
+    let cover = match Context::client().get_mapset_cover(entry.map.cover()).await {
+        Ok(cover) => Some(cover),
+        Err(err) => {
+            warn!(?err, "Failed to get mapset cover for score card");
+
+            None
+        }
+    };
+
+    let mods = entry.score.mods.to_string();
+    let date = entry.score.ended_at.date().format(DATE_FORMAT).unwrap();
+
+    let card = ScoreCard {
+        mode: entry.score.mode,
+        cover: cover.as_deref(),
+        artist: entry.map.artist(),
+        title: entry.map.title(),
+        version: entry.map.version(),
+        creator: entry.map.creator(),
+        username,
+        grade: entry.score.grade,
+        accuracy: entry.score.accuracy,
+        pp: entry.score.pp,
+        max_pp: entry.max_pp,
+        combo: entry.score.max_combo,
+        max_combo: entry.max_combo,
+        misses: entry.score.statistics.miss,
+        stars: entry.stars,
+        mods: &mods,
+        date: &date,
+        assets: BotConfig::get().paths.assets.clone(),
+    };
+
+    let _permit = match Context::render_queue().acquire(&orig).await {
+        Ok(permit) => permit,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let bytes = match card.draw() {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = orig.error("Failed to draw the score card :(").await;
+
+            return Err(Report::new(err).wrap_err("Failed to draw score card"));
+        }
+    };
+
+    let builder = MessageBuilder::new().attachment("score.png", bytes);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
@@ -0,0 +1,174 @@
+use std::future::Future;
+
+use bathbot_util::constants::GENERAL_ISSUE;
+use eyre::{Report, Result};
+use rosu_v2::{
+    prelude::{GameMode, OsuError, Score},
+    request::UserId,
+};
+
+use super::user_not_found;
+use crate::{
+    core::{Context, commands::CommandOrigin},
+    manager::redis::osu::{CachedUser, UserArgs, UserArgsError, UserArgsSlim},
+};
+
+/// Somewhere to retrieve a user's scores from.
+///
+/// List commands retrieve their scores through this trait so they share the
+/// retrieval and its error handling. Implementing it for a new source is all
+/// it takes to feed the commands' filtering, sorting, and pagination.
+pub trait ScoreSource: Send + Sync {
+    /// Plural description of the scores e.g. "top scores"
+    fn label(&self) -> &'static str;
+
+    fn capabilities(&self) -> SourceCapabilities;
+
+    /// Retrieve the scores of a user.
+    fn fetch(
+        &self,
+        user_args: UserArgsSlim,
+    ) -> impl Future<Output = Result<Vec<Score>, UserArgsError>> + Send;
+
+    /// Retrieve a user alongside their scores.
+    fn fetch_with_user(
+        &self,
+        user_args: UserArgs,
+    ) -> impl Future<Output = Result<(CachedUser, Vec<Score>), UserArgsError>> + Send {
+        async move {
+            match user_args {
+                UserArgs::Args(args) => {
+                    let user_fut = Context::redis().osu_user_from_args(args);
+                    let score_fut = self.fetch(args);
+
+                    let (user_res, score_res) = tokio::join!(user_fut, score_fut);
+
+                    Ok((user_res?, score_res?))
+                }
+                UserArgs::User { user, mode } => {
+                    let args = UserArgsSlim::user_id(user.user_id.to_native()).mode(mode);
+                    let scores = self.fetch(args).await?;
+
+                    Ok((user, scores))
+                }
+                UserArgs::Err(err) => Err(err),
+            }
+        }
+    }
+}
+
+/// What list commands can expect from the scores of a [`ScoreSource`].
+#[derive(Copy, Clone)]
+pub struct SourceCapabilities {
+    /// Maximum amount of scores that are retrieved
+    pub max_scores: usize,
+    /// Whether failed scores may be included
+    pub fails: bool,
+}
+
+pub struct TopScores {
+    pub limit: usize,
+    pub legacy_scores: bool,
+}
+
+impl ScoreSource for TopScores {
+    fn label(&self) -> &'static str {
+        "top scores"
+    }
+
+    fn capabilities(&self) -> SourceCapabilities {
+        SourceCapabilities {
+            max_scores: self.limit,
+            fails: false,
+        }
+    }
+
+    async fn fetch(&self, user_args: UserArgsSlim) -> Result<Vec<Score>, UserArgsError> {
+        Context::osu_scores()
+            .top(self.limit, self.legacy_scores)
+            .exec(user_args)
+            .await
+    }
+}
+
+pub struct RecentScores {
+    pub legacy_scores: bool,
+    pub include_fails: bool,
+}
+
+impl ScoreSource for RecentScores {
+    fn label(&self) -> &'static str {
+        "recent scores"
+    }
+
+    fn capabilities(&self) -> SourceCapabilities {
+        SourceCapabilities {
+            max_scores: 100,
+            fails: self.include_fails,
+        }
+    }
+
+    async fn fetch(&self, user_args: UserArgsSlim) -> Result<Vec<Score>, UserArgsError> {
+        Context::osu_scores()
+            .recent(self.legacy_scores)
+            .limit(100)
+            .include_fails(self.include_fails)
+            .exec(user_args)
+            .await
+    }
+}
+
+pub struct PinnedScores {
+    pub legacy_scores: bool,
+}
+
+impl ScoreSource for PinnedScores {
+    fn label(&self) -> &'static str {
+        "pinned scores"
+    }
+
+    fn capabilities(&self) -> SourceCapabilities {
+        SourceCapabilities {
+            max_scores: 100,
+            fails: false,
+        }
+    }
+
+    async fn fetch(&self, user_args: UserArgsSlim) -> Result<Vec<Score>, UserArgsError> {
+        Context::osu_scores()
+            .pinned(self.legacy_scores)
+            .limit(100)
+            .exec(user_args)
+            .await
+    }
+}
+
+/// Retrieve a user and their scores from the source.
+///
+/// If the user could not be found or the retrieval failed, an error response
+/// is sent and `None` is returned.
+pub async fn fetch_scores(
+    orig: &CommandOrigin<'_>,
+    source: &impl ScoreSource,
+    user_id: UserId,
+    mode: GameMode,
+) -> Result<Option<(CachedUser, Vec<Score>)>> {
+    let user_args = UserArgs::rosu_id(&user_id, mode).await;
+
+    match source.fetch_with_user(user_args).await {
+        Ok(tuple) => Ok(Some(tuple)),
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+            orig.error(content).await?;
+
+            Ok(None)
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err =
+                Report::new(err).wrap_err(format!("Failed to get user or {}", source.label()));
+
+            Err(err)
+        }
+    }
+}
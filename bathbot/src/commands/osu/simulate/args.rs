@@ -48,7 +48,9 @@ impl SimulateArg {
             Some("acc" | "a" | "accuracy") => parse_acc(rest).map(SimulateArg::Acc),
             Some("bpm") => parse_bpm(rest).map(SimulateArg::Bpm),
             Some("combo" | "c") => parse_combo(rest).map(SimulateArg::Combo),
-            Some("clockrate" | "cr" | "rate") => parse_clock_rate(rest).map(SimulateArg::ClockRate),
+            Some("clockrate" | "clock_rate" | "cr" | "rate") => {
+                parse_clock_rate(rest).map(SimulateArg::ClockRate)
+            }
             Some("n300") => parse_n300(rest).map(SimulateArg::N300),
             Some("n100") => parse_n100(rest).map(SimulateArg::N100),
             Some("n50") => parse_n50(rest).map(SimulateArg::N50),
@@ -57,11 +59,15 @@ impl SimulateArg {
             Some("cs") => parse_cs(rest).map(SimulateArg::Cs),
             Some("hp") => parse_hp(rest).map(SimulateArg::Hp),
             Some("od") => parse_od(rest).map(SimulateArg::Od),
-            Some("slider_ends" | "sliderends") => {
+            Some("slider_ends" | "sliderends" | "slider_end_hits") => {
                 parse_slider_ends(rest).map(SimulateArg::SliderEnds)
             }
-            Some("largeticks") => parse_large_ticks(rest).map(SimulateArg::LargeTicks),
-            Some("smallticks") => parse_small_ticks(rest).map(SimulateArg::SmallTicks),
+            Some("largeticks" | "large_ticks" | "large_tick_hits") => {
+                parse_large_ticks(rest).map(SimulateArg::LargeTicks)
+            }
+            Some("smallticks" | "small_ticks") => {
+                parse_small_ticks(rest).map(SimulateArg::SmallTicks)
+            }
             Some("lazer") => parse_lazer(rest, ParseError::Lazer).map(SimulateArg::Lazer),
             Some("stable") => parse_lazer(rest, ParseError::Stable)
                 .map(<bool as std::ops::Not>::not)
@@ -82,7 +88,9 @@ impl SimulateArg {
 }
 
 fn parse_key(input: &str) -> IResult<&str, Option<&str>> {
-    opt(terminated(ch::alphanumeric1, ch::char('=')))(input)
+    let key = by::take_while1(|c: char| c.is_ascii_alphanumeric() || c == '_');
+
+    opt(terminated(key, ch::char('=')))(input)
 }
 
 fn parse_any(input: &str) -> Result<SimulateArg, ParseError> {
@@ -598,6 +606,30 @@ mod tests {
             SimulateArg::parse("sliderends=123x100"),
             Err(ParseError::SliderEnds)
         );
+        assert_eq!(
+            SimulateArg::parse("slider_ends=123"),
+            Ok(SimulateArg::SliderEnds(123))
+        );
+    }
+
+    #[test]
+    fn slash_option_names() {
+        assert_eq!(
+            SimulateArg::parse("clock_rate=1.1"),
+            Ok(SimulateArg::ClockRate(1.1))
+        );
+        assert_eq!(
+            SimulateArg::parse("slider_end_hits=42"),
+            Ok(SimulateArg::SliderEnds(42))
+        );
+        assert_eq!(
+            SimulateArg::parse("large_tick_hits=7"),
+            Ok(SimulateArg::LargeTicks(7))
+        );
+        assert_eq!(
+            SimulateArg::parse("small_ticks=3"),
+            Ok(SimulateArg::SmallTicks(3))
+        );
     }
 
     #[test]
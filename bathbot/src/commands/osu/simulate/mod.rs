@@ -1,5 +1,6 @@
 pub mod args;
 pub mod parsed_map;
+pub mod replay;
 
 use std::borrow::Cow;
 
@@ -11,19 +12,19 @@ use bathbot_util::{
     matcher,
     osu::{MapIdType, ModSelection},
 };
-use eyre::Result;
-use rosu_v2::prelude::{GameMode, GameModsIntermode};
+use eyre::{Report, Result};
+use rosu_v2::prelude::{GameMode, GameModsIntermode, OsuError};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::{
     channel::{Attachment, Message},
     guild::Permissions,
 };
 
-use self::args::{ParseError, SimulateArg};
-use super::{
-    HasMods, ModsResult, TopOldCatchVersion, TopOldManiaVersion, TopOldOsuVersion,
-    TopOldTaikoVersion,
+use self::{
+    args::{ParseError, SimulateArg},
+    replay::ReplayHeader,
 };
+use super::{HasMods, ModsResult};
 use crate::{
     active::{
         ActiveMessages,
@@ -86,7 +87,12 @@ pub struct Simulate<'m> {
     hp: Option<f32>,
     #[command(desc = "Overwrite the map's overall difficulty")]
     od: Option<f32>,
-    #[command(desc = "Specify a .osu file")]
+    #[command(
+        desc = "Specify a .osu file or a .osr replay",
+        help = "Specify a .osu file or a .osr replay.\n\
+        A replay's mods, hit results, and combo will be used unless other \
+        arguments are specified."
+    )]
     file: Option<Attachment>,
 }
 
@@ -96,7 +102,7 @@ pub async fn slash_simulate(mut command: InteractionCommand) -> Result<()> {
 
     match SimulateArgs::from_simulate(args) {
         Ok(args) => simulate(orig, args).await,
-        Err(content) => orig.error(content).await,
+        Err(content) => orig.error_with_help(content).await,
     }
 }
 
@@ -104,6 +110,32 @@ async fn simulate(orig: CommandOrigin<'_>, mut args: SimulateArgs) -> Result<()>
     let owner = orig.user_id()?;
     let config = Context::user_config().with_osu_id(owner).await?;
 
+    if let Some(SimulateMapArg::Replay(ref attachment)) = args.map {
+        let Some(header) = ReplayHeader::from_attachment(&orig, attachment).await? else {
+            return Ok(());
+        };
+
+        let map_fut =
+            Context::osu_request(|osu| osu.beatmap().checksum(header.map_checksum.as_ref()));
+
+        let map_id = match map_fut.await {
+            Ok(map) => map.map_id,
+            Err(OsuError::NotFound) => {
+                let content = "Could not find the beatmap of the replay";
+
+                return orig.error(content).await;
+            }
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(Report::new(err).wrap_err("Failed to get beatmap by checksum"));
+            }
+        };
+
+        args.fill_from_replay(header);
+        args.map = Some(SimulateMapArg::Id(MapIdType::Map(map_id)));
+    }
+
     let map = args.map.take();
     let mode = args.mode.or(config.mode);
 
@@ -114,12 +146,7 @@ async fn simulate(orig: CommandOrigin<'_>, mut args: SimulateArgs) -> Result<()>
     let mode = map.mode();
     debug!(?map, ?mode, "Processing simulate command...");
 
-    let version = match mode {
-        GameMode::Osu => TopOldVersion::Osu(TopOldOsuVersion::March25Now),
-        GameMode::Taiko => TopOldVersion::Taiko(TopOldTaikoVersion::March25Now),
-        GameMode::Catch => TopOldVersion::Catch(TopOldCatchVersion::October24Now),
-        GameMode::Mania => TopOldVersion::Mania(TopOldManiaVersion::October24Now),
-    };
+    let version = TopOldVersion::latest(mode);
 
     let max_combo = match map {
         SimulateMap::Full(ref map) => match Context::pp(map).difficulty().await {
@@ -230,7 +257,7 @@ async fn prefix_simulate(
 
     match SimulateArgs::from_args(None, msg, args).await {
         Ok(args) => simulate(orig, args).await,
-        Err(content) => orig.error(content).await,
+        Err(content) => orig.error_with_help(content).await,
     }
 }
 
@@ -270,7 +297,7 @@ async fn prefix_simulatetaiko(
 
     match SimulateArgs::from_args(Some(GameMode::Taiko), msg, args).await {
         Ok(args) => simulate(orig, args).await,
-        Err(content) => orig.error(content).await,
+        Err(content) => orig.error_with_help(content).await,
     }
 }
 
@@ -312,7 +339,7 @@ async fn prefix_simulatectb(
 
     match SimulateArgs::from_args(Some(GameMode::Catch), msg, args).await {
         Ok(args) => simulate(orig, args).await,
-        Err(content) => orig.error(content).await,
+        Err(content) => orig.error_with_help(content).await,
     }
 }
 
@@ -356,7 +383,7 @@ async fn prefix_simulatemania(
 
     match SimulateArgs::from_args(Some(GameMode::Mania), msg, args).await {
         Ok(args) => simulate(orig, args).await,
-        Err(content) => orig.error(content).await,
+        Err(content) => orig.error_with_help(content).await,
     }
 }
 
@@ -377,6 +404,8 @@ async fn prepare_map(
                 .await
                 .map(|opt| opt.map(SimulateMap::Attached));
         }
+        // Replays are resolved into a map id beforehand
+        Some(SimulateMapArg::Replay(_)) => unreachable!(),
         None => {
             let msgs = match Context::retrieve_channel_history(orig.channel_id()).await {
                 Ok(msgs) => msgs,
@@ -427,6 +456,7 @@ async fn prepare_map(
 enum SimulateMapArg {
     Id(MapIdType),
     Attachment(Box<Attachment>),
+    Replay(Box<Attachment>),
 }
 
 #[derive(Default)]
@@ -513,6 +543,23 @@ impl SimulateArgs {
         Ok(simulate)
     }
 
+    /// Use the replay's values for all arguments that were not specified.
+    fn fill_from_replay(&mut self, header: ReplayHeader) {
+        self.mode.get_or_insert(header.mode);
+        self.mods.get_or_insert(header.mods);
+        self.combo.get_or_insert(header.max_combo as u32);
+        self.n300.get_or_insert(header.n300 as u32);
+        self.n100.get_or_insert(header.n100 as u32);
+        self.n50.get_or_insert(header.n50 as u32);
+        self.geki.get_or_insert(header.n_geki as u32);
+        self.katu.get_or_insert(header.n_katu as u32);
+        self.misses.get_or_insert(header.n_miss as u32);
+
+        // Replay files don't contain lazer-only statistics like slider end
+        // hits so the score is simulated as stable score
+        self.set_on_lazer.get_or_insert(false);
+    }
+
     fn from_simulate(simulate: Simulate<'_>) -> Result<Self, &'static str> {
         let mods = match simulate.mods() {
             ModsResult::Mods(ModSelection::Include(mods) | ModSelection::Exact(mods)) => Some(mods),
@@ -533,6 +580,9 @@ impl SimulateArgs {
         let mode = simulate.mode.map(GameMode::from);
 
         let map = match simulate.file {
+            Some(attachment) if attachment.filename.ends_with(".osr") => {
+                Some(SimulateMapArg::Replay(Box::new(attachment)))
+            }
             Some(attachment) => Some(SimulateMapArg::Attachment(Box::new(attachment))),
             None => match simulate.map {
                 Some(map) => matcher::get_osu_map_id(&map)
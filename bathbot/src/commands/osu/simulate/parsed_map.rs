@@ -18,39 +18,10 @@ impl AttachedSimulateMap {
         attachment: Box<Attachment>,
         mode: Option<GameMode>,
     ) -> Result<Option<Self>> {
-        if !attachment.filename.ends_with(".osu") {
-            let content = "The attached file must be of type .osu";
-            orig.error(content).await?;
-
+        let Some(pp_map) = parse_attached_map(orig, &attachment, mode).await? else {
             return Ok(None);
-        }
-
-        let bytes = match Context::client().get_discord_attachment(&attachment).await {
-            Ok(bytes) => bytes,
-            Err(err) => {
-                let _ = orig.error(GENERAL_ISSUE).await;
-
-                return Err(err.wrap_err("Failed to download attachment"));
-            }
-        };
-
-        let mut pp_map = match Beatmap::from_bytes(&bytes) {
-            Ok(map) => map,
-            Err(err) => {
-                debug!(err = ?Report::new(err), "Failed to parse attachment as beatmap");
-
-                let content = "Failed to parse file. Be sure you provide a valid .osu file.";
-                orig.error(content).await?;
-
-                return Ok(None);
-            }
         };
 
-        if let Some(mode) = mode {
-            // TODO: use mods
-            let _ = pp_map.convert_mut((mode as u8).into(), &Default::default());
-        }
-
         let max_combo = if pp_map.check_suspicion().is_ok() {
             Difficulty::new().calculate(&pp_map).max_combo()
         } else {
@@ -64,3 +35,49 @@ impl AttachedSimulateMap {
         }))
     }
 }
+
+/// Download the attachment and parse it as a beatmap, converting it to the
+/// given mode if specified.
+///
+/// If the attachment is not a valid .osu file, the user is notified and `None`
+/// is returned.
+pub async fn parse_attached_map(
+    orig: &CommandOrigin<'_>,
+    attachment: &Attachment,
+    mode: Option<GameMode>,
+) -> Result<Option<Beatmap>> {
+    if !attachment.filename.ends_with(".osu") {
+        let content = "The attached file must be of type .osu";
+        orig.error(content).await?;
+
+        return Ok(None);
+    }
+
+    let bytes = match Context::client().get_discord_attachment(attachment).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to download attachment"));
+        }
+    };
+
+    let mut pp_map = match Beatmap::from_bytes(&bytes) {
+        Ok(map) => map,
+        Err(err) => {
+            debug!(err = ?Report::new(err), "Failed to parse attachment as beatmap");
+
+            let content = "Failed to parse file. Be sure you provide a valid .osu file.";
+            orig.error(content).await?;
+
+            return Ok(None);
+        }
+    };
+
+    if let Some(mode) = mode {
+        // TODO: use mods
+        let _ = pp_map.convert_mut((mode as u8).into(), &Default::default());
+    }
+
+    Ok(Some(pp_map))
+}
@@ -0,0 +1,201 @@
+use bathbot_util::constants::GENERAL_ISSUE;
+use eyre::Result;
+use nom::{
+    IResult, Parser,
+    bytes::complete as by,
+    combinator::{map_res, verify},
+    number::complete as num,
+};
+use rosu_v2::prelude::{GameMode, GameModsIntermode};
+use twilight_model::channel::Attachment;
+
+use crate::core::{Context, commands::CommandOrigin};
+
+/// The header of a .osr replay file i.e. everything up to the mods.
+#[derive(Debug, PartialEq)]
+pub struct ReplayHeader {
+    pub mode: GameMode,
+    pub map_checksum: Box<str>,
+    pub username: Box<str>,
+    pub n300: u16,
+    pub n100: u16,
+    pub n50: u16,
+    pub n_geki: u16,
+    pub n_katu: u16,
+    pub n_miss: u16,
+    pub score: u32,
+    pub max_combo: u16,
+    pub mods: GameModsIntermode,
+}
+
+impl ReplayHeader {
+    /// Download the attachment and parse it as a replay.
+    ///
+    /// If the attachment is not a valid .osr file, the user is notified and
+    /// `None` is returned.
+    pub async fn from_attachment(
+        orig: &CommandOrigin<'_>,
+        attachment: &Attachment,
+    ) -> Result<Option<Self>> {
+        let bytes = match Context::client().get_discord_attachment(attachment).await {
+            Ok(bytes) => bytes,
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("Failed to download attachment"));
+            }
+        };
+
+        match Self::parse(&bytes) {
+            Some(header) => Ok(Some(header)),
+            None => {
+                debug!(
+                    filename = attachment.filename.as_str(),
+                    "Failed to parse replay header"
+                );
+
+                let content = "Failed to parse file. Be sure you provide a valid .osr file.";
+                orig.error(content).await?;
+
+                Ok(None)
+            }
+        }
+    }
+
+    pub fn parse(bytes: &[u8]) -> Option<Self> {
+        parse_header(bytes).ok().map(|(_, header)| header)
+    }
+}
+
+fn parse_header(input: &[u8]) -> IResult<&[u8], ReplayHeader> {
+    let (input, mode) = verify(num::le_u8, |mode| *mode <= 3).parse(input)?;
+    let (input, _version) = num::le_i32(input)?;
+    let (input, map_checksum) = parse_string(input)?;
+    let (input, username) = parse_string(input)?;
+    let (input, _replay_checksum) = parse_string(input)?;
+    let (input, n300) = num::le_u16(input)?;
+    let (input, n100) = num::le_u16(input)?;
+    let (input, n50) = num::le_u16(input)?;
+    let (input, n_geki) = num::le_u16(input)?;
+    let (input, n_katu) = num::le_u16(input)?;
+    let (input, n_miss) = num::le_u16(input)?;
+    let (input, score) = num::le_u32(input)?;
+    let (input, max_combo) = num::le_u16(input)?;
+    let (input, _perfect) = num::le_u8(input)?;
+    let (input, mods) = num::le_u32(input)?;
+
+    let header = ReplayHeader {
+        mode: GameMode::from(mode),
+        map_checksum,
+        username,
+        n300,
+        n100,
+        n50,
+        n_geki,
+        n_katu,
+        n_miss,
+        score,
+        max_combo,
+        mods: GameModsIntermode::from_bits(mods),
+    };
+
+    Ok((input, header))
+}
+
+/// Strings are either a single `0x00` byte if they're absent, or a `0x0b`
+/// byte followed by the ULEB128 encoded length and the UTF-8 content.
+fn parse_string(input: &[u8]) -> IResult<&[u8], Box<str>> {
+    let (input, indicator) = verify(num::le_u8, |byte| matches!(byte, 0x00 | 0x0b)).parse(input)?;
+
+    if indicator == 0x00 {
+        return Ok((input, Box::default()));
+    }
+
+    let (input, len) = parse_uleb128(input)?;
+
+    map_res(by::take(len), |bytes: &[u8]| {
+        std::str::from_utf8(bytes).map(Box::from)
+    })
+    .parse(input)
+}
+
+fn parse_uleb128(mut input: &[u8]) -> IResult<&[u8], usize> {
+    let mut value = 0;
+    let mut shift = 0;
+
+    loop {
+        let (rest, byte) = verify(num::le_u8, |_| shift < usize::BITS).parse(input)?;
+        input = rest;
+        value |= ((byte & 0x7f) as usize) << shift;
+
+        if byte & 0x80 == 0 {
+            return Ok((input, value));
+        }
+
+        shift += 7;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use rosu_v2::prelude::mods;
+
+    use super::*;
+
+    fn push_string(bytes: &mut Vec<u8>, s: &str) {
+        bytes.push(0x0b);
+        bytes.push(s.len() as u8);
+        bytes.extend_from_slice(s.as_bytes());
+    }
+
+    #[test]
+    fn header() {
+        let mut bytes = vec![1];
+        bytes.extend_from_slice(&20250107_i32.to_le_bytes());
+        push_string(&mut bytes, "a5b99395a42bd55bc5eb1d2411cbdf8b");
+        push_string(&mut bytes, "peppy");
+        bytes.push(0x00);
+
+        for count in [1000_u16, 20, 3, 4, 5, 6] {
+            bytes.extend_from_slice(&count.to_le_bytes());
+        }
+
+        bytes.extend_from_slice(&1_234_567_u32.to_le_bytes());
+        bytes.extend_from_slice(&789_u16.to_le_bytes());
+        bytes.push(0);
+        bytes.extend_from_slice(&(8_u32 | 16).to_le_bytes());
+
+        let expected = ReplayHeader {
+            mode: GameMode::Taiko,
+            map_checksum: Box::from("a5b99395a42bd55bc5eb1d2411cbdf8b"),
+            username: Box::from("peppy"),
+            n300: 1000,
+            n100: 20,
+            n50: 3,
+            n_geki: 4,
+            n_katu: 5,
+            n_miss: 6,
+            score: 1_234_567,
+            max_combo: 789,
+            mods: mods!(HD HR),
+        };
+
+        assert_eq!(ReplayHeader::parse(&bytes), Some(expected));
+    }
+
+    #[test]
+    fn invalid_mode() {
+        assert_eq!(ReplayHeader::parse(&[4, 0, 0, 0, 0]), None);
+    }
+
+    #[test]
+    fn long_string() {
+        let mut bytes = vec![0x0b, 0xac, 0x02];
+        bytes.extend(std::iter::repeat_n(b'a', 300));
+
+        let (rest, s) = parse_string(&bytes).unwrap();
+
+        assert!(rest.is_empty());
+        assert_eq!(s.len(), 300);
+    }
+}
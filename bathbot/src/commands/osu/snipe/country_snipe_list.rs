@@ -38,6 +38,7 @@ use crate::{
 #[usage("[country acronym] [sort=count/pp/stars/weighted]")]
 #[example("sort=stars", "fr sort=weighted", "sort=pp")]
 #[aliases("csl", "countrysnipeleaderboard", "cslb")]
+#[flags(BANCHO_ONLY)]
 #[group(Osu)]
 async fn prefix_countrysnipelist(msg: &Message, args: Args<'_>) -> Result<()> {
     match SnipeCountryList::args(args, GameMode::Osu) {
@@ -74,6 +75,7 @@ async fn prefix_countrysnipelist(msg: &Message, args: Args<'_>) -> Result<()> {
     "countrysnipeleaderboardcatch",
     "cslbc"
 )]
+#[flags(BANCHO_ONLY)]
 #[group(Catch)]
 async fn prefix_countrysnipelistctb(msg: &Message, args: Args<'_>) -> Result<()> {
     match SnipeCountryList::args(args, GameMode::Catch) {
@@ -104,6 +106,7 @@ async fn prefix_countrysnipelistctb(msg: &Message, args: Args<'_>) -> Result<()>
 #[usage("[country acronym] [sort=count/pp/stars/weighted]")]
 #[example("sort=stars", "fr sort=weighted", "sort=pp")]
 #[aliases("cslm", "countrysnipeleaderboardmania", "cslbm")]
+#[flags(BANCHO_ONLY)]
 #[group(Mania)]
 async fn prefix_countrysnipelistmania(msg: &Message, args: Args<'_>) -> Result<()> {
     match SnipeCountryList::args(args, GameMode::Mania) {
@@ -35,6 +35,7 @@ use crate::{
 #[usage("[country acronym]")]
 #[examples("fr")]
 #[alias("css")]
+#[flags(BANCHO_ONLY)]
 #[group(Osu)]
 async fn prefix_countrysnipestats(
     msg: &Message,
@@ -61,6 +62,7 @@ async fn prefix_countrysnipestats(
 #[usage("[country acronym]")]
 #[examples("fr")]
 #[alias("cssc", "countrysnipestatscatch")]
+#[flags(BANCHO_ONLY)]
 #[group(Catch)]
 async fn prefix_countrysnipestatsctb(
     msg: &Message,
@@ -87,6 +89,7 @@ async fn prefix_countrysnipestatsctb(
 #[usage("[country acronym]")]
 #[examples("fr")]
 #[alias("cssm")]
+#[flags(BANCHO_ONLY)]
 #[group(Mania)]
 async fn prefix_countrysnipestatsmania(
     msg: &Message,
@@ -33,6 +33,7 @@ pub mod sniped;
     - osu!mania: [kittenroleplay](https://snipes.kittenroleplay.com)\n\
     Note that the data usually __updates once per week__."
 )]
+#[flags(BANCHO_ONLY)]
 pub enum Snipe<'a> {
     #[command(name = "country")]
     Country(SnipeCountry<'a>),
@@ -194,6 +195,7 @@ pub struct SnipePlayerStats<'a> {
     desc = "Sniped users of the last 8 weeks",
     help = "Display who sniped and was sniped the most by a user in last 8 weeks"
 )]
+#[flags(BANCHO_ONLY)]
 pub struct SnipePlayerSniped<'a> {
     #[command(desc = "Specify a gamemode")]
     mode: Option<SnipeGameMode>,
@@ -39,6 +39,7 @@ use crate::{
 #[usage("[username] [+mods] [sort=acc/stars/misses/scoredate] [reverse=true/false]")]
 #[examples("badewanne3 +dt sort=acc reverse=true", "+hdhr sort=scoredate")]
 #[alias("psl")]
+#[flags(BANCHO_ONLY)]
 #[group(Osu)]
 async fn prefix_playersnipelist(msg: &Message, args: Args<'_>) -> Result<()> {
     match SnipePlayerList::args(args, GameMode::Osu) {
@@ -68,6 +69,7 @@ async fn prefix_playersnipelist(msg: &Message, args: Args<'_>) -> Result<()> {
 #[usage("[username] [sort=acc/stars/misses/scoredate] [reverse=true/false]")]
 #[examples("badewanne3 sort=acc reverse=true", "sort=scoredate")]
 #[alias("pslc", "playersnipelistcatch")]
+#[flags(BANCHO_ONLY)]
 #[group(Catch)]
 async fn prefix_playersnipelistctb(msg: &Message, args: Args<'_>) -> Result<()> {
     match SnipePlayerList::args(args, GameMode::Catch) {
@@ -97,6 +99,7 @@ async fn prefix_playersnipelistctb(msg: &Message, args: Args<'_>) -> Result<()>
 #[usage("[username] [sort=acc/stars/misses/scoredate] [reverse=true/false]")]
 #[examples("badewanne3 sort=acc reverse=true", "sort=scoredate")]
 #[alias("pslm")]
+#[flags(BANCHO_ONLY)]
 #[group(Mania)]
 async fn prefix_playersnipelistmania(msg: &Message, args: Args<'_>) -> Result<()> {
     match SnipePlayerList::args(args, GameMode::Mania) {
@@ -35,6 +35,7 @@ use crate::{
 #[usage("[username]")]
 #[example("badewanne3")]
 #[alias("pss")]
+#[flags(BANCHO_ONLY)]
 #[group(Osu)]
 async fn prefix_playersnipestats(
     msg: &Message,
@@ -56,6 +57,7 @@ async fn prefix_playersnipestats(
 #[usage("[username]")]
 #[example("badewanne3")]
 #[alias("pssc", "playersnipestatscatch")]
+#[flags(BANCHO_ONLY)]
 #[group(Catch)]
 async fn prefix_playersnipestatsctb(
     msg: &Message,
@@ -77,6 +79,7 @@ async fn prefix_playersnipestatsctb(
 #[usage("[username]")]
 #[example("badewanne3")]
 #[alias("pssm")]
+#[flags(BANCHO_ONLY)]
 #[group(Mania)]
 async fn prefix_playersnipestatsmania(
     msg: &Message,
@@ -36,6 +36,7 @@ use crate::{
 #[usage("[username]")]
 #[example("badewanne3")]
 #[alias("snipes")]
+#[flags(BANCHO_ONLY)]
 #[group(Osu)]
 async fn prefix_sniped(
     msg: &Message,
@@ -57,6 +58,7 @@ async fn prefix_sniped(
 #[usage("[username]")]
 #[example("badewanne3")]
 #[alias("snipedc", "snipedcatch", "snipesctb", "snipescatch")]
+#[flags(BANCHO_ONLY)]
 #[group(Catch)]
 async fn prefix_snipedctb(
     msg: &Message,
@@ -78,6 +80,7 @@ async fn prefix_snipedctb(
 #[usage("[username]")]
 #[example("badewanne3")]
 #[alias("snipedm", "snipesmania")]
+#[flags(BANCHO_ONLY)]
 #[group(Mania)]
 async fn prefix_snipedmania(
     msg: &Message,
@@ -24,6 +24,7 @@ use crate::{
 #[usage("[username]")]
 #[example("badewanne3")]
 #[aliases("sg", "snipegain", "snipesgain")]
+#[flags(BANCHO_ONLY)]
 #[group(Osu)]
 async fn prefix_snipedgain(msg: &Message, args: Args<'_>) -> Result<()> {
     let args = SnipePlayerGain::args(args, None);
@@ -48,6 +49,7 @@ async fn prefix_snipedgain(msg: &Message, args: Args<'_>) -> Result<()> {
     "snipesgainctb",
     "snipesgaincatch"
 )]
+#[flags(BANCHO_ONLY)]
 #[group(Catch)]
 async fn prefix_snipedgainctb(msg: &Message, args: Args<'_>) -> Result<()> {
     let args = SnipePlayerGain::args(args, Some(GameMode::Catch));
@@ -65,6 +67,7 @@ async fn prefix_snipedgainctb(msg: &Message, args: Args<'_>) -> Result<()> {
 #[usage("[username]")]
 #[example("badewanne3")]
 #[aliases("sgm", "snipegainmania", "snipesgainmania")]
+#[flags(BANCHO_ONLY)]
 #[group(Mania)]
 async fn prefix_snipedgainmania(msg: &Message, args: Args<'_>) -> Result<()> {
     let args = SnipePlayerGain::args(args, Some(GameMode::Mania));
@@ -89,6 +92,7 @@ async fn prefix_snipedgainmania(msg: &Message, args: Args<'_>) -> Result<()> {
     "snipelost",
     "snipeslost"
 )]
+#[flags(BANCHO_ONLY)]
 #[group(Osu)]
 async fn prefix_snipedloss(msg: &Message, args: Args<'_>) -> Result<()> {
     let args = SnipePlayerLoss::args(args, None);
@@ -118,6 +122,7 @@ async fn prefix_snipedloss(msg: &Message, args: Args<'_>) -> Result<()> {
     "snipeslostctb",
     "snipeslostcatch"
 )]
+#[flags(BANCHO_ONLY)]
 #[group(Catch)]
 async fn prefix_snipedlossctb(msg: &Message, args: Args<'_>) -> Result<()> {
     let args = SnipePlayerLoss::args(args, Some(GameMode::Catch));
@@ -142,6 +147,7 @@ async fn prefix_snipedlossctb(msg: &Message, args: Args<'_>) -> Result<()> {
     "snipelostmania",
     "snipeslostmania"
 )]
+#[flags(BANCHO_ONLY)]
 #[group(Mania)]
 async fn prefix_snipedlossmania(msg: &Message, args: Args<'_>) -> Result<()> {
     let args = SnipePlayerLoss::args(args, Some(GameMode::Mania));
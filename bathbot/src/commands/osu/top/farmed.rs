@@ -0,0 +1,462 @@
+use std::{
+    borrow::Cow,
+    collections::{BTreeMap, HashMap},
+    fmt::Write,
+};
+
+use bathbot_macros::{HasName, SlashCommand, command};
+use bathbot_model::{FarmSet, FarmSets, OsuStatsBestTimeframe, command_fields::GameModeOption};
+use bathbot_psql::model::configs::ScoreData;
+use bathbot_util::{
+    CowUtils, EmbedBuilder, FooterBuilder, IntHasher, MessageBuilder, attachment,
+    constants::{GENERAL_ISSUE, OSU_BASE},
+    matcher,
+    numbers::round,
+    units::Pp,
+};
+use eyre::{Report, Result, WrapErr};
+use rosu_v2::{
+    prelude::{GameMode, OsuError},
+    request::UserId,
+};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    channel::message::embed::EmbedField,
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    Context,
+    commands::{
+        DISCORD_OPTION_DESC, DISCORD_OPTION_HELP,
+        osu::{GraphPalette, GraphRenderer, PieChart, require_link, user_not_found},
+    },
+    core::commands::{CommandOrigin, prefix::Args},
+    manager::redis::osu::{UserArgs, UserArgsError},
+    util::{CachedUserExt, InteractionCommandExt, interaction::InteractionCommand},
+};
+
+const W: u32 = 1000;
+const H: u32 = 600;
+
+/// How many repeated mapsets are listed at most
+const MAPSET_COUNT: usize = 5;
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "topfarmed",
+    desc = "Check how much of a user's top200 comes from farm maps",
+    help = "Group a user's top200 by mapset, ranked year, and whether the mapset \
+    is currently a farm set.\n\
+    The shares are based on weighted pp so top plays matter more than the bottom ones.\n\
+    Farm sets are the mapsets on which multiple users set one of the best scores of the \
+    last month according to [osu!stats](https://osustats.ppy.sh/)."
+)]
+pub struct TopFarmed<'a> {
+    #[command(desc = "Specify a gamemode")]
+    mode: Option<GameModeOption>,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
+    discord: Option<Id<UserMarker>>,
+}
+
+async fn slash_topfarmed(mut command: InteractionCommand) -> Result<()> {
+    let args = TopFarmed::from_interaction(command.input_data())?;
+
+    topfarmed((&mut command).into(), args).await
+}
+
+#[command]
+#[desc("Check how much of a user's top200 comes from farm maps")]
+#[help(
+    "Group a user's top200 by mapset, ranked year, and whether the mapset \
+    is currently a farm set.\n\
+    The shares are based on weighted pp so top plays matter more than the bottom ones.\n\
+    Farm sets are the mapsets on which multiple users set one of the best scores of the \
+    last month according to [osu!stats](https://osustats.ppy.sh/)."
+)]
+#[usage("[username]")]
+#[example("badewanne3")]
+#[alias("tfarm")]
+#[group(Osu)]
+async fn prefix_topfarmed(msg: &Message, mut args: Args<'_>) -> Result<()> {
+    let mut name = None;
+    let mut discord = None;
+
+    if let Some(arg) = args.next() {
+        match matcher::get_mention_user(arg) {
+            Some(id) => discord = Some(id),
+            None => name = Some(Cow::Borrowed(arg)),
+        }
+    }
+
+    let args = TopFarmed {
+        mode: None,
+        name,
+        discord,
+    };
+
+    topfarmed(msg.into(), args).await
+}
+
+async fn topfarmed(orig: CommandOrigin<'_>, args: TopFarmed<'_>) -> Result<()> {
+    let owner = orig.user_id()?;
+    let config = Context::user_config().with_osu_id(owner).await?;
+
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match config.osu {
+            Some(user_id) => UserId::Id(user_id),
+            None => return require_link(&orig).await,
+        },
+    };
+
+    let mode = args
+        .mode
+        .map(GameMode::from)
+        .or(config.mode)
+        .unwrap_or(GameMode::Osu);
+
+    let legacy_scores = match config.score_data {
+        Some(score_data) => score_data.is_legacy(),
+        None => match orig.guild_id() {
+            Some(guild_id) => Context::guild_config()
+                .peek(guild_id, |config| config.score_data)
+                .await
+                .is_some_and(ScoreData::is_legacy),
+            None => false,
+        },
+    };
+
+    let user_args = UserArgs::rosu_id(&user_id, mode).await;
+    let scores_fut = Context::osu_scores()
+        .top(200, legacy_scores)
+        .exec_with_user(user_args);
+
+    let (user, scores) = match scores_fut.await {
+        Ok(tuple) => tuple,
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user or scores");
+
+            return Err(err);
+        }
+    };
+
+    if scores.is_empty() {
+        return orig.error("User's top scores are empty").await;
+    }
+
+    let maps_id_checksum = scores
+        .iter()
+        .map(|score| {
+            (
+                score.map_id as i32,
+                score.map.as_ref().and_then(|map| map.checksum.as_deref()),
+            )
+        })
+        .collect();
+
+    let maps = match Context::osu_map().maps(&maps_id_checksum).await {
+        Ok(maps) => maps,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get maps"));
+        }
+    };
+
+    let best_fut = Context::redis().osustats_best(OsuStatsBestTimeframe::LastMonth, mode);
+
+    let farm_sets = match best_fut.await {
+        Ok(best) => FarmSets::new(&best.scores),
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get osustats best scores"));
+        }
+    };
+
+    let entries = scores.iter().filter_map(|score| {
+        let map = maps.get(&score.map_id)?;
+
+        Some(FarmEntry {
+            mapset_id: map.mapset_id(),
+            ranked_year: map.ranked_date().map(|datetime| datetime.year()),
+            pp: score.weight.map_or(0.0, |weight| weight.pp),
+        })
+    });
+
+    let summary = FarmSummary::new(entries, &farm_sets);
+
+    let username = user.username.as_str();
+    let farm_share = summary.farm.pp / summary.total.pp.max(1.0) * 100.0;
+
+    let mut description = format!(
+        "**{farm_share}%** of the weighted top200 pp (`{farm_pp}pp`) comes from current farm sets \
+        ({farm_count} of {total_count} scores)",
+        farm_share = round(farm_share),
+        farm_pp = Pp(summary.farm.pp),
+        farm_count = summary.farm.count,
+        total_count = summary.total.count,
+    );
+
+    for (set, group) in summary.farm_sets.iter() {
+        let _ = write!(
+            description,
+            "\n- [{name}]({OSU_BASE}s/{mapset_id}): {count} score{plural} • `{pp}pp`",
+            name = set.name.cow_escape_markdown(),
+            mapset_id = set.mapset_id,
+            count = group.count,
+            plural = if group.count == 1 { "" } else { "s" },
+            pp = Pp(group.pp),
+        );
+    }
+
+    let mut fields = Vec::with_capacity(2);
+
+    let repeated: Vec<_> = summary
+        .mapsets
+        .iter()
+        .filter(|(_, group)| group.count > 1)
+        .take(MAPSET_COUNT)
+        .collect();
+
+    if !repeated.is_empty() {
+        let mut value = String::with_capacity(repeated.len() * 64);
+
+        for (mapset_id, group) in repeated {
+            let name = maps
+                .values()
+                .find(|map| map.mapset_id() == *mapset_id)
+                .map_or(Cow::Borrowed("?"), |map| {
+                    Cow::Owned(format!(
+                        "{} - {}",
+                        map.artist().cow_escape_markdown(),
+                        map.title().cow_escape_markdown()
+                    ))
+                });
+
+            let _ = writeln!(
+                value,
+                "[{name}]({OSU_BASE}s/{mapset_id}): {count} scores • `{pp}pp`",
+                count = group.count,
+                pp = Pp(group.pp),
+            );
+        }
+
+        fields.push(EmbedField {
+            inline: false,
+            name: "Repeated mapsets".to_owned(),
+            value,
+        });
+    }
+
+    if !summary.years.is_empty() {
+        let mut value = String::with_capacity(summary.years.len() * 32);
+
+        for (year, group) in summary.years.iter() {
+            let _ = writeln!(
+                value,
+                "`{year}`: {count} • `{pp}pp`",
+                count = group.count,
+                pp = Pp(group.pp),
+            );
+        }
+
+        fields.push(EmbedField {
+            inline: false,
+            name: "Ranked years".to_owned(),
+            value,
+        });
+    }
+
+    let mut embed = EmbedBuilder::new()
+        .author(user.author_builder(false))
+        .description(description)
+        .fields(fields)
+        .footer(FooterBuilder::new("Shares are based on weighted pp • Farm sets via osu!stats"))
+        .thumbnail(user.avatar_url.as_ref().to_owned());
+
+    let mut slices: Vec<_> = summary
+        .farm_sets
+        .iter()
+        .map(|(set, group)| (set.name.to_string(), f64::from(group.pp)))
+        .collect();
+
+    slices.push((
+        "Other".to_owned(),
+        f64::from(summary.total.pp - summary.farm.pp),
+    ));
+
+    let chart = PieChart {
+        caption: format!(
+            "{username}'{genitive} top200 pp by farm set",
+            genitive = if username.ends_with('s') { "" } else { "s" },
+        ),
+        slices: &slices,
+    };
+
+    let palette = GraphPalette::new(config.graph_theme.unwrap_or_default());
+    let mut builder = MessageBuilder::new();
+
+    match chart.render(W, H, palette) {
+        Ok(bytes) => {
+            embed = embed.image(attachment("farm.png"));
+            builder = builder.attachment("farm.png", bytes);
+        }
+        Err(err) => warn!(?err, "Failed to render farm pie chart"),
+    }
+
+    orig.create_message(builder.embed(embed)).await?;
+
+    Ok(())
+}
+
+struct FarmEntry {
+    mapset_id: u32,
+    ranked_year: Option<i32>,
+    /// Weighted pp of the score
+    pp: f32,
+}
+
+#[derive(Copy, Clone, Default, Debug, PartialEq)]
+struct FarmGroup {
+    count: usize,
+    pp: f32,
+}
+
+impl FarmGroup {
+    fn add(&mut self, pp: f32) {
+        self.count += 1;
+        self.pp += pp;
+    }
+}
+
+struct FarmSummary {
+    total: FarmGroup,
+    farm: FarmGroup,
+    /// Sorted by count, then pp, both descending
+    mapsets: Vec<(u32, FarmGroup)>,
+    years: BTreeMap<i32, FarmGroup>,
+    /// Sorted by pp descending
+    farm_sets: Vec<(FarmSet, FarmGroup)>,
+}
+
+impl FarmSummary {
+    fn new(entries: impl Iterator<Item = FarmEntry>, farm_sets: &FarmSets) -> Self {
+        let mut total = FarmGroup::default();
+        let mut farm = FarmGroup::default();
+        let mut mapsets = HashMap::<u32, FarmGroup, IntHasher>::default();
+        let mut years = BTreeMap::<i32, FarmGroup>::new();
+
+        for entry in entries {
+            total.add(entry.pp);
+            mapsets.entry(entry.mapset_id).or_default().add(entry.pp);
+
+            if let Some(year) = entry.ranked_year {
+                years.entry(year).or_default().add(entry.pp);
+            }
+
+            if farm_sets.get(entry.mapset_id).is_some() {
+                farm.add(entry.pp);
+            }
+        }
+
+        let mut farm_sets: Vec<_> = mapsets
+            .iter()
+            .filter_map(|(mapset_id, group)| Some((farm_sets.get(*mapset_id)?.clone(), *group)))
+            .collect();
+
+        farm_sets.sort_unstable_by(|(_, a), (_, b)| b.pp.total_cmp(&a.pp));
+
+        let mut mapsets: Vec<_> = mapsets.into_iter().collect();
+
+        mapsets.sort_unstable_by(|(_, a), (_, b)| {
+            b.count.cmp(&a.count).then_with(|| b.pp.total_cmp(&a.pp))
+        });
+
+        Self {
+            total,
+            farm,
+            mapsets,
+            years,
+            farm_sets,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(mapset_id: u32, ranked_year: Option<i32>, pp: f32) -> FarmEntry {
+        FarmEntry {
+            mapset_id,
+            ranked_year,
+            pp,
+        }
+    }
+
+    #[test]
+    fn groups_scores() {
+        let entries = [
+            entry(39804, Some(2011), 400.0),
+            entry(1, Some(2020), 300.0),
+            entry(39804, Some(2011), 200.0),
+            entry(2, None, 100.0),
+        ];
+
+        let farm_sets: FarmSets = [FarmSet {
+            mapset_id: 39804,
+            name: "xi - FREEDOM DiVE".into(),
+            users: 2,
+        }]
+        .into_iter()
+        .collect();
+
+        let summary = FarmSummary::new(entries.into_iter(), &farm_sets);
+
+        assert_eq!(
+            summary.total,
+            FarmGroup {
+                count: 4,
+                pp: 1000.0
+            }
+        );
+        assert_eq!(
+            summary.farm,
+            FarmGroup {
+                count: 2,
+                pp: 600.0
+            }
+        );
+        assert_eq!(
+            summary.mapsets[0],
+            (
+                39804,
+                FarmGroup {
+                    count: 2,
+                    pp: 600.0
+                }
+            )
+        );
+        assert_eq!(summary.mapsets[1].0, 1);
+        assert_eq!(summary.years.len(), 2);
+        assert_eq!(
+            summary.years[&2011],
+            FarmGroup {
+                count: 2,
+                pp: 600.0
+            }
+        );
+        assert_eq!(summary.farm_sets.len(), 1);
+        assert_eq!(summary.farm_sets[0].0.mapset_id, 39804);
+    }
+}
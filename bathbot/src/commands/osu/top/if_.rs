@@ -31,7 +31,7 @@ use crate::{
         OsuMap,
         redis::osu::{UserArgs, UserArgsError},
     },
-    util::{ChannelExt, InteractionCommandExt, interaction::InteractionCommand},
+    util::{InteractionCommandExt, interaction::InteractionCommand},
 };
 
 #[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
@@ -144,11 +144,7 @@ impl<'m> TopIf<'m> {
 async fn prefix_topif(msg: &Message, args: Args<'_>) -> Result<()> {
     match TopIf::args(None, args) {
         Ok(args) => topif(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -168,11 +164,7 @@ async fn prefix_topif(msg: &Message, args: Args<'_>) -> Result<()> {
 async fn prefix_topiftaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     match TopIf::args(Some(GameModeOption::Taiko), args) {
         Ok(args) => topif(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -192,18 +184,14 @@ async fn prefix_topiftaiko(msg: &Message, args: Args<'_>) -> Result<()> {
 async fn prefix_topifctb(msg: &Message, args: Args<'_>) -> Result<()> {
     match TopIf::args(Some(GameModeOption::Catch), args) {
         Ok(args) => topif(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
 async fn topif(orig: CommandOrigin<'_>, args: TopIf<'_>) -> Result<()> {
     let mods = match matcher::get_mods(&args.mods) {
         Some(mods) => mods,
-        None => return orig.error(TopIf::ERR_PARSE_MODS).await,
+        None => return orig.error_with_help(TopIf::ERR_PARSE_MODS).await,
     };
 
     let owner = orig.user_id()?;
@@ -223,7 +211,7 @@ async fn topif(orig: CommandOrigin<'_>, args: TopIf<'_>) -> Result<()> {
     };
 
     if let Err(content) = mods.clone().validate(mode) {
-        return orig.error(content).await;
+        return orig.error_with_help(content).await;
     }
 
     let legacy_scores = match config.score_data {
@@ -410,6 +398,9 @@ impl<'q> Searchable<TopCriteria<'q>> for TopIfEntry {
         .unwrap_or(attrs.cs as f32);
 
         matches &= self.map.mode() != GameMode::Mania || criteria.keys.contains(keys);
+        matches &= self.map.mode() != GameMode::Mania
+            || criteria.ln.is_empty()
+            || criteria.ln.contains(self.map.ln_percent());
 
         if !matches
             || (criteria.length.is_empty()
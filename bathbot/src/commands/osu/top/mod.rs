@@ -11,13 +11,13 @@ use bathbot_util::{
     constants::GENERAL_ISSUE,
     matcher,
     numbers::round,
-    osu::ModSelection,
+    osu::{ModSelection, effective_clock_rate},
     query::{IFilterCriteria, Searchable, TopCriteria},
 };
-use eyre::{Report, Result};
+use eyre::Result;
 use rand::{Rng, thread_rng};
 use rosu_v2::{
-    prelude::{GameMode, Grade, OsuError, Score},
+    prelude::{GameMode, Grade, Score},
     request::UserId,
 };
 use twilight_interactions::command::{CommandModel, CommandOption, CreateCommand, CreateOption};
@@ -26,8 +26,11 @@ use twilight_model::{
     id::{Id, marker::UserMarker},
 };
 
-pub use self::{if_::*, old::*};
-use super::{HasMods, ModsResult, ScoreOrder, map_strains_graph, require_link, user_not_found};
+pub use self::{farmed::*, if_::*, old::*};
+use super::{
+    HasMods, ModsResult, ScoreOrder, map_strains_graph, require_link,
+    score_source::{ScoreSource, TopScores, fetch_scores},
+};
 use crate::{
     Context,
     active::{
@@ -42,10 +45,10 @@ use crate::{
         },
     },
     core::commands::{CommandOrigin, prefix::Args},
-    manager::redis::osu::{UserArgs, UserArgsError},
-    util::{ChannelExt, CheckPermissions, InteractionCommandExt, interaction::InteractionCommand},
+    util::{CheckPermissions, InteractionCommandExt, interaction::InteractionCommand},
 };
 
+mod farmed;
 mod if_;
 mod old;
 
@@ -187,11 +190,7 @@ const TOP_USAGE: &str = "[username] [mods] [acc=number[..number]] \
 async fn prefix_top(msg: &Message, args: Args<'_>) -> Result<()> {
     match TopArgs::args(None, args) {
         Ok(args) => top(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -222,11 +221,7 @@ async fn prefix_top(msg: &Message, args: Args<'_>) -> Result<()> {
 async fn prefix_topmania(msg: &Message, args: Args<'_>) -> Result<()> {
     match TopArgs::args(Some(GameMode::Mania), args) {
         Ok(args) => top(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -257,11 +252,7 @@ async fn prefix_topmania(msg: &Message, args: Args<'_>) -> Result<()> {
 async fn prefix_toptaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     match TopArgs::args(Some(GameMode::Taiko), args) {
         Ok(args) => top(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -292,11 +283,7 @@ async fn prefix_toptaiko(msg: &Message, args: Args<'_>) -> Result<()> {
 async fn prefix_topctb(msg: &Message, args: Args<'_>) -> Result<()> {
     match TopArgs::args(Some(GameMode::Catch), args) {
         Ok(args) => top(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -340,11 +327,7 @@ async fn prefix_recentbest(msg: &Message, args: Args<'_>) -> Result<()> {
 
             top(msg.into(), args).await
         }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -378,11 +361,7 @@ async fn prefix_recentbestmania(msg: &Message, args: Args<'_>) -> Result<()> {
 
             top(msg.into(), args).await
         }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -416,11 +395,7 @@ async fn prefix_recentbesttaiko(msg: &Message, args: Args<'_>) -> Result<()> {
 
             top(msg.into(), args).await
         }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -454,11 +429,7 @@ async fn prefix_recentbestctb(msg: &Message, args: Args<'_>) -> Result<()> {
 
             top(msg.into(), args).await
         }
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -468,9 +439,9 @@ async fn slash_top(mut command: InteractionCommand) -> Result<()> {
     match TopArgs::try_from(args) {
         Ok(args) => top((&mut command).into(), args).await,
         Err(content) => {
-            command.error(content).await?;
-
-            Ok(())
+            CommandOrigin::from(&mut command)
+                .error_with_help(content)
+                .await
         }
     }
 }
@@ -784,24 +755,13 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
     let legacy_scores = score_data.is_legacy();
 
     // Retrieve the user and their top scores
-    let user_args = UserArgs::rosu_id(&user_id, mode).await;
-    let scores_fut = Context::osu_scores()
-        .top(200, legacy_scores)
-        .exec_with_user(user_args);
-
-    let (user, scores) = match scores_fut.await {
-        Ok((user, scores)) => (user, scores),
-        Err(UserArgsError::Osu(OsuError::NotFound)) => {
-            let content = user_not_found(user_id).await;
-
-            return orig.error(content).await;
-        }
-        Err(err) => {
-            let _ = orig.error(GENERAL_ISSUE).await;
-            let err = Report::new(err).wrap_err("Failed to get user or scores");
+    let source = TopScores {
+        limit: 200,
+        legacy_scores,
+    };
 
-            return Err(err);
-        }
+    let Some((user, scores)) = fetch_scores(&orig, &source, user_id, mode).await? else {
+        return Ok(());
     };
 
     let settings = config.score_embed.unwrap_or_default();
@@ -835,7 +795,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
         Some("random" | "?") => (post_len > 0).then(|| thread_rng().gen_range(1..=post_len)),
         Some(n) => match n.parse::<usize>() {
             Ok(n) if n > post_len => {
-                let mut content = format!("`{username}` only has {post_len} top scores");
+                let mut content = format!("`{username}` only has {post_len} {}", source.label());
 
                 if pre_len > post_len {
                     let _ = write!(content, " with the specified properties");
@@ -845,8 +805,11 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
             }
             Ok(n) => Some(n),
             Err(_) => {
-                let content = "Failed to parse index. \
-                Must be an integer between 1 and 200 or `random` / `?`.";
+                let content = format!(
+                    "Failed to parse index. \
+                    Must be an integer between 1 and {} or `random` / `?`.",
+                    source.capabilities().max_scores
+                );
 
                 return orig.error(content).await;
             }
@@ -882,7 +845,7 @@ pub(super) async fn top(orig: CommandOrigin<'_>, args: TopArgs<'_>) -> Result<()
                     let fut = map_strains_graph(
                         &entry.map.pp_map,
                         entry.score.mods.clone(),
-                        entry.map.cover(),
+                        Some(entry.map.cover()),
                         SingleScorePagination::IMAGE_W,
                         SingleScorePagination::IMAGE_H,
                     );
@@ -1033,9 +996,9 @@ async fn process_scores(
         }
         TopScoreOrder::Bpm => entries.sort_by(|a, b| {
             let a_bpm =
-                a.get_half().map.bpm() as f64 * a.get_half().score.mods.clock_rate().unwrap_or(1.0);
+                a.get_half().map.bpm() as f64 * effective_clock_rate(&a.get_half().score.mods);
             let b_bpm =
-                b.get_half().map.bpm() as f64 * b.get_half().score.mods.clock_rate().unwrap_or(1.0);
+                b.get_half().map.bpm() as f64 * effective_clock_rate(&b.get_half().score.mods);
 
             b_bpm.total_cmp(&a_bpm)
         }),
@@ -1054,9 +1017,9 @@ async fn process_scores(
         TopScoreOrder::Length => {
             entries.sort_by(|a, b| {
                 let a_len = a.get_half().map.seconds_drain() as f64
-                    / a.get_half().score.mods.clock_rate().unwrap_or(1.0);
+                    / effective_clock_rate(&a.get_half().score.mods);
                 let b_len = b.get_half().map.seconds_drain() as f64
-                    / b.get_half().score.mods.clock_rate().unwrap_or(1.0);
+                    / effective_clock_rate(&b.get_half().score.mods);
 
                 b_len.total_cmp(&a_len)
             });
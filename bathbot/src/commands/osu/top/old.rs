@@ -34,7 +34,7 @@ use crate::{
         OsuMap,
         redis::osu::{UserArgs, UserArgsError},
     },
-    util::{ChannelExt, InteractionCommandExt, interaction::InteractionCommand},
+    util::{InteractionCommandExt, interaction::InteractionCommand},
 };
 
 #[derive(CommandModel, CreateCommand, SlashCommand)]
@@ -433,11 +433,7 @@ pub async fn slash_topold(mut command: InteractionCommand) -> Result<()> {
 async fn prefix_topold(msg: &Message, args: Args<'_>) -> Result<()> {
     match TopOld::args(GameMode::Osu, args) {
         Ok(args) => topold(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -460,11 +456,7 @@ async fn prefix_topold(msg: &Message, args: Args<'_>) -> Result<()> {
 async fn prefix_topoldmania(msg: &Message, args: Args<'_>) -> Result<()> {
     match TopOld::args(GameMode::Mania, args) {
         Ok(args) => topold(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -488,11 +480,7 @@ async fn prefix_topoldmania(msg: &Message, args: Args<'_>) -> Result<()> {
 async fn prefix_topoldtaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     match TopOld::args(GameMode::Taiko, args) {
         Ok(args) => topold(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
@@ -514,11 +502,7 @@ async fn prefix_topoldtaiko(msg: &Message, args: Args<'_>) -> Result<()> {
 async fn prefix_topoldctb(msg: &Message, args: Args<'_>) -> Result<()> {
     match TopOld::args(GameMode::Catch, args) {
         Ok(args) => topold(msg.into(), args).await,
-        Err(content) => {
-            msg.error(content).await?;
-
-            Ok(())
-        }
+        Err(content) => CommandOrigin::from(msg).error_with_help(content).await,
     }
 }
 
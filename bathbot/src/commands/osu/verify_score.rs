@@ -0,0 +1,332 @@
+use std::{
+    borrow::Cow,
+    fmt::{Display, Formatter, Result as FmtResult, Write},
+};
+
+use bathbot_macros::{HasName, SlashCommand};
+use bathbot_psql::model::configs::ScoreData;
+use bathbot_util::{
+    EmbedBuilder, FooterBuilder, MessageBuilder, ModsFormatter,
+    constants::{GENERAL_ISSUE, OSU_BASE},
+    datetime::HowLongAgoDynamic,
+    matcher,
+    numbers::WithComma,
+    osu::ModSelection,
+};
+use eyre::{Report, Result};
+use rosu_v2::{
+    prelude::{GameMod, GameModsIntermode, OsuError, Score},
+    request::UserId,
+};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use super::{require_link, user_not_found};
+use crate::{
+    Context,
+    commands::{DISCORD_OPTION_DESC, DISCORD_OPTION_HELP},
+    core::commands::CommandOrigin,
+    manager::{
+        MapError,
+        redis::osu::{UserArgs, UserArgsError},
+    },
+    util::{CachedUserExt, InteractionCommandExt, interaction::InteractionCommand},
+};
+
+#[derive(CommandModel, CreateCommand, HasName, SlashCommand)]
+#[command(
+    name = "verifyscore",
+    desc = "Verify a claimed score against the osu! API",
+    help = "Verify that a user actually set a score on a map.\n\
+    The claimed score value is looked up in the map's top 100 leaderboard \
+    and in the user's 100 most recent scores.\n\
+    Both the lazer score and the classic score value are accepted."
+)]
+pub struct VerifyScore<'a> {
+    #[command(desc = "Specify a map url or map id")]
+    map: String,
+    #[command(desc = "Specify the claimed score value", min_value = 0)]
+    score: i64,
+    #[command(desc = "Specify a username")]
+    name: Option<Cow<'a, str>>,
+    #[command(
+        desc = "Specify the claimed mods e.g. hdhr or nm",
+        help = "Specify mods either directly or through the explicit `+mods!` / `+mods` syntax e.g. `hdhr` or `+hdhr!`"
+    )]
+    mods: Option<Cow<'a, str>>,
+    #[command(desc = DISCORD_OPTION_DESC, help = DISCORD_OPTION_HELP)]
+    discord: Option<Id<UserMarker>>,
+}
+
+async fn slash_verifyscore(mut command: InteractionCommand) -> Result<()> {
+    let args = VerifyScore::from_interaction(command.input_data())?;
+
+    verify_score((&mut command).into(), args).await
+}
+
+async fn verify_score(orig: CommandOrigin<'_>, args: VerifyScore<'_>) -> Result<()> {
+    let Some(map_id) = matcher::get_osu_map_id(&args.map) else {
+        let content =
+            "Failed to parse map url. Be sure you specify a valid map id or url to a map.";
+
+        return orig.error(content).await;
+    };
+
+    let claimed_mods = match args.mods.as_deref() {
+        Some(mods) => match matcher::get_mods(mods) {
+            Some(ModSelection::Exact(mods) | ModSelection::Include(mods)) => Some(mods),
+            Some(ModSelection::Exclude { .. }) | None => {
+                let content = "Failed to parse mods. Be sure to either specify them directly \
+                or through the `+mods` / `+mods!` syntax e.g. `hdhr` or `+hdhr!`";
+
+                return orig.error(content).await;
+            }
+        },
+        None => None,
+    };
+
+    let owner = orig.user_id()?;
+
+    let config = match Context::user_config().with_osu_id(owner).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let user_id = match user_id!(orig, args) {
+        Some(user_id) => user_id,
+        None => match config.osu {
+            Some(user_id) => UserId::Id(user_id),
+            None => return require_link(&orig).await,
+        },
+    };
+
+    let legacy_scores = match config.score_data {
+        Some(score_data) => score_data.is_legacy(),
+        None => match orig.guild_id() {
+            Some(guild_id) => Context::guild_config()
+                .peek(guild_id, |config| config.score_data)
+                .await
+                .is_some_and(ScoreData::is_legacy),
+            None => false,
+        },
+    };
+
+    let map = match Context::osu_map().map(map_id, None).await {
+        Ok(map) => map,
+        Err(MapError::NotFound) => {
+            let content = format!(
+                "Could not find beatmap with id `{map_id}`. \
+                Did you give me a mapset id instead of a map id?"
+            );
+
+            return orig.error(content).await;
+        }
+        Err(MapError::Report(err)) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let mode = map.mode();
+    let user_args = UserArgs::rosu_id(&user_id, mode).await;
+
+    let recent_fut = Context::osu_scores()
+        .recent(legacy_scores)
+        .limit(100)
+        .exec_with_user(user_args);
+
+    let leaderboard_fut =
+        Context::osu_scores().map_leaderboard(map_id, mode, None, 100, legacy_scores);
+
+    let (recent_res, leaderboard_res) = tokio::join!(recent_fut, leaderboard_fut);
+
+    let (user, recent_scores) = match recent_res {
+        Ok(tuple) => tuple,
+        Err(UserArgsError::Osu(OsuError::NotFound)) => {
+            let content = user_not_found(user_id).await;
+
+            return orig.error(content).await;
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+            let err = Report::new(err).wrap_err("Failed to get user or recent scores");
+
+            return Err(err);
+        }
+    };
+
+    let leaderboard = match leaderboard_res {
+        Ok(scores) => scores,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get map leaderboard"));
+        }
+    };
+
+    let user_id = user.user_id.to_native();
+
+    let leaderboard_score = leaderboard
+        .iter()
+        .position(|score| score.user_id == user_id)
+        .map(|idx| (idx + 1, &leaderboard[idx]));
+
+    let candidates: Vec<(&Score, ScoreSource)> = leaderboard_score
+        .map(|(rank, score)| (score, ScoreSource::Leaderboard { rank }))
+        .into_iter()
+        .chain(
+            recent_scores
+                .iter()
+                .filter(|score| score.map_id == map_id)
+                .map(|score| (score, ScoreSource::Recent)),
+        )
+        .collect();
+
+    let claimed = args.score;
+
+    let verified = candidates
+        .iter()
+        .find(|(score, _)| is_claimed(score, claimed))
+        .copied();
+
+    let mut description = String::new();
+    let mut discrepancies = Vec::new();
+
+    let _ = writeln!(
+        description,
+        "Claimed: **{}**{} on [{} - {} [{}]]({OSU_BASE}b/{map_id})",
+        WithComma::new(claimed),
+        claimed_mods
+            .as_ref()
+            .map_or_else(String::new, |mods| format!(" +{mods}")),
+        map.artist(),
+        map.title(),
+        map.version(),
+    );
+
+    match verified {
+        Some((score, source)) => {
+            let _ = writeln!(
+                description,
+                "\n✅ **Verified** through the {source}\n\
+                {mods} • {acc:.2}% • {combo}x • {ago}",
+                mods = score_mods(score),
+                acc = score.accuracy,
+                combo = score.max_combo,
+                ago = HowLongAgoDynamic::new(&score.ended_at),
+            );
+
+            if let Some(ref mods) = claimed_mods {
+                let score_mods: GameModsIntermode =
+                    score.mods.iter().map(GameMod::intermode).collect();
+
+                if &score_mods != mods {
+                    discrepancies.push(format!(
+                        "Claimed mods `+{mods}` but the score was set with `+{score_mods}`"
+                    ));
+                }
+            }
+
+            if !score.passed {
+                discrepancies.push("The score is a fail".to_owned());
+            }
+        }
+        None => {
+            let _ = writeln!(description, "\n❌ **Could not be verified**");
+
+            let closest = candidates
+                .iter()
+                .min_by_key(|(score, _)| (score_value(score, legacy_scores) - claimed).abs());
+
+            match closest {
+                Some((score, source)) => {
+                    let value = score_value(score, legacy_scores);
+
+                    discrepancies.push(format!(
+                        "Closest score in the {source} is {value} ({diff:+}) with {mods}",
+                        value = WithComma::new(value),
+                        diff = value - claimed,
+                        mods = score_mods(score),
+                    ));
+                }
+                None => discrepancies.push(format!(
+                    "No score of {} found on this map in the top 100 leaderboard \
+                    or the 100 most recent scores",
+                    user.username
+                )),
+            }
+        }
+    }
+
+    if !discrepancies.is_empty() {
+        description.push_str("\n**Discrepancies:**");
+
+        for discrepancy in discrepancies {
+            let _ = write!(description, "\n- {discrepancy}");
+        }
+    }
+
+    let footer = FooterBuilder::new(format!(
+        "Checked {} leaderboard scores and {} recent scores",
+        leaderboard.len(),
+        recent_scores.len()
+    ));
+
+    let mut embed = EmbedBuilder::new()
+        .author(user.author_builder(false))
+        .description(description)
+        .footer(footer)
+        .thumbnail(map.thumbnail());
+
+    embed = if verified.is_some() {
+        embed.color_green()
+    } else {
+        embed.color_red()
+    };
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+#[derive(Copy, Clone)]
+enum ScoreSource {
+    Leaderboard { rank: usize },
+    Recent,
+}
+
+impl Display for ScoreSource {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        match self {
+            Self::Leaderboard { rank } => write!(f, "map leaderboard (#{rank})"),
+            Self::Recent => f.write_str("recent scores"),
+        }
+    }
+}
+
+/// Both lazer and classic score values are accepted as claim.
+fn is_claimed(score: &Score, claimed: i64) -> bool {
+    score.score as i64 == claimed || score.classic_score as i64 == claimed
+}
+
+fn score_value(score: &Score, legacy_scores: bool) -> i64 {
+    if legacy_scores && score.classic_score > 0 {
+        score.classic_score as i64
+    } else {
+        score.score as i64
+    }
+}
+
+fn score_mods(score: &Score) -> String {
+    if score.mods.is_empty() {
+        "NM".to_owned()
+    } else {
+        format!("+{}", ModsFormatter::new(&score.mods, score.is_legacy()))
+    }
+}
@@ -0,0 +1,51 @@
+use bathbot_util::{EmbedBuilder, MessageBuilder, constants::GENERAL_ISSUE};
+use eyre::Result;
+
+use crate::{
+    core::CleanupReport,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+pub async fn cleanup(command: InteractionCommand, dry_run: bool) -> Result<()> {
+    command.defer(false).await?;
+
+    let report = match CleanupReport::run(dry_run).await {
+        Ok(report) => report,
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let CleanupReport {
+        osu_channels,
+        twitch_channels,
+        active_msgs,
+        user_configs,
+        incomplete_members,
+    } = report;
+
+    let mut description = format!(
+        "osu!tracking channels: {osu_channels}\n\
+        Twitch tracking channels: {twitch_channels}\n\
+        Orphaned active messages: {active_msgs}\n\
+        User configs: {user_configs}"
+    );
+
+    if incomplete_members {
+        description.push_str(" (skipped, guild members are not fully cached yet)");
+    }
+
+    let title = if dry_run {
+        "Cleanup dry run, entries that would be removed:"
+    } else {
+        "Cleanup finished, removed entries:"
+    };
+
+    let embed = EmbedBuilder::new().description(description).title(title);
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
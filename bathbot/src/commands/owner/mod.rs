@@ -5,7 +5,7 @@ use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::channel::Attachment;
 
 pub use self::reshard::RESHARD_TX;
-use self::{add_bg::*, cache::*, request_members::*};
+use self::{add_bg::*, cache::*, cleanup::*, request_members::*};
 use crate::{
     commands::owner::reshard::reshard,
     util::{InteractionCommandExt, interaction::InteractionCommand},
@@ -13,6 +13,7 @@ use crate::{
 
 mod add_bg;
 mod cache;
+mod cleanup;
 mod request_members;
 mod reshard;
 mod tracking_stats;
@@ -26,6 +27,8 @@ pub enum Owner {
     AddBg(OwnerAddBg),
     #[command(name = "cache")]
     Cache(OwnerCache),
+    #[command(name = "cleanup")]
+    Cleanup(OwnerCleanup),
     #[command(name = "requestmembers")]
     RequestMembers(OwnerRequestMembers),
     #[command(name = "reshard")]
@@ -47,6 +50,16 @@ pub struct OwnerAddBg {
 #[command(name = "cache", desc = "Display stats about the internal cache")]
 pub struct OwnerCache;
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "cleanup",
+    desc = "Prune stale tracking entries, active messages, and user configs"
+)]
+pub struct OwnerCleanup {
+    #[command(desc = "Only report what would be removed, defaults to true")]
+    dry_run: Option<bool>,
+}
+
 #[derive(CommandModel, CreateCommand)]
 #[command(
     name = "requestmembers",
@@ -76,6 +89,7 @@ async fn slash_owner(mut command: InteractionCommand) -> Result<()> {
     match Owner::from_interaction(command.input_data())? {
         Owner::AddBg(bg) => addbg(command, bg).await,
         Owner::Cache(_) => cache(command).await,
+        Owner::Cleanup(args) => cleanup(command, args.dry_run.unwrap_or(true)).await,
         Owner::RequestMembers(args) => request_members(command, &args.guild_id).await,
         Owner::Reshard(_) => reshard(command).await,
         Owner::Tracking(OwnerTracking::Stats(_)) => tracking_stats::trackingstats(command).await,
@@ -165,7 +165,7 @@ const TRACK_USAGE: &str = "[limit=number] [username1] [username2] ...";
     "limit=45 cookiezi whitecat",
     "\"freddie benson\""
 )]
-#[flags(AUTHORITY, ONLY_GUILDS)]
+#[flags(AUTHORITY, ONLY_GUILDS, BANCHO_ONLY)]
 #[group(Tracking)]
 async fn prefix_track(msg: &Message, args: Args<'_>) -> Result<()> {
     match TrackArgs::args(Some(GameMode::Osu), args).await {
@@ -197,7 +197,7 @@ async fn prefix_track(msg: &Message, args: Args<'_>) -> Result<()> {
     "limit=45 cookiezi whitecat",
     "\"freddie benson\""
 )]
-#[flags(AUTHORITY, ONLY_GUILDS)]
+#[flags(AUTHORITY, ONLY_GUILDS, BANCHO_ONLY)]
 #[group(Tracking)]
 pub async fn prefix_trackmania(msg: &Message, args: Args<'_>) -> Result<()> {
     match TrackArgs::args(Some(GameMode::Mania), args).await {
@@ -229,7 +229,7 @@ pub async fn prefix_trackmania(msg: &Message, args: Args<'_>) -> Result<()> {
     "limit=45 cookiezi whitecat",
     "\"freddie benson\""
 )]
-#[flags(AUTHORITY, ONLY_GUILDS)]
+#[flags(AUTHORITY, ONLY_GUILDS, BANCHO_ONLY)]
 #[group(Tracking)]
 pub async fn prefix_tracktaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     match TrackArgs::args(Some(GameMode::Taiko), args).await {
@@ -261,7 +261,7 @@ pub async fn prefix_tracktaiko(msg: &Message, args: Args<'_>) -> Result<()> {
     "limit=45 cookiezi whitecat",
     "\"freddie benson\""
 )]
-#[flags(AUTHORITY, ONLY_GUILDS)]
+#[flags(AUTHORITY, ONLY_GUILDS, BANCHO_ONLY)]
 #[alias("trackingcatch")]
 #[group(Tracking)]
 pub async fn prefix_trackctb(msg: &Message, args: Args<'_>) -> Result<()> {
@@ -25,7 +25,7 @@ pub struct TracklistUserEntry {
 #[desc("Display tracked users of a channel")]
 #[alias("tl")]
 #[group(Tracking)]
-#[flags(AUTHORITY, ONLY_GUILDS)]
+#[flags(AUTHORITY, ONLY_GUILDS, BANCHO_ONLY)]
 async fn prefix_tracklist(msg: &Message) -> Result<()> {
     tracklist(msg.into()).await
 }
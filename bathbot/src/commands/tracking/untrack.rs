@@ -20,7 +20,7 @@ use crate::{
 )]
 #[usage("[username1] [username2] ...")]
 #[example("badewanne3 cookiezi \"freddie benson\" peppy")]
-#[flags(AUTHORITY, ONLY_GUILDS)]
+#[flags(AUTHORITY, ONLY_GUILDS, BANCHO_ONLY)]
 #[group(Tracking)]
 async fn prefix_untrack(msg: &Message, args: Args<'_>) -> Result<()> {
     match TrackArgs::args(None, args).await {
@@ -1,12 +1,12 @@
 use ::time::UtcOffset;
 use bathbot_macros::{SlashCommand, command};
-use bathbot_model::command_fields::{ShowHideOption, TimezoneOption};
+use bathbot_model::command_fields::{EnableDisable, ShowHideOption, TimezoneOption};
 use bathbot_psql::model::configs::{
-    ListSize, OsuUserId, OsuUsername, Retries, ScoreData, UserConfig,
+    CardBackground, GraphTheme, ListSize, OsuUserId, OsuUsername, Retries, ScoreData, UserConfig,
 };
 #[cfg(feature = "server")]
 use bathbot_server::AuthenticationStandbyError;
-use bathbot_util::{Authored, constants::GENERAL_ISSUE};
+use bathbot_util::{Authored, constants::GENERAL_ISSUE, matcher};
 #[cfg(feature = "server")]
 use bathbot_util::{EmbedBuilder, MessageBuilder};
 use eyre::{Report, Result};
@@ -89,6 +89,68 @@ pub struct Config {
     render_button: Option<ShowHideOption>,
     #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
     score_data: Option<ScoreData>,
+    #[command(
+        desc = "Specify the default color theme for graphs",
+        help = "Specify the default color theme for graphs.\n\
+        `Light` is easier to read when discord itself is in light mode.\n\
+        The `theme` option of `/graph` commands takes precedence."
+    )]
+    graph_theme: Option<GraphTheme>,
+    #[command(
+        desc = "Specify how many days graphs cover by default, 0 to reset",
+        help = "Specify how many days graphs cover by default.\n\
+        Applies to the rank, score rank, and snipe gains/losses graphs, \
+        each of which still limits it to the range it supports.\n\
+        Use `0` to reset it.\n\
+        The day options of `/graph` commands take precedence.",
+        max_value = 3650
+    )]
+    graph_days: Option<u16>,
+    #[command(
+        desc = "Should list commands include a plain-text version for screen readers?",
+        help = "Should list commands include a plain-text version for screen readers?\n\
+        Embeds with many inline fields read poorly in screen readers so, if enabled, \
+        paginated responses additionally contain their content as plain text."
+    )]
+    accessibility: Option<EnableDisable>,
+    #[command(
+        desc = "Should large images like graphs and cards be omitted?",
+        help = "Should large images like graphs and cards be omitted?\n\
+        Useful on metered connections. \
+        If enabled, responses mention the omitted image instead of attaching it."
+    )]
+    data_saver: Option<EnableDisable>,
+    #[command(
+        desc = "Should profiles show supporter hearts and playstyles?",
+        help = "Should profiles show supporter hearts and playstyles?\n\
+        If shown, the author line of `/profile` includes a heart for each osu!supporter level \
+        as well as icons for the user's playstyles, i.e. mouse, keyboard, tablet, or touch."
+    )]
+    profile_badges: Option<ShowHideOption>,
+    #[command(
+        desc = "Should map titles and artists be shown in their original language?",
+        help = "Should map titles and artists be shown in their original language?\n\
+        If enabled, embeds and graphs use the unicode title and artist of a map \
+        if it has one instead of the romanized version."
+    )]
+    unicode_titles: Option<EnableDisable>,
+    #[command(
+        desc = "Specify the default background for cards",
+        help = "Specify the default background for `/card`.\n\
+        `Title` uses the background that belongs to the card's title, \
+        `Profile cover` uses the cover of the user's osu! profile, \
+        and any other choice always uses the background of that title.\n\
+        The `background` option of `/card` takes precedence."
+    )]
+    card_background: Option<CardBackground>,
+    #[command(
+        desc = "Specify the default accent color for cards, e.g. #ff66aa",
+        help = "Specify the default accent color for `/card` as hex code, e.g. `#ff66aa`.\n\
+        The header and footer of cards will be tinted in that color instead of black.\n\
+        Use `none` to reset the color.\n\
+        The `accent_color` option of `/card` takes precedence."
+    )]
+    card_accent_color: Option<String>,
 }
 
 pub const SCORE_DATA_DESC: &str = "Whether scores should be requested as lazer or stable scores";
@@ -144,6 +206,68 @@ pub struct Config {
     render_button: Option<ShowHideOption>,
     #[command(desc = SCORE_DATA_DESC, help = SCORE_DATA_HELP)]
     score_data: Option<ScoreData>,
+    #[command(
+        desc = "Specify the default color theme for graphs",
+        help = "Specify the default color theme for graphs.\n\
+        `Light` is easier to read when discord itself is in light mode.\n\
+        The `theme` option of `/graph` commands takes precedence."
+    )]
+    graph_theme: Option<GraphTheme>,
+    #[command(
+        desc = "Specify how many days graphs cover by default, 0 to reset",
+        help = "Specify how many days graphs cover by default.\n\
+        Applies to the rank, score rank, and snipe gains/losses graphs, \
+        each of which still limits it to the range it supports.\n\
+        Use `0` to reset it.\n\
+        The day options of `/graph` commands take precedence.",
+        max_value = 3650
+    )]
+    graph_days: Option<u16>,
+    #[command(
+        desc = "Should list commands include a plain-text version for screen readers?",
+        help = "Should list commands include a plain-text version for screen readers?\n\
+        Embeds with many inline fields read poorly in screen readers so, if enabled, \
+        paginated responses additionally contain their content as plain text."
+    )]
+    accessibility: Option<EnableDisable>,
+    #[command(
+        desc = "Should large images like graphs and cards be omitted?",
+        help = "Should large images like graphs and cards be omitted?\n\
+        Useful on metered connections. \
+        If enabled, responses mention the omitted image instead of attaching it."
+    )]
+    data_saver: Option<EnableDisable>,
+    #[command(
+        desc = "Should profiles show supporter hearts and playstyles?",
+        help = "Should profiles show supporter hearts and playstyles?\n\
+        If shown, the author line of `/profile` includes a heart for each osu!supporter level \
+        as well as icons for the user's playstyles, i.e. mouse, keyboard, tablet, or touch."
+    )]
+    profile_badges: Option<ShowHideOption>,
+    #[command(
+        desc = "Should map titles and artists be shown in their original language?",
+        help = "Should map titles and artists be shown in their original language?\n\
+        If enabled, embeds and graphs use the unicode title and artist of a map \
+        if it has one instead of the romanized version."
+    )]
+    unicode_titles: Option<EnableDisable>,
+    #[command(
+        desc = "Specify the default background for cards",
+        help = "Specify the default background for `/card`.\n\
+        `Title` uses the background that belongs to the card's title, \
+        `Profile cover` uses the cover of the user's osu! profile, \
+        and any other choice always uses the background of that title.\n\
+        The `background` option of `/card` takes precedence."
+    )]
+    card_background: Option<CardBackground>,
+    #[command(
+        desc = "Specify the default accent color for cards, e.g. #ff66aa",
+        help = "Specify the default accent color for `/card` as hex code, e.g. `#ff66aa`.\n\
+        The header and footer of cards will be tinted in that color instead of black.\n\
+        Use `none` to reset the color.\n\
+        The `accent_color` option of `/card` takes precedence."
+    )]
+    card_accent_color: Option<String>,
 }
 
 #[derive(CommandOption, CreateOption)]
@@ -199,8 +323,34 @@ pub async fn config(command: InteractionCommand, config: Config) -> Result<()> {
         mut skin_url,
         render_button,
         score_data,
+        graph_theme,
+        graph_days,
+        accessibility,
+        data_saver,
+        profile_badges,
+        unicode_titles,
+        card_background,
+        card_accent_color,
     } = config;
 
+    let card_accent_color = match card_accent_color.as_deref() {
+        None => None,
+        Some(color) if color.eq_ignore_ascii_case("none") => Some(None),
+        Some(color) => match matcher::get_hex_color(color) {
+            Some(color) => Some(Some(color)),
+            None => {
+                let content = format!(
+                    "Failed to parse `{color}` as color.\n\
+                    Be sure to specify it as hex code, e.g. `#ff66aa`."
+                );
+
+                command.error(content).await?;
+
+                return Ok(());
+            }
+        },
+    };
+
     if let Some(ref skin_url) = skin_url {
         match SkinValidation::check(&command, skin_url).await? {
             ValidationStatus::Continue => {}
@@ -248,6 +398,38 @@ pub async fn config(command: InteractionCommand, config: Config) -> Result<()> {
         config.score_data = Some(score_data);
     }
 
+    if let Some(graph_theme) = graph_theme {
+        config.graph_theme = Some(graph_theme);
+    }
+
+    if let Some(graph_days) = graph_days {
+        config.graph_days = (graph_days > 0).then_some(graph_days);
+    }
+
+    if let Some(accessibility) = accessibility {
+        config.accessibility = Some(matches!(accessibility, EnableDisable::Enable));
+    }
+
+    if let Some(data_saver) = data_saver {
+        config.data_saver = Some(matches!(data_saver, EnableDisable::Enable));
+    }
+
+    if let Some(profile_badges) = profile_badges {
+        config.profile_badges = Some(matches!(profile_badges, ShowHideOption::Show));
+    }
+
+    if let Some(unicode_titles) = unicode_titles {
+        config.unicode_titles = Some(matches!(unicode_titles, EnableDisable::Enable));
+    }
+
+    if let Some(card_background) = card_background {
+        config.card_background = Some(card_background);
+    }
+
+    if let Some(card_accent_color) = card_accent_color {
+        config.card_accent_color = card_accent_color;
+    }
+
     #[cfg(feature = "server")]
     if let Some(ConfigLink::Unlink) = osu {
         config.osu.take();
@@ -379,6 +561,11 @@ async fn handle_both_links(
         Err(err) => return HandleResult::Err(err),
     };
 
+    // The member linked themselves so links imported by guilds are obsolete
+    if let Err(err) = Context::user_config().remove_imported_links(author.id).await {
+        warn!(?err, "Failed to remove imported links");
+    }
+
     if let Err(err) = Context::user_config().store(author.id, config).await {
         let _ = command.error(GENERAL_ISSUE).await;
 
@@ -457,6 +644,11 @@ async fn handle_osu_link(
         Err(err) => return HandleResult::Err(err),
     };
 
+    // The member linked themselves so links imported by guilds are obsolete
+    if let Err(err) = Context::user_config().remove_imported_links(author.id).await {
+        warn!(?err, "Failed to remove imported links");
+    }
+
     let mut twitch_name = None;
 
     if let Some(user_id) = config.twitch_id {
@@ -579,6 +771,14 @@ async fn convert_config(
         timezone,
         render_button,
         score_data,
+        graph_theme,
+        accessibility,
+        data_saver,
+        profile_badges,
+        unicode_titles,
+        card_background,
+        card_accent_color,
+        graph_days,
     } = config;
 
     UserConfig {
@@ -591,6 +791,14 @@ async fn convert_config(
         timezone,
         render_button,
         score_data,
+        graph_theme,
+        accessibility,
+        data_saver,
+        profile_badges,
+        unicode_titles,
+        card_background,
+        card_accent_color,
+        graph_days,
     }
 }
 
@@ -6,6 +6,7 @@ use bathbot_psql::model::configs::ScoreData;
 use bathbot_util::{
     Authored, CowUtils, MessageOrigin,
     constants::GENERAL_ISSUE,
+    osu::effective_clock_rate,
     query::{FilterCriteria, Searchable, TopCriteria},
 };
 use eyre::{Report, Result};
@@ -476,6 +477,10 @@ impl ScoreEmbedDataHalf {
     }
 
     async fn into_full(self) -> ScoreEmbedData {
+        // Score ids of private servers collide with bancho's so neither the miss
+        // analyzer nor renders can be offered for them
+        let bancho = Context::osu_server().is_none();
+
         let global_idx_fut = async {
             if !matches!(
                 self.map.status(),
@@ -515,7 +520,7 @@ impl ScoreEmbedDataHalf {
             let guild_id = self
                 .miss_analyzer_check
                 .guild_id
-                .filter(|_| !self.score.is_legacy)?;
+                .filter(|_| bancho && !self.score.is_legacy)?;
 
             let score_id = self.score.score_id;
 
@@ -546,8 +551,9 @@ impl ScoreEmbedDataHalf {
 
         let if_fc_pp = if_fc.map(|if_fc| if_fc.pp);
 
-        let replay_score_id = (self.with_render && self.has_replay && !self.score.is_legacy)
-            .then_some(self.score.score_id);
+        let replay_score_id =
+            (bancho && self.with_render && self.has_replay && !self.score.is_legacy)
+                .then_some(self.score.score_id);
 
         ScoreEmbedData {
             score: self.score,
@@ -666,9 +672,7 @@ const _: () = {
                 map_len += map.pp_map.total_break_time() / 1000.0;
             }
 
-            if let Some(clock_rate) = score.mods.clock_rate() {
-                map_len /= clock_rate;
-            }
+            map_len /= effective_clock_rate(&score.mods);
 
             score.ended_at - std::time::Duration::from_secs(map_len as u64 + 3)
         }
@@ -834,6 +838,8 @@ impl ScoreEmbedDataRaw {
             set_on_lazer: self.set_on_lazer,
         };
 
+        let bancho = Context::osu_server().is_none();
+
         let global_idx_fut = async {
             if !matches!(
                 map.status(),
@@ -873,7 +879,7 @@ impl ScoreEmbedDataRaw {
             let guild_id = self
                 .miss_analyzer_check
                 .guild_id
-                .filter(|_| self.has_replay && !self.is_legacy)?;
+                .filter(|_| bancho && self.has_replay && !self.is_legacy)?;
 
             let score_id = self.score_id;
 
@@ -904,8 +910,8 @@ impl ScoreEmbedDataRaw {
 
         let if_fc_pp = if_fc.map(|if_fc| if_fc.pp);
 
-        let replay_score_id =
-            (self.with_render && self.has_replay && !self.is_legacy).then_some(score.score_id);
+        let replay_score_id = (bancho && self.with_render && self.has_replay && !self.is_legacy)
+            .then_some(score.score_id);
 
         let pb_idx = self
             .top100
@@ -1075,6 +1081,9 @@ impl<'q> Searchable<TopCriteria<'q>> for ScoreEmbedDataHalf {
         .unwrap_or(attrs.cs as f32);
 
         matches &= self.map.mode() != GameMode::Mania || criteria.keys.contains(keys);
+        matches &= self.map.mode() != GameMode::Mania
+            || criteria.ln.is_empty()
+            || criteria.ln.contains(self.map.ln_percent());
 
         if !matches
             || (criteria.length.is_empty()
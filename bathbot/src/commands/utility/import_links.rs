@@ -0,0 +1,319 @@
+use std::{collections::HashSet, fmt::Write};
+
+use bathbot_util::{
+    EmbedBuilder, FooterBuilder, IntHasher, MessageBuilder, constants::GENERAL_ISSUE,
+};
+use eyre::{ContextCompat, Report, Result};
+use futures::{StreamExt, stream};
+use rosu_v2::prelude::{GameMode, OsuError};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    channel::Attachment,
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    Context,
+    core::commands::CommandOrigin,
+    manager::redis::osu::{UserArgs, UserArgsError},
+};
+
+/// Maximum amount of links that can be imported at once
+const MAX_LINKS: usize = 500;
+
+/// Maximum size of the attached file in bytes
+const MAX_FILE_SIZE: u64 = 1024 * 1024;
+
+/// How many osu! ids are verified at the same time
+const VERIFY_CONCURRENCY: usize = 8;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "importlinks",
+    desc = "Import osu! links of members from a CSV file",
+    help = "Import osu! links of members from a CSV file.\n\
+    Handy when migrating from another bot.\n\
+    Each line of the file must be of the form `discord_id,osu_id`, a header line is allowed.\n\
+    Only members of this server are imported, every osu! id is checked before importing, \
+    and members that already have a link are skipped.\n\
+    Imported links are unverified and only apply within this server when the member is \
+    mentioned in a command. As soon as the member links themselves via `/link`, \
+    their own link is used instead.\n\
+    At most 500 links can be imported at once."
+)]
+pub struct ServerConfigImportLinks {
+    #[command(desc = "Specify a CSV file with `discord_id,osu_id` lines")]
+    file: Attachment,
+    #[command(desc = "Only preview the import without saving anything")]
+    dry_run: Option<bool>,
+}
+
+pub async fn import_links(orig: CommandOrigin<'_>, args: ServerConfigImportLinks) -> Result<()> {
+    let ServerConfigImportLinks { file, dry_run } = args;
+    let dry_run = dry_run.unwrap_or(false);
+    let guild_id = orig.guild_id().wrap_err("Missing guild id")?;
+
+    if !file.filename.to_ascii_lowercase().ends_with(".csv") {
+        return orig.error("The attached file must be of type .csv").await;
+    }
+
+    if file.size > MAX_FILE_SIZE {
+        return orig
+            .error("The attached file must not be larger than 1MB")
+            .await;
+    }
+
+    let bytes = match Context::client().get_discord_attachment(&file).await {
+        Ok(bytes) => bytes,
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to download attachment"));
+        }
+    };
+
+    let ParsedLinks {
+        mut links,
+        invalid_lines,
+        duplicates,
+    } = ParsedLinks::new(&String::from_utf8_lossy(&bytes));
+
+    if links.is_empty() {
+        let content = "The file does not contain any valid `discord_id,osu_id` line";
+
+        return orig.error(content).await;
+    } else if links.len() > MAX_LINKS {
+        let content = format!(
+            "The file contains {} links but only up to {MAX_LINKS} can be imported at once",
+            links.len()
+        );
+
+        return orig.error(content).await;
+    }
+
+    let valid_entries = links.len();
+
+    let not_members = match Context::cache().members(guild_id).await {
+        Ok(members) => {
+            let members: HashSet<_, IntHasher> = members.into_iter().collect();
+            let len = links.len();
+            links.retain(|(discord_id, _)| members.contains(&discord_id.get()));
+
+            len - links.len()
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err.wrap_err("Failed to get guild members"));
+        }
+    };
+
+    let discord_ids: Vec<_> = links.iter().map(|(discord_id, _)| *discord_id).collect();
+
+    let already_linked = match Context::user_config()
+        .linked_discord_ids(&discord_ids)
+        .await
+    {
+        Ok(linked) => {
+            let linked: HashSet<_, IntHasher> = linked.into_iter().map(Id::get).collect();
+            let len = links.len();
+            links.retain(|(discord_id, _)| !linked.contains(&discord_id.get()));
+
+            len - links.len()
+        }
+        Err(err) => {
+            let _ = orig.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let mut unknown_osu_ids = Vec::new();
+    let mut verified = Vec::with_capacity(links.len());
+
+    let mut verify_stream = stream::iter(links)
+        .map(|(discord_id, osu_id)| async move {
+            let user_args = UserArgs::user_id(osu_id, GameMode::Osu);
+
+            (discord_id, osu_id, Context::redis().osu_user(user_args).await)
+        })
+        .buffer_unordered(VERIFY_CONCURRENCY);
+
+    while let Some((discord_id, osu_id, res)) = verify_stream.next().await {
+        match res {
+            Ok(_) => verified.push((discord_id, osu_id)),
+            Err(UserArgsError::Osu(OsuError::NotFound)) => unknown_osu_ids.push(osu_id),
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+                let err = Report::new(err).wrap_err("Failed to verify osu! id");
+
+                return Err(err);
+            }
+        }
+    }
+
+    let imported = if dry_run || verified.is_empty() {
+        verified.len() as u64
+    } else {
+        match Context::user_config().import_links(guild_id, &verified).await {
+            Ok(imported) => imported,
+            Err(err) => {
+                let _ = orig.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        }
+    };
+
+    let mut description = String::new();
+
+    let _ = writeln!(description, "Valid entries: {valid_entries}");
+
+    if !invalid_lines.is_empty() {
+        let _ = write!(description, "Invalid lines: {} (", invalid_lines.len());
+
+        for (i, line) in invalid_lines.iter().take(10).enumerate() {
+            if i > 0 {
+                description.push_str(", ");
+            }
+
+            let _ = write!(description, "`{line}`");
+        }
+
+        if invalid_lines.len() > 10 {
+            description.push_str(", ...");
+        }
+
+        description.push_str(")\n");
+    }
+
+    if duplicates > 0 {
+        let _ = writeln!(description, "Duplicate discord ids: {duplicates}");
+    }
+
+    if not_members > 0 {
+        let _ = writeln!(description, "Not a member of this server: {not_members}");
+    }
+
+    if already_linked > 0 {
+        let _ = writeln!(description, "Already linked: {already_linked}");
+    }
+
+    if !unknown_osu_ids.is_empty() {
+        let _ = write!(description, "Unknown osu! ids: {} (", unknown_osu_ids.len());
+
+        for (i, osu_id) in unknown_osu_ids.iter().take(10).enumerate() {
+            if i > 0 {
+                description.push_str(", ");
+            }
+
+            let _ = write!(description, "`{osu_id}`");
+        }
+
+        if unknown_osu_ids.len() > 10 {
+            description.push_str(", ...");
+        }
+
+        description.push_str(")\n");
+    }
+
+    let (title, footer) = if dry_run {
+        let _ = write!(description, "**Would import: {imported}**");
+
+        ("Link import preview", "Dry run, no links were saved")
+    } else {
+        let _ = write!(description, "**Imported: {imported}**");
+
+        ("Link import", "Imported links only apply within this server")
+    };
+
+    let embed = EmbedBuilder::new()
+        .title(title)
+        .description(description)
+        .footer(FooterBuilder::new(footer));
+
+    let builder = MessageBuilder::new().embed(embed);
+    orig.create_message(builder).await?;
+
+    Ok(())
+}
+
+#[derive(Debug, Default, PartialEq)]
+struct ParsedLinks {
+    links: Vec<(Id<UserMarker>, u32)>,
+    /// 1-based line numbers that could not be parsed
+    invalid_lines: Vec<usize>,
+    /// Amount of lines whose discord id already appeared before
+    duplicates: usize,
+}
+
+impl ParsedLinks {
+    fn new(content: &str) -> Self {
+        let mut parsed = Self::default();
+        let mut seen = HashSet::with_hasher(IntHasher);
+
+        let lines = content
+            .lines()
+            .enumerate()
+            .map(|(i, line)| (i + 1, line.trim()))
+            .filter(|(_, line)| !line.is_empty());
+
+        for (i, (line_num, line)) in lines.enumerate() {
+            let Some(link) = Self::parse_line(line) else {
+                // The first line may be a header
+                let is_header = i == 0 && !line.starts_with(|c: char| c.is_ascii_digit());
+
+                if !is_header {
+                    parsed.invalid_lines.push(line_num);
+                }
+
+                continue;
+            };
+
+            if seen.insert(link.0.get()) {
+                parsed.links.push(link);
+            } else {
+                parsed.duplicates += 1;
+            }
+        }
+
+        parsed
+    }
+
+    fn parse_line(line: &str) -> Option<(Id<UserMarker>, u32)> {
+        let (discord_id, osu_id) = line.split_once([',', ';'])?;
+
+        let discord_id = discord_id.trim().trim_matches('"').parse().ok()?;
+        let osu_id = osu_id.trim().trim_matches('"').parse().ok()?;
+
+        Some((Id::new_checked(discord_id)?, osu_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_links() {
+        let content = "discord_id,osu_id\n\
+            219905108316520448,2211396\n\
+            \n\
+            \"381596405542764544\";\"9217626\"\n\
+            219905108316520448,123\n\
+            abc,123\n\
+            0,123\n\
+            123456789,not_a_number";
+
+        let expected = ParsedLinks {
+            links: vec![
+                (Id::new(219905108316520448), 2211396),
+                (Id::new(381596405542764544), 9217626),
+            ],
+            invalid_lines: vec![6, 7, 8],
+            duplicates: 1,
+        };
+
+        assert_eq!(ParsedLinks::new(content), expected);
+    }
+}
@@ -3,12 +3,17 @@ mod changelog;
 mod commands;
 mod config;
 mod embed_builder;
+mod import_links;
 mod invite;
 mod ping;
 mod prefix;
+mod refscript;
 mod roll;
+mod schedule;
 mod server_config;
 mod skin;
+mod stats;
 
+pub use self::schedule::match_reminder_loop;
 #[allow(unused_imports)]
 pub use self::{authorities::*, changelog::*, config::*, embed_builder::*, skin::*};
@@ -0,0 +1,364 @@
+use std::fmt::Write;
+
+use bathbot_macros::SlashCommand;
+use bathbot_util::{EmbedBuilder, MessageBuilder, constants::GENERAL_ISSUE};
+use eyre::Result;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+
+use crate::{
+    core::{Context, commands::checks::check_authority},
+    util::{Authored, InteractionCommandExt, interaction::InteractionCommand},
+};
+
+const DEFAULT_TEMPLATE: &str = "!mp map {map_id}\n!mp mods {mods}\n!mp timer {timer}";
+const DEFAULT_TIMER: u32 = 120;
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "refscript",
+    desc = "Generate !mp commands for refereeing a match",
+    help = "Generate the sequence of `!mp` commands for a match so referees can just copy them.\n\
+    First, an authority of the server needs to configure the mappool via `/refscript pool`.\n\
+    Optionally, the commands for each slot can be adjusted via `/refscript template`."
+)]
+#[flags(ONLY_GUILDS)]
+pub enum Refscript {
+    #[command(name = "generate")]
+    Generate(RefscriptGenerate),
+    #[command(name = "pool")]
+    Pool(RefscriptPool),
+    #[command(name = "template")]
+    Template(RefscriptTemplate),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "generate", desc = "Generate the !mp commands for a match")]
+pub struct RefscriptGenerate {
+    #[command(desc = "Specify the name of the first team")]
+    team1: String,
+    #[command(desc = "Specify the name of the second team")]
+    team2: String,
+    #[command(desc = "Specify the players to invite, separated by commas")]
+    players: Option<String>,
+    #[command(
+        min_value = 10,
+        max_value = 600,
+        desc = "Specify the timer in seconds for each map (defaults to 120)"
+    )]
+    timer: Option<u32>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "pool",
+    desc = "Configure the mappool of this server",
+    help = "Configure the mappool of this server.\n\
+    Slots are given as mod group plus optional number, followed by the map id \
+    e.g. `NM1 1234, NM2 5678, HD1 9012, FM1 3456, TB 7890`."
+)]
+pub struct RefscriptPool {
+    #[command(desc = "Specify slots and map ids e.g. `NM1 1234, HD1 5678, TB 9012`")]
+    pool: String,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "template",
+    desc = "Configure the commands for each slot",
+    help = "Configure the commands for each slot of the mappool.\n\
+    Use `;` to separate commands and the placeholders `{slot}`, `{map_id}`, `{mods}`, \
+    and `{timer}`.\n\
+    If no template is specified, it will be reset to \
+    `!mp map {map_id}; !mp mods {mods}; !mp timer {timer}`."
+)]
+pub struct RefscriptTemplate {
+    #[command(desc = "Specify the template e.g. `!mp map {map_id}; !mp mods {mods}`")]
+    template: Option<String>,
+}
+
+async fn slash_refscript(mut command: InteractionCommand) -> Result<()> {
+    match Refscript::from_interaction(command.input_data())? {
+        Refscript::Generate(args) => generate(command, args).await,
+        Refscript::Pool(args) => pool(command, args).await,
+        Refscript::Template(args) => template(command, args).await,
+    }
+}
+
+async fn generate(command: InteractionCommand, args: RefscriptGenerate) -> Result<()> {
+    let guild_id = command.guild_id.expect("missing guild id");
+
+    let config = match Context::refscripts().get(guild_id).await {
+        Ok(config) => config,
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let Some(config) = config.filter(|config| !config.mappool.is_empty()) else {
+        let content = "There is no mappool configured for this server yet. \
+            An authority can configure it via `/refscript pool`.";
+        command.error(content).await?;
+
+        return Ok(());
+    };
+
+    let slots = match parse_mappool(&config.mappool) {
+        Ok(slots) => slots,
+        Err(content) => {
+            command.error(content).await?;
+
+            return Ok(());
+        }
+    };
+
+    let template = config.template.as_deref().unwrap_or(DEFAULT_TEMPLATE);
+    let timer = args.timer.unwrap_or(DEFAULT_TIMER);
+
+    let players: Vec<_> = args
+        .players
+        .as_deref()
+        .map(|players| {
+            players
+                .split(',')
+                .map(str::trim)
+                .filter(|player| !player.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let script = generate_script(&args.team1, &args.team2, &players, &slots, template, timer);
+
+    if script.len() > 4000 {
+        let content = "The script is too long to be displayed, \
+            consider shortening the template or the mappool";
+        command.error(content).await?;
+
+        return Ok(());
+    }
+
+    let embed = EmbedBuilder::new()
+        .description(format!("```\n{script}```"))
+        .title(format!("{} vs {}", args.team1, args.team2));
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+async fn pool(command: InteractionCommand, args: RefscriptPool) -> Result<()> {
+    let guild_id = command.guild_id.expect("missing guild id");
+
+    if !require_authority(&command).await? {
+        return Ok(());
+    }
+
+    let slots = match parse_mappool(&args.pool) {
+        Ok(slots) => slots,
+        Err(content) => {
+            command.error(content).await?;
+
+            return Ok(());
+        }
+    };
+
+    let mappool = slots
+        .iter()
+        .map(|slot| format!("{} {}", slot.name, slot.map_id))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    if let Err(err) = Context::refscripts().set_mappool(guild_id, &mappool).await {
+        let _ = command.error(GENERAL_ISSUE).await;
+
+        return Err(err);
+    }
+
+    let content = format!("Mappool of this server is now `{mappool}`");
+    let builder = MessageBuilder::new().embed(content);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+async fn template(command: InteractionCommand, args: RefscriptTemplate) -> Result<()> {
+    let guild_id = command.guild_id.expect("missing guild id");
+
+    if !require_authority(&command).await? {
+        return Ok(());
+    }
+
+    let template = args.template.map(|template| {
+        template
+            .split(';')
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n")
+    });
+
+    let set_fut = Context::refscripts().set_template(guild_id, template.as_deref());
+
+    if let Err(err) = set_fut.await {
+        let _ = command.error(GENERAL_ISSUE).await;
+
+        return Err(err);
+    }
+
+    let template = template.as_deref().unwrap_or(DEFAULT_TEMPLATE);
+    let content = format!("Commands for each slot are now:\n```\n{template}\n```");
+    let builder = MessageBuilder::new().embed(content);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+/// Responds with an error and returns `false` if the author is not an
+/// authority.
+async fn require_authority(command: &InteractionCommand) -> Result<bool> {
+    match check_authority(command.user_id()?, command.guild_id).await {
+        Ok(None) => Ok(true),
+        Ok(Some(content)) => {
+            command.error(content).await?;
+
+            Ok(false)
+        }
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            Err(err.wrap_err("Failed to check authority status"))
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct PoolSlot<'a> {
+    /// Slot name e.g. `NM1`
+    name: &'a str,
+    /// Mod group of the slot e.g. `NM`
+    group: &'a str,
+    map_id: u32,
+}
+
+fn parse_mappool(pool: &str) -> Result<Vec<PoolSlot<'_>>, String> {
+    let mut slots = Vec::new();
+
+    for entry in pool
+        .split(',')
+        .map(str::trim)
+        .filter(|entry| !entry.is_empty())
+    {
+        let Some((name, map_id)) = entry.split_once(char::is_whitespace) else {
+            return Err(format!("Missing map id for slot `{entry}`"));
+        };
+
+        let Ok(map_id) = map_id.trim().parse() else {
+            return Err(format!("Invalid map id for slot `{name}`"));
+        };
+
+        let group = name.trim_end_matches(|c: char| c.is_ascii_digit());
+
+        if group.is_empty() || !group.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(format!("Invalid slot name `{name}`"));
+        }
+
+        slots.push(PoolSlot {
+            name,
+            group,
+            map_id,
+        });
+    }
+
+    if slots.is_empty() {
+        return Err("The mappool must contain at least one slot".to_owned());
+    }
+
+    Ok(slots)
+}
+
+/// Arguments of `!mp mods` for a mod group.
+fn slot_mods(group: &str) -> String {
+    match group.to_ascii_uppercase().as_str() {
+        "NM" => "NF".to_owned(),
+        "FM" | "TB" => "Freemod".to_owned(),
+        other => {
+            let mut mods = String::from("NF");
+
+            for i in (0..other.len()).step_by(2) {
+                let _ = write!(mods, " {}", other.get(i..i + 2).unwrap_or(&other[i..]));
+            }
+
+            mods
+        }
+    }
+}
+
+fn generate_script(
+    team1: &str,
+    team2: &str,
+    players: &[&str],
+    slots: &[PoolSlot<'_>],
+    template: &str,
+    timer: u32,
+) -> String {
+    let mut script = format!("!mp make ({team1}) vs ({team2})\n!mp set 2 3\n");
+
+    for player in players {
+        let _ = writeln!(script, "!mp invite {}", player.replace(' ', "_"));
+    }
+
+    for slot in slots {
+        let commands = template
+            .replace("{slot}", slot.name)
+            .replace("{map_id}", &slot.map_id.to_string())
+            .replace("{mods}", &slot_mods(slot.group))
+            .replace("{timer}", &timer.to_string());
+
+        let _ = writeln!(script, "\n// {}\n{commands}", slot.name);
+    }
+
+    script
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_pool() {
+        let slots = parse_mappool("NM1 123, HDHR1 456,TB 789").unwrap();
+
+        let expected = vec![
+            PoolSlot {
+                name: "NM1",
+                group: "NM",
+                map_id: 123,
+            },
+            PoolSlot {
+                name: "HDHR1",
+                group: "HDHR",
+                map_id: 456,
+            },
+            PoolSlot {
+                name: "TB",
+                group: "TB",
+                map_id: 789,
+            },
+        ];
+
+        assert_eq!(slots, expected);
+        assert!(parse_mappool("NM1").is_err());
+        assert!(parse_mappool("NM1 abc").is_err());
+        assert!(parse_mappool("").is_err());
+    }
+
+    #[test]
+    fn mods_per_group() {
+        assert_eq!(slot_mods("NM"), "NF");
+        assert_eq!(slot_mods("hd"), "NF HD");
+        assert_eq!(slot_mods("HDHR"), "NF HD HR");
+        assert_eq!(slot_mods("FM"), "Freemod");
+    }
+}
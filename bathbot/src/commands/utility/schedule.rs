@@ -0,0 +1,224 @@
+use std::fmt::Write;
+
+use bathbot_macros::SlashCommand;
+use bathbot_util::{
+    EmbedBuilder, MessageBuilder,
+    constants::GENERAL_ISSUE,
+    datetime::{parse_local_datetime, parse_utc_offset},
+};
+use eyre::{Report, Result};
+use time::{OffsetDateTime, UtcOffset};
+use tokio::time::{Duration, interval};
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::{
+    channel::message::AllowedMentions,
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    core::Context,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+/// How long before a match its participants are pinged.
+const REMINDER_LEAD: time::Duration = time::Duration::minutes(15);
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(
+    name = "schedule",
+    desc = "Schedule tournament matches",
+    help = "Schedule tournament matches between two participants.\n\
+    The start time is shown in everyone's local time and both participants \
+    will be pinged 15 minutes before the match starts."
+)]
+#[flags(ONLY_GUILDS)]
+pub enum Schedule {
+    #[command(name = "match")]
+    Match(ScheduleMatch),
+    #[command(name = "list")]
+    List(ScheduleList),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "match", desc = "Schedule a match between two participants")]
+pub struct ScheduleMatch {
+    #[command(desc = "Specify the first participant")]
+    player1: Id<UserMarker>,
+    #[command(desc = "Specify the second participant")]
+    player2: Id<UserMarker>,
+    #[command(desc = "Specify the start time e.g. `2025-05-01 18:00`")]
+    time: String,
+    #[command(desc = "Specify the timezone of the start time e.g. `UTC+2` (defaults to UTC)")]
+    timezone: Option<String>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "list", desc = "List all upcoming matches of this server")]
+pub struct ScheduleList;
+
+async fn slash_schedule(mut command: InteractionCommand) -> Result<()> {
+    match Schedule::from_interaction(command.input_data())? {
+        Schedule::Match(args) => schedule_match(command, args).await,
+        Schedule::List(_) => schedule_list(command).await,
+    }
+}
+
+async fn schedule_match(command: InteractionCommand, args: ScheduleMatch) -> Result<()> {
+    let guild_id = command.guild_id.expect("missing guild id");
+
+    let offset = match args.timezone.as_deref().map(parse_utc_offset) {
+        Some(Some(offset)) => offset,
+        Some(None) => {
+            let content = "Failed to parse timezone. \
+                Be sure to specify it as offset to UTC e.g. `UTC+2` or `UTC-5:30`.";
+            command.error(content).await?;
+
+            return Ok(());
+        }
+        None => UtcOffset::UTC,
+    };
+
+    let Some(start_at) = parse_local_datetime(&args.time, offset) else {
+        let content = "Failed to parse time. \
+            Be sure to specify it in the format `YYYY-MM-DD HH:MM` e.g. `2025-05-01 18:00`.";
+        command.error(content).await?;
+
+        return Ok(());
+    };
+
+    if start_at <= OffsetDateTime::now_utc() {
+        command.error("The match must start in the future").await?;
+
+        return Ok(());
+    }
+
+    if args.player1 == args.player2 {
+        command
+            .error("A match requires two different participants")
+            .await?;
+
+        return Ok(());
+    }
+
+    let schedule_fut = Context::scheduled_matches().schedule(
+        guild_id,
+        command.channel_id,
+        [args.player1, args.player2],
+        start_at,
+    );
+
+    if let Err(err) = schedule_fut.await {
+        let _ = command.error(GENERAL_ISSUE).await;
+
+        return Err(err);
+    }
+
+    let timestamp = start_at.unix_timestamp();
+
+    let description = format!(
+        "Scheduled match between <@{}> and <@{}>\n\
+        Starts <t:{timestamp}:F> (<t:{timestamp}:R>)",
+        args.player1, args.player2,
+    );
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .title("Match scheduled");
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+async fn schedule_list(command: InteractionCommand) -> Result<()> {
+    let guild_id = command.guild_id.expect("missing guild id");
+
+    let matches = match Context::scheduled_matches().upcoming(guild_id).await {
+        Ok(matches) => matches,
+        Err(err) => {
+            let _ = command.error(GENERAL_ISSUE).await;
+
+            return Err(err);
+        }
+    };
+
+    let mut description = String::new();
+
+    for scheduled in matches.iter().take(25) {
+        let timestamp = scheduled.start_at.unix_timestamp();
+
+        let _ = writeln!(
+            description,
+            "<t:{timestamp}:F> (<t:{timestamp}:R>): <@{}> vs <@{}>",
+            scheduled.player1, scheduled.player2,
+        );
+    }
+
+    if matches.len() > 25 {
+        let _ = write!(description, "*and {} more*", matches.len() - 25);
+    }
+
+    if description.is_empty() {
+        description.push_str("No upcoming matches");
+    }
+
+    let embed = EmbedBuilder::new()
+        .description(description)
+        .title("Upcoming matches");
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+pub async fn match_reminder_loop() {
+    let mut interval = interval(Duration::from_secs(60));
+
+    loop {
+        interval.tick().await;
+
+        let until = OffsetDateTime::now_utc() + REMINDER_LEAD;
+
+        let matches = match Context::scheduled_matches().due_reminders(until).await {
+            Ok(matches) => matches,
+            Err(err) => {
+                warn!(?err, "Failed to get due match reminders");
+
+                continue;
+            }
+        };
+
+        for scheduled in matches {
+            let player1 = Id::new(scheduled.player1 as u64);
+            let player2 = Id::new(scheduled.player2 as u64);
+            let timestamp = scheduled.start_at.unix_timestamp();
+
+            let content = format!("<@{player1}> <@{player2}> your match starts <t:{timestamp}:R>!");
+
+            let mentions = AllowedMentions {
+                users: vec![player1, player2],
+                ..Default::default()
+            };
+
+            let send_fut = Context::http()
+                .create_message(Id::new(scheduled.channel_id as u64))
+                .content(&content)
+                .allowed_mentions(Some(&mentions));
+
+            if let Err(err) = send_fut.await {
+                let err = Report::new(err).wrap_err("Failed to send match reminder");
+                warn!(match_id = scheduled.match_id, ?err);
+            }
+
+            // Mark as reminded either way to not retry a deleted channel forever
+            if let Err(err) = Context::scheduled_matches()
+                .mark_reminded(scheduled.match_id)
+                .await
+            {
+                warn!(match_id = scheduled.match_id, ?err);
+            }
+        }
+    }
+}
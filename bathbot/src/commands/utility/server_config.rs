@@ -1,15 +1,23 @@
+use std::fmt::Write;
+
 use bathbot_macros::{SlashCommand, command};
 use bathbot_model::command_fields::{EnableDisable, ShowHideOption};
 use bathbot_psql::model::configs::{GuildConfig, HideSolutions, ListSize, Retries, ScoreData};
-use bathbot_util::constants::GENERAL_ISSUE;
+use bathbot_util::{constants::GENERAL_ISSUE, matcher};
 use eyre::{Report, Result};
 use twilight_interactions::command::{CommandModel, CreateCommand};
 use twilight_model::{
     guild::Permissions,
-    id::{Id, marker::RoleMarker},
+    id::{
+        Id,
+        marker::{ChannelMarker, RoleMarker},
+    },
 };
 
-use super::AuthorityCommandKind;
+use super::{
+    AuthorityCommandKind,
+    import_links::{ServerConfigImportLinks, import_links},
+};
 use crate::{
     Context,
     core::commands::CommandOrigin,
@@ -29,6 +37,16 @@ pub enum ServerConfig {
     Authorities(ServerConfigAuthorities),
     #[command(name = "edit")]
     Edit(ServerConfigEdit),
+    #[command(name = "importlinks")]
+    ImportLinks(ServerConfigImportLinks),
+    #[command(name = "pulse")]
+    Pulse(ServerConfigPulse),
+    #[command(name = "mapintro")]
+    MapIntro(ServerConfigMapIntro),
+    #[command(name = "imagechannels")]
+    ImageChannels(ServerConfigImageChannels),
+    #[command(name = "osuserver")]
+    OsuServer(ServerConfigOsuServer),
 }
 
 #[derive(CommandModel, CreateCommand)]
@@ -101,6 +119,100 @@ pub struct ServerConfigAuthoritiesRemoveAll;
 #[command(name = "list", desc = "Display all current authority roles")]
 pub struct ServerConfigAuthoritiesList;
 
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "pulse",
+    desc = "Show live server stats as the name of a voice channel",
+    help = "Show live server stats as the name of a voice channel.\n\
+    Once an hour, the channel will be renamed to show how many members are linked \
+    to an osu! profile and how much pp they gained on the current day.\n\
+    The bot requires the `Manage Channels` permission for the channel.\n\
+    Use this command without specifying a channel to disable it."
+)]
+pub struct ServerConfigPulse {
+    #[command(
+        desc = "Specify the channel to rename, omit to disable",
+        channel_types = "guild_voice guild_stage_voice"
+    )]
+    channel: Option<Id<ChannelMarker>>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "mapintro",
+    desc = "Adjust the branding of map intro cards",
+    help = "Adjust the branding of images created via `/mapintro`.\n\
+    Options that are not specified will be reset to their default."
+)]
+pub struct ServerConfigMapIntro {
+    #[command(desc = "Specify an accent color as hex code, e.g. #ff66aa")]
+    accent_color: Option<String>,
+    #[command(
+        max_length = 64,
+        desc = "Specify a text to show on the cards, e.g. the tournament name"
+    )]
+    label: Option<String>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "imagechannels",
+    desc = "Restrict image responses to specific channels",
+    help = "Restrict image responses such as graphs, cards, or simulate images to specific channels.\n\
+    In all other channels, commands will respond without their image.\n\
+    If no channel is specified, images are allowed everywhere."
+)]
+pub enum ServerConfigImageChannels {
+    #[command(name = "add")]
+    Add(ServerConfigImageChannelsAdd),
+    #[command(name = "remove")]
+    Remove(ServerConfigImageChannelsRemove),
+    #[command(name = "reset")]
+    Reset(ServerConfigImageChannelsReset),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "add",
+    desc = "Allow images in a channel",
+    help = "Allow images in a channel.\n\
+    Servers can specify at most 25 channels."
+)]
+pub struct ServerConfigImageChannelsAdd {
+    #[command(
+        desc = "Specify the channel in which images should be allowed",
+        channel_types = "guild_text guild_announcement public_thread private_thread"
+    )]
+    channel: Id<ChannelMarker>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "remove", desc = "No longer allow images in a channel")]
+pub struct ServerConfigImageChannelsRemove {
+    #[command(desc = "Specify the channel in which images should no longer be allowed")]
+    channel: Id<ChannelMarker>,
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(name = "reset", desc = "Allow images in all channels again")]
+pub struct ServerConfigImageChannelsReset;
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "osuserver",
+    desc = "Target a private osu! server instead of bancho",
+    help = "Target a private osu! server instead of bancho.\n\
+    Users and their scores are then requested from that server and commands \
+    that only exist on bancho, e.g. rankings or medals, are disabled in this server.\n\
+    Linked members are looked up by their bancho name.\n\
+    Only servers that were set up by the bot owner are available.\n\
+    Use this command without specifying a server to target bancho again."
+)]
+pub struct ServerConfigOsuServer {
+    #[command(desc = "Specify the name of the server, omit to target bancho")]
+    server: Option<String>,
+}
+
 #[derive(CommandModel, CreateCommand, Default)]
 #[command(name = "edit", desc = "Adjust configurations for a server")]
 pub struct ServerConfigEdit {
@@ -142,6 +254,14 @@ pub struct ServerConfigEdit {
         Applies only if the member has not specified a config for themselves."
     )]
     score_data: Option<ScoreData>,
+    #[command(
+        desc = "Should map titles and artists be shown in their original language?",
+        help = "Should map titles and artists be shown in their original language?\n\
+        If enabled, embeds and graphs use the unicode title and artist of a map \
+        if it has one instead of the romanized version.\n\
+        Applies only if the member has not specified a config for themselves."
+    )]
+    unicode_titles: Option<EnableDisable>,
 }
 
 impl ServerConfigEdit {
@@ -154,6 +274,7 @@ impl ServerConfigEdit {
             allow_custom_skins,
             hide_medal_solutions,
             score_data,
+            unicode_titles,
         } = self;
 
         song_commands.is_some()
@@ -163,12 +284,18 @@ impl ServerConfigEdit {
             || allow_custom_skins.is_some()
             || hide_medal_solutions.is_some()
             || score_data.is_some()
+            || unicode_titles.is_some()
     }
 }
 
 async fn slash_serverconfig(mut command: InteractionCommand) -> Result<()> {
     let args = ServerConfig::from_interaction(command.input_data())?;
 
+    // Importing links may take a while
+    if let ServerConfig::ImportLinks(_) = args {
+        command.defer(true).await?;
+    }
+
     serverconfig((&mut command).into(), args).await
 }
 
@@ -210,6 +337,119 @@ async fn serverconfig(orig: CommandOrigin<'_>, args: ServerConfig) -> Result<()>
             return super::authorities(orig, args.into()).await;
         }
         ServerConfig::Edit(edit) => edit,
+        ServerConfig::ImportLinks(args) => return import_links(orig, args).await,
+        ServerConfig::Pulse(args) => {
+            let f = |config: &mut GuildConfig| config.pulse_channel = args.channel;
+
+            if let Err(err) = Context::guild_config().update(guild_id, f).await {
+                let _ = orig.error_callback(GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("failed to update guild config"));
+            }
+
+            ServerConfigEdit::default()
+        }
+        ServerConfig::MapIntro(args) => {
+            let accent_color = match args.accent_color.as_deref() {
+                Some(color) => match matcher::get_hex_color(color) {
+                    Some(color) => Some(color),
+                    None => {
+                        let content = format!(
+                            "Failed to parse `{color}` as color.\n\
+                            Be sure to specify it as hex code, e.g. `#ff66aa`."
+                        );
+
+                        return orig.error_callback(content).await;
+                    }
+                },
+                None => None,
+            };
+
+            let f = |config: &mut GuildConfig| {
+                config.intro_accent = accent_color;
+                config.intro_label = args.label;
+            };
+
+            if let Err(err) = Context::guild_config().update(guild_id, f).await {
+                let _ = orig.error_callback(GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("failed to update guild config"));
+            }
+
+            ServerConfigEdit::default()
+        }
+        ServerConfig::OsuServer(args) => {
+            let client = Context::client();
+
+            if let Some(ref server) = args.server {
+                if !client.has_osu_server(server) {
+                    let mut content = format!("Unknown osu! server `{server}`");
+                    let mut servers = client.osu_servers();
+
+                    match servers.next() {
+                        Some(first) => {
+                            let _ = write!(content, "; available servers: `{first}`");
+
+                            for server in servers {
+                                let _ = write!(content, ", `{server}`");
+                            }
+                        }
+                        None => content.push_str("; no private servers are available"),
+                    }
+
+                    return orig.error_callback(content).await;
+                }
+            }
+
+            let f = |config: &mut GuildConfig| config.osu_server = args.server;
+
+            if let Err(err) = Context::guild_config().update(guild_id, f).await {
+                let _ = orig.error_callback(GENERAL_ISSUE).await;
+
+                return Err(err.wrap_err("failed to update guild config"));
+            }
+
+            ServerConfigEdit::default()
+        }
+        ServerConfig::ImageChannels(args) => {
+            let f = |config: &mut GuildConfig| {
+                let channels = &mut config.image_channels;
+
+                match args {
+                    ServerConfigImageChannels::Add(args) => {
+                        if channels.contains(&args.channel) {
+                            return true;
+                        } else if channels.len() >= 25 {
+                            return false;
+                        }
+
+                        channels.push(args.channel);
+                    }
+                    ServerConfigImageChannels::Remove(args) => {
+                        channels.retain(|&channel| channel != args.channel)
+                    }
+                    ServerConfigImageChannels::Reset(_) => channels.clear(),
+                }
+
+                true
+            };
+
+            match Context::guild_config().update(guild_id, f).await {
+                Ok(true) => {}
+                Ok(false) => {
+                    let content = "Images can be allowed in at most 25 channels";
+
+                    return orig.error_callback(content).await;
+                }
+                Err(err) => {
+                    let _ = orig.error_callback(GENERAL_ISSUE).await;
+
+                    return Err(err.wrap_err("failed to update guild config"));
+                }
+            }
+
+            ServerConfigEdit::default()
+        }
     };
 
     if args.any() {
@@ -222,6 +462,7 @@ async fn serverconfig(orig: CommandOrigin<'_>, args: ServerConfig) -> Result<()>
                 allow_custom_skins,
                 hide_medal_solutions,
                 score_data,
+                unicode_titles,
             } = args;
 
             if let Some(list_embeds) = list_embeds {
@@ -251,6 +492,10 @@ async fn serverconfig(orig: CommandOrigin<'_>, args: ServerConfig) -> Result<()>
             if let Some(score_data) = score_data {
                 config.score_data = Some(score_data);
             }
+
+            if let Some(unicode_titles) = unicode_titles {
+                config.unicode_titles = Some(unicode_titles == EnableDisable::Enable);
+            }
         };
 
         if let Err(err) = Context::guild_config().update(guild_id, f).await {
@@ -0,0 +1,127 @@
+use std::time::{Duration, Instant};
+
+use bathbot_macros::SlashCommand;
+use bathbot_util::{
+    EmbedBuilder, FooterBuilder, MessageBuilder, constants::GENERAL_ISSUE, numbers::WithComma,
+};
+use eyre::Result;
+use metrics::Key;
+use time::OffsetDateTime;
+use tokio::sync::Mutex;
+use twilight_interactions::command::{CommandModel, CreateCommand};
+use twilight_model::channel::message::embed::EmbedField;
+
+use crate::{
+    core::Context,
+    util::{InteractionCommandExt, interaction::InteractionCommand},
+};
+
+/// Minimum time between two refreshes of the statistics.
+const REFRESH_INTERVAL: Duration = Duration::from_secs(60);
+
+static BOT_STATS: Mutex<Option<BotStats>> = Mutex::const_new(None);
+
+#[derive(CommandModel, CreateCommand, SlashCommand)]
+#[command(name = "stats", desc = "Display statistics")]
+pub enum Stats {
+    #[command(name = "bot")]
+    Bot(StatsBot),
+}
+
+#[derive(CommandModel, CreateCommand)]
+#[command(
+    name = "bot",
+    desc = "Display bot-wide statistics",
+    help = "Display bot-wide statistics such as the amount of servers, \
+    linked users, or commands that were used today.\n\
+    The statistics are refreshed at most once per minute."
+)]
+pub struct StatsBot;
+
+async fn slash_stats(mut command: InteractionCommand) -> Result<()> {
+    match Stats::from_interaction(command.input_data())? {
+        Stats::Bot(_) => bot_stats(command).await,
+    }
+}
+
+async fn bot_stats(command: InteractionCommand) -> Result<()> {
+    let mut guard = BOT_STATS.lock().await;
+
+    let stats = match *guard {
+        Some(stats) if stats.fetched_at.elapsed() < REFRESH_INTERVAL => stats,
+        _ => match BotStats::fetch().await {
+            Ok(stats) => *guard.insert(stats),
+            Err(err) => {
+                let _ = command.error(GENERAL_ISSUE).await;
+
+                return Err(err);
+            }
+        },
+    };
+
+    drop(guard);
+
+    let field = |name: &str, value: u64| EmbedField {
+        inline: true,
+        name: name.to_owned(),
+        value: WithComma::new(value).to_string(),
+    };
+
+    let fields = vec![
+        field("Servers", stats.servers),
+        field("Linked users", stats.linked_users),
+        field("Commands today", stats.commands_today),
+        field("Graphs rendered", stats.graphs_rendered),
+        field("Maps cached", stats.maps_cached),
+    ];
+
+    let embed = EmbedBuilder::new()
+        .fields(fields)
+        .footer(FooterBuilder::new(
+            "Graphs rendered since boot • Last refresh",
+        ))
+        .timestamp(stats.refreshed_at)
+        .title("Bot statistics");
+
+    let builder = MessageBuilder::new().embed(embed);
+    command.update(builder).await?;
+
+    Ok(())
+}
+
+#[derive(Copy, Clone)]
+struct BotStats {
+    servers: u64,
+    linked_users: u64,
+    commands_today: u64,
+    graphs_rendered: u64,
+    maps_cached: u64,
+    fetched_at: Instant,
+    refreshed_at: OffsetDateTime,
+}
+
+impl BotStats {
+    async fn fetch() -> Result<Self> {
+        let (linked_users, commands_today, maps_cached) = tokio::try_join!(
+            Context::user_config().linked_count(),
+            Context::redis().commands_today(),
+            Context::osu_map().count(),
+        )?;
+
+        let cache_stats = Context::cache().stats();
+
+        let graphs_rendered = Context::get()
+            .metrics
+            .sum_counters(&Key::from_static_name("bathbot.graphs_rendered"));
+
+        Ok(Self {
+            servers: (cache_stats.guilds + cache_stats.unavailable_guilds) as u64,
+            linked_users,
+            commands_today,
+            graphs_rendered,
+            maps_cached,
+            fetched_at: Instant::now(),
+            refreshed_at: OffsetDateTime::now_utc(),
+        })
+    }
+}
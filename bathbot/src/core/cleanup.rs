@@ -0,0 +1,156 @@
+use std::{collections::HashSet, time::Duration};
+
+use bathbot_util::{IntHasher, constants::UNKNOWN_CHANNEL};
+use eyre::Result;
+use time::OffsetDateTime;
+use tokio::time::{interval, sleep};
+use twilight_http::{
+    api_error::{ApiError, GeneralApiError},
+    error::ErrorType,
+};
+use twilight_model::id::Id;
+
+use crate::{core::Context, tracking::OsuTracking};
+
+/// Unlinked configs of users that did not share a guild with the bot nor
+/// invoked a command for this long will be removed.
+const USER_CONFIG_RETENTION: time::Duration = time::Duration::days(180);
+
+/// Once a day, prune tracking entries of channels that no longer exist,
+/// orphaned active messages, and configs of users that left long ago.
+pub async fn cleanup_loop() {
+    // Give the cache some time to fill up after booting
+    sleep(Duration::from_secs(60 * 60)).await;
+
+    let mut interval = interval(Duration::from_secs(60 * 60 * 24));
+
+    loop {
+        interval.tick().await;
+
+        match CleanupReport::run(false).await {
+            Ok(report) => info!(?report, "Finished cleanup"),
+            Err(err) => error!(?err, "Failed to run cleanup"),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CleanupReport {
+    pub osu_channels: usize,
+    pub twitch_channels: usize,
+    pub active_msgs: usize,
+    pub user_configs: u64,
+    /// Whether pruning user configs was skipped because not all guild
+    /// members are cached
+    pub incomplete_members: bool,
+}
+
+impl CleanupReport {
+    /// Prune stale state and report how many entries were removed.
+    ///
+    /// On `dry_run`, nothing is removed and the report contains the amount of
+    /// entries that would have been removed.
+    pub async fn run(dry_run: bool) -> Result<Self> {
+        let mut report = Self {
+            active_msgs: Context::get().active_msgs.prune_orphaned(dry_run).await,
+            ..Default::default()
+        };
+
+        let cache = Context::cache();
+        let channels: HashSet<u64, IntHasher> = cache.channel_ids().await?.into_iter().collect();
+
+        for channel in OsuTracking::channels() {
+            if channels.contains(&channel.get()) || !is_unknown_channel(channel.get()).await {
+                continue;
+            }
+
+            report.osu_channels += 1;
+
+            if !dry_run {
+                OsuTracking::remove_channel(channel, None).await;
+            }
+        }
+
+        #[cfg(feature = "twitchtracking")]
+        {
+            let mut twitch_channels = HashSet::with_hasher(IntHasher);
+
+            for twitch_id in Context::tracked_users() {
+                if let Some(tracked) = Context::tracked_channels_for(twitch_id) {
+                    twitch_channels.extend(tracked);
+                }
+            }
+
+            for channel in twitch_channels {
+                if channels.contains(&channel.get()) || !is_unknown_channel(channel.get()).await {
+                    continue;
+                }
+
+                report.twitch_channels += 1;
+
+                if dry_run {
+                    continue;
+                }
+
+                for twitch_id in Context::tracked_users_in(channel) {
+                    Context::remove_tracking(twitch_id, channel.get());
+                }
+
+                Context::twitch().untrack_all(channel).await?;
+            }
+        }
+
+        let mut members = HashSet::with_hasher(IntHasher);
+
+        for guild in cache.guild_ids().await? {
+            members.extend(cache.members(Id::new(guild)).await?);
+        }
+
+        let members: Vec<_> = members.into_iter().map(|id| id as i64).collect();
+        let user_config = Context::user_config();
+
+        if !dry_run {
+            user_config.update_last_seen(&members).await?;
+        }
+
+        // Members that are missing from the cache would be considered gone
+        report.incomplete_members = !Context::get().member_requests.is_complete()
+            || cache.stats().unavailable_guilds > 0;
+
+        if report.incomplete_members {
+            return Ok(report);
+        }
+
+        let last_seen_before = OffsetDateTime::now_utc() - USER_CONFIG_RETENTION;
+
+        report.user_configs = user_config
+            .prune_stale(&members, last_seen_before, dry_run)
+            .await?;
+
+        Ok(report)
+    }
+}
+
+/// Channels may be missing from the cache because they're archived threads or
+/// belong to an unavailable guild so only Discord can tell whether they're gone.
+async fn is_unknown_channel(channel: u64) -> bool {
+    let Err(err) = Context::http().channel(Id::new(channel)).await else {
+        return false;
+    };
+
+    match err.kind() {
+        ErrorType::Response {
+            error:
+                ApiError::General(GeneralApiError {
+                    code: UNKNOWN_CHANNEL,
+                    ..
+                }),
+            ..
+        } => true,
+        _ => {
+            warn!(channel, ?err, "Failed to check stale channel");
+
+            false
+        }
+    }
+}
@@ -0,0 +1,123 @@
+use std::time::Instant;
+
+use bathbot_util::{Authored, MessageBuilder};
+use eyre::{Result, WrapErr};
+use twilight_model::{
+    channel::message::{
+        Component, EmojiReactionType,
+        component::{ActionRow, Button, ButtonStyle},
+    },
+    id::{Id, marker::UserMarker},
+};
+
+use crate::{
+    core::{BotMetrics, Context, EventKind, commands::checks::check_authority},
+    util::{ComponentExt, interaction::InteractionComponent},
+};
+
+const DELETE_RESPONSE_PREFIX: &str = "delete_response_";
+
+/// Discord allows at most this many action rows per message.
+const MAX_ACTION_ROWS: usize = 5;
+
+/// Append a row with a button that allows the given user or authorities to
+/// delete the response.
+///
+/// Nothing is added if the components already contain such a button or if
+/// there is no room for another row.
+pub fn add_delete_response_row(components: &mut Vec<Component>, owner: Id<UserMarker>) {
+    let has_delete_button = components.iter().any(|component| match component {
+        Component::ActionRow(row) => row.components.iter().any(|component| match component {
+            Component::Button(button) => button
+                .custom_id
+                .as_deref()
+                .is_some_and(|custom_id| custom_id.starts_with(DELETE_RESPONSE_PREFIX)),
+            _ => false,
+        }),
+        _ => false,
+    });
+
+    if !has_delete_button && components.len() < MAX_ACTION_ROWS {
+        components.extend(delete_response_components(owner));
+    }
+}
+
+/// Components containing a single button that allows the given user or
+/// authorities to delete the response.
+fn delete_response_components(owner: Id<UserMarker>) -> Vec<Component> {
+    let button = Button {
+        custom_id: Some(format!("{DELETE_RESPONSE_PREFIX}{owner}")),
+        disabled: false,
+        emoji: Some(EmojiReactionType::Unicode {
+            name: "🗑️".to_owned(),
+        }),
+        label: None,
+        style: ButtonStyle::Secondary,
+        url: None,
+        sku_id: None,
+    };
+
+    vec![Component::ActionRow(ActionRow {
+        components: vec![Component::Button(button)],
+    })]
+}
+
+/// Returns the owner of the response if the component is a delete button.
+pub fn delete_response_owner(component: &InteractionComponent) -> Option<Id<UserMarker>> {
+    component
+        .data
+        .custom_id
+        .strip_prefix(DELETE_RESPONSE_PREFIX)
+        .and_then(|owner| owner.parse().ok())
+}
+
+pub async fn handle_delete_response(component: InteractionComponent, owner: Id<UserMarker>) {
+    let start = Instant::now();
+
+    EventKind::Component
+        .log(&component, &component.data.custom_id)
+        .await;
+
+    if let Err(err) = delete_response(&component, owner).await {
+        BotMetrics::inc_command_error("component", component.data.custom_id.clone());
+        error!(?err, "Failed to delete response");
+    }
+
+    let elapsed = start.elapsed();
+    BotMetrics::observe_command("component", component.data.custom_id, elapsed);
+}
+
+async fn delete_response(component: &InteractionComponent, owner: Id<UserMarker>) -> Result<()> {
+    let user_id = component.user_id()?;
+
+    if user_id != owner {
+        match check_authority(user_id, component.guild_id).await {
+            Ok(None) => {}
+            Ok(Some(_)) => {
+                let content = "Only the command author can delete this";
+                let builder = MessageBuilder::new().embed(content);
+
+                component
+                    .ephemeral(builder)
+                    .await
+                    .wrap_err("Failed to respond to non-owner")?;
+
+                return Ok(());
+            }
+            Err(err) => return Err(err.wrap_err("Failed to check authority")),
+        }
+    }
+
+    component
+        .defer()
+        .await
+        .wrap_err("Failed to defer component")?;
+
+    // Deleting through the interaction token also works for ephemeral messages
+    Context::interaction()
+        .delete_response(&component.token)
+        .await
+        .wrap_err("Failed to delete message")?;
+
+    Ok(())
+}
@@ -6,6 +6,7 @@ bitflags::bitflags! {
         const ONLY_GUILDS = 1 << 2;
         const ONLY_OWNER  = 1 << 3;
         const SKIP_DEFER  = 1 << 4;
+        const BANCHO_ONLY = 1 << 5;
     }
 }
 
@@ -29,4 +30,8 @@ impl CommandFlags {
     pub fn only_owner(self) -> bool {
         self.contains(CommandFlags::ONLY_OWNER)
     }
+
+    pub fn bancho_only(self) -> bool {
+        self.contains(CommandFlags::BANCHO_ONLY)
+    }
 }
@@ -0,0 +1,177 @@
+use std::{future::Future, time::Instant};
+
+use bathbot_psql::model::configs::GuildConfig;
+use bathbot_util::{EmbedBuilder, FooterBuilder, MessageBuilder};
+use eyre::{ContextCompat, Result, WrapErr};
+use twilight_interactions::command::ApplicationCommandData;
+use twilight_model::{
+    application::command::Command,
+    channel::message::{
+        Component, EmojiReactionType,
+        component::{ActionRow, Button, ButtonStyle},
+        embed::EmbedField,
+    },
+};
+
+use crate::{
+    core::{
+        BotMetrics, Context, EventKind,
+        commands::{
+            CommandOrigin,
+            interaction::{InteractionCommandKind, InteractionCommands},
+            prefix::PrefixCommands,
+        },
+    },
+    util::{ComponentExt, interaction::InteractionComponent},
+};
+
+const SHOW_HELP_PREFIX: &str = "show_help_";
+const SLASH_KIND: &str = "slash_";
+const PREFIX_KIND: &str = "prefix_";
+
+tokio::task_local! {
+    /// Name of the prefix command that is currently being processed.
+    static PREFIX_COMMAND: &'static str;
+}
+
+/// Process a prefix command such that error responses can refer to the
+/// command's help.
+pub async fn with_prefix_command<F: Future>(name: &'static str, fut: F) -> F::Output {
+    PREFIX_COMMAND.scope(name, fut).await
+}
+
+/// Components containing a single button that shows the help of the command
+/// that the origin belongs to.
+pub fn show_help_components(orig: &CommandOrigin<'_>) -> Vec<Component> {
+    let custom_id = match orig {
+        CommandOrigin::Message { .. } => match PREFIX_COMMAND.try_with(|name| *name) {
+            Ok(name) => format!("{SHOW_HELP_PREFIX}{PREFIX_KIND}{name}"),
+            Err(_) => return Vec::new(),
+        },
+        CommandOrigin::Interaction { command } => {
+            format!("{SHOW_HELP_PREFIX}{SLASH_KIND}{}", command.data.name)
+        }
+    };
+
+    let button = Button {
+        custom_id: Some(custom_id),
+        disabled: false,
+        emoji: Some(EmojiReactionType::Unicode {
+            name: "❔".to_owned(),
+        }),
+        label: Some("Show help".to_owned()),
+        style: ButtonStyle::Secondary,
+        url: None,
+        sku_id: None,
+    };
+
+    vec![Component::ActionRow(ActionRow {
+        components: vec![Component::Button(button)],
+    })]
+}
+
+/// Whether the component is a button to show a command's help.
+pub fn is_show_help(component: &InteractionComponent) -> bool {
+    component.data.custom_id.starts_with(SHOW_HELP_PREFIX)
+}
+
+pub async fn handle_show_help(component: InteractionComponent) {
+    let start = Instant::now();
+
+    EventKind::Component
+        .log(&component, &component.data.custom_id)
+        .await;
+
+    if let Err(err) = show_help(&component).await {
+        BotMetrics::inc_command_error("component", component.data.custom_id.clone());
+        error!(?err, "Failed to show command help");
+    }
+
+    let elapsed = start.elapsed();
+    BotMetrics::observe_command("component", component.data.custom_id, elapsed);
+}
+
+async fn show_help(component: &InteractionComponent) -> Result<()> {
+    let target = component
+        .data
+        .custom_id
+        .strip_prefix(SHOW_HELP_PREFIX)
+        .wrap_err("Missing help button prefix")?;
+
+    let embed = if let Some(name) = target.strip_prefix(SLASH_KIND) {
+        slash_help(name)?
+    } else if let Some(name) = target.strip_prefix(PREFIX_KIND) {
+        prefix_help(component, name).await?
+    } else {
+        bail!("Unknown help button target `{target}`");
+    };
+
+    let builder = MessageBuilder::new().embed(embed);
+
+    component
+        .ephemeral(builder)
+        .await
+        .wrap_err("Failed to respond with help")?;
+
+    Ok(())
+}
+
+fn slash_help(name: &str) -> Result<EmbedBuilder> {
+    let help = match InteractionCommands::get_command(name) {
+        Some(InteractionCommandKind::Chat(cmd)) => {
+            let ApplicationCommandData {
+                help, description, ..
+            } = (cmd.create)();
+
+            help.map_or(description, str::to_owned)
+        }
+        Some(InteractionCommandKind::Message(cmd)) => {
+            let Command { description, .. } = (cmd.create)();
+
+            description
+        }
+        None => bail!("Unknown slash command `{name}`"),
+    };
+
+    let footer = format!("Use /help command:{name} for details on all options");
+
+    let embed = EmbedBuilder::new()
+        .title(format!("/{name}"))
+        .description(help)
+        .footer(FooterBuilder::new(footer));
+
+    Ok(embed)
+}
+
+async fn prefix_help(component: &InteractionComponent, name: &str) -> Result<EmbedBuilder> {
+    let cmd = PrefixCommands::get()
+        .command(name)
+        .wrap_err_with(|| format!("Unknown prefix command `{name}`"))?;
+
+    let mut embed = EmbedBuilder::new()
+        .title(name)
+        .description(cmd.help.unwrap_or(cmd.desc));
+
+    if let Some(usage) = cmd.usage {
+        let prefix = match component.guild_id {
+            Some(guild_id) => {
+                Context::guild_config()
+                    .peek(guild_id, |config| config.prefixes.first().cloned())
+                    .await
+            }
+            None => None,
+        };
+
+        let prefix = prefix.as_deref().unwrap_or(GuildConfig::DEFAULT_PREFIX);
+
+        let field = EmbedField {
+            inline: false,
+            name: "How to use".to_owned(),
+            value: format!("`{prefix}{name} {usage}`"),
+        };
+
+        embed = embed.fields(vec![field]);
+    }
+
+    Ok(embed)
+}
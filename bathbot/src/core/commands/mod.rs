@@ -1,11 +1,13 @@
 pub use self::{
     flags::CommandFlags,
-    origin::{CommandOrigin, OwnedCommandOrigin},
+    origin::{CommandOrigin, OwnedCommandOrigin, restrict_attachment},
 };
 
 mod flags;
 mod origin;
 
 pub mod checks;
+pub mod delete;
+pub mod help_button;
 pub mod interaction;
 pub mod prefix;
@@ -1,3 +1,6 @@
+use std::fmt::Write;
+
+use bathbot_psql::model::configs::GuildConfig;
 use bathbot_util::{Authored, EmbedBuilder, MessageBuilder};
 use eyre::{ContextCompat, Result, WrapErr};
 use twilight_http::Response;
@@ -11,7 +14,11 @@ use twilight_model::{
 };
 
 use crate::{
-    core::Context,
+    core::{
+        Context,
+        commands::{delete::add_delete_response_row, help_button::show_help_components},
+    },
+    manager::redis::osu::take_alias_notes,
     util::{
         ChannelExt, InteractionCommandExt, InteractionToken, MessageExt,
         interaction::{InteractionCommand, InteractionComponent},
@@ -56,6 +63,9 @@ impl CommandOrigin<'_> {
     ///
     /// In case of an interaction, the response will **not** be ephemeral.
     pub async fn callback(&self, builder: MessageBuilder<'_>) -> Result<()> {
+        let builder = add_alias_notes(builder);
+        let builder = self.restrict_attachment(builder).await;
+
         match self {
             Self::Message { msg, permissions } => msg
                 .create_message(builder, *permissions)
@@ -77,6 +87,9 @@ impl CommandOrigin<'_> {
         &self,
         builder: MessageBuilder<'_>,
     ) -> Result<Response<Message>> {
+        let builder = add_alias_notes(builder);
+        let builder = self.restrict_attachment(builder).await;
+
         match self {
             Self::Message { msg, permissions } => msg
                 .create_message(builder, *permissions)
@@ -106,6 +119,9 @@ impl CommandOrigin<'_> {
         builder: MessageBuilder<'_>,
         ephemeral: bool,
     ) -> Result<()> {
+        let builder = add_alias_notes(builder);
+        let builder = self.restrict_attachment(builder).await;
+
         match self {
             Self::Message { msg, permissions } => msg
                 .create_message(builder, *permissions)
@@ -126,7 +142,19 @@ impl CommandOrigin<'_> {
     /// either through deferring or a previous initial response.
     /// Also be sure this is only called once.
     /// Afterwards, use the resulting response message instead.
-    pub async fn create_message(&self, builder: MessageBuilder<'_>) -> Result<Response<Message>> {
+    ///
+    /// A row with a button is appended to the components that lets the
+    /// invoker or authorities delete the response.
+    pub async fn create_message(
+        &self,
+        mut builder: MessageBuilder<'_>,
+    ) -> Result<Response<Message>> {
+        let owner = self.user_id()?;
+        add_delete_response_row(builder.components.get_or_insert_with(Vec::new), owner);
+
+        let builder = add_alias_notes(builder);
+        let builder = self.restrict_attachment(builder).await;
+
         match self {
             Self::Message { msg, permissions } => msg
                 .create_message(builder, *permissions)
@@ -140,7 +168,15 @@ impl CommandOrigin<'_> {
     }
 
     /// Update a response and return the resulting response message.
-    pub async fn update(&self, builder: MessageBuilder<'_>) -> Result<Response<Message>> {
+    ///
+    /// If the components are replaced, the delete button is kept.
+    pub async fn update(&self, mut builder: MessageBuilder<'_>) -> Result<Response<Message>> {
+        if let Some(ref mut components) = builder.components {
+            add_delete_response_row(components, self.user_id()?);
+        }
+
+        let builder = self.restrict_attachment(builder).await;
+
         match self {
             Self::Message { msg, permissions } => msg
                 .update(builder, *permissions)
@@ -154,6 +190,40 @@ impl CommandOrigin<'_> {
         }
     }
 
+    /// Omit the builder's attachment if the guild does not allow images in
+    /// the channel or if the author enabled data saver mode.
+    async fn restrict_attachment<'b>(&self, builder: MessageBuilder<'b>) -> MessageBuilder<'b> {
+        let user_id = self.user_id().ok();
+
+        restrict_attachment(builder, self.guild_id(), self.channel_id(), user_id).await
+    }
+
+    /// Respond with a red embed and a button that shows the command's help.
+    ///
+    /// Intended for errors caused by invalid arguments.
+    ///
+    /// In case of an interaction, be sure you already called back beforehand.
+    pub async fn error_with_help(&self, content: impl Into<String>) -> Result<()> {
+        let embed = EmbedBuilder::new().description(content).color_red();
+
+        let builder = MessageBuilder::new()
+            .embed(embed)
+            .components(show_help_components(self));
+
+        match self {
+            Self::Message { msg, permissions } => msg
+                .create_message(builder, *permissions)
+                .await
+                .map(unit)
+                .wrap_err("failed to respond with error"),
+            Self::Interaction { command } => command
+                .update(builder)
+                .await
+                .map(unit)
+                .wrap_err("failed to respond with error"),
+        }
+    }
+
     /// Respond with a red embed.
     ///
     /// In case of an interaction, be sure you already called back beforehand.
@@ -276,6 +346,19 @@ impl OwnedCommandOrigin {
 
 fn unit<T>(_: T) {}
 
+/// Point out names in the content that were resolved through a previous
+/// username.
+fn add_alias_notes(mut builder: MessageBuilder<'_>) -> MessageBuilder<'_> {
+    if let Some(notes) = take_alias_notes() {
+        builder.content = match builder.content.take() {
+            Some(content) => Some(format!("{notes}\n{content}").into()),
+            None => Some(notes.into()),
+        };
+    }
+
+    builder
+}
+
 impl From<(Message, Option<Permissions>)> for OwnedCommandOrigin {
     fn from((msg, permissions): (Message, Option<Permissions>)) -> Self {
         Self::Message {
@@ -313,3 +396,59 @@ impl From<&InteractionComponent> for OwnedCommandOrigin {
         }
     }
 }
+
+/// Omit the builder's attachment if the guild does not allow images in the
+/// channel or if the user enabled data saver mode.
+///
+/// Applies to every response that may contain an image, including updates of
+/// active messages through components.
+pub async fn restrict_attachment<'b>(
+    builder: MessageBuilder<'b>,
+    guild_id: Option<Id<GuildMarker>>,
+    channel_id: Id<ChannelMarker>,
+    user_id: Option<Id<UserMarker>>,
+) -> MessageBuilder<'b> {
+    if builder.attachments.is_empty() {
+        return builder;
+    }
+
+    if let Some(guild_id) = guild_id {
+        let f = |config: &GuildConfig| {
+            let channels = &config.image_channels;
+
+            if channels.is_empty() || channels.contains(&channel_id) {
+                return None;
+            }
+
+            let mut reason = "because images are only allowed in ".to_owned();
+
+            for (i, channel) in channels.iter().enumerate() {
+                if i > 0 {
+                    reason.push_str(", ");
+                }
+
+                let _ = write!(reason, "<#{channel}>");
+            }
+
+            Some(reason)
+        };
+
+        if let Some(reason) = Context::guild_config().peek(guild_id, f).await {
+            return builder.omit_attachment(&reason);
+        }
+    }
+
+    let Some(user_id) = user_id else {
+        return builder;
+    };
+
+    match Context::user_config().data_saver(user_id).await {
+        Ok(true) => builder.omit_attachment("due to data saver mode, see `/config`"),
+        Ok(false) => builder,
+        Err(err) => {
+            warn!(?err, "Failed to get data saver config");
+
+            builder
+        }
+    }
+}
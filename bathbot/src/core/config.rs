@@ -1,5 +1,6 @@
 use std::{env, fmt::Debug, mem::MaybeUninit, path::PathBuf, str::FromStr};
 
+use bathbot_client::OsuServer;
 use eyre::Result;
 use once_cell::sync::OnceCell;
 use rosu_v2::model::Grade;
@@ -43,6 +44,8 @@ pub struct Paths {
 pub struct Server {
     pub port: u16,
     pub public_url: Box<str>,
+    /// Secret that third-party trackers sign their scores with
+    pub score_webhook_secret: Option<Box<str>>,
 }
 
 #[derive(Debug)]
@@ -50,6 +53,12 @@ pub struct Tokens {
     pub discord: Box<str>,
     pub osu_client_id: u64,
     pub osu_client_secret: Box<str>,
+    /// Additional osu! clients that requests are distributed across
+    pub osu_extra_clients: Vec<OsuCredentials>,
+    /// osu! client that is reserved for background tasks
+    pub osu_background_client: Option<OsuCredentials>,
+    /// Private osu! servers that guilds may target instead of bancho
+    pub osu_servers: Vec<OsuServer>,
     #[cfg(not(debug_assertions))]
     pub ordr_key: Box<str>,
     pub github_token: Box<str>,
@@ -59,6 +68,14 @@ pub struct Tokens {
     pub twitch_token: Box<str>,
 }
 
+#[derive(Debug)]
+pub struct OsuCredentials {
+    pub client_id: u64,
+    pub client_secret: Box<str>,
+    /// Requests per second this client may use
+    pub ratelimit: Option<u32>,
+}
+
 impl BotConfig {
     pub fn get() -> &'static Self {
         CONFIG
@@ -97,6 +114,9 @@ impl BotConfig {
                 discord: env_var("DISCORD_TOKEN")?,
                 osu_client_id: env_var("OSU_CLIENT_ID")?,
                 osu_client_secret: env_var("OSU_CLIENT_SECRET")?,
+                osu_extra_clients: opt_env_var("OSU_EXTRA_CLIENTS")?.unwrap_or_default(),
+                osu_background_client: opt_env_var("OSU_BACKGROUND_CLIENT")?,
+                osu_servers: opt_env_var("OSU_SERVERS")?.unwrap_or_default(),
                 #[cfg(not(debug_assertions))]
                 ordr_key: env_var("ORDR_KEY")?,
                 github_token: env_var("GITHUB_TOKEN")?,
@@ -115,6 +135,7 @@ impl BotConfig {
             server: Server {
                 port: env_var("SERVER_PORT")?,
                 public_url: env_var("PUBLIC_URL")?,
+                score_webhook_secret: env_var("SCORE_WEBHOOK_SECRET").ok(),
             },
             grades,
             emotes,
@@ -213,6 +234,88 @@ impl EnvKind for CustomEmote {
     }
 }
 
+impl OsuCredentials {
+    fn parse(s: &str) -> Option<Self> {
+        let mut split = s.trim().split(':');
+        let client_id = split.next()?.parse().ok()?;
+        let client_secret = split.next().filter(|secret| !secret.is_empty())?;
+
+        let ratelimit = match split.next() {
+            Some(ratelimit) => Some(ratelimit.parse().ok()?),
+            None => None,
+        };
+
+        if split.next().is_some() {
+            return None;
+        }
+
+        Some(Self {
+            client_id,
+            client_secret: Box::from(client_secret),
+            ratelimit,
+        })
+    }
+}
+
+impl EnvKind for OsuCredentials {
+    const EXPECTED: &'static str = "`client_id:client_secret` with optional `:ratelimit` suffix";
+
+    fn from_str(s: String) -> Result<Self, String> {
+        Self::parse(&s).ok_or(s)
+    }
+}
+
+impl EnvKind for Vec<OsuCredentials> {
+    const EXPECTED: &'static str =
+        "a comma-separated list of `client_id:client_secret` with optional `:ratelimit` suffix";
+
+    fn from_str(s: String) -> Result<Self, String> {
+        s.split(',')
+            .filter(|entry| !entry.trim().is_empty())
+            .map(OsuCredentials::parse)
+            .collect::<Option<_>>()
+            .ok_or(s)
+    }
+}
+
+impl EnvKind for Vec<OsuServer> {
+    const EXPECTED: &'static str =
+        "a comma-separated list of `name=base_url|client_id:client_secret`";
+
+    fn from_str(s: String) -> Result<Self, String> {
+        fn parse(s: &str) -> Option<OsuServer> {
+            let (name, rest) = s.trim().split_once('=')?;
+            let (base_url, credentials) = rest.split_once('|')?;
+            let (client_id, client_secret) = credentials.split_once(':')?;
+
+            if name.is_empty() || base_url.is_empty() || client_secret.is_empty() {
+                return None;
+            }
+
+            Some(OsuServer {
+                name: Box::from(name),
+                base_url: Box::from(base_url),
+                client_id: client_id.parse().ok()?,
+                client_secret: Box::from(client_secret),
+            })
+        }
+
+        s.split(',')
+            .filter(|entry| !entry.trim().is_empty())
+            .map(parse)
+            .collect::<Option<_>>()
+            .ok_or(s)
+    }
+}
+
+/// Missing or empty env variables are considered to be `None`.
+fn opt_env_var<T: EnvKind>(name: &str) -> Result<Option<T>> {
+    match env::var(name) {
+        Ok(value) if !value.trim().is_empty() => env_var(name).map(Some),
+        _ => Ok(None),
+    }
+}
+
 fn env_var<T: EnvKind>(name: &str) -> Result<T> {
     let value = env::var(name).map_err(|_| eyre!("missing env variable `{name}`"))?;
 
@@ -130,12 +130,21 @@ impl Context {
                 }
             };
 
-            if let Err(err) = command_res {
-                let wrap = format!("Failed to request members for guild {guild_id}");
-                warn!("{:?}", Report::new(err).wrap_err(wrap));
+            match command_res {
+                Ok(_) => {
+                    ctx.member_requests
+                        .awaiting_chunks
+                        .lock()
+                        .unwrap()
+                        .insert(guild_id);
+                }
+                Err(err) => {
+                    let wrap = format!("Failed to request members for guild {guild_id}");
+                    warn!("{:?}", Report::new(err).wrap_err(wrap));
 
-                if let Err(err) = ctx.member_requests.tx.send((guild_id, shard_id)) {
-                    warn!("Failed to re-forward member request: {err}");
+                    if let Err(err) = ctx.member_requests.tx.send((guild_id, shard_id)) {
+                        warn!("Failed to re-forward member request: {err}");
+                    }
                 }
             }
         }
@@ -3,9 +3,10 @@ use rosu_v2::prelude::GameMode;
 
 use super::Context;
 use crate::manager::{
-    ApproxManager, BookmarkManager, GameManager, GithubManager, GuildConfigManager,
-    HuismetbenenCountryManager, MapManager, OsuMap, OsuUserManager, PpManager, ReplayManager,
-    ScoresManager, UserConfigManager, redis::RedisManager,
+    ApproxManager, BadgeNotifyManager, BookmarkManager, FavoritesManager, GameManager,
+    GithubManager, GuildConfigManager, HuismetbenenCountryManager, MapManager, MilestoneManager,
+    OsuMap, OsuUserManager, PpManager, RefscriptManager, ReplayManager, ScheduledMatchManager,
+    ScoresManager, SimulatePresetManager, UserConfigManager, redis::RedisManager,
 };
 
 impl Context {
@@ -48,10 +49,18 @@ impl Context {
         BookmarkManager::new()
     }
 
+    pub fn favorites() -> FavoritesManager {
+        FavoritesManager::new()
+    }
+
     pub fn replay() -> ReplayManager {
         let ctx = Self::get();
 
-        ReplayManager::new(&ctx.clients.psql, &ctx.clients.osu, &ctx.data.cache)
+        ReplayManager::new(
+            &ctx.clients.psql,
+            ctx.clients.osu.primary(),
+            &ctx.data.cache,
+        )
     }
 
     pub fn github() -> GithubManager {
@@ -73,4 +82,24 @@ impl Context {
     pub fn huismetbenen() -> HuismetbenenCountryManager {
         HuismetbenenCountryManager::new()
     }
+
+    pub fn simulate_presets() -> SimulatePresetManager {
+        SimulatePresetManager::new()
+    }
+
+    pub fn scheduled_matches() -> ScheduledMatchManager {
+        ScheduledMatchManager::new()
+    }
+
+    pub fn refscripts() -> RefscriptManager {
+        RefscriptManager::new()
+    }
+
+    pub fn badge_notify() -> BadgeNotifyManager {
+        BadgeNotifyManager::new()
+    }
+
+    pub fn milestones() -> MilestoneManager {
+        MilestoneManager::new()
+    }
 }
@@ -1,13 +1,16 @@
 use std::{collections::hash_map::Entry, slice};
 
-use rosu_v2::prelude::{MatchEvent, OsuError};
+use rosu_v2::prelude::{MatchEvent, OsuError, OsuMatch};
 use tokio::time::{Duration, interval};
 use twilight_model::id::{Id, marker::ChannelMarker};
 
 use super::Context;
 use crate::{
     embeds::MatchLiveEmbed,
-    matchlive::{Channel, MatchEntry, MatchTrackResult, TrackedMatch, send_match_messages},
+    matchlive::{
+        BracketEntry, Channel, MatchAnnotateResult, MatchEntry, MatchSummary, MatchTrackResult,
+        TrackedMatch, WinProbability, send_match_messages,
+    },
     util::ChannelExt,
 };
 
@@ -30,7 +33,13 @@ impl Context {
             .map(|(key, _)| *key)
     }
 
-    pub async fn add_match_track(channel: Id<ChannelMarker>, match_id: u32) -> MatchTrackResult {
+    /// `thread` specifies whether the channel is a thread that was created
+    /// specifically for the match.
+    pub async fn add_match_track(
+        channel: Id<ChannelMarker>,
+        match_id: u32,
+        thread: bool,
+    ) -> MatchTrackResult {
         let mut match_live = Context::get().data.matchlive.inner.lock().await;
 
         // Increment the track counter for the channel
@@ -55,9 +64,10 @@ impl Context {
                 }
 
                 let embeds = &entry.tracked.embeds;
+                let bracket = entry.tracked.bracket.field();
 
-                let channel = match send_match_messages(channel, embeds).await {
-                    Ok(msg) => Channel::new(channel, msg),
+                let channel = match send_match_messages(channel, embeds, bracket).await {
+                    Ok(msg) => Channel::new(channel, msg, thread),
                     Err(err) => {
                         error!("{err:?}");
 
@@ -72,12 +82,14 @@ impl Context {
                 MatchTrackResult::Added
             }
             // The match is not yet tracked -> request and store it
-            Entry::Vacant(e) => match Context::osu().osu_match(match_id).await {
+            Entry::Vacant(e) => match Context::osu_request(|osu| osu.osu_match(match_id)).await {
                 Ok(osu_match) => {
-                    let embeds = MatchLiveEmbed::new(&osu_match);
+                    let mut win_probability = WinProbability::default();
+                    win_probability.fetch_pp(&osu_match).await;
+                    let embeds = MatchLiveEmbed::new(&osu_match, &mut win_probability);
 
-                    let channel = match send_match_messages(channel, &embeds).await {
-                        Ok(msg) => Channel::new(channel, msg),
+                    let channel = match send_match_messages(channel, &embeds, None).await {
+                        Ok(msg) => Channel::new(channel, msg, thread),
                         Err(err) => {
                             error!("{err:?}");
 
@@ -87,10 +99,23 @@ impl Context {
 
                     // Only add to tracking if it's not already disbanded
                     if !matches!(osu_match.events.last(), Some(MatchEvent::Disbanded { .. })) {
-                        let tracked_match = TrackedMatch::new(osu_match, embeds);
+                        let tracked_match = TrackedMatch::new(osu_match, embeds, win_probability);
                         let id = channel.id;
                         e.insert(MatchEntry::new(tracked_match, channel));
                         *match_live.channel_count.entry(id).or_insert(0) += 1;
+                    } else if channel.thread {
+                        let mut summary = MatchSummary::default();
+                        summary.add_games(&osu_match);
+
+                        if let Some(embed) = MatchLiveEmbed::summary(&osu_match, &summary) {
+                            let embeds = slice::from_ref(&embed);
+
+                            if let Err(err) = send_match_messages(channel.id, embeds, None).await {
+                                warn!(?err, "Failed to send summary msg");
+                            }
+                        }
+
+                        conclude_thread(channel.id, &osu_match, &summary).await;
                     }
 
                     MatchTrackResult::Added
@@ -108,6 +133,49 @@ impl Context {
         }
     }
 
+    /// Add a pick or ban to the bracket of a match that is tracked in the
+    /// channel and update the latest message of all channels tracking it.
+    pub async fn annotate_match_track(
+        channel: Id<ChannelMarker>,
+        match_id: u32,
+        entry: BracketEntry,
+    ) -> MatchAnnotateResult {
+        let mut match_live = Context::get().data.matchlive.inner.lock().await;
+
+        let Some(match_entry) = match_live
+            .match_channels
+            .get_mut(&match_id)
+            .filter(|e| e.channels.iter().any(|c| c.id == channel))
+        else {
+            return MatchAnnotateResult::NotTracked;
+        };
+
+        let tracked_match = &mut match_entry.tracked;
+
+        if !tracked_match.bracket.push(entry) {
+            return MatchAnnotateResult::Duplicate;
+        }
+
+        let Some(data) = tracked_match.embeds.last() else {
+            return MatchAnnotateResult::Annotated;
+        };
+
+        let mut embed = data.as_embed();
+        embed.fields.extend(tracked_match.bracket.field());
+
+        for Channel { id, msg_id, .. } in match_entry.channels.iter() {
+            let update_fut = Context::http()
+                .update_message(*id, *msg_id)
+                .embeds(Some(slice::from_ref(&embed)));
+
+            if let Err(err) = update_fut.await {
+                warn!(?err, "Failed to update msg with bracket");
+            }
+        }
+
+        MatchAnnotateResult::Annotated
+    }
+
     /// Returns false if the match wasn't tracked in the channel
     pub async fn remove_match_track(channel: Id<ChannelMarker>, match_id: u32) -> bool {
         let mut match_live = Context::get().data.matchlive.inner.lock().await;
@@ -178,7 +246,10 @@ impl Context {
                     let tracked_match = &mut entry.tracked;
 
                     // Request an update
-                    let next_match = match tracked_match.osu_match.get_next(Context::osu()).await {
+                    let next_fut =
+                        Context::osu_request(|osu| tracked_match.osu_match.get_next(osu));
+
+                    let next_match = match next_fut.await {
                         Ok(next_match) => next_match,
                         Err(err) => {
                             warn!(?err, "Failed to request match");
@@ -187,26 +258,40 @@ impl Context {
                         }
                     };
 
+                    tracked_match.win_probability.fetch_pp(&next_match).await;
+
                     // Update the embeds
                     let (update, new_embeds) = tracked_match
                         .embeds
                         .last_mut()
                         .expect("no last live embed")
-                        .update(&next_match);
+                        .update(&next_match, &mut tracked_match.win_probability);
 
-                    if next_match.end_time.is_some() {
-                        remove.push(next_match.match_id);
-                    }
+                    tracked_match.summary.add_games(&next_match);
+                    tracked_match.bracket.add_games(&next_match);
+
+                    let summary_embed = match next_match.end_time {
+                        Some(_) => {
+                            remove.push(next_match.match_id);
+
+                            MatchLiveEmbed::summary(&next_match, &tracked_match.summary)
+                        }
+                        None => None,
+                    };
 
                     tracked_match.osu_match = next_match;
 
+                    let bracket = tracked_match.bracket.field();
+
                     // If there was an update for the last embed
                     if update {
                         let data = tracked_match.embeds.last().unwrap();
 
                         // For every channel that's tracking the match
-                        for Channel { id, msg_id } in entry.channels.iter() {
-                            let embed = Some(data.as_embed());
+                        for Channel { id, msg_id, .. } in entry.channels.iter() {
+                            let mut embed = data.as_embed();
+                            embed.fields.extend(bracket.clone());
+                            let embed = Some(embed);
 
                             // Update the last message
                             let update_fut = http
@@ -221,8 +306,8 @@ impl Context {
 
                     // For all new embeds, send them to all channels
                     if let Some(embeds) = new_embeds {
-                        for Channel { id, msg_id } in entry.channels.iter_mut() {
-                            match send_match_messages(*id, &embeds).await {
+                        for Channel { id, msg_id, .. } in entry.channels.iter_mut() {
+                            match send_match_messages(*id, &embeds, bracket.clone()).await {
                                 Ok(msg) => *msg_id = msg,
                                 Err(err) => {
                                     error!(channel = id.get(), ?err, "Failed to send last msg")
@@ -232,6 +317,28 @@ impl Context {
 
                         tracked_match.embeds.extend(embeds);
                     }
+
+                    // Conclude the match with a summary of all games
+                    if let Some(embed) = summary_embed {
+                        for Channel { id, msg_id, .. } in entry.channels.iter_mut() {
+                            let embeds = slice::from_ref(&embed);
+
+                            match send_match_messages(*id, embeds, bracket.clone()).await {
+                                Ok(msg) => *msg_id = msg,
+                                Err(err) => {
+                                    error!(channel = id.get(), ?err, "Failed to send summary msg")
+                                }
+                            }
+                        }
+                    }
+
+                    // Rename and archive threads that were created for the match
+                    if tracked_match.osu_match.end_time.is_some() {
+                        for channel in entry.channels.iter().filter(|channel| channel.thread) {
+                            let lobby = &tracked_match.osu_match;
+                            conclude_thread(channel.id, lobby, &tracked_match.summary).await;
+                        }
+                    }
                 }
             }
 
@@ -265,3 +372,105 @@ impl Context {
         notified
     }
 }
+
+/// Max length of a thread name as imposed by discord
+const THREAD_NAME_LIMIT: usize = 100;
+
+/// Rename the thread to include the final score and archive it.
+async fn conclude_thread(thread: Id<ChannelMarker>, lobby: &OsuMatch, summary: &MatchSummary) {
+    let name = thread_name(&lobby.name, summary.team_wins);
+
+    let update_fut = Context::http()
+        .update_thread(thread)
+        .name(&name)
+        .archived(true);
+
+    if let Err(err) = update_fut.await {
+        warn!(
+            thread = thread.get(),
+            ?err,
+            "Failed to conclude matchlive thread"
+        );
+    }
+}
+
+/// The thread name after the match ended e.g. `Team A 5–3 Team B` if the team
+/// names can be parsed from the lobby name, `<lobby name> | 5 - 3` otherwise.
+fn thread_name(lobby_name: &str, team_wins: [u32; 3]) -> String {
+    let [_, blue, red] = team_wins;
+
+    if blue == 0 && red == 0 {
+        return truncate_thread_name(lobby_name, " | Finished");
+    }
+
+    match team_names(lobby_name) {
+        // Tournament lobbies name the red team first
+        Some((first, second)) => {
+            let name = format!("{first} {red}–{blue} {second}");
+
+            truncate_thread_name(&name, "")
+        }
+        None => truncate_thread_name(lobby_name, &format!(" | {blue} - {red}")),
+    }
+}
+
+fn truncate_thread_name(name: &str, suffix: &str) -> String {
+    let name_len = THREAD_NAME_LIMIT - suffix.chars().count();
+    let mut name: String = name.chars().take(name_len).collect();
+    name.push_str(suffix);
+
+    name
+}
+
+/// Parse the team names of tournament lobbies which are usually named
+/// `ACRONYM: (Team A) vs (Team B)`.
+fn team_names(lobby_name: &str) -> Option<(&str, &str)> {
+    let (first, second) = lobby_name
+        .split_once(" vs ")
+        .or_else(|| lobby_name.split_once(" VS "))?;
+
+    let first = first.rsplit_once(':').map_or(first, |(_, team)| team);
+
+    let first = strip_parens(first);
+    let second = strip_parens(second);
+
+    (!first.is_empty() && !second.is_empty()).then_some((first, second))
+}
+
+fn strip_parens(team: &str) -> &str {
+    let team = team.trim();
+
+    team.strip_prefix('(')
+        .and_then(|team| team.strip_suffix(')'))
+        .unwrap_or(team)
+        .trim()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_team_names() {
+        assert_eq!(
+            team_names("OWC2023: (United States) vs (Germany)"),
+            Some(("United States", "Germany"))
+        );
+        assert_eq!(team_names("(A) VS (B)"), Some(("A", "B")));
+        assert_eq!(team_names("Lobby of someone"), None);
+        assert_eq!(team_names("OWC: () vs (B)"), None);
+    }
+
+    #[test]
+    fn concluded_thread_names() {
+        assert_eq!(
+            thread_name("OWC: (Team A) vs (Team B)", [0, 3, 5]),
+            "Team A 5–3 Team B"
+        );
+        assert_eq!(thread_name("some lobby", [0, 2, 1]), "some lobby | 2 - 1");
+        assert_eq!(
+            thread_name("some lobby", [0, 0, 0]),
+            "some lobby | Finished"
+        );
+    }
+}
@@ -44,10 +44,25 @@ impl Context {
 
     pub async fn find_map_id_in_msg(msg: &Message) -> Option<MapIdType> {
         if let id @ Some(_) = Self::find_map_id_in_content(&msg.content) {
-            id
-        } else {
-            Self::find_map_id_in_embeds(&msg.embeds).await
+            return id;
         }
+
+        if let id @ Some(_) = Self::find_map_id_in_embeds(&msg.embeds).await {
+            return id;
+        }
+
+        let is_other_bot =
+            msg.author.bot && msg.author.id != Context::get().data.application_id.cast();
+
+        if is_other_bot {
+            if let id @ Some(_) = Self::find_map_id_in_other_bot_embeds(&msg.embeds) {
+                return id;
+            }
+        }
+
+        msg.attachments
+            .iter()
+            .find_map(|attachment| map_id_from_filename(&attachment.filename))
     }
 
     fn find_map_id_in_content(content: &str) -> Option<MapIdType> {
@@ -60,6 +75,25 @@ impl Context {
             .or_else(|| matcher::get_osu_mapset_id(content).map(MapIdType::Set))
     }
 
+    /// Other bots put map links in all kinds of places so, unlike for our own
+    /// embeds, titles, descriptions, and fields are all considered.
+    fn find_map_id_in_other_bot_embeds(embeds: &[Embed]) -> Option<MapIdType> {
+        embeds.iter().find_map(|embed| {
+            let fields = embed
+                .fields
+                .iter()
+                .flat_map(|field| [field.name.as_str(), field.value.as_str()]);
+
+            embed
+                .title
+                .as_deref()
+                .into_iter()
+                .chain(embed.description.as_deref())
+                .chain(fields)
+                .find_map(Self::find_map_id_in_content)
+        })
+    }
+
     pub async fn find_map_id_in_embeds(embeds: &[Embed]) -> Option<MapIdType> {
         let opt = embeds.iter().find_map(|embed| {
             let url = embed
@@ -150,3 +184,40 @@ impl Context {
         None
     }
 }
+
+/// Map files are usually named after their id, e.g. `123456 Artist - Title.osz`
+/// for mapsets or `1234567.osu` for difficulties.
+fn map_id_from_filename(filename: &str) -> Option<MapIdType> {
+    let (stem, ext) = filename.rsplit_once('.')?;
+
+    match ext {
+        "osz" => {
+            let end = stem
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(stem.len());
+
+            stem[..end].parse().ok().map(MapIdType::Set)
+        }
+        "osu" => stem.parse().ok().map(MapIdType::Map),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn map_id_in_filenames() {
+        assert!(matches!(
+            map_id_from_filename("123456 Artist - Title.osz"),
+            Some(MapIdType::Set(123456))
+        ));
+        assert!(matches!(
+            map_id_from_filename("1234567.osu"),
+            Some(MapIdType::Map(1234567))
+        ));
+        assert!(map_id_from_filename("Artist - Title (Mapper) [Diff].osu").is_none());
+        assert!(map_id_from_filename("123456.png").is_none());
+    }
+}
@@ -1,5 +1,6 @@
 use std::{
     collections::{HashMap, HashSet},
+    future::IntoFuture,
     sync::{Arc, Mutex, OnceLock, RwLock},
     time::Duration,
 };
@@ -26,8 +27,15 @@ use twilight_model::id::{
 };
 use twilight_standby::Standby;
 
-use self::osutrack::OsuTrackUserNotifTimestamps;
-use super::{BotConfig, BotMetrics};
+pub use self::{
+    osu_pool::with_background_osu,
+    osu_server::{guild_osu_server, spawn_with_osu_server, with_osu_server},
+};
+use self::{
+    osu_pool::{OsuPool, OsuResponse},
+    osutrack::OsuTrackUserNotifTimestamps,
+};
+use super::{BotConfig, BotMetrics, RenderQueue};
 use crate::{
     active::{ActiveMessages, impls::BackgroundGame},
     tracking::{Ordr, OsuTracking, ScoresWebSocket, ScoresWebSocketDisconnect},
@@ -37,6 +45,8 @@ mod discord;
 mod games;
 mod manager;
 mod messages;
+mod osu_pool;
+mod osu_server;
 mod osutrack;
 mod set_commands;
 mod shutdown;
@@ -97,8 +107,16 @@ impl Context {
         &Self::get().data.cache
     }
 
-    pub fn osu() -> &'static Osu {
-        &Self::get().clients.osu
+    /// Send an osu!api request through a client of the pool.
+    ///
+    /// Errors that indicate an issue with the client itself make the pool
+    /// skip the client for a while.
+    pub async fn osu_request<R>(req: impl FnOnce(&'static Osu) -> R) -> R::Output
+    where
+        R: IntoFuture,
+        R::Output: OsuResponse,
+    {
+        Self::get().clients.osu.request(req).await
     }
 
     pub fn client() -> &'static BathbotClient {
@@ -144,6 +162,10 @@ impl Context {
         &Self::get().data.miss_analyzer_guilds
     }
 
+    pub fn render_queue() -> &'static RenderQueue {
+        &Self::get().data.render_queue
+    }
+
     pub fn has_miss_analyzer(guild: &Id<GuildMarker>) -> bool {
         Self::miss_analyzer_guilds().read().unwrap().contains(guild)
     }
@@ -194,11 +216,9 @@ impl Context {
             .wrap_err("Failed to create discord http client")?;
 
         // Connect to osu! API
-        let osu_client_id = config.tokens.osu_client_id;
-        let osu_client_secret = &config.tokens.osu_client_secret;
-        let osu = Osu::new(osu_client_id, osu_client_secret.as_ref())
+        let osu = OsuPool::new(&config.tokens)
             .await
-            .wrap_err("Failed to create osu client")?;
+            .wrap_err("Failed to create osu client pool")?;
 
         let cache = Cache::new(&config.redis_host, config.redis_port, config.redis_db_idx)
             .await
@@ -221,6 +241,8 @@ impl Context {
             #[cfg(feature = "twitch")]
             (&config.tokens.twitch_client_id, &config.tokens.twitch_token),
             &config.tokens.github_token,
+            (config.tokens.osu_client_id, &config.tokens.osu_client_secret),
+            &config.tokens.osu_servers,
         );
 
         let custom_client = client_fut
@@ -257,9 +279,10 @@ impl Context {
         let shard_senders = RwLock::new(shard_senders);
 
         #[cfg(feature = "server")]
-        let (auth_standby, server_tx) = bathbot_server(config, _prometheus, reader.clone())
-            .await
-            .wrap_err("Failed to create server")?;
+        let (auth_standby, server_tx, score_rx) =
+            bathbot_server(config, _prometheus, reader.clone())
+                .await
+                .wrap_err("Failed to create server")?;
 
         let clients = Clients {
             http,
@@ -298,6 +321,9 @@ impl Context {
             Err(err) => warn!(?err, "Failed to connect scores websocket"),
         };
 
+        #[cfg(feature = "server")]
+        tokio::spawn(crate::tracking::score_webhook_loop(score_rx));
+
         Ok((
             shards,
             #[cfg(feature = "server")]
@@ -376,6 +402,9 @@ pub type ContextResult = (Shards, tokio::sync::oneshot::Sender<()>);
 pub struct MemberRequests {
     pub tx: UnboundedSender<(Id<GuildMarker>, u32)>,
     pub pending_guilds: Mutex<HashSet<Id<GuildMarker>, IntHasher>>,
+    /// Guilds whose members were requested but whose last chunk has not
+    /// arrived yet
+    pub awaiting_chunks: Mutex<HashSet<Id<GuildMarker>, IntHasher>>,
 }
 
 impl MemberRequests {
@@ -383,15 +412,22 @@ impl MemberRequests {
         Self {
             tx,
             pending_guilds: Mutex::new(HashSet::default()),
+            awaiting_chunks: Mutex::new(HashSet::default()),
         }
     }
+
+    /// Whether the members of all guilds have been received.
+    pub fn is_complete(&self) -> bool {
+        self.pending_guilds.lock().unwrap().is_empty()
+            && self.awaiting_chunks.lock().unwrap().is_empty()
+    }
 }
 
 struct Clients {
     http: Arc<Client>,
     standby: Standby,
     custom: BathbotClient,
-    osu: Osu,
+    osu: OsuPool,
     psql: Database,
     ordr: Option<Arc<Ordr>>,
     #[cfg(feature = "server")]
@@ -411,6 +447,7 @@ struct ContextData {
     guild_shards: GuildShards,
     miss_analyzer_guilds: MissAnalyzerGuilds,
     osutrack_user_notif_timestamps: OsuTrackUserNotifTimestamps,
+    render_queue: RenderQueue,
     #[cfg(feature = "twitch")]
     online_twitch_streams: crate::tracking::OnlineTwitchStreams,
 }
@@ -463,6 +500,7 @@ impl ContextData {
             matchlive: crate::matchlive::MatchLiveChannels::new(),
             miss_analyzer_guilds,
             osutrack_user_notif_timestamps: OsuTrackUserNotifTimestamps::default(),
+            render_queue: RenderQueue::new(),
             #[cfg(feature = "twitch")]
             online_twitch_streams: crate::tracking::OnlineTwitchStreams::default(),
         })
@@ -537,7 +575,10 @@ async fn bathbot_server(
 ) -> Result<(
     Arc<bathbot_server::AuthenticationStandby>,
     tokio::sync::oneshot::Sender<()>,
+    tokio::sync::mpsc::UnboundedReceiver<rosu_v2::prelude::Score>,
 )> {
+    let (score_tx, score_rx) = tokio::sync::mpsc::unbounded_channel();
+
     let builder = bathbot_server::AppStateBuilder {
         website_path: config.paths.website.clone(),
         prometheus,
@@ -547,11 +588,18 @@ async fn bathbot_server(
         twitch_client_id: config.tokens.twitch_client_id.to_string(),
         twitch_token: config.tokens.twitch_token.to_string(),
         redirect_base: config.server.public_url.to_string(),
+        score_webhook_secret: config
+            .server
+            .score_webhook_secret
+            .as_deref()
+            .filter(|secret| !secret.is_empty())
+            .map(str::to_owned),
+        score_tx,
     };
 
     let (server, standby, tx) = bathbot_server::Server::new(builder)?;
 
     tokio::spawn(server.run(config.server.port));
 
-    Ok((standby, tx))
+    Ok((standby, tx, score_rx))
 }
@@ -0,0 +1,237 @@
+use std::{
+    future::{Future, IntoFuture},
+    ops::Deref,
+    ptr,
+    sync::atomic::{AtomicI64, AtomicUsize, Ordering},
+};
+
+use eyre::{Result, WrapErr};
+use rosu_v2::{Osu, prelude::OsuError};
+use time::OffsetDateTime;
+
+use crate::core::{Context, config::Tokens};
+
+/// Requests per second a client may use if not specified otherwise.
+///
+/// The primary client's requests additionally go through the osu!api
+/// ratelimit of bathbot's own client because both use the same credentials.
+const DEFAULT_RATELIMIT: u32 = 15;
+
+/// How many seconds a client is skipped after it failed.
+const UNHEALTHY_COOLDOWN: i64 = 60;
+
+tokio::task_local! {
+    /// Whether the currently processed future is a background task.
+    static BACKGROUND: ();
+}
+
+/// Process a background task such that its osu!api requests use the reserved
+/// background client if one is configured.
+pub async fn with_background_osu<F: Future>(fut: F) -> F::Output {
+    BACKGROUND.scope((), fut).await
+}
+
+/// Distributes osu!api requests across multiple clients so that heavy
+/// features don't exhaust the ratelimit of a single client.
+pub struct OsuPool {
+    clients: Box<[PooledOsu]>,
+    /// Client that is only used by background tasks so that they can't
+    /// exhaust the ratelimit of the clients handling commands.
+    background: Option<PooledOsu>,
+    next: AtomicUsize,
+}
+
+impl OsuPool {
+    pub async fn new(tokens: &Tokens) -> Result<Self> {
+        let primary = PooledOsu::new(
+            tokens.osu_client_id,
+            &tokens.osu_client_secret,
+            DEFAULT_RATELIMIT,
+        )
+        .await
+        .wrap_err("Failed to create primary osu client")?;
+
+        let mut clients = vec![primary];
+
+        for credentials in tokens.osu_extra_clients.iter() {
+            let ratelimit = credentials.ratelimit.unwrap_or(DEFAULT_RATELIMIT);
+            let client_fut =
+                PooledOsu::new(credentials.client_id, &credentials.client_secret, ratelimit);
+
+            match client_fut.await {
+                Ok(client) => clients.push(client),
+                Err(err) => warn!(
+                    client_id = credentials.client_id,
+                    ?err,
+                    "Failed to create additional osu client, skipping it"
+                ),
+            }
+        }
+
+        let background = match tokens.osu_background_client {
+            Some(ref credentials) => {
+                let ratelimit = credentials.ratelimit.unwrap_or(DEFAULT_RATELIMIT);
+                let client_fut =
+                    PooledOsu::new(credentials.client_id, &credentials.client_secret, ratelimit);
+
+                match client_fut.await {
+                    Ok(client) => Some(client),
+                    Err(err) => {
+                        warn!(
+                            client_id = credentials.client_id,
+                            ?err,
+                            "Failed to create background osu client, using the pool instead"
+                        );
+
+                        None
+                    }
+                }
+            }
+            None => None,
+        };
+
+        info!(
+            count = clients.len(),
+            background = background.is_some(),
+            "Initialized osu client pool"
+        );
+
+        Ok(Self {
+            clients: clients.into_boxed_slice(),
+            background,
+            next: AtomicUsize::new(0),
+        })
+    }
+
+    /// Send a request through a client of the pool.
+    ///
+    /// Errors that indicate an issue with the client itself make the pool
+    /// skip the client for a while.
+    pub async fn request<R>(&'static self, req: impl FnOnce(&'static Osu) -> R) -> R::Output
+    where
+        R: IntoFuture,
+        R::Output: OsuResponse,
+    {
+        let client = self.get();
+
+        if ptr::eq(client, &self.clients[0]) {
+            Context::client().osu_api_ratelimit().await;
+        }
+
+        let res = req(&client.osu).await;
+
+        if let Some(err) = res.osu_error() {
+            client.report(err);
+        }
+
+        res
+    }
+
+    /// The client of the main credentials.
+    pub fn primary(&self) -> &Osu {
+        &self.clients[0]
+    }
+
+    /// Picks the next healthy client in turn.
+    ///
+    /// Each client paces its requests through its own ratelimiter.
+    /// Background tasks use the reserved background client while it's healthy.
+    /// If no client is healthy, the primary client is used.
+    fn get(&self) -> &PooledOsu {
+        let now = OffsetDateTime::now_utc().unix_timestamp();
+
+        if BACKGROUND.try_with(|_| ()).is_ok() {
+            let background = self.background.as_ref();
+
+            if let Some(client) = background.filter(|client| client.is_healthy(now)) {
+                return client;
+            }
+        }
+
+        if let [client] = self.clients.as_ref() {
+            return client;
+        }
+
+        let len = self.clients.len();
+        let start = self.next.fetch_add(1, Ordering::Relaxed);
+
+        (start..start + len)
+            .map(|i| &self.clients[i % len])
+            .find(|client| client.is_healthy(now))
+            .unwrap_or(&self.clients[0])
+    }
+}
+
+pub struct PooledOsu {
+    osu: Osu,
+    client_id: u64,
+    unhealthy_until: AtomicI64,
+}
+
+impl PooledOsu {
+    async fn new(client_id: u64, client_secret: &str, ratelimit: u32) -> Result<Self> {
+        let osu = Osu::builder()
+            .client_id(client_id)
+            .client_secret(client_secret)
+            .ratelimit(ratelimit)
+            .build()
+            .await?;
+
+        Ok(Self {
+            osu,
+            client_id,
+            unhealthy_until: AtomicI64::new(0),
+        })
+    }
+
+    fn is_healthy(&self, now: i64) -> bool {
+        self.unhealthy_until.load(Ordering::Relaxed) <= now
+    }
+
+    /// Inspect an error returned by a request of this client and skip the
+    /// client for a while if the error indicates an issue with it.
+    fn report(&self, err: &OsuError) {
+        let OsuError::Response { status, .. } = err else {
+            return;
+        };
+
+        let code = status.as_u16();
+
+        if code != 429 && code < 500 {
+            return;
+        }
+
+        let until = OffsetDateTime::now_utc().unix_timestamp() + UNHEALTHY_COOLDOWN;
+        self.unhealthy_until.store(until, Ordering::Relaxed);
+
+        warn!(
+            client_id = self.client_id,
+            code, "osu client marked as unhealthy"
+        );
+    }
+}
+
+impl Deref for PooledOsu {
+    type Target = Osu;
+
+    fn deref(&self) -> &Self::Target {
+        &self.osu
+    }
+}
+
+/// Output of an osu!api request that may contain an error.
+pub trait OsuResponse {
+    fn osu_error(&self) -> Option<&OsuError>;
+}
+
+impl<T> OsuResponse for Result<T, OsuError> {
+    fn osu_error(&self) -> Option<&OsuError> {
+        self.as_ref().err()
+    }
+}
+
+impl<T> OsuResponse for Option<Result<T, OsuError>> {
+    fn osu_error(&self) -> Option<&OsuError> {
+        self.as_ref().and_then(|res| res.as_ref().err())
+    }
+}
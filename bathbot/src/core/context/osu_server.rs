@@ -0,0 +1,57 @@
+use std::future::Future;
+
+use tokio::task::JoinHandle;
+use twilight_model::id::{Id, marker::GuildMarker};
+
+use super::Context;
+
+tokio::task_local! {
+    /// Private osu! server that the currently processed event targets.
+    static OSU_SERVER: Box<str>;
+}
+
+/// Process an event such that osu! users and their scores are requested from
+/// the private server that the guild targets, if any.
+pub async fn with_osu_server<F: Future>(guild_id: Option<Id<GuildMarker>>, fut: F) -> F::Output {
+    match guild_osu_server(guild_id).await {
+        Some(server) => OSU_SERVER.scope(server, fut).await,
+        None => fut.await,
+    }
+}
+
+/// Spawn a task that keeps targeting the osu! server of the current event.
+///
+/// Task-locals are not inherited by spawned tasks so the server is captured
+/// beforehand.
+pub fn spawn_with_osu_server<F>(fut: F) -> JoinHandle<F::Output>
+where
+    F: Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    match Context::osu_server() {
+        Some(server) => tokio::spawn(OSU_SERVER.scope(server, fut)),
+        None => tokio::spawn(fut),
+    }
+}
+
+/// The private osu! server that the guild targets.
+///
+/// Servers that are no longer configured are ignored so that the guild falls
+/// back to bancho.
+pub async fn guild_osu_server(guild_id: Option<Id<GuildMarker>>) -> Option<Box<str>> {
+    let server = Context::guild_config()
+        .peek(guild_id?, |config| config.osu_server.clone())
+        .await?;
+
+    Context::client()
+        .has_osu_server(&server)
+        .then(|| server.into_boxed_str())
+}
+
+impl Context {
+    /// The private osu! server that the currently processed event targets or
+    /// `None` for bancho.
+    pub fn osu_server() -> Option<Box<str>> {
+        OSU_SERVER.try_with(Box::clone).ok()
+    }
+}
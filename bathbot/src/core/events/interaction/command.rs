@@ -21,6 +21,10 @@ pub async fn handle_command(mut command: InteractionCommand) {
     let name = mem::take(&mut command.data.name);
     EventKind::InteractionCommand.log(&command, &name).await;
 
+    if let Ok(user_id) = command.user_id() {
+        tokio::spawn(Context::user_config().mark_seen(user_id));
+    }
+
     let Some(cmd) = InteractionCommands::get().command(&name) else {
         return error!(name, "Unknown interaction command");
     };
@@ -28,7 +32,10 @@ pub async fn handle_command(mut command: InteractionCommand) {
     let group_sub = command.group_sub();
 
     match process_command(command, cmd).await {
-        Ok(ProcessResult::Success) => info!(%name, "Processed interaction command"),
+        Ok(ProcessResult::Success) => {
+            info!(%name, "Processed interaction command");
+            tokio::spawn(Context::redis().inc_commands_today());
+        }
         Ok(reason) => info!(?reason, "Interaction command `{name}` was not processed"),
         Err(err) => {
             match group_sub.clone() {
@@ -99,6 +106,18 @@ async fn pre_process_command(
         return Ok(Some(ProcessResult::NoDM));
     }
 
+    // Only for bancho?
+    if slash.flags.bancho_only() {
+        if let Some(server) = Context::osu_server() {
+            let content = format!(
+                "That command is only available for bancho but this server targets `{server}`"
+            );
+            command.error_callback(content).await?;
+
+            return Ok(Some(ProcessResult::NoBancho));
+        }
+    }
+
     // Ratelimited?
     if let Some(bucket) = slash.bucket {
         if let Some(cooldown) = Context::check_ratelimit(user_id, bucket) {
@@ -3,7 +3,18 @@ use twilight_model::application::interaction::{Interaction, InteractionData, Int
 use self::{autocomplete::handle_autocomplete, command::handle_command};
 use crate::{
     active::ActiveMessages,
-    util::interaction::{InteractionCommand, InteractionComponent, InteractionModal},
+    core::{
+        commands::{
+            delete::{delete_response_owner, handle_delete_response},
+            help_button::{handle_show_help, is_show_help},
+        },
+        with_osu_server,
+    },
+    manager::{redis::osu::with_alias_notes, with_unicode_titles},
+    util::{
+        interaction::{InteractionCommand, InteractionComponent, InteractionModal},
+        with_emote_permissions,
+    },
 };
 
 mod autocomplete;
@@ -28,59 +39,84 @@ pub async fn handle_interaction(interaction: Interaction) {
         return warn!(?kind, "No channel id for interaction");
     };
 
-    match data {
-        Some(InteractionData::ApplicationCommand(data)) => {
-            let cmd = InteractionCommand {
-                permissions,
-                channel_id,
-                data,
-                guild_id,
-                id,
-                member,
-                token,
-                user,
-            };
+    // Autocompletes don't render any maps so no need to check the config
+    let user_id = (kind != InteractionType::ApplicationCommandAutocomplete)
+        .then(|| {
+            member
+                .as_ref()
+                .and_then(|member| member.user.as_ref())
+                .or(user.as_ref())
+                .map(|user| user.id)
+        })
+        .flatten();
 
-            match kind {
-                InteractionType::ApplicationCommand => handle_command(cmd).await,
-                InteractionType::ApplicationCommandAutocomplete => handle_autocomplete(cmd).await,
-                _ => warn!(?kind, "Got unexpected interaction"),
+    let handle_fut = async move {
+        match data {
+            Some(InteractionData::ApplicationCommand(data)) => {
+                let cmd = InteractionCommand {
+                    permissions,
+                    channel_id,
+                    data,
+                    guild_id,
+                    id,
+                    member,
+                    token,
+                    user,
+                };
+
+                match kind {
+                    InteractionType::ApplicationCommand => handle_command(cmd).await,
+                    InteractionType::ApplicationCommandAutocomplete => {
+                        handle_autocomplete(cmd).await
+                    }
+                    _ => warn!(?kind, "Got unexpected interaction"),
+                }
             }
-        }
-        Some(InteractionData::MessageComponent(data)) => {
-            let Some(message) = message else {
-                return warn!("No message in interaction component");
-            };
+            Some(InteractionData::MessageComponent(data)) => {
+                let Some(message) = message else {
+                    return warn!("No message in interaction component");
+                };
 
-            let component = InteractionComponent {
-                permissions,
-                channel_id,
-                data,
-                guild_id,
-                id,
-                member,
-                message,
-                token,
-                user,
-            };
+                let component = InteractionComponent {
+                    permissions,
+                    channel_id,
+                    data,
+                    guild_id,
+                    id,
+                    member,
+                    message,
+                    token,
+                    user,
+                };
 
-            ActiveMessages::handle_component(component).await
-        }
-        Some(InteractionData::ModalSubmit(data)) => {
-            let modal = InteractionModal {
-                permissions,
-                channel_id,
-                data,
-                guild_id,
-                id,
-                member,
-                message,
-                token,
-                user,
-            };
+                match delete_response_owner(&component) {
+                    Some(owner) => handle_delete_response(component, owner).await,
+                    None if is_show_help(&component) => handle_show_help(component).await,
+                    None => ActiveMessages::handle_component(component).await,
+                }
+            }
+            Some(InteractionData::ModalSubmit(data)) => {
+                let modal = InteractionModal {
+                    permissions,
+                    channel_id,
+                    data,
+                    guild_id,
+                    id,
+                    member,
+                    message,
+                    token,
+                    user,
+                };
 
-            ActiveMessages::handle_modal(modal).await
+                ActiveMessages::handle_modal(modal).await
+            }
+            _ => {}
         }
-        _ => {}
-    }
+    };
+
+    let handle_fut = with_unicode_titles(user_id, guild_id, handle_fut);
+    let handle_fut = with_alias_notes(handle_fut);
+    let handle_fut = with_osu_server(guild_id, handle_fut);
+
+    with_emote_permissions(permissions, handle_fut).await
 }
@@ -14,9 +14,14 @@ use super::{EventKind, ProcessResult};
 use crate::{
     core::{
         BotMetrics, Context,
-        commands::checks::{check_authority, check_channel_permissions},
+        commands::{
+            checks::{check_authority, check_channel_permissions},
+            help_button::with_prefix_command,
+        },
+        guild_osu_server, with_osu_server,
     },
-    util::ChannelExt,
+    manager::{redis::osu::with_alias_notes, with_unicode_titles},
+    util::{ChannelExt, with_emote_permissions},
 };
 
 mod parse;
@@ -58,9 +63,13 @@ pub async fn handle_message(msg: Message) {
 
     let name = invoke.cmd.name();
     EventKind::PrefixCommand.log(&msg, name).await;
+    tokio::spawn(Context::user_config().mark_seen(msg.author.id));
 
     match process_command(invoke, &msg).await {
-        Ok(ProcessResult::Success) => info!(%name, "Processed command"),
+        Ok(ProcessResult::Success) => {
+            info!(%name, "Processed command");
+            tokio::spawn(Context::redis().inc_commands_today());
+        }
         Ok(reason) => info!(?reason, "Command `{name}` was not processed"),
         Err(err) => {
             BotMetrics::inc_command_error("prefix", name);
@@ -86,6 +95,18 @@ async fn process_command<'m>(invoke: Invoke<'m>, msg: &'m Message) -> Result<Pro
     // Only for owner?
     // * Not necessary since there are no owner-only prefix commands
 
+    // Only for bancho?
+    if cmd.flags.bancho_only() {
+        if let Some(server) = guild_osu_server(msg.guild_id).await {
+            let content = format!(
+                "That command is only available for bancho but this server targets `{server}`"
+            );
+            msg.error(content).await?;
+
+            return Ok(ProcessResult::NoBancho);
+        }
+    }
+
     let channel = msg.channel_id;
 
     // Does bot have sufficient permissions to send response in a guild?
@@ -147,7 +168,17 @@ async fn process_command<'m>(invoke: Invoke<'m>, msg: &'m Message) -> Result<Pro
     }
 
     // Call command function
-    (cmd.exec)(msg, args, permissions).await?;
+    let exec_fut = with_unicode_titles(
+        Some(msg.author.id),
+        msg.guild_id,
+        (cmd.exec)(msg, args, permissions),
+    );
+
+    let exec_fut = with_prefix_command(cmd.name(), exec_fut);
+    let exec_fut = with_alias_notes(exec_fut);
+    let exec_fut = with_osu_server(msg.guild_id, exec_fut);
+
+    with_emote_permissions(permissions, exec_fut).await?;
 
     Ok(ProcessResult::Success)
 }
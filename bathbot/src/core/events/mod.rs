@@ -31,6 +31,7 @@ enum ProcessResult {
     ),
     NoOwner,
     NoAuthority,
+    NoBancho,
 }
 
 pub enum EventKind {
@@ -237,6 +238,15 @@ async fn handle_event(event: Event, shard_id: u32) {
                     .insert(e.guild_id);
             }
             Event::MemberChunk(e) => {
+                if e.chunk_index + 1 >= e.chunk_count {
+                    Context::get()
+                        .member_requests
+                        .awaiting_chunks
+                        .lock()
+                        .unwrap()
+                        .remove(&e.guild_id);
+                }
+
                 if e.members
                     .iter()
                     .any(|member| member.user.id == MISS_ANALYZER_ID)
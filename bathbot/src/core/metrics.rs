@@ -14,6 +14,7 @@ const COMMAND_ERRORS: &str = "command_errors";
 const CACHE_ENTRIES: &str = "cache_entries";
 const REDIS_CACHE_HITS: &str = "redis_cache_hits";
 const OSU_TRACKING_HIT: &str = "osu_tracking_hit";
+const GRAPHS_RENDERED: &str = "graphs_rendered";
 
 pub struct BotMetrics;
 
@@ -36,6 +37,7 @@ impl BotMetrics {
             Unit::Count,
             "Number of times redis contained a cached value"
         );
+        describe_counter!(GRAPHS_RENDERED, Unit::Count, "Number of rendered graphs");
 
         let stats = cache.stats();
 
@@ -89,6 +91,10 @@ impl BotMetrics {
         counter!(OSU_TRACKING_HIT, "mode" => mode.as_str()).increment(1);
     }
 
+    pub fn inc_graph_rendered() {
+        counter!(GRAPHS_RENDERED).increment(1);
+    }
+
     pub fn inc_redis_hit(kind: impl Into<SharedString>) {
         counter!(REDIS_CACHE_HITS, "kind" => kind).increment(1);
     }
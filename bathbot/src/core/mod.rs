@@ -1,14 +1,22 @@
 pub use self::{
+    cleanup::{CleanupReport, cleanup_loop},
     config::BotConfig,
-    context::Context,
+    context::{
+        Context, guild_osu_server, spawn_with_osu_server, with_background_osu, with_osu_server,
+    },
     events::{EventKind, event_loop},
     metrics::BotMetrics,
+    pulse::server_pulse_loop,
+    render_queue::RenderQueue,
 };
 
+mod cleanup;
 mod config;
 mod context;
 mod events;
 mod metrics;
+mod pulse;
+mod render_queue;
 
 pub mod commands;
 pub mod logging;
@@ -0,0 +1,129 @@
+use std::{collections::HashMap, time::Duration};
+
+use bathbot_util::{IntHasher, numbers::WithComma};
+use eyre::{Report, Result};
+use rosu_v2::prelude::GameMode;
+use time::{Date, OffsetDateTime};
+use tokio::time::{interval, sleep};
+use twilight_model::id::{Id, marker::GuildMarker};
+
+use crate::core::Context;
+
+/// Once an hour, rename the pulse channel of each guild that configured one
+/// so that it shows live stats about the guild's linked members.
+pub async fn server_pulse_loop() {
+    // Give the cache some time to fill up after booting
+    sleep(Duration::from_secs(60 * 10)).await;
+
+    let mut interval = interval(Duration::from_secs(60 * 60));
+
+    // The pp of each guild's linked members as of the first update of the
+    // current UTC day. Kept in memory only so the first day after a restart
+    // undercounts.
+    let mut baselines: HashMap<Id<GuildMarker>, Baseline, IntHasher> = HashMap::default();
+
+    loop {
+        interval.tick().await;
+
+        let today = OffsetDateTime::now_utc().date();
+        let channels = Context::guild_config().pulse_channels();
+
+        baselines.retain(|guild_id, _| channels.iter().any(|(id, _)| id == guild_id));
+
+        for (guild_id, channel_id) in channels {
+            let pulse = match ServerPulse::new(guild_id).await {
+                Ok(pulse) => pulse,
+                Err(err) => {
+                    warn!(
+                        guild = guild_id.get(),
+                        ?err,
+                        "Failed to gather server pulse"
+                    );
+
+                    continue;
+                }
+            };
+
+            let baseline = baselines
+                .entry(guild_id)
+                .or_insert_with(|| Baseline::new(today, &pulse));
+
+            if baseline.date != today {
+                *baseline = Baseline::new(today, &pulse);
+            }
+
+            let pp_gained = baseline.pp_gained(&pulse.pp).round() as u64;
+
+            let name = format!(
+                "Linked: {} • pp today: {}",
+                WithComma::new(pulse.linked as u64),
+                WithComma::new(pp_gained)
+            );
+
+            if let Err(err) = Context::http().update_channel(channel_id).name(&name).await {
+                let err = Report::new(err).wrap_err("Failed to rename pulse channel");
+                warn!(guild = guild_id.get(), channel = channel_id.get(), ?err);
+            }
+        }
+    }
+}
+
+struct Baseline {
+    date: Date,
+    /// osu!standard pp of each linked member at the start of the day
+    pp: HashMap<u32, f32, IntHasher>,
+}
+
+impl Baseline {
+    fn new(date: Date, pulse: &ServerPulse) -> Self {
+        Self {
+            date,
+            pp: pulse.pp.clone(),
+        }
+    }
+
+    /// Sum of pp that members of the baseline gained since then.
+    ///
+    /// Members that joined or linked during the day are only counted from the
+    /// next day on so that they don't add their whole pp at once.
+    fn pp_gained(&self, current: &HashMap<u32, f32, IntHasher>) -> f32 {
+        self.pp
+            .iter()
+            .filter_map(|(user_id, baseline)| {
+                current.get(user_id).map(|pp| (pp - baseline).max(0.0))
+            })
+            .sum()
+    }
+}
+
+struct ServerPulse {
+    /// Amount of members that are linked to an osu! profile
+    linked: usize,
+    /// osu!standard pp of each linked member whose stats are stored
+    pp: HashMap<u32, f32, IntHasher>,
+}
+
+impl ServerPulse {
+    async fn new(guild_id: Id<GuildMarker>) -> Result<Self> {
+        let members: Vec<_> = Context::cache()
+            .members(guild_id)
+            .await?
+            .into_iter()
+            .map(|id| id as i64)
+            .collect();
+
+        let osu_ids: Vec<_> = Context::user_config()
+            .linked_osu_ids(&members)
+            .await?
+            .into_iter()
+            .map(|id| id as i32)
+            .collect();
+
+        let pp = Context::osu_user().pp(&osu_ids, GameMode::Osu).await?;
+
+        Ok(Self {
+            linked: osu_ids.len(),
+            pp,
+        })
+    }
+}
@@ -0,0 +1,178 @@
+use std::{
+    collections::HashMap,
+    pin::pin,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+use bathbot_util::{IntHasher, MessageBuilder};
+use eyre::{Result, WrapErr};
+use tokio::{
+    sync::{OwnedSemaphorePermit, Semaphore, SemaphorePermit},
+    time::timeout,
+};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use super::commands::CommandOrigin;
+
+/// How many renders may run at the same time across all users
+const GLOBAL_LIMIT: usize = 6;
+
+/// How many renders a single user may run at the same time
+const USER_LIMIT: usize = 1;
+
+/// Waiting longer than this shows the queue position to the invoker.
+const NOTICE_AFTER: Duration = Duration::from_secs(1);
+
+/// Updates of the queue position won't be sent more frequently than this.
+const NOTICE_INTERVAL: Duration = Duration::from_secs(3);
+
+/// Marks that a render is still waiting for the user's previous render.
+const NO_TICKET: u64 = u64::MAX;
+
+/// Limits how many images are rendered concurrently so that a burst of
+/// requests doesn't spike memory usage.
+///
+/// Renders are served in order, with each user being limited to
+/// [`USER_LIMIT`] concurrent renders so that a single user can't fill up the
+/// queue for everyone else.
+pub struct RenderQueue {
+    global: Semaphore,
+    users: Mutex<HashMap<Id<UserMarker>, Arc<Semaphore>, IntHasher>>,
+    /// Ticket for the next render that waits for a global slot
+    next_ticket: AtomicU64,
+    /// Amount of renders that received a global slot
+    served: AtomicU64,
+}
+
+impl RenderQueue {
+    pub fn new() -> Self {
+        Self {
+            global: Semaphore::new(GLOBAL_LIMIT),
+            users: Mutex::new(HashMap::default()),
+            next_ticket: AtomicU64::new(0),
+            served: AtomicU64::new(0),
+        }
+    }
+
+    /// Wait until the invoker is allowed to render.
+    ///
+    /// If that takes longer than a second, interactions are informed about
+    /// their position in the queue through the content of their response.
+    /// The content is cleared again once it's their turn.
+    pub async fn acquire(&self, orig: &CommandOrigin<'_>) -> Result<RenderPermit<'_>> {
+        let user_id = orig.user_id()?;
+        let ticket = AtomicU64::new(NO_TICKET);
+        let mut acquire_fut = pin!(self.acquire_slots(user_id, &ticket));
+
+        if let Ok(res) = timeout(NOTICE_AFTER, &mut acquire_fut).await {
+            return res;
+        }
+
+        if matches!(orig, CommandOrigin::Message { .. }) {
+            return acquire_fut.await;
+        }
+
+        let mut last_content = None;
+
+        let res = loop {
+            let content = self.notice_content(ticket.load(Ordering::Relaxed));
+
+            if last_content.as_ref() != Some(&content) {
+                let builder = MessageBuilder::new().content(content.as_str());
+
+                if let Err(err) = orig.update(builder).await {
+                    warn!(?err, "Failed to show render queue position");
+                }
+
+                last_content = Some(content);
+            }
+
+            if let Ok(res) = timeout(NOTICE_INTERVAL, &mut acquire_fut).await {
+                break res;
+            }
+        };
+
+        if let Err(err) = orig.update(MessageBuilder::new().content("")).await {
+            warn!(?err, "Failed to clear render queue position");
+        }
+
+        res
+    }
+
+    async fn acquire_slots(
+        &self,
+        user_id: Id<UserMarker>,
+        ticket: &AtomicU64,
+    ) -> Result<RenderPermit<'_>> {
+        let user_semaphore = self
+            .users
+            .lock()
+            .unwrap()
+            .entry(user_id)
+            .or_insert_with(|| Arc::new(Semaphore::new(USER_LIMIT)))
+            .clone();
+
+        let user_permit = user_semaphore
+            .acquire_owned()
+            .await
+            .wrap_err("Failed to acquire user render permit")?;
+
+        ticket.store(
+            self.next_ticket.fetch_add(1, Ordering::Relaxed),
+            Ordering::Relaxed,
+        );
+
+        let global_permit = self
+            .global
+            .acquire()
+            .await
+            .wrap_err("Failed to acquire global render permit")?;
+
+        self.served.fetch_add(1, Ordering::Relaxed);
+
+        Ok(RenderPermit {
+            queue: self,
+            user_id,
+            _global: global_permit,
+            _user: user_permit,
+        })
+    }
+
+    fn notice_content(&self, ticket: u64) -> String {
+        if ticket == NO_TICKET {
+            return "Waiting for your previous image to finish rendering...".to_owned();
+        }
+
+        let position = ticket.saturating_sub(self.served.load(Ordering::Relaxed)) + 1;
+
+        format!("Lots of images are being rendered right now, you're `#{position}` in the queue...")
+    }
+}
+
+/// Allows rendering as long as it's not dropped.
+pub struct RenderPermit<'q> {
+    queue: &'q RenderQueue,
+    user_id: Id<UserMarker>,
+    _global: SemaphorePermit<'q>,
+    _user: OwnedSemaphorePermit,
+}
+
+impl Drop for RenderPermit<'_> {
+    fn drop(&mut self) {
+        let mut users = self.queue.users.lock().unwrap();
+
+        // Only the map and this permit reference the semaphore so nobody else
+        // of that user is waiting
+        let unused = users
+            .get(&self.user_id)
+            .is_some_and(|semaphore| Arc::strong_count(semaphore) == 2);
+
+        if unused {
+            users.remove(&self.user_id);
+        }
+    }
+}
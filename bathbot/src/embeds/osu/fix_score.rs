@@ -4,8 +4,9 @@ use bathbot_macros::EmbedData;
 use bathbot_util::{
     AuthorBuilder, CowUtils,
     constants::OSU_BASE,
-    numbers::{WithComma, round},
+    numbers::WithComma,
     osu::{ExtractablePp, PpListUtil},
+    units::Pp,
 };
 use rosu_v2::prelude::{GameModsIntermode, RankStatus, Score};
 use time::OffsetDateTime;
@@ -49,8 +50,8 @@ impl FixScoreEmbed {
                 let mut description = format!(
                     "A {mods} FC would have improved the score from {from} to **{to}pp**. ",
                     mods = fix_score.score.mods,
-                    from = round(score.pp),
-                    to = round(if_fc.pp),
+                    from = Pp(score.pp),
+                    to = Pp(if_fc.pp),
                 );
 
                 let in_top = top.iter().any(|s| s.pp.unwrap_or(0.0) < if_fc.pp);
@@ -120,7 +121,7 @@ impl FixScoreEmbed {
                 // The score is already an FC
                 format!(
                     "Already got a {pp}pp {mods} FC",
-                    pp = round(score.pp),
+                    pp = Pp(score.pp),
                     mods = fix_score.score.mods
                 )
             }
@@ -10,8 +10,9 @@ use bathbot_util::{
     CowUtils, EmbedBuilder, FooterBuilder,
     constants::{DESCRIPTION_SIZE, OSU_BASE},
     datetime::SecToMinSec,
-    numbers::{WithComma, round},
+    numbers::WithComma,
     osu::calculate_legacy_grade,
+    units::Acc,
 };
 use rosu_v2::prelude::{
     GameMode, Grade, MatchEvent, MatchGame, MatchScore, OsuMatch, ScoringType, TeamType, User,
@@ -20,7 +21,10 @@ use rosu_v2::prelude::{
 use smallvec::SmallVec;
 use twilight_model::channel::message::embed::Embed;
 
-use crate::util::{Emote, osu::grade_emote};
+use crate::{
+    matchlive::{MatchSummary, WinProbability},
+    util::{Emote, osu::grade_emote, score_v2::score_v2_to_lazer},
+};
 
 const DESCRIPTION_BUFFER: usize = 45;
 
@@ -88,7 +92,7 @@ macro_rules! team {
 }
 
 impl MatchLiveEmbed {
-    pub fn new(lobby: &OsuMatch) -> MatchLiveEmbeds {
+    pub fn new(lobby: &OsuMatch, win_probability: &mut WinProbability) -> MatchLiveEmbeds {
         let mut embeds = MatchLiveEmbeds::new();
 
         if lobby.events.is_empty() {
@@ -148,7 +152,7 @@ impl MatchLiveEmbed {
                         }
                     }
 
-                    let (description, image, footer) = game_content(lobby, game);
+                    let (description, image, footer) = game_content(lobby, game, win_probability);
                     state = Some(next_state);
 
                     let embed = Self {
@@ -208,7 +212,11 @@ impl MatchLiveEmbed {
         embeds
     }
 
-    pub fn update(&mut self, lobby: &OsuMatch) -> (bool, Option<MatchLiveEmbeds>) {
+    pub fn update(
+        &mut self,
+        lobby: &OsuMatch,
+        win_probability: &mut WinProbability,
+    ) -> (bool, Option<MatchLiveEmbeds>) {
         if lobby.events.is_empty() {
             return (false, None);
         }
@@ -277,7 +285,8 @@ impl MatchLiveEmbed {
                             continue;
                         }
 
-                        let (description, image, footer) = game_content(lobby, game);
+                        let (description, image, footer) =
+                            game_content(lobby, game, win_probability);
 
                         // Previous game not yet finished but next one already there => override
                         if !state.finished {
@@ -367,7 +376,8 @@ impl MatchLiveEmbed {
                         embed.description.push_str("• **Lobby was closed**")
                     }
                     MatchEvent::Game { game, .. } => {
-                        let (description, image, footer) = game_content(lobby, game);
+                        let (description, image, footer) =
+                            game_content(lobby, game, win_probability);
 
                         let state = GameState {
                             game_id: game.game_id,
@@ -432,6 +442,77 @@ impl MatchLiveEmbed {
         )
     }
 
+    /// Summary of all games once the match is over; `None` if no game was
+    /// finished.
+    pub fn summary(lobby: &OsuMatch, summary: &MatchSummary) -> Option<Self> {
+        if summary.is_empty() {
+            return None;
+        }
+
+        let mut description = String::with_capacity(1024);
+        let [_, blue, red] = summary.team_totals;
+
+        if blue > 0 || red > 0 {
+            let _ = writeln!(
+                description,
+                ":blue_circle: **Blue Team** {} | {} **Red Team** :red_circle:",
+                WithComma::new(blue),
+                WithComma::new(red),
+            );
+        }
+
+        if let Some((user_id, mvp)) = summary.mvp() {
+            let _ = writeln!(
+                description,
+                ":trophy: MVP: `{name}` with {score} total score",
+                name = mvp.username(user_id),
+                score = WithComma::new(mvp.total_score),
+            );
+        }
+
+        let mut players: Vec<_> = summary.players.iter().collect();
+        players.sort_unstable_by(|(_, a), (_, b)| b.total_score.cmp(&a.total_score));
+
+        for (&user_id, player) in players {
+            let mut entry = format!(
+                "\n`{name}`: {games} game{plural} • Avg: `{score}` • `{acc}%`",
+                name = player.username(user_id),
+                games = player.games,
+                plural = if player.games == 1 { "" } else { "s" },
+                score = WithComma::new(player.avg_score()),
+                acc = Acc(player.avg_acc()),
+            );
+
+            if let Some(ref best) = player.best {
+                let _ = write!(
+                    entry,
+                    "\nBest: {} • `{}`",
+                    best.map,
+                    WithComma::new(best.score)
+                );
+            }
+
+            if description.len() + entry.len() + DESCRIPTION_BUFFER > DESCRIPTION_SIZE {
+                description.push_str("\n…");
+
+                break;
+            }
+
+            description.push_str(&entry);
+        }
+
+        let embed = Self {
+            title: format!("Summary: {}", lobby.name.as_str().cow_escape_markdown()),
+            url: format!("{OSU_BASE}community/matches/{}", lobby.match_id),
+            description,
+            image: None,
+            footer: None,
+            state: None,
+        };
+
+        Some(embed)
+    }
+
     pub fn as_embed(&self) -> Embed {
         let mut builder = EmbedBuilder::new()
             .description(&self.description)
@@ -456,8 +537,10 @@ impl MatchLiveEmbed {
 fn game_content(
     lobby: &OsuMatch,
     game: &MatchGame,
+    win_probability: &mut WinProbability,
 ) -> (String, Option<String>, Option<FooterBuilder>) {
     let mut description = String::with_capacity(128);
+    let blue_chance = win_probability.blue_chance(game);
 
     match game.end_time {
         Some(_) => {
@@ -491,7 +574,13 @@ fn game_content(
                 }
             };
 
-            description.push_str("**\n\n");
+            description.push_str("**\n");
+
+            if let Some(chance) = blue_chance {
+                write_win_probability(&mut description, chance);
+            }
+
+            description.push('\n');
 
             let (scores, sizes, team_scores) =
                 prepare_scores(game.mode, &game.scores, &lobby.users, game.scoring_type);
@@ -527,7 +616,7 @@ fn game_content(
                     len = sizes.name,
                     mods = score.mods,
                     mods_len = sizes.mods,
-                    acc = round(score.accuracy),
+                    acc = Acc(score.accuracy),
                     combo = score.combo,
                     combo_len = sizes.combo,
                     score = score.score_str,
@@ -536,10 +625,10 @@ fn game_content(
                 );
             }
 
-            let footer = team_scores.map(|(blue, red)| {
+            let mut footer = team_scores.map(|(blue, red)| {
                 let difference = (blue as i64 - red as i64).abs();
 
-                let footer = match blue.cmp(&red) {
+                match blue.cmp(&red) {
                     Ordering::Greater => {
                         format!("Blue Team wins by {}", WithComma::new(difference as u64))
                     }
@@ -547,11 +636,22 @@ fn game_content(
                         format!("Red Team wins by {}", WithComma::new(difference as u64))
                     }
                     Ordering::Equal => "Team scores are tied".to_owned(),
-                };
-
-                FooterBuilder::new(footer)
+                }
             });
 
+            if matches!(game.scoring_type, ScoringType::ScoreV2) {
+                let estimate = lazer_estimate(&game.scores, team_scores.is_some());
+                let footer = footer.get_or_insert_with(String::new);
+
+                if !footer.is_empty() {
+                    footer.push('\n');
+                }
+
+                footer.push_str(&estimate);
+            }
+
+            let footer = footer.map(FooterBuilder::new);
+
             (description, image, footer)
         }
         None => {
@@ -599,11 +699,47 @@ fn game_content(
                 game.scoring_type, game.team_type
             );
 
+            if let Some(chance) = blue_chance {
+                description.push('\n');
+                write_win_probability(&mut description, chance);
+            }
+
             (description, image, None)
         }
     }
 }
 
+fn write_win_probability(description: &mut String, blue_chance: f64) {
+    let _ = writeln!(
+        description,
+        "Win probability: :blue_circle: `{blue:.0}%` - `{red:.0}%` :red_circle:",
+        blue = blue_chance * 100.0,
+        red = (1.0 - blue_chance) * 100.0,
+    );
+}
+
+/// Estimated lazer standardized scores so that totals can be compared with
+/// lazer lobbies.
+fn lazer_estimate(scores: &[MatchScore], teams: bool) -> String {
+    let mut totals = [0_u64; 3];
+
+    for score in scores.iter().filter(|score| score.score > 0) {
+        totals[score.info.team as usize] += score_v2_to_lazer(score.score, &score.mods) as u64;
+    }
+
+    if teams {
+        format!(
+            "Estimated lazer totals: Blue {} | Red {}",
+            WithComma::new(totals[1]),
+            WithComma::new(totals[2])
+        )
+    } else {
+        let total = totals.iter().sum::<u64>();
+
+        format!("Estimated lazer total: {}", WithComma::new(total))
+    }
+}
+
 type Scores = SmallVec<[EmbedScore; 16]>;
 
 #[derive(Default)]
@@ -16,6 +16,7 @@ mod match_live;
 
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
+use bathbot_util::units::Pp;
 use rosu_v2::prelude::{GameModIntermode, GameMode, GameMods, ScoreStatistics};
 
 #[cfg(feature = "matchlive")]
@@ -64,11 +65,9 @@ impl Display for PpFormatter {
     #[inline]
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match (self.actual, self.max) {
-            (Some(actual), Some(max)) => {
-                write!(f, "**{actual:.2}**/{max:.2}", max = max.max(actual))?
-            }
-            (Some(actual), None) => write!(f, "**{actual:.2}**/-")?,
-            (None, Some(max)) => write!(f, "-/{max:.2}")?,
+            (Some(actual), Some(max)) => write!(f, "**{}**/{}", Pp(actual), Pp(max.max(actual)))?,
+            (Some(actual), None) => write!(f, "**{}**/-", Pp(actual))?,
+            (None, Some(max)) => write!(f, "-/{}", Pp(max))?,
             (None, None) => f.write_str("-/-")?,
         }
 
@@ -115,6 +114,23 @@ impl Display for KeyFormatter<'_> {
     }
 }
 
+pub struct LnFormatter {
+    percent: f32,
+}
+
+impl LnFormatter {
+    pub fn new(percent: f32) -> Self {
+        Self { percent }
+    }
+}
+
+impl Display for LnFormatter {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(f, "[{:.0}% LN]", self.percent)
+    }
+}
+
 #[derive(Clone)]
 pub struct HitResultFormatter<'a> {
     mode: GameMode,
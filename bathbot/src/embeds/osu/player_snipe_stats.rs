@@ -3,11 +3,8 @@ use std::fmt::Write;
 use bathbot_macros::EmbedData;
 use bathbot_model::SnipePlayer;
 use bathbot_util::{
-    AuthorBuilder, CowUtils, FooterBuilder, attachment,
-    constants::OSU_BASE,
-    datetime::HowLongAgoDynamic,
-    fields,
-    numbers::{WithComma, round},
+    AuthorBuilder, CowUtils, FooterBuilder, attachment, constants::OSU_BASE,
+    datetime::HowLongAgoDynamic, fields, numbers::WithComma, units::Acc,
 };
 use osu::{ComboFormatter, HitResultFormatter, PpFormatter};
 use rosu_v2::prelude::{GameMode, Score};
@@ -98,7 +95,7 @@ impl PlayerSnipeStatsEmbed {
                         oldest_map.n_objects(),
                     ),
                     score = WithComma::new(oldest_score.score),
-                    acc = round(oldest_score.accuracy),
+                    acc = Acc(oldest_score.accuracy),
                     combo = ComboFormatter::new(oldest_score.max_combo, Some(max_combo)),
                     pp = PpFormatter::new(Some(pp), Some(max_pp)),
                     hits = HitResultFormatter::new(GameMode::Osu, &oldest_score.statistics),
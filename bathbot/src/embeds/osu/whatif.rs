@@ -1,10 +1,7 @@
 use std::fmt::Write;
 
 use bathbot_macros::EmbedData;
-use bathbot_util::{
-    AuthorBuilder, CowUtils,
-    numbers::{WithComma, round},
-};
+use bathbot_util::{AuthorBuilder, CowUtils, numbers::WithComma, units::Pp};
 
 use crate::{commands::osu::WhatIfData, manager::redis::osu::CachedUser, util::CachedUserExt};
 
@@ -30,12 +27,12 @@ impl WhatIfEmbed {
         let title = if count <= 1 {
             format!(
                 "What if {username} got a new {pp_given}pp score?",
-                pp_given = round(pp),
+                pp_given = Pp(pp),
             )
         } else {
             format!(
                 "What if {username} got {count} new {pp_given}pp scores?",
-                pp_given = round(pp),
+                pp_given = Pp(pp),
             )
         };
 
@@ -44,7 +41,7 @@ impl WhatIfEmbed {
                 format!(
                     "A {pp_given}pp play wouldn't even be in {username}'s top 200 plays.\n\
                     There would not be any significant pp change.",
-                    pp_given = round(pp),
+                    pp_given = Pp(pp),
                 )
             }
             WhatIfData::NoScores { count, rank } => {
@@ -87,7 +84,7 @@ impl WhatIfEmbed {
                     format!(
                         "A {pp}pp play would be {username}'s #{new_pos} best play.\n\
                         Their pp would change by **{pp_change:+.2}** to **{new_pp}pp**",
-                        pp = round(pp),
+                        pp = Pp(pp),
                         pp_change = (new_pp + bonus_pp - stats_pp).max(0.0),
                         new_pp = WithComma::new(new_pp + bonus_pp)
                     )
@@ -95,7 +92,7 @@ impl WhatIfEmbed {
                     format!(
                         "A {pp}pp play would be {username}'s #{new_pos} best play.\n\
                         Adding {count} of them would change their pp by **{pp_change:+.2}** to **{new_pp}pp**",
-                        pp = round(pp),
+                        pp = Pp(pp),
                         pp_change = (new_pp + bonus_pp - stats_pp).max(0.0),
                         new_pp = WithComma::new(new_pp + bonus_pp)
                     )
@@ -1,7 +1,9 @@
 use std::fmt::{Display, Write};
 
 use ::time::UtcOffset;
-use bathbot_psql::model::configs::{ListSize, OsuUsername, Retries, ScoreData, UserConfig};
+use bathbot_psql::model::configs::{
+    GraphTheme, ListSize, OsuUsername, Retries, ScoreData, UserConfig,
+};
 use bathbot_util::{AuthorBuilder, EmbedBuilder, FooterBuilder};
 use rosu_v2::prelude::GameMode;
 use twilight_model::{channel::message::embed::EmbedField, user::User};
@@ -108,8 +110,61 @@ impl ConfigEmbed {
                     (Retries::IgnoreMods, "ignore mods"),
                 ],
             ),
+            create_field(
+                "Graph theme",
+                config.graph_theme.unwrap_or_default(),
+                &[(GraphTheme::Dark, "dark"), (GraphTheme::Light, "light")],
+            ),
+            create_field(
+                "Accessibility",
+                config.accessibility.unwrap_or(false),
+                &[(true, "enabled"), (false, "disabled")],
+            ),
+            create_field(
+                "Data saver",
+                config.data_saver.unwrap_or(false),
+                &[(true, "enabled"), (false, "disabled")],
+            ),
+            create_field(
+                "Profile badges",
+                config.profile_badges.unwrap_or(false),
+                &[(true, "show"), (false, "hide")],
+            ),
+            create_field(
+                "Unicode titles",
+                config.unicode_titles.unwrap_or(false),
+                &[(true, "enabled"), (false, "disabled")],
+            ),
         ];
 
+        let graph_days = config
+            .graph_days
+            .map_or_else(|| "default".to_owned(), |days| days.to_string());
+
+        fields.push(EmbedField {
+            inline: true,
+            name: "Graph days".to_owned(),
+            value: format!("```\n{graph_days}\n```"),
+        });
+
+        let accent_color = config
+            .card_accent_color
+            .map_or_else(|| "-".to_owned(), |color| format!("#{color:06x}"));
+
+        let card_value = format!(
+            "```\n\
+            Background: {:?}\n\
+            Accent color: {accent_color}\n\
+            ```",
+            config.card_background.unwrap_or_default(),
+        );
+
+        fields.push(EmbedField {
+            inline: false,
+            name: "Card".to_owned(),
+            value: card_value,
+        });
+
         if let Some(skin_url) = skin_url {
             fields.push(EmbedField {
                 inline: false,
@@ -67,6 +67,53 @@ impl ServerConfigEmbed {
 
         description.push_str("\n```");
 
+        let pulse = match config.pulse_channel {
+            Some(channel) => format!("<#{channel}>"),
+            None => "disabled".to_owned(),
+        };
+
+        let map_intro = match (config.intro_accent, config.intro_label.as_deref()) {
+            (None, None) => "default".to_owned(),
+            (accent, label) => {
+                let mut value = String::new();
+
+                if let Some(accent) = accent {
+                    let _ = write!(value, "Accent color: `#{accent:06x}`");
+                }
+
+                if let Some(label) = label {
+                    if !value.is_empty() {
+                        value.push('\n');
+                    }
+
+                    let _ = write!(value, "Label: `{label}`");
+                }
+
+                value
+            }
+        };
+
+        let image_channels = if config.image_channels.is_empty() {
+            "all channels".to_owned()
+        } else {
+            let mut value = String::new();
+
+            for (i, channel) in config.image_channels.iter().enumerate() {
+                if i > 0 {
+                    value.push_str(", ");
+                }
+
+                let _ = write!(value, "<#{channel}>");
+            }
+
+            value
+        };
+
+        let osu_server = match config.osu_server.as_deref() {
+            Some(server) => format!("`{server}`"),
+            None => "bancho".to_owned(),
+        };
+
         let fields = vec![
             create_field(
                 "Song commands",
@@ -128,6 +175,31 @@ impl ServerConfigEmbed {
                     (Retries::IgnoreMods, "ignore mods"),
                 ],
             ),
+            create_field(
+                "Unicode titles*",
+                config.unicode_titles.unwrap_or(false),
+                &[(true, "enabled"), (false, "disabled")],
+            ),
+            EmbedField {
+                inline: false,
+                name: "Server pulse".to_owned(),
+                value: pulse,
+            },
+            EmbedField {
+                inline: false,
+                name: "Map intro branding".to_owned(),
+                value: map_intro,
+            },
+            EmbedField {
+                inline: false,
+                name: "Image channels".to_owned(),
+                value: image_channels,
+            },
+            EmbedField {
+                inline: false,
+                name: "osu! server".to_owned(),
+                value: osu_server,
+            },
         ];
 
         Self {
@@ -26,7 +26,10 @@ use tokio::{
 
 use crate::{
     commands::owner::RESHARD_TX,
-    core::{BotConfig, Context, commands::interaction::InteractionCommands, event_loop, logging},
+    core::{
+        BotConfig, Context, cleanup_loop, commands::interaction::InteractionCommands, event_loop,
+        logging, server_pulse_loop, with_background_osu,
+    },
 };
 
 fn main() {
@@ -90,10 +93,28 @@ async fn async_main() -> Result<()> {
         tokio::spawn(tracking::twitch_tracking_loop());
     }
 
+    // Spawn daily rank snapshot worker
+    tokio::spawn(with_background_osu(tracking::rank_snapshot_loop()));
+
+    // Spawn daily worker that prunes stale entries
+    tokio::spawn(cleanup_loop());
+
+    // Spawn hourly worker that renames server pulse channels
+    tokio::spawn(server_pulse_loop());
+
+    // Spawn worker that pings participants of upcoming matches
+    tokio::spawn(commands::utility::match_reminder_loop());
+
+    // Spawn worker that announces new osekai badges
+    tokio::spawn(with_background_osu(commands::osu::badge_notify_loop()));
+
+    // Spawn hourly worker that announces milestones of guild members
+    tokio::spawn(commands::osu::milestone_feed_loop());
+
     #[cfg(feature = "matchlive")]
     {
         // Spawn osu match ticker worker
-        tokio::spawn(Context::match_live_loop());
+        tokio::spawn(with_background_osu(Context::match_live_loop()));
     }
 
     // Request members
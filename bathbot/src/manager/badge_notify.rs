@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+
+use bathbot_psql::Database;
+use bathbot_util::IntHasher;
+use eyre::{Result, WrapErr};
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, GuildMarker, UserMarker},
+};
+
+use crate::core::Context;
+
+#[derive(Copy, Clone)]
+pub struct BadgeNotifyManager {
+    psql: &'static Database,
+}
+
+impl BadgeNotifyManager {
+    pub fn new() -> Self {
+        Self {
+            psql: Context::psql(),
+        }
+    }
+
+    pub async fn channels(self) -> Result<Vec<(Id<GuildMarker>, Id<ChannelMarker>)>> {
+        let channels = self
+            .psql
+            .select_badge_notify_channels()
+            .await
+            .wrap_err("Failed to get badge notify channels")?;
+
+        let channels = channels
+            .into_iter()
+            .map(|entry| {
+                (
+                    Id::new(entry.guild_id as u64),
+                    Id::new(entry.channel_id as u64),
+                )
+            })
+            .collect();
+
+        Ok(channels)
+    }
+
+    pub async fn set_channel(
+        self,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<()> {
+        self.psql
+            .upsert_badge_notify_channel(guild_id, channel_id)
+            .await
+            .wrap_err("Failed to upsert badge notify channel")
+    }
+
+    /// Returns whether the guild had a notification channel.
+    pub async fn remove_channel(self, guild_id: Id<GuildMarker>) -> Result<bool> {
+        self.psql
+            .delete_badge_notify_channel(guild_id)
+            .await
+            .wrap_err("Failed to delete badge notify channel")
+    }
+
+    pub async fn users(self) -> Result<Vec<Id<UserMarker>>> {
+        let users = self
+            .psql
+            .select_badge_notify_users()
+            .await
+            .wrap_err("Failed to get badge notify users")?;
+
+        Ok(users.into_iter().map(|id| Id::new(id as u64)).collect())
+    }
+
+    pub async fn add_user(self, user_id: Id<UserMarker>) -> Result<()> {
+        self.psql
+            .insert_badge_notify_user(user_id)
+            .await
+            .wrap_err("Failed to insert badge notify user")
+    }
+
+    /// Returns whether the user was subscribed.
+    pub async fn remove_user(self, user_id: Id<UserMarker>) -> Result<bool> {
+        self.psql
+            .delete_badge_notify_user(user_id)
+            .await
+            .wrap_err("Failed to delete badge notify user")
+    }
+
+    /// Last seen badge ids of the given osu! users, if they were seen before.
+    pub async fn seen(self, osu_ids: &[u32]) -> Result<HashMap<u32, Vec<u32>, IntHasher>> {
+        let osu_ids: Vec<_> = osu_ids.iter().map(|&id| id as i32).collect();
+
+        let seen = self
+            .psql
+            .select_seen_badges(&osu_ids)
+            .await
+            .wrap_err("Failed to get seen badges")?;
+
+        let seen = seen
+            .into_iter()
+            .map(|entry| {
+                let badge_ids = entry.badge_ids.into_iter().map(|id| id as u32).collect();
+
+                (entry.osu_id as u32, badge_ids)
+            })
+            .collect();
+
+        Ok(seen)
+    }
+
+    pub async fn set_seen(self, osu_id: u32, badge_ids: &[u32]) -> Result<()> {
+        let badge_ids: Vec<_> = badge_ids.iter().map(|&id| id as i32).collect();
+
+        self.psql
+            .upsert_seen_badges(osu_id, &badge_ids)
+            .await
+            .wrap_err("Failed to upsert seen badges")
+    }
+}
@@ -0,0 +1,42 @@
+use bathbot_psql::Database;
+use eyre::{Result, WrapErr};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use crate::core::Context;
+
+#[derive(Copy, Clone)]
+pub struct FavoritesManager {
+    psql: &'static Database,
+}
+
+impl FavoritesManager {
+    /// Max amount of favorite players a user can have.
+    pub const MAX_FAVORITES: usize = 25;
+
+    pub fn new() -> Self {
+        Self {
+            psql: Context::psql(),
+        }
+    }
+
+    pub async fn get(self, user: Id<UserMarker>) -> Result<Vec<u32>> {
+        self.psql
+            .select_favorite_players(user)
+            .await
+            .wrap_err("Failed to get favorite players")
+    }
+
+    pub async fn add(self, user: Id<UserMarker>, osu_id: u32) -> Result<bool> {
+        self.psql
+            .insert_favorite_player(user, osu_id)
+            .await
+            .wrap_err("Failed to insert favorite player")
+    }
+
+    pub async fn remove(self, user: Id<UserMarker>, osu_id: u32) -> Result<bool> {
+        self.psql
+            .delete_favorite_player(user, osu_id)
+            .await
+            .wrap_err("Failed to delete favorite player")
+    }
+}
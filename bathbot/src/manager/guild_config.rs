@@ -2,7 +2,10 @@ use bathbot_psql::{Database, model::configs::GuildConfig};
 use bathbot_util::IntHasher;
 use eyre::{Result, WrapErr};
 use papaya::HashMap as PapayaMap;
-use twilight_model::id::{Id, marker::GuildMarker};
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, GuildMarker},
+};
 
 type GuildConfigs = PapayaMap<Id<GuildMarker>, GuildConfig, IntHasher>;
 
@@ -52,6 +55,15 @@ impl GuildConfigManager {
         prefix_opt.unwrap_or_else(|| GuildConfig::DEFAULT_PREFIX.to_owned())
     }
 
+    /// All guilds that configured a pulse channel.
+    pub fn pulse_channels(self) -> Vec<(Id<GuildMarker>, Id<ChannelMarker>)> {
+        self.guild_configs
+            .pin()
+            .iter()
+            .filter_map(|(guild_id, config)| Some((*guild_id, config.pulse_channel?)))
+            .collect()
+    }
+
     pub async fn update<F, O>(self, guild_id: Id<GuildMarker>, f: F) -> Result<O>
     where
         F: FnOnce(&mut GuildConfig) -> O,
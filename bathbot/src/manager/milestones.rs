@@ -0,0 +1,154 @@
+use std::collections::HashMap;
+
+use bathbot_psql::Database;
+use eyre::{Result, WrapErr};
+use rosu_v2::prelude::GameMode;
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, GuildMarker},
+};
+
+use crate::core::Context;
+
+/// Latest stored stats of an osu! user in a mode.
+pub struct MilestoneStats {
+    pub user_id: u32,
+    pub mode: GameMode,
+    pub pp: f32,
+    pub playcount: u32,
+    pub global_rank: u32,
+}
+
+/// Highest milestones that an osu! user reached in a mode.
+///
+/// A `rank` of 0 means that no rank milestone was reached.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct MilestoneLevels {
+    pub pp: u32,
+    pub playcount: u32,
+    pub rank: u32,
+}
+
+#[derive(Copy, Clone)]
+pub struct MilestoneManager {
+    psql: &'static Database,
+}
+
+impl MilestoneManager {
+    pub fn new() -> Self {
+        Self {
+            psql: Context::psql(),
+        }
+    }
+
+    pub async fn channels(self) -> Result<Vec<(Id<GuildMarker>, Id<ChannelMarker>)>> {
+        let channels = self
+            .psql
+            .select_milestone_channels()
+            .await
+            .wrap_err("Failed to get milestone channels")?;
+
+        let channels = channels
+            .into_iter()
+            .map(|entry| {
+                (
+                    Id::new(entry.guild_id as u64),
+                    Id::new(entry.channel_id as u64),
+                )
+            })
+            .collect();
+
+        Ok(channels)
+    }
+
+    pub async fn set_channel(
+        self,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+    ) -> Result<()> {
+        self.psql
+            .upsert_milestone_channel(guild_id, channel_id)
+            .await
+            .wrap_err("Failed to upsert milestone channel")
+    }
+
+    /// Returns whether the guild had a milestone channel.
+    pub async fn remove_channel(self, guild_id: Id<GuildMarker>) -> Result<bool> {
+        self.psql
+            .delete_milestone_channel(guild_id)
+            .await
+            .wrap_err("Failed to delete milestone channel")
+    }
+
+    /// Latest stored stats of the given osu! users for all modes they were
+    /// seen in.
+    pub async fn stats(self, osu_ids: &[u32]) -> Result<Vec<MilestoneStats>> {
+        let osu_ids: Vec<_> = osu_ids.iter().map(|&id| id as i32).collect();
+
+        let stats = self
+            .psql
+            .select_milestone_stats(&osu_ids)
+            .await
+            .wrap_err("Failed to get milestone stats")?;
+
+        let stats = stats
+            .into_iter()
+            .map(|entry| MilestoneStats {
+                user_id: entry.user_id as u32,
+                mode: GameMode::from(entry.gamemode as u8),
+                pp: entry.pp,
+                playcount: entry.playcount as u32,
+                global_rank: entry.global_rank as u32,
+            })
+            .collect();
+
+        Ok(stats)
+    }
+
+    /// Highest reached milestones of the given osu! users, if they were seen
+    /// before.
+    pub async fn seen(self, osu_ids: &[u32]) -> Result<HashMap<(u32, GameMode), MilestoneLevels>> {
+        let osu_ids: Vec<_> = osu_ids.iter().map(|&id| id as i32).collect();
+
+        let seen = self
+            .psql
+            .select_milestone_seen(&osu_ids)
+            .await
+            .wrap_err("Failed to get seen milestones")?;
+
+        let seen = seen
+            .into_iter()
+            .map(|entry| {
+                let key = (entry.osu_id as u32, GameMode::from(entry.gamemode as u8));
+
+                let levels = MilestoneLevels {
+                    pp: entry.pp as u32,
+                    playcount: entry.playcount as u32,
+                    rank: entry.rank as u32,
+                };
+
+                (key, levels)
+            })
+            .collect();
+
+        Ok(seen)
+    }
+
+    pub async fn set_seen(
+        self,
+        osu_id: u32,
+        mode: GameMode,
+        levels: MilestoneLevels,
+    ) -> Result<()> {
+        let MilestoneLevels {
+            pp,
+            playcount,
+            rank,
+        } = levels;
+
+        self.psql
+            .upsert_milestone_seen(osu_id, mode, pp, playcount, rank)
+            .await
+            .wrap_err("Failed to upsert seen milestones")
+    }
+}
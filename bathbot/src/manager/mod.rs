@@ -1,33 +1,45 @@
 #[cfg(feature = "twitch")]
 pub use self::twitch::TwitchManager;
 pub use self::{
+    badge_notify::BadgeNotifyManager,
     bookmarks::BookmarkManager,
+    favorites::FavoritesManager,
     games::GameManager,
     github::GithubManager,
     guild_config::GuildConfigManager,
     huismetbenen_country::HuismetbenenCountryManager,
-    osu_map::{MapError, MapManager, OsuMap, OsuMapSlim},
-    osu_scores::ScoresManager,
+    milestones::{MilestoneLevels, MilestoneManager, MilestoneStats},
+    osu_map::{MapError, MapManager, OsuMap, OsuMapSlim, with_unicode_titles},
+    osu_scores::{MapCompletions, ScoresManager},
     osu_user::OsuUserManager,
     pp::{Mods, PpManager},
     rank_pp_approx::ApproxManager,
+    refscripts::RefscriptManager,
     replay::{ReplayError, ReplayManager, ReplaySettings},
+    scheduled_matches::ScheduledMatchManager,
+    simulate_presets::SimulatePresetManager,
     user_config::UserConfigManager,
 };
 
 pub mod redis;
 
+mod badge_notify;
 mod bookmarks;
+mod favorites;
 mod games;
 mod github;
 mod guild_config;
 mod huismetbenen_country;
+mod milestones;
 mod osu_map;
 mod osu_scores;
 mod osu_user;
 mod pp;
 mod rank_pp_approx;
+mod refscripts;
 mod replay;
+mod scheduled_matches;
+mod simulate_presets;
 mod user_config;
 
 #[cfg(feature = "twitch")]
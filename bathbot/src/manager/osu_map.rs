@@ -1,6 +1,7 @@
 use std::{
     collections::HashMap,
     fmt::{Debug, Display, Formatter, Result as FmtResult},
+    future::Future,
     io::Error as IoError,
     ops::Deref,
 };
@@ -16,18 +17,67 @@ use futures::{TryStreamExt, stream::FuturesUnordered};
 use rosu_pp::{
     Beatmap,
     any::DifficultyAttributes,
-    model::{beatmap::BeatmapAttributesBuilder, mode::GameMode as MapMode},
+    model::{
+        beatmap::BeatmapAttributesBuilder, hit_object::HitObjectKind, mode::GameMode as MapMode,
+    },
 };
 use rosu_v2::prelude::{BeatmapsetExtended, GameMode, OsuError, RankStatus};
 use thiserror::Error;
 use time::OffsetDateTime;
 use tokio::time::sleep;
+use twilight_model::id::{
+    Id,
+    marker::{GuildMarker, UserMarker},
+};
 
 use super::{PpManager, pp::Mods};
 use crate::{core::Context, util::NativeCriteria};
 
 type Result<T> = eyre::Result<T, MapError>;
 
+tokio::task_local! {
+    /// Whether unicode artists and titles are preferred for the currently
+    /// processed event.
+    static UNICODE_TITLES: bool;
+}
+
+/// Process an event such that all map artists and titles rendered along the
+/// way prefer their unicode version if the user or, as fallback, the guild
+/// configured it.
+pub async fn with_unicode_titles<F: Future>(
+    user_id: Option<Id<UserMarker>>,
+    guild_id: Option<Id<GuildMarker>>,
+    fut: F,
+) -> F::Output {
+    let Some(user_id) = user_id else {
+        return fut.await;
+    };
+
+    let user_config = match Context::user_config().unicode_titles(user_id).await {
+        Ok(unicode_titles) => unicode_titles,
+        Err(err) => {
+            warn!(?err, "Failed to get unicode titles config");
+
+            None
+        }
+    };
+
+    let unicode_titles = match (user_config, guild_id) {
+        (Some(unicode_titles), _) => unicode_titles,
+        (None, Some(guild_id)) => Context::guild_config()
+            .peek(guild_id, |config| config.unicode_titles)
+            .await
+            .unwrap_or(false),
+        (None, None) => false,
+    };
+
+    UNICODE_TITLES.scope(unicode_titles, fut).await
+}
+
+fn unicode_titles() -> bool {
+    UNICODE_TITLES.try_with(|unicode| *unicode).unwrap_or(false)
+}
+
 #[derive(Copy, Clone)]
 pub struct MapManager;
 
@@ -183,6 +233,14 @@ impl MapManager {
             .unwrap_or_default()
     }
 
+    /// Amount of maps stored in the database.
+    pub async fn count(self) -> Result<u64> {
+        Context::psql()
+            .select_osu_map_count()
+            .await
+            .wrap_err("Failed to get map count")
+    }
+
     pub async fn versions_by_map(self, map_id: u32) -> Result<Vec<MapVersion>> {
         let versions = Context::psql()
             .select_map_versions_by_map_id(map_id)
@@ -193,16 +251,7 @@ impl MapManager {
             return Ok(versions);
         }
 
-        let mapset = match Context::osu().beatmapset_from_map_id(map_id).await {
-            Ok(mapset) => mapset,
-            Err(OsuError::NotFound) => return Err(MapError::NotFound),
-            Err(err) => {
-                return Err(MapError::Report(
-                    Report::new(err).wrap_err("Failed to retrieve mapset"),
-                ));
-            }
-        };
-
+        let mapset = self.request_mapset_from_map_id(map_id).await?;
         let versions = Self::mapset_to_map_versions(&mapset);
 
         tokio::spawn(async move { self.store(&mapset).await });
@@ -220,16 +269,7 @@ impl MapManager {
             return Ok(versions);
         }
 
-        let mapset = match Context::osu().beatmapset(mapset_id).await {
-            Ok(mapset) => mapset,
-            Err(OsuError::NotFound) => return Err(MapError::NotFound),
-            Err(err) => {
-                return Err(MapError::Report(
-                    Report::new(err).wrap_err("Failed to retrieve mapset"),
-                ));
-            }
-        };
-
+        let mapset = self.request_mapset(mapset_id).await?;
         let versions = Self::mapset_to_map_versions(&mapset);
 
         tokio::spawn(async move { self.store(&mapset).await });
@@ -237,6 +277,44 @@ impl MapManager {
         Ok(versions)
     }
 
+    /// Request a [`BeatmapsetExtended`] from a map id.
+    ///
+    /// The request is revalidated against a previous response if possible and
+    /// only goes through the osu!api client if the request could not be sent
+    /// or its response could not be deserialized.
+    pub async fn request_mapset_from_map_id(self, map_id: u32) -> Result<BeatmapsetExtended> {
+        match Context::client().get_osu_mapset_from_map_id(map_id).await {
+            Ok(mapset) => Ok(mapset),
+            Err(err @ ClientError::Report(_)) => {
+                warn!(?err, map_id, "Failed conditional mapset request");
+
+                let mapset_fut = Context::osu_request(|osu| osu.beatmapset_from_map_id(map_id));
+
+                mapset_fut.await.map_err(MapError::from_osu)
+            }
+            Err(err) => Err(MapError::from_client(err)),
+        }
+    }
+
+    /// Request a [`BeatmapsetExtended`] from a mapset id.
+    ///
+    /// The request is revalidated against a previous response if possible and
+    /// only goes through the osu!api client if the request could not be sent
+    /// or its response could not be deserialized.
+    pub async fn request_mapset(self, mapset_id: u32) -> Result<BeatmapsetExtended> {
+        match Context::client().get_osu_mapset(mapset_id).await {
+            Ok(mapset) => Ok(mapset),
+            Err(err @ ClientError::Report(_)) => {
+                warn!(?err, mapset_id, "Failed conditional mapset request");
+
+                let mapset_fut = Context::osu_request(|osu| osu.beatmapset(mapset_id));
+
+                mapset_fut.await.map_err(MapError::from_osu)
+            }
+            Err(err) => Err(MapError::from_client(err)),
+        }
+    }
+
     pub async fn store(&self, mapset: &BeatmapsetExtended) {
         if let Err(err) = Context::psql().upsert_beatmapset(mapset).await {
             warn!(?err, "Failed to store mapset");
@@ -246,34 +324,22 @@ impl MapManager {
     /// Request a [`BeatmapsetExtended`] from a map id and turn it into a
     /// [`OsuMapSlim`]
     async fn retrieve_map(self, map_id: u32) -> Result<OsuMapSlim> {
-        match Context::osu().beatmapset_from_map_id(map_id).await {
-            Ok(mapset) => {
-                let mapset_clone = mapset.clone();
-                tokio::spawn(async move { self.store(&mapset_clone).await });
+        let mapset = self.request_mapset_from_map_id(map_id).await?;
 
-                OsuMapSlim::try_from_mapset(mapset, map_id)
-            }
-            Err(OsuError::NotFound) => Err(MapError::NotFound),
-            Err(err) => Err(MapError::Report(
-                Report::new(err).wrap_err("Failed to retrieve mapset"),
-            )),
-        }
+        let mapset_clone = mapset.clone();
+        tokio::spawn(async move { self.store(&mapset_clone).await });
+
+        OsuMapSlim::try_from_mapset(mapset, map_id)
     }
 
     /// Request a [`BeatmapsetExtended`] from a mapset id
     async fn retrieve_mapset(self, mapset_id: u32) -> Result<BeatmapsetExtended> {
-        match Context::osu().beatmapset(mapset_id).await {
-            Ok(mapset) => {
-                let mapset_clone = mapset.clone();
-                tokio::spawn(async move { self.store(&mapset_clone).await });
+        let mapset = self.request_mapset(mapset_id).await?;
 
-                Ok(mapset)
-            }
-            Err(OsuError::NotFound) => Err(MapError::NotFound),
-            Err(err) => Err(MapError::Report(
-                Report::new(err).wrap_err("Failed to retrieve mapset"),
-            )),
-        }
+        let mapset_clone = mapset.clone();
+        tokio::spawn(async move { self.store(&mapset_clone).await });
+
+        Ok(mapset)
     }
 
     /// Make sure the map's current file is available
@@ -404,6 +470,8 @@ impl OsuMapSlim {
             user_id: mapset.creator_id as i32,
             artist: mapset.artist,
             title: mapset.title,
+            artist_unicode: mapset.artist_unicode,
+            title_unicode: mapset.title_unicode,
             creator: mapset.creator_name.into_string(),
             rank_status: mapset.status as i16,
             ranked_date: mapset.ranked_date,
@@ -438,12 +506,22 @@ impl OsuMapSlim {
         self.map.map_version.as_str()
     }
 
+    /// The unicode artist if preferred and available, the romanized one
+    /// otherwise.
     pub fn artist(&self) -> &str {
-        self.mapset.artist.as_str()
+        match self.mapset.artist_unicode.as_deref() {
+            Some(artist) if !artist.is_empty() && unicode_titles() => artist,
+            _ => self.mapset.artist.as_str(),
+        }
     }
 
+    /// The unicode title if preferred and available, the romanized one
+    /// otherwise.
     pub fn title(&self) -> &str {
-        self.mapset.title.as_str()
+        match self.mapset.title_unicode.as_deref() {
+            Some(title) if !title.is_empty() && unicode_titles() => title,
+            _ => self.mapset.title.as_str(),
+        }
     }
 
     pub fn creator(&self) -> &str {
@@ -542,6 +620,24 @@ impl OsuMap {
 
         self
     }
+
+    /// Percentage of hit objects that are long notes.
+    ///
+    /// Only meaningful if the map is mania or has been converted to mania.
+    pub fn ln_percent(&self) -> f32 {
+        let hit_objects = &self.pp_map.hit_objects;
+
+        if hit_objects.is_empty() {
+            return 0.0;
+        }
+
+        let long_notes = hit_objects
+            .iter()
+            .filter(|h| matches!(h.kind, HitObjectKind::Hold(_)))
+            .count();
+
+        100.0 * long_notes as f32 / hit_objects.len() as f32
+    }
 }
 
 impl Deref for OsuMap {
@@ -556,7 +652,11 @@ impl Deref for OsuMap {
 impl Searchable<RegularCriteria<'_>> for OsuMap {
     #[inline]
     fn matches(&self, criteria: &FilterCriteria<RegularCriteria<'_>>) -> bool {
-        self.map.matches(criteria) && self.pp_map.matches(NativeCriteria::cast(criteria))
+        self.map.matches(criteria)
+            && self.pp_map.matches(NativeCriteria::cast(criteria))
+            && (self.mode() != GameMode::Mania
+                || criteria.ln.is_empty()
+                || criteria.ln.contains(self.ln_percent()))
     }
 }
 
@@ -568,6 +668,22 @@ pub enum MapError {
     Report(#[from] Report),
 }
 
+impl MapError {
+    fn from_osu(err: OsuError) -> Self {
+        match err {
+            OsuError::NotFound => Self::NotFound,
+            err => Self::Report(Report::new(err).wrap_err("Failed to retrieve mapset")),
+        }
+    }
+
+    fn from_client(err: ClientError) -> Self {
+        match err {
+            ClientError::NotFound => Self::NotFound,
+            err => Self::Report(Report::new(err).wrap_err("Failed to retrieve mapset")),
+        }
+    }
+}
+
 impl Debug for MapError {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
         match self {
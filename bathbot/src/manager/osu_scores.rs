@@ -1,8 +1,9 @@
 use std::{cmp, slice};
 
-use eyre::{Result, WrapErr};
+use bathbot_client::{ClientError, OsuServerScores};
+use eyre::{Report, Result, WrapErr};
+use futures::{StreamExt, stream};
 use rosu_v2::{
-    OsuResult,
     model::score::BeatmapUserScore,
     prelude::{GameMode, GameModsIntermode, OsuError, Score},
 };
@@ -26,16 +27,19 @@ impl ScoresManager {
         limit: u32,
         legacy_scores: bool,
     ) -> Result<Vec<Score>> {
-        let mut req = Context::osu()
-            .beatmap_scores(map_id)
-            .limit(limit)
-            .mode(mode)
-            .legacy_only(legacy_scores)
-            .legacy_scores(legacy_scores);
-
-        if let Some(mods) = mods {
-            req = req.mods(mods);
-        }
+        let req = Context::osu_request(|osu| {
+            let req = osu
+                .beatmap_scores(map_id)
+                .limit(limit)
+                .mode(mode)
+                .legacy_only(legacy_scores)
+                .legacy_scores(legacy_scores);
+
+            match mods {
+                Some(mods) => req.mods(mods),
+                None => req,
+            }
+        });
 
         let scores = req.await.wrap_err("Failed to get map leaderboard")?.scores;
 
@@ -53,15 +57,18 @@ impl ScoresManager {
         mods: Option<GameModsIntermode>,
         legacy_scores: bool,
     ) -> Result<BeatmapUserScore, OsuError> {
-        let mut req = Context::osu()
-            .beatmap_user_score(map_id, user_id)
-            .mode(mode)
-            .legacy_only(legacy_scores)
-            .legacy_scores(legacy_scores);
-
-        if let Some(mods) = mods {
-            req = req.mods(mods);
-        }
+        let req = Context::osu_request(|osu| {
+            let req = osu
+                .beatmap_user_score(map_id, user_id)
+                .mode(mode)
+                .legacy_only(legacy_scores)
+                .legacy_scores(legacy_scores);
+
+            match mods {
+                Some(mods) => req.mods(mods),
+                None => req,
+            }
+        });
 
         let score = req.await?;
 
@@ -71,6 +78,102 @@ impl ScoresManager {
         Ok(score)
     }
 
+    /// Fetch the best score on a map for each of the given users.
+    ///
+    /// Users without a score on the map are skipped. The returned scores are
+    /// sorted by score.
+    pub async fn users_on_map(
+        self,
+        user_ids: &[u32],
+        map_id: u32,
+        mode: GameMode,
+        mods: Option<GameModsIntermode>,
+        legacy_scores: bool,
+    ) -> Result<Vec<Score>> {
+        /// Max amount of simultaneous requests
+        const CONCURRENCY: usize = 8;
+
+        let mut score_stream = stream::iter(user_ids)
+            .map(|&user_id| {
+                let mods = mods.clone();
+
+                async move {
+                    let score_fut = Context::osu_scores().user_on_map_single(
+                        user_id,
+                        map_id,
+                        mode,
+                        mods,
+                        legacy_scores,
+                    );
+
+                    (user_id, score_fut.await)
+                }
+            })
+            .buffer_unordered(CONCURRENCY);
+
+        let mut scores = Vec::new();
+
+        while let Some((user_id, res)) = score_stream.next().await {
+            match res {
+                Ok(score) => scores.push(score.score),
+                Err(OsuError::NotFound) => {}
+                Err(err) => {
+                    let wrap = format!("Failed to get score of user {user_id}");
+
+                    return Err(Report::new(err).wrap_err(wrap));
+                }
+            }
+        }
+
+        scores.sort_unstable_by(|a, b| b.score.cmp(&a.score));
+
+        Ok(scores)
+    }
+
+    /// Fetch the most recently set top score for each of the given users.
+    ///
+    /// Users without top scores are skipped. The returned scores are sorted
+    /// by date, newest first.
+    pub async fn users_latest_top(
+        self,
+        user_ids: &[u32],
+        mode: GameMode,
+        legacy_scores: bool,
+    ) -> Result<Vec<Score>> {
+        /// Max amount of simultaneous requests
+        const CONCURRENCY: usize = 8;
+
+        let mut score_stream = stream::iter(user_ids)
+            .map(|&user_id| async move {
+                let user_args = UserArgsSlim::user_id(user_id).mode(mode);
+
+                let scores_fut = Context::osu_scores()
+                    .top(100, legacy_scores)
+                    .exec(user_args);
+
+                (user_id, scores_fut.await)
+            })
+            .buffer_unordered(CONCURRENCY);
+
+        let mut scores = Vec::with_capacity(user_ids.len());
+
+        while let Some((user_id, res)) = score_stream.next().await {
+            match res {
+                Ok(top) => scores.extend(top.into_iter().max_by_key(|score| score.ended_at)),
+                Err(UserArgsError::Osu(OsuError::NotFound)) => {}
+                Err(err) => {
+                    let wrap = format!("Failed to get top scores of user {user_id}");
+
+                    return Err(Report::new(err).wrap_err(wrap));
+                }
+            }
+        }
+
+        scores.sort_unstable_by(|a, b| b.ended_at.cmp(&a.ended_at));
+
+        Ok(scores)
+    }
+
     pub fn top(self, limit: usize, legacy_scores: bool) -> ScoreArgs {
         ScoreArgs {
             manager: self,
@@ -84,6 +187,7 @@ impl ScoresManager {
             manager: self,
             kind: ScoreKind::Recent {
                 limit: 100,
+                offset: 0,
                 include_fails: true,
             },
             legacy_scores,
@@ -111,6 +215,34 @@ impl ScoresManager {
             warn!(?err, "Failed to store scores");
         }
     }
+
+    async fn store_completions(&self, scores: &[Score]) {
+        if let Err(err) = Context::psql().insert_map_completions(scores).await {
+            warn!(?err, "Failed to store map completions");
+        }
+    }
+
+    /// Returns how many observed recent scores reached each completion
+    /// percentage on the map.
+    pub async fn map_completions(self, map_id: u32, mode: GameMode) -> Result<MapCompletions> {
+        let rows = Context::psql()
+            .select_map_completions(map_id, mode)
+            .await
+            .wrap_err("Failed to get map completions")?;
+
+        let mut completions = MapCompletions::default();
+
+        for row in rows {
+            if row.passed {
+                completions.passes += row.count as usize;
+            } else {
+                let idx = (row.completion.clamp(0, 100) as usize).min(99) / 10;
+                completions.fails[idx] += row.count as usize;
+            }
+        }
+
+        Ok(completions)
+    }
 }
 
 #[derive(Clone)]
@@ -122,10 +254,21 @@ pub struct ScoreArgs {
 
 #[derive(Copy, Clone)]
 enum ScoreKind {
-    Top { limit: usize, offset: usize },
-    Recent { limit: usize, include_fails: bool },
-    Pinned { limit: usize },
-    UserMap { map_id: u32 },
+    Top {
+        limit: usize,
+        offset: usize,
+    },
+    Recent {
+        limit: usize,
+        offset: usize,
+        include_fails: bool,
+    },
+    Pinned {
+        limit: usize,
+    },
+    UserMap {
+        map_id: u32,
+    },
 }
 
 impl ScoreArgs {
@@ -152,7 +295,11 @@ impl ScoreArgs {
         self
     }
 
-    pub async fn exec(mut self, user_args: UserArgsSlim) -> OsuResult<Vec<Score>> {
+    pub async fn exec(mut self, user_args: UserArgsSlim) -> Result<Vec<Score>, UserArgsError> {
+        if let Some(server) = Context::osu_server() {
+            return self.exec_server(&server, user_args).await;
+        }
+
         let UserArgsSlim { user_id, mode } = user_args;
         let mut again = true;
         let mut scores = Vec::new();
@@ -160,6 +307,8 @@ impl ScoreArgs {
         while again {
             again = false;
 
+            let legacy_scores = self.legacy_scores;
+
             // Retrieve score(s)
             let scores_res = match self.kind {
                 ScoreKind::Top {
@@ -176,47 +325,63 @@ impl ScoreArgs {
                         again = true;
                     }
 
-                    Context::osu()
-                        .user_scores(user_id)
-                        .best()
-                        .limit(curr_limit)
-                        .offset(curr_offset)
-                        .mode(mode)
-                        .legacy_only(self.legacy_scores)
-                        .legacy_scores(self.legacy_scores)
-                        .await
+                    Context::osu_request(|osu| {
+                        osu.user_scores(user_id)
+                            .best()
+                            .limit(curr_limit)
+                            .offset(curr_offset)
+                            .mode(mode)
+                            .legacy_only(legacy_scores)
+                            .legacy_scores(legacy_scores)
+                    })
+                    .await
                 }
                 ScoreKind::Recent {
-                    limit,
+                    ref mut limit,
+                    ref mut offset,
                     include_fails,
                 } => {
-                    Context::osu()
-                        .user_scores(user_id)
-                        .recent()
-                        .limit(limit)
-                        .mode(mode)
-                        .include_fails(include_fails)
-                        .legacy_only(self.legacy_scores)
-                        .legacy_scores(self.legacy_scores)
-                        .await
+                    let curr_limit = cmp::min(*limit, 100);
+                    let curr_offset = *offset;
+
+                    if *limit > 100 {
+                        *limit -= 100;
+                        *offset += 100;
+
+                        again = true;
+                    }
+
+                    Context::osu_request(|osu| {
+                        osu.user_scores(user_id)
+                            .recent()
+                            .limit(curr_limit)
+                            .offset(curr_offset)
+                            .mode(mode)
+                            .include_fails(include_fails)
+                            .legacy_only(legacy_scores)
+                            .legacy_scores(legacy_scores)
+                    })
+                    .await
                 }
                 ScoreKind::Pinned { limit } => {
-                    Context::osu()
-                        .user_scores(user_id)
-                        .pinned()
-                        .limit(limit)
-                        .mode(mode)
-                        .legacy_only(self.legacy_scores)
-                        .legacy_scores(self.legacy_scores)
-                        .await
+                    Context::osu_request(|osu| {
+                        osu.user_scores(user_id)
+                            .pinned()
+                            .limit(limit)
+                            .mode(mode)
+                            .legacy_only(legacy_scores)
+                            .legacy_scores(legacy_scores)
+                    })
+                    .await
                 }
                 ScoreKind::UserMap { map_id } => {
-                    Context::osu()
-                        .beatmap_user_scores(map_id, user_id)
-                        .mode(mode)
-                        .legacy_only(self.legacy_scores)
-                        .legacy_scores(self.legacy_scores)
-                        .await
+                    Context::osu_request(|osu| {
+                        osu.beatmap_user_scores(map_id, user_id)
+                            .mode(mode)
+                            .legacy_only(legacy_scores)
+                            .legacy_scores(legacy_scores)
+                    })
+                    .await
                 }
             };
 
@@ -230,9 +395,9 @@ impl ScoreArgs {
                         warn!(?err, "Failed to remove stats of unknown user");
                     }
 
-                    return Err(OsuError::NotFound);
+                    return Err(UserArgsError::Osu(OsuError::NotFound));
                 }
-                Err(err) => return Err(err),
+                Err(err) => return Err(UserArgsError::Osu(err)),
             };
 
             if scores.is_empty() {
@@ -251,11 +416,65 @@ impl ScoreArgs {
         }
 
         let scores_clone = Box::from(scores.as_slice());
-        tokio::spawn(async move { self.manager.store(&scores_clone).await });
+        let is_recent = matches!(self.kind, ScoreKind::Recent { .. });
+
+        tokio::spawn(async move {
+            // Recent scores are an unbiased sample of how far players get
+            // into maps
+            if is_recent {
+                self.manager.store_completions(&scores_clone).await;
+            }
+
+            self.manager.store(&scores_clone).await;
+        });
 
         Ok(scores)
     }
 
+    /// Retrieve scores from the private server that the current event targets.
+    ///
+    /// Such scores are not stored because their ids collide with bancho's.
+    async fn exec_server(
+        self,
+        server: &str,
+        user_args: UserArgsSlim,
+    ) -> Result<Vec<Score>, UserArgsError> {
+        let UserArgsSlim { user_id, mode } = user_args;
+
+        let scores_res = match self.kind {
+            ScoreKind::Top { limit, offset } => {
+                let kind = OsuServerScores::Best;
+
+                server_scores(server, user_id, kind, mode, limit, offset).await
+            }
+            ScoreKind::Recent {
+                limit,
+                offset,
+                include_fails,
+            } => {
+                let kind = OsuServerScores::Recent { include_fails };
+
+                server_scores(server, user_id, kind, mode, limit, offset).await
+            }
+            ScoreKind::Pinned { limit } => {
+                let kind = OsuServerScores::Pinned;
+
+                server_scores(server, user_id, kind, mode, limit, 0).await
+            }
+            ScoreKind::UserMap { map_id } => {
+                Context::client()
+                    .get_server_user_map_scores(server, map_id, user_id, mode)
+                    .await
+            }
+        };
+
+        match scores_res {
+            Ok(scores) => Ok(scores),
+            Err(ClientError::NotFound) => Err(UserArgsError::Osu(OsuError::NotFound)),
+            Err(err) => Err(UserArgsError::Client(err)),
+        }
+    }
+
     pub async fn exec_with_user(
         self,
         user_args: UserArgs,
@@ -279,3 +498,47 @@ impl ScoreArgs {
         }
     }
 }
+
+/// Request scores from a private server in pages of up to 100 scores.
+async fn server_scores(
+    server: &str,
+    user_id: u32,
+    kind: OsuServerScores,
+    mode: GameMode,
+    mut limit: usize,
+    mut offset: usize,
+) -> Result<Vec<Score>, ClientError> {
+    let mut scores = Vec::new();
+
+    loop {
+        let curr_limit = cmp::min(limit, 100);
+
+        let mut next_scores = Context::client()
+            .get_server_user_scores(server, user_id, kind, mode, curr_limit, offset)
+            .await?;
+
+        let done = limit <= 100 || next_scores.len() < curr_limit;
+        scores.append(&mut next_scores);
+
+        if done {
+            return Ok(scores);
+        }
+
+        limit -= 100;
+        offset += 100;
+    }
+}
+
+/// Pass and fail counts of observed recent scores on a map.
+#[derive(Default)]
+pub struct MapCompletions {
+    pub passes: usize,
+    /// Fails grouped by completion into buckets of 10%
+    pub fails: [usize; 10],
+}
+
+impl MapCompletions {
+    pub fn total(&self) -> usize {
+        self.passes + self.fails.iter().sum::<usize>()
+    }
+}
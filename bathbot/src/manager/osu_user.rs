@@ -1,7 +1,7 @@
 use std::{borrow::Cow, collections::HashMap};
 
 use bathbot_model::{RankingEntries, UserModeStatsColumn, UserStatsColumn};
-use bathbot_psql::Database;
+use bathbot_psql::{Database, model::osu::DbEfficiencyPeer};
 use bathbot_util::{CowUtils, IntHasher};
 use eyre::{Result, WrapErr};
 use rosu_v2::prelude::{GameMode, UserExtended, Username};
@@ -29,6 +29,20 @@ impl OsuUserManager {
             .wrap_err("Failed to get osu id")
     }
 
+    /// Id and current name of the user that previously went by the given name.
+    pub async fn alias(
+        self,
+        username: &str,
+        alt_username: Option<&str>,
+    ) -> Result<Option<(u32, Username)>> {
+        let username = username.cow_replace('_', r"\_");
+
+        self.psql
+            .select_osu_user_by_alias(username.as_ref(), alt_username)
+            .await
+            .wrap_err("Failed to get user by alias")
+    }
+
     pub async fn name(self, user_id: u32) -> Result<Option<Username>> {
         self.psql
             .select_osu_name_by_osu_id(user_id)
@@ -43,6 +57,17 @@ impl OsuUserManager {
             .wrap_err("Failed to get usernames")
     }
 
+    pub async fn pp(
+        self,
+        user_ids: &[i32],
+        mode: GameMode,
+    ) -> Result<HashMap<u32, f32, IntHasher>> {
+        self.psql
+            .select_osu_users_pp(user_ids, mode)
+            .await
+            .wrap_err("Failed to get users' pp")
+    }
+
     pub async fn ids(&self, names: &[String]) -> Result<HashMap<Username, u32>> {
         let escaped_names = if names.iter().any(|name| name.contains('_')) {
             let names: Vec<_> = names.iter().map(|name| name.replace('_', r"\_")).collect();
@@ -85,6 +110,21 @@ impl OsuUserManager {
             .wrap_err("Failed to get user mode stats")
     }
 
+    /// Stats of users with a global rank between `min_rank` and `max_rank`,
+    /// at most `limit` of them.
+    pub async fn efficiency_peers(
+        self,
+        mode: GameMode,
+        min_rank: u32,
+        max_rank: u32,
+        limit: usize,
+    ) -> Result<Vec<DbEfficiencyPeer>> {
+        self.psql
+            .select_osu_user_efficiency_peers(mode, min_rank, max_rank, limit)
+            .await
+            .wrap_err("Failed to get efficiency peers")
+    }
+
     pub async fn store(self, user: &UserExtended, mode: GameMode) {
         if let Err(err) = self.psql.upsert_osu_user(user, mode).await {
             warn!(?err, "Failed to upsert osu user");
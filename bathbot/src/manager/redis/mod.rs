@@ -6,9 +6,10 @@ use bathbot_cache::{
     util::serialize::{SerializerStrategy, serialize_using_arena, serialize_using_arena_and_with},
 };
 use bathbot_model::{
-    ArchivedOsekaiBadge, ArchivedOsekaiMedal, ArchivedOsuStatsBestScores,
-    ArchivedOsuTrackHistoryEntry, ArchivedScrapedMedal, ArchivedSnipeCountries, OsekaiRanking,
-    OsuStatsBestScores, OsuStatsBestTimeframe,
+    ArchivedLeaderboardSnapshotEntry, ArchivedOsekaiBadge, ArchivedOsekaiMedal,
+    ArchivedOsuStatsBestScores, ArchivedOsuTrackHistoryEntry, ArchivedScrapedMedal,
+    ArchivedSnipeCountries, LeaderboardSnapshotEntry, OsekaiRanking, OsuStatsBestScores,
+    OsuStatsBestTimeframe,
     rosu_v2::{
         multiplayer::{ArchivedRoom, RoomRkyv},
         ranking::{ArchivedRankings, RankingsRkyv},
@@ -28,6 +29,7 @@ use rosu_v2::{
 };
 use thiserror::Error as ThisError;
 use time::{Date, UtcDateTime};
+use twilight_model::id::{Id, marker::GuildMarker};
 
 use crate::{
     core::{BotMetrics, Context},
@@ -282,12 +284,13 @@ impl RedisManager {
             RoomsFilter::Ended
         };
 
-        let rooms = Context::osu()
-            .rooms()
-            .category(RoomCategory::DailyChallenge)
-            .filter(filter)
-            .await
-            .map_err(Report::new)?;
+        let rooms = Context::osu_request(|osu| {
+            osu.rooms()
+                .category(RoomCategory::DailyChallenge)
+                .filter(filter)
+        })
+        .await
+        .map_err(Report::new)?;
 
         let bytes_res = rooms.iter().find_map(|room| {
             if room.starts_at.date() != date {
@@ -400,11 +403,15 @@ impl RedisManager {
             }
         };
 
-        let mut ranking_fut = Context::osu().performance_rankings(mode).page(page);
+        let ranking_fut = Context::osu_request(|osu| {
+            let mut ranking_fut = osu.performance_rankings(mode).page(page);
 
-        if let Some(country) = country {
-            ranking_fut = ranking_fut.country(country);
-        }
+            if let Some(country) = country {
+                ranking_fut = ranking_fut.country(country);
+            }
+
+            ranking_fut
+        });
 
         let ranking = ranking_fut.await.map_err(Report::new)?;
 
@@ -581,11 +588,15 @@ impl RedisManager {
         let map_id = match map {
             Some(MapOrScore::Map(id)) => Some(id),
             Some(MapOrScore::Score { id, mode }) => {
-                let mut score_fut = Context::osu().score(id);
+                let score_fut = Context::osu_request(|osu| {
+                    let mut score_fut = osu.score(id);
 
-                if let Some(mode) = mode {
-                    score_fut = score_fut.mode(mode);
-                }
+                    if let Some(mode) = mode {
+                        score_fut = score_fut.mode(mode);
+                    }
+
+                    score_fut
+                });
 
                 let score = score_fut.await.wrap_err("Failed to get score")?;
 
@@ -624,6 +635,77 @@ impl RedisManager {
             .map(Some)
             .map_err(RedisError::Validation)
     }
+
+    /// Store the given leaderboard entries for the guild and map while
+    /// returning the previously stored entries.
+    pub async fn swap_leaderboard_snapshot(
+        self,
+        guild_id: Id<GuildMarker>,
+        map_id: u32,
+        mode: GameMode,
+        entries: Vec<LeaderboardSnapshotEntry>,
+    ) -> Result<Option<CachedArchive<ArchivedVec<ArchivedLeaderboardSnapshotEntry>>>, RedisError>
+    {
+        const EXPIRE: u64 = 2_592_000; // 30 days
+        let key = format!("leaderboard_snapshot_{guild_id}_{map_id}_{}", mode as u8);
+
+        let (prev, mut conn) = match Context::cache().fetch(&key).await {
+            Ok(Ok(prev)) => (Some(prev), None),
+            Ok(Err(conn)) => (None, Some(conn)),
+            Err(err) => {
+                warn!(?err, "Failed to fetch leaderboard snapshot");
+
+                (None, None)
+            }
+        };
+
+        let bytes = serialize_using_arena(&entries).map_err(RedisError::Serialization)?;
+
+        let store_res = match conn {
+            Some(ref mut conn) => Cache::store(conn, &key, bytes.as_slice(), EXPIRE).await,
+            None => {
+                Context::cache()
+                    .store_new(&key, bytes.as_slice(), EXPIRE)
+                    .await
+            }
+        };
+
+        if let Err(err) = store_res {
+            warn!(?err, "Failed to store leaderboard snapshot");
+        }
+
+        Ok(prev)
+    }
+
+    /// Count a processed command towards today's total.
+    pub async fn inc_commands_today(self) {
+        const EXPIRE: i64 = 172_800; // 2 days
+
+        let key = commands_today_key(UtcDateTime::now().date());
+
+        if let Err(err) = Context::cache().increment_counter(&key, EXPIRE).await {
+            warn!(?err, "Failed to increment commands counter");
+        }
+    }
+
+    /// Amount of commands that were processed today (UTC).
+    pub async fn commands_today(self) -> Result<u64> {
+        let key = commands_today_key(UtcDateTime::now().date());
+
+        Context::cache()
+            .fetch_counter(&key)
+            .await
+            .wrap_err("Failed to fetch commands counter")
+    }
+}
+
+fn commands_today_key(date: Date) -> String {
+    format!(
+        "commands_today_{}_{}_{}",
+        date.year() % 2000,
+        date.month() as u8,
+        date.day()
+    )
 }
 
 #[cfg(feature = "twitch")]
@@ -1,11 +1,12 @@
-use std::borrow::Cow;
+use std::{borrow::Cow, cell::RefCell, fmt::Write, future::Future, mem};
 
 use bathbot_cache::{Cache, model::CachedArchive, util::serialize::serialize_using_arena_and_with};
+use bathbot_client::ClientError;
 use bathbot_model::rosu_v2::user::{ArchivedUser, User};
 use bathbot_util::CowUtils;
 use rkyv::rancor::BoxedError;
 use rosu_v2::{
-    prelude::{GameMode, OsuError, UserExtended},
+    prelude::{GameMode, OsuError, UserExtended, Username},
     request::UserId,
 };
 
@@ -14,6 +15,36 @@ use crate::core::{BotMetrics, Context};
 
 pub type CachedUser = CachedArchive<ArchivedUser>;
 
+tokio::task_local! {
+    /// Names that were resolved through a previous username while processing
+    /// the current event alongside the user's current name.
+    static RESOLVED_ALIASES: RefCell<Vec<(Box<str>, Username)>>;
+}
+
+/// Process an event such that names which are resolved through a previous
+/// username can be pointed out in the response.
+pub async fn with_alias_notes<F: Future>(fut: F) -> F::Output {
+    RESOLVED_ALIASES.scope(RefCell::default(), fut).await
+}
+
+/// Take a note about all names that were resolved through a previous username
+/// since the last call.
+pub fn take_alias_notes() -> Option<String> {
+    let aliases = RESOLVED_ALIASES
+        .try_with(|aliases| mem::take(&mut *aliases.borrow_mut()))
+        .ok()?;
+
+    let mut aliases = aliases.into_iter();
+    let (name, current) = aliases.next()?;
+    let mut notes = format!("`{name}` is now known as `{current}`");
+
+    for (name, current) in aliases {
+        let _ = write!(notes, "\n`{name}` is now known as `{current}`");
+    }
+
+    Some(notes)
+}
+
 /// Retrieve an osu user through redis or the osu!api as backup
 pub enum UserArgs {
     Args(UserArgsSlim),
@@ -25,6 +56,8 @@ pub enum UserArgs {
 pub enum UserArgsError {
     #[error("osu! error")]
     Osu(#[from] OsuError),
+    #[error("client error")]
+    Client(#[source] ClientError),
     #[error("Failed to serialize data; {user:?}")]
     Serialization {
         #[source]
@@ -49,6 +82,16 @@ impl UserArgs {
 
     pub async fn username(name: impl AsRef<str>, mode: GameMode) -> Self {
         let name = name.as_ref();
+
+        if let Some(server) = Context::osu_server() {
+            let user_id = UserId::Name(name.into());
+
+            return match RedisManager::server_osu_user(&server, &user_id, mode).await {
+                Ok(user) => Self::User { user, mode },
+                Err(err) => Self::Err(err),
+            };
+        }
+
         let alt_name = Self::alt_name(name);
 
         match Context::osu_user().user_id(name, alt_name.as_deref()).await {
@@ -57,15 +100,40 @@ impl UserArgs {
             Ok(None) => {}
         }
 
-        match (Context::osu().user(name).mode(mode).await, alt_name) {
-            (Ok(user), _) => Self::from_user(user, mode),
+        let res = match (
+            Context::osu_request(|osu| osu.user(name).mode(mode)).await,
+            alt_name.as_deref(),
+        ) {
+            (Ok(user), _) => return Self::from_user(user, mode),
             (Err(OsuError::NotFound), Some(alt_name)) => {
-                match Context::osu().user(alt_name).mode(mode).await {
-                    Ok(user) => Self::from_user(user, mode),
-                    Err(err) => Self::Err(UserArgsError::Osu(err)),
-                }
+                Context::osu_request(|osu| osu.user(alt_name).mode(mode)).await
+            }
+            (Err(err), _) => Err(err),
+        };
+
+        match res {
+            Ok(user) => Self::from_user(user, mode),
+            Err(OsuError::NotFound) => Self::alias(name, alt_name.as_deref(), mode).await,
+            Err(err) => Self::Err(UserArgsError::Osu(err)),
+        }
+    }
+
+    /// Resolve a name the user went by previously.
+    async fn alias(name: &str, alt_name: Option<&str>, mode: GameMode) -> Self {
+        match Context::osu_user().alias(name, alt_name).await {
+            Ok(Some((user_id, current))) => {
+                let _ = RESOLVED_ALIASES.try_with(|aliases| {
+                    aliases.borrow_mut().push((Box::from(name), current));
+                });
+
+                Self::user_id(user_id, mode)
+            }
+            Ok(None) => Self::Err(UserArgsError::Osu(OsuError::NotFound)),
+            Err(err) => {
+                warn!(?err, "Failed to get user by alias");
+
+                Self::Err(UserArgsError::Osu(OsuError::NotFound))
             }
-            (Err(err), _) => Self::Err(UserArgsError::Osu(err)),
         }
     }
 
@@ -147,12 +215,18 @@ impl TryFrom<UserArgs> for UserArgsSlim {
 const EXPIRE: u64 = 600;
 
 impl RedisManager {
+    // Bump the version whenever the layout of the archived user changes
     fn osu_user_key(user_id: u32, mode: GameMode) -> String {
-        format!("osu_user_{user_id}_{}", mode as u8)
+        format!("osu_user_v3_{user_id}_{}", mode as u8)
     }
 
     pub async fn osu_user_from_args(self, args: UserArgsSlim) -> Result<CachedUser, UserArgsError> {
         let UserArgsSlim { user_id, mode } = args;
+
+        if let Some(server) = Context::osu_server() {
+            return Self::server_osu_user(&server, &UserId::Id(user_id), mode).await;
+        }
+
         let key = Self::osu_user_key(user_id, mode);
 
         let mut conn = match Context::cache().fetch(&key).await {
@@ -169,18 +243,18 @@ impl RedisManager {
             }
         };
 
-        let mut user = match Context::osu().user(user_id).mode(mode).await {
+        let mut user = match Self::request_user(user_id, mode).await {
             Ok(user) => user,
-            Err(err @ OsuError::NotFound) => {
+            Err(err @ UserArgsError::Osu(OsuError::NotFound)) => {
                 // Remove stats of unknown/restricted users so they don't appear in the
                 // leaderboard
                 if let Err(err) = Context::osu_user().remove_stats_and_scores(user_id).await {
                     warn!(?err, "Failed to remove stats of unknown user");
                 }
 
-                return Err(UserArgsError::Osu(err));
+                return Err(err);
             }
-            Err(err) => return Err(UserArgsError::Osu(err)),
+            Err(err) => return Err(err),
         };
 
         user.mode = mode;
@@ -212,7 +286,66 @@ impl RedisManager {
         CachedUser::new(bytes).map_err(UserArgsError::Validation)
     }
 
+    /// Request a user from a private server.
+    ///
+    /// Such users are neither cached nor stored because their ids collide with
+    /// bancho's.
+    async fn server_osu_user(
+        server: &str,
+        user_id: &UserId,
+        mode: GameMode,
+    ) -> Result<CachedUser, UserArgsError> {
+        let mut user = match Context::client()
+            .get_server_osu_user(server, user_id, mode)
+            .await
+        {
+            Ok(user) => user,
+            Err(ClientError::NotFound) => return Err(UserArgsError::Osu(OsuError::NotFound)),
+            Err(err) => return Err(UserArgsError::Client(err)),
+        };
+
+        user.mode = mode;
+
+        let bytes = match serialize_using_arena_and_with::<_, User>(&user) {
+            Ok(bytes) => bytes,
+            Err(source) => {
+                return Err(UserArgsError::Serialization {
+                    source,
+                    user: Box::new(user),
+                });
+            }
+        };
+
+        CachedUser::new(bytes).map_err(UserArgsError::Validation)
+    }
+
+    /// Revalidate the user against a previous response if possible and only go
+    /// through the osu!api client if the request could not be sent or its
+    /// response could not be deserialized.
+    ///
+    /// Error responses such as ratelimits are returned as is because the
+    /// osu!api client would run into them as well.
+    async fn request_user(user_id: u32, mode: GameMode) -> Result<UserExtended, UserArgsError> {
+        match Context::client().get_osu_user(user_id, mode).await {
+            Ok(user) => Ok(user),
+            Err(ClientError::NotFound) => Err(UserArgsError::Osu(OsuError::NotFound)),
+            Err(err @ ClientError::Report(_)) => {
+                warn!(?err, user_id, "Failed conditional user request");
+
+                Context::osu_request(|osu| osu.user(user_id).mode(mode))
+                    .await
+                    .map_err(UserArgsError::Osu)
+            }
+            Err(err) => Err(UserArgsError::Client(err)),
+        }
+    }
+
     pub async fn osu_user_from_archived(self, user: CachedUser, mode: GameMode) -> CachedUser {
+        // Users of private servers must not end up in bancho's cache
+        if Context::osu_server().is_some() {
+            return user;
+        }
+
         let key = Self::osu_user_key(user.user_id.to_native(), mode);
         let bytes = user.as_bytes();
         let store_fut = Context::cache().store_new(&key, bytes, EXPIRE);
@@ -0,0 +1,43 @@
+use bathbot_psql::{Database, model::refscript::DbRefscript};
+use eyre::{Result, WrapErr};
+use twilight_model::id::{Id, marker::GuildMarker};
+
+use crate::core::Context;
+
+#[derive(Copy, Clone)]
+pub struct RefscriptManager {
+    psql: &'static Database,
+}
+
+impl RefscriptManager {
+    pub fn new() -> Self {
+        Self {
+            psql: Context::psql(),
+        }
+    }
+
+    pub async fn get(self, guild_id: Id<GuildMarker>) -> Result<Option<DbRefscript>> {
+        self.psql
+            .select_guild_refscript(guild_id)
+            .await
+            .wrap_err("Failed to get refscript config")
+    }
+
+    pub async fn set_mappool(self, guild_id: Id<GuildMarker>, mappool: &str) -> Result<()> {
+        self.psql
+            .upsert_guild_refscript_mappool(guild_id, mappool)
+            .await
+            .wrap_err("Failed to upsert refscript mappool")
+    }
+
+    pub async fn set_template(
+        self,
+        guild_id: Id<GuildMarker>,
+        template: Option<&str>,
+    ) -> Result<()> {
+        self.psql
+            .upsert_guild_refscript_template(guild_id, template)
+            .await
+            .wrap_err("Failed to upsert refscript template")
+    }
+}
@@ -7,6 +7,8 @@ use rosu_render::model::{RenderOptions, RenderResolution, RenderSkinOption, Skin
 use rosu_v2::{Osu, error::OsuError};
 use twilight_model::id::{Id, marker::UserMarker};
 
+use crate::core::Context;
+
 #[derive(Copy, Clone)]
 pub struct ReplayManager {
     psql: &'static Database,
@@ -38,6 +40,9 @@ impl ReplayManager {
             return Ok(None);
         }
 
+        // The client uses the primary credentials whose ratelimit is shared
+        Context::client().osu_api_ratelimit().await;
+
         let replay = self
             .osu
             .replay_raw(score_id)
@@ -0,0 +1,58 @@
+use bathbot_psql::{Database, model::scheduled_match::DbScheduledMatch};
+use eyre::{Result, WrapErr};
+use time::OffsetDateTime;
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, GuildMarker, UserMarker},
+};
+
+use crate::core::Context;
+
+#[derive(Copy, Clone)]
+pub struct ScheduledMatchManager {
+    psql: &'static Database,
+}
+
+impl ScheduledMatchManager {
+    pub fn new() -> Self {
+        Self {
+            psql: Context::psql(),
+        }
+    }
+
+    pub async fn schedule(
+        self,
+        guild_id: Id<GuildMarker>,
+        channel_id: Id<ChannelMarker>,
+        players: [Id<UserMarker>; 2],
+        start_at: OffsetDateTime,
+    ) -> Result<()> {
+        let [player1, player2] = players;
+
+        self.psql
+            .insert_scheduled_match(guild_id, channel_id, player1, player2, start_at)
+            .await
+            .wrap_err("Failed to insert scheduled match")
+    }
+
+    pub async fn upcoming(self, guild_id: Id<GuildMarker>) -> Result<Vec<DbScheduledMatch>> {
+        self.psql
+            .select_upcoming_matches(guild_id)
+            .await
+            .wrap_err("Failed to get upcoming matches")
+    }
+
+    pub async fn due_reminders(self, until: OffsetDateTime) -> Result<Vec<DbScheduledMatch>> {
+        self.psql
+            .select_due_match_reminders(until)
+            .await
+            .wrap_err("Failed to get due match reminders")
+    }
+
+    pub async fn mark_reminded(self, match_id: i32) -> Result<()> {
+        self.psql
+            .update_match_reminded(match_id)
+            .await
+            .wrap_err("Failed to mark match as reminded")
+    }
+}
@@ -0,0 +1,35 @@
+use bathbot_psql::{Database, model::simulate_preset::DbSimulatePreset};
+use eyre::{Result, WrapErr};
+use twilight_model::id::{Id, marker::UserMarker};
+
+use crate::core::Context;
+
+#[derive(Copy, Clone)]
+pub struct SimulatePresetManager {
+    psql: &'static Database,
+}
+
+impl SimulatePresetManager {
+    /// Max amount of presets a user can have.
+    pub const MAX_PRESETS: usize = 25;
+
+    pub fn new() -> Self {
+        Self {
+            psql: Context::psql(),
+        }
+    }
+
+    pub async fn get(self, user: Id<UserMarker>) -> Result<Vec<DbSimulatePreset>> {
+        self.psql
+            .select_simulate_presets(user)
+            .await
+            .wrap_err("Failed to get simulate presets")
+    }
+
+    pub async fn store(self, user: Id<UserMarker>, preset: &DbSimulatePreset) -> Result<()> {
+        self.psql
+            .upsert_simulate_preset(user, preset)
+            .await
+            .wrap_err("Failed to upsert simulate preset")
+    }
+}
@@ -6,7 +6,11 @@ use bathbot_psql::{
 use bathbot_util::CowUtils;
 use eyre::{Result, WrapErr};
 use rosu_v2::prelude::{GameMode, Username};
-use twilight_model::id::{Id, marker::UserMarker};
+use time::OffsetDateTime;
+use twilight_model::id::{
+    Id,
+    marker::{GuildMarker, UserMarker},
+};
 
 use crate::core::Context;
 
@@ -48,6 +52,29 @@ impl UserConfigManager {
             .wrap_err("Failed to get user mode from DB")
     }
 
+    pub async fn accessibility(self, user_id: Id<UserMarker>) -> Result<bool> {
+        self.psql
+            .select_user_accessibility(user_id)
+            .await
+            .map(|accessibility| accessibility.unwrap_or(false))
+            .wrap_err("Failed to get user accessibility from DB")
+    }
+
+    pub async fn data_saver(self, user_id: Id<UserMarker>) -> Result<bool> {
+        self.psql
+            .select_user_data_saver(user_id)
+            .await
+            .map(|data_saver| data_saver.unwrap_or(false))
+            .wrap_err("Failed to get user data saver from DB")
+    }
+
+    pub async fn unicode_titles(self, user_id: Id<UserMarker>) -> Result<Option<bool>> {
+        self.psql
+            .select_user_unicode_titles(user_id)
+            .await
+            .wrap_err("Failed to get user unicode titles from DB")
+    }
+
     pub async fn osu_id(self, user_id: Id<UserMarker>) -> Result<Option<u32>> {
         self.psql
             .select_osu_id_by_discord_id(user_id)
@@ -62,6 +89,107 @@ impl UserConfigManager {
             .wrap_err("failed to get username from DB")
     }
 
+    pub async fn linked_discord_ids(
+        self,
+        user_ids: &[Id<UserMarker>],
+    ) -> Result<Vec<Id<UserMarker>>> {
+        let discord_ids: Vec<_> = user_ids.iter().map(|id| id.get() as i64).collect();
+
+        self.psql
+            .select_linked_discord_ids(&discord_ids)
+            .await
+            .map(|ids| ids.into_iter().map(|id| Id::new(id as u64)).collect())
+            .wrap_err("Failed to get linked discord ids")
+    }
+
+    /// Returns the osu! user ids of all given discord users that are linked.
+    pub async fn linked_osu_ids(self, discord_ids: &[i64]) -> Result<Vec<u32>> {
+        self.psql
+            .select_linked_osu_ids(discord_ids)
+            .await
+            .map(|ids| ids.into_iter().map(|id| id as u32).collect())
+            .wrap_err("Failed to get linked osu ids")
+    }
+
+    /// Amount of discord users that are linked to an osu! profile.
+    pub async fn linked_count(self) -> Result<u64> {
+        self.psql
+            .select_linked_user_count()
+            .await
+            .wrap_err("Failed to get linked user count")
+    }
+
+    /// Mark the user as seen so that their config is not pruned.
+    pub async fn mark_seen(self, user_id: Id<UserMarker>) {
+        if let Err(err) = self.update_last_seen(&[user_id.get() as i64]).await {
+            warn!(?err, "Failed to mark user as seen");
+        }
+    }
+
+    pub async fn update_last_seen(self, discord_ids: &[i64]) -> Result<u64> {
+        self.psql
+            .update_user_configs_last_seen(discord_ids)
+            .await
+            .wrap_err("Failed to update last seen of user configs")
+    }
+
+    /// Count or delete unlinked configs of users that are not among
+    /// `present_ids` and have not been seen since `last_seen_before`.
+    pub async fn prune_stale(
+        self,
+        present_ids: &[i64],
+        last_seen_before: OffsetDateTime,
+        dry_run: bool,
+    ) -> Result<u64> {
+        if dry_run {
+            self.psql
+                .select_stale_user_config_count(present_ids, last_seen_before)
+                .await
+                .wrap_err("Failed to count stale user configs")
+        } else {
+            self.psql
+                .delete_stale_user_configs(present_ids, last_seen_before)
+                .await
+                .wrap_err("Failed to delete stale user configs")
+        }
+    }
+
+    /// The osu! id that the guild imported for the user, if any.
+    pub async fn imported_osu_id(
+        self,
+        guild_id: Id<GuildMarker>,
+        user_id: Id<UserMarker>,
+    ) -> Result<Option<u32>> {
+        self.psql
+            .select_imported_osu_id(guild_id, user_id)
+            .await
+            .wrap_err("Failed to get imported osu! id")
+    }
+
+    /// Remove links that guilds imported for the user.
+    pub async fn remove_imported_links(self, user_id: Id<UserMarker>) -> Result<()> {
+        self.psql
+            .delete_imported_osu_links(user_id)
+            .await
+            .wrap_err("Failed to remove imported links")
+    }
+
+    pub async fn import_links(
+        self,
+        guild_id: Id<GuildMarker>,
+        links: &[(Id<UserMarker>, u32)],
+    ) -> Result<u64> {
+        let (discord_ids, osu_ids): (Vec<_>, Vec<_>) = links
+            .iter()
+            .map(|(discord_id, osu_id)| (discord_id.get() as i64, *osu_id as i32))
+            .unzip();
+
+        self.psql
+            .insert_imported_osu_links(guild_id, &discord_ids, &osu_ids)
+            .await
+            .wrap_err("Failed to insert imported links")
+    }
+
     pub async fn discord_from_osu_id(self, user_id: u32) -> Result<Option<Id<UserMarker>>> {
         self.psql
             .select_user_discord_id_by_osu_id(user_id)
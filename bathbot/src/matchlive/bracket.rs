@@ -0,0 +1,144 @@
+use std::{cmp::Ordering, fmt::Write};
+
+use rosu_v2::prelude::{MatchEvent, MatchTeam, OsuMatch};
+use twilight_model::channel::message::embed::EmbedField;
+
+const FIELD_VALUE_SIZE: usize = 1024;
+
+/// Space reserved for the tally of won picks
+const FIELD_BUFFER: usize = 64;
+
+/// Picks and bans that were announced for a tracked match.
+#[derive(Default)]
+pub struct Bracket {
+    entries: Vec<BracketEntry>,
+    /// Id of the latest game that was checked for a picked map
+    last_game: Option<u64>,
+}
+
+pub struct BracketEntry {
+    pub kind: BracketKind,
+    pub team: MatchTeam,
+    pub map_id: u32,
+    /// Formatted name of the map
+    pub map: String,
+    /// Team that won the game on a picked map
+    pub winner: Option<MatchTeam>,
+}
+
+#[derive(Copy, Clone, Eq, PartialEq)]
+pub enum BracketKind {
+    Pick,
+    Ban,
+}
+
+impl Bracket {
+    /// Returns `false` if the map was already picked or banned.
+    pub fn push(&mut self, entry: BracketEntry) -> bool {
+        if self.entries.iter().any(|e| e.map_id == entry.map_id) {
+            return false;
+        }
+
+        self.entries.push(entry);
+
+        true
+    }
+
+    /// Assign winners to picked maps based on finished games that were not
+    /// yet considered.
+    pub fn add_games(&mut self, lobby: &OsuMatch) {
+        for event in lobby.events.iter() {
+            let MatchEvent::Game { game, .. } = event else {
+                continue;
+            };
+
+            if game.end_time.is_none() || self.last_game.is_some_and(|id| id >= game.game_id) {
+                continue;
+            }
+
+            self.last_game = Some(game.game_id);
+
+            let Some(entry) = self.entries.iter_mut().find(|entry| {
+                entry.kind == BracketKind::Pick
+                    && entry.winner.is_none()
+                    && entry.map_id == game.map_id
+            }) else {
+                continue;
+            };
+
+            let [_, blue, red] = game.scores.iter().fold([0_u64; 3], |mut totals, score| {
+                totals[score.info.team as usize] += u64::from(score.score);
+
+                totals
+            });
+
+            entry.winner = match blue.cmp(&red) {
+                Ordering::Greater => Some(MatchTeam::Blue),
+                Ordering::Less => Some(MatchTeam::Red),
+                Ordering::Equal => None,
+            };
+        }
+    }
+
+    /// Embed field listing all picks and bans in order; `None` if there are
+    /// none yet.
+    pub fn field(&self) -> Option<EmbedField> {
+        if self.entries.is_empty() {
+            return None;
+        }
+
+        let mut value = String::with_capacity(self.entries.len() * 64);
+        let mut wins = [0; 3];
+        let mut truncated = false;
+
+        for (entry, i) in self.entries.iter().zip(1..) {
+            if let Some(winner) = entry.winner {
+                wins[winner as usize] += 1;
+            }
+
+            if truncated {
+                continue;
+            }
+
+            let team = team_emote(entry.team);
+
+            let mut line = match entry.kind {
+                BracketKind::Ban => format!("`{i}.` {team} ban ~~{}~~", entry.map),
+                BracketKind::Pick => format!("`{i}.` {team} pick {}", entry.map),
+            };
+
+            if let Some(winner) = entry.winner {
+                let _ = write!(line, " → {} won", team_emote(winner));
+            }
+
+            if value.len() + line.len() + FIELD_BUFFER > FIELD_VALUE_SIZE {
+                value.push_str("…\n");
+                truncated = true;
+            } else {
+                value.push_str(&line);
+                value.push('\n');
+            }
+        }
+
+        let [_, blue, red] = wins;
+
+        let _ = write!(
+            value,
+            "Picks won: :blue_circle: `{blue}` - `{red}` :red_circle:"
+        );
+
+        Some(EmbedField {
+            name: "Picks & bans".to_owned(),
+            value,
+            inline: false,
+        })
+    }
+}
+
+fn team_emote(team: MatchTeam) -> &'static str {
+    match team {
+        MatchTeam::Blue => ":blue_circle:",
+        MatchTeam::Red => ":red_circle:",
+        MatchTeam::None => ":white_circle:",
+    }
+}
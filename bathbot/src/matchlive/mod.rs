@@ -2,32 +2,48 @@ use std::{slice, time::Duration};
 
 use eyre::{Context as EyreContext, Result};
 use tokio::time::{MissedTickBehavior, interval};
-use twilight_model::id::{
-    Id,
-    marker::{ChannelMarker, MessageMarker},
+use twilight_model::{
+    channel::message::embed::EmbedField,
+    id::{
+        Id,
+        marker::{ChannelMarker, MessageMarker},
+    },
 };
 
-pub use self::types::*;
+pub use self::{
+    bracket::{Bracket, BracketEntry, BracketKind},
+    summary::MatchSummary,
+    types::*,
+    win_probability::WinProbability,
+};
 use crate::{core::Context, embeds::MatchLiveEmbed};
 
+mod bracket;
+mod summary;
 mod types;
+mod win_probability;
 
 const EMBED_LIMIT: usize = 10;
 
 /// Sends a message to the channel for each embed
-/// and returns the last of these messages
+/// and returns the last of these messages.
+///
+/// The bracket field is only added to the last embed.
 pub async fn send_match_messages(
     channel: Id<ChannelMarker>,
     embeds: &[MatchLiveEmbed],
+    bracket: Option<EmbedField>,
 ) -> Result<Id<MessageMarker>> {
     let mut iter = embeds.iter();
 
     // Msg of last embed will be stored, do it separately
-    let last = iter
+    let mut last = iter
         .next_back()
         .expect("no embed on fresh match")
         .as_embed();
 
+    last.fields.extend(bracket);
+
     let http = Context::http();
 
     let mut last_msg_fut = http.create_message(channel).embeds(slice::from_ref(&last));
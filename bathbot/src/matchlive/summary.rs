@@ -0,0 +1,132 @@
+use std::{borrow::Cow, cmp::Ordering, collections::HashMap};
+
+use bathbot_util::{CowUtils, IntHasher};
+use rosu_v2::prelude::{MatchEvent, MatchGame, OsuMatch, User, Username};
+
+/// Stats accumulated over all finished games of a tracked match.
+#[derive(Default)]
+pub struct MatchSummary {
+    pub players: HashMap<u32, PlayerSummary, IntHasher>,
+    /// Summed up scores of the blue and red team at index 1 and 2
+    pub team_totals: [u64; 3],
+    /// Amount of games won by the blue and red team at index 1 and 2
+    pub team_wins: [u32; 3],
+    /// Id of the latest game that was accumulated
+    last_game: Option<u64>,
+}
+
+#[derive(Default)]
+pub struct PlayerSummary {
+    /// Remembered since later lobby updates only contain recently active users
+    username: Option<Username>,
+    pub games: u32,
+    pub total_score: u64,
+    pub total_acc: f32,
+    pub best: Option<BestGame>,
+}
+
+pub struct BestGame {
+    pub score: u32,
+    pub map: String,
+}
+
+impl PlayerSummary {
+    pub fn username(&self, user_id: u32) -> Cow<'_, str> {
+        match self.username {
+            Some(ref name) => Cow::Borrowed(name.as_str()),
+            None => format!("User id {user_id}").into(),
+        }
+    }
+
+    pub fn avg_score(&self) -> u64 {
+        self.total_score / u64::from(self.games.max(1))
+    }
+
+    pub fn avg_acc(&self) -> f32 {
+        self.total_acc / self.games.max(1) as f32
+    }
+}
+
+impl MatchSummary {
+    /// Accumulate all finished games of the lobby that were not yet considered.
+    pub fn add_games(&mut self, lobby: &OsuMatch) {
+        for event in lobby.events.iter() {
+            let MatchEvent::Game { game, .. } = event else {
+                continue;
+            };
+
+            if game.end_time.is_none() || self.last_game.is_some_and(|id| id >= game.game_id) {
+                continue;
+            }
+
+            self.last_game = Some(game.game_id);
+            self.add_game(game, &lobby.users);
+        }
+    }
+
+    fn add_game(&mut self, game: &MatchGame, users: &HashMap<u32, User>) {
+        let map = match game.map {
+            Some(ref map) => match map.mapset {
+                Some(ref mapset) => format!(
+                    "{} - {} [{}]",
+                    mapset.artist.cow_escape_markdown(),
+                    mapset.title.cow_escape_markdown(),
+                    map.version.cow_escape_markdown()
+                ),
+                None => format!("[{}]", map.version.cow_escape_markdown()),
+            },
+            None => "Unknown map".to_owned(),
+        };
+
+        let mut game_totals = [0_u64; 3];
+
+        for score in game.scores.iter().filter(|score| score.score > 0) {
+            let player = self.players.entry(score.user_id).or_default();
+
+            if player.username.is_none() {
+                player.username = users.get(&score.user_id).map(|user| user.username.clone());
+            }
+
+            player.games += 1;
+            player.total_score += u64::from(score.score);
+            player.total_acc += score.accuracy;
+
+            if player
+                .best
+                .as_ref()
+                .is_none_or(|best| best.score < score.score)
+            {
+                player.best = Some(BestGame {
+                    score: score.score,
+                    map: map.clone(),
+                });
+            }
+
+            game_totals[score.info.team as usize] += u64::from(score.score);
+        }
+
+        let [_, blue, red] = game_totals;
+
+        match blue.cmp(&red) {
+            Ordering::Greater => self.team_wins[1] += 1,
+            Ordering::Less => self.team_wins[2] += 1,
+            Ordering::Equal => {}
+        }
+
+        for (total, game_total) in self.team_totals.iter_mut().zip(game_totals) {
+            *total += game_total;
+        }
+    }
+
+    /// The player with the highest aggregate score.
+    pub fn mvp(&self) -> Option<(u32, &PlayerSummary)> {
+        self.players
+            .iter()
+            .max_by_key(|(_, player)| player.total_score)
+            .map(|(user_id, player)| (*user_id, player))
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.players.is_empty()
+    }
+}
@@ -9,6 +9,7 @@ use twilight_model::id::{
     marker::{ChannelMarker, MessageMarker},
 };
 
+use super::{Bracket, MatchSummary, WinProbability};
 use crate::embeds::{MatchLiveEmbed, MatchLiveEmbeds};
 
 pub struct MatchLiveChannels {
@@ -33,6 +34,8 @@ pub struct MatchLiveChannelsInner {
     pub channel_count: HashMap<Id<ChannelMarker>, u8, IntHasher>,
 }
 
+/// A match is requested only once per update, regardless of how many channels
+/// track it; the resulting embeds are then sent to all of them.
 pub struct MatchEntry {
     pub tracked: TrackedMatch,
     // Not a set since the list is expected to be very short and thus cheap to iterate over.
@@ -53,11 +56,13 @@ pub struct Channel {
     pub id: Id<ChannelMarker>,
     /// Last msg in the channel
     pub msg_id: Id<MessageMarker>,
+    /// Whether the channel is a thread that was created for the match
+    pub thread: bool,
 }
 
 impl Channel {
-    pub fn new(id: Id<ChannelMarker>, msg_id: Id<MessageMarker>) -> Self {
-        Self { id, msg_id }
+    pub fn new(id: Id<ChannelMarker>, msg_id: Id<MessageMarker>, thread: bool) -> Self {
+        Self { id, msg_id, thread }
     }
 }
 
@@ -76,18 +81,43 @@ pub enum MatchTrackResult {
     Private,
 }
 
+pub enum MatchAnnotateResult {
+    /// The pick or ban was added to the match's bracket
+    Annotated,
+    /// The map was already picked or banned in the match
+    Duplicate,
+    /// The match is not tracked in the channel
+    NotTracked,
+}
+
 pub struct TrackedMatch {
     /// Most recent update of the match
     pub osu_match: OsuMatch,
     /// All embeds of the match
     pub embeds: Vec<MatchLiveEmbed>,
+    /// Estimates which team wins the current game
+    pub win_probability: WinProbability,
+    /// Stats of all finished games
+    pub summary: MatchSummary,
+    /// Announced picks and bans
+    pub bracket: Bracket,
 }
 
 impl TrackedMatch {
-    pub fn new(osu_match: OsuMatch, embeds: MatchLiveEmbeds) -> Self {
+    pub fn new(
+        osu_match: OsuMatch,
+        embeds: MatchLiveEmbeds,
+        win_probability: WinProbability,
+    ) -> Self {
+        let mut summary = MatchSummary::default();
+        summary.add_games(&osu_match);
+
         Self {
             osu_match,
             embeds: embeds.into_vec(),
+            win_probability,
+            summary,
+            bracket: Bracket::default(),
         }
     }
 }
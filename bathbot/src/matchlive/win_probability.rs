@@ -0,0 +1,149 @@
+//! Rough estimate of which team is going to win a game of a tracked match.
+//!
+//! A team's strength is the average pp of its players. The log-ratio of both
+//! strengths is scaled by the map's star rating, since harder maps tend to
+//! amplify skill differences, and then put through a logistic function. After
+//! every finished game, the estimate is nudged towards the team that won it,
+//! similar to an Elo update, so that the form of the day is considered too.
+
+use std::{
+    cmp::Ordering,
+    collections::{HashMap, hash_map::Entry},
+};
+
+use bathbot_util::IntHasher;
+use rosu_v2::prelude::{GameMode, MatchEvent, MatchGame, OsuMatch, TeamType};
+
+use crate::{core::Context, manager::redis::osu::UserArgs};
+
+/// How strongly the pp difference between teams affects the probability.
+const PP_WEIGHT: f64 = 0.6;
+
+/// How far the estimate moves after a finished game.
+const RESULT_WEIGHT: f64 = 0.5;
+
+/// Star rating to use if the map is unknown.
+const DEFAULT_STARS: f64 = 5.0;
+
+#[derive(Default)]
+pub struct WinProbability {
+    /// Pp of players in a mode; `None` if the user could not be retrieved
+    pp: HashMap<(u32, GameMode), Option<f32>>,
+    /// Team of players in their most recent game; 1 for blue, 2 for red
+    teams: HashMap<u32, usize, IntHasher>,
+    /// Accumulated log-odds shift towards the blue team
+    shift: f64,
+    /// Id of the latest finished game whose result was considered
+    last_game: Option<u64>,
+}
+
+impl WinProbability {
+    /// Retrieve the pp of all lobby users that are not yet known.
+    pub async fn fetch_pp(&mut self, lobby: &OsuMatch) {
+        let mut modes = Vec::new();
+
+        let game_modes = lobby.events.iter().filter_map(|event| match event {
+            MatchEvent::Game { game, .. } => Some(game.mode),
+            _ => None,
+        });
+
+        for mode in game_modes {
+            if !modes.contains(&mode) {
+                modes.push(mode);
+            }
+        }
+
+        for mode in modes {
+            for &user_id in lobby.users.keys() {
+                let Entry::Vacant(e) = self.pp.entry((user_id, mode)) else {
+                    continue;
+                };
+
+                let pp = match Context::redis()
+                    .osu_user(UserArgs::user_id(user_id, mode))
+                    .await
+                {
+                    Ok(user) => user.statistics.as_ref().map(|stats| stats.pp.to_native()),
+                    Err(err) => {
+                        warn!(?err, user_id, "Failed to get user for win probability");
+
+                        None
+                    }
+                };
+
+                e.insert(pp);
+            }
+        }
+    }
+
+    /// Probability for the blue team to win the game.
+    ///
+    /// Returns `None` if the game is not played in teams or the pp of either
+    /// team is unknown.
+    pub fn blue_chance(&mut self, game: &MatchGame) -> Option<f64> {
+        if !matches!(game.team_type, TeamType::TeamVS | TeamType::TagTeamVS) {
+            return None;
+        }
+
+        for score in game.scores.iter() {
+            let team = score.info.team as usize;
+
+            if team > 0 {
+                self.teams.insert(score.user_id, team);
+            }
+        }
+
+        let mut sums = [(0.0, 0); 3];
+
+        for (user_id, &team) in self.teams.iter() {
+            if let Some(Some(pp)) = self.pp.get(&(*user_id, game.mode)) {
+                sums[team].0 += f64::from(*pp);
+                sums[team].1 += 1;
+            }
+        }
+
+        let [_, (blue_sum, blue_count), (red_sum, red_count)] = sums;
+
+        if blue_count == 0 || red_count == 0 || blue_sum <= 0.0 || red_sum <= 0.0 {
+            return None;
+        }
+
+        let blue = blue_sum / blue_count as f64;
+        let red = red_sum / red_count as f64;
+
+        let stars = game
+            .map
+            .as_ref()
+            .map_or(DEFAULT_STARS, |map| f64::from(map.stars));
+
+        let log_odds = (blue / red).ln() * stars * PP_WEIGHT + self.shift;
+        let chance = logistic(log_odds);
+
+        if game.end_time.is_some() && self.last_game.is_none_or(|id| id < game.game_id) {
+            self.last_game = Some(game.game_id);
+
+            let [_, blue_score, red_score] =
+                game.scores.iter().fold([0_u64; 3], |mut totals, score| {
+                    totals[score.info.team as usize] += u64::from(score.score);
+
+                    totals
+                });
+
+            let result = match blue_score.cmp(&red_score) {
+                Ordering::Greater => 1.0,
+                Ordering::Less => 0.0,
+                Ordering::Equal => 0.5,
+            };
+
+            if blue_score > 0 || red_score > 0 {
+                self.shift += RESULT_WEIGHT * (result - chance);
+            }
+        }
+
+        Some(chance)
+    }
+}
+
+fn logistic(x: f64) -> f64 {
+    1.0 / (1.0 + (-x).exp())
+}
@@ -1,3 +1,5 @@
+#[cfg(feature = "server")]
+pub use self::score_webhook::score_webhook_loop;
 #[cfg(feature = "twitch")]
 pub use self::twitch::online_streams::OnlineTwitchStreams;
 #[cfg(feature = "twitchtracking")]
@@ -5,12 +7,16 @@ pub use self::twitch::twitch_loop::twitch_tracking_loop;
 pub use self::{
     ordr::{Ordr, OrdrReceivers},
     osu::{OsuTracking, TrackEntryParams},
+    rank_snapshots::rank_snapshot_loop,
     scores_ws::{ScoresWebSocket, ScoresWebSocketDisconnect},
 };
 
 mod ordr;
 mod osu;
+mod rank_snapshots;
 mod scores_ws;
 
+#[cfg(feature = "server")]
+mod score_webhook;
 #[cfg(feature = "twitch")]
 mod twitch;
@@ -5,7 +5,10 @@ macro_rules! log {
     };
 }
 
-use std::{collections::HashMap, sync::RwLock};
+use std::{
+    collections::{HashMap, HashSet},
+    sync::RwLock,
+};
 
 use bathbot_psql::Database;
 use bathbot_util::{IntHasher, datetime::NAIVE_DATETIME_FORMAT};
@@ -15,7 +18,7 @@ use twilight_model::id::{Id, marker::ChannelMarker};
 
 use self::{entry::TrackedUser, require_top::RequireTopScores};
 pub use self::{params::TrackEntryParams, stats::OsuTrackingStats};
-use crate::core::Context;
+use crate::core::{Context, with_background_osu};
 
 mod entry;
 mod params;
@@ -53,6 +56,48 @@ impl OsuTracking {
         OsuTrackingStats::new()
     }
 
+    /// Returns all users and modes that are tracked in at least one channel.
+    pub fn tracked_users() -> Vec<(u32, GameMode)> {
+        const MODES: [GameMode; 4] = [
+            GameMode::Osu,
+            GameMode::Taiko,
+            GameMode::Catch,
+            GameMode::Mania,
+        ];
+
+        Self::users()
+            .read()
+            .unwrap()
+            .iter()
+            .flat_map(|(&user_id, user)| {
+                MODES
+                    .into_iter()
+                    .filter(|&mode| user.try_get(mode).is_some())
+                    .map(move |mode| (user_id, mode))
+            })
+            .collect()
+    }
+
+    /// Returns all channels in which at least one user is tracked.
+    pub fn channels() -> Vec<Id<ChannelMarker>> {
+        const MODES: [GameMode; 4] = [
+            GameMode::Osu,
+            GameMode::Taiko,
+            GameMode::Catch,
+            GameMode::Mania,
+        ];
+
+        let mut channels = HashSet::with_hasher(IntHasher);
+
+        for user in Self::users().read().unwrap().values() {
+            for mode in MODES {
+                channels.extend(user.get(mode).channels().keys().copied());
+            }
+        }
+
+        channels.into_iter().map(Id::from).collect()
+    }
+
     fn users() -> &'static TrackedUsers {
         &Context::tracking().users
     }
@@ -85,7 +130,8 @@ impl OsuTracking {
             return;
         }
 
-        tokio::spawn(process_score::process_score(score, entry));
+        let process_fut = process_score::process_score(score, entry);
+        tokio::spawn(with_background_osu(process_fut));
     }
 
     pub async fn remove_channel(channel: Id<ChannelMarker>, mode: Option<GameMode>) {
@@ -0,0 +1,66 @@
+use std::time::Duration;
+
+use time::{OffsetDateTime, Time};
+use tokio::time::{interval, sleep};
+
+use crate::{core::Context, manager::redis::osu::UserArgsSlim, tracking::OsuTracking};
+
+/// Once a day, store the current global rank of all tracked users so that
+/// rank graphs can go further back than the osu!api's 90 days.
+pub async fn rank_snapshot_loop() {
+    // Start at the beginning of the next UTC day
+    let now = OffsetDateTime::now_utc();
+    let tomorrow = now
+        .date()
+        .next_day()
+        .unwrap_or(now.date())
+        .with_time(Time::MIDNIGHT);
+    let until_tomorrow = (tomorrow.assume_utc() - now).unsigned_abs();
+    sleep(until_tomorrow).await;
+
+    let mut interval = interval(Duration::from_secs(60 * 60 * 24));
+
+    loop {
+        interval.tick().await;
+        store_snapshots().await;
+    }
+}
+
+async fn store_snapshots() {
+    let users = OsuTracking::tracked_users();
+    let date = OffsetDateTime::now_utc().date();
+    let mut stored = 0;
+
+    info!(users = users.len(), "Storing rank snapshots...");
+
+    for (user_id, mode) in users {
+        let user_args = UserArgsSlim::user_id(user_id).mode(mode);
+
+        let user = match Context::redis().osu_user_from_args(user_args).await {
+            Ok(user) => user,
+            Err(err) => {
+                warn!(user_id, ?mode, ?err, "Failed to get user for rank snapshot");
+
+                continue;
+            }
+        };
+
+        let Some(global_rank) = user
+            .statistics
+            .as_ref()
+            .map(|stats| stats.global_rank.to_native())
+            .filter(|&rank| rank > 0)
+        else {
+            continue;
+        };
+
+        let upsert_fut = Context::psql().upsert_rank_snapshot(user_id, mode, date, global_rank);
+
+        match upsert_fut.await {
+            Ok(_) => stored += 1,
+            Err(err) => error!(user_id, ?mode, ?err, "Failed to store rank snapshot"),
+        }
+    }
+
+    info!(stored, "Finished storing rank snapshots");
+}
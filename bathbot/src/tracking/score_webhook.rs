@@ -0,0 +1,39 @@
+use std::collections::{HashSet, VecDeque};
+
+use bathbot_util::IntHasher;
+use rosu_v2::prelude::Score;
+use tokio::sync::mpsc::UnboundedReceiver;
+
+use crate::tracking::OsuTracking;
+
+/// How many of the most recent score ids are remembered to skip duplicates
+const SEEN_CAPACITY: usize = 10_000;
+
+/// Pass scores that were received through the server's score webhook on to
+/// the tracking, the same way as scores of the scores websocket.
+///
+/// Scores that were already received recently are skipped so that repeated
+/// deliveries are not tracked multiple times.
+pub async fn score_webhook_loop(mut rx: UnboundedReceiver<Score>) {
+    let mut seen = HashSet::with_capacity_and_hasher(SEEN_CAPACITY, IntHasher);
+    let mut order = VecDeque::with_capacity(SEEN_CAPACITY + 1);
+
+    while let Some(score) = rx.recv().await {
+        if !seen.insert(score.id) {
+            continue;
+        }
+
+        order.push_back(score.id);
+        OsuTracking::process_score(score);
+
+        if order.len() <= SEEN_CAPACITY {
+            continue;
+        }
+
+        if let Some(oldest) = order.pop_front() {
+            seen.remove(&oldest);
+        }
+    }
+
+    info!("Score webhook channel closed");
+}
@@ -1,16 +1,57 @@
 use std::{
     fmt::{Display, Formatter, Result as FmtResult},
+    future::Future,
     str::FromStr,
 };
 
-use rosu_v2::prelude::GameMode;
+use rosu_v2::prelude::{GameMode, Grade};
 use twilight_model::{
     channel::message::EmojiReactionType,
+    guild::Permissions,
     id::{Id, marker::EmojiMarker},
 };
 
 use crate::core::BotConfig;
 
+tokio::task_local! {
+    /// Whether emotes are rendered as text for the currently processed event.
+    static TEXT_EMOTES: bool;
+}
+
+/// Process an event such that all emotes rendered along the way are replaced
+/// by text if the bot is not allowed to use external emojis.
+///
+/// Permissions of `None` e.g. in DMs are considered to allow emojis.
+pub async fn with_emote_permissions<F: Future>(
+    permissions: Option<Permissions>,
+    fut: F,
+) -> F::Output {
+    let text = permissions
+        .is_some_and(|permissions| !permissions.contains(Permissions::USE_EXTERNAL_EMOJIS));
+
+    TEXT_EMOTES.scope(text, fut).await
+}
+
+/// Whether emotes should be replaced by text codes.
+pub fn text_emotes() -> bool {
+    TEXT_EMOTES.try_with(|text| *text).unwrap_or(false)
+}
+
+/// Text replacement for a grade emote.
+pub fn grade_text(grade: Grade) -> &'static str {
+    match grade {
+        Grade::F => "F",
+        Grade::D => "D",
+        Grade::C => "C",
+        Grade::B => "B",
+        Grade::A => "A",
+        Grade::S => "S",
+        Grade::SH => "SH",
+        Grade::X => "SS",
+        Grade::XH => "SSH",
+    }
+}
+
 #[derive(Copy, Clone, Eq, PartialEq, Debug, Hash)]
 #[repr(u8)]
 pub enum Emote {
@@ -52,6 +93,28 @@ impl Emote {
 
         format!("https://cdn.discordapp.com/emojis/{id}.png")
     }
+
+    /// Text replacement in case emotes can't be used.
+    fn text(self) -> &'static str {
+        match self {
+            Self::Std | Self::Osu => "[osu!]",
+            Self::Tko => "[taiko]",
+            Self::Ctb => "[ctb]",
+            Self::Mna => "[mania]",
+            Self::Twitch => "[twitch]",
+            Self::Tracking => "[tracking]",
+            Self::JumpStart => "<<",
+            Self::SingleStepBack => "<",
+            Self::MyPosition => "[me]",
+            Self::SingleStep => ">",
+            Self::JumpEnd => ">>",
+            Self::Miss => "x",
+            Self::Bpm => "BPM",
+            Self::CountObjects => "objects",
+            Self::CountSliders => "sliders",
+            Self::CountSpinners => "spinners",
+        }
+    }
 }
 
 impl From<GameMode> for Emote {
@@ -96,6 +159,10 @@ impl FromStr for Emote {
 
 impl Display for Emote {
     fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        if text_emotes() {
+            return f.write_str(self.text());
+        }
+
         let CustomEmote { id, name } = BotConfig::get().emote(*self);
 
         write!(f, "<:{name}:{id}>")
@@ -2,7 +2,7 @@ use std::{cmp::Ordering, fmt::Write};
 
 use bathbot_model::{
     rkyv_util::time::ArchivedDateTime,
-    rosu_v2::user::{ArchivedUser, ArchivedUserHighestRank},
+    rosu_v2::user::{ArchivedUser, ArchivedUserHighestRank, Playstyles},
 };
 use bathbot_util::{AuthorBuilder, constants::OSU_BASE, numbers::WithComma, osu::flag_url};
 use rkyv::{munge::munge, niche::niched_option::NichedOption};
@@ -10,13 +10,27 @@ use rkyv::{munge::munge, niche::niched_option::NichedOption};
 use crate::manager::redis::osu::CachedUser;
 
 pub trait CachedUserExt {
-    fn author_builder(&self, with_rank_change: bool) -> AuthorBuilder;
+    fn author_builder(&self, with_rank_change: bool) -> AuthorBuilder {
+        self.author_builder_with_badges(with_rank_change, false)
+    }
+
+    /// Same as [`CachedUserExt::author_builder`] but optionally appends the
+    /// supporter level and playstyles.
+    fn author_builder_with_badges(
+        &self,
+        with_rank_change: bool,
+        with_badges: bool,
+    ) -> AuthorBuilder;
     fn rank_change_since_30_days(&self) -> Option<i32>;
     fn update(&mut self, user: rosu_v2::model::user::User);
 }
 
 impl CachedUserExt for CachedUser {
-    fn author_builder(&self, with_rank_change: bool) -> AuthorBuilder {
+    fn author_builder_with_badges(
+        &self,
+        with_rank_change: bool,
+        with_badges: bool,
+    ) -> AuthorBuilder {
         let stats = self.statistics.as_ref().expect("missing stats");
         let country_code = self.country_code.as_str();
 
@@ -51,6 +65,33 @@ impl CachedUserExt for CachedUser {
             national = stats.country_rank,
         );
 
+        if with_badges {
+            if self.support_level > 0 {
+                text.push(' ');
+
+                for _ in 0..self.support_level {
+                    text.push('❤');
+                }
+            }
+
+            let playstyles = Playstyles::from_bits_truncate(self.playstyles);
+
+            if !playstyles.is_empty() {
+                text.push(' ');
+
+                for (playstyle, emoji) in [
+                    (Playstyles::MOUSE, "🖱"),
+                    (Playstyles::KEYBOARD, "⌨"),
+                    (Playstyles::TABLET, "✏"),
+                    (Playstyles::TOUCH, "👆"),
+                ] {
+                    if playstyles.contains(playstyle) {
+                        text.push_str(emoji);
+                    }
+                }
+            }
+        }
+
         let url = format!("{OSU_BASE}users/{}/{}", self.user_id, self.mode);
         let icon = flag_url(country_code);
 
@@ -83,6 +124,7 @@ impl CachedUserExt for CachedUser {
                 pending_mapset_count: pending_mapset_count_seal,
                 statistics: statistics_seal,
                 avatar_url: _,
+                cover_url: _,
                 country_code: _,
                 join_date: _,
                 kudosu: _,
@@ -97,6 +139,8 @@ impl CachedUserExt for CachedUser {
                 medals: _,
                 daily_challenge: _,
                 team: _,
+                support_level: _,
+                playstyles: _,
             } = seal);
 
             if let Some(last_visit) = user.last_visit {
@@ -1,4 +1,4 @@
-use std::{future::IntoFuture, slice};
+use std::future::IntoFuture;
 
 use bathbot_util::{EmbedBuilder, MessageBuilder};
 use twilight_http::response::ResponseFuture;
@@ -47,12 +47,14 @@ impl ChannelExt for Id<ChannelMarker> {
             req = req.components(components);
         }
 
-        match builder.attachment.as_ref().filter(|_| {
-            permissions.is_none_or(|permissions| permissions.contains(Permissions::ATTACH_FILES))
-        }) {
-            Some(attachment) => req.attachments(slice::from_ref(attachment)).into_future(),
-            None => req.into_future(),
+        let can_attach =
+            permissions.is_none_or(|permissions| permissions.contains(Permissions::ATTACH_FILES));
+
+        if can_attach && !builder.attachments.is_empty() {
+            req = req.attachments(&builder.attachments);
         }
+
+        req.into_future()
     }
 
     fn error(&self, content: impl Into<String>) -> ResponseFuture<Message> {
@@ -1,9 +1,9 @@
-use std::{borrow::Cow, future::IntoFuture, slice};
+use std::{borrow::Cow, future::IntoFuture};
 
 use bathbot_util::{MessageBuilder, modal::ModalBuilder};
 use twilight_http::response::{ResponseFuture, marker::EmptyBody};
 use twilight_model::{
-    channel::Message,
+    channel::{Message, message::MessageFlags},
     guild::Permissions,
     http::interaction::{InteractionResponse, InteractionResponseData, InteractionResponseType},
 };
@@ -24,17 +24,20 @@ pub trait ComponentExt {
 
     /// Acknowledge a component by responding with a modal.
     fn modal(&self, modal: ModalBuilder) -> ResponseFuture<EmptyBody>;
+
+    /// Acknowledge a component by responding with a new ephemeral message
+    /// instead of updating the component's message.
+    fn ephemeral(&self, builder: MessageBuilder<'_>) -> ResponseFuture<EmptyBody>;
 }
 
 impl ComponentExt for InteractionComponent {
     fn callback(&self, builder: MessageBuilder<'_>) -> ResponseFuture<EmptyBody> {
-        let attachments = builder
-            .attachment
-            .filter(|_| {
-                self.permissions
+        let attachments = Some(builder.attachments).filter(|attachments| {
+            !attachments.is_empty()
+                && self
+                    .permissions
                     .is_none_or(|permissions| permissions.contains(Permissions::ATTACH_FILES))
-            })
-            .map(|attachment| vec![attachment]);
+        });
 
         let data = InteractionResponseData {
             components: builder.components,
@@ -81,11 +84,12 @@ impl ComponentExt for InteractionComponent {
             req = req.components(Some(components));
         }
 
-        if let Some(attachment) = builder.attachment.as_ref().filter(|_| {
-            self.permissions
-                .is_none_or(|permissions| permissions.contains(Permissions::ATTACH_FILES))
-        }) {
-            req = req.attachments(slice::from_ref(attachment));
+        let can_attach = self
+            .permissions
+            .is_none_or(|permissions| permissions.contains(Permissions::ATTACH_FILES));
+
+        if can_attach && !builder.attachments.is_empty() {
+            req = req.attachments(&builder.attachments);
         }
 
         req.into_future()
@@ -101,4 +105,23 @@ impl ComponentExt for InteractionComponent {
             .create_response(self.id, &self.token, &response)
             .into_future()
     }
+
+    fn ephemeral(&self, builder: MessageBuilder<'_>) -> ResponseFuture<EmptyBody> {
+        let data = InteractionResponseData {
+            components: builder.components,
+            embeds: builder.embed.into(),
+            content: builder.content.map(Cow::into_owned),
+            flags: Some(MessageFlags::EPHEMERAL),
+            ..Default::default()
+        };
+
+        let response = InteractionResponse {
+            kind: InteractionResponseType::ChannelMessageWithSource,
+            data: Some(data),
+        };
+
+        Context::interaction()
+            .create_response(self.id, &self.token, &response)
+            .into_future()
+    }
 }
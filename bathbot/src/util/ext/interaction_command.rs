@@ -1,4 +1,4 @@
-use std::{borrow::Cow, future::IntoFuture, mem, slice};
+use std::{borrow::Cow, future::IntoFuture, mem};
 
 use bathbot_util::{EmbedBuilder, MessageBuilder};
 use twilight_http::response::{ResponseFuture, marker::EmptyBody};
@@ -101,10 +101,8 @@ impl InteractionCommandExt for InteractionCommand {
     }
 
     fn callback(&self, builder: MessageBuilder<'_>, ephemeral: bool) -> ResponseFuture<EmptyBody> {
-        let attachments = builder
-            .attachment
-            .filter(|_| self.can_attach_file())
-            .map(|attachment| vec![attachment]);
+        let attachments = Some(builder.attachments)
+            .filter(|attachments| !attachments.is_empty() && self.can_attach_file());
 
         let data = InteractionResponseData {
             components: builder.components,
@@ -192,10 +190,11 @@ impl InteractionToken<'_> {
             req = req.components(components);
         }
 
-        if let Some(attachment) = builder.attachment.as_ref().filter(|_| {
-            permissions.is_none_or(|permissions| permissions.contains(Permissions::ATTACH_FILES))
-        }) {
-            req = req.attachments(slice::from_ref(attachment));
+        let can_attach =
+            permissions.is_none_or(|permissions| permissions.contains(Permissions::ATTACH_FILES));
+
+        if can_attach && !builder.attachments.is_empty() {
+            req = req.attachments(&builder.attachments);
         }
 
         req.into_future()
@@ -224,10 +223,11 @@ impl InteractionToken<'_> {
             req = req.components(Some(components));
         }
 
-        if let Some(attachment) = builder.attachment.as_ref().filter(|_| {
-            permissions.is_none_or(|permissions| permissions.contains(Permissions::ATTACH_FILES))
-        }) {
-            req = req.attachments(slice::from_ref(attachment));
+        let can_attach =
+            permissions.is_none_or(|permissions| permissions.contains(Permissions::ATTACH_FILES));
+
+        if can_attach && !builder.attachments.is_empty() {
+            req = req.attachments(&builder.attachments);
         }
 
         req.into_future()
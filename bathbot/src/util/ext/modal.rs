@@ -1,4 +1,4 @@
-use std::{future::IntoFuture, slice};
+use std::future::IntoFuture;
 
 use bathbot_util::MessageBuilder;
 use twilight_http::response::{ResponseFuture, marker::EmptyBody};
@@ -25,13 +25,12 @@ pub trait ModalExt {
 
 impl ModalExt for InteractionModal {
     fn callback(&self, builder: MessageBuilder<'_>) -> ResponseFuture<EmptyBody> {
-        let attachments = builder
-            .attachment
-            .filter(|_| {
-                self.permissions
+        let attachments = Some(builder.attachments).filter(|attachments| {
+            !attachments.is_empty()
+                && self
+                    .permissions
                     .is_none_or(|permissions| permissions.contains(Permissions::ATTACH_FILES))
-            })
-            .map(|attachment| vec![attachment]);
+        });
 
         let data = InteractionResponseData {
             components: builder.components,
@@ -77,11 +76,12 @@ impl ModalExt for InteractionModal {
             req = req.components(Some(components));
         }
 
-        if let Some(attachment) = builder.attachment.as_ref().filter(|_| {
-            self.permissions
-                .is_none_or(|permissions| permissions.contains(Permissions::ATTACH_FILES))
-        }) {
-            req = req.attachments(slice::from_ref(attachment));
+        let can_attach = self
+            .permissions
+            .is_none_or(|permissions| permissions.contains(Permissions::ATTACH_FILES));
+
+        if can_attach && !builder.attachments.is_empty() {
+            req = req.attachments(&builder.attachments);
         }
 
         req.into_future()
@@ -1,16 +1,21 @@
 pub use self::{
     check_permissions::CheckPermissions,
-    emote::{CustomEmote, Emote},
+    emote::{CustomEmote, Emote, grade_text, text_emotes, with_emote_permissions},
     ext::*,
     monthly::Monthly,
+    partial::PartialResult,
+    progress::ProgressReporter,
     searchable::NativeCriteria,
 };
 
 pub mod interaction;
 pub mod osu;
+pub mod score_v2;
 
 mod check_permissions;
 mod emote;
 mod ext;
 mod monthly;
+mod partial;
+mod progress;
 mod searchable;
@@ -14,7 +14,8 @@ use bathbot_util::{
     datetime::SecToMinSec,
     matcher,
     numbers::{WithComma, round},
-    osu::MapIdType,
+    osu::{MapIdType, effective_clock_rate},
+    units::Stars,
 };
 use eyre::{Result, WrapErr};
 use futures::{StreamExt, stream::FuturesOrdered};
@@ -27,7 +28,7 @@ use rosu_pp::{
 };
 use rosu_v2::{
     model::mods::GameMods,
-    prelude::{GameModIntermode, GameMode, Grade, ScoreStatistics},
+    prelude::{GameMod, GameModIntermode, GameMode, Grade, ScoreStatistics},
 };
 use time::OffsetDateTime;
 use twilight_model::channel::{Message, message::MessageType};
@@ -35,12 +36,53 @@ use twilight_model::channel::{Message, message::MessageType};
 use crate::{
     core::{BotConfig, Context},
     manager::{OsuMap, redis::osu::CachedUser},
+    util::{grade_text, text_emotes},
 };
 
 pub fn grade_emote(grade: Grade) -> &'static str {
+    if text_emotes() {
+        return grade_text(grade);
+    }
+
     BotConfig::get().grade(grade)
 }
 
+pub fn score_multiplier(mods: &GameMods) -> f32 {
+    mods.iter().map(mod_multiplier).product()
+}
+
+pub fn mod_multiplier(gamemod: &GameMod) -> f32 {
+    match gamemod {
+        GameMod::HalfTimeOsu(_) | GameMod::HalfTimeTaiko(_) | GameMod::HalfTimeCatch(_) => 0.3,
+        GameMod::EasyOsu(_)
+        | GameMod::NoFailOsu(_)
+        | GameMod::EasyTaiko(_)
+        | GameMod::NoFailTaiko(_)
+        | GameMod::EasyCatch(_)
+        | GameMod::NoFailCatch(_)
+        | GameMod::EasyMania(_)
+        | GameMod::NoFailMania(_)
+        | GameMod::HalfTimeMania(_) => 0.5,
+        GameMod::SpunOutOsu(_) => 0.9,
+        GameMod::HardRockOsu(_)
+        | GameMod::HiddenOsu(_)
+        | GameMod::HardRockTaiko(_)
+        | GameMod::HiddenTaiko(_)
+        | GameMod::DoubleTimeCatch(_)
+        | GameMod::NightcoreCatch(_)
+        | GameMod::HiddenCatch(_) => 1.06,
+        GameMod::DoubleTimeOsu(_)
+        | GameMod::NightcoreOsu(_)
+        | GameMod::FlashlightOsu(_)
+        | GameMod::DoubleTimeTaiko(_)
+        | GameMod::NightcoreTaiko(_)
+        | GameMod::FlashlightTaiko(_)
+        | GameMod::HardRockCatch(_)
+        | GameMod::FlashlightCatch(_) => 1.12,
+        _ => 1.0,
+    }
+}
+
 pub struct GradeCompletionFormatter<'a> {
     mods: &'a GameMods,
     grade: Grade,
@@ -733,10 +775,16 @@ impl Display for MapInfo<'_> {
             builder = builder.clock_rate(clock_rate);
         }
 
+        // Variable rate mods only affect the displayed length and bpm, not
+        // the attributes
+        let display_clock_rate = self
+            .clock_rate
+            .unwrap_or_else(|| effective_clock_rate(&mods));
+
         let mods_bits = mods.bits();
         let attrs = builder.mods(mods).build();
 
-        let clock_rate = attrs.clock_rate;
+        let clock_rate = display_clock_rate;
         let mut sec_drain = self.map.seconds_drain();
         let mut bpm = self.map.bpm();
 
@@ -763,8 +811,23 @@ impl Display for MapInfo<'_> {
             ar = round(attrs.ar as f32),
             od = round(attrs.od as f32),
             hp = round(attrs.hp as f32),
-            stars = round(self.stars),
-        )
+            stars = Stars(self.stars),
+        )?;
+
+        // The objects of converts differ from the original map so their
+        // values are not obvious
+        if self.map.mode() == GameMode::Mania && self.map.pp_map.is_convert {
+            let notes = self.map.pp_map.hit_objects.len();
+            let ln_percent = self.map.ln_percent();
+
+            write!(
+                f,
+                "\nConvert: `{cs_value}K` derived from the osu!standard CS & OD \
+                • Notes: `{notes}` • LN: `{ln_percent:.1}%`",
+            )?;
+        }
+
+        Ok(())
     }
 }
 
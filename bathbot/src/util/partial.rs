@@ -0,0 +1,57 @@
+use eyre::Report;
+use twilight_model::channel::message::embed::EmbedField;
+
+/// Keeps track of secondary data sources that failed so a command can still
+/// respond with whatever it managed to gather.
+#[derive(Default)]
+pub struct PartialResult {
+    missing: Vec<&'static str>,
+}
+
+impl PartialResult {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the value on success.
+    ///
+    /// On failure, the error is logged and `source` is remembered as missing.
+    pub fn take<T, E: Into<Report>>(
+        &mut self,
+        source: &'static str,
+        res: Result<T, E>,
+    ) -> Option<T> {
+        match res {
+            Ok(value) => Some(value),
+            Err(err) => {
+                self.fail(source, err);
+
+                None
+            }
+        }
+    }
+
+    /// Log the error and remember `source` as missing.
+    pub fn fail<E: Into<Report>>(&mut self, source: &'static str, err: E) {
+        let err: Report = err.into();
+        warn!(source, ?err, "Continuing with partial result");
+        self.missing.push(source);
+    }
+
+    /// Embed field that lists all missing sources, if any.
+    pub fn warning_field(&self) -> Option<EmbedField> {
+        let (last, rest) = self.missing.split_last()?;
+
+        let sources = if rest.is_empty() {
+            (*last).to_owned()
+        } else {
+            format!("{} and {last}", rest.join(", "))
+        };
+
+        Some(EmbedField {
+            inline: false,
+            name: ":warning: Incomplete".to_owned(),
+            value: format!("Failed to retrieve {sources} so some data may be missing"),
+        })
+    }
+}
@@ -0,0 +1,153 @@
+use std::time::{Duration, Instant};
+
+use bathbot_util::{EmbedBuilder, MessageBuilder};
+use eyre::{Report, Result};
+use twilight_model::id::{
+    Id,
+    marker::{ChannelMarker, MessageMarker},
+};
+
+use crate::{
+    core::commands::CommandOrigin,
+    util::{ChannelExt, MessageExt},
+};
+
+/// Updates won't be sent more frequently than this to avoid ratelimits.
+const MIN_INTERVAL: Duration = Duration::from_millis(1500);
+
+/// Keeps the invoker informed while a slow command is processing.
+///
+/// Interactions show the progress as content of their response. Message
+/// commands use a separate message instead which is deleted once the actual
+/// response is sent through [`finish`] or [`error`].
+///
+/// [`finish`]: ProgressReporter::finish
+/// [`error`]: ProgressReporter::error
+pub struct ProgressReporter<'o, 'd> {
+    orig: &'o CommandOrigin<'d>,
+    msg: Option<(Id<MessageMarker>, Id<ChannelMarker>)>,
+    step: usize,
+    steps: usize,
+    last_update: Instant,
+}
+
+impl<'o, 'd> ProgressReporter<'o, 'd> {
+    /// Send the initial progress message for a process with `steps` steps.
+    pub async fn start(orig: &'o CommandOrigin<'d>, steps: usize) -> Self {
+        let builder = MessageBuilder::new().content(progress_content(0, steps, None));
+
+        let msg = match orig {
+            CommandOrigin::Message { msg, permissions } => {
+                match msg.create_message(builder, *permissions).await {
+                    Ok(response) => match response.model().await {
+                        Ok(msg) => Some((msg.id, msg.channel_id)),
+                        Err(err) => {
+                            warn!(?err, "Failed to deserialize progress message");
+
+                            None
+                        }
+                    },
+                    Err(err) => {
+                        warn!(?err, "Failed to create progress message");
+
+                        None
+                    }
+                }
+            }
+            CommandOrigin::Interaction { .. } => {
+                if let Err(err) = orig.update(builder).await {
+                    warn!(?err, "Failed to show progress");
+                }
+
+                None
+            }
+        };
+
+        Self {
+            orig,
+            msg,
+            step: 0,
+            steps,
+            last_update: Instant::now(),
+        }
+    }
+
+    /// Mark the next step as started.
+    pub async fn advance(&mut self, label: &str) {
+        self.step = (self.step + 1).min(self.steps);
+
+        if self.last_update.elapsed() < MIN_INTERVAL {
+            return;
+        }
+
+        let content = progress_content(self.step, self.steps, Some(label));
+        self.update(content).await;
+        self.last_update = Instant::now();
+    }
+
+    /// Replace the progress with the actual response.
+    pub async fn finish(self, mut builder: MessageBuilder<'_>) -> Result<()> {
+        self.remove().await;
+
+        // The progress is the response's content so it needs to be overwritten
+        if matches!(self.orig, CommandOrigin::Interaction { .. }) && builder.content.is_none() {
+            builder = builder.content("");
+        }
+
+        self.orig.create_message(builder).await.map(drop)
+    }
+
+    /// Replace the progress with a red embed.
+    pub async fn error(self, content: impl Into<String>) -> Result<()> {
+        self.remove().await;
+
+        match self.orig {
+            CommandOrigin::Message { .. } => self.orig.error(content).await,
+            CommandOrigin::Interaction { .. } => {
+                let embed = EmbedBuilder::new().description(content).color_red();
+                let builder = MessageBuilder::new().content("").embed(embed);
+
+                self.orig.update(builder).await.map(drop)
+            }
+        }
+    }
+
+    async fn remove(&self) {
+        if let Some(msg) = self.msg {
+            if let Err(err) = msg.delete().await {
+                warn!(?err, "Failed to delete progress message");
+            }
+        }
+    }
+
+    async fn update(&self, content: String) {
+        let builder = MessageBuilder::new().content(content);
+
+        let res = match (self.msg, self.orig) {
+            (Some(msg), CommandOrigin::Message { permissions, .. }) => {
+                match msg.update(builder, *permissions) {
+                    Some(update_fut) => update_fut.await.map(drop).map_err(Report::new),
+                    None => return,
+                }
+            }
+            (None, CommandOrigin::Interaction { .. }) => self.orig.update(builder).await.map(drop),
+            // The initial message could not be created
+            (_, CommandOrigin::Message { .. }) | (Some(_), CommandOrigin::Interaction { .. }) => {
+                return;
+            }
+        };
+
+        if let Err(err) = res {
+            warn!(?err, "Failed to update progress");
+        }
+    }
+}
+
+fn progress_content(step: usize, steps: usize, label: Option<&str>) -> String {
+    let percent = 100 * step / steps.max(1);
+
+    match label {
+        Some(label) => format!("Crunching numbers... `{percent}%` ({label})"),
+        None => format!("Crunching numbers... `{percent}%`"),
+    }
+}
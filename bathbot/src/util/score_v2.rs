@@ -0,0 +1,81 @@
+//! Approximate conversion from stable's ScoreV2 to lazer's standardized score.
+//!
+//! Both scoring algorithms consist of a combo portion of up to 700,000 and an
+//! accuracy portion of up to 300,000 that are then multiplied by the score
+//! multiplier of the mods. The main difference lies in those multipliers,
+//! e.g. `DT` gives 1.20x on ScoreV2 but only 1.12x on lazer and `NF` does not
+//! reduce ScoreV2 at all.
+//!
+//! The conversion divides out the ScoreV2 multipliers and applies lazer's.
+//! Slight differences in how combo is weighted are not considered so results
+//! should only be used to sanity-check totals.
+
+use rosu_v2::prelude::{GameMod, GameModIntermode, GameMods};
+
+use super::osu::score_multiplier;
+
+/// Estimate the lazer standardized score of a ScoreV2 score.
+pub fn score_v2_to_lazer(score: u32, mods: &GameMods) -> u32 {
+    let factor = f64::from(score_multiplier(mods)) / f64::from(score_v2_multiplier(mods));
+
+    (f64::from(score) * factor).round() as u32
+}
+
+fn score_v2_multiplier(mods: &GameMods) -> f32 {
+    mods.iter().map(score_v2_mod_multiplier).product()
+}
+
+fn score_v2_mod_multiplier(gamemod: &GameMod) -> f32 {
+    match gamemod.intermode() {
+        GameModIntermode::HalfTime | GameModIntermode::Daycore => 0.3,
+        GameModIntermode::Easy => 0.5,
+        GameModIntermode::SpunOut => 0.9,
+        GameModIntermode::Hidden => 1.06,
+        GameModIntermode::HardRock => 1.1,
+        GameModIntermode::Flashlight => 1.12,
+        GameModIntermode::DoubleTime | GameModIntermode::Nightcore => 1.2,
+        _ => 1.0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mods(gamemods: impl IntoIterator<Item = GameMod>) -> GameMods {
+        gamemods.into_iter().collect()
+    }
+
+    #[test]
+    fn nomod_is_unchanged() {
+        let nomod = GameMods::new();
+
+        assert_eq!(score_v2_to_lazer(987_654, &nomod), 987_654);
+    }
+
+    #[test]
+    fn doubletime() {
+        let dt = mods([GameMod::DoubleTimeOsu(Default::default())]);
+
+        // 1,000,000 * 1.2 on ScoreV2 corresponds to 1,000,000 * 1.12 on lazer
+        assert_eq!(score_v2_to_lazer(1_200_000, &dt), 1_120_000);
+    }
+
+    #[test]
+    fn nofail() {
+        let nf = mods([GameMod::NoFailOsu(Default::default())]);
+
+        assert_eq!(score_v2_to_lazer(800_000, &nf), 400_000);
+    }
+
+    #[test]
+    fn hidden_hardrock() {
+        let hdhr = mods([
+            GameMod::HiddenOsu(Default::default()),
+            GameMod::HardRockOsu(Default::default()),
+        ]);
+
+        // 1.06 * 1.06 on lazer instead of 1.06 * 1.1 on ScoreV2
+        assert_eq!(score_v2_to_lazer(1_100_000, &hdhr), 1_060_000);
+    }
+}
@@ -27,6 +27,16 @@ impl<'a> NativeCriteria<'a> {
         // `RegularCriteria`
         unsafe { mem::transmute(criteria) }
     }
+
+    /// Whether the map's long note percentage is within the requested range.
+    ///
+    /// Unlike other fields, this requires the map's hit objects so it cannot
+    /// be checked on a [`Score`] alone.
+    pub fn matches_ln(&self, map: &OsuMap) -> bool {
+        map.mode() != GameMode::Mania
+            || self.0.ln.is_empty()
+            || self.0.ln.contains(map.ln_percent())
+    }
 }
 
 impl<'q> IFilterCriteria<'q> for NativeCriteria<'q> {
@@ -142,6 +152,9 @@ impl Searchable<NativeCriteria<'_>> for (&'_ ScoreSlim, &'_ OsuMap) {
 
         matches &= score.mode != GameMode::Mania
             || criteria.0.keys.contains(keys(&score.mods, attrs.cs as f32));
+        matches &= score.mode != GameMode::Mania
+            || criteria.0.ln.is_empty()
+            || criteria.0.ln.contains(map.ln_percent());
 
         if matches && criteria.has_search_terms() {
             let artist = map.artist().cow_to_ascii_lowercase();